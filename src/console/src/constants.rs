@@ -26,7 +26,16 @@ pub const DEFAULT_RELEASES_COLLECTIONS: [(&str, SetRule); 1] = [(
         max_capacity: None,
         version: None,
         rate_config: None,
+        user_quota: None,
+        collation: None,
+        retention: None,
     },
 )];
 
 pub const RELEASES_METADATA_JSON: &str = "/releases/metadata.json";
+
+pub const RELEASES_TEMPLATES_PATH: &str = "/releases/templates";
+
+// The creation statistics are accounted per day and kept for three months.
+pub const CREATION_STATS_PERIOD_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+pub const CREATION_STATS_MAX_PERIODS: u64 = 90;
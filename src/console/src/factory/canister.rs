@@ -21,16 +21,14 @@ pub async fn create_canister<F, Fut>(
     get_fee: &dyn Fn() -> Tokens,
     console: Principal,
     caller: Principal,
-    CreateCanisterArgs {
-        user,
-        block_index,
-        subnet_id,
-    }: CreateCanisterArgs,
+    args: CreateCanisterArgs,
 ) -> Result<Principal, String>
 where
     F: FnOnce(Principal, MissionControlId, UserId, Option<SubnetId>) -> Fut,
     Fut: Future<Output = Result<Principal, String>>,
 {
+    let user = args.user;
+
     // User should have a mission control center
     let mission_control = get_existing_mission_control(&user, &caller)?;
 
@@ -48,24 +46,13 @@ where
                     console,
                     mission_control_id,
                     user,
-                    subnet_id,
+                    args.subnet_id,
                 )
                 .await;
             }
 
-            create_canister_with_payment(
-                create,
-                console,
-                caller,
-                mission_control_id,
-                CreateCanisterArgs {
-                    user,
-                    block_index,
-                    subnet_id,
-                },
-                fee,
-            )
-            .await
+            create_canister_with_payment(create, console, caller, mission_control_id, args, fee)
+                .await
         }
     }
 }
@@ -107,6 +94,7 @@ async fn create_canister_with_payment<F, Fut>(
         user,
         block_index,
         subnet_id,
+        ..
     }: CreateCanisterArgs,
     fee: Tokens,
 ) -> Result<Principal, String>
@@ -1,8 +1,10 @@
 use crate::controllers::update_mission_control_controllers;
+use crate::health::record_creation;
 use crate::store::heap::increment_mission_controls_rate;
 use crate::store::stable::{
     add_mission_control, delete_mission_control, get_mission_control, init_empty_mission_control,
 };
+use crate::types::interface::SegmentType;
 use crate::types::state::MissionControl;
 use crate::wasm::mission_control_wasm_arg;
 use candid::Principal;
@@ -23,7 +25,11 @@ pub async fn init_user_mission_control(
                 // Guard too many requests
                 increment_mission_controls_rate()?;
 
-                create_mission_control(caller, console).await
+                let result = create_mission_control(caller, console).await;
+
+                record_creation(&SegmentType::MissionControl, result.is_ok());
+
+                result
             }
         },
     }
@@ -1,6 +1,8 @@
 use crate::controllers::remove_console_controller;
 use crate::factory::canister::create_canister;
+use crate::health::record_creation;
 use crate::store::heap::{get_orbiter_fee, increment_orbiters_rate};
+use crate::types::interface::SegmentType;
 use crate::wasm::orbiter_wasm_arg;
 use candid::Principal;
 use junobuild_shared::constants::CREATE_ORBITER_CYCLES;
@@ -16,7 +18,13 @@ pub async fn create_orbiter(
     args: CreateCanisterArgs,
 ) -> Result<Principal, String> {
     create_canister(
-        create_orbiter_wasm,
+        |console, mission_control_id, user, subnet_id| async move {
+            let result = create_orbiter_wasm(console, mission_control_id, user, subnet_id).await;
+
+            record_creation(&SegmentType::Orbiter, result.is_ok());
+
+            result
+        },
         &increment_orbiters_rate,
         &get_orbiter_fee,
         console,
@@ -1,13 +1,16 @@
 use crate::controllers::remove_console_controller;
 use crate::factory::canister::create_canister;
+use crate::health::record_creation;
+use crate::project_templates::resolve_init_config;
 use crate::store::heap::{get_satellite_fee, increment_satellites_rate};
+use crate::types::interface::SegmentType;
 use crate::wasm::satellite_wasm_arg;
 use candid::Principal;
 use junobuild_shared::constants::CREATE_SATELLITE_CYCLES;
 use junobuild_shared::mgmt::cmc::cmc_create_canister_install_code;
 use junobuild_shared::mgmt::ic::create_canister_install_code;
 use junobuild_shared::mgmt::types::cmc::SubnetId;
-use junobuild_shared::types::interface::CreateCanisterArgs;
+use junobuild_shared::types::interface::{CreateCanisterArgs, SatelliteInitConfig};
 use junobuild_shared::types::state::{MissionControlId, UserId};
 
 pub async fn create_satellite(
@@ -15,8 +18,18 @@ pub async fn create_satellite(
     caller: Principal,
     args: CreateCanisterArgs,
 ) -> Result<Principal, String> {
+    let init_config = resolve_init_config(&args.project_template, &args.init_config)?;
+
     create_canister(
-        create_satellite_wasm,
+        |console, mission_control_id, user, subnet_id| async move {
+            let result =
+                create_satellite_wasm(console, mission_control_id, user, subnet_id, init_config)
+                    .await;
+
+            record_creation(&SegmentType::Satellite, result.is_ok());
+
+            result
+        },
         &increment_satellites_rate,
         &get_satellite_fee,
         console,
@@ -31,8 +44,9 @@ async fn create_satellite_wasm(
     mission_control_id: MissionControlId,
     user: UserId,
     subnet_id: Option<SubnetId>,
+    init_config: Option<SatelliteInitConfig>,
 ) -> Result<Principal, String> {
-    let wasm_arg = satellite_wasm_arg(&user, &mission_control_id)?;
+    let wasm_arg = satellite_wasm_arg(&user, &mission_control_id, init_config)?;
 
     let controller = Vec::from([console, mission_control_id, user]);
 
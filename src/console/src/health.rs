@@ -0,0 +1,74 @@
+use crate::constants::{CREATION_STATS_MAX_PERIODS, CREATION_STATS_PERIOD_NS};
+use crate::store::heap::{
+    get_latest_mission_control_version, get_latest_orbiter_version, get_latest_satellite_version,
+};
+use crate::store::stable::{delete_creation_stats_before, increment_creation_stats};
+use crate::types::interface::{
+    ConsoleHealth, ObservatoryHealth, PlatformHealth, ReleasesHealth, SegmentType,
+};
+use crate::types::state::CreationStatsKey;
+use candid::Principal;
+use ic_cdk::api::{canister_balance128, time};
+use ic_cdk::call;
+use junobuild_shared::canister::memory_size;
+use junobuild_shared::env::OBSERVATORY;
+
+/// Collects the health of the console, of the observatory - which is reached through an inter-canister call - and the
+/// latest released versions, for the dashboard of the operators.
+pub async fn get_platform_health() -> PlatformHealth {
+    let console = ConsoleHealth {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        memory_size: memory_size(),
+        cycles: canister_balance128(),
+    };
+
+    let observatory = get_observatory_health().await;
+
+    let releases = ReleasesHealth {
+        satellite: get_latest_satellite_version(),
+        mission_control: get_latest_mission_control_version(),
+        orbiter: get_latest_orbiter_version(),
+    };
+
+    PlatformHealth {
+        console,
+        observatory,
+        releases,
+        timestamp: time(),
+    }
+}
+
+async fn get_observatory_health() -> ObservatoryHealth {
+    let observatory = Principal::from_text(OBSERVATORY).unwrap();
+
+    let result: Result<(String,), _> = call(observatory, "version", ()).await;
+
+    match result {
+        Ok((version,)) => ObservatoryHealth {
+            version: Some(version),
+            error: None,
+        },
+        Err((code, message)) => ObservatoryHealth {
+            version: None,
+            error: Some(format!("{:?} - {}", code, message)),
+        },
+    }
+}
+
+/// Accounts the outcome of the creation of a segment in the statistics of the current day and drops the days that are
+/// out of the retained period, so that the statistics remain bounded.
+pub fn record_creation(segment: &SegmentType, succeeded: bool) {
+    let day = time() / CREATION_STATS_PERIOD_NS * CREATION_STATS_PERIOD_NS;
+
+    let key = CreationStatsKey {
+        day,
+        segment: segment.clone(),
+    };
+
+    increment_creation_stats(&key, succeeded);
+
+    let oldest_day =
+        day.saturating_sub((CREATION_STATS_MAX_PERIODS - 1) * CREATION_STATS_PERIOD_NS);
+
+    delete_creation_stats_before(oldest_day);
+}
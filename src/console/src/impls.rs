@@ -3,8 +3,8 @@ use crate::memory::init_stable_state;
 use crate::types::core::CommitProposalError;
 use crate::types::ledger::Payment;
 use crate::types::state::{
-    Fee, Fees, HeapState, MissionControl, Proposal, ProposalKey, ProposalStatus, ProposalType,
-    Rate, Rates, State,
+    CreationStats, CreationStatsKey, Fee, Fees, HeapState, MissionControl, Proposal, ProposalKey,
+    ProposalStatus, ProposalType, Rate, Rates, State,
 };
 use candid::Principal;
 use ic_cdk::api::time;
@@ -119,6 +119,30 @@ impl Storable for Proposal {
     const BOUND: Bound = Bound::Unbounded;
 }
 
+impl Storable for CreationStatsKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl Storable for CreationStats {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
 impl Proposal {
     fn get_next_version(current_proposal: &Option<Proposal>) -> Version {
         match current_proposal {
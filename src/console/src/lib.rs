@@ -2,13 +2,16 @@ mod constants;
 mod controllers;
 mod factory;
 mod guards;
+mod health;
 mod impls;
 mod memory;
 mod metadata;
 mod msg;
+mod project_templates;
 mod proposals;
 mod storage;
 mod store;
+mod templates;
 mod types;
 mod wasm;
 
@@ -16,7 +19,9 @@ use crate::factory::mission_control::init_user_mission_control;
 use crate::factory::orbiter::create_orbiter as create_orbiter_console;
 use crate::factory::satellite::create_satellite as create_satellite_console;
 use crate::guards::{caller_is_admin_controller, caller_is_observatory};
+use crate::health::get_platform_health as get_platform_health_store;
 use crate::memory::{init_storage_heap_state, STATE};
+use crate::project_templates::set_project_template as set_project_template_store;
 use crate::proposals::{
     commit_proposal as make_commit_proposal,
     delete_proposal_assets as delete_proposal_assets_proposal, init_proposal as make_init_proposal,
@@ -30,21 +35,34 @@ use crate::storage::store::{
 };
 use crate::storage::strategy_impls::{StorageAssertions, StorageState, StorageUpload};
 use crate::store::heap::{
-    add_invitation_code as add_invitation_code_store, delete_controllers, get_controllers,
-    get_orbiter_fee, get_satellite_fee, set_controllers as set_controllers_store,
-    set_create_orbiter_fee, set_create_satellite_fee, update_mission_controls_rate_config,
-    update_orbiters_rate_config, update_satellites_rate_config,
+    add_invitation_code as add_invitation_code_store, delete_controllers, delete_project_template,
+    get_controllers, get_orbiter_fee, get_satellite_fee,
+    list_project_templates as list_project_templates_store, list_templates as list_templates_store,
+    set_controllers as set_controllers_store, set_create_orbiter_fee, set_create_satellite_fee,
+    update_mission_controls_rate_config, update_orbiters_rate_config,
+    update_satellites_rate_config,
 };
 use crate::store::stable::{
     add_credits as add_credits_store, get_credits as get_credits_store,
     get_existing_mission_control, get_mission_control, get_proposal as get_proposal_state,
-    has_credits, list_mission_controls, list_payments as list_payments_state,
+    has_credits, list_creation_stats as list_creation_stats_store, list_mission_controls,
+    list_payments as list_payments_state,
+};
+use crate::templates::{
+    get_template_wasm_chunk as get_template_wasm_chunk_store,
+    set_template_version as set_template_version_store,
+};
+use crate::types::interface::{
+    CommitProposal, Config, DeleteProposalAssets, GetReleaseWasmChunk, GetTemplateWasmChunk,
+    PlatformHealth, ReleaseWasmChunk, SegmentType, SetProjectTemplate, SetTemplateVersion,
+    TemplateWasmChunk,
 };
-use crate::types::interface::{CommitProposal, Config, DeleteProposalAssets, SegmentType};
 use crate::types::state::{
-    Fees, HeapState, InvitationCode, MissionControl, MissionControls, Proposal, ProposalId,
-    ProposalType, Rates, ReleasesMetadata, State,
+    CreationStats, CreationStatsKey, Fees, HeapState, InvitationCode, MissionControl,
+    MissionControls, ProjectTemplate, ProjectTemplateId, Proposal, ProposalId, ProposalType, Rates,
+    ReleasesMetadata, State, Template, TemplateId,
 };
+use crate::wasm::get_release_wasm_chunk as get_release_wasm_chunk_store;
 use candid::Principal;
 use ciborium::into_writer;
 use ic_cdk::api::call::ManualReply;
@@ -54,14 +72,15 @@ use ic_cdk_macros::{export_candid, init, post_upgrade, pre_upgrade, query, updat
 use ic_ledger_types::Tokens;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_shared::controllers::init_controllers;
+use junobuild_shared::list::list_page;
 use junobuild_shared::rate::types::RateConfig;
-use junobuild_shared::types::core::DomainName;
+use junobuild_shared::types::core::{DomainName, Key};
 use junobuild_shared::types::domain::CustomDomains;
 use junobuild_shared::types::interface::{
     AssertMissionControlCenterArgs, CreateCanisterArgs, DeleteControllersArgs,
     GetCreateCanisterFeeArgs, SetControllersArgs,
 };
-use junobuild_shared::types::list::{ListParams, ListResults};
+use junobuild_shared::types::list::{ListParams, ListResults, Page};
 use junobuild_shared::types::state::{Controllers, UserId};
 use junobuild_shared::upgrade::{read_post_upgrade, write_pre_upgrade};
 use junobuild_storage::http::types::{
@@ -94,6 +113,8 @@ fn init() {
         fees: Fees::default(),
         storage: init_storage_heap_state(),
         releases_metadata: ReleasesMetadata::default(),
+        templates: None,
+        project_templates: None,
     };
 
     STATE.with(|state| {
@@ -288,6 +309,20 @@ fn version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
 
+// ---------------------------------------------------------
+// Health
+// ---------------------------------------------------------
+
+#[update(guard = "caller_is_admin_controller")]
+async fn get_platform_health() -> PlatformHealth {
+    get_platform_health_store().await
+}
+
+#[query(guard = "caller_is_admin_controller")]
+fn list_creation_stats() -> Vec<(CreationStatsKey, CreationStats)> {
+    list_creation_stats_store()
+}
+
 // ---------------------------------------------------------
 // Controllers
 // ---------------------------------------------------------
@@ -348,6 +383,49 @@ fn delete_proposal_assets(DeleteProposalAssets { proposal_ids }: DeleteProposalA
     delete_proposal_assets_proposal(caller, &proposal_ids).unwrap_or_else(|e| trap(&e));
 }
 
+// ---------------------------------------------------------
+// Templates
+// ---------------------------------------------------------
+
+#[update(guard = "caller_is_admin_controller")]
+fn set_template_version(id: TemplateId, version: SetTemplateVersion) -> Template {
+    set_template_version_store(&id, &version).unwrap_or_else(|e| trap(&e))
+}
+
+#[query]
+fn list_templates() -> Vec<(TemplateId, Template)> {
+    list_templates_store()
+}
+
+#[query]
+fn get_template_wasm_chunk(id: TemplateId, chunk: GetTemplateWasmChunk) -> TemplateWasmChunk {
+    get_template_wasm_chunk_store(&id, &chunk).unwrap_or_else(|e| trap(&e))
+}
+
+// ---------------------------------------------------------
+// Project templates
+// ---------------------------------------------------------
+
+#[update(guard = "caller_is_admin_controller")]
+fn set_project_template(id: ProjectTemplateId, template: SetProjectTemplate) -> ProjectTemplate {
+    set_project_template_store(&id, &template).unwrap_or_else(|e| trap(&e))
+}
+
+#[update(guard = "caller_is_admin_controller")]
+fn del_project_template(id: ProjectTemplateId) {
+    delete_project_template(&id);
+}
+
+#[query]
+fn list_project_templates() -> Vec<(ProjectTemplateId, ProjectTemplate)> {
+    list_project_templates_store()
+}
+
+#[query]
+fn get_release_wasm_chunk(chunk: GetReleaseWasmChunk) -> ReleaseWasmChunk {
+    get_release_wasm_chunk_store(&chunk).unwrap_or_else(|e| trap(&e))
+}
+
 // ---------------------------------------------------------
 // Storage
 // ---------------------------------------------------------
@@ -406,6 +484,14 @@ pub fn list_assets(collection: CollectionKey, filter: ListParams) -> ListResults
     }
 }
 
+#[query(guard = "caller_is_admin_controller")]
+pub fn list_assets_page(
+    collection: CollectionKey,
+    filter: ListParams,
+) -> Page<(Key, AssetNoContent)> {
+    list_page(|filter| list_assets(collection, filter), &filter)
+}
+
 // ---------------------------------------------------------
 // Config
 // ---------------------------------------------------------
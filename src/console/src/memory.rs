@@ -14,6 +14,7 @@ const PAYMENTS: MemoryId = MemoryId::new(2);
 const ASSETS: MemoryId = MemoryId::new(3);
 const CONTENT_CHUNKS: MemoryId = MemoryId::new(4);
 const PROPOSALS: MemoryId = MemoryId::new(5);
+const CREATION_STATS: MemoryId = MemoryId::new(6);
 
 thread_local! {
     pub static STATE: RefCell<State> = RefCell::default();
@@ -46,6 +47,10 @@ fn get_memory_proposals() -> Memory {
     MEMORY_MANAGER.with(|m| m.borrow().get(PROPOSALS))
 }
 
+fn get_memory_creation_stats() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(CREATION_STATS))
+}
+
 pub fn init_stable_state() -> StableState {
     StableState {
         mission_controls: StableBTreeMap::init(get_memory_mission_controls()),
@@ -53,6 +58,7 @@ pub fn init_stable_state() -> StableState {
         proposals_assets: StableBTreeMap::init(get_memory_assets()),
         proposals_content_chunks: StableBTreeMap::init(get_memory_content_chunks()),
         proposals: StableBTreeMap::init(get_memory_proposals()),
+        creation_stats: StableBTreeMap::init(get_memory_creation_stats()),
     }
 }
 
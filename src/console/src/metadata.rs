@@ -56,6 +56,9 @@ pub fn map_releases_metadata_asset(metadata: &str, existing_asset: Option<Asset>
         collection: RELEASES_COLLECTION_KEY.to_string(),
         owner: id(),
         description: None,
+        publish_at: None,
+        unpublish_at: None,
+        moderation: None,
     };
 
     let headers = map_content_type_headers("application/json");
@@ -2,3 +2,16 @@ pub const ERROR_CANNOT_SUBMIT_PROPOSAL: &str = "Cannot submit proposal.";
 pub const ERROR_CANNOT_COMMIT_PROPOSAL: &str = "Cannot commit proposal.";
 pub const ERROR_PROPOSAL_TYPE_NOT_SUPPORTED: &str = "Proposal type is not supported.";
 pub const ERROR_CANNOT_DELETE_PROPOSAL_ASSETS: &str = "Cannot delete proposal assets.";
+pub const ERROR_TEMPLATE_NOT_FOUND: &str = "Template not found.";
+pub const ERROR_TEMPLATE_VERSION_NOT_FOUND: &str = "Template version not found.";
+pub const ERROR_TEMPLATE_VERSION_EXISTS: &str = "Template version already published.";
+pub const ERROR_TEMPLATE_INVALID_ID: &str =
+    "Template ID must contain only lowercase letters, digits and dashes.";
+pub const ERROR_PROJECT_TEMPLATE_NOT_FOUND: &str = "Project template not found.";
+pub const ERROR_PROJECT_TEMPLATE_EMPTY_BUNDLE: &str =
+    "The configuration bundle of a project template cannot be empty.";
+pub const ERROR_PROJECT_TEMPLATE_WITH_BUNDLE: &str =
+    "A project template and a configuration bundle cannot be provided together.";
+pub const ERROR_RELEASE_VERSION_NOT_FOUND: &str = "Release version not found.";
+pub const ERROR_SCHEDULED_ASSET_NOT_SUPPORTED: &str =
+    "Scheduled publishing is not supported for the assets of the console.";
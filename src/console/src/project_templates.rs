@@ -0,0 +1,72 @@
+use crate::msg::{
+    ERROR_PROJECT_TEMPLATE_EMPTY_BUNDLE, ERROR_PROJECT_TEMPLATE_NOT_FOUND,
+    ERROR_PROJECT_TEMPLATE_WITH_BUNDLE,
+};
+use crate::store::heap::{get_project_template, insert_project_template};
+use crate::templates::assert_template_id;
+use crate::types::interface::SetProjectTemplate;
+use crate::types::state::{ProjectTemplate, ProjectTemplateId};
+use ic_cdk::api::time;
+use junobuild_shared::assert::assert_description_length;
+use junobuild_shared::types::interface::SatelliteInitConfig;
+
+/// Creates or replaces a project template. The bundle is not decoded by the console, it is validated by the satellites
+/// when they are installed with it.
+pub fn set_project_template(
+    id: &ProjectTemplateId,
+    SetProjectTemplate {
+        description,
+        bundle,
+    }: &SetProjectTemplate,
+) -> Result<ProjectTemplate, String> {
+    assert_template_id(id)?;
+
+    assert_description_length(description)?;
+
+    if bundle.is_empty() {
+        return Err(ERROR_PROJECT_TEMPLATE_EMPTY_BUNDLE.to_string());
+    }
+
+    let now = time();
+
+    let created_at = get_project_template(id).map_or(now, |template| template.created_at);
+
+    let template = ProjectTemplate {
+        description: description.clone(),
+        bundle: bundle.clone(),
+        created_at,
+        updated_at: now,
+    };
+
+    insert_project_template(id, &template);
+
+    Ok(template)
+}
+
+/// The configuration a satellite is installed with - i.e. the bundle of the selected project template, if any,
+/// together with the controllers provided at creation time.
+pub fn resolve_init_config(
+    project_template: &Option<ProjectTemplateId>,
+    init_config: &Option<SatelliteInitConfig>,
+) -> Result<Option<SatelliteInitConfig>, String> {
+    let Some(id) = project_template else {
+        return Ok(init_config.clone());
+    };
+
+    let template = get_project_template(id).ok_or(ERROR_PROJECT_TEMPLATE_NOT_FOUND.to_string())?;
+
+    if init_config
+        .as_ref()
+        .is_some_and(|init_config| init_config.bundle.is_some())
+    {
+        return Err(ERROR_PROJECT_TEMPLATE_WITH_BUNDLE.to_string());
+    }
+
+    Ok(Some(SatelliteInitConfig {
+        bundle: Some(template.bundle),
+        controllers: init_config
+            .as_ref()
+            .map(|init_config| init_config.controllers.clone())
+            .unwrap_or_default(),
+    }))
+}
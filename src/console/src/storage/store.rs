@@ -1,4 +1,6 @@
+use crate::constants::RELEASES_TEMPLATES_PATH;
 use crate::memory::STATE;
+use crate::msg::ERROR_SCHEDULED_ASSET_NOT_SUPPORTED;
 use crate::storage::certified_assets::runtime::init_certified_assets as init_runtime_certified_assets;
 use crate::storage::state::heap::{
     collect_delete_assets, delete_asset, delete_domain, get_asset, get_config, get_domain,
@@ -88,6 +90,9 @@ fn list_assets_impl(
         items_page: values.items_page,
         matches_length: values.matches_length,
         matches_pages: values.matches_pages,
+        truncated: values.truncated,
+        resume_after: values.resume_after,
+        version: values.version,
     }
 }
 
@@ -112,6 +117,10 @@ pub fn init_asset_upload(
 
     assert_releases_keys(&init)?;
 
+    if init.publish_at.is_some() || init.unpublish_at.is_some() {
+        return Err(ERROR_SCHEDULED_ASSET_NOT_SUPPORTED.to_string());
+    }
+
     let controllers = get_controllers();
     let config = get_config();
 
@@ -148,6 +157,19 @@ fn assert_releases_keys(InitAssetKey { full_path, .. }: &InitAssetKey) -> Result
         };
     }
 
+    if full_path.starts_with(RELEASES_TEMPLATES_PATH) {
+        let re = Regex::new(r"^/releases/templates/[a-z0-9-]+-v\d+\.\d+\.\d+\.wasm\.gz$").unwrap();
+
+        return if re.is_match(full_path) {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} does not match the required pattern.",
+                full_path
+            ))
+        };
+    }
+
     Ok(())
 }
 
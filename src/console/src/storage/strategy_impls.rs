@@ -17,7 +17,7 @@ use junobuild_storage::strategies::{
 use junobuild_storage::types::config::StorageConfig;
 use junobuild_storage::types::state::FullPath;
 use junobuild_storage::types::store::{
-    Asset, AssetAssertUpload, AssetEncoding, Batch, EncodingType, ReferenceId,
+    Asset, AssetAssertUpload, AssetEncoding, AssetKey, Batch, EncodingType, ReferenceId,
 };
 
 pub struct StorageAssertions;
@@ -31,6 +31,18 @@ impl StorageAssertionsStrategy for StorageAssertions {
         // No pre-assertions on the console
         Ok(())
     }
+
+    fn assert_user_quota(
+        &self,
+        _caller: &Principal,
+        _key: &AssetKey,
+        _rule: &Rule,
+        _current: &Option<Asset>,
+        _bytes: u128,
+    ) -> Result<(), String> {
+        // No quotas on the console
+        Ok(())
+    }
 }
 
 pub struct StorageState;
@@ -65,6 +77,11 @@ impl StorageStateStrategy for StorageState {
         get_domains()
     }
 
+    // The console does not seed a random number generator, therefore it serves its pages without CSP nonces.
+    fn get_random_seed(&self) -> Option<[u8; 32]> {
+        None
+    }
+
     fn get_asset(
         &self,
         _collection: &CollectionKey,
@@ -1,7 +1,8 @@
 use crate::memory::STATE;
 use crate::types::state::{
     Fee, Fees, HeapState, InvitationCode, InvitationCodeRedeem, InvitationCodes, MissionControls,
-    Payments, Rate, ReleaseVersion, ReleasesMetadata,
+    Payments, ProjectTemplate, ProjectTemplateId, Rate, ReleaseVersion, ReleasesMetadata, Template,
+    TemplateId,
 };
 use ic_cdk::api::time;
 use ic_ledger_types::Tokens;
@@ -14,7 +15,7 @@ use junobuild_shared::types::interface::SetController;
 use junobuild_shared::types::state::UserId;
 use junobuild_shared::types::state::{ControllerId, Controllers};
 use semver::Version;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 // ---------------------------------------------------------
 // Mission control centers
@@ -233,3 +234,104 @@ fn get_latest_version(versions: &HashSet<ReleaseVersion>) -> Option<ReleaseVersi
         .max_by(|(parsed_a, _), (parsed_b, _)| parsed_a.cmp(parsed_b))
         .map(|(_, version)| version.clone())
 }
+
+// ---------------------------------------------------------
+// Templates
+// ---------------------------------------------------------
+
+pub fn get_template(id: &TemplateId) -> Option<Template> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .templates
+            .as_ref()
+            .and_then(|templates| templates.get(id).cloned())
+    })
+}
+
+pub fn list_templates() -> Vec<(TemplateId, Template)> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .templates
+            .as_ref()
+            .map(|templates| {
+                templates
+                    .iter()
+                    .map(|(id, template)| (id.clone(), template.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    })
+}
+
+pub fn insert_template(id: &TemplateId, template: &Template) {
+    STATE.with(|state| insert_template_impl(id, template, &mut state.borrow_mut().heap))
+}
+
+fn insert_template_impl(id: &TemplateId, template: &Template, heap_state: &mut HeapState) {
+    heap_state
+        .templates
+        .get_or_insert_with(HashMap::new)
+        .insert(id.clone(), template.clone());
+}
+
+// ---------------------------------------------------------
+// Project templates
+// ---------------------------------------------------------
+
+pub fn get_project_template(id: &ProjectTemplateId) -> Option<ProjectTemplate> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .project_templates
+            .as_ref()
+            .and_then(|templates| templates.get(id).cloned())
+    })
+}
+
+pub fn list_project_templates() -> Vec<(ProjectTemplateId, ProjectTemplate)> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .project_templates
+            .as_ref()
+            .map(|templates| {
+                templates
+                    .iter()
+                    .map(|(id, template)| (id.clone(), template.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    })
+}
+
+pub fn insert_project_template(id: &ProjectTemplateId, template: &ProjectTemplate) {
+    STATE.with(|state| insert_project_template_impl(id, template, &mut state.borrow_mut().heap))
+}
+
+pub fn delete_project_template(id: &ProjectTemplateId) -> Option<ProjectTemplate> {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .project_templates
+            .as_mut()
+            .and_then(|templates| templates.remove(id))
+    })
+}
+
+fn insert_project_template_impl(
+    id: &ProjectTemplateId,
+    template: &ProjectTemplate,
+    heap_state: &mut HeapState,
+) {
+    heap_state
+        .project_templates
+        .get_or_insert_with(HashMap::new)
+        .insert(id.clone(), template.clone());
+}
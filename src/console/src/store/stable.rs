@@ -2,14 +2,15 @@ use crate::constants::E8S_PER_ICP;
 use crate::memory::STATE;
 use crate::types::ledger::{Payment, PaymentStatus};
 use crate::types::state::{
-    MissionControl, MissionControls, MissionControlsStable, Payments, PaymentsStable, Proposal,
-    ProposalId, ProposalKey, ProposalsStable, StableState,
+    CreationStats, CreationStatsKey, CreationStatsStable, MissionControl, MissionControls,
+    MissionControlsStable, Payments, PaymentsStable, Proposal, ProposalId, ProposalKey,
+    ProposalsStable, StableState,
 };
 use ic_cdk::api::time;
 use ic_ledger_types::BlockIndex;
 use ic_ledger_types::Tokens;
-use junobuild_shared::types::state::MissionControlId;
 use junobuild_shared::types::state::UserId;
+use junobuild_shared::types::state::{MissionControlId, Timestamp};
 use junobuild_shared::utils::principal_equal;
 
 // ---------------------------------------------------------
@@ -391,3 +392,69 @@ fn stable_proposal_key(proposal_id: &ProposalId) -> ProposalKey {
         proposal_id: *proposal_id,
     }
 }
+
+// ---------------------------------------------------------
+// Creation statistics
+// ---------------------------------------------------------
+
+pub fn increment_creation_stats(key: &CreationStatsKey, succeeded: bool) {
+    STATE.with(|state| {
+        increment_creation_stats_impl(
+            key,
+            succeeded,
+            &mut state.borrow_mut().stable.creation_stats,
+        )
+    })
+}
+
+fn increment_creation_stats_impl(
+    key: &CreationStatsKey,
+    succeeded: bool,
+    creation_stats: &mut CreationStatsStable,
+) {
+    let current = creation_stats.get(key).unwrap_or_default();
+
+    let stats = if succeeded {
+        CreationStats {
+            succeeded: current.succeeded.saturating_add(1),
+            ..current
+        }
+    } else {
+        CreationStats {
+            failed: current.failed.saturating_add(1),
+            ..current
+        }
+    };
+
+    creation_stats.insert(key.clone(), stats);
+}
+
+/// Removes the statistics of the days before the given one. Keys are ordered by day first, therefore the expired
+/// entries are always the first ones.
+pub fn delete_creation_stats_before(day: Timestamp) {
+    STATE.with(|state| {
+        delete_creation_stats_before_impl(day, &mut state.borrow_mut().stable.creation_stats)
+    })
+}
+
+fn delete_creation_stats_before_impl(day: Timestamp, creation_stats: &mut CreationStatsStable) {
+    let expired: Vec<CreationStatsKey> = creation_stats
+        .iter()
+        .take_while(|(key, _)| key.day < day)
+        .map(|(key, _)| key)
+        .collect();
+
+    for key in expired {
+        creation_stats.remove(&key);
+    }
+}
+
+pub fn list_creation_stats() -> Vec<(CreationStatsKey, CreationStats)> {
+    STATE.with(|state| list_creation_stats_impl(&state.borrow().stable.creation_stats))
+}
+
+fn list_creation_stats_impl(
+    creation_stats: &CreationStatsStable,
+) -> Vec<(CreationStatsKey, CreationStats)> {
+    creation_stats.iter().collect()
+}
@@ -0,0 +1,122 @@
+use crate::constants::RELEASES_TEMPLATES_PATH;
+use crate::msg::{
+    ERROR_TEMPLATE_INVALID_ID, ERROR_TEMPLATE_NOT_FOUND, ERROR_TEMPLATE_VERSION_EXISTS,
+    ERROR_TEMPLATE_VERSION_NOT_FOUND,
+};
+use crate::store::heap::{get_template, insert_template};
+use crate::types::interface::{GetTemplateWasmChunk, SetTemplateVersion, TemplateWasmChunk};
+use crate::types::state::{ReleaseVersion, Template, TemplateId, TemplateVersion};
+use crate::wasm::get_identity_encoding;
+use ic_cdk::api::time;
+use junobuild_shared::assert::assert_description_length;
+use junobuild_storage::types::state::FullPath;
+use semver::Version;
+use std::collections::BTreeMap;
+
+/// Publishes a version of a template. The WASM must have been uploaded beforehand - with a proposal - to the releases storage,
+/// e.g. /releases/templates/my-extension-v0.1.0.wasm.gz for the template "my-extension" and version "0.1.0".
+pub fn set_template_version(
+    id: &TemplateId,
+    SetTemplateVersion {
+        version,
+        description,
+        metadata,
+    }: &SetTemplateVersion,
+) -> Result<Template, String> {
+    assert_template_id(id)?;
+
+    Version::parse(version).map_err(|e| e.to_string())?;
+
+    assert_description_length(description)?;
+
+    let current_template = get_template(id);
+
+    if let Some(current_template) = &current_template {
+        if current_template.versions.contains_key(version) {
+            return Err(ERROR_TEMPLATE_VERSION_EXISTS.to_string());
+        }
+    }
+
+    let full_path = template_full_path(id, version);
+
+    let encoding = get_identity_encoding(&full_path)?;
+
+    let now = time();
+
+    let template_version = TemplateVersion {
+        full_path,
+        sha256: encoding.sha256,
+        size: encoding.total_length as u64,
+        metadata: metadata.clone(),
+        created_at: now,
+    };
+
+    let template = match current_template {
+        None => Template {
+            description: description.clone(),
+            versions: BTreeMap::from([(version.clone(), template_version)]),
+            created_at: now,
+            updated_at: now,
+        },
+        Some(current_template) => {
+            let mut versions = current_template.versions.clone();
+            versions.insert(version.clone(), template_version);
+
+            Template {
+                description: description.clone().or(current_template.description),
+                versions,
+                created_at: current_template.created_at,
+                updated_at: now,
+            }
+        }
+    };
+
+    insert_template(id, &template);
+
+    Ok(template)
+}
+
+/// The WASM is served in chunks - those of the upload - so that mission controls can fetch templates larger than the inter-canister message limit.
+pub fn get_template_wasm_chunk(
+    id: &TemplateId,
+    GetTemplateWasmChunk {
+        version,
+        chunk_index,
+    }: &GetTemplateWasmChunk,
+) -> Result<TemplateWasmChunk, String> {
+    let template = get_template(id).ok_or(ERROR_TEMPLATE_NOT_FOUND.to_string())?;
+
+    let template_version = template
+        .versions
+        .get(version)
+        .ok_or(ERROR_TEMPLATE_VERSION_NOT_FOUND.to_string())?;
+
+    let encoding = get_identity_encoding(&template_version.full_path)?;
+
+    let content = encoding
+        .content_chunks
+        .get(*chunk_index as usize)
+        .ok_or(format!("No chunk {} for template {}.", chunk_index, id))?;
+
+    Ok(TemplateWasmChunk {
+        content: content.clone(),
+        chunks_length: encoding.content_chunks.len() as u64,
+        sha256: template_version.sha256,
+    })
+}
+
+fn template_full_path(id: &TemplateId, version: &ReleaseVersion) -> FullPath {
+    format!("{}/{}-v{}.wasm.gz", RELEASES_TEMPLATES_PATH, id, version)
+}
+
+pub fn assert_template_id(id: &TemplateId) -> Result<(), String> {
+    if id.is_empty()
+        || !id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err(ERROR_TEMPLATE_INVALID_ID.to_string());
+    }
+
+    Ok(())
+}
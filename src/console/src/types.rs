@@ -1,18 +1,19 @@
 pub mod state {
     use crate::memory::init_stable_state;
     use crate::storage::types::state::{AssetsStable, ContentChunksStable};
+    use crate::types::interface::SegmentType;
     use crate::types::ledger::Payment;
     use candid::{CandidType, Principal};
     use ic_ledger_types::{BlockIndex, Tokens};
     use ic_stable_structures::StableBTreeMap;
     use junobuild_shared::rate::types::{RateConfig, RateTokens};
-    use junobuild_shared::types::core::Hash;
+    use junobuild_shared::types::core::{Blob, Hash};
     use junobuild_shared::types::memory::Memory;
-    use junobuild_shared::types::state::{Controllers, Timestamp, Version};
+    use junobuild_shared::types::state::{Controllers, Metadata, Timestamp, Version};
     use junobuild_shared::types::state::{MissionControlId, UserId};
-    use junobuild_storage::types::state::StorageHeapState;
+    use junobuild_storage::types::state::{FullPath, StorageHeapState};
     use serde::{Deserialize, Serialize};
-    use std::collections::{HashMap, HashSet};
+    use std::collections::{BTreeMap, HashMap, HashSet};
 
     pub type MissionControls = HashMap<UserId, MissionControl>;
     pub type Payments = HashMap<BlockIndex, Payment>;
@@ -21,6 +22,7 @@ pub mod state {
     pub type MissionControlsStable = StableBTreeMap<UserId, MissionControl, Memory>;
     pub type PaymentsStable = StableBTreeMap<BlockIndex, Payment, Memory>;
     pub type ProposalsStable = StableBTreeMap<ProposalKey, Proposal, Memory>;
+    pub type CreationStatsStable = StableBTreeMap<CreationStatsKey, CreationStats, Memory>;
 
     #[derive(Serialize, Deserialize)]
     pub struct State {
@@ -37,6 +39,7 @@ pub mod state {
         pub proposals_assets: AssetsStable,
         pub proposals_content_chunks: ContentChunksStable,
         pub proposals: ProposalsStable,
+        pub creation_stats: CreationStatsStable,
     }
 
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
@@ -51,6 +54,8 @@ pub mod state {
         pub fees: Fees,
         pub storage: StorageHeapState,
         pub releases_metadata: ReleasesMetadata,
+        pub templates: Option<Templates>,
+        pub project_templates: Option<ProjectTemplates>,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -71,6 +76,44 @@ pub mod state {
         pub orbiters: HashSet<ReleaseVersion>,
     }
 
+    pub type TemplateId = String;
+
+    pub type Templates = HashMap<TemplateId, Template>;
+
+    /// A serverless function template - i.e. a satellite WASM extended with functions - that can be installed into a satellite through its mission control.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct Template {
+        pub description: Option<String>,
+        pub versions: BTreeMap<ReleaseVersion, TemplateVersion>,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+    }
+
+    /// Published versions are immutable, the checksum is the one of the WASM uploaded to the releases storage.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct TemplateVersion {
+        pub full_path: FullPath,
+        pub sha256: Hash,
+        pub size: u64,
+        pub metadata: Metadata,
+        pub created_at: Timestamp,
+    }
+
+    pub type ProjectTemplateId = String;
+
+    pub type ProjectTemplates = HashMap<ProjectTemplateId, ProjectTemplate>;
+
+    /// A project template - i.e. the configuration of the collections, their rules and the storage - applied to the
+    /// satellites created with it, so that teams standardize their new environments. The bundle is the Candid encoding
+    /// of the `ConfigBundle` of a satellite, for example exported with `get_config_bundle` from a reference environment.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ProjectTemplate {
+        pub description: Option<String>,
+        pub bundle: Blob,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+    }
+
     pub type InvitationCode = String;
 
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
@@ -143,6 +186,19 @@ pub mod state {
         pub orbiter: Option<ReleaseVersion>,
     }
 
+    /// The creations of a type of segment are accounted per day - i.e. `day` is the timestamp of the start of the day.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct CreationStatsKey {
+        pub day: Timestamp,
+        pub segment: SegmentType,
+    }
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct CreationStats {
+        pub succeeded: u64,
+        pub failed: u64,
+    }
+
     #[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq)]
     pub enum ProposalStatus {
         Initialized,
@@ -155,14 +211,16 @@ pub mod state {
 }
 
 pub mod interface {
-    use crate::types::state::ProposalId;
+    use crate::types::state::{ProposalId, ReleaseVersion};
     use candid::CandidType;
-    use junobuild_shared::types::core::Hash;
+    use junobuild_shared::types::core::{Blob, Hash};
     use junobuild_shared::types::cronjob::CronJobs;
+    use junobuild_shared::types::interface::MemorySize;
+    use junobuild_shared::types::state::{Metadata, Timestamp};
     use junobuild_storage::types::config::StorageConfig;
     use serde::{Deserialize, Serialize};
 
-    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
     pub enum SegmentType {
         Satellite,
         MissionControl,
@@ -179,6 +237,46 @@ pub mod interface {
         pub storage: StorageConfig,
     }
 
+    #[derive(CandidType, Deserialize)]
+    pub struct SetTemplateVersion {
+        pub version: ReleaseVersion,
+        pub description: Option<String>,
+        pub metadata: Metadata,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct SetProjectTemplate {
+        pub description: Option<String>,
+        pub bundle: Blob,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct GetReleaseWasmChunk {
+        pub segment_type: SegmentType,
+        pub version: ReleaseVersion,
+        pub chunk_index: u64,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize)]
+    pub struct ReleaseWasmChunk {
+        pub content: Blob,
+        pub chunks_length: u64,
+        pub sha256: Hash,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct GetTemplateWasmChunk {
+        pub version: ReleaseVersion,
+        pub chunk_index: u64,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize)]
+    pub struct TemplateWasmChunk {
+        pub content: Blob,
+        pub chunks_length: u64,
+        pub sha256: Hash,
+    }
+
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct CommitProposal {
         pub proposal_id: ProposalId,
@@ -189,6 +287,36 @@ pub mod interface {
     pub struct DeleteProposalAssets {
         pub proposal_ids: Vec<ProposalId>,
     }
+
+    #[derive(CandidType, Serialize, Deserialize)]
+    pub struct PlatformHealth {
+        pub console: ConsoleHealth,
+        pub observatory: ObservatoryHealth,
+        pub releases: ReleasesHealth,
+        pub timestamp: Timestamp,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize)]
+    pub struct ConsoleHealth {
+        pub version: String,
+        pub memory_size: MemorySize,
+        pub cycles: u128,
+    }
+
+    /// The observatory is considered reachable when it answers to its `version` endpoint.
+    #[derive(CandidType, Serialize, Deserialize)]
+    pub struct ObservatoryHealth {
+        pub version: Option<String>,
+        pub error: Option<String>,
+    }
+
+    /// The latest versions of the segments released to the console.
+    #[derive(CandidType, Serialize, Deserialize)]
+    pub struct ReleasesHealth {
+        pub satellite: Option<ReleaseVersion>,
+        pub mission_control: Option<ReleaseVersion>,
+        pub orbiter: Option<ReleaseVersion>,
+    }
 }
 
 pub mod ledger {
@@ -1,14 +1,21 @@
+use crate::msg::ERROR_RELEASE_VERSION_NOT_FOUND;
 use crate::storage::state::heap::get_asset;
 use crate::store::heap::{
     get_latest_mission_control_version, get_latest_orbiter_version, get_latest_satellite_version,
+    get_releases_metadata,
 };
+use crate::types::interface::{GetReleaseWasmChunk, ReleaseWasmChunk, SegmentType};
+use crate::types::state::ReleaseVersion;
 use candid::{Encode, Principal};
 use junobuild_shared::mgmt::types::ic::WasmArg;
 use junobuild_shared::types::core::Blob;
-use junobuild_shared::types::interface::{MissionControlArgs, SegmentArgs};
+use junobuild_shared::types::interface::{
+    MissionControlArgs, SatelliteArgs, SatelliteInitConfig, SegmentArgs,
+};
 use junobuild_shared::types::state::{MissionControlId, UserId};
 use junobuild_storage::constants::ASSET_ENCODING_NO_COMPRESSION;
 use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::store::AssetEncoding;
 
 fn get_chunks(full_path: &FullPath) -> Result<Blob, String> {
     let asset = match get_asset(full_path) {
@@ -45,13 +52,15 @@ pub fn mission_control_wasm_arg(user: &UserId) -> Result<WasmArg, String> {
 pub fn satellite_wasm_arg(
     user: &UserId,
     mission_control_id: &MissionControlId,
+    init_config: Option<SatelliteInitConfig>,
 ) -> Result<WasmArg, String> {
     let latest_version =
         get_latest_satellite_version().ok_or("No satellite versions available.")?;
     let full_path = format!("/releases/satellite-v{}.wasm.gz", latest_version);
     let wasm: Blob = get_chunks(&full_path)?;
-    let install_arg: Vec<u8> = Encode!(&SegmentArgs {
-        controllers: user_mission_control_controllers(user, mission_control_id)
+    let install_arg: Vec<u8> = Encode!(&SatelliteArgs {
+        controllers: user_mission_control_controllers(user, mission_control_id),
+        init_config
     })
     .unwrap();
     Ok(WasmArg { wasm, install_arg })
@@ -71,6 +80,63 @@ pub fn orbiter_wasm_arg(
     Ok(WasmArg { wasm, install_arg })
 }
 
+/// Serves a published release in chunks together with the checksum of its WASM, so that mission controls can attest
+/// the binary they install when upgrading their modules.
+pub fn get_release_wasm_chunk(
+    GetReleaseWasmChunk {
+        segment_type,
+        version,
+        chunk_index,
+    }: &GetReleaseWasmChunk,
+) -> Result<ReleaseWasmChunk, String> {
+    let metadata = get_releases_metadata();
+
+    let versions = match segment_type {
+        SegmentType::Satellite => &metadata.satellites,
+        SegmentType::MissionControl => &metadata.mission_controls,
+        SegmentType::Orbiter => &metadata.orbiters,
+    };
+
+    if !versions.contains(version) {
+        return Err(ERROR_RELEASE_VERSION_NOT_FOUND.to_string());
+    }
+
+    let full_path = release_full_path(segment_type, version);
+
+    let encoding = get_identity_encoding(&full_path)?;
+
+    let content = encoding
+        .content_chunks
+        .get(*chunk_index as usize)
+        .ok_or(format!("No chunk {} for {}.", chunk_index, full_path))?;
+
+    Ok(ReleaseWasmChunk {
+        content: content.clone(),
+        chunks_length: encoding.content_chunks.len() as u64,
+        sha256: encoding.sha256,
+    })
+}
+
+pub fn get_identity_encoding(full_path: &FullPath) -> Result<AssetEncoding, String> {
+    let asset = get_asset(full_path).ok_or(format!("No asset found for {}", full_path))?;
+
+    asset
+        .encodings
+        .get(ASSET_ENCODING_NO_COMPRESSION)
+        .cloned()
+        .ok_or(format!("No identity encoding found for {}", full_path))
+}
+
+fn release_full_path(segment_type: &SegmentType, version: &ReleaseVersion) -> FullPath {
+    let segment = match segment_type {
+        SegmentType::Satellite => "satellite",
+        SegmentType::MissionControl => "mission_control",
+        SegmentType::Orbiter => "orbiter",
+    };
+
+    format!("/releases/{}-v{}.wasm.gz", segment, version)
+}
+
 pub fn user_mission_control_controllers(
     user: &UserId,
     mission_control_id: &MissionControlId,
@@ -17,6 +17,9 @@ export interface AssetKey {
 	owner: Principal;
 	name: string;
 	description: [] | [string];
+	publish_at: [] | [bigint];
+	moderation: [] | [ModerationStatus];
+	unpublish_at: [] | [bigint];
 	full_path: string;
 }
 export interface AssetNoContent {
@@ -30,6 +33,10 @@ export interface AssetNoContent {
 export interface AssetsUpgradeOptions {
 	clear_existing_assets: [] | [boolean];
 }
+export interface CollectionVersion {
+	last_modified: bigint;
+	changes: bigint;
+}
 export interface CommitBatch {
 	batch_id: bigint;
 	headers: Array<[string, string]>;
@@ -46,11 +53,26 @@ export interface ConfigMaxMemorySize {
 	stable: [] | [bigint];
 	heap: [] | [bigint];
 }
+export interface ConsoleHealth {
+	memory_size: MemorySize;
+	version: string;
+	cycles: bigint;
+}
 export type ControllerScope = { Write: null } | { Admin: null };
 export interface CreateCanisterArgs {
+	init_config: [] | [SatelliteInitConfig];
 	block_index: [] | [bigint];
 	subnet_id: [] | [Principal];
 	user: Principal;
+	project_template: [] | [string];
+}
+export interface CreationStats {
+	failed: bigint;
+	succeeded: bigint;
+}
+export interface CreationStatsKey {
+	day: bigint;
+	segment: SegmentType;
 }
 export interface CustomDomain {
 	updated_at: bigint;
@@ -67,6 +89,15 @@ export interface DeleteProposalAssets {
 export interface GetCreateCanisterFeeArgs {
 	user: Principal;
 }
+export interface GetReleaseWasmChunk {
+	chunk_index: bigint;
+	segment_type: SegmentType;
+	version: string;
+}
+export interface GetTemplateWasmChunk {
+	chunk_index: bigint;
+	version: string;
+}
 export interface HttpRequest {
 	url: string;
 	method: string;
@@ -77,6 +108,7 @@ export interface HttpRequest {
 export interface HttpResponse {
 	body: Uint8Array | number[];
 	headers: Array<[string, string]>;
+	upgrade: [] | [boolean];
 	streaming_strategy: [] | [StreamingStrategy];
 	status_code: number;
 }
@@ -85,17 +117,33 @@ export interface InitAssetKey {
 	collection: string;
 	name: string;
 	description: [] | [string];
+	publish_at: [] | [bigint];
+	unpublish_at: [] | [bigint];
 	encoding_type: [] | [string];
 	full_path: string;
 }
 export interface InitUploadResult {
 	batch_id: bigint;
 }
+export type ListFilter =
+	| { Or: Array<ListFilter> }
+	| { And: Array<ListFilter> }
+	| { Key: TextMatcher }
+	| { Not: ListFilter }
+	| { UpdatedAt: TimestampMatcher }
+	| { Description: TextMatcher }
+	| { Owner: PrincipalMatcher }
+	| { CreatedAt: TimestampMatcher };
+export interface ListLimits {
+	max_scanned_items: [] | [bigint];
+	max_returned_bytes: [] | [bigint];
+}
 export interface ListMatcher {
 	key: [] | [string];
 	updated_at: [] | [TimestampMatcher];
 	description: [] | [string];
 	created_at: [] | [TimestampMatcher];
+	filter: [] | [ListFilter];
 }
 export interface ListOrder {
 	field: ListOrderField;
@@ -115,11 +163,19 @@ export interface ListParams {
 export interface ListResults {
 	matches_pages: [] | [bigint];
 	matches_length: bigint;
+	truncated: [] | [ListTruncated];
+	version: [] | [CollectionVersion];
 	items_page: [] | [bigint];
 	items: Array<[string, AssetNoContent]>;
+	resume_after: [] | [string];
 	items_length: bigint;
 }
+export type ListTruncated = { MaxScannedItems: null } | { MaxReturnedBytes: null };
 export type Memory = { Heap: null } | { Stable: null };
+export interface MemorySize {
+	stable: bigint;
+	heap: bigint;
+}
 export interface MissionControl {
 	updated_at: bigint;
 	credits: Tokens;
@@ -127,6 +183,16 @@ export interface MissionControl {
 	owner: Principal;
 	created_at: bigint;
 }
+export type ModerationStatus = { Approved: null } | { Rejected: null } | { Pending: null };
+export interface ObservatoryHealth {
+	error: [] | [string];
+	version: [] | [string];
+}
+export interface Page {
+	next_cursor: [] | [string];
+	items: Array<[string, AssetNoContent]>;
+	total_estimate: bigint;
+}
 export interface Payment {
 	status: PaymentStatus;
 	updated_at: bigint;
@@ -136,6 +202,19 @@ export interface Payment {
 	block_index_refunded: [] | [bigint];
 }
 export type PaymentStatus = { Refunded: null } | { Acknowledged: null } | { Completed: null };
+export interface PlatformHealth {
+	console: ConsoleHealth;
+	releases: ReleasesHealth;
+	timestamp: bigint;
+	observatory: ObservatoryHealth;
+}
+export type PrincipalMatcher = { In: Array<Principal> } | { Equal: Principal };
+export interface ProjectTemplate {
+	updated_at: bigint;
+	description: [] | [string];
+	created_at: bigint;
+	bundle: Uint8Array | number[];
+}
 export interface Proposal {
 	status: ProposalStatus;
 	updated_at: bigint;
@@ -160,6 +239,20 @@ export interface RateConfig {
 	max_tokens: bigint;
 	time_per_token_ns: bigint;
 }
+export interface ReleaseWasmChunk {
+	content: Uint8Array | number[];
+	sha256: Uint8Array | number[];
+	chunks_length: bigint;
+}
+export interface ReleasesHealth {
+	satellite: [] | [string];
+	orbiter: [] | [string];
+	mission_control: [] | [string];
+}
+export interface SatelliteInitConfig {
+	controllers: Array<[Principal, SetController]>;
+	bundle: [] | [Uint8Array | number[]];
+}
 export type SegmentType = { Orbiter: null } | { MissionControl: null } | { Satellite: null };
 export interface SegmentsDeploymentOptions {
 	orbiter: [] | [string];
@@ -175,20 +268,55 @@ export interface SetControllersArgs {
 	controller: SetController;
 	controllers: Array<Principal>;
 }
+export interface SetProjectTemplate {
+	description: [] | [string];
+	bundle: Uint8Array | number[];
+}
+export interface SetTemplateVersion {
+	metadata: Array<[string, string]>;
+	description: [] | [string];
+	version: string;
+}
 export interface StorageConfig {
 	iframe: [] | [StorageConfigIFrame];
+	csp_nonce: [] | [boolean];
+	locales: [] | [StorageConfigLocales];
+	wildcard_domains: [] | [Array<[string, string]>];
+	list_limits: [] | [ListLimits];
+	delegations: [] | [Array<[string, StorageConfigDelegation]>];
 	rewrites: Array<[string, string]>;
 	headers: Array<[string, Array<[string, string]>]>;
 	max_memory_size: [] | [ConfigMaxMemorySize];
+	max_response_body_size: [] | [bigint];
 	raw_access: [] | [StorageConfigRawAccess];
+	weighted_rewrites: [] | [Array<[string, StorageConfigWeightedRewrite]>];
 	redirects: [] | [Array<[string, StorageConfigRedirect]>];
 }
+export interface StorageConfigDelegation {
+	raw: [] | [boolean];
+	domain: [] | [string];
+	canister_id: Principal;
+}
 export type StorageConfigIFrame = { Deny: null } | { AllowAny: null } | { SameOrigin: null };
+export interface StorageConfigLocales {
+	default_locale: string;
+	cookie: [] | [string];
+	supported_locales: Array<string>;
+}
 export type StorageConfigRawAccess = { Deny: null } | { Allow: null };
 export interface StorageConfigRedirect {
 	status_code: number;
 	location: string;
 }
+export type StorageConfigRewriteSession = { Header: string } | { Cookie: string };
+export interface StorageConfigWeightedDestination {
+	weight: number;
+	destination: string;
+}
+export interface StorageConfigWeightedRewrite {
+	session: StorageConfigRewriteSession;
+	destinations: Array<StorageConfigWeightedDestination>;
+}
 export interface StreamingCallbackHttpResponse {
 	token: [] | [StreamingCallbackToken];
 	body: Uint8Array | number[];
@@ -197,6 +325,7 @@ export interface StreamingCallbackToken {
 	memory: Memory;
 	token: [] | [string];
 	sha256: [] | [Uint8Array | number[]];
+	offset: [] | [bigint];
 	headers: Array<[string, string]>;
 	index: bigint;
 	encoding_type: string;
@@ -208,6 +337,30 @@ export type StreamingStrategy = {
 		callback: [Principal, string];
 	};
 };
+export interface Template {
+	updated_at: bigint;
+	description: [] | [string];
+	created_at: bigint;
+	versions: Array<[string, TemplateVersion]>;
+}
+export interface TemplateVersion {
+	sha256: Uint8Array | number[];
+	metadata: Array<[string, string]>;
+	size: bigint;
+	created_at: bigint;
+	full_path: string;
+}
+export interface TemplateWasmChunk {
+	content: Uint8Array | number[];
+	sha256: Uint8Array | number[];
+	chunks_length: bigint;
+}
+export type TextMatcher =
+	| { In: Array<string> }
+	| { Equal: string }
+	| { GreaterThan: string }
+	| { LessThan: string }
+	| { Prefix: string };
 export type TimestampMatcher =
 	| { Equal: bigint }
 	| { Between: [bigint, bigint] }
@@ -234,13 +387,17 @@ export interface _SERVICE {
 	create_satellite: ActorMethod<[CreateCanisterArgs], Principal>;
 	del_controllers: ActorMethod<[DeleteControllersArgs], undefined>;
 	del_custom_domain: ActorMethod<[string], undefined>;
+	del_project_template: ActorMethod<[string], undefined>;
 	delete_proposal_assets: ActorMethod<[DeleteProposalAssets], undefined>;
 	get_config: ActorMethod<[], Config>;
 	get_create_orbiter_fee: ActorMethod<[GetCreateCanisterFeeArgs], [] | [Tokens]>;
 	get_create_satellite_fee: ActorMethod<[GetCreateCanisterFeeArgs], [] | [Tokens]>;
 	get_credits: ActorMethod<[], Tokens>;
+	get_platform_health: ActorMethod<[], PlatformHealth>;
 	get_proposal: ActorMethod<[bigint], [] | [Proposal]>;
+	get_release_wasm_chunk: ActorMethod<[GetReleaseWasmChunk], ReleaseWasmChunk>;
 	get_storage_config: ActorMethod<[], StorageConfig>;
+	get_template_wasm_chunk: ActorMethod<[string, GetTemplateWasmChunk], TemplateWasmChunk>;
 	get_user_mission_control_center: ActorMethod<[], [] | [MissionControl]>;
 	http_request: ActorMethod<[HttpRequest], HttpResponse>;
 	http_request_streaming_callback: ActorMethod<
@@ -251,13 +408,19 @@ export interface _SERVICE {
 	init_proposal: ActorMethod<[ProposalType], [bigint, Proposal]>;
 	init_user_mission_control_center: ActorMethod<[], MissionControl>;
 	list_assets: ActorMethod<[string, ListParams], ListResults>;
+	list_assets_page: ActorMethod<[string, ListParams], Page>;
+	list_creation_stats: ActorMethod<[], Array<[CreationStatsKey, CreationStats]>>;
 	list_custom_domains: ActorMethod<[], Array<[string, CustomDomain]>>;
 	list_payments: ActorMethod<[], Array<[bigint, Payment]>>;
+	list_project_templates: ActorMethod<[], Array<[string, ProjectTemplate]>>;
+	list_templates: ActorMethod<[], Array<[string, Template]>>;
 	list_user_mission_control_centers: ActorMethod<[], Array<[Principal, MissionControl]>>;
 	set_controllers: ActorMethod<[SetControllersArgs], undefined>;
 	set_custom_domain: ActorMethod<[string, [] | [string]], undefined>;
 	set_fee: ActorMethod<[SegmentType, Tokens], undefined>;
 	set_storage_config: ActorMethod<[StorageConfig], undefined>;
+	set_project_template: ActorMethod<[string, SetProjectTemplate], ProjectTemplate>;
+	set_template_version: ActorMethod<[string, SetTemplateVersion], Template>;
 	submit_proposal: ActorMethod<[bigint], [bigint, Proposal]>;
 	update_rate_config: ActorMethod<[SegmentType, RateConfig], undefined>;
 	upload_asset_chunk: ActorMethod<[UploadChunk], UploadChunkResult>;
@@ -1,5 +1,6 @@
 // @ts-ignore
 export const idlFactory = ({ IDL }) => {
+	const ListFilter = IDL.Rec();
 	const Tokens = IDL.Record({ e8s: IDL.Nat64 });
 	const AssertMissionControlCenterArgs = IDL.Record({
 		mission_control_id: IDL.Principal,
@@ -14,10 +15,25 @@ export const idlFactory = ({ IDL }) => {
 		sha256: IDL.Vec(IDL.Nat8),
 		proposal_id: IDL.Nat
 	});
+	const ControllerScope = IDL.Variant({
+		Write: IDL.Null,
+		Admin: IDL.Null
+	});
+	const SetController = IDL.Record({
+		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		scope: ControllerScope,
+		expires_at: IDL.Opt(IDL.Nat64)
+	});
+	const SatelliteInitConfig = IDL.Record({
+		controllers: IDL.Vec(IDL.Tuple(IDL.Principal, SetController)),
+		bundle: IDL.Opt(IDL.Vec(IDL.Nat8))
+	});
 	const CreateCanisterArgs = IDL.Record({
+		init_config: IDL.Opt(SatelliteInitConfig),
 		block_index: IDL.Opt(IDL.Nat64),
 		subnet_id: IDL.Opt(IDL.Principal),
-		user: IDL.Principal
+		user: IDL.Principal,
+		project_template: IDL.Opt(IDL.Text)
 	});
 	const DeleteControllersArgs = IDL.Record({
 		controllers: IDL.Vec(IDL.Principal)
@@ -34,20 +50,53 @@ export const idlFactory = ({ IDL }) => {
 		stable: IDL.Opt(IDL.Nat64),
 		heap: IDL.Opt(IDL.Nat64)
 	});
+	const StorageConfigLocales = IDL.Record({
+		default_locale: IDL.Text,
+		cookie: IDL.Opt(IDL.Text),
+		supported_locales: IDL.Vec(IDL.Text)
+	});
 	const StorageConfigRawAccess = IDL.Variant({
 		Deny: IDL.Null,
 		Allow: IDL.Null
 	});
+	const StorageConfigRewriteSession = IDL.Variant({
+		Header: IDL.Text,
+		Cookie: IDL.Text
+	});
+	const StorageConfigWeightedDestination = IDL.Record({
+		weight: IDL.Nat32,
+		destination: IDL.Text
+	});
+	const StorageConfigWeightedRewrite = IDL.Record({
+		session: StorageConfigRewriteSession,
+		destinations: IDL.Vec(StorageConfigWeightedDestination)
+	});
 	const StorageConfigRedirect = IDL.Record({
 		status_code: IDL.Nat16,
 		location: IDL.Text
 	});
+	const ListLimits = IDL.Record({
+		max_scanned_items: IDL.Opt(IDL.Nat64),
+		max_returned_bytes: IDL.Opt(IDL.Nat64)
+	});
+	const StorageConfigDelegation = IDL.Record({
+		raw: IDL.Opt(IDL.Bool),
+		domain: IDL.Opt(IDL.Text),
+		canister_id: IDL.Principal
+	});
 	const StorageConfig = IDL.Record({
 		iframe: IDL.Opt(StorageConfigIFrame),
+		csp_nonce: IDL.Opt(IDL.Bool),
+		locales: IDL.Opt(StorageConfigLocales),
+		wildcard_domains: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text))),
+		list_limits: IDL.Opt(ListLimits),
+		delegations: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigDelegation))),
 		rewrites: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)))),
 		max_memory_size: IDL.Opt(ConfigMaxMemorySize),
+		max_response_body_size: IDL.Opt(IDL.Nat64),
 		raw_access: IDL.Opt(StorageConfigRawAccess),
+		weighted_rewrites: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigWeightedRewrite))),
 		redirects: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigRedirect)))
 	});
 	const Config = IDL.Record({ storage: StorageConfig });
@@ -82,6 +131,30 @@ export const idlFactory = ({ IDL }) => {
 		version: IDL.Opt(IDL.Nat64),
 		proposal_type: ProposalType
 	});
+	const SegmentType = IDL.Variant({
+		Orbiter: IDL.Null,
+		MissionControl: IDL.Null,
+		Satellite: IDL.Null
+	});
+	const GetReleaseWasmChunk = IDL.Record({
+		chunk_index: IDL.Nat64,
+		segment_type: SegmentType,
+		version: IDL.Text
+	});
+	const ReleaseWasmChunk = IDL.Record({
+		content: IDL.Vec(IDL.Nat8),
+		sha256: IDL.Vec(IDL.Nat8),
+		chunks_length: IDL.Nat64
+	});
+	const GetTemplateWasmChunk = IDL.Record({
+		chunk_index: IDL.Nat64,
+		version: IDL.Text
+	});
+	const TemplateWasmChunk = IDL.Record({
+		content: IDL.Vec(IDL.Nat8),
+		sha256: IDL.Vec(IDL.Nat8),
+		chunks_length: IDL.Nat64
+	});
 	const MissionControl = IDL.Record({
 		updated_at: IDL.Nat64,
 		credits: Tokens,
@@ -101,6 +174,7 @@ export const idlFactory = ({ IDL }) => {
 		memory: Memory,
 		token: IDL.Opt(IDL.Text),
 		sha256: IDL.Opt(IDL.Vec(IDL.Nat8)),
+		offset: IDL.Opt(IDL.Nat64),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		index: IDL.Nat64,
 		encoding_type: IDL.Text,
@@ -115,6 +189,7 @@ export const idlFactory = ({ IDL }) => {
 	const HttpResponse = IDL.Record({
 		body: IDL.Vec(IDL.Nat8),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		upgrade: IDL.Opt(IDL.Bool),
 		streaming_strategy: IDL.Opt(StreamingStrategy),
 		status_code: IDL.Nat16
 	});
@@ -127,6 +202,8 @@ export const idlFactory = ({ IDL }) => {
 		collection: IDL.Text,
 		name: IDL.Text,
 		description: IDL.Opt(IDL.Text),
+		publish_at: IDL.Opt(IDL.Nat64),
+		unpublish_at: IDL.Opt(IDL.Nat64),
 		encoding_type: IDL.Opt(IDL.Text),
 		full_path: IDL.Text
 	});
@@ -143,11 +220,35 @@ export const idlFactory = ({ IDL }) => {
 		GreaterThan: IDL.Nat64,
 		LessThan: IDL.Nat64
 	});
+	const TextMatcher = IDL.Variant({
+		In: IDL.Vec(IDL.Text),
+		Equal: IDL.Text,
+		GreaterThan: IDL.Text,
+		LessThan: IDL.Text,
+		Prefix: IDL.Text
+	});
+	const PrincipalMatcher = IDL.Variant({
+		In: IDL.Vec(IDL.Principal),
+		Equal: IDL.Principal
+	});
+	ListFilter.fill(
+		IDL.Variant({
+			Or: IDL.Vec(ListFilter),
+			And: IDL.Vec(ListFilter),
+			Key: TextMatcher,
+			Not: ListFilter,
+			UpdatedAt: TimestampMatcher,
+			Description: TextMatcher,
+			Owner: PrincipalMatcher,
+			CreatedAt: TimestampMatcher
+		})
+	);
 	const ListMatcher = IDL.Record({
 		key: IDL.Opt(IDL.Text),
 		updated_at: IDL.Opt(TimestampMatcher),
 		description: IDL.Opt(IDL.Text),
-		created_at: IDL.Opt(TimestampMatcher)
+		created_at: IDL.Opt(TimestampMatcher),
+		filter: IDL.Opt(ListFilter)
 	});
 	const ListPaginate = IDL.Record({
 		start_after: IDL.Opt(IDL.Text),
@@ -159,12 +260,20 @@ export const idlFactory = ({ IDL }) => {
 		matcher: IDL.Opt(ListMatcher),
 		paginate: IDL.Opt(ListPaginate)
 	});
+	const ModerationStatus = IDL.Variant({
+		Approved: IDL.Null,
+		Rejected: IDL.Null,
+		Pending: IDL.Null
+	});
 	const AssetKey = IDL.Record({
 		token: IDL.Opt(IDL.Text),
 		collection: IDL.Text,
 		owner: IDL.Principal,
 		name: IDL.Text,
 		description: IDL.Opt(IDL.Text),
+		publish_at: IDL.Opt(IDL.Nat64),
+		moderation: IDL.Opt(ModerationStatus),
+		unpublish_at: IDL.Opt(IDL.Nat64),
 		full_path: IDL.Text
 	});
 	const AssetEncodingNoContent = IDL.Record({
@@ -180,13 +289,29 @@ export const idlFactory = ({ IDL }) => {
 		created_at: IDL.Nat64,
 		version: IDL.Opt(IDL.Nat64)
 	});
+	const ListTruncated = IDL.Variant({
+		MaxScannedItems: IDL.Null,
+		MaxReturnedBytes: IDL.Null
+	});
+	const CollectionVersion = IDL.Record({
+		last_modified: IDL.Nat64,
+		changes: IDL.Nat64
+	});
 	const ListResults = IDL.Record({
 		matches_pages: IDL.Opt(IDL.Nat64),
 		matches_length: IDL.Nat64,
+		truncated: IDL.Opt(ListTruncated),
+		version: IDL.Opt(CollectionVersion),
 		items_page: IDL.Opt(IDL.Nat64),
 		items: IDL.Vec(IDL.Tuple(IDL.Text, AssetNoContent)),
+		resume_after: IDL.Opt(IDL.Text),
 		items_length: IDL.Nat64
 	});
+	const Page = IDL.Record({
+		next_cursor: IDL.Opt(IDL.Text),
+		items: IDL.Vec(IDL.Tuple(IDL.Text, AssetNoContent)),
+		total_estimate: IDL.Nat64
+	});
 	const CustomDomain = IDL.Record({
 		updated_at: IDL.Nat64,
 		created_at: IDL.Nat64,
@@ -206,23 +331,37 @@ export const idlFactory = ({ IDL }) => {
 		created_at: IDL.Nat64,
 		block_index_refunded: IDL.Opt(IDL.Nat64)
 	});
-	const ControllerScope = IDL.Variant({
-		Write: IDL.Null,
-		Admin: IDL.Null
-	});
-	const SetController = IDL.Record({
+	const TemplateVersion = IDL.Record({
+		sha256: IDL.Vec(IDL.Nat8),
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
-		scope: ControllerScope,
-		expires_at: IDL.Opt(IDL.Nat64)
+		size: IDL.Nat64,
+		created_at: IDL.Nat64,
+		full_path: IDL.Text
+	});
+	const ProjectTemplate = IDL.Record({
+		updated_at: IDL.Nat64,
+		description: IDL.Opt(IDL.Text),
+		created_at: IDL.Nat64,
+		bundle: IDL.Vec(IDL.Nat8)
+	});
+	const Template = IDL.Record({
+		updated_at: IDL.Nat64,
+		description: IDL.Opt(IDL.Text),
+		created_at: IDL.Nat64,
+		versions: IDL.Vec(IDL.Tuple(IDL.Text, TemplateVersion))
 	});
 	const SetControllersArgs = IDL.Record({
 		controller: SetController,
 		controllers: IDL.Vec(IDL.Principal)
 	});
-	const SegmentType = IDL.Variant({
-		Orbiter: IDL.Null,
-		MissionControl: IDL.Null,
-		Satellite: IDL.Null
+	const SetProjectTemplate = IDL.Record({
+		description: IDL.Opt(IDL.Text),
+		bundle: IDL.Vec(IDL.Nat8)
+	});
+	const SetTemplateVersion = IDL.Record({
+		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		description: IDL.Opt(IDL.Text),
+		version: IDL.Text
 	});
 	const RateConfig = IDL.Record({
 		max_tokens: IDL.Nat64,
@@ -234,6 +373,35 @@ export const idlFactory = ({ IDL }) => {
 		order_id: IDL.Opt(IDL.Nat)
 	});
 	const UploadChunkResult = IDL.Record({ chunk_id: IDL.Nat });
+	const MemorySize = IDL.Record({ stable: IDL.Nat64, heap: IDL.Nat64 });
+	const ConsoleHealth = IDL.Record({
+		memory_size: MemorySize,
+		version: IDL.Text,
+		cycles: IDL.Nat
+	});
+	const ReleasesHealth = IDL.Record({
+		satellite: IDL.Opt(IDL.Text),
+		orbiter: IDL.Opt(IDL.Text),
+		mission_control: IDL.Opt(IDL.Text)
+	});
+	const ObservatoryHealth = IDL.Record({
+		error: IDL.Opt(IDL.Text),
+		version: IDL.Opt(IDL.Text)
+	});
+	const PlatformHealth = IDL.Record({
+		console: ConsoleHealth,
+		releases: ReleasesHealth,
+		timestamp: IDL.Nat64,
+		observatory: ObservatoryHealth
+	});
+	const CreationStatsKey = IDL.Record({
+		day: IDL.Nat64,
+		segment: SegmentType
+	});
+	const CreationStats = IDL.Record({
+		failed: IDL.Nat64,
+		succeeded: IDL.Nat64
+	});
 	return IDL.Service({
 		add_credits: IDL.Func([IDL.Principal, Tokens], [], []),
 		add_invitation_code: IDL.Func([IDL.Text], [], []),
@@ -244,13 +412,21 @@ export const idlFactory = ({ IDL }) => {
 		create_satellite: IDL.Func([CreateCanisterArgs], [IDL.Principal], []),
 		del_controllers: IDL.Func([DeleteControllersArgs], [], []),
 		del_custom_domain: IDL.Func([IDL.Text], [], []),
+		del_project_template: IDL.Func([IDL.Text], [], []),
 		delete_proposal_assets: IDL.Func([DeleteProposalAssets], [], []),
 		get_config: IDL.Func([], [Config], []),
 		get_create_orbiter_fee: IDL.Func([GetCreateCanisterFeeArgs], [IDL.Opt(Tokens)], ['query']),
 		get_create_satellite_fee: IDL.Func([GetCreateCanisterFeeArgs], [IDL.Opt(Tokens)], ['query']),
 		get_credits: IDL.Func([], [Tokens], ['query']),
+		get_platform_health: IDL.Func([], [PlatformHealth], []),
 		get_proposal: IDL.Func([IDL.Nat], [IDL.Opt(Proposal)], ['query']),
+		get_release_wasm_chunk: IDL.Func([GetReleaseWasmChunk], [ReleaseWasmChunk], ['query']),
 		get_storage_config: IDL.Func([], [StorageConfig], ['query']),
+		get_template_wasm_chunk: IDL.Func(
+			[IDL.Text, GetTemplateWasmChunk],
+			[TemplateWasmChunk],
+			['query']
+		),
 		get_user_mission_control_center: IDL.Func([], [IDL.Opt(MissionControl)], ['query']),
 		http_request: IDL.Func([HttpRequest], [HttpResponse], ['query']),
 		http_request_streaming_callback: IDL.Func(
@@ -262,8 +438,20 @@ export const idlFactory = ({ IDL }) => {
 		init_proposal: IDL.Func([ProposalType], [IDL.Nat, Proposal], []),
 		init_user_mission_control_center: IDL.Func([], [MissionControl], []),
 		list_assets: IDL.Func([IDL.Text, ListParams], [ListResults], ['query']),
+		list_assets_page: IDL.Func([IDL.Text, ListParams], [Page], ['query']),
+		list_creation_stats: IDL.Func(
+			[],
+			[IDL.Vec(IDL.Tuple(CreationStatsKey, CreationStats))],
+			['query']
+		),
 		list_custom_domains: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Text, CustomDomain))], ['query']),
 		list_payments: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Nat64, Payment))], ['query']),
+		list_project_templates: IDL.Func(
+			[],
+			[IDL.Vec(IDL.Tuple(IDL.Text, ProjectTemplate))],
+			['query']
+		),
+		list_templates: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Text, Template))], ['query']),
 		list_user_mission_control_centers: IDL.Func(
 			[],
 			[IDL.Vec(IDL.Tuple(IDL.Principal, MissionControl))],
@@ -273,6 +461,8 @@ export const idlFactory = ({ IDL }) => {
 		set_custom_domain: IDL.Func([IDL.Text, IDL.Opt(IDL.Text)], [], []),
 		set_fee: IDL.Func([SegmentType, Tokens], [], []),
 		set_storage_config: IDL.Func([StorageConfig], [], []),
+		set_project_template: IDL.Func([IDL.Text, SetProjectTemplate], [ProjectTemplate], []),
+		set_template_version: IDL.Func([IDL.Text, SetTemplateVersion], [Template], []),
 		submit_proposal: IDL.Func([IDL.Nat], [IDL.Nat, Proposal], []),
 		update_rate_config: IDL.Func([SegmentType, RateConfig], [], []),
 		upload_asset_chunk: IDL.Func([UploadChunk], [UploadChunkResult], []),
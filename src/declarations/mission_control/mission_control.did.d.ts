@@ -7,6 +7,13 @@ export interface Account {
 	subaccount: [] | [Uint8Array | number[]];
 }
 export type CanisterStatusType = { stopped: null } | { stopping: null } | { running: null };
+export interface CloneSatelliteOptions {
+	storage_collections: Array<string>;
+	subnet_id: [] | [Principal];
+	name: [] | [string];
+	db_collections: Array<string>;
+	config: boolean;
+}
 export interface Controller {
 	updated_at: bigint;
 	metadata: Array<[string, string]>;
@@ -16,6 +23,13 @@ export interface Controller {
 }
 export type ControllerScope = { Write: null } | { Admin: null };
 export interface CreateCanisterConfig {
+	init_config: [] | [SatelliteInitConfig];
+	subnet_id: [] | [Principal];
+	name: [] | [string];
+	project_template: [] | [string];
+}
+export interface CreatePreviewConfig {
+	ttl_seconds: bigint;
 	subnet_id: [] | [Principal];
 	name: [] | [string];
 }
@@ -27,6 +41,17 @@ export interface CyclesBalance {
 	timestamp: bigint;
 	amount: bigint;
 }
+export interface CyclesFreezeAlert {
+	horizon_days: bigint;
+}
+export interface CyclesFreezePrediction {
+	alert: boolean;
+	freezing_threshold_cycles: bigint;
+	burn_rate_per_day: bigint;
+	cycles: bigint;
+	days_until_freeze: [] | [bigint];
+	predicted_at: bigint;
+}
 export interface CyclesMonitoring {
 	strategy: [] | [CyclesMonitoringStrategy];
 	enabled: boolean;
@@ -39,6 +64,7 @@ export interface CyclesMonitoringStartConfig {
 export interface CyclesMonitoringStatus {
 	monitored_ids: Array<Principal>;
 	running: boolean;
+	freeze_predictions: Array<[Principal, CyclesFreezePrediction]>;
 }
 export interface CyclesMonitoringStopConfig {
 	satellite_ids: [] | [Array<Principal>];
@@ -59,11 +85,32 @@ export interface GetMonitoringHistory {
 	from: [] | [bigint];
 	segment_id: Principal;
 }
+export interface GetUsageDigest {
+	to: [] | [bigint];
+	from: [] | [bigint];
+}
+export interface InstallTemplateArgs {
+	id: string;
+	version: string;
+}
+export interface MemorySize {
+	stable: bigint;
+	heap: bigint;
+}
 export interface MissionControlSettings {
 	updated_at: bigint;
 	created_at: bigint;
+	freeze_alert: [] | [CyclesFreezeAlert];
 	monitoring: [] | [Monitoring];
 }
+export type ModulesControllersOperation =
+	| { Set: { controller: SetController; controllers: Array<Principal> } }
+	| { Delete: { controllers: Array<Principal> } }
+	| { Rotate: { to: Principal; controller: SetController; from: Principal } };
+export interface ModulesControllersResults {
+	orbiters: Array<[Principal, Result_3]>;
+	satellites: Array<[Principal, Result_3]>;
+}
 export interface Monitoring {
 	cycles: [] | [CyclesMonitoring];
 }
@@ -83,6 +130,7 @@ export interface MonitoringStartConfig {
 }
 export interface MonitoringStatus {
 	cycles: [] | [CyclesMonitoringStatus];
+	quotas: [] | [Array<[Principal, QuotaStatus]>];
 }
 export interface MonitoringStopConfig {
 	cycles_config: [] | [CyclesMonitoringStopConfig];
@@ -94,9 +142,27 @@ export interface Orbiter {
 	created_at: bigint;
 	settings: [] | [Settings];
 }
+export interface OrbiterUsage {
+	cycles_burned: [] | [bigint];
+	top_pages: Array<[string, number]>;
+	page_views: [] | [number];
+	unique_sessions: [] | [bigint];
+}
+export interface Preview {
+	created_at: bigint;
+	expires_at: bigint;
+}
+export interface QuotaStatus {
+	updated_at: bigint;
+	memory_size: bigint;
+	warnings: Array<QuotaWarning>;
+	docs_count: bigint;
+}
+export type QuotaWarning = { DocsCount: null } | { MemorySize: null };
 export type Result = { Ok: bigint } | { Err: TransferError };
 export type Result_1 = { Ok: bigint } | { Err: TransferError_1 };
 export type Result_2 = { Ok: SegmentStatus } | { Err: string };
+export type Result_3 = { Ok: null } | { Err: string };
 export interface Satellite {
 	updated_at: bigint;
 	metadata: Array<[string, string]>;
@@ -104,6 +170,14 @@ export interface Satellite {
 	satellite_id: Principal;
 	settings: [] | [Settings];
 }
+export interface SatelliteInitConfig {
+	controllers: Array<[Principal, SetController]>;
+	bundle: [] | [Uint8Array | number[]];
+}
+export interface SatelliteUsage {
+	cycles_burned: [] | [bigint];
+	memory_size: [] | [MemorySize];
+}
 export interface SegmentCanisterSettings {
 	freezing_threshold: bigint;
 	controllers: Array<Principal>;
@@ -188,36 +262,61 @@ export type TransferError_1 =
 	| { CreatedInFuture: { ledger_time: bigint } }
 	| { TooOld: null }
 	| { InsufficientFunds: { balance: bigint } };
+export interface UpgradeAttestation {
+	sha256: Uint8Array | number[];
+	version: string;
+	upgraded_at: bigint;
+}
+export interface UpgradeSegmentArgs {
+	sha256: Uint8Array | number[];
+	version: string;
+}
+export interface UsageDigest {
+	to: bigint;
+	orbiters: Array<[Principal, OrbiterUsage]>;
+	from: bigint;
+	satellites: Array<[Principal, SatelliteUsage]>;
+	mission_control_cycles_burned: [] | [bigint];
+}
 export interface _SERVICE {
 	add_mission_control_controllers: ActorMethod<[Array<Principal>], undefined>;
 	add_satellites_controllers: ActorMethod<[Array<Principal>, Array<Principal>], undefined>;
+	clone_satellite: ActorMethod<[Principal, CloneSatelliteOptions], Satellite>;
 	create_orbiter: ActorMethod<[[] | [string]], Orbiter>;
 	create_orbiter_with_config: ActorMethod<[CreateCanisterConfig], Orbiter>;
+	create_preview_satellite: ActorMethod<[CreatePreviewConfig], Satellite>;
 	create_satellite: ActorMethod<[string], Satellite>;
 	create_satellite_with_config: ActorMethod<[CreateCanisterConfig], Satellite>;
 	del_mission_control_controllers: ActorMethod<[Array<Principal>], undefined>;
 	del_orbiter: ActorMethod<[Principal, bigint], undefined>;
 	del_orbiters_controllers: ActorMethod<[Array<Principal>, Array<Principal>], undefined>;
+	del_preview_satellite: ActorMethod<[Principal], undefined>;
 	del_satellite: ActorMethod<[Principal, bigint], undefined>;
 	del_satellites_controllers: ActorMethod<[Array<Principal>, Array<Principal>], undefined>;
 	deposit_cycles: ActorMethod<[DepositCyclesArgs], undefined>;
+	get_freeze_predictions: ActorMethod<[], Array<[Principal, CyclesFreezePrediction]>>;
 	get_monitoring_history: ActorMethod<
 		[GetMonitoringHistory],
 		Array<[MonitoringHistoryKey, MonitoringHistory]>
 	>;
 	get_monitoring_status: ActorMethod<[], MonitoringStatus>;
 	get_settings: ActorMethod<[], [] | [MissionControlSettings]>;
+	get_usage_digest: ActorMethod<[GetUsageDigest], UsageDigest>;
 	get_user: ActorMethod<[], Principal>;
 	icp_transfer: ActorMethod<[TransferArgs], Result>;
 	icrc_transfer: ActorMethod<[Principal, TransferArg], Result_1>;
+	install_satellite_template: ActorMethod<[Principal, InstallTemplateArgs], undefined>;
 	list_mission_control_controllers: ActorMethod<[], Array<[Principal, Controller]>>;
 	list_mission_control_statuses: ActorMethod<[], Array<[bigint, Result_2]>>;
 	list_orbiter_statuses: ActorMethod<[Principal], [] | [Array<[bigint, Result_2]>]>;
 	list_orbiters: ActorMethod<[], Array<[Principal, Orbiter]>>;
+	list_previews: ActorMethod<[], Array<[Principal, Preview]>>;
 	list_satellite_statuses: ActorMethod<[Principal], [] | [Array<[bigint, Result_2]>]>;
 	list_satellites: ActorMethod<[], Array<[Principal, Satellite]>>;
+	list_upgrade_history: ActorMethod<[Principal], Array<UpgradeAttestation>>;
 	remove_mission_control_controllers: ActorMethod<[Array<Principal>], undefined>;
 	remove_satellites_controllers: ActorMethod<[Array<Principal>, Array<Principal>], undefined>;
+	set_freeze_alert: ActorMethod<[CyclesFreezeAlert], MissionControlSettings>;
 	set_metadata: ActorMethod<[Array<[string, string]>], undefined>;
 	set_mission_control_controllers: ActorMethod<[Array<Principal>, SetController], undefined>;
 	set_orbiter: ActorMethod<[Principal, [] | [string]], Orbiter>;
@@ -240,6 +339,9 @@ export interface _SERVICE {
 	unset_satellite: ActorMethod<[Principal], undefined>;
 	update_and_start_monitoring: ActorMethod<[MonitoringStartConfig], undefined>;
 	update_and_stop_monitoring: ActorMethod<[MonitoringStopConfig], undefined>;
+	update_modules_controllers: ActorMethod<[ModulesControllersOperation], ModulesControllersResults>;
+	upgrade_orbiter: ActorMethod<[Principal, UpgradeSegmentArgs], UpgradeAttestation>;
+	upgrade_satellite: ActorMethod<[Principal, UpgradeSegmentArgs], UpgradeAttestation>;
 	version: ActorMethod<[], string>;
 }
 export declare const idlFactory: IDL.InterfaceFactory;
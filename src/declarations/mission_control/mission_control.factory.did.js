@@ -1,5 +1,12 @@
 // @ts-ignore
 export const idlFactory = ({ IDL }) => {
+	const CloneSatelliteOptions = IDL.Record({
+		storage_collections: IDL.Vec(IDL.Text),
+		subnet_id: IDL.Opt(IDL.Principal),
+		name: IDL.Opt(IDL.Text),
+		db_collections: IDL.Vec(IDL.Text),
+		config: IDL.Bool
+	});
 	const CyclesThreshold = IDL.Record({
 		fund_cycles: IDL.Nat,
 		min_cycles: IDL.Nat
@@ -13,6 +20,13 @@ export const idlFactory = ({ IDL }) => {
 	});
 	const Monitoring = IDL.Record({ cycles: IDL.Opt(CyclesMonitoring) });
 	const Settings = IDL.Record({ monitoring: IDL.Opt(Monitoring) });
+	const Satellite = IDL.Record({
+		updated_at: IDL.Nat64,
+		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		created_at: IDL.Nat64,
+		satellite_id: IDL.Principal,
+		settings: IDL.Opt(Settings)
+	});
 	const Orbiter = IDL.Record({
 		updated_at: IDL.Nat64,
 		orbiter_id: IDL.Principal,
@@ -20,16 +34,29 @@ export const idlFactory = ({ IDL }) => {
 		created_at: IDL.Nat64,
 		settings: IDL.Opt(Settings)
 	});
+	const ControllerScope = IDL.Variant({
+		Write: IDL.Null,
+		Admin: IDL.Null
+	});
+	const SetController = IDL.Record({
+		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		scope: ControllerScope,
+		expires_at: IDL.Opt(IDL.Nat64)
+	});
+	const SatelliteInitConfig = IDL.Record({
+		controllers: IDL.Vec(IDL.Tuple(IDL.Principal, SetController)),
+		bundle: IDL.Opt(IDL.Vec(IDL.Nat8))
+	});
 	const CreateCanisterConfig = IDL.Record({
+		init_config: IDL.Opt(SatelliteInitConfig),
 		subnet_id: IDL.Opt(IDL.Principal),
-		name: IDL.Opt(IDL.Text)
+		name: IDL.Opt(IDL.Text),
+		project_template: IDL.Opt(IDL.Text)
 	});
-	const Satellite = IDL.Record({
-		updated_at: IDL.Nat64,
-		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
-		created_at: IDL.Nat64,
-		satellite_id: IDL.Principal,
-		settings: IDL.Opt(Settings)
+	const CreatePreviewConfig = IDL.Record({
+		ttl_seconds: IDL.Nat64,
+		subnet_id: IDL.Opt(IDL.Principal),
+		name: IDL.Opt(IDL.Text)
 	});
 	const DepositCyclesArgs = IDL.Record({
 		cycles: IDL.Nat,
@@ -55,16 +82,38 @@ export const idlFactory = ({ IDL }) => {
 	const MonitoringHistory = IDL.Record({
 		cycles: IDL.Opt(MonitoringHistoryCycles)
 	});
+	const CyclesFreezePrediction = IDL.Record({
+		alert: IDL.Bool,
+		freezing_threshold_cycles: IDL.Nat,
+		burn_rate_per_day: IDL.Nat,
+		cycles: IDL.Nat,
+		days_until_freeze: IDL.Opt(IDL.Nat64),
+		predicted_at: IDL.Nat64
+	});
 	const CyclesMonitoringStatus = IDL.Record({
 		monitored_ids: IDL.Vec(IDL.Principal),
-		running: IDL.Bool
+		running: IDL.Bool,
+		freeze_predictions: IDL.Vec(IDL.Tuple(IDL.Principal, CyclesFreezePrediction))
+	});
+	const QuotaWarning = IDL.Variant({
+		DocsCount: IDL.Null,
+		MemorySize: IDL.Null
+	});
+	const QuotaStatus = IDL.Record({
+		updated_at: IDL.Nat64,
+		memory_size: IDL.Nat64,
+		warnings: IDL.Vec(QuotaWarning),
+		docs_count: IDL.Nat64
 	});
 	const MonitoringStatus = IDL.Record({
-		cycles: IDL.Opt(CyclesMonitoringStatus)
+		cycles: IDL.Opt(CyclesMonitoringStatus),
+		quotas: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Principal, QuotaStatus)))
 	});
+	const CyclesFreezeAlert = IDL.Record({ horizon_days: IDL.Nat64 });
 	const MissionControlSettings = IDL.Record({
 		updated_at: IDL.Nat64,
 		created_at: IDL.Nat64,
+		freeze_alert: IDL.Opt(CyclesFreezeAlert),
 		monitoring: IDL.Opt(Monitoring)
 	});
 	const Tokens = IDL.Record({ e8s: IDL.Nat64 });
@@ -111,10 +160,7 @@ export const idlFactory = ({ IDL }) => {
 		InsufficientFunds: IDL.Record({ balance: IDL.Nat })
 	});
 	const Result_1 = IDL.Variant({ Ok: IDL.Nat, Err: TransferError_1 });
-	const ControllerScope = IDL.Variant({
-		Write: IDL.Null,
-		Admin: IDL.Null
-	});
+	const InstallTemplateArgs = IDL.Record({ id: IDL.Text, version: IDL.Text });
 	const Controller = IDL.Record({
 		updated_at: IDL.Nat64,
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
@@ -148,10 +194,14 @@ export const idlFactory = ({ IDL }) => {
 		status_at: IDL.Nat64
 	});
 	const Result_2 = IDL.Variant({ Ok: SegmentStatus, Err: IDL.Text });
-	const SetController = IDL.Record({
-		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
-		scope: ControllerScope,
-		expires_at: IDL.Opt(IDL.Nat64)
+	const Preview = IDL.Record({
+		created_at: IDL.Nat64,
+		expires_at: IDL.Nat64
+	});
+	const UpgradeAttestation = IDL.Record({
+		sha256: IDL.Vec(IDL.Nat8),
+		version: IDL.Text,
+		upgraded_at: IDL.Nat64
 	});
 	const CronJobStatusesConfig = IDL.Record({
 		enabled: IDL.Bool,
@@ -188,19 +238,70 @@ export const idlFactory = ({ IDL }) => {
 	const MonitoringStopConfig = IDL.Record({
 		cycles_config: IDL.Opt(CyclesMonitoringStopConfig)
 	});
+	const ModulesControllersOperation = IDL.Variant({
+		Set: IDL.Record({
+			controller: SetController,
+			controllers: IDL.Vec(IDL.Principal)
+		}),
+		Delete: IDL.Record({ controllers: IDL.Vec(IDL.Principal) }),
+		Rotate: IDL.Record({
+			to: IDL.Principal,
+			controller: SetController,
+			from: IDL.Principal
+		})
+	});
+	const Result_3 = IDL.Variant({ Ok: IDL.Null, Err: IDL.Text });
+	const ModulesControllersResults = IDL.Record({
+		orbiters: IDL.Vec(IDL.Tuple(IDL.Principal, Result_3)),
+		satellites: IDL.Vec(IDL.Tuple(IDL.Principal, Result_3))
+	});
+	const UpgradeSegmentArgs = IDL.Record({
+		sha256: IDL.Vec(IDL.Nat8),
+		version: IDL.Text
+	});
+	const GetUsageDigest = IDL.Record({
+		to: IDL.Opt(IDL.Nat64),
+		from: IDL.Opt(IDL.Nat64)
+	});
+	const OrbiterUsage = IDL.Record({
+		cycles_burned: IDL.Opt(IDL.Nat),
+		top_pages: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Nat32)),
+		page_views: IDL.Opt(IDL.Nat32),
+		unique_sessions: IDL.Opt(IDL.Nat64)
+	});
+	const MemorySize = IDL.Record({ stable: IDL.Nat64, heap: IDL.Nat64 });
+	const SatelliteUsage = IDL.Record({
+		cycles_burned: IDL.Opt(IDL.Nat),
+		memory_size: IDL.Opt(MemorySize)
+	});
+	const UsageDigest = IDL.Record({
+		to: IDL.Nat64,
+		orbiters: IDL.Vec(IDL.Tuple(IDL.Principal, OrbiterUsage)),
+		from: IDL.Nat64,
+		satellites: IDL.Vec(IDL.Tuple(IDL.Principal, SatelliteUsage)),
+		mission_control_cycles_burned: IDL.Opt(IDL.Nat)
+	});
 	return IDL.Service({
 		add_mission_control_controllers: IDL.Func([IDL.Vec(IDL.Principal)], [], []),
 		add_satellites_controllers: IDL.Func([IDL.Vec(IDL.Principal), IDL.Vec(IDL.Principal)], [], []),
+		clone_satellite: IDL.Func([IDL.Principal, CloneSatelliteOptions], [Satellite], []),
 		create_orbiter: IDL.Func([IDL.Opt(IDL.Text)], [Orbiter], []),
 		create_orbiter_with_config: IDL.Func([CreateCanisterConfig], [Orbiter], []),
+		create_preview_satellite: IDL.Func([CreatePreviewConfig], [Satellite], []),
 		create_satellite: IDL.Func([IDL.Text], [Satellite], []),
 		create_satellite_with_config: IDL.Func([CreateCanisterConfig], [Satellite], []),
 		del_mission_control_controllers: IDL.Func([IDL.Vec(IDL.Principal)], [], []),
 		del_orbiter: IDL.Func([IDL.Principal, IDL.Nat], [], []),
 		del_orbiters_controllers: IDL.Func([IDL.Vec(IDL.Principal), IDL.Vec(IDL.Principal)], [], []),
+		del_preview_satellite: IDL.Func([IDL.Principal], [], []),
 		del_satellite: IDL.Func([IDL.Principal, IDL.Nat], [], []),
 		del_satellites_controllers: IDL.Func([IDL.Vec(IDL.Principal), IDL.Vec(IDL.Principal)], [], []),
 		deposit_cycles: IDL.Func([DepositCyclesArgs], [], []),
+		get_freeze_predictions: IDL.Func(
+			[],
+			[IDL.Vec(IDL.Tuple(IDL.Principal, CyclesFreezePrediction))],
+			['query']
+		),
 		get_monitoring_history: IDL.Func(
 			[GetMonitoringHistory],
 			[IDL.Vec(IDL.Tuple(MonitoringHistoryKey, MonitoringHistory))],
@@ -208,9 +309,11 @@ export const idlFactory = ({ IDL }) => {
 		),
 		get_monitoring_status: IDL.Func([], [MonitoringStatus], ['query']),
 		get_settings: IDL.Func([], [IDL.Opt(MissionControlSettings)], ['query']),
+		get_usage_digest: IDL.Func([GetUsageDigest], [UsageDigest], []),
 		get_user: IDL.Func([], [IDL.Principal], ['query']),
 		icp_transfer: IDL.Func([TransferArgs], [Result], []),
 		icrc_transfer: IDL.Func([IDL.Principal, TransferArg], [Result_1], []),
+		install_satellite_template: IDL.Func([IDL.Principal, InstallTemplateArgs], [], []),
 		list_mission_control_controllers: IDL.Func(
 			[],
 			[IDL.Vec(IDL.Tuple(IDL.Principal, Controller))],
@@ -227,18 +330,21 @@ export const idlFactory = ({ IDL }) => {
 			['query']
 		),
 		list_orbiters: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Principal, Orbiter))], ['query']),
+		list_previews: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Principal, Preview))], ['query']),
 		list_satellite_statuses: IDL.Func(
 			[IDL.Principal],
 			[IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Nat64, Result_2)))],
 			['query']
 		),
 		list_satellites: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Principal, Satellite))], ['query']),
+		list_upgrade_history: IDL.Func([IDL.Principal], [IDL.Vec(UpgradeAttestation)], ['query']),
 		remove_mission_control_controllers: IDL.Func([IDL.Vec(IDL.Principal)], [], []),
 		remove_satellites_controllers: IDL.Func(
 			[IDL.Vec(IDL.Principal), IDL.Vec(IDL.Principal)],
 			[],
 			[]
 		),
+		set_freeze_alert: IDL.Func([CyclesFreezeAlert], [MissionControlSettings], []),
 		set_metadata: IDL.Func([IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text))], [], []),
 		set_mission_control_controllers: IDL.Func([IDL.Vec(IDL.Principal), SetController], [], []),
 		set_orbiter: IDL.Func([IDL.Principal, IDL.Opt(IDL.Text)], [Orbiter], []),
@@ -271,6 +377,13 @@ export const idlFactory = ({ IDL }) => {
 		unset_satellite: IDL.Func([IDL.Principal], [], []),
 		update_and_start_monitoring: IDL.Func([MonitoringStartConfig], [], []),
 		update_and_stop_monitoring: IDL.Func([MonitoringStopConfig], [], []),
+		update_modules_controllers: IDL.Func(
+			[ModulesControllersOperation],
+			[ModulesControllersResults],
+			[]
+		),
+		upgrade_orbiter: IDL.Func([IDL.Principal, UpgradeSegmentArgs], [UpgradeAttestation], []),
+		upgrade_satellite: IDL.Func([IDL.Principal, UpgradeSegmentArgs], [UpgradeAttestation], []),
 		version: IDL.Func([], [IDL.Text], ['query'])
 	});
 };
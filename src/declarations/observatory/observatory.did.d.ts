@@ -2,12 +2,20 @@ import type { ActorMethod } from '@dfinity/agent';
 import type { IDL } from '@dfinity/candid';
 import type { Principal } from '@dfinity/principal';
 
+export interface ArchiveDigest {
+	timestamp: bigint;
+	memory_sizes: Array<[Principal, bigint]>;
+}
 export interface ArchiveStatuses {
 	statuses: Result_1;
 	timestamp: bigint;
 }
 export type CanisterStatusType = { stopped: null } | { stopping: null } | { running: null };
 export type ControllerScope = { Write: null } | { Admin: null };
+export interface CronJobDigest {
+	top_pages: [] | [number];
+	enabled: boolean;
+}
 export interface CronJobStatuses {
 	mission_control_cycles_threshold: [] | [bigint];
 	orbiters: Array<[Principal, CronJobStatusesConfig]>;
@@ -22,6 +30,7 @@ export interface CronJobStatusesConfig {
 export interface CronJobs {
 	metadata: Array<[string, string]>;
 	statuses: CronJobStatuses;
+	digest: [] | [CronJobDigest];
 }
 export interface CronTab {
 	cron_jobs: CronJobs;
@@ -33,6 +42,15 @@ export interface CronTab {
 export interface DeleteControllersArgs {
 	controllers: Array<Principal>;
 }
+export interface HttpHeader {
+	value: string;
+	name: string;
+}
+export interface HttpResponse {
+	status: bigint;
+	body: Uint8Array | number[];
+	headers: Array<HttpHeader>;
+}
 export interface ListStatuses {
 	cron_jobs: CronJobs;
 	statuses: Result_1;
@@ -41,6 +59,19 @@ export interface ListStatuses {
 export interface ListStatusesArgs {
 	time_delta: [] | [bigint];
 }
+export type NotificationChannelKindNoSecret =
+	| { Slack: null }
+	| { Webhook: { url: string; headers: Array<string> } }
+	| { Telegram: { chat_id: string } };
+export interface NotificationChannelNoSecret {
+	updated_at: bigint;
+	kind: NotificationChannelKindNoSecret;
+	created_at: bigint;
+	last_failed_at: [] | [bigint];
+	version: [] | [bigint];
+	enabled: boolean;
+	last_error: [] | [string];
+}
 export type Result = { Ok: SegmentStatus } | { Err: string };
 export type Result_1 = { Ok: SegmentsStatuses } | { Err: string };
 export interface SegmentCanisterSettings {
@@ -82,13 +113,35 @@ export interface SetCronTab {
 	mission_control_id: Principal;
 	version: [] | [bigint];
 }
+export interface SetNotificationChannel {
+	kind: SetNotificationChannelKind;
+	version: [] | [bigint];
+	enabled: boolean;
+}
+export type SetNotificationChannelKind =
+	| { Slack: { url: string } }
+	| { Webhook: { url: string; headers: Array<[string, string]> } }
+	| { Telegram: { bot_token: string; chat_id: string } };
+export interface TransformArgs {
+	context: Uint8Array | number[];
+	response: HttpResponse;
+}
 export interface _SERVICE {
 	del_controllers: ActorMethod<[DeleteControllersArgs], undefined>;
+	del_notification_channel: ActorMethod<[string], undefined>;
 	get_cron_tab: ActorMethod<[], [] | [CronTab]>;
+	get_digest: ActorMethod<[], [] | [ArchiveDigest]>;
 	get_statuses: ActorMethod<[], [] | [ArchiveStatuses]>;
+	list_notification_channels: ActorMethod<[], Array<[string, NotificationChannelNoSecret]>>;
 	list_statuses: ActorMethod<[ListStatusesArgs], Array<ListStatuses>>;
 	set_controllers: ActorMethod<[SetControllersArgs], undefined>;
 	set_cron_tab: ActorMethod<[SetCronTab], CronTab>;
+	set_notification_channel: ActorMethod<
+		[string, SetNotificationChannel],
+		NotificationChannelNoSecret
+	>;
+	test_notification_channel: ActorMethod<[string], number>;
+	transform_notification_response: ActorMethod<[TransformArgs], HttpResponse>;
 	version: ActorMethod<[], string>;
 }
 export declare const idlFactory: IDL.InterfaceFactory;
@@ -14,9 +14,14 @@ export const idlFactory = ({ IDL }) => {
 		enabled: IDL.Bool,
 		cycles_threshold: IDL.Opt(IDL.Nat64)
 	});
+	const CronJobDigest = IDL.Record({
+		top_pages: IDL.Opt(IDL.Nat8),
+		enabled: IDL.Bool
+	});
 	const CronJobs = IDL.Record({
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
-		statuses: CronJobStatuses
+		statuses: CronJobStatuses,
+		digest: IDL.Opt(CronJobDigest)
 	});
 	const CronTab = IDL.Record({
 		cron_jobs: CronJobs,
@@ -85,13 +90,68 @@ export const idlFactory = ({ IDL }) => {
 		mission_control_id: IDL.Principal,
 		version: IDL.Opt(IDL.Nat64)
 	});
+	const NotificationChannelKindNoSecret = IDL.Variant({
+		Slack: IDL.Null,
+		Webhook: IDL.Record({ url: IDL.Text, headers: IDL.Vec(IDL.Text) }),
+		Telegram: IDL.Record({ chat_id: IDL.Text })
+	});
+	const NotificationChannelNoSecret = IDL.Record({
+		updated_at: IDL.Nat64,
+		kind: NotificationChannelKindNoSecret,
+		created_at: IDL.Nat64,
+		last_failed_at: IDL.Opt(IDL.Nat64),
+		version: IDL.Opt(IDL.Nat64),
+		enabled: IDL.Bool,
+		last_error: IDL.Opt(IDL.Text)
+	});
+	const SetNotificationChannelKind = IDL.Variant({
+		Slack: IDL.Record({ url: IDL.Text }),
+		Webhook: IDL.Record({
+			url: IDL.Text,
+			headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text))
+		}),
+		Telegram: IDL.Record({ bot_token: IDL.Text, chat_id: IDL.Text })
+	});
+	const SetNotificationChannel = IDL.Record({
+		kind: SetNotificationChannelKind,
+		version: IDL.Opt(IDL.Nat64),
+		enabled: IDL.Bool
+	});
+	const HttpHeader = IDL.Record({ value: IDL.Text, name: IDL.Text });
+	const HttpResponse = IDL.Record({
+		status: IDL.Nat,
+		body: IDL.Vec(IDL.Nat8),
+		headers: IDL.Vec(HttpHeader)
+	});
+	const TransformArgs = IDL.Record({
+		context: IDL.Vec(IDL.Nat8),
+		response: HttpResponse
+	});
+	const ArchiveDigest = IDL.Record({
+		timestamp: IDL.Nat64,
+		memory_sizes: IDL.Vec(IDL.Tuple(IDL.Principal, IDL.Nat64))
+	});
 	return IDL.Service({
 		del_controllers: IDL.Func([DeleteControllersArgs], [], []),
+		del_notification_channel: IDL.Func([IDL.Text], [], []),
 		get_cron_tab: IDL.Func([], [IDL.Opt(CronTab)], ['query']),
+		get_digest: IDL.Func([], [IDL.Opt(ArchiveDigest)], ['query']),
 		get_statuses: IDL.Func([], [IDL.Opt(ArchiveStatuses)], ['query']),
+		list_notification_channels: IDL.Func(
+			[],
+			[IDL.Vec(IDL.Tuple(IDL.Text, NotificationChannelNoSecret))],
+			['query']
+		),
 		list_statuses: IDL.Func([ListStatusesArgs], [IDL.Vec(ListStatuses)], ['query']),
 		set_controllers: IDL.Func([SetControllersArgs], [], []),
 		set_cron_tab: IDL.Func([SetCronTab], [CronTab], []),
+		set_notification_channel: IDL.Func(
+			[IDL.Text, SetNotificationChannel],
+			[NotificationChannelNoSecret],
+			[]
+		),
+		test_notification_channel: IDL.Func([IDL.Text], [IDL.Nat16], []),
+		transform_notification_response: IDL.Func([TransformArgs], [HttpResponse], ['query']),
 		version: IDL.Func([], [IDL.Text], ['query'])
 	});
 };
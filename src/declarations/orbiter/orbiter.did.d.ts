@@ -17,11 +17,30 @@ export interface AnalyticsClientsPageViews {
 	browsers: AnalyticsBrowsersPageViews;
 	devices: AnalyticsDevicesPageViews;
 }
+export interface AnalyticsClientsPageViewsBySatellite {
+	total: AnalyticsClientsPageViews;
+	satellites: Array<[Principal, AnalyticsClientsPageViews]>;
+}
 export interface AnalyticsDevicesPageViews {
 	desktop: number;
 	others: number;
 	mobile: number;
 }
+export interface AnalyticsErrorReports {
+	groups: Array<AnalyticsErrorReportsGroup>;
+}
+export interface AnalyticsErrorReportsBySatellite {
+	total: AnalyticsErrorReports;
+	satellites: Array<[Principal, AnalyticsErrorReports]>;
+}
+export interface AnalyticsErrorReportsGroup {
+	total: number;
+	last_seen_at: bigint;
+	stack_hash: string;
+	daily_total: Array<[CalendarDate, number]>;
+	message: string;
+	unique_sessions: bigint;
+}
 export interface AnalyticsMetricsPageViews {
 	bounce_rate: number;
 	average_page_views_per_session: number;
@@ -30,13 +49,25 @@ export interface AnalyticsMetricsPageViews {
 	unique_page_views: bigint;
 	unique_sessions: bigint;
 }
+export interface AnalyticsMetricsPageViewsBySatellite {
+	total: AnalyticsMetricsPageViews;
+	satellites: Array<[Principal, AnalyticsMetricsPageViews]>;
+}
 export interface AnalyticsTop10PageViews {
 	referrers: Array<[string, number]>;
 	pages: Array<[string, number]>;
 }
+export interface AnalyticsTop10PageViewsBySatellite {
+	total: AnalyticsTop10PageViews;
+	satellites: Array<[Principal, AnalyticsTop10PageViews]>;
+}
 export interface AnalyticsTrackEvents {
 	total: Array<[string, number]>;
 }
+export interface AnalyticsTrackEventsBySatellite {
+	total: AnalyticsTrackEvents;
+	satellites: Array<[Principal, AnalyticsTrackEvents]>;
+}
 export interface AnalyticsWebVitalsPageMetrics {
 	cls: [] | [number];
 	fcp: [] | [number];
@@ -48,6 +79,10 @@ export interface AnalyticsWebVitalsPerformanceMetrics {
 	overall: AnalyticsWebVitalsPageMetrics;
 	pages: Array<[string, AnalyticsWebVitalsPageMetrics]>;
 }
+export interface AnalyticsWebVitalsPerformanceMetricsBySatellite {
+	total: AnalyticsWebVitalsPerformanceMetrics;
+	satellites: Array<[Principal, AnalyticsWebVitalsPerformanceMetrics]>;
+}
 export interface CalendarDate {
 	day: number;
 	month: number;
@@ -71,11 +106,27 @@ export interface DepositCyclesArgs {
 	cycles: bigint;
 	destination_id: Principal;
 }
+export interface ErrorReport {
+	updated_at: bigint;
+	session_id: string;
+	href: string;
+	created_at: bigint;
+	satellite_id: Principal;
+	stack_hash: string;
+	version: [] | [bigint];
+	message: string;
+	user_agent: [] | [string];
+}
 export interface GetAnalytics {
 	to: [] | [bigint];
 	from: [] | [bigint];
 	satellite_id: [] | [Principal];
 }
+export interface GetAnalyticsBySatellite {
+	to: [] | [bigint];
+	from: [] | [bigint];
+	satellite_ids: Array<Principal>;
+}
 export interface MemorySize {
 	stable: bigint;
 	heap: bigint;
@@ -91,13 +142,22 @@ export interface OrbiterSatelliteConfig {
 	updated_at: bigint;
 	features: [] | [OrbiterSatelliteFeatures];
 	created_at: bigint;
+	anonymize: [] | [boolean];
 	version: [] | [bigint];
+	rate_config: [] | [RateConfig];
+	sampling: [] | [OrbiterSatelliteSampling];
 }
 export interface OrbiterSatelliteFeatures {
+	error_reports: [] | [boolean];
 	performance_metrics: boolean;
 	track_events: boolean;
 	page_views: boolean;
 }
+export interface OrbiterSatelliteSampling {
+	performance_metrics: [] | [number];
+	track_events: [] | [number];
+	page_views: [] | [number];
+}
 export interface PageView {
 	title: string;
 	updated_at: bigint;
@@ -132,10 +192,15 @@ export type PerformanceMetricName =
 	| { INP: null }
 	| { LCP: null }
 	| { TTFB: null };
-export type Result = { Ok: PageView } | { Err: string };
+export interface RateConfig {
+	max_tokens: bigint;
+	time_per_token_ns: bigint;
+}
+export type Result = { Ok: ErrorReport } | { Err: string };
 export type Result_1 = { Ok: null } | { Err: Array<[AnalyticKey, string]> };
-export type Result_2 = { Ok: PerformanceMetric } | { Err: string };
-export type Result_3 = { Ok: TrackEvent } | { Err: string };
+export type Result_2 = { Ok: PageView } | { Err: string };
+export type Result_3 = { Ok: PerformanceMetric } | { Err: string };
+export type Result_4 = { Ok: TrackEvent } | { Err: string };
 export interface SetController {
 	metadata: Array<[string, string]>;
 	scope: ControllerScope;
@@ -145,6 +210,15 @@ export interface SetControllersArgs {
 	controller: SetController;
 	controllers: Array<Principal>;
 }
+export interface SetErrorReport {
+	session_id: string;
+	href: string;
+	satellite_id: Principal;
+	stack_hash: string;
+	version: [] | [bigint];
+	message: string;
+	user_agent: [] | [string];
+}
 export interface SetPageView {
 	title: string;
 	updated_at: [] | [bigint];
@@ -168,7 +242,10 @@ export interface SetPerformanceMetric {
 }
 export interface SetSatelliteConfig {
 	features: [] | [OrbiterSatelliteFeatures];
+	anonymize: [] | [boolean];
 	version: [] | [bigint];
+	rate_config: [] | [RateConfig];
+	sampling: [] | [OrbiterSatelliteSampling];
 }
 export interface SetTrackEvent {
 	updated_at: [] | [bigint];
@@ -198,30 +275,58 @@ export interface _SERVICE {
 	del_controllers: ActorMethod<[DeleteControllersArgs], Array<[Principal, Controller]>>;
 	del_satellite_config: ActorMethod<[Principal, DelSatelliteConfig], undefined>;
 	deposit_cycles: ActorMethod<[DepositCyclesArgs], undefined>;
+	get_error_reports: ActorMethod<[GetAnalytics], Array<[AnalyticKey, ErrorReport]>>;
+	get_error_reports_analytics: ActorMethod<[GetAnalytics], AnalyticsErrorReports>;
+	get_error_reports_analytics_by_satellite: ActorMethod<
+		[GetAnalyticsBySatellite],
+		AnalyticsErrorReportsBySatellite
+	>;
 	get_page_views: ActorMethod<[GetAnalytics], Array<[AnalyticKey, PageView]>>;
 	get_page_views_analytics_clients: ActorMethod<[GetAnalytics], AnalyticsClientsPageViews>;
+	get_page_views_analytics_clients_by_satellite: ActorMethod<
+		[GetAnalyticsBySatellite],
+		AnalyticsClientsPageViewsBySatellite
+	>;
 	get_page_views_analytics_metrics: ActorMethod<[GetAnalytics], AnalyticsMetricsPageViews>;
+	get_page_views_analytics_metrics_by_satellite: ActorMethod<
+		[GetAnalyticsBySatellite],
+		AnalyticsMetricsPageViewsBySatellite
+	>;
 	get_page_views_analytics_top_10: ActorMethod<[GetAnalytics], AnalyticsTop10PageViews>;
+	get_page_views_analytics_top_10_by_satellite: ActorMethod<
+		[GetAnalyticsBySatellite],
+		AnalyticsTop10PageViewsBySatellite
+	>;
 	get_performance_metrics: ActorMethod<[GetAnalytics], Array<[AnalyticKey, PerformanceMetric]>>;
 	get_performance_metrics_analytics_web_vitals: ActorMethod<
 		[GetAnalytics],
 		AnalyticsWebVitalsPerformanceMetrics
 	>;
+	get_performance_metrics_analytics_web_vitals_by_satellite: ActorMethod<
+		[GetAnalyticsBySatellite],
+		AnalyticsWebVitalsPerformanceMetricsBySatellite
+	>;
 	get_track_events: ActorMethod<[GetAnalytics], Array<[AnalyticKey, TrackEvent]>>;
 	get_track_events_analytics: ActorMethod<[GetAnalytics], AnalyticsTrackEvents>;
+	get_track_events_analytics_by_satellite: ActorMethod<
+		[GetAnalyticsBySatellite],
+		AnalyticsTrackEventsBySatellite
+	>;
 	list_controllers: ActorMethod<[], Array<[Principal, Controller]>>;
 	list_satellite_configs: ActorMethod<[], Array<[Principal, OrbiterSatelliteConfig]>>;
 	memory_size: ActorMethod<[], MemorySize>;
 	set_controllers: ActorMethod<[SetControllersArgs], Array<[Principal, Controller]>>;
-	set_page_view: ActorMethod<[AnalyticKey, SetPageView], Result>;
+	set_error_report: ActorMethod<[AnalyticKey, SetErrorReport], Result>;
+	set_error_reports: ActorMethod<[Array<[AnalyticKey, SetErrorReport]>], Result_1>;
+	set_page_view: ActorMethod<[AnalyticKey, SetPageView], Result_2>;
 	set_page_views: ActorMethod<[Array<[AnalyticKey, SetPageView]>], Result_1>;
-	set_performance_metric: ActorMethod<[AnalyticKey, SetPerformanceMetric], Result_2>;
+	set_performance_metric: ActorMethod<[AnalyticKey, SetPerformanceMetric], Result_3>;
 	set_performance_metrics: ActorMethod<[Array<[AnalyticKey, SetPerformanceMetric]>], Result_1>;
 	set_satellite_configs: ActorMethod<
 		[Array<[Principal, SetSatelliteConfig]>],
 		Array<[Principal, OrbiterSatelliteConfig]>
 	>;
-	set_track_event: ActorMethod<[AnalyticKey, SetTrackEvent], Result_3>;
+	set_track_event: ActorMethod<[AnalyticKey, SetTrackEvent], Result_4>;
 	set_track_events: ActorMethod<[Array<[AnalyticKey, SetTrackEvent]>], Result_1>;
 	version: ActorMethod<[], string>;
 }
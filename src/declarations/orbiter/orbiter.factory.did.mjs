@@ -28,6 +28,33 @@ export const idlFactory = ({ IDL }) => {
 		key: IDL.Text,
 		collected_at: IDL.Nat64
 	});
+	const ErrorReport = IDL.Record({
+		updated_at: IDL.Nat64,
+		session_id: IDL.Text,
+		href: IDL.Text,
+		created_at: IDL.Nat64,
+		satellite_id: IDL.Principal,
+		stack_hash: IDL.Text,
+		version: IDL.Opt(IDL.Nat64),
+		message: IDL.Text,
+		user_agent: IDL.Opt(IDL.Text)
+	});
+	const CalendarDate = IDL.Record({
+		day: IDL.Nat8,
+		month: IDL.Nat8,
+		year: IDL.Int32
+	});
+	const AnalyticsErrorReportsGroup = IDL.Record({
+		total: IDL.Nat32,
+		last_seen_at: IDL.Nat64,
+		stack_hash: IDL.Text,
+		daily_total: IDL.Vec(IDL.Tuple(CalendarDate, IDL.Nat32)),
+		message: IDL.Text,
+		unique_sessions: IDL.Nat64
+	});
+	const AnalyticsErrorReports = IDL.Record({
+		groups: IDL.Vec(AnalyticsErrorReportsGroup)
+	});
 	const PageViewDevice = IDL.Record({
 		inner_height: IDL.Nat16,
 		inner_width: IDL.Nat16
@@ -61,11 +88,6 @@ export const idlFactory = ({ IDL }) => {
 		browsers: AnalyticsBrowsersPageViews,
 		devices: AnalyticsDevicesPageViews
 	});
-	const CalendarDate = IDL.Record({
-		day: IDL.Nat8,
-		month: IDL.Nat8,
-		year: IDL.Int32
-	});
 	const AnalyticsMetricsPageViews = IDL.Record({
 		bounce_rate: IDL.Float64,
 		average_page_views_per_session: IDL.Float64,
@@ -134,15 +156,28 @@ export const idlFactory = ({ IDL }) => {
 		total: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Nat32))
 	});
 	const OrbiterSatelliteFeatures = IDL.Record({
+		error_reports: IDL.Opt(IDL.Bool),
 		performance_metrics: IDL.Bool,
 		track_events: IDL.Bool,
 		page_views: IDL.Bool
 	});
+	const RateConfig = IDL.Record({
+		max_tokens: IDL.Nat64,
+		time_per_token_ns: IDL.Nat64
+	});
+	const OrbiterSatelliteSampling = IDL.Record({
+		performance_metrics: IDL.Opt(IDL.Nat8),
+		track_events: IDL.Opt(IDL.Nat8),
+		page_views: IDL.Opt(IDL.Nat8)
+	});
 	const OrbiterSatelliteConfig = IDL.Record({
 		updated_at: IDL.Nat64,
 		features: IDL.Opt(OrbiterSatelliteFeatures),
 		created_at: IDL.Nat64,
-		version: IDL.Opt(IDL.Nat64)
+		anonymize: IDL.Opt(IDL.Bool),
+		version: IDL.Opt(IDL.Nat64),
+		rate_config: IDL.Opt(RateConfig),
+		sampling: IDL.Opt(OrbiterSatelliteSampling)
 	});
 	const MemorySize = IDL.Record({ stable: IDL.Nat64, heap: IDL.Nat64 });
 	const SetController = IDL.Record({
@@ -154,6 +189,20 @@ export const idlFactory = ({ IDL }) => {
 		controller: SetController,
 		controllers: IDL.Vec(IDL.Principal)
 	});
+	const SetErrorReport = IDL.Record({
+		session_id: IDL.Text,
+		href: IDL.Text,
+		satellite_id: IDL.Principal,
+		stack_hash: IDL.Text,
+		version: IDL.Opt(IDL.Nat64),
+		message: IDL.Text,
+		user_agent: IDL.Opt(IDL.Text)
+	});
+	const Result = IDL.Variant({ Ok: ErrorReport, Err: IDL.Text });
+	const Result_1 = IDL.Variant({
+		Ok: IDL.Null,
+		Err: IDL.Vec(IDL.Tuple(AnalyticKey, IDL.Text))
+	});
 	const SetPageView = IDL.Record({
 		title: IDL.Text,
 		updated_at: IDL.Opt(IDL.Nat64),
@@ -166,11 +215,7 @@ export const idlFactory = ({ IDL }) => {
 		version: IDL.Opt(IDL.Nat64),
 		user_agent: IDL.Opt(IDL.Text)
 	});
-	const Result = IDL.Variant({ Ok: PageView, Err: IDL.Text });
-	const Result_1 = IDL.Variant({
-		Ok: IDL.Null,
-		Err: IDL.Vec(IDL.Tuple(AnalyticKey, IDL.Text))
-	});
+	const Result_2 = IDL.Variant({ Ok: PageView, Err: IDL.Text });
 	const SetPerformanceMetric = IDL.Record({
 		session_id: IDL.Text,
 		data: PerformanceData,
@@ -180,10 +225,13 @@ export const idlFactory = ({ IDL }) => {
 		version: IDL.Opt(IDL.Nat64),
 		user_agent: IDL.Opt(IDL.Text)
 	});
-	const Result_2 = IDL.Variant({ Ok: PerformanceMetric, Err: IDL.Text });
+	const Result_3 = IDL.Variant({ Ok: PerformanceMetric, Err: IDL.Text });
 	const SetSatelliteConfig = IDL.Record({
 		features: IDL.Opt(OrbiterSatelliteFeatures),
-		version: IDL.Opt(IDL.Nat64)
+		anonymize: IDL.Opt(IDL.Bool),
+		version: IDL.Opt(IDL.Nat64),
+		rate_config: IDL.Opt(RateConfig),
+		sampling: IDL.Opt(OrbiterSatelliteSampling)
 	});
 	const SetTrackEvent = IDL.Record({
 		updated_at: IDL.Opt(IDL.Nat64),
@@ -194,7 +242,36 @@ export const idlFactory = ({ IDL }) => {
 		version: IDL.Opt(IDL.Nat64),
 		user_agent: IDL.Opt(IDL.Text)
 	});
-	const Result_3 = IDL.Variant({ Ok: TrackEvent, Err: IDL.Text });
+	const Result_4 = IDL.Variant({ Ok: TrackEvent, Err: IDL.Text });
+	const GetAnalyticsBySatellite = IDL.Record({
+		to: IDL.Opt(IDL.Nat64),
+		from: IDL.Opt(IDL.Nat64),
+		satellite_ids: IDL.Vec(IDL.Principal)
+	});
+	const AnalyticsMetricsPageViewsBySatellite = IDL.Record({
+		total: AnalyticsMetricsPageViews,
+		satellites: IDL.Vec(IDL.Tuple(IDL.Principal, AnalyticsMetricsPageViews))
+	});
+	const AnalyticsTop10PageViewsBySatellite = IDL.Record({
+		total: AnalyticsTop10PageViews,
+		satellites: IDL.Vec(IDL.Tuple(IDL.Principal, AnalyticsTop10PageViews))
+	});
+	const AnalyticsClientsPageViewsBySatellite = IDL.Record({
+		total: AnalyticsClientsPageViews,
+		satellites: IDL.Vec(IDL.Tuple(IDL.Principal, AnalyticsClientsPageViews))
+	});
+	const AnalyticsTrackEventsBySatellite = IDL.Record({
+		total: AnalyticsTrackEvents,
+		satellites: IDL.Vec(IDL.Tuple(IDL.Principal, AnalyticsTrackEvents))
+	});
+	const AnalyticsWebVitalsPerformanceMetricsBySatellite = IDL.Record({
+		total: AnalyticsWebVitalsPerformanceMetrics,
+		satellites: IDL.Vec(IDL.Tuple(IDL.Principal, AnalyticsWebVitalsPerformanceMetrics))
+	});
+	const AnalyticsErrorReportsBySatellite = IDL.Record({
+		total: AnalyticsErrorReports,
+		satellites: IDL.Vec(IDL.Tuple(IDL.Principal, AnalyticsErrorReports))
+	});
 	return IDL.Service({
 		del_controllers: IDL.Func(
 			[DeleteControllersArgs],
@@ -203,6 +280,17 @@ export const idlFactory = ({ IDL }) => {
 		),
 		del_satellite_config: IDL.Func([IDL.Principal, DelSatelliteConfig], [], []),
 		deposit_cycles: IDL.Func([DepositCyclesArgs], [], []),
+		get_error_reports: IDL.Func(
+			[GetAnalytics],
+			[IDL.Vec(IDL.Tuple(AnalyticKey, ErrorReport))],
+			['query']
+		),
+		get_error_reports_analytics: IDL.Func([GetAnalytics], [AnalyticsErrorReports], ['query']),
+		get_error_reports_analytics_by_satellite: IDL.Func(
+			[GetAnalyticsBySatellite],
+			[AnalyticsErrorReportsBySatellite],
+			['query']
+		),
 		get_page_views: IDL.Func(
 			[GetAnalytics],
 			[IDL.Vec(IDL.Tuple(AnalyticKey, PageView))],
@@ -213,12 +301,27 @@ export const idlFactory = ({ IDL }) => {
 			[AnalyticsClientsPageViews],
 			['query']
 		),
+		get_page_views_analytics_clients_by_satellite: IDL.Func(
+			[GetAnalyticsBySatellite],
+			[AnalyticsClientsPageViewsBySatellite],
+			['query']
+		),
 		get_page_views_analytics_metrics: IDL.Func(
 			[GetAnalytics],
 			[AnalyticsMetricsPageViews],
 			['query']
 		),
+		get_page_views_analytics_metrics_by_satellite: IDL.Func(
+			[GetAnalyticsBySatellite],
+			[AnalyticsMetricsPageViewsBySatellite],
+			['query']
+		),
 		get_page_views_analytics_top_10: IDL.Func([GetAnalytics], [AnalyticsTop10PageViews], ['query']),
+		get_page_views_analytics_top_10_by_satellite: IDL.Func(
+			[GetAnalyticsBySatellite],
+			[AnalyticsTop10PageViewsBySatellite],
+			['query']
+		),
 		get_performance_metrics: IDL.Func(
 			[GetAnalytics],
 			[IDL.Vec(IDL.Tuple(AnalyticKey, PerformanceMetric))],
@@ -229,12 +332,22 @@ export const idlFactory = ({ IDL }) => {
 			[AnalyticsWebVitalsPerformanceMetrics],
 			['query']
 		),
+		get_performance_metrics_analytics_web_vitals_by_satellite: IDL.Func(
+			[GetAnalyticsBySatellite],
+			[AnalyticsWebVitalsPerformanceMetricsBySatellite],
+			['query']
+		),
 		get_track_events: IDL.Func(
 			[GetAnalytics],
 			[IDL.Vec(IDL.Tuple(AnalyticKey, TrackEvent))],
 			['query']
 		),
 		get_track_events_analytics: IDL.Func([GetAnalytics], [AnalyticsTrackEvents], ['query']),
+		get_track_events_analytics_by_satellite: IDL.Func(
+			[GetAnalyticsBySatellite],
+			[AnalyticsTrackEventsBySatellite],
+			['query']
+		),
 		list_controllers: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Principal, Controller))], ['query']),
 		list_satellite_configs: IDL.Func(
 			[],
@@ -247,9 +360,11 @@ export const idlFactory = ({ IDL }) => {
 			[IDL.Vec(IDL.Tuple(IDL.Principal, Controller))],
 			[]
 		),
-		set_page_view: IDL.Func([AnalyticKey, SetPageView], [Result], []),
+		set_error_report: IDL.Func([AnalyticKey, SetErrorReport], [Result], []),
+		set_error_reports: IDL.Func([IDL.Vec(IDL.Tuple(AnalyticKey, SetErrorReport))], [Result_1], []),
+		set_page_view: IDL.Func([AnalyticKey, SetPageView], [Result_2], []),
 		set_page_views: IDL.Func([IDL.Vec(IDL.Tuple(AnalyticKey, SetPageView))], [Result_1], []),
-		set_performance_metric: IDL.Func([AnalyticKey, SetPerformanceMetric], [Result_2], []),
+		set_performance_metric: IDL.Func([AnalyticKey, SetPerformanceMetric], [Result_3], []),
 		set_performance_metrics: IDL.Func(
 			[IDL.Vec(IDL.Tuple(AnalyticKey, SetPerformanceMetric))],
 			[Result_1],
@@ -260,7 +375,7 @@ export const idlFactory = ({ IDL }) => {
 			[IDL.Vec(IDL.Tuple(IDL.Principal, OrbiterSatelliteConfig))],
 			[]
 		),
-		set_track_event: IDL.Func([AnalyticKey, SetTrackEvent], [Result_3], []),
+		set_track_event: IDL.Func([AnalyticKey, SetTrackEvent], [Result_4], []),
 		set_track_events: IDL.Func([IDL.Vec(IDL.Tuple(AnalyticKey, SetTrackEvent))], [Result_1], []),
 		version: IDL.Func([], [IDL.Text], ['query'])
 	});
@@ -2,6 +2,20 @@ import type { ActorMethod } from '@dfinity/agent';
 import type { IDL } from '@dfinity/candid';
 import type { Principal } from '@dfinity/principal';
 
+export interface AnalyticsConfig {
+	orbiter_id: [] | [Principal];
+	rate_config: [] | [RateConfig];
+}
+export interface ApiKey {
+	description: [] | [string];
+	collections: Array<string>;
+	created_at: bigint;
+	expires_at: [] | [bigint];
+}
+export interface ApprovalsConfig {
+	enabled: boolean;
+	confirmation_window_ns: [] | [bigint];
+}
 export interface AssetEncodingNoContent {
 	modified: bigint;
 	sha256: Uint8Array | number[];
@@ -13,8 +27,21 @@ export interface AssetKey {
 	owner: Principal;
 	name: string;
 	description: [] | [string];
+	publish_at: [] | [bigint];
+	moderation: [] | [ModerationStatus];
+	unpublish_at: [] | [bigint];
 	full_path: string;
 }
+export interface AssetLinksReport {
+	orphans: Array<string>;
+	orphans_count: bigint;
+	parsed_assets: bigint;
+	links_count: bigint;
+	completed_at: [] | [bigint];
+	broken_links_count: bigint;
+	started_at: bigint;
+	broken_links: Array<BrokenLink>;
+}
 export interface AssetNoContent {
 	key: AssetKey;
 	updated_at: bigint;
@@ -23,22 +50,114 @@ export interface AssetNoContent {
 	created_at: bigint;
 	version: [] | [bigint];
 }
+export interface AuditBlock {
+	hash: Uint8Array | number[];
+	operation: AuditOperation;
+	timestamp: bigint;
+	caller: Principal;
+	parent_hash: [] | [Uint8Array | number[]];
+}
+export interface AuditBlocks {
+	log_length: bigint;
+	blocks: Array<[bigint, AuditBlock]>;
+}
+export type AuditOperation =
+	| { Upgrade: { version: string } }
+	| { SetDbConfig: null }
+	| {
+			SetControllers: {
+				controllers: Array<Principal>;
+				scope: ControllerScope;
+			};
+	  }
+	| { AcknowledgeSafeMode: null }
+	| { ApplyConfigBundle: null }
+	| { SetCustomDomain: { domain_name: string } }
+	| { DelCustomDomain: { domain_name: string } }
+	| { SetStorageConfig: null }
+	| { DelRule: { collection: string; rules_type: RulesType } }
+	| { SetRule: { collection: string; rules_type: RulesType } }
+	| { SetAuthConfig: null }
+	| { DelControllers: { controllers: Array<Principal> } };
 export interface AuthenticationConfig {
 	internet_identity: [] | [AuthenticationConfigInternetIdentity];
 }
 export interface AuthenticationConfigInternetIdentity {
 	derivation_origin: [] | [string];
 }
+export interface BrokenLink {
+	source: string;
+	target: string;
+}
+export interface CanaryDeploymentStatus {
+	updated_at: bigint;
+	created_at: bigint;
+	session: [] | [StorageConfigRewriteSession];
+	paths: Array<string>;
+	percentage: number;
+}
+export interface CertifiedPath {
+	url: string;
+	sha256: Uint8Array | number[];
+}
+export interface ClientMetadata {
+	features: ManifestFeatures;
+	extension_candid: string;
+	version: string;
+	extensions: Array<ExtensionKey>;
+	candid: string;
+	custom_endpoints: Array<string>;
+}
+export type CollectionJobKind = { CompactCollection: null } | { RebuildIndexes: null };
+export interface CollectionJobReport {
+	collection: string;
+	processed_docs: bigint;
+	kind: CollectionJobKind;
+	total_docs: bigint;
+	error: [] | [string];
+	completed_at: [] | [bigint];
+	rewritten_docs: bigint;
+	reclaimed_bytes: bigint;
+	started_at: bigint;
+}
+export interface CollectionStats {
+	docs: [] | [ItemsStats];
+	assets: [] | [ItemsStats];
+}
+export interface CollectionVersion {
+	last_modified: bigint;
+	changes: bigint;
+}
 export interface CommitBatch {
 	batch_id: bigint;
 	headers: Array<[string, string]>;
 	chunk_ids: Array<bigint>;
 }
+export interface CompactionReport {
+	scanned_chunks: bigint;
+	removed_chunks: bigint;
+	completed_at: [] | [bigint];
+	reclaimed_bytes: bigint;
+	started_at: bigint;
+}
+export type Compression = { Deflate: null };
 export interface Config {
 	db: [] | [DbConfig];
 	authentication: [] | [AuthenticationConfig];
 	storage: StorageConfig;
 }
+export interface ConfigBundle {
+	db: [] | [DbConfig];
+	authentication: [] | [AuthenticationConfig];
+	custom_domains: Array<[string, [] | [string]]>;
+	storage: StorageConfig;
+	version: bigint;
+	rules: ConfigBundleRules;
+}
+export interface ConfigBundleRules {
+	db: Array<[string, SetRule]>;
+	storage: Array<[string, SetRule]>;
+}
 export interface ConfigMaxMemorySize {
 	stable: [] | [bigint];
 	heap: [] | [bigint];
@@ -50,19 +169,109 @@ export interface Controller {
 	scope: ControllerScope;
 	expires_at: [] | [bigint];
 }
+export type ControllerAction =
+	| {
+			DelFilteredAssets: {
+				filter: ListParams;
+				collection: string;
+			};
+	  }
+	| { SetControllers: SetControllersArgs }
+	| {
+			DelFilteredDocs: {
+				filter: ListParams;
+				collection: string;
+			};
+	  }
+	| { DelAssets: { collection: string } }
+	| { ApplyConfigBundle: ConfigBundle }
+	| { DelDocs: { collection: string } }
+	| {
+			SetAssetOwner: {
+				collection: string;
+				owner: SetAssetOwner;
+				full_path: string;
+			};
+	  }
+	| {
+			DelRule: {
+				collection: string;
+				rule: DelRule;
+				rules_type: RulesType;
+			};
+	  }
+	| {
+			SetDocOwner: {
+				key: string;
+				collection: string;
+				owner: SetDocOwner;
+			};
+	  }
+	| {
+			SetRule: {
+				collection: string;
+				rule: SetRule;
+				rules_type: RulesType;
+			};
+	  }
+	| { SetApprovalsConfig: ApprovalsConfig }
+	| { DelControllers: DeleteControllersArgs };
 export type ControllerScope = { Write: null } | { Admin: null };
+export interface CreateApiKeyResult {
+	id: string;
+	secret: string;
+}
+export interface CreateUploadTokenResult {
+	id: string;
+	secret: string;
+}
 export interface CustomDomain {
 	updated_at: bigint;
 	created_at: bigint;
 	version: [] | [bigint];
 	bn_id: [] | [string];
 }
+export interface DailyEndpointTrace {
+	day: bigint;
+	trace: EndpointTrace;
+	endpoint: string;
+}
 export interface DbConfig {
+	proof_of_work: [] | [Array<DbConfigProofOfWork>];
+	aggregates: [] | [Array<DbConfigAggregate>];
+	views: [] | [Array<DbConfigView>];
+	list_limits: [] | [ListLimits];
 	max_memory_size: [] | [ConfigMaxMemorySize];
+	history_collections: [] | [Array<string>];
+	attachments: [] | [Array<DbConfigAttachment>];
+	http_collections: [] | [Array<string>];
+}
+export interface DbConfigAggregate {
+	sum_fields: [] | [Array<string>];
+	collection: string;
+	target_collection: string;
+	parent_field: string;
+}
+export interface DbConfigAttachment {
+	storage_collection: string;
+	collection: string;
+	cascade_delete: [] | [boolean];
+}
+export interface DbConfigProofOfWork {
+	collection: string;
+	difficulty: number;
+}
+export interface DbConfigView {
+	collection: string;
+	fields: Array<string>;
+	sources: Array<string>;
 }
 export interface DelDoc {
 	version: [] | [bigint];
 }
+export interface DelFeatureFlag {
+	version: [] | [bigint];
+}
 export interface DelRule {
 	version: [] | [bigint];
 }
@@ -73,13 +282,99 @@ export interface DepositCyclesArgs {
 	cycles: bigint;
 	destination_id: Principal;
 }
+export interface DiffAssetsArgs {
+	collection: string;
+	assets: Array<[string, Uint8Array | number[]]>;
+}
 export interface Doc {
 	updated_at: bigint;
 	owner: Principal;
 	data: Uint8Array | number[];
 	description: [] | [string];
 	created_at: bigint;
+	publish_at: [] | [bigint];
 	version: [] | [bigint];
+	moderation: [] | [ModerationStatus];
+	unpublish_at: [] | [bigint];
+	attachments: [] | [Array<string>];
+}
+export interface DocChunk {
+	content: Uint8Array | number[];
+	chunks_length: bigint;
+	version: [] | [bigint];
+	data_length: bigint;
+}
+export type DocPatch = { MergePatch: string } | { JsonPatch: string };
+export type DryRunOperation =
+	| { DelFilteredAssets: { collection: string; filter: ListParams } }
+	| { DelFilteredDocs: { collection: string; filter: ListParams } }
+	| { DelAssets: { collection: string } }
+	| { PurgeRetention: { collection: string; rules_type: RulesType } }
+	| { DelDocs: { collection: string } }
+	| { DelRule: { collection: string; rule: DelRule; rules_type: RulesType } }
+	| { SetRule: { collection: string; rule: SetRule; rules_type: RulesType } };
+export interface DryRunReport {
+	keys: Array<string>;
+	rule: [] | [Rule];
+	error: [] | [string];
+}
+export interface EndpointTrace {
+	calls: bigint;
+	instructions: bigint;
+	max_instructions: bigint;
+}
+export interface ExportChunk {
+	cursor: [] | [Uint8Array | number[]];
+	data: Uint8Array | number[];
+}
+export interface ExportChunkArgs {
+	cursor: [] | [Uint8Array | number[]];
+	section: TransferSection;
+}
+export interface Extension {
+	updated_at: bigint;
+	created_at: bigint;
+	version: [] | [bigint];
+	enabled: boolean;
+	settings: Array<[string, string]>;
+}
+export type ExtensionKey = { Traces: null };
+export interface FeatureFlag {
+	updated_at: bigint;
+	rule: FeatureFlagRule;
+	description: [] | [string];
+	created_at: bigint;
+	version: [] | [bigint];
+}
+export type FeatureFlagRole =
+	| { Anonymous: null }
+	| { User: null }
+	| { Admin: null }
+	| { Controller: null };
+export type FeatureFlagRule =
+	| { Boolean: boolean }
+	| { Targeted: Array<FeatureFlagRole> }
+	| { Percentage: number };
+export interface Health {
+	pending_upload_batches: bigint;
+	certification_pending: boolean;
+	memory_size: MemorySize;
+	safe_mode: boolean;
+	version: string;
+	cycles: bigint;
+	upgraded_at: [] | [bigint];
+	timestamp: bigint;
+	pending_deleted_chunks: bigint;
+	pending_deleted_docs: bigint;
+}
+export interface HttpHeader {
+	value: string;
+	name: string;
+}
+export interface HttpOutcallResponse {
+	status: bigint;
+	body: Uint8Array | number[];
+	headers: Array<HttpHeader>;
 }
 export interface HttpRequest {
 	url: string;
@@ -91,25 +386,129 @@ export interface HttpRequest {
 export interface HttpResponse {
 	body: Uint8Array | number[];
 	headers: Array<[string, string]>;
+	upgrade: [] | [boolean];
 	streaming_strategy: [] | [StreamingStrategy];
 	status_code: number;
 }
+export interface Icrc21ConsentInfo {
+	metadata: Icrc21ConsentMessageMetadata;
+	consent_message: Icrc21ConsentMessage;
+}
+export type Icrc21ConsentMessage =
+	| {
+			LineDisplayMessage: { pages: Array<Icrc21LineDisplayPage> };
+	  }
+	| { GenericDisplayMessage: string };
+export interface Icrc21ConsentMessageMetadata {
+	utc_offset_minutes: [] | [number];
+	language: string;
+}
+export interface Icrc21ConsentMessageRequest {
+	arg: Uint8Array | number[];
+	method: string;
+	user_preferences: Icrc21ConsentMessageSpec;
+}
+export interface Icrc21ConsentMessageSpec {
+	metadata: Icrc21ConsentMessageMetadata;
+	device_spec: [] | [Icrc21DeviceSpec];
+}
+export type Icrc21DeviceSpec =
+	| { GenericDisplay: null }
+	| {
+			LineDisplay: {
+				characters_per_line: number;
+				lines_per_page: number;
+			};
+	  };
+export type Icrc21Error =
+	| {
+			GenericError: { description: string; error_code: bigint };
+	  }
+	| { InsufficientPayment: Icrc21ErrorInfo }
+	| { UnsupportedCanisterCall: Icrc21ErrorInfo }
+	| { ConsentMessageUnavailable: Icrc21ErrorInfo };
+export interface Icrc21ErrorInfo {
+	description: string;
+}
+export interface Icrc21LineDisplayPage {
+	lines: Array<string>;
+}
+export interface ImportChunkArgs {
+	data: Uint8Array | number[];
+	section: TransferSection;
+}
+export interface ImportUser {
+	data: Uint8Array | number[];
+	created_at: [] | [bigint];
+	user_id: Principal;
+}
+export type ImportUserConflict = { Reject: null } | { Skip: null } | { Overwrite: null };
+export interface ImportUsers {
+	conflict: [] | [ImportUserConflict];
+	users: Array<ImportUser>;
+}
+export interface ImportUsersReport {
+	imported: Array<Principal>;
+	skipped: Array<Principal>;
+}
 export interface InitAssetKey {
 	token: [] | [string];
 	collection: string;
 	name: string;
 	description: [] | [string];
+	publish_at: [] | [bigint];
+	unpublish_at: [] | [bigint];
 	encoding_type: [] | [string];
 	full_path: string;
 }
 export interface InitUploadResult {
 	batch_id: bigint;
 }
+export type IntegrityIssue =
+	| { AssetsCount: { found: bigint; counted: bigint; collection: string } }
+	| { UncertifiedAsset: { collection: string; full_path: string } }
+	| { MissingRule: { collection: string; rules_type: RulesType } }
+	| { DocsCount: { found: bigint; counted: bigint; collection: string } }
+	| { MissingContentChunks: { collection: string; full_path: string } };
+export interface IntegrityReport {
+	issues_count: bigint;
+	checked_collections: bigint;
+	version: string;
+	issues: Array<IntegrityIssue>;
+	completed_at: [] | [bigint];
+	started_at: bigint;
+}
+export interface ItemsStats {
+	max_updated_at: [] | [bigint];
+	top_owners: Array<[Principal, OwnerCounters]>;
+	items_count: bigint;
+	items_bytes: bigint;
+	min_created_at: [] | [bigint];
+}
+export type KeyCollation = { Binary: null } | { CaseInsensitive: null } | { Numeric: null };
+export interface ListAuditBlocks {
+	start: bigint;
+	length: bigint;
+}
+export type ListFilter =
+	| { Or: Array<ListFilter> }
+	| { And: Array<ListFilter> }
+	| { Key: TextMatcher }
+	| { Not: ListFilter }
+	| { UpdatedAt: TimestampMatcher }
+	| { Description: TextMatcher }
+	| { Owner: PrincipalMatcher }
+	| { CreatedAt: TimestampMatcher };
+export interface ListLimits {
+	max_scanned_items: [] | [bigint];
+	max_returned_bytes: [] | [bigint];
+}
 export interface ListMatcher {
 	key: [] | [string];
 	updated_at: [] | [TimestampMatcher];
 	description: [] | [string];
 	created_at: [] | [TimestampMatcher];
+	filter: [] | [ListFilter];
 }
 export interface ListOrder {
 	field: ListOrderField;
@@ -129,44 +528,171 @@ export interface ListParams {
 export interface ListResults {
 	matches_pages: [] | [bigint];
 	matches_length: bigint;
+	truncated: [] | [ListTruncated];
+	version: [] | [CollectionVersion];
 	items_page: [] | [bigint];
 	items: Array<[string, AssetNoContent]>;
+	resume_after: [] | [string];
 	items_length: bigint;
 }
 export interface ListResults_1 {
 	matches_pages: [] | [bigint];
 	matches_length: bigint;
+	truncated: [] | [ListTruncated];
+	version: [] | [CollectionVersion];
 	items_page: [] | [bigint];
 	items: Array<[string, Doc]>;
+	resume_after: [] | [string];
 	items_length: bigint;
 }
+export type ListTruncated = { MaxScannedItems: null } | { MaxReturnedBytes: null };
+export interface ManifestFeatures {
+	authentication: boolean;
+	traces: boolean;
+	analytics: boolean;
+	moderation: boolean;
+	feature_flags: boolean;
+}
 export type Memory = { Heap: null } | { Stable: null };
+export type MemoryRegion =
+	| { Db: null }
+	| { Upgrades: null }
+	| { Assets: null }
+	| { Audit: null }
+	| { Revisions: null }
+	| { ContentChunks: null };
+export interface MemoryRegionStats {
+	region: MemoryRegion;
+	allocated: bigint;
+	used: bigint;
+	entries: bigint;
+	fragmented: bigint;
+}
 export interface MemorySize {
 	stable: bigint;
 	heap: bigint;
 }
+export interface ModerationConfig {
+	db: Array<string>;
+	storage: Array<string>;
+}
+export type ModerationStatus = { Approved: null } | { Rejected: null } | { Pending: null };
+export interface OwnerCounters {
+	items_count: bigint;
+	items_bytes: bigint;
+}
+export interface Page {
+	next_cursor: [] | [string];
+	items: Array<[string, AssetNoContent]>;
+	total_estimate: bigint;
+}
+export interface Page_1 {
+	next_cursor: [] | [string];
+	items: Array<[Principal, Controller]>;
+	total_estimate: bigint;
+}
+export interface Page_2 {
+	next_cursor: [] | [string];
+	items: Array<[string, Doc]>;
+	total_estimate: bigint;
+}
+export interface PatchDoc {
+	version: [] | [bigint];
+	patch: DocPatch;
+}
+export interface PendingAction {
+	action: ControllerAction;
+	expires_at: bigint;
+	proposed_at: bigint;
+	proposed_by: Principal;
+}
 export type Permission =
 	| { Controllers: null }
 	| { Private: null }
 	| { Public: null }
-	| { Managed: null };
+	| { Managed: null }
+	| { TokenGated: TokenGate };
+export type PrincipalMatcher = { In: Array<Principal> } | { Equal: Principal };
+export interface QueryEstimate {
+	matches_length: bigint;
+	truncated: [] | [ListTruncated];
+	scanned_items: bigint;
+	returned_bytes: bigint;
+	items_length: bigint;
+}
+export interface QuotaStatus {
+	updated_at: bigint;
+	memory_size: bigint;
+	warnings: Array<QuotaWarning>;
+	docs_count: bigint;
+}
+export interface QuotaThresholds {
+	memory_size: [] | [bigint];
+	docs_count: [] | [bigint];
+}
+export type QuotaWarning = { DocsCount: null } | { MemorySize: null };
 export interface RateConfig {
 	max_tokens: bigint;
 	time_per_token_ns: bigint;
 }
+export interface RateLimitConfig {
+	http: [] | [RateConfig];
+	anonymous: [] | [RateConfig];
+	caller: RateConfig;
+}
+export type Result = { Ok: Icrc21ConsentInfo } | { Err: Icrc21Error };
+export interface RetentionPolicy {
+	max_items: [] | [bigint];
+	max_age: [] | [bigint];
+}
 export interface Rule {
 	max_capacity: [] | [number];
 	memory: [] | [Memory];
 	updated_at: bigint;
+	user_quota: [] | [UserQuota];
 	max_size: [] | [bigint];
+	collation: [] | [KeyCollation];
 	read: Permission;
+	retention: [] | [RetentionPolicy];
 	created_at: bigint;
+	compression: [] | [Compression];
 	version: [] | [bigint];
 	mutable_permissions: [] | [boolean];
 	rate_config: [] | [RateConfig];
 	write: Permission;
 }
 export type RulesType = { Db: null } | { Storage: null };
+export type SelfTestIssue =
+	| { NoAdminController: null }
+	| { MissingCollection: { collection: string } }
+	| {
+			MissingSystemCollection: {
+				collection: string;
+				rules_type: RulesType;
+			};
+	  }
+	| { MissingRule: { collection: string } };
+export interface SelfTestReport {
+	safe_mode: boolean;
+	version: string;
+	issues: Array<SelfTestIssue>;
+	acknowledged_at: [] | [bigint];
+	acknowledged_by: [] | [Principal];
+	checked_at: bigint;
+}
+export interface SetApiKey {
+	description: [] | [string];
+	collections: Array<string>;
+	expires_at: [] | [bigint];
+}
+export interface SetAssetOwner {
+	owner: Principal;
+	version: [] | [bigint];
+}
+export interface SetCanaryDeployment {
+	session: [] | [StorageConfigRewriteSession];
+	percentage: number;
+}
 export interface SetController {
 	metadata: Array<[string, string]>;
 	scope: ControllerScope;
@@ -179,32 +705,94 @@ export interface SetControllersArgs {
 export interface SetDoc {
 	data: Uint8Array | number[];
 	description: [] | [string];
+	publish_at: [] | [bigint];
+	version: [] | [bigint];
+	unpublish_at: [] | [bigint];
+	attachments: [] | [Array<string>];
+}
+export interface SetDocOwner {
+	owner: Principal;
+	version: [] | [bigint];
+}
+export interface SetExtensionConfig {
+	version: [] | [bigint];
+	enabled: boolean;
+	settings: Array<[string, string]>;
+}
+export interface SetFeatureFlag {
+	rule: FeatureFlagRule;
+	description: [] | [string];
+	version: [] | [bigint];
+}
+export interface SetModeration {
+	status: ModerationStatus;
 	version: [] | [bigint];
 }
 export interface SetRule {
 	max_capacity: [] | [number];
 	memory: [] | [Memory];
+	user_quota: [] | [UserQuota];
 	max_size: [] | [bigint];
+	collation: [] | [KeyCollation];
 	read: Permission;
+	retention: [] | [RetentionPolicy];
 	version: [] | [bigint];
 	mutable_permissions: [] | [boolean];
 	rate_config: [] | [RateConfig];
 	write: Permission;
 }
+export interface SetUploadToken {
+	collection: string;
+	owner: Principal;
+	expires_at: [] | [bigint];
+}
+export interface SetWebhook {
+	url: string;
+	collections: [] | [Array<string>];
+	secret: [] | [string];
+	enabled: boolean;
+	events: Array<WebhookEvent>;
+}
 export interface StorageConfig {
 	iframe: [] | [StorageConfigIFrame];
+	csp_nonce: [] | [boolean];
+	locales: [] | [StorageConfigLocales];
+	wildcard_domains: [] | [Array<[string, string]>];
+	list_limits: [] | [ListLimits];
+	delegations: [] | [Array<[string, StorageConfigDelegation]>];
 	rewrites: Array<[string, string]>;
 	headers: Array<[string, Array<[string, string]>]>;
 	max_memory_size: [] | [ConfigMaxMemorySize];
+	max_response_body_size: [] | [bigint];
 	raw_access: [] | [StorageConfigRawAccess];
+	weighted_rewrites: [] | [Array<[string, StorageConfigWeightedRewrite]>];
 	redirects: [] | [Array<[string, StorageConfigRedirect]>];
 }
+export interface StorageConfigDelegation {
+	raw: [] | [boolean];
+	domain: [] | [string];
+	canister_id: Principal;
+}
 export type StorageConfigIFrame = { Deny: null } | { AllowAny: null } | { SameOrigin: null };
+export interface StorageConfigLocales {
+	default_locale: string;
+	cookie: [] | [string];
+	supported_locales: Array<string>;
+}
 export type StorageConfigRawAccess = { Deny: null } | { Allow: null };
 export interface StorageConfigRedirect {
 	status_code: number;
 	location: string;
 }
+export type StorageConfigRewriteSession = { Header: string } | { Cookie: string };
+export interface StorageConfigWeightedDestination {
+	weight: number;
+	destination: string;
+}
+export interface StorageConfigWeightedRewrite {
+	session: StorageConfigRewriteSession;
+	destinations: Array<StorageConfigWeightedDestination>;
+}
 export interface StreamingCallbackHttpResponse {
 	token: [] | [StreamingCallbackToken];
 	body: Uint8Array | number[];
@@ -213,6 +801,7 @@ export interface StreamingCallbackToken {
 	memory: Memory;
 	token: [] | [string];
 	sha256: [] | [Uint8Array | number[]];
+	offset: [] | [bigint];
 	headers: Array<[string, string]>;
 	index: bigint;
 	encoding_type: string;
@@ -224,11 +813,35 @@ export type StreamingStrategy = {
 		callback: [Principal, string];
 	};
 };
+export interface SupportedStandard {
+	url: string;
+	name: string;
+}
+export type TextMatcher =
+	| { In: Array<string> }
+	| { Equal: string }
+	| { GreaterThan: string }
+	| { LessThan: string }
+	| { Prefix: string };
 export type TimestampMatcher =
 	| { Equal: bigint }
 	| { Between: [bigint, bigint] }
 	| { GreaterThan: bigint }
 	| { LessThan: bigint };
+export interface TokenGate {
+	ledger_id: Principal;
+	min_balance: bigint;
+	standard: TokenStandard;
+}
+export type TokenStandard = { Icrc1: null } | { Icrc7: null };
+export interface TracesConfig {
+	enabled: boolean;
+}
+export type TransferSection = { Db: string } | { Storage: string } | { Config: null };
+export interface TransformArgs {
+	context: Uint8Array | number[];
+	response: HttpOutcallResponse;
+}
 export interface UploadChunk {
 	content: Uint8Array | number[];
 	batch_id: bigint;
@@ -237,55 +850,169 @@ export interface UploadChunk {
 export interface UploadChunkResult {
 	chunk_id: bigint;
 }
+export interface UploadToken {
+	collection: string;
+	owner: Principal;
+	created_at: bigint;
+	expires_at: [] | [bigint];
+}
+export interface UserQuota {
+	max_bytes: [] | [bigint];
+	max_items: [] | [bigint];
+}
+export interface WebhookDelivery {
+	request_id: [] | [string];
+	status: WebhookDeliveryStatus;
+	updated_at: bigint;
+	attempts: number;
+	created_at: bigint;
+	error: [] | [string];
+	event: WebhookEvent;
+	response_status: [] | [number];
+	payload: string;
+	webhook_id: string;
+}
+export type WebhookDeliveryStatus = { Failed: null } | { Delivered: null } | { Pending: null };
+export type WebhookEvent =
+	| { UploadAsset: null }
+	| { DeleteDoc: null }
+	| { DeleteAsset: null }
+	| { SetDoc: null };
+export interface WebhookNoSecret {
+	url: string;
+	updated_at: bigint;
+	collections: [] | [Array<string>];
+	created_at: bigint;
+	enabled: boolean;
+	events: Array<WebhookEvent>;
+}
 export interface _SERVICE {
 	build_version: ActorMethod<[], string>;
+	acknowledge_safe_mode: ActorMethod<[], undefined>;
+	analyze_asset_links: ActorMethod<[], undefined>;
+	apply_config_bundle: ActorMethod<[ConfigBundle], undefined>;
+	cancel_action: ActorMethod<[bigint], undefined>;
 	commit_asset_upload: ActorMethod<[CommitBatch], undefined>;
+	compact_collection: ActorMethod<[string], undefined>;
+	compact_content_chunks: ActorMethod<[], undefined>;
+	confirm_action: ActorMethod<[bigint], undefined>;
 	count_assets: ActorMethod<[string, ListParams], bigint>;
 	count_collection_assets: ActorMethod<[string], bigint>;
 	count_collection_docs: ActorMethod<[string], bigint>;
 	count_docs: ActorMethod<[string, ListParams], bigint>;
+	create_api_key: ActorMethod<[SetApiKey], CreateApiKeyResult>;
+	create_upload_token: ActorMethod<[SetUploadToken], CreateUploadTokenResult>;
+	del_api_key: ActorMethod<[string], undefined>;
 	del_asset: ActorMethod<[string, string], undefined>;
 	del_assets: ActorMethod<[string], undefined>;
 	del_controllers: ActorMethod<[DeleteControllersArgs], Array<[Principal, Controller]>>;
 	del_custom_domain: ActorMethod<[string], undefined>;
 	del_doc: ActorMethod<[string, string, DelDoc], undefined>;
 	del_docs: ActorMethod<[string], undefined>;
+	del_feature_flag: ActorMethod<[string, DelFeatureFlag], undefined>;
 	del_filtered_assets: ActorMethod<[string, ListParams], undefined>;
 	del_filtered_docs: ActorMethod<[string, ListParams], undefined>;
 	del_many_assets: ActorMethod<[Array<[string, string]>], undefined>;
 	del_many_docs: ActorMethod<[Array<[string, string, DelDoc]>], undefined>;
 	del_rule: ActorMethod<[RulesType, string, DelRule], undefined>;
+	del_upload_token: ActorMethod<[string], undefined>;
+	del_webhook: ActorMethod<[string], undefined>;
 	deposit_cycles: ActorMethod<[DepositCyclesArgs], undefined>;
+	diff_assets: ActorMethod<[DiffAssetsArgs], Array<string>>;
+	dry_run: ActorMethod<[DryRunOperation], DryRunReport>;
+	estimate_query: ActorMethod<[RulesType, string, ListParams], QueryEstimate>;
+	export_chunk: ActorMethod<[ExportChunkArgs], ExportChunk>;
+	finalize_deployment: ActorMethod<[], undefined>;
+	get_analytics_config: ActorMethod<[], [] | [AnalyticsConfig]>;
+	get_approvals_config: ActorMethod<[], [] | [ApprovalsConfig]>;
+	get_asset_links_report: ActorMethod<[], [] | [AssetLinksReport]>;
 	get_asset: ActorMethod<[string, string], [] | [AssetNoContent]>;
 	get_auth_config: ActorMethod<[], [] | [AuthenticationConfig]>;
+	get_canary_deployment: ActorMethod<[], [] | [CanaryDeploymentStatus]>;
+	get_client_metadata: ActorMethod<[], ClientMetadata>;
+	get_collection_job_report: ActorMethod<[], [] | [CollectionJobReport]>;
+	get_collection_stats: ActorMethod<[string], CollectionStats>;
+	get_collection_version: ActorMethod<[RulesType, string], [] | [CollectionVersion]>;
+	get_compaction_report: ActorMethod<[], [] | [CompactionReport]>;
 	get_config: ActorMethod<[], Config>;
+	get_config_bundle: ActorMethod<[], ConfigBundle>;
 	get_db_config: ActorMethod<[], [] | [DbConfig]>;
 	get_doc: ActorMethod<[string, string], [] | [Doc]>;
+	get_doc_at: ActorMethod<[string, string, bigint], [] | [Doc]>;
+	get_doc_chunk: ActorMethod<[string, string, bigint], [] | [DocChunk]>;
+	get_feature_flags: ActorMethod<[], Array<[string, boolean]>>;
+	get_integrity_report: ActorMethod<[], [] | [IntegrityReport]>;
 	get_many_assets: ActorMethod<[Array<[string, string]>], Array<[string, [] | [AssetNoContent]]>>;
 	get_many_docs: ActorMethod<[Array<[string, string]>], Array<[string, [] | [Doc]]>>;
+	get_memory_stats: ActorMethod<[], Array<MemoryRegionStats>>;
+	get_moderation_config: ActorMethod<[], [] | [ModerationConfig]>;
+	get_quota_status: ActorMethod<[], [] | [QuotaStatus]>;
+	get_quota_thresholds: ActorMethod<[], [] | [QuotaThresholds]>;
+	get_rate_limit_config: ActorMethod<[], [] | [RateLimitConfig]>;
 	get_rule: ActorMethod<[RulesType, string], [] | [Rule]>;
+	get_self_test_report: ActorMethod<[], [] | [SelfTestReport]>;
 	get_storage_config: ActorMethod<[], StorageConfig>;
+	get_traces_config: ActorMethod<[], [] | [TracesConfig]>;
+	health: ActorMethod<[], Health>;
 	http_request: ActorMethod<[HttpRequest], HttpResponse>;
 	http_request_streaming_callback: ActorMethod<
 		[StreamingCallbackToken],
 		StreamingCallbackHttpResponse
 	>;
+	http_request_update: ActorMethod<[HttpRequest], HttpResponse>;
+	icrc10_supported_standards: ActorMethod<[], Array<SupportedStandard>>;
+	icrc21_canister_call_consent_message: ActorMethod<[Icrc21ConsentMessageRequest], Result>;
+	import_chunk: ActorMethod<[ImportChunkArgs], undefined>;
+	import_users: ActorMethod<[ImportUsers], ImportUsersReport>;
 	init_asset_upload: ActorMethod<[InitAssetKey], InitUploadResult>;
+	list_api_keys: ActorMethod<[], Array<[string, ApiKey]>>;
 	list_assets: ActorMethod<[string, ListParams], ListResults>;
+	list_assets_page: ActorMethod<[string, ListParams], Page>;
+	list_audit_blocks: ActorMethod<[ListAuditBlocks], AuditBlocks>;
+	list_certified_paths: ActorMethod<[], Array<CertifiedPath>>;
 	list_controllers: ActorMethod<[], Array<[Principal, Controller]>>;
+	list_controllers_page: ActorMethod<[ListPaginate], Page_1>;
 	list_custom_domains: ActorMethod<[], Array<[string, CustomDomain]>>;
 	list_docs: ActorMethod<[string, ListParams], ListResults_1>;
+	list_docs_page: ActorMethod<[string, ListParams], Page_2>;
+	list_extensions: ActorMethod<[], Array<[ExtensionKey, Extension]>>;
+	list_feature_flags: ActorMethod<[], Array<[string, FeatureFlag]>>;
+	list_pending_actions: ActorMethod<[], Array<[bigint, PendingAction]>>;
 	list_rules: ActorMethod<[RulesType], Array<[string, Rule]>>;
+	list_traces: ActorMethod<[], Array<DailyEndpointTrace>>;
+	list_upload_tokens: ActorMethod<[], Array<[string, UploadToken]>>;
+	list_webhook_deliveries: ActorMethod<[], Array<[bigint, WebhookDelivery]>>;
+	list_webhooks: ActorMethod<[], Array<[string, WebhookNoSecret]>>;
 	memory_size: ActorMethod<[], MemorySize>;
+	patch_doc: ActorMethod<[string, string, PatchDoc], Doc>;
+	propose_action: ActorMethod<[ControllerAction], bigint>;
+	rebuild_indexes: ActorMethod<[string], undefined>;
+	set_analytics_config: ActorMethod<[AnalyticsConfig], undefined>;
+	set_approvals_config: ActorMethod<[ApprovalsConfig], undefined>;
+	set_asset_moderation: ActorMethod<[string, string, SetModeration], AssetNoContent>;
+	set_asset_owner: ActorMethod<[string, string, SetAssetOwner], AssetNoContent>;
 	set_auth_config: ActorMethod<[AuthenticationConfig], undefined>;
+	set_canary_deployment: ActorMethod<[SetCanaryDeployment], CanaryDeploymentStatus>;
 	set_controllers: ActorMethod<[SetControllersArgs], Array<[Principal, Controller]>>;
 	set_custom_domain: ActorMethod<[string, [] | [string]], undefined>;
 	set_db_config: ActorMethod<[DbConfig], undefined>;
 	set_doc: ActorMethod<[string, string, SetDoc], Doc>;
+	set_doc_moderation: ActorMethod<[string, string, SetModeration], Doc>;
+	set_doc_owner: ActorMethod<[string, string, SetDocOwner], Doc>;
+	set_extension_config: ActorMethod<[ExtensionKey, SetExtensionConfig], Extension>;
+	set_feature_flag: ActorMethod<[string, SetFeatureFlag], FeatureFlag>;
 	set_many_docs: ActorMethod<[Array<[string, string, SetDoc]>], Array<[string, Doc]>>;
+	set_moderation_config: ActorMethod<[ModerationConfig], undefined>;
+	set_quota_thresholds: ActorMethod<[QuotaThresholds], undefined>;
+	set_rate_limit_config: ActorMethod<[[] | [RateLimitConfig]], undefined>;
 	set_rule: ActorMethod<[RulesType, string, SetRule], Rule>;
+	set_rule_compression: ActorMethod<[RulesType, string, [] | [Compression]], Rule>;
 	set_storage_config: ActorMethod<[StorageConfig], undefined>;
+	set_traces_config: ActorMethod<[TracesConfig], undefined>;
+	set_webhook: ActorMethod<[string, SetWebhook], undefined>;
+	transform_webhook_response: ActorMethod<[TransformArgs], HttpOutcallResponse>;
 	upload_asset_chunk: ActorMethod<[UploadChunk], UploadChunkResult>;
+	verify_token_gate: ActorMethod<[string], boolean>;
 	version: ActorMethod<[], string>;
 }
 export declare const idlFactory: IDL.InterfaceFactory;
@@ -1,5 +1,162 @@
 // @ts-ignore
 export const idlFactory = ({ IDL }) => {
+	const ListFilter = IDL.Rec();
+	const ConfigMaxMemorySize = IDL.Record({
+		stable: IDL.Opt(IDL.Nat64),
+		heap: IDL.Opt(IDL.Nat64)
+	});
+	const DbConfigAggregate = IDL.Record({
+		sum_fields: IDL.Opt(IDL.Vec(IDL.Text)),
+		collection: IDL.Text,
+		target_collection: IDL.Text,
+		parent_field: IDL.Text
+	});
+	const DbConfigAttachment = IDL.Record({
+		storage_collection: IDL.Text,
+		collection: IDL.Text,
+		cascade_delete: IDL.Opt(IDL.Bool)
+	});
+	const DbConfigProofOfWork = IDL.Record({
+		collection: IDL.Text,
+		difficulty: IDL.Nat8
+	});
+	const DbConfigView = IDL.Record({
+		collection: IDL.Text,
+		fields: IDL.Vec(IDL.Text),
+		sources: IDL.Vec(IDL.Text)
+	});
+	const ListLimits = IDL.Record({
+		max_scanned_items: IDL.Opt(IDL.Nat64),
+		max_returned_bytes: IDL.Opt(IDL.Nat64)
+	});
+	const DbConfig = IDL.Record({
+		proof_of_work: IDL.Opt(IDL.Vec(DbConfigProofOfWork)),
+		aggregates: IDL.Opt(IDL.Vec(DbConfigAggregate)),
+		views: IDL.Opt(IDL.Vec(DbConfigView)),
+		list_limits: IDL.Opt(ListLimits),
+		max_memory_size: IDL.Opt(ConfigMaxMemorySize),
+		history_collections: IDL.Opt(IDL.Vec(IDL.Text)),
+		attachments: IDL.Opt(IDL.Vec(DbConfigAttachment)),
+		http_collections: IDL.Opt(IDL.Vec(IDL.Text))
+	});
+	const AuthenticationConfigInternetIdentity = IDL.Record({
+		derivation_origin: IDL.Opt(IDL.Text)
+	});
+	const AuthenticationConfig = IDL.Record({
+		internet_identity: IDL.Opt(AuthenticationConfigInternetIdentity)
+	});
+	const StorageConfigIFrame = IDL.Variant({
+		Deny: IDL.Null,
+		AllowAny: IDL.Null,
+		SameOrigin: IDL.Null
+	});
+	const StorageConfigLocales = IDL.Record({
+		default_locale: IDL.Text,
+		cookie: IDL.Opt(IDL.Text),
+		supported_locales: IDL.Vec(IDL.Text)
+	});
+	const StorageConfigRawAccess = IDL.Variant({
+		Deny: IDL.Null,
+		Allow: IDL.Null
+	});
+	const StorageConfigRewriteSession = IDL.Variant({
+		Header: IDL.Text,
+		Cookie: IDL.Text
+	});
+	const StorageConfigWeightedDestination = IDL.Record({
+		weight: IDL.Nat32,
+		destination: IDL.Text
+	});
+	const StorageConfigWeightedRewrite = IDL.Record({
+		session: StorageConfigRewriteSession,
+		destinations: IDL.Vec(StorageConfigWeightedDestination)
+	});
+	const StorageConfigRedirect = IDL.Record({
+		status_code: IDL.Nat16,
+		location: IDL.Text
+	});
+	const StorageConfigDelegation = IDL.Record({
+		raw: IDL.Opt(IDL.Bool),
+		domain: IDL.Opt(IDL.Text),
+		canister_id: IDL.Principal
+	});
+	const StorageConfig = IDL.Record({
+		iframe: IDL.Opt(StorageConfigIFrame),
+		csp_nonce: IDL.Opt(IDL.Bool),
+		locales: IDL.Opt(StorageConfigLocales),
+		wildcard_domains: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text))),
+		list_limits: IDL.Opt(ListLimits),
+		delegations: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigDelegation))),
+		rewrites: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)))),
+		max_memory_size: IDL.Opt(ConfigMaxMemorySize),
+		max_response_body_size: IDL.Opt(IDL.Nat64),
+		raw_access: IDL.Opt(StorageConfigRawAccess),
+		weighted_rewrites: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigWeightedRewrite))),
+		redirects: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigRedirect)))
+	});
+	const Memory = IDL.Variant({ Heap: IDL.Null, Stable: IDL.Null });
+	const UserQuota = IDL.Record({
+		max_bytes: IDL.Opt(IDL.Nat),
+		max_items: IDL.Opt(IDL.Nat64)
+	});
+	const KeyCollation = IDL.Variant({
+		Binary: IDL.Null,
+		CaseInsensitive: IDL.Null,
+		Numeric: IDL.Null
+	});
+	const TokenStandard = IDL.Variant({ Icrc1: IDL.Null, Icrc7: IDL.Null });
+	const TokenGate = IDL.Record({
+		ledger_id: IDL.Principal,
+		min_balance: IDL.Nat,
+		standard: TokenStandard
+	});
+	const Permission = IDL.Variant({
+		Controllers: IDL.Null,
+		Private: IDL.Null,
+		Public: IDL.Null,
+		Managed: IDL.Null,
+		TokenGated: TokenGate
+	});
+	const RetentionPolicy = IDL.Record({
+		max_items: IDL.Opt(IDL.Nat64),
+		max_age: IDL.Opt(IDL.Nat64)
+	});
+	const Compression = IDL.Variant({ Deflate: IDL.Null });
+	const RateConfig = IDL.Record({
+		max_tokens: IDL.Nat64,
+		time_per_token_ns: IDL.Nat64
+	});
+	const RateLimitConfig = IDL.Record({
+		http: IDL.Opt(RateConfig),
+		anonymous: IDL.Opt(RateConfig),
+		caller: RateConfig
+	});
+	const SetRule = IDL.Record({
+		max_capacity: IDL.Opt(IDL.Nat32),
+		memory: IDL.Opt(Memory),
+		user_quota: IDL.Opt(UserQuota),
+		max_size: IDL.Opt(IDL.Nat),
+		collation: IDL.Opt(KeyCollation),
+		read: Permission,
+		retention: IDL.Opt(RetentionPolicy),
+		version: IDL.Opt(IDL.Nat64),
+		mutable_permissions: IDL.Opt(IDL.Bool),
+		rate_config: IDL.Opt(RateConfig),
+		write: Permission
+	});
+	const ConfigBundleRules = IDL.Record({
+		db: IDL.Vec(IDL.Tuple(IDL.Text, SetRule)),
+		storage: IDL.Vec(IDL.Tuple(IDL.Text, SetRule))
+	});
+	const ConfigBundle = IDL.Record({
+		db: IDL.Opt(DbConfig),
+		authentication: IDL.Opt(AuthenticationConfig),
+		custom_domains: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Opt(IDL.Text))),
+		storage: StorageConfig,
+		version: IDL.Nat64,
+		rules: ConfigBundleRules
+	});
 	const CommitBatch = IDL.Record({
 		batch_id: IDL.Nat,
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
@@ -17,11 +174,35 @@ export const idlFactory = ({ IDL }) => {
 		GreaterThan: IDL.Nat64,
 		LessThan: IDL.Nat64
 	});
+	const TextMatcher = IDL.Variant({
+		In: IDL.Vec(IDL.Text),
+		Equal: IDL.Text,
+		GreaterThan: IDL.Text,
+		LessThan: IDL.Text,
+		Prefix: IDL.Text
+	});
+	const PrincipalMatcher = IDL.Variant({
+		In: IDL.Vec(IDL.Principal),
+		Equal: IDL.Principal
+	});
+	ListFilter.fill(
+		IDL.Variant({
+			Or: IDL.Vec(ListFilter),
+			And: IDL.Vec(ListFilter),
+			Key: TextMatcher,
+			Not: ListFilter,
+			UpdatedAt: TimestampMatcher,
+			Description: TextMatcher,
+			Owner: PrincipalMatcher,
+			CreatedAt: TimestampMatcher
+		})
+	);
 	const ListMatcher = IDL.Record({
 		key: IDL.Opt(IDL.Text),
 		updated_at: IDL.Opt(TimestampMatcher),
 		description: IDL.Opt(IDL.Text),
-		created_at: IDL.Opt(TimestampMatcher)
+		created_at: IDL.Opt(TimestampMatcher),
+		filter: IDL.Opt(ListFilter)
 	});
 	const ListPaginate = IDL.Record({
 		start_after: IDL.Opt(IDL.Text),
@@ -33,6 +214,12 @@ export const idlFactory = ({ IDL }) => {
 		matcher: IDL.Opt(ListMatcher),
 		paginate: IDL.Opt(ListPaginate)
 	});
+	const SetUploadToken = IDL.Record({
+		collection: IDL.Text,
+		owner: IDL.Principal,
+		expires_at: IDL.Opt(IDL.Nat64)
+	});
+	const CreateUploadTokenResult = IDL.Record({ id: IDL.Text, secret: IDL.Text });
 	const DeleteControllersArgs = IDL.Record({
 		controllers: IDL.Vec(IDL.Principal)
 	});
@@ -48,18 +235,117 @@ export const idlFactory = ({ IDL }) => {
 		expires_at: IDL.Opt(IDL.Nat64)
 	});
 	const DelDoc = IDL.Record({ version: IDL.Opt(IDL.Nat64) });
+	const DelFeatureFlag = IDL.Record({ version: IDL.Opt(IDL.Nat64) });
 	const RulesType = IDL.Variant({ Db: IDL.Null, Storage: IDL.Null });
 	const DelRule = IDL.Record({ version: IDL.Opt(IDL.Nat64) });
 	const DepositCyclesArgs = IDL.Record({
 		cycles: IDL.Nat,
 		destination_id: IDL.Principal
 	});
+	const DiffAssetsArgs = IDL.Record({
+		collection: IDL.Text,
+		assets: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Vec(IDL.Nat8)))
+	});
+	const DryRunOperation = IDL.Variant({
+		DelFilteredAssets: IDL.Record({
+			collection: IDL.Text,
+			filter: ListParams
+		}),
+		DelFilteredDocs: IDL.Record({
+			collection: IDL.Text,
+			filter: ListParams
+		}),
+		DelAssets: IDL.Record({ collection: IDL.Text }),
+		PurgeRetention: IDL.Record({
+			collection: IDL.Text,
+			rules_type: RulesType
+		}),
+		DelDocs: IDL.Record({ collection: IDL.Text }),
+		DelRule: IDL.Record({
+			collection: IDL.Text,
+			rule: DelRule,
+			rules_type: RulesType
+		}),
+		SetRule: IDL.Record({
+			collection: IDL.Text,
+			rule: SetRule,
+			rules_type: RulesType
+		})
+	});
+	const Rule = IDL.Record({
+		max_capacity: IDL.Opt(IDL.Nat32),
+		memory: IDL.Opt(Memory),
+		updated_at: IDL.Nat64,
+		user_quota: IDL.Opt(UserQuota),
+		max_size: IDL.Opt(IDL.Nat),
+		collation: IDL.Opt(KeyCollation),
+		read: Permission,
+		retention: IDL.Opt(RetentionPolicy),
+		created_at: IDL.Nat64,
+		compression: IDL.Opt(Compression),
+		version: IDL.Opt(IDL.Nat64),
+		mutable_permissions: IDL.Opt(IDL.Bool),
+		rate_config: IDL.Opt(RateConfig),
+		write: Permission
+	});
+	const DryRunReport = IDL.Record({
+		keys: IDL.Vec(IDL.Text),
+		rule: IDL.Opt(Rule),
+		error: IDL.Opt(IDL.Text)
+	});
+	const ListTruncated = IDL.Variant({
+		MaxScannedItems: IDL.Null,
+		MaxReturnedBytes: IDL.Null
+	});
+	const QueryEstimate = IDL.Record({
+		matches_length: IDL.Nat64,
+		truncated: IDL.Opt(ListTruncated),
+		scanned_items: IDL.Nat64,
+		returned_bytes: IDL.Nat64,
+		items_length: IDL.Nat64
+	});
+	const TransferSection = IDL.Variant({
+		Db: IDL.Text,
+		Storage: IDL.Text,
+		Config: IDL.Null
+	});
+	const ExportChunkArgs = IDL.Record({
+		cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
+		section: TransferSection
+	});
+	const ExportChunk = IDL.Record({
+		cursor: IDL.Opt(IDL.Vec(IDL.Nat8)),
+		data: IDL.Vec(IDL.Nat8)
+	});
+	const AnalyticsConfig = IDL.Record({
+		orbiter_id: IDL.Opt(IDL.Principal),
+		rate_config: IDL.Opt(RateConfig)
+	});
+	const BrokenLink = IDL.Record({ source: IDL.Text, target: IDL.Text });
+	const AssetLinksReport = IDL.Record({
+		orphans: IDL.Vec(IDL.Text),
+		orphans_count: IDL.Nat64,
+		parsed_assets: IDL.Nat64,
+		links_count: IDL.Nat64,
+		completed_at: IDL.Opt(IDL.Nat64),
+		broken_links_count: IDL.Nat64,
+		started_at: IDL.Nat64,
+		broken_links: IDL.Vec(BrokenLink)
+	});
+	const ModerationStatus = IDL.Variant({
+		Approved: IDL.Null,
+		Rejected: IDL.Null,
+		Pending: IDL.Null
+	});
 	const AssetKey = IDL.Record({
 		token: IDL.Opt(IDL.Text),
 		collection: IDL.Text,
 		owner: IDL.Principal,
 		name: IDL.Text,
 		description: IDL.Opt(IDL.Text),
+		publish_at: IDL.Opt(IDL.Nat64),
+		moderation: IDL.Opt(ModerationStatus),
+		unpublish_at: IDL.Opt(IDL.Nat64),
 		full_path: IDL.Text
 	});
 	const AssetEncodingNoContent = IDL.Record({
@@ -75,39 +361,53 @@ export const idlFactory = ({ IDL }) => {
 		created_at: IDL.Nat64,
 		version: IDL.Opt(IDL.Nat64)
 	});
-	const AuthenticationConfigInternetIdentity = IDL.Record({
-		derivation_origin: IDL.Opt(IDL.Text)
+	const CanaryDeploymentStatus = IDL.Record({
+		updated_at: IDL.Nat64,
+		created_at: IDL.Nat64,
+		session: IDL.Opt(StorageConfigRewriteSession),
+		paths: IDL.Vec(IDL.Text),
+		percentage: IDL.Nat8
 	});
-	const AuthenticationConfig = IDL.Record({
-		internet_identity: IDL.Opt(AuthenticationConfigInternetIdentity)
+	const OwnerCounters = IDL.Record({
+		items_count: IDL.Nat64,
+		items_bytes: IDL.Nat
 	});
-	const ConfigMaxMemorySize = IDL.Record({
-		stable: IDL.Opt(IDL.Nat64),
-		heap: IDL.Opt(IDL.Nat64)
+	const ItemsStats = IDL.Record({
+		max_updated_at: IDL.Opt(IDL.Nat64),
+		top_owners: IDL.Vec(IDL.Tuple(IDL.Principal, OwnerCounters)),
+		items_count: IDL.Nat64,
+		items_bytes: IDL.Nat,
+		min_created_at: IDL.Opt(IDL.Nat64)
 	});
-	const DbConfig = IDL.Record({
-		max_memory_size: IDL.Opt(ConfigMaxMemorySize)
+	const CollectionJobKind = IDL.Variant({
+		CompactCollection: IDL.Null,
+		RebuildIndexes: IDL.Null
 	});
-	const StorageConfigIFrame = IDL.Variant({
-		Deny: IDL.Null,
-		AllowAny: IDL.Null,
-		SameOrigin: IDL.Null
+	const CollectionJobReport = IDL.Record({
+		collection: IDL.Text,
+		processed_docs: IDL.Nat64,
+		kind: CollectionJobKind,
+		total_docs: IDL.Nat64,
+		error: IDL.Opt(IDL.Text),
+		completed_at: IDL.Opt(IDL.Nat64),
+		rewritten_docs: IDL.Nat64,
+		reclaimed_bytes: IDL.Nat64,
+		started_at: IDL.Nat64
 	});
-	const StorageConfigRawAccess = IDL.Variant({
-		Deny: IDL.Null,
-		Allow: IDL.Null
+	const CollectionStats = IDL.Record({
+		docs: IDL.Opt(ItemsStats),
+		assets: IDL.Opt(ItemsStats)
 	});
-	const StorageConfigRedirect = IDL.Record({
-		status_code: IDL.Nat16,
-		location: IDL.Text
+	const CollectionVersion = IDL.Record({
+		last_modified: IDL.Nat64,
+		changes: IDL.Nat64
 	});
-	const StorageConfig = IDL.Record({
-		iframe: IDL.Opt(StorageConfigIFrame),
-		rewrites: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
-		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)))),
-		max_memory_size: IDL.Opt(ConfigMaxMemorySize),
-		raw_access: IDL.Opt(StorageConfigRawAccess),
-		redirects: IDL.Opt(IDL.Vec(IDL.Tuple(IDL.Text, StorageConfigRedirect)))
+	const CompactionReport = IDL.Record({
+		scanned_chunks: IDL.Nat64,
+		removed_chunks: IDL.Nat64,
+		completed_at: IDL.Opt(IDL.Nat64),
+		reclaimed_bytes: IDL.Nat64,
+		started_at: IDL.Nat64
 	});
 	const Config = IDL.Record({
 		db: IDL.Opt(DbConfig),
@@ -120,30 +420,96 @@ export const idlFactory = ({ IDL }) => {
 		data: IDL.Vec(IDL.Nat8),
 		description: IDL.Opt(IDL.Text),
 		created_at: IDL.Nat64,
-		version: IDL.Opt(IDL.Nat64)
+		publish_at: IDL.Opt(IDL.Nat64),
+		version: IDL.Opt(IDL.Nat64),
+		moderation: IDL.Opt(ModerationStatus),
+		unpublish_at: IDL.Opt(IDL.Nat64),
+		attachments: IDL.Opt(IDL.Vec(IDL.Text))
 	});
-	const Memory = IDL.Variant({ Heap: IDL.Null, Stable: IDL.Null });
-	const Permission = IDL.Variant({
-		Controllers: IDL.Null,
-		Private: IDL.Null,
-		Public: IDL.Null,
-		Managed: IDL.Null
+	const DocChunk = IDL.Record({
+		content: IDL.Vec(IDL.Nat8),
+		chunks_length: IDL.Nat64,
+		version: IDL.Opt(IDL.Nat64),
+		data_length: IDL.Nat64
 	});
-	const RateConfig = IDL.Record({
-		max_tokens: IDL.Nat64,
-		time_per_token_ns: IDL.Nat64
+	const IntegrityIssue = IDL.Variant({
+		AssetsCount: IDL.Record({
+			found: IDL.Nat64,
+			counted: IDL.Nat64,
+			collection: IDL.Text
+		}),
+		UncertifiedAsset: IDL.Record({
+			collection: IDL.Text,
+			full_path: IDL.Text
+		}),
+		MissingRule: IDL.Record({
+			collection: IDL.Text,
+			rules_type: RulesType
+		}),
+		DocsCount: IDL.Record({
+			found: IDL.Nat64,
+			counted: IDL.Nat64,
+			collection: IDL.Text
+		}),
+		MissingContentChunks: IDL.Record({
+			collection: IDL.Text,
+			full_path: IDL.Text
+		})
 	});
-	const Rule = IDL.Record({
-		max_capacity: IDL.Opt(IDL.Nat32),
-		memory: IDL.Opt(Memory),
+	const IntegrityReport = IDL.Record({
+		issues_count: IDL.Nat64,
+		checked_collections: IDL.Nat64,
+		version: IDL.Text,
+		issues: IDL.Vec(IntegrityIssue),
+		completed_at: IDL.Opt(IDL.Nat64),
+		started_at: IDL.Nat64
+	});
+	const MemoryRegion = IDL.Variant({
+		Db: IDL.Null,
+		Upgrades: IDL.Null,
+		Assets: IDL.Null,
+		Audit: IDL.Null,
+		Revisions: IDL.Null,
+		ContentChunks: IDL.Null
+	});
+	const MemoryRegionStats = IDL.Record({
+		region: MemoryRegion,
+		allocated: IDL.Nat64,
+		used: IDL.Nat64,
+		entries: IDL.Nat64,
+		fragmented: IDL.Nat64
+	});
+	const ModerationConfig = IDL.Record({
+		db: IDL.Vec(IDL.Text),
+		storage: IDL.Vec(IDL.Text)
+	});
+	const QuotaWarning = IDL.Variant({
+		DocsCount: IDL.Null,
+		MemorySize: IDL.Null
+	});
+	const QuotaStatus = IDL.Record({
 		updated_at: IDL.Nat64,
-		max_size: IDL.Opt(IDL.Nat),
-		read: Permission,
-		created_at: IDL.Nat64,
-		version: IDL.Opt(IDL.Nat64),
-		mutable_permissions: IDL.Opt(IDL.Bool),
-		rate_config: IDL.Opt(RateConfig),
-		write: Permission
+		memory_size: IDL.Nat64,
+		warnings: IDL.Vec(QuotaWarning),
+		docs_count: IDL.Nat64
+	});
+	const QuotaThresholds = IDL.Record({
+		memory_size: IDL.Opt(IDL.Nat64),
+		docs_count: IDL.Opt(IDL.Nat64)
+	});
+	const TracesConfig = IDL.Record({ enabled: IDL.Bool });
+	const MemorySize = IDL.Record({ stable: IDL.Nat64, heap: IDL.Nat64 });
+	const Health = IDL.Record({
+		pending_upload_batches: IDL.Nat64,
+		certification_pending: IDL.Bool,
+		memory_size: MemorySize,
+		safe_mode: IDL.Bool,
+		version: IDL.Text,
+		cycles: IDL.Nat,
+		upgraded_at: IDL.Opt(IDL.Nat64),
+		timestamp: IDL.Nat64,
+		pending_deleted_chunks: IDL.Nat64,
+		pending_deleted_docs: IDL.Nat64
 	});
 	const HttpRequest = IDL.Record({
 		url: IDL.Text,
@@ -156,6 +522,7 @@ export const idlFactory = ({ IDL }) => {
 		memory: Memory,
 		token: IDL.Opt(IDL.Text),
 		sha256: IDL.Opt(IDL.Vec(IDL.Nat8)),
+		offset: IDL.Opt(IDL.Nat64),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		index: IDL.Nat64,
 		encoding_type: IDL.Text,
@@ -170,6 +537,7 @@ export const idlFactory = ({ IDL }) => {
 	const HttpResponse = IDL.Record({
 		body: IDL.Vec(IDL.Nat8),
 		headers: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		upgrade: IDL.Opt(IDL.Bool),
 		streaming_strategy: IDL.Opt(StreamingStrategy),
 		status_code: IDL.Nat16
 	});
@@ -177,11 +545,76 @@ export const idlFactory = ({ IDL }) => {
 		token: IDL.Opt(StreamingCallbackToken),
 		body: IDL.Vec(IDL.Nat8)
 	});
+	const SupportedStandard = IDL.Record({ url: IDL.Text, name: IDL.Text });
+	const Icrc21ConsentMessageMetadata = IDL.Record({
+		utc_offset_minutes: IDL.Opt(IDL.Int16),
+		language: IDL.Text
+	});
+	const Icrc21DeviceSpec = IDL.Variant({
+		GenericDisplay: IDL.Null,
+		LineDisplay: IDL.Record({
+			characters_per_line: IDL.Nat16,
+			lines_per_page: IDL.Nat16
+		})
+	});
+	const Icrc21ConsentMessageSpec = IDL.Record({
+		metadata: Icrc21ConsentMessageMetadata,
+		device_spec: IDL.Opt(Icrc21DeviceSpec)
+	});
+	const Icrc21ConsentMessageRequest = IDL.Record({
+		arg: IDL.Vec(IDL.Nat8),
+		method: IDL.Text,
+		user_preferences: Icrc21ConsentMessageSpec
+	});
+	const Icrc21LineDisplayPage = IDL.Record({ lines: IDL.Vec(IDL.Text) });
+	const Icrc21ConsentMessage = IDL.Variant({
+		LineDisplayMessage: IDL.Record({ pages: IDL.Vec(Icrc21LineDisplayPage) }),
+		GenericDisplayMessage: IDL.Text
+	});
+	const Icrc21ConsentInfo = IDL.Record({
+		metadata: Icrc21ConsentMessageMetadata,
+		consent_message: Icrc21ConsentMessage
+	});
+	const Icrc21ErrorInfo = IDL.Record({ description: IDL.Text });
+	const Icrc21Error = IDL.Variant({
+		GenericError: IDL.Record({
+			description: IDL.Text,
+			error_code: IDL.Nat
+		}),
+		InsufficientPayment: Icrc21ErrorInfo,
+		UnsupportedCanisterCall: Icrc21ErrorInfo,
+		ConsentMessageUnavailable: Icrc21ErrorInfo
+	});
+	const Result = IDL.Variant({ Ok: Icrc21ConsentInfo, Err: Icrc21Error });
+	const ImportChunkArgs = IDL.Record({
+		data: IDL.Vec(IDL.Nat8),
+		section: TransferSection
+	});
+	const ImportUserConflict = IDL.Variant({
+		Reject: IDL.Null,
+		Skip: IDL.Null,
+		Overwrite: IDL.Null
+	});
+	const ImportUser = IDL.Record({
+		data: IDL.Vec(IDL.Nat8),
+		created_at: IDL.Opt(IDL.Nat64),
+		user_id: IDL.Principal
+	});
+	const ImportUsers = IDL.Record({
+		conflict: IDL.Opt(ImportUserConflict),
+		users: IDL.Vec(ImportUser)
+	});
+	const ImportUsersReport = IDL.Record({
+		imported: IDL.Vec(IDL.Principal),
+		skipped: IDL.Vec(IDL.Principal)
+	});
 	const InitAssetKey = IDL.Record({
 		token: IDL.Opt(IDL.Text),
 		collection: IDL.Text,
 		name: IDL.Text,
 		description: IDL.Opt(IDL.Text),
+		publish_at: IDL.Opt(IDL.Nat64),
+		unpublish_at: IDL.Opt(IDL.Nat64),
 		encoding_type: IDL.Opt(IDL.Text),
 		full_path: IDL.Text
 	});
@@ -189,10 +622,62 @@ export const idlFactory = ({ IDL }) => {
 	const ListResults = IDL.Record({
 		matches_pages: IDL.Opt(IDL.Nat64),
 		matches_length: IDL.Nat64,
+		truncated: IDL.Opt(ListTruncated),
+		version: IDL.Opt(CollectionVersion),
 		items_page: IDL.Opt(IDL.Nat64),
 		items: IDL.Vec(IDL.Tuple(IDL.Text, AssetNoContent)),
+		resume_after: IDL.Opt(IDL.Text),
 		items_length: IDL.Nat64
 	});
+	const Page = IDL.Record({
+		next_cursor: IDL.Opt(IDL.Text),
+		items: IDL.Vec(IDL.Tuple(IDL.Text, AssetNoContent)),
+		total_estimate: IDL.Nat64
+	});
+	const ListAuditBlocks = IDL.Record({ start: IDL.Nat64, length: IDL.Nat64 });
+	const AuditOperation = IDL.Variant({
+		Upgrade: IDL.Record({ version: IDL.Text }),
+		SetDbConfig: IDL.Null,
+		SetControllers: IDL.Record({
+			controllers: IDL.Vec(IDL.Principal),
+			scope: ControllerScope
+		}),
+		AcknowledgeSafeMode: IDL.Null,
+		ApplyConfigBundle: IDL.Null,
+		SetCustomDomain: IDL.Record({ domain_name: IDL.Text }),
+		DelCustomDomain: IDL.Record({ domain_name: IDL.Text }),
+		SetStorageConfig: IDL.Null,
+		DelRule: IDL.Record({
+			collection: IDL.Text,
+			rules_type: RulesType
+		}),
+		SetRule: IDL.Record({
+			collection: IDL.Text,
+			rules_type: RulesType
+		}),
+		SetAuthConfig: IDL.Null,
+		DelControllers: IDL.Record({ controllers: IDL.Vec(IDL.Principal) })
+	});
+	const AuditBlock = IDL.Record({
+		hash: IDL.Vec(IDL.Nat8),
+		operation: AuditOperation,
+		timestamp: IDL.Nat64,
+		caller: IDL.Principal,
+		parent_hash: IDL.Opt(IDL.Vec(IDL.Nat8))
+	});
+	const AuditBlocks = IDL.Record({
+		log_length: IDL.Nat64,
+		blocks: IDL.Vec(IDL.Tuple(IDL.Nat64, AuditBlock))
+	});
+	const CertifiedPath = IDL.Record({
+		url: IDL.Text,
+		sha256: IDL.Vec(IDL.Nat8)
+	});
+	const Page_1 = IDL.Record({
+		next_cursor: IDL.Opt(IDL.Text),
+		items: IDL.Vec(IDL.Tuple(IDL.Principal, Controller)),
+		total_estimate: IDL.Nat64
+	});
 	const CustomDomain = IDL.Record({
 		updated_at: IDL.Nat64,
 		created_at: IDL.Nat64,
@@ -202,11 +687,95 @@ export const idlFactory = ({ IDL }) => {
 	const ListResults_1 = IDL.Record({
 		matches_pages: IDL.Opt(IDL.Nat64),
 		matches_length: IDL.Nat64,
+		truncated: IDL.Opt(ListTruncated),
+		version: IDL.Opt(CollectionVersion),
 		items_page: IDL.Opt(IDL.Nat64),
 		items: IDL.Vec(IDL.Tuple(IDL.Text, Doc)),
+		resume_after: IDL.Opt(IDL.Text),
 		items_length: IDL.Nat64
 	});
-	const MemorySize = IDL.Record({ stable: IDL.Nat64, heap: IDL.Nat64 });
+	const Page_2 = IDL.Record({
+		next_cursor: IDL.Opt(IDL.Text),
+		items: IDL.Vec(IDL.Tuple(IDL.Text, Doc)),
+		total_estimate: IDL.Nat64
+	});
+	const FeatureFlagRole = IDL.Variant({
+		Anonymous: IDL.Null,
+		User: IDL.Null,
+		Admin: IDL.Null,
+		Controller: IDL.Null
+	});
+	const FeatureFlagRule = IDL.Variant({
+		Boolean: IDL.Bool,
+		Targeted: IDL.Vec(FeatureFlagRole),
+		Percentage: IDL.Nat8
+	});
+	const FeatureFlag = IDL.Record({
+		updated_at: IDL.Nat64,
+		rule: FeatureFlagRule,
+		description: IDL.Opt(IDL.Text),
+		created_at: IDL.Nat64,
+		version: IDL.Opt(IDL.Nat64)
+	});
+	const EndpointTrace = IDL.Record({
+		calls: IDL.Nat64,
+		instructions: IDL.Nat,
+		max_instructions: IDL.Nat64
+	});
+	const DailyEndpointTrace = IDL.Record({
+		day: IDL.Nat64,
+		trace: EndpointTrace,
+		endpoint: IDL.Text
+	});
+	const UploadToken = IDL.Record({
+		collection: IDL.Text,
+		owner: IDL.Principal,
+		created_at: IDL.Nat64,
+		expires_at: IDL.Opt(IDL.Nat64)
+	});
+	const WebhookDeliveryStatus = IDL.Variant({
+		Failed: IDL.Null,
+		Delivered: IDL.Null,
+		Pending: IDL.Null
+	});
+	const WebhookEvent = IDL.Variant({
+		UploadAsset: IDL.Null,
+		DeleteDoc: IDL.Null,
+		DeleteAsset: IDL.Null,
+		SetDoc: IDL.Null
+	});
+	const WebhookDelivery = IDL.Record({
+		request_id: IDL.Opt(IDL.Text),
+		status: WebhookDeliveryStatus,
+		updated_at: IDL.Nat64,
+		attempts: IDL.Nat8,
+		created_at: IDL.Nat64,
+		error: IDL.Opt(IDL.Text),
+		event: WebhookEvent,
+		response_status: IDL.Opt(IDL.Nat16),
+		payload: IDL.Text,
+		webhook_id: IDL.Text
+	});
+	const WebhookNoSecret = IDL.Record({
+		url: IDL.Text,
+		updated_at: IDL.Nat64,
+		collections: IDL.Opt(IDL.Vec(IDL.Text)),
+		created_at: IDL.Nat64,
+		enabled: IDL.Bool,
+		events: IDL.Vec(WebhookEvent)
+	});
+	const SetModeration = IDL.Record({
+		status: ModerationStatus,
+		version: IDL.Opt(IDL.Nat64)
+	});
+	const SetAssetOwner = IDL.Record({
+		owner: IDL.Principal,
+		version: IDL.Opt(IDL.Nat64)
+	});
+	const SetCanaryDeployment = IDL.Record({
+		session: IDL.Opt(StorageConfigRewriteSession),
+		percentage: IDL.Nat8
+	});
 	const SetController = IDL.Record({
 		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
 		scope: ControllerScope,
@@ -219,17 +788,36 @@ export const idlFactory = ({ IDL }) => {
 	const SetDoc = IDL.Record({
 		data: IDL.Vec(IDL.Nat8),
 		description: IDL.Opt(IDL.Text),
+		publish_at: IDL.Opt(IDL.Nat64),
+		version: IDL.Opt(IDL.Nat64),
+		unpublish_at: IDL.Opt(IDL.Nat64),
+		attachments: IDL.Opt(IDL.Vec(IDL.Text))
+	});
+	const SetDocOwner = IDL.Record({
+		owner: IDL.Principal,
 		version: IDL.Opt(IDL.Nat64)
 	});
-	const SetRule = IDL.Record({
-		max_capacity: IDL.Opt(IDL.Nat32),
-		memory: IDL.Opt(Memory),
-		max_size: IDL.Opt(IDL.Nat),
-		read: Permission,
-		version: IDL.Opt(IDL.Nat64),
-		mutable_permissions: IDL.Opt(IDL.Bool),
-		rate_config: IDL.Opt(RateConfig),
-		write: Permission
+	const SetFeatureFlag = IDL.Record({
+		rule: FeatureFlagRule,
+		description: IDL.Opt(IDL.Text),
+		version: IDL.Opt(IDL.Nat64)
+	});
+	const SetWebhook = IDL.Record({
+		url: IDL.Text,
+		collections: IDL.Opt(IDL.Vec(IDL.Text)),
+		secret: IDL.Opt(IDL.Text),
+		enabled: IDL.Bool,
+		events: IDL.Vec(WebhookEvent)
+	});
+	const HttpHeader = IDL.Record({ value: IDL.Text, name: IDL.Text });
+	const HttpOutcallResponse = IDL.Record({
+		status: IDL.Nat,
+		body: IDL.Vec(IDL.Nat8),
+		headers: IDL.Vec(HttpHeader)
+	});
+	const TransformArgs = IDL.Record({
+		context: IDL.Vec(IDL.Nat8),
+		response: HttpOutcallResponse
 	});
 	const UploadChunk = IDL.Record({
 		content: IDL.Vec(IDL.Nat8),
@@ -237,13 +825,134 @@ export const idlFactory = ({ IDL }) => {
 		order_id: IDL.Opt(IDL.Nat)
 	});
 	const UploadChunkResult = IDL.Record({ chunk_id: IDL.Nat });
+	const ApprovalsConfig = IDL.Record({
+		enabled: IDL.Bool,
+		confirmation_window_ns: IDL.Opt(IDL.Nat64)
+	});
+	const ControllerAction = IDL.Variant({
+		DelFilteredAssets: IDL.Record({
+			filter: ListParams,
+			collection: IDL.Text
+		}),
+		SetControllers: SetControllersArgs,
+		DelFilteredDocs: IDL.Record({
+			filter: ListParams,
+			collection: IDL.Text
+		}),
+		DelAssets: IDL.Record({ collection: IDL.Text }),
+		ApplyConfigBundle: ConfigBundle,
+		DelDocs: IDL.Record({ collection: IDL.Text }),
+		SetAssetOwner: IDL.Record({
+			collection: IDL.Text,
+			owner: SetAssetOwner,
+			full_path: IDL.Text
+		}),
+		DelRule: IDL.Record({
+			collection: IDL.Text,
+			rule: DelRule,
+			rules_type: RulesType
+		}),
+		SetDocOwner: IDL.Record({
+			key: IDL.Text,
+			collection: IDL.Text,
+			owner: SetDocOwner
+		}),
+		SetRule: IDL.Record({
+			collection: IDL.Text,
+			rule: SetRule,
+			rules_type: RulesType
+		}),
+		SetApprovalsConfig: ApprovalsConfig,
+		DelControllers: DeleteControllersArgs
+	});
+	const PendingAction = IDL.Record({
+		action: ControllerAction,
+		expires_at: IDL.Nat64,
+		proposed_at: IDL.Nat64,
+		proposed_by: IDL.Principal
+	});
+	const DocPatch = IDL.Variant({
+		MergePatch: IDL.Text,
+		JsonPatch: IDL.Text
+	});
+	const PatchDoc = IDL.Record({
+		version: IDL.Opt(IDL.Nat64),
+		patch: DocPatch
+	});
+	const SelfTestIssue = IDL.Variant({
+		NoAdminController: IDL.Null,
+		MissingCollection: IDL.Record({ collection: IDL.Text }),
+		MissingSystemCollection: IDL.Record({
+			collection: IDL.Text,
+			rules_type: RulesType
+		}),
+		MissingRule: IDL.Record({ collection: IDL.Text })
+	});
+	const SelfTestReport = IDL.Record({
+		safe_mode: IDL.Bool,
+		version: IDL.Text,
+		issues: IDL.Vec(SelfTestIssue),
+		acknowledged_at: IDL.Opt(IDL.Nat64),
+		acknowledged_by: IDL.Opt(IDL.Principal),
+		checked_at: IDL.Nat64
+	});
+	const ExtensionKey = IDL.Variant({ Traces: IDL.Null });
+	const Extension = IDL.Record({
+		updated_at: IDL.Nat64,
+		created_at: IDL.Nat64,
+		version: IDL.Opt(IDL.Nat64),
+		enabled: IDL.Bool,
+		settings: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text))
+	});
+	const SetExtensionConfig = IDL.Record({
+		version: IDL.Opt(IDL.Nat64),
+		enabled: IDL.Bool,
+		settings: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text))
+	});
+	const SetApiKey = IDL.Record({
+		description: IDL.Opt(IDL.Text),
+		collections: IDL.Vec(IDL.Text),
+		expires_at: IDL.Opt(IDL.Nat64)
+	});
+	const CreateApiKeyResult = IDL.Record({ id: IDL.Text, secret: IDL.Text });
+	const ApiKey = IDL.Record({
+		description: IDL.Opt(IDL.Text),
+		collections: IDL.Vec(IDL.Text),
+		created_at: IDL.Nat64,
+		expires_at: IDL.Opt(IDL.Nat64)
+	});
+	const ManifestFeatures = IDL.Record({
+		authentication: IDL.Bool,
+		traces: IDL.Bool,
+		analytics: IDL.Bool,
+		moderation: IDL.Bool,
+		feature_flags: IDL.Bool
+	});
+	const ClientMetadata = IDL.Record({
+		features: ManifestFeatures,
+		extension_candid: IDL.Text,
+		version: IDL.Text,
+		extensions: IDL.Vec(ExtensionKey),
+		candid: IDL.Text,
+		custom_endpoints: IDL.Vec(IDL.Text)
+	});
 	return IDL.Service({
 		build_version: IDL.Func([], [IDL.Text], ['query']),
+		acknowledge_safe_mode: IDL.Func([], [], []),
+		analyze_asset_links: IDL.Func([], [], []),
+		apply_config_bundle: IDL.Func([ConfigBundle], [], []),
+		cancel_action: IDL.Func([IDL.Nat64], [], []),
 		commit_asset_upload: IDL.Func([CommitBatch], [], []),
+		compact_collection: IDL.Func([IDL.Text], [], []),
+		compact_content_chunks: IDL.Func([], [], []),
+		confirm_action: IDL.Func([IDL.Nat64], [], []),
 		count_assets: IDL.Func([IDL.Text, ListParams], [IDL.Nat64], ['query']),
 		count_collection_assets: IDL.Func([IDL.Text], [IDL.Nat64], ['query']),
 		count_collection_docs: IDL.Func([IDL.Text], [IDL.Nat64], ['query']),
 		count_docs: IDL.Func([IDL.Text, ListParams], [IDL.Nat64], ['query']),
+		create_api_key: IDL.Func([SetApiKey], [CreateApiKeyResult], []),
+		create_upload_token: IDL.Func([SetUploadToken], [CreateUploadTokenResult], []),
+		del_api_key: IDL.Func([IDL.Text], [], []),
 		del_asset: IDL.Func([IDL.Text, IDL.Text], [], []),
 		del_assets: IDL.Func([IDL.Text], [], []),
 		del_controllers: IDL.Func(
@@ -254,17 +963,43 @@ export const idlFactory = ({ IDL }) => {
 		del_custom_domain: IDL.Func([IDL.Text], [], []),
 		del_doc: IDL.Func([IDL.Text, IDL.Text, DelDoc], [], []),
 		del_docs: IDL.Func([IDL.Text], [], []),
+		del_feature_flag: IDL.Func([IDL.Text, DelFeatureFlag], [], []),
 		del_filtered_assets: IDL.Func([IDL.Text, ListParams], [], []),
 		del_filtered_docs: IDL.Func([IDL.Text, ListParams], [], []),
 		del_many_assets: IDL.Func([IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text))], [], []),
 		del_many_docs: IDL.Func([IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text, DelDoc))], [], []),
 		del_rule: IDL.Func([RulesType, IDL.Text, DelRule], [], []),
+		del_upload_token: IDL.Func([IDL.Text], [], []),
+		del_webhook: IDL.Func([IDL.Text], [], []),
 		deposit_cycles: IDL.Func([DepositCyclesArgs], [], []),
+		diff_assets: IDL.Func([DiffAssetsArgs], [IDL.Vec(IDL.Text)], ['query']),
+		dry_run: IDL.Func([DryRunOperation], [DryRunReport], ['query']),
+		estimate_query: IDL.Func([RulesType, IDL.Text, ListParams], [QueryEstimate], ['query']),
+		export_chunk: IDL.Func([ExportChunkArgs], [ExportChunk], ['query']),
+		finalize_deployment: IDL.Func([], [], []),
+		get_analytics_config: IDL.Func([], [IDL.Opt(AnalyticsConfig)], ['query']),
+		get_approvals_config: IDL.Func([], [IDL.Opt(ApprovalsConfig)], ['query']),
+		get_asset_links_report: IDL.Func([], [IDL.Opt(AssetLinksReport)], ['query']),
 		get_asset: IDL.Func([IDL.Text, IDL.Text], [IDL.Opt(AssetNoContent)], ['query']),
 		get_auth_config: IDL.Func([], [IDL.Opt(AuthenticationConfig)], ['query']),
+		get_canary_deployment: IDL.Func([], [IDL.Opt(CanaryDeploymentStatus)], ['query']),
+		get_client_metadata: IDL.Func([], [ClientMetadata], ['query']),
+		get_collection_job_report: IDL.Func([], [IDL.Opt(CollectionJobReport)], ['query']),
+		get_collection_stats: IDL.Func([IDL.Text], [CollectionStats], ['query']),
+		get_collection_version: IDL.Func(
+			[RulesType, IDL.Text],
+			[IDL.Opt(CollectionVersion)],
+			['query']
+		),
+		get_compaction_report: IDL.Func([], [IDL.Opt(CompactionReport)], ['query']),
 		get_config: IDL.Func([], [Config], []),
+		get_config_bundle: IDL.Func([], [ConfigBundle], []),
 		get_db_config: IDL.Func([], [IDL.Opt(DbConfig)], ['query']),
 		get_doc: IDL.Func([IDL.Text, IDL.Text], [IDL.Opt(Doc)], ['query']),
+		get_doc_at: IDL.Func([IDL.Text, IDL.Text, IDL.Nat64], [IDL.Opt(Doc)], ['query']),
+		get_doc_chunk: IDL.Func([IDL.Text, IDL.Text, IDL.Nat64], [IDL.Opt(DocChunk)], ['query']),
+		get_feature_flags: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Text, IDL.Bool))], ['query']),
+		get_integrity_report: IDL.Func([], [IDL.Opt(IntegrityReport)], ['query']),
 		get_many_assets: IDL.Func(
 			[IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text))],
 			[IDL.Vec(IDL.Tuple(IDL.Text, IDL.Opt(AssetNoContent)))],
@@ -275,22 +1010,64 @@ export const idlFactory = ({ IDL }) => {
 			[IDL.Vec(IDL.Tuple(IDL.Text, IDL.Opt(Doc)))],
 			['query']
 		),
+		get_memory_stats: IDL.Func([], [IDL.Vec(MemoryRegionStats)], ['query']),
+		get_moderation_config: IDL.Func([], [IDL.Opt(ModerationConfig)], ['query']),
+		get_quota_status: IDL.Func([], [IDL.Opt(QuotaStatus)], ['query']),
+		get_quota_thresholds: IDL.Func([], [IDL.Opt(QuotaThresholds)], ['query']),
+		get_rate_limit_config: IDL.Func([], [IDL.Opt(RateLimitConfig)], ['query']),
 		get_rule: IDL.Func([RulesType, IDL.Text], [IDL.Opt(Rule)], ['query']),
+		get_self_test_report: IDL.Func([], [IDL.Opt(SelfTestReport)], ['query']),
 		get_storage_config: IDL.Func([], [StorageConfig], ['query']),
+		get_traces_config: IDL.Func([], [IDL.Opt(TracesConfig)], ['query']),
+		health: IDL.Func([], [Health], ['query']),
 		http_request: IDL.Func([HttpRequest], [HttpResponse], ['query']),
 		http_request_streaming_callback: IDL.Func(
 			[StreamingCallbackToken],
 			[StreamingCallbackHttpResponse],
 			['query']
 		),
+		http_request_update: IDL.Func([HttpRequest], [HttpResponse], []),
+		icrc10_supported_standards: IDL.Func([], [IDL.Vec(SupportedStandard)], ['query']),
+		icrc21_canister_call_consent_message: IDL.Func([Icrc21ConsentMessageRequest], [Result], []),
+		import_chunk: IDL.Func([ImportChunkArgs], [], []),
+		import_users: IDL.Func([ImportUsers], [ImportUsersReport], []),
 		init_asset_upload: IDL.Func([InitAssetKey], [InitUploadResult], []),
+		list_api_keys: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Text, ApiKey))], ['query']),
 		list_assets: IDL.Func([IDL.Text, ListParams], [ListResults], ['query']),
+		list_assets_page: IDL.Func([IDL.Text, ListParams], [Page], ['query']),
+		list_audit_blocks: IDL.Func([ListAuditBlocks], [AuditBlocks], ['query']),
+		list_certified_paths: IDL.Func([], [IDL.Vec(CertifiedPath)], ['query']),
 		list_controllers: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Principal, Controller))], ['query']),
+		list_controllers_page: IDL.Func([ListPaginate], [Page_1], ['query']),
 		list_custom_domains: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Text, CustomDomain))], ['query']),
 		list_docs: IDL.Func([IDL.Text, ListParams], [ListResults_1], ['query']),
+		list_docs_page: IDL.Func([IDL.Text, ListParams], [Page_2], ['query']),
+		list_extensions: IDL.Func([], [IDL.Vec(IDL.Tuple(ExtensionKey, Extension))], ['query']),
+		list_feature_flags: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Text, FeatureFlag))], ['query']),
+		list_pending_actions: IDL.Func(
+			[],
+			[IDL.Vec(IDL.Tuple(IDL.Nat64, PendingAction))],
+			['query']
+		),
 		list_rules: IDL.Func([RulesType], [IDL.Vec(IDL.Tuple(IDL.Text, Rule))], ['query']),
+		list_traces: IDL.Func([], [IDL.Vec(DailyEndpointTrace)], ['query']),
+		list_upload_tokens: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Text, UploadToken))], ['query']),
+		list_webhook_deliveries: IDL.Func(
+			[],
+			[IDL.Vec(IDL.Tuple(IDL.Nat64, WebhookDelivery))],
+			['query']
+		),
+		list_webhooks: IDL.Func([], [IDL.Vec(IDL.Tuple(IDL.Text, WebhookNoSecret))], ['query']),
 		memory_size: IDL.Func([], [MemorySize], ['query']),
+		patch_doc: IDL.Func([IDL.Text, IDL.Text, PatchDoc], [Doc], []),
+		propose_action: IDL.Func([ControllerAction], [IDL.Nat64], []),
+		rebuild_indexes: IDL.Func([IDL.Text], [], []),
+		set_analytics_config: IDL.Func([AnalyticsConfig], [], []),
+		set_approvals_config: IDL.Func([ApprovalsConfig], [], []),
+		set_asset_moderation: IDL.Func([IDL.Text, IDL.Text, SetModeration], [AssetNoContent], []),
+		set_asset_owner: IDL.Func([IDL.Text, IDL.Text, SetAssetOwner], [AssetNoContent], []),
 		set_auth_config: IDL.Func([AuthenticationConfig], [], []),
+		set_canary_deployment: IDL.Func([SetCanaryDeployment], [CanaryDeploymentStatus], []),
 		set_controllers: IDL.Func(
 			[SetControllersArgs],
 			[IDL.Vec(IDL.Tuple(IDL.Principal, Controller))],
@@ -299,14 +1076,26 @@ export const idlFactory = ({ IDL }) => {
 		set_custom_domain: IDL.Func([IDL.Text, IDL.Opt(IDL.Text)], [], []),
 		set_db_config: IDL.Func([DbConfig], [], []),
 		set_doc: IDL.Func([IDL.Text, IDL.Text, SetDoc], [Doc], []),
+		set_doc_moderation: IDL.Func([IDL.Text, IDL.Text, SetModeration], [Doc], []),
+		set_doc_owner: IDL.Func([IDL.Text, IDL.Text, SetDocOwner], [Doc], []),
+		set_extension_config: IDL.Func([ExtensionKey, SetExtensionConfig], [Extension], []),
+		set_feature_flag: IDL.Func([IDL.Text, SetFeatureFlag], [FeatureFlag], []),
 		set_many_docs: IDL.Func(
 			[IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text, SetDoc))],
 			[IDL.Vec(IDL.Tuple(IDL.Text, Doc))],
 			[]
 		),
+		set_moderation_config: IDL.Func([ModerationConfig], [], []),
+		set_quota_thresholds: IDL.Func([QuotaThresholds], [], []),
+		set_rate_limit_config: IDL.Func([IDL.Opt(RateLimitConfig)], [], []),
 		set_rule: IDL.Func([RulesType, IDL.Text, SetRule], [Rule], []),
+		set_rule_compression: IDL.Func([RulesType, IDL.Text, IDL.Opt(Compression)], [Rule], []),
 		set_storage_config: IDL.Func([StorageConfig], [], []),
+		set_traces_config: IDL.Func([TracesConfig], [], []),
+		set_webhook: IDL.Func([IDL.Text, SetWebhook], [], []),
+		transform_webhook_response: IDL.Func([TransformArgs], [HttpOutcallResponse], ['query']),
 		upload_asset_chunk: IDL.Func([UploadChunk], [UploadChunkResult], []),
+		verify_token_gate: IDL.Func([IDL.Text], [IDL.Bool], []),
 		version: IDL.Func([], [IDL.Text], ['query'])
 	});
 };
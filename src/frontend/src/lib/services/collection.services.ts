@@ -53,7 +53,9 @@ export const setRule = async ({
 						max_tokens: BigInt(maxTokens)
 					}
 				: undefined
-		)
+		),
+		user_quota: isNullish(rule) ? [] : rule.user_quota,
+		collation: isNullish(rule) ? [] : rule.collation
 	};
 
 	await setRuleApi({
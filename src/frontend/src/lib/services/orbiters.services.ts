@@ -84,7 +84,9 @@ export const createOrbiterWithConfig = async ({
 	});
 	return create_orbiter_with_config({
 		name: toNullable(name),
-		subnet_id: toNullable(subnetId)
+		subnet_id: toNullable(subnetId),
+		init_config: toNullable(),
+		project_template: toNullable()
 	});
 };
 
@@ -245,10 +247,11 @@ export const getAnalyticsPerformanceMetrics = async ({
 	return undefined;
 };
 
-const enabledFeatures = {
+const enabledFeatures: OrbiterSatelliteFeatures = {
 	performance_metrics: true,
 	track_events: true,
-	page_views: true
+	page_views: true,
+	error_reports: []
 };
 
 const listOrbiterSatelliteConfigs = async ({
@@ -270,7 +273,10 @@ const listOrbiterSatelliteConfigs = async ({
 		satelliteId,
 		{
 			...rest,
-			features: enabled ? [enabledFeatures] : []
+			features: enabled ? [enabledFeatures] : [],
+			sampling: [],
+			rate_config: [],
+			anonymize: []
 		}
 	]);
 };
@@ -295,6 +301,9 @@ export const setOrbiterSatelliteConfigs = async ({
 				Principal.fromText(satelliteId),
 				{
 					features: value.enabled ? [features ?? enabledFeatures] : [],
+					sampling: nonNullish(value.config) ? value.config.sampling : [],
+					rate_config: nonNullish(value.config) ? value.config.rate_config : [],
+					anonymize: nonNullish(value.config) ? value.config.anonymize : [],
 					version: nonNullish(value.config) ? value.config.version : []
 				}
 			]),
@@ -319,7 +328,10 @@ export const setOrbiterSatelliteConfigs = async ({
 		satelliteId,
 		{
 			...rest,
-			features: enabled ? [enabledFeatures] : []
+			features: enabled ? [enabledFeatures] : [],
+			sampling: [],
+			rate_config: [],
+			anonymize: []
 		}
 	]);
 };
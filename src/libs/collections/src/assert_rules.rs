@@ -1,7 +1,7 @@
 use crate::constants::SYS_COLLECTION_PREFIX;
 use crate::types::core::CollectionKey;
 use crate::types::interface::SetRule;
-use crate::types::rules::{Memory, Rule, Rules};
+use crate::types::rules::{Compression, Memory, Permission, RetentionPolicy, Rule, Rules};
 use junobuild_shared::assert::assert_version;
 use junobuild_shared::types::state::Version;
 
@@ -89,6 +89,31 @@ pub fn assert_write_version(
     Ok(())
 }
 
+pub fn assert_retention(retention: &Option<RetentionPolicy>) -> Result<(), String> {
+    let Some(retention) = retention else {
+        return Ok(());
+    };
+
+    if retention.max_age.is_none() && retention.max_items.is_none() {
+        return Err("A retention policy must define a maximal age or number of items.".to_string());
+    }
+
+    if retention.max_age == Some(0) || retention.max_items == Some(0) {
+        return Err("The limits of a retention policy must be greater than zero.".to_string());
+    }
+
+    Ok(())
+}
+
+/// The entries of the heap are not written to stable memory, therefore they are never compressed.
+pub fn assert_compression(rule: &Rule, compression: &Option<Compression>) -> Result<(), String> {
+    if compression.is_some() && matches!(rule.mem(), Memory::Heap) {
+        return Err("The compression is only supported in stable memory.".to_string());
+    }
+
+    Ok(())
+}
+
 pub fn assert_system_collection_set_permission(
     collection: &CollectionKey,
     current_rule: Option<&Rule>,
@@ -140,6 +165,19 @@ pub fn assert_system_collection_delete_permission(
     Ok(())
 }
 
+/// The holdings of the callers are only verified when the documents of the datastore are accessed.
+pub fn assert_storage_permissions(user_rule: &SetRule) -> Result<(), String> {
+    let token_gated = [&user_rule.read, &user_rule.write]
+        .iter()
+        .any(|permission| matches!(permission, Permission::TokenGated(_)));
+
+    if token_gated {
+        return Err("Token gated permissions are not supported by the storage.".to_string());
+    }
+
+    Ok(())
+}
+
 // In the storage, the collection name must be included within the path (e.g., /hello/index.html for the collection "hello").
 // Therefore, to avoid conflicts with system collections like #dapp and #releases (used in the Console),
 // we need to ensure that the custom collection name does not clash with any reserved system collection names.
@@ -15,6 +15,8 @@ pub fn assert_permission(
         Permission::Private => assert_caller(caller, owner),
         Permission::Managed => assert_caller(caller, owner) || is_controller(caller, controllers),
         Permission::Controllers => is_controller(caller, controllers),
+        // The holdings are verified by the satellite, therefore only the controllers pass the generic assertion.
+        Permission::TokenGated(_) => is_controller(caller, controllers),
     }
 }
 
@@ -30,6 +32,7 @@ pub fn assert_create_permission(
         Permission::Private => assert_not_anonymous(caller),
         Permission::Managed => assert_not_anonymous(caller),
         Permission::Controllers => is_controller(caller, controllers),
+        Permission::TokenGated(_) => is_controller(caller, controllers),
     }
 }
 
@@ -16,6 +16,9 @@ pub const DEFAULT_DB_LOG_RULE: SetRule = SetRule {
     max_capacity: Some(100),
     version: None,
     rate_config: None,
+    user_quota: None,
+    collation: None,
+    retention: None,
 };
 
 pub const DEFAULT_DB_COLLECTIONS: [(&str, SetRule); 2] = [
@@ -30,6 +33,9 @@ pub const DEFAULT_DB_COLLECTIONS: [(&str, SetRule); 2] = [
             max_capacity: None,
             version: None,
             rate_config: Some(DEFAULT_RATE_CONFIG),
+            user_quota: None,
+            collation: None,
+            retention: None,
         },
     ),
     (LOG_COLLECTION_KEY, DEFAULT_DB_LOG_RULE),
@@ -48,5 +54,8 @@ pub const DEFAULT_ASSETS_COLLECTIONS: [(&str, SetRule); 1] = [(
         max_capacity: None,
         version: None,
         rate_config: None,
+        user_quota: None,
+        collation: None,
+        retention: None,
     },
 )];
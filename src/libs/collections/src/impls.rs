@@ -1,7 +1,7 @@
 use crate::constants::SYS_COLLECTION_PREFIX;
 use crate::types::core::CollectionKey;
 use crate::types::interface::SetRule;
-use crate::types::rules::{Memory, Rule};
+use crate::types::rules::{Compression, Memory, Rule};
 use ic_cdk::api::time;
 use junobuild_shared::constants::INITIAL_VERSION;
 use junobuild_shared::types::state::{Timestamp, Version};
@@ -23,6 +23,18 @@ impl Rule {
         Ok(Self::prepare_user_rule(current_rule, user_rule))
     }
 
+    pub fn prepare_compression(current_rule: &Rule, compression: Option<Compression>) -> Self {
+        let (created_at, version, updated_at) = Self::initialize_common_fields(&Some(current_rule));
+
+        Rule {
+            created_at,
+            updated_at,
+            version: Some(version),
+            compression,
+            ..current_rule.clone()
+        }
+    }
+
     fn initialize_common_fields(current_rule: &Option<&Rule>) -> (Timestamp, Version, Timestamp) {
         let now = time();
 
@@ -55,6 +67,10 @@ impl Rule {
             updated_at,
             version: Some(version),
             rate_config: user_rule.rate_config.clone(),
+            user_quota: user_rule.user_quota.clone(),
+            collation: user_rule.collation.clone(),
+            retention: user_rule.retention.clone(),
+            compression: current_rule.and_then(|current_rule| current_rule.compression.clone()),
         }
     }
 
@@ -80,6 +96,10 @@ impl Rule {
                     updated_at,
                     version: Some(version),
                     rate_config: user_rule.rate_config.clone(),
+                    user_quota: current_rule.user_quota.clone(),
+                    collation: current_rule.collation.clone(),
+                    retention: current_rule.retention.clone(),
+                    compression: current_rule.compression.clone(),
                 };
 
                 Ok(rule)
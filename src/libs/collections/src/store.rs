@@ -1,12 +1,14 @@
 use crate::assert_rules::{
-    assert_memory, assert_mutable_permissions, assert_storage_reserved_collection,
+    assert_compression, assert_memory, assert_mutable_permissions, assert_retention,
+    assert_storage_permissions, assert_storage_reserved_collection,
     assert_system_collection_delete_permission, assert_system_collection_set_permission,
     assert_write_version,
 };
 use crate::constants::SYS_COLLECTION_PREFIX;
+use crate::msg::{msg_db_collection_not_found, msg_storage_collection_not_found};
 use crate::types::core::CollectionKey;
 use crate::types::interface::{DelRule, SetRule};
-use crate::types::rules::{Rule, Rules};
+use crate::types::rules::{Compression, Rule, Rules};
 
 // ---------------------------------------------------------
 // Rules
@@ -34,10 +36,12 @@ pub fn set_rule(
 
     if storage_checks {
         assert_storage_reserved_collection(&collection, rules)?;
+        assert_storage_permissions(&user_rule)?;
     }
 
     assert_memory(current_rule, &user_rule.memory)?;
     assert_mutable_permissions(current_rule, &user_rule)?;
+    assert_retention(&user_rule.retention)?;
 
     let rule: Rule = Rule::prepare(&collection, &current_rule, &user_rule)?;
 
@@ -46,6 +50,31 @@ pub fn set_rule(
     Ok(rule)
 }
 
+/// The compression is set apart from the other settings of the rule. It only applies to the entries written afterwards,
+/// the existing entries being read as they were stored.
+pub fn set_rule_compression(
+    collection: CollectionKey,
+    compression: Option<Compression>,
+    storage_checks: bool,
+    rules: &mut Rules,
+) -> Result<Rule, String> {
+    let current_rule = rules.get(&collection).ok_or_else(|| {
+        if storage_checks {
+            msg_storage_collection_not_found(&collection)
+        } else {
+            msg_db_collection_not_found(&collection)
+        }
+    })?;
+
+    assert_compression(current_rule, &compression)?;
+
+    let rule: Rule = Rule::prepare_compression(current_rule, compression);
+
+    rules.insert(collection, rule.clone());
+
+    Ok(rule)
+}
+
 pub fn del_rule(
     collection: CollectionKey,
     user_rule: DelRule,
@@ -10,9 +10,10 @@ pub mod core {
 
 pub mod rules {
     use crate::types::core::CollectionKey;
-    use candid::CandidType;
+    use candid::{CandidType, Nat, Principal};
     use junobuild_shared::rate::types::RateConfig;
     use junobuild_shared::serializers::deserialize_default_as_true;
+    use junobuild_shared::types::list::KeyCollation;
     use junobuild_shared::types::state::Timestamp;
     use junobuild_shared::types::state::Version;
     use serde::{Deserialize, Serialize};
@@ -33,6 +34,44 @@ pub mod rules {
         pub updated_at: Timestamp,
         pub version: Option<Version>,
         pub rate_config: Option<RateConfig>,
+        pub user_quota: Option<UserQuota>,
+        /// The ordering of the keys when the documents or assets are listed. Binary if not set.
+        pub collation: Option<KeyCollation>,
+        /// The documents or assets that are periodically purged from the collection. Kept forever if not set.
+        pub retention: Option<RetentionPolicy>,
+        /// The codec used to compress the documents or the content of the assets written to stable memory. Not
+        /// compressed if not set. Set apart from the other settings of the rule.
+        pub compression: Option<Compression>,
+    }
+
+    /// The maximal usage of each user in a collection, counted over the documents or assets they own.
+    /// Controllers are not subject to the quotas.
+    ///
+    /// - `max_items`: The maximal number of documents or assets per user.
+    /// - `max_bytes`: The maximal size per user - i.e. the size of the data of the documents or of the content of the assets.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct UserQuota {
+        pub max_items: Option<u64>,
+        pub max_bytes: Option<u128>,
+    }
+
+    /// The retention of the documents or assets of a collection, enforced by a periodic purge. When both are set, an
+    /// entry is purged as soon as one of the limits is exceeded.
+    ///
+    /// - `max_age`: The duration in nanoseconds after which an entry that was not updated is purged.
+    /// - `max_items`: The number of entries that are kept - the most recently updated - per collection.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct RetentionPolicy {
+        pub max_age: Option<u64>,
+        pub max_items: Option<u64>,
+    }
+
+    /// A fast codec used to compress the entries at rest. Only the payloads that are large enough and that shrink once
+    /// compressed are stored compressed, each entry recording whether it was compressed or not. Therefore the codec of a
+    /// collection can be modified at any time, the existing entries being decompressed transparently.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq)]
+    pub enum Compression {
+        Deflate,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Default, Clone, PartialEq)]
@@ -55,17 +94,37 @@ pub mod rules {
         Managed,
         // The controllers - and only these - can rule the document
         Controllers,
+        // The holders of a token - verified by the satellite - and the controllers can rule the document
+        TokenGated(Box<TokenGate>),
+    }
+
+    /// The holdings required to access a token gated collection.
+    ///
+    /// - `ledger_id`: The ledger of the ICRC-1 token or of the ICRC-7 collection of NFTs.
+    /// - `min_balance`: The minimal balance of the caller - i.e. in the smallest unit of the token, or a number of NFTs.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq)]
+    pub struct TokenGate {
+        pub ledger_id: Principal,
+        pub standard: TokenStandard,
+        pub min_balance: Nat,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq)]
+    pub enum TokenStandard {
+        Icrc1,
+        Icrc7,
     }
 }
 
 pub mod interface {
-    use crate::types::rules::{Memory, Permission};
+    use crate::types::rules::{Memory, Permission, RetentionPolicy, UserQuota};
     use candid::CandidType;
     use junobuild_shared::rate::types::RateConfig;
+    use junobuild_shared::types::list::KeyCollation;
     use junobuild_shared::types::state::Version;
-    use serde::Deserialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(CandidType, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct SetRule {
         pub read: Permission,
         pub write: Permission,
@@ -75,9 +134,12 @@ pub mod interface {
         pub max_capacity: Option<u32>,
         pub version: Option<Version>,
         pub rate_config: Option<RateConfig>,
+        pub user_quota: Option<UserQuota>,
+        pub collation: Option<KeyCollation>,
+        pub retention: Option<RetentionPolicy>,
     }
 
-    #[derive(Default, CandidType, Deserialize, Clone)]
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
     pub struct DelRule {
         pub version: Option<Version>,
     }
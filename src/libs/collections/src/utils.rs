@@ -1,4 +1,7 @@
 use crate::types::core::CollectionKey;
+use crate::types::rules::RetentionPolicy;
+use junobuild_shared::types::state::Timestamp;
+use std::collections::BTreeSet;
 
 pub fn range_collection_end(collection: &CollectionKey) -> CollectionKey {
     // Source: https://github.com/frederikrothenberger
@@ -8,3 +11,27 @@ pub fn range_collection_end(collection: &CollectionKey) -> CollectionKey {
 
     end_collection
 }
+
+/// Selects the entries of an index - their last update and their key, the oldest first - that exceed a retention
+/// policy. Given the order, the expired entries are the first ones: those beyond the maximal number of items, counted
+/// from the most recent, or older than the maximal age. Therefore only the expired entries are iterated.
+pub fn filter_expired_keys<'a>(
+    index: &'a BTreeSet<(Timestamp, String)>,
+    retention: &'a RetentionPolicy,
+    now: Timestamp,
+) -> impl Iterator<Item = &'a String> + 'a {
+    let excess = retention.max_items.map_or(0, |max_items| {
+        index.len().saturating_sub(max_items as usize)
+    });
+
+    index
+        .iter()
+        .enumerate()
+        .take_while(move |(position, (updated_at, _))| {
+            *position < excess
+                || retention
+                    .max_age
+                    .map_or(false, |max_age| now.saturating_sub(*updated_at) > max_age)
+        })
+        .map(|(_, (_, key))| key)
+}
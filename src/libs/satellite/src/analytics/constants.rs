@@ -0,0 +1,12 @@
+use junobuild_shared::rate::types::RateConfig;
+
+/// The maximal size of the body of a ping - i.e. its JSON payload.
+pub const MAX_ANALYTICS_PAYLOAD_SIZE: usize = 16 * 1024;
+
+pub const ANALYTICS_RATE_KEY: &str = "analytics";
+
+/// A burst of 1000 pings, after which a token is refilled every 10 milliseconds - i.e. 100 pings per second.
+pub const DEFAULT_ANALYTICS_RATE_CONFIG: RateConfig = RateConfig {
+    max_tokens: 1_000,
+    time_per_token_ns: 10_000_000,
+};
@@ -0,0 +1,190 @@
+use crate::analytics::constants::MAX_ANALYTICS_PAYLOAD_SIZE;
+use crate::analytics::msg::{
+    ERROR_ANALYTICS_NOT_CONFIGURED, ERROR_ANALYTICS_PAYLOAD_TOO_LARGE,
+    ERROR_ANALYTICS_UNKNOWN_ENDPOINT,
+};
+use crate::analytics::orbiter::{set_page_view, set_track_event};
+use crate::analytics::store::{assert_analytics_rate, get_config};
+use crate::analytics::types::http::{
+    AnalyticKeyPayload, PageViewPayload, SetPageViewPayload, SetTrackEventPayload,
+    TrackEventPayload,
+};
+use crate::analytics::types::orbiter::{AnalyticKey, PageViewDevice, SetPageView, SetTrackEvent};
+use ic_cdk::id;
+use junobuild_shared::types::state::OrbiterId;
+use junobuild_storage::http::response::upgrade_response;
+use junobuild_storage::http::types::{HeaderField, HttpRequest, HttpResponse, StatusCode};
+use junobuild_storage::http::utils::get_header_value;
+use serde::de::DeserializeOwned;
+
+const ANALYTICS_PATH: &str = "/_juno/analytics/";
+pub const ANALYTICS_PAGE_VIEW_PATH: &str = "/_juno/analytics/page-view";
+pub const ANALYTICS_TRACK_EVENT_PATH: &str = "/_juno/analytics/track-event";
+
+const RESPONSE_STATUS_CODE_202: StatusCode = 202;
+const RESPONSE_STATUS_CODE_400: StatusCode = 400;
+const RESPONSE_STATUS_CODE_404: StatusCode = 404;
+const RESPONSE_STATUS_CODE_413: StatusCode = 413;
+const RESPONSE_STATUS_CODE_429: StatusCode = 429;
+const RESPONSE_STATUS_CODE_502: StatusCode = 502;
+
+/// Pings are sent with POST to the satellite itself - i.e. same origin - which avoids the calls to a third-party canister blocked by strict CSP or ad-blockers.
+pub fn is_analytics_request(HttpRequest { method, url, .. }: &HttpRequest) -> bool {
+    method == "POST" && url.starts_with(ANALYTICS_PATH)
+}
+
+/// The pings are upgraded to an update call - the only one that can forward them to the orbiter - unless they are
+/// too large or the analytics are not configured, so that those do not cost an update.
+pub fn http_request(HttpRequest { body, .. }: &HttpRequest) -> HttpResponse {
+    if body.len() > MAX_ANALYTICS_PAYLOAD_SIZE {
+        return response(
+            RESPONSE_STATUS_CODE_413,
+            ERROR_ANALYTICS_PAYLOAD_TOO_LARGE.to_string(),
+        );
+    }
+
+    if get_config().and_then(|config| config.orbiter_id).is_none() {
+        return response(
+            RESPONSE_STATUS_CODE_404,
+            ERROR_ANALYTICS_NOT_CONFIGURED.to_string(),
+        );
+    }
+
+    upgrade_response()
+}
+
+pub async fn http_request_update(
+    HttpRequest {
+        url, body, headers, ..
+    }: HttpRequest,
+) -> HttpResponse {
+    if body.len() > MAX_ANALYTICS_PAYLOAD_SIZE {
+        return response(
+            RESPONSE_STATUS_CODE_413,
+            ERROR_ANALYTICS_PAYLOAD_TOO_LARGE.to_string(),
+        );
+    }
+
+    let config = get_config().unwrap_or_default();
+
+    let orbiter_id = match config.orbiter_id {
+        None => {
+            return response(
+                RESPONSE_STATUS_CODE_404,
+                ERROR_ANALYTICS_NOT_CONFIGURED.to_string(),
+            );
+        }
+        Some(orbiter_id) => orbiter_id,
+    };
+
+    // The pings are not authenticated, therefore a single bucket limits the inter-canister calls they trigger
+    if let Err(err) = assert_analytics_rate(&config) {
+        return response(RESPONSE_STATUS_CODE_429, err);
+    }
+
+    let path = url.split('?').next().unwrap_or_default();
+
+    let result = match path {
+        ANALYTICS_PAGE_VIEW_PATH => forward_page_view(orbiter_id, &body, &headers).await,
+        ANALYTICS_TRACK_EVENT_PATH => forward_track_event(orbiter_id, &body, &headers).await,
+        _ => Err((
+            RESPONSE_STATUS_CODE_404,
+            ERROR_ANALYTICS_UNKNOWN_ENDPOINT.to_string(),
+        )),
+    };
+
+    match result {
+        Ok(_) => response(RESPONSE_STATUS_CODE_202, "".to_string()),
+        Err((status_code, message)) => response(status_code, message),
+    }
+}
+
+async fn forward_page_view(
+    orbiter_id: OrbiterId,
+    body: &[u8],
+    headers: &[HeaderField],
+) -> Result<(), (StatusCode, String)> {
+    let PageViewPayload { key, page_view } = decode_payload::<PageViewPayload>(body)?;
+
+    let SetPageViewPayload {
+        title,
+        href,
+        referrer,
+        device,
+        time_zone,
+        user_agent,
+        session_id,
+        version,
+    } = page_view;
+
+    let page_view = SetPageView {
+        title,
+        href,
+        referrer,
+        device: PageViewDevice {
+            inner_width: device.inner_width,
+            inner_height: device.inner_height,
+        },
+        time_zone,
+        user_agent: user_agent.or_else(|| get_header_value(headers, "User-Agent")),
+        satellite_id: id(),
+        session_id,
+        version,
+    };
+
+    set_page_view(orbiter_id, map_key(key), page_view)
+        .await
+        .map_err(|err| (RESPONSE_STATUS_CODE_502, err))
+}
+
+async fn forward_track_event(
+    orbiter_id: OrbiterId,
+    body: &[u8],
+    headers: &[HeaderField],
+) -> Result<(), (StatusCode, String)> {
+    let TrackEventPayload { key, track_event } = decode_payload::<TrackEventPayload>(body)?;
+
+    let SetTrackEventPayload {
+        name,
+        metadata,
+        user_agent,
+        session_id,
+        version,
+    } = track_event;
+
+    let track_event = SetTrackEvent {
+        name,
+        metadata,
+        user_agent: user_agent.or_else(|| get_header_value(headers, "User-Agent")),
+        satellite_id: id(),
+        session_id,
+        version,
+    };
+
+    set_track_event(orbiter_id, map_key(key), track_event)
+        .await
+        .map_err(|err| (RESPONSE_STATUS_CODE_502, err))
+}
+
+fn decode_payload<T: DeserializeOwned>(body: &[u8]) -> Result<T, (StatusCode, String)> {
+    serde_json::from_slice::<T>(body).map_err(|err| {
+        (
+            RESPONSE_STATUS_CODE_400,
+            format!("Invalid analytics payload: {}.", err),
+        )
+    })
+}
+
+fn map_key(AnalyticKeyPayload { collected_at, key }: AnalyticKeyPayload) -> AnalyticKey {
+    AnalyticKey { collected_at, key }
+}
+
+fn response(status_code: StatusCode, body: String) -> HttpResponse {
+    HttpResponse {
+        body: body.as_bytes().to_vec(),
+        headers: Vec::new(),
+        status_code,
+        streaming_strategy: None,
+        upgrade: None,
+    }
+}
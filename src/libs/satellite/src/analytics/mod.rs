@@ -0,0 +1,7 @@
+mod constants;
+pub mod http;
+mod msg;
+mod orbiter;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,3 @@
+pub const ERROR_ANALYTICS_NOT_CONFIGURED: &str = "No orbiter configured to forward the analytics.";
+pub const ERROR_ANALYTICS_UNKNOWN_ENDPOINT: &str = "Unknown analytics endpoint.";
+pub const ERROR_ANALYTICS_PAYLOAD_TOO_LARGE: &str = "Analytics payload exceeds the maximal size.";
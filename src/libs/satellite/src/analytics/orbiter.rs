@@ -0,0 +1,36 @@
+use crate::analytics::types::orbiter::{AnalyticKey, SetPageView, SetTrackEvent};
+use candid::types::reserved::Reserved;
+use ic_cdk::api::call::CallResult;
+use ic_cdk::call;
+use junobuild_shared::types::state::OrbiterId;
+
+// The page view or track event saved by the orbiter is not needed by the satellite, therefore its content is ignored.
+
+pub async fn set_page_view(
+    orbiter_id: OrbiterId,
+    key: AnalyticKey,
+    page_view: SetPageView,
+) -> Result<(), String> {
+    let result: CallResult<(Result<Reserved, String>,)> =
+        call(orbiter_id, "set_page_view", (key, page_view)).await;
+
+    map_result(result)
+}
+
+pub async fn set_track_event(
+    orbiter_id: OrbiterId,
+    key: AnalyticKey,
+    track_event: SetTrackEvent,
+) -> Result<(), String> {
+    let result: CallResult<(Result<Reserved, String>,)> =
+        call(orbiter_id, "set_track_event", (key, track_event)).await;
+
+    map_result(result)
+}
+
+fn map_result(result: CallResult<(Result<Reserved, String>,)>) -> Result<(), String> {
+    match result {
+        Err((_, message)) => Err(["Forwarding to the orbiter failed.", &message].join(" - ")),
+        Ok((result,)) => result.map(|_| ()),
+    }
+}
@@ -0,0 +1,56 @@
+use crate::analytics::types::config::AnalyticsConfig;
+use crate::analytics::types::state::AnalyticsHeapState;
+use crate::memory::STATE;
+use junobuild_shared::rate::types::RateConfig;
+use junobuild_shared::rate::utils::increment_and_assert_rate_store;
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+pub fn get_config() -> Option<AnalyticsConfig> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .analytics
+            .as_ref()
+            .map(|analytics| analytics.config.clone())
+    })
+}
+
+pub fn insert_config(config: &AnalyticsConfig) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+
+        insert_config_impl(config, &mut state.heap.analytics);
+
+        // The tokens accumulated with a previous configuration are reset
+        state.runtime.analytics.rate_tokens.clear();
+    })
+}
+
+fn insert_config_impl(config: &AnalyticsConfig, state: &mut Option<AnalyticsHeapState>) {
+    match state {
+        None => {
+            *state = Some(AnalyticsHeapState {
+                config: config.clone(),
+            })
+        }
+        Some(state) => state.config = config.clone(),
+    }
+}
+
+// ---------------------------------------------------------
+// Rate
+// ---------------------------------------------------------
+
+pub fn increment_and_assert_rate(key: &String, config: &RateConfig) -> Result<(), String> {
+    STATE.with(|state| {
+        increment_and_assert_rate_store(
+            key,
+            &Some(config.clone()),
+            &mut state.borrow_mut().runtime.analytics.rate_tokens,
+        )
+    })
+}
@@ -0,0 +1,33 @@
+use crate::analytics::constants::{ANALYTICS_RATE_KEY, DEFAULT_ANALYTICS_RATE_CONFIG};
+use crate::analytics::state::{
+    get_config as get_state_config, increment_and_assert_rate, insert_config as insert_state_config,
+};
+use crate::analytics::types::config::AnalyticsConfig;
+use crate::manifest::http::certify_manifest;
+use crate::rate_limit::assert::assert_rate_config;
+
+pub fn set_config(config: &AnalyticsConfig) -> Result<(), String> {
+    if let Some(rate_config) = &config.rate_config {
+        assert_rate_config(rate_config)?;
+    }
+
+    insert_state_config(config);
+
+    certify_manifest();
+
+    Ok(())
+}
+
+pub fn get_config() -> Option<AnalyticsConfig> {
+    get_state_config()
+}
+
+/// Deducts a token from the bucket shared by the pings received over HTTP and rejects the ping if it is empty.
+pub fn assert_analytics_rate(config: &AnalyticsConfig) -> Result<(), String> {
+    let rate_config = config
+        .rate_config
+        .clone()
+        .unwrap_or(DEFAULT_ANALYTICS_RATE_CONFIG);
+
+    increment_and_assert_rate(&ANALYTICS_RATE_KEY.to_string(), &rate_config)
+}
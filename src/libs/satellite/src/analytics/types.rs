@@ -0,0 +1,127 @@
+pub mod state {
+    use crate::analytics::types::config::AnalyticsConfig;
+    use candid::CandidType;
+    use junobuild_shared::rate::types::RateTokenStore;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct AnalyticsHeapState {
+        pub config: AnalyticsConfig,
+    }
+
+    #[derive(Default, Clone)]
+    pub struct AnalyticsRuntimeState {
+        pub rate_tokens: RateTokenStore,
+    }
+}
+
+pub mod config {
+    use candid::{CandidType, Deserialize};
+    use junobuild_shared::rate::types::RateConfig;
+    use junobuild_shared::types::state::OrbiterId;
+    use serde::Serialize;
+
+    /// - `orbiter_id`: The orbiter to which the pings are forwarded.
+    /// - `rate_config`: The token bucket shared by all the pings received over HTTP, which are not authenticated and
+    ///   each cost an update and an inter-canister call. A default bucket applies if not set.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct AnalyticsConfig {
+        pub orbiter_id: Option<OrbiterId>,
+        pub rate_config: Option<RateConfig>,
+    }
+}
+
+pub mod http {
+    use junobuild_shared::types::state::{Metadata, Timestamp, Version};
+    use serde::Deserialize;
+
+    // The payloads the HTTP endpoints accept in JSON. Those mirror the parameters of the orbiter without the satellite ID, which is always the one of the satellite proxying the request.
+
+    #[derive(Deserialize)]
+    pub struct AnalyticKeyPayload {
+        pub collected_at: Timestamp,
+        pub key: String,
+    }
+
+    #[derive(Deserialize)]
+    pub struct PageViewDevicePayload {
+        pub inner_width: u16,
+        pub inner_height: u16,
+    }
+
+    #[derive(Deserialize)]
+    pub struct SetPageViewPayload {
+        pub title: String,
+        pub href: String,
+        pub referrer: Option<String>,
+        pub device: PageViewDevicePayload,
+        pub time_zone: String,
+        pub user_agent: Option<String>,
+        pub session_id: String,
+        pub version: Option<Version>,
+    }
+
+    #[derive(Deserialize)]
+    pub struct SetTrackEventPayload {
+        pub name: String,
+        pub metadata: Option<Metadata>,
+        pub user_agent: Option<String>,
+        pub session_id: String,
+        pub version: Option<Version>,
+    }
+
+    #[derive(Deserialize)]
+    pub struct PageViewPayload {
+        pub key: AnalyticKeyPayload,
+        pub page_view: SetPageViewPayload,
+    }
+
+    #[derive(Deserialize)]
+    pub struct TrackEventPayload {
+        pub key: AnalyticKeyPayload,
+        pub track_event: SetTrackEventPayload,
+    }
+}
+
+pub mod orbiter {
+    use candid::CandidType;
+    use junobuild_shared::types::state::{Metadata, SatelliteId, Timestamp, Version};
+    use serde::Deserialize;
+
+    // Subset of the interface of the orbiter used to forward the analytics.
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct AnalyticKey {
+        pub collected_at: Timestamp,
+        pub key: String,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct PageViewDevice {
+        pub inner_width: u16,
+        pub inner_height: u16,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct SetPageView {
+        pub title: String,
+        pub href: String,
+        pub referrer: Option<String>,
+        pub device: PageViewDevice,
+        pub time_zone: String,
+        pub user_agent: Option<String>,
+        pub satellite_id: SatelliteId,
+        pub session_id: String,
+        pub version: Option<Version>,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct SetTrackEvent {
+        pub name: String,
+        pub metadata: Option<Metadata>,
+        pub user_agent: Option<String>,
+        pub satellite_id: SatelliteId,
+        pub session_id: String,
+        pub version: Option<Version>,
+    }
+}
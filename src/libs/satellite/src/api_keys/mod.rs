@@ -0,0 +1,4 @@
+mod msg;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,7 @@
+pub const ERROR_API_KEY_NO_COLLECTION: &str = "API keys require at least one collection.";
+pub const ERROR_API_KEY_UNKNOWN_COLLECTION: &str =
+    "API keys require existing datastore collections.";
+pub const ERROR_API_KEY_EXPIRES_IN_THE_PAST: &str = "API key expiration must be in the future.";
+pub const ERROR_API_KEY_NOT_FOUND: &str = "API key not found.";
+pub const ERROR_API_KEY_UNAUTHORIZED: &str = "Unknown or expired API key.";
+pub const ERROR_API_KEY_FORBIDDEN: &str = "The API key does not grant access to this collection.";
@@ -0,0 +1,46 @@
+use crate::api_keys::types::state::{ApiKey, ApiKeyId, ApiKeys, ApiKeysHeapState};
+use crate::memory::STATE;
+
+pub fn get_api_key(id: &ApiKeyId) -> Option<ApiKey> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .api_keys
+            .as_ref()
+            .and_then(|api_keys| api_keys.keys.get(id).cloned())
+    })
+}
+
+pub fn get_api_keys() -> ApiKeys {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .api_keys
+            .as_ref()
+            .map(|api_keys| api_keys.keys.clone())
+            .unwrap_or_default()
+    })
+}
+
+pub fn insert_api_key(id: &ApiKeyId, api_key: &ApiKey) {
+    STATE.with(|state| insert_api_key_impl(id, api_key, &mut state.borrow_mut().heap.api_keys))
+}
+
+pub fn delete_api_key(id: &ApiKeyId) {
+    STATE.with(|state| delete_api_key_impl(id, &mut state.borrow_mut().heap.api_keys))
+}
+
+fn insert_api_key_impl(id: &ApiKeyId, api_key: &ApiKey, state: &mut Option<ApiKeysHeapState>) {
+    state
+        .get_or_insert_with(ApiKeysHeapState::default)
+        .keys
+        .insert(id.clone(), api_key.clone());
+}
+
+fn delete_api_key_impl(id: &ApiKeyId, state: &mut Option<ApiKeysHeapState>) {
+    if let Some(state) = state {
+        state.keys.remove(id);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::api_keys::msg::{
+    ERROR_API_KEY_EXPIRES_IN_THE_PAST, ERROR_API_KEY_FORBIDDEN, ERROR_API_KEY_NOT_FOUND,
+    ERROR_API_KEY_NO_COLLECTION, ERROR_API_KEY_UNAUTHORIZED, ERROR_API_KEY_UNKNOWN_COLLECTION,
+};
+use crate::api_keys::state::{delete_api_key, get_api_key, get_api_keys, insert_api_key};
+use crate::api_keys::types::interface::{CreateApiKeyResult, SetApiKey};
+use crate::api_keys::types::state::{ApiKey, ApiKeyId};
+use crate::rules::store::get_rule_db;
+use crate::uploads::store::{hash_secret, random_secret};
+use ic_cdk::api::time;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::assert::assert_description_length;
+
+pub fn create_api_key_store(api_key: &SetApiKey) -> Result<CreateApiKeyResult, String> {
+    if api_key.collections.is_empty() {
+        return Err(ERROR_API_KEY_NO_COLLECTION.to_string());
+    }
+
+    if api_key
+        .collections
+        .iter()
+        .any(|collection| get_rule_db(collection).is_none())
+    {
+        return Err(ERROR_API_KEY_UNKNOWN_COLLECTION.to_string());
+    }
+
+    assert_description_length(&api_key.description)?;
+
+    let now = time();
+
+    if api_key
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= now)
+    {
+        return Err(ERROR_API_KEY_EXPIRES_IN_THE_PAST.to_string());
+    }
+
+    let secret = random_secret()?;
+    let id = hash_secret(&secret);
+
+    insert_api_key(
+        &id,
+        &ApiKey {
+            collections: api_key.collections.clone(),
+            description: api_key.description.clone(),
+            expires_at: api_key.expires_at,
+            created_at: now,
+        },
+    );
+
+    Ok(CreateApiKeyResult { id, secret })
+}
+
+pub fn list_api_keys_store() -> Vec<(ApiKeyId, ApiKey)> {
+    get_api_keys().into_iter().collect()
+}
+
+/// Revokes a key. The backends using it are rejected from the next request on.
+pub fn delete_api_key_store(id: &ApiKeyId) -> Result<(), String> {
+    if get_api_key(id).is_none() {
+        return Err(ERROR_API_KEY_NOT_FOUND.to_string());
+    }
+
+    delete_api_key(id);
+
+    Ok(())
+}
+
+/// The keys are read-only, therefore only the read requests should be authenticated with this function.
+pub fn authenticate_api_key(secret: &str) -> Result<ApiKey, String> {
+    let api_key =
+        get_api_key(&hash_secret(secret)).ok_or_else(|| ERROR_API_KEY_UNAUTHORIZED.to_string())?;
+
+    if api_key
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= time())
+    {
+        return Err(ERROR_API_KEY_UNAUTHORIZED.to_string());
+    }
+
+    Ok(api_key)
+}
+
+pub fn assert_api_key_scope(api_key: &ApiKey, collection: &CollectionKey) -> Result<(), String> {
+    if !api_key.collections.contains(collection) {
+        return Err(ERROR_API_KEY_FORBIDDEN.to_string());
+    }
+
+    Ok(())
+}
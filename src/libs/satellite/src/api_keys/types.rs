@@ -0,0 +1,52 @@
+pub mod state {
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::state::Timestamp;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    /// The SHA-256 of the secret of the key, in hexadecimal. The secret itself is not kept by the satellite.
+    pub type ApiKeyId = String;
+
+    pub type ApiKeys = HashMap<ApiKeyId, ApiKey>;
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct ApiKeysHeapState {
+        pub keys: ApiKeys,
+    }
+
+    /// A key which lets backends without an identity read the documents of some collections over HTTP.
+    ///
+    /// - `collections`: The datastore collections the key can read, regardless of whether these are exposed over
+    ///   HTTP. The documents of the private collections remain readable by their owners only.
+    /// - `description`: An optional description - e.g. the backend using the key - limited to 1024 characters.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ApiKey {
+        pub collections: Vec<CollectionKey>,
+        pub description: Option<String>,
+        pub expires_at: Option<Timestamp>,
+        pub created_at: Timestamp,
+    }
+}
+
+pub mod interface {
+    use crate::api_keys::types::state::ApiKeyId;
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::state::Timestamp;
+    use serde::Deserialize;
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct SetApiKey {
+        pub collections: Vec<CollectionKey>,
+        pub description: Option<String>,
+        pub expires_at: Option<Timestamp>,
+    }
+
+    /// The secret is returned only once, when the key is created.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct CreateApiKeyResult {
+        pub id: ApiKeyId,
+        pub secret: String,
+    }
+}
@@ -0,0 +1,2 @@
+// The time window within which a proposed action can be confirmed, if not configured
+pub const DEFAULT_CONFIRMATION_WINDOW_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
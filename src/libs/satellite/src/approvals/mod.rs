@@ -0,0 +1,5 @@
+mod constants;
+mod msg;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,9 @@
+pub const ERROR_APPROVAL_REQUIRED: &str =
+    "The operation must be proposed by a controller and confirmed by another.";
+pub const ERROR_APPROVALS_NOT_ENABLED: &str =
+    "The approval of the controller operations is not enabled.";
+pub const ERROR_ACTION_NOT_FOUND: &str = "Pending action not found.";
+pub const ERROR_ACTION_EXPIRED: &str = "The pending action has expired.";
+pub const ERROR_ACTION_SELF_CONFIRMATION: &str =
+    "A pending action cannot be confirmed by the controller that proposed it.";
+pub const ERROR_CONFIRMATION_WINDOW_ZERO: &str = "The confirmation window cannot be zero.";
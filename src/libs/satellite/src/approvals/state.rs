@@ -0,0 +1,90 @@
+use crate::approvals::types::config::ApprovalsConfig;
+use crate::approvals::types::state::{ActionId, ApprovalsHeapState, PendingAction, PendingActions};
+use crate::memory::STATE;
+use junobuild_shared::types::state::Timestamp;
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+pub fn get_config() -> Option<ApprovalsConfig> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .approvals
+            .as_ref()
+            .map(|approvals| approvals.config.clone())
+    })
+}
+
+pub fn insert_config(config: &ApprovalsConfig) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .approvals
+            .get_or_insert_with(ApprovalsHeapState::default)
+            .config = config.clone();
+    })
+}
+
+// ---------------------------------------------------------
+// Pending actions
+// ---------------------------------------------------------
+
+pub fn get_pending_actions() -> PendingActions {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .approvals
+            .as_ref()
+            .map(|approvals| approvals.pending.clone())
+            .unwrap_or_default()
+    })
+}
+
+pub fn get_pending_action(id: &ActionId) -> Option<PendingAction> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .approvals
+            .as_ref()
+            .and_then(|approvals| approvals.pending.get(id).cloned())
+    })
+}
+
+/// Inserts the action with the next id and removes those that have expired meanwhile.
+pub fn insert_pending_action(action: PendingAction, now: Timestamp) -> ActionId {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let approvals = state
+            .heap
+            .approvals
+            .get_or_insert_with(ApprovalsHeapState::default);
+
+        approvals
+            .pending
+            .retain(|_, pending| pending.expires_at > now);
+
+        let id = approvals.next_id;
+
+        approvals.pending.insert(id, action);
+        approvals.next_id += 1;
+
+        id
+    })
+}
+
+pub fn delete_pending_action(id: &ActionId) -> Option<PendingAction> {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .approvals
+            .as_mut()
+            .and_then(|approvals| approvals.pending.remove(id))
+    })
+}
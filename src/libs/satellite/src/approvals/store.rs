@@ -0,0 +1,128 @@
+use crate::approvals::constants::DEFAULT_CONFIRMATION_WINDOW_NS;
+use crate::approvals::msg::{
+    ERROR_ACTION_EXPIRED, ERROR_ACTION_NOT_FOUND, ERROR_ACTION_SELF_CONFIRMATION,
+    ERROR_APPROVALS_NOT_ENABLED, ERROR_APPROVAL_REQUIRED, ERROR_CONFIRMATION_WINDOW_ZERO,
+};
+use crate::approvals::state::{
+    delete_pending_action, get_config, get_pending_action, get_pending_actions, insert_config,
+    insert_pending_action,
+};
+use crate::approvals::types::config::ApprovalsConfig;
+use crate::approvals::types::state::{ActionId, ControllerAction, PendingAction};
+use ic_cdk::api::time;
+use junobuild_shared::types::state::ControllerId;
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+/// Once enabled, the config itself can only be changed with a confirmed action.
+pub fn set_config_store(config: &ApprovalsConfig) -> Result<(), String> {
+    assert_approval_not_required()?;
+
+    apply_config_store(config)
+}
+
+pub fn apply_config_store(config: &ApprovalsConfig) -> Result<(), String> {
+    assert_config(config)?;
+
+    insert_config(config);
+
+    Ok(())
+}
+
+pub fn get_config_store() -> Option<ApprovalsConfig> {
+    get_config()
+}
+
+fn assert_config(config: &ApprovalsConfig) -> Result<(), String> {
+    if config.confirmation_window_ns == Some(0) {
+        return Err(ERROR_CONFIRMATION_WINDOW_ZERO.to_string());
+    }
+
+    Ok(())
+}
+
+fn is_enabled() -> bool {
+    get_config().is_some_and(|config| config.enabled)
+}
+
+/// The destructive operations cannot be called directly while the approval workflow is enabled.
+pub fn assert_approval_not_required() -> Result<(), String> {
+    if is_enabled() {
+        return Err(ERROR_APPROVAL_REQUIRED.to_string());
+    }
+
+    Ok(())
+}
+
+// ---------------------------------------------------------
+// Pending actions
+// ---------------------------------------------------------
+
+pub fn propose_action_store(
+    caller: ControllerId,
+    action: &ControllerAction,
+) -> Result<ActionId, String> {
+    let config = get_config()
+        .filter(|config| config.enabled)
+        .ok_or_else(|| ERROR_APPROVALS_NOT_ENABLED.to_string())?;
+
+    if let ControllerAction::SetApprovalsConfig(config) = action {
+        assert_config(config)?;
+    }
+
+    let now = time();
+
+    let window = config
+        .confirmation_window_ns
+        .unwrap_or(DEFAULT_CONFIRMATION_WINDOW_NS);
+
+    let id = insert_pending_action(
+        PendingAction {
+            action: action.clone(),
+            proposed_by: caller,
+            proposed_at: now,
+            expires_at: now.saturating_add(window),
+        },
+        now,
+    );
+
+    Ok(id)
+}
+
+/// Removes the pending action and returns it to be executed. It should be confirmed by another controller than the
+/// one that proposed it and before it expires.
+pub fn confirm_action_store(
+    caller: ControllerId,
+    id: &ActionId,
+) -> Result<ControllerAction, String> {
+    let pending = get_pending_action(id).ok_or_else(|| ERROR_ACTION_NOT_FOUND.to_string())?;
+
+    if pending.expires_at <= time() {
+        return Err(ERROR_ACTION_EXPIRED.to_string());
+    }
+
+    if pending.proposed_by == caller {
+        return Err(ERROR_ACTION_SELF_CONFIRMATION.to_string());
+    }
+
+    delete_pending_action(id);
+
+    Ok(pending.action)
+}
+
+pub fn cancel_action_store(id: &ActionId) -> Result<(), String> {
+    delete_pending_action(id)
+        .map(|_| ())
+        .ok_or_else(|| ERROR_ACTION_NOT_FOUND.to_string())
+}
+
+pub fn list_pending_actions_store() -> Vec<(ActionId, PendingAction)> {
+    let now = time();
+
+    get_pending_actions()
+        .into_iter()
+        .filter(|(_, pending)| pending.expires_at > now)
+        .collect()
+}
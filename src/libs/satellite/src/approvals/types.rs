@@ -0,0 +1,99 @@
+pub mod state {
+    use crate::approvals::types::config::ApprovalsConfig;
+    use crate::bundle::types::interface::ConfigBundle;
+    use crate::ownership::types::interface::{SetAssetOwner, SetDocOwner};
+    use crate::types::interface::RulesType;
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_collections::types::interface::{DelRule, SetRule};
+    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::interface::{DeleteControllersArgs, SetControllersArgs};
+    use junobuild_shared::types::list::ListParams;
+    use junobuild_shared::types::state::{ControllerId, Timestamp};
+    use junobuild_storage::types::state::FullPath;
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    pub type ActionId = u64;
+
+    pub type PendingActions = BTreeMap<ActionId, PendingAction>;
+
+    /// - `next_id`: The id of the next proposed action. The ids are never reused.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct ApprovalsHeapState {
+        pub config: ApprovalsConfig,
+        pub pending: PendingActions,
+        pub next_id: ActionId,
+    }
+
+    /// An action proposed by a controller that waits for the confirmation of another controller.
+    ///
+    /// - `expires_at`: The action cannot be confirmed anymore after this time.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct PendingAction {
+        pub action: ControllerAction,
+        pub proposed_by: ControllerId,
+        pub proposed_at: Timestamp,
+        pub expires_at: Timestamp,
+    }
+
+    /// The destructive or privilege-changing operations that require the approval of two controllers once the workflow
+    /// is enabled - i.e. deleting a collection, purging or deleting with a filter its documents or assets, transferring
+    /// ownership, changing the permissions of a collection, setting or deleting controllers and applying a configuration
+    /// bundle. Disabling the workflow or changing its window is an action as well, so that a single controller cannot
+    /// bypass it.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum ControllerAction {
+        DelRule {
+            rules_type: RulesType,
+            collection: CollectionKey,
+            rule: DelRule,
+        },
+        DelDocs {
+            collection: CollectionKey,
+        },
+        DelAssets {
+            collection: CollectionKey,
+        },
+        DelFilteredDocs {
+            collection: CollectionKey,
+            filter: ListParams,
+        },
+        DelFilteredAssets {
+            collection: CollectionKey,
+            filter: ListParams,
+        },
+        SetRule {
+            rules_type: RulesType,
+            collection: CollectionKey,
+            rule: SetRule,
+        },
+        SetDocOwner {
+            collection: CollectionKey,
+            key: Key,
+            owner: SetDocOwner,
+        },
+        SetAssetOwner {
+            collection: CollectionKey,
+            full_path: FullPath,
+            owner: SetAssetOwner,
+        },
+        SetControllers(SetControllersArgs),
+        DelControllers(DeleteControllersArgs),
+        ApplyConfigBundle(Box<ConfigBundle>),
+        SetApprovalsConfig(ApprovalsConfig),
+    }
+}
+
+pub mod config {
+    use candid::{CandidType, Deserialize};
+    use serde::Serialize;
+
+    /// - `enabled`: Whether the destructive operations must be proposed by a controller and confirmed by another.
+    /// - `confirmation_window_ns`: The time within which a proposed action can be confirmed. Defaults to 24 hours.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct ApprovalsConfig {
+        pub enabled: bool,
+        pub confirmation_window_ns: Option<u64>,
+    }
+}
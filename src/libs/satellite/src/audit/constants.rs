@@ -0,0 +1 @@
+pub const AUDIT_BLOCKS_MAX_LENGTH: u64 = 100;
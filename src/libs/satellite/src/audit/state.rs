@@ -0,0 +1,37 @@
+use crate::audit::types::state::{AuditBlock, AuditBlockIndex};
+use crate::defrag::state::usage_mut;
+use crate::defrag::stats::entry_length;
+use crate::memory::STATE;
+
+pub fn get_last_block() -> Option<(AuditBlockIndex, AuditBlock)> {
+    STATE.with(|state| state.borrow().stable.audit.last_key_value())
+}
+
+pub fn insert_block(index: AuditBlockIndex, block: AuditBlock) {
+    STATE.with(|state| {
+        let state = &mut *state.borrow_mut();
+
+        let length = entry_length(&index, &block);
+        let previous = state.stable.audit.insert(index, block);
+
+        usage_mut(&mut state.heap.defrag).audit.update(
+            previous.map(|previous| entry_length(&index, &previous)),
+            Some(length),
+        );
+    })
+}
+
+pub fn count_blocks() -> u64 {
+    STATE.with(|state| state.borrow().stable.audit.len())
+}
+
+pub fn get_blocks(start: AuditBlockIndex, length: u64) -> Vec<(AuditBlockIndex, AuditBlock)> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .audit
+            .range(start..start.saturating_add(length))
+            .collect()
+    })
+}
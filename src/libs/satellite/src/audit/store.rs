@@ -0,0 +1,80 @@
+use crate::audit::constants::AUDIT_BLOCKS_MAX_LENGTH;
+use crate::audit::state::{count_blocks, get_blocks, get_last_block, insert_block};
+use crate::audit::types::interface::{AuditBlocks, ListAuditBlocks};
+use crate::audit::types::state::{AuditBlock, AuditOperation};
+use crate::events::types::interface::SatelliteEvent;
+use candid::encode_args;
+use ic_cdk::api::time;
+use junobuild_shared::types::core::Hash;
+use junobuild_shared::types::state::UserId;
+use sha2::{Digest, Sha256};
+
+/// Appends an operation to the audit log, chained to the last block.
+pub fn record_audit_block(caller: &UserId, operation: AuditOperation) {
+    let (index, parent_hash) = match get_last_block() {
+        None => (0, None),
+        Some((index, block)) => (index + 1, Some(block.hash)),
+    };
+
+    let timestamp = time();
+
+    let hash = hash_block(&parent_hash, timestamp, caller, &operation);
+
+    insert_block(
+        index,
+        AuditBlock {
+            parent_hash,
+            hash,
+            timestamp,
+            caller: *caller,
+            operation,
+        },
+    );
+}
+
+/// Subscribed to the events of the satellite to record the changes of the rules and configuration.
+pub fn record_audit_event(caller: &UserId, event: &SatelliteEvent) {
+    let operation = match event {
+        SatelliteEvent::SetRule {
+            rules_type,
+            collection,
+            ..
+        } => AuditOperation::SetRule {
+            rules_type: rules_type.clone(),
+            collection: collection.clone(),
+        },
+        SatelliteEvent::DeleteRule {
+            rules_type,
+            collection,
+        } => AuditOperation::DelRule {
+            rules_type: rules_type.clone(),
+            collection: collection.clone(),
+        },
+        SatelliteEvent::SetStorageConfig(_) => AuditOperation::SetStorageConfig,
+        SatelliteEvent::SetDbConfig(_) => AuditOperation::SetDbConfig,
+        SatelliteEvent::SetAuthConfig(_) => AuditOperation::SetAuthConfig,
+        SatelliteEvent::ApplyConfigBundle(_) => AuditOperation::ApplyConfigBundle,
+        _ => return,
+    };
+
+    record_audit_block(caller, operation);
+}
+
+pub fn list_audit_blocks_store(ListAuditBlocks { start, length }: &ListAuditBlocks) -> AuditBlocks {
+    AuditBlocks {
+        log_length: count_blocks(),
+        blocks: get_blocks(*start, (*length).min(AUDIT_BLOCKS_MAX_LENGTH)),
+    }
+}
+
+fn hash_block(
+    parent_hash: &Option<Hash>,
+    timestamp: u64,
+    caller: &UserId,
+    operation: &AuditOperation,
+) -> Hash {
+    let bytes = encode_args((parent_hash, timestamp, caller, operation))
+        .expect("Failed to encode the audit block.");
+
+    Sha256::digest(bytes).into()
+}
@@ -0,0 +1,84 @@
+pub mod state {
+    use crate::types::interface::RulesType;
+    use candid::CandidType;
+    use ic_stable_structures::StableBTreeMap;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::core::{DomainName, Hash};
+    use junobuild_shared::types::memory::Memory;
+    use junobuild_shared::types::state::{ControllerId, ControllerScope, Timestamp, UserId};
+    use serde::{Deserialize, Serialize};
+
+    /// The position of a block in the log, starting at zero.
+    pub type AuditBlockIndex = u64;
+
+    pub type AuditLogStable = StableBTreeMap<AuditBlockIndex, AuditBlock, Memory>;
+
+    /// An entry of the audit log - similar to an ICRC-3 block.
+    ///
+    /// - `parent_hash`: The hash of the previous block. `None` for the first block of the log.
+    /// - `hash`: The SHA-256 of the candid encoded parent hash, timestamp, caller and operation. Modifying or removing
+    ///   a block breaks the chain for all the blocks that follow.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct AuditBlock {
+        pub parent_hash: Option<Hash>,
+        pub hash: Hash,
+        pub timestamp: Timestamp,
+        pub caller: UserId,
+        pub operation: AuditOperation,
+    }
+
+    /// The administrative operations recorded in the audit log.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum AuditOperation {
+        SetRule {
+            rules_type: RulesType,
+            collection: CollectionKey,
+        },
+        DelRule {
+            rules_type: RulesType,
+            collection: CollectionKey,
+        },
+        SetStorageConfig,
+        SetDbConfig,
+        SetAuthConfig,
+        ApplyConfigBundle,
+        SetControllers {
+            controllers: Vec<ControllerId>,
+            scope: ControllerScope,
+        },
+        DelControllers {
+            controllers: Vec<ControllerId>,
+        },
+        SetCustomDomain {
+            domain_name: DomainName,
+        },
+        DelCustomDomain {
+            domain_name: DomainName,
+        },
+        Upgrade {
+            version: String,
+        },
+        AcknowledgeSafeMode,
+    }
+}
+
+pub mod interface {
+    use crate::audit::types::state::{AuditBlock, AuditBlockIndex};
+    use candid::CandidType;
+    use serde::Deserialize;
+
+    /// A range of blocks, ICRC-3 style. The `length` is capped to 100 blocks per call.
+    #[derive(CandidType, Deserialize)]
+    pub struct ListAuditBlocks {
+        pub start: AuditBlockIndex,
+        pub length: u64,
+    }
+
+    /// - `log_length`: The total number of blocks in the log.
+    /// - `blocks`: The requested blocks with their index.
+    #[derive(CandidType, Deserialize)]
+    pub struct AuditBlocks {
+        pub log_length: u64,
+        pub blocks: Vec<(AuditBlockIndex, AuditBlock)>,
+    }
+}
@@ -1,10 +1,13 @@
 use crate::auth::alternative_origins::update_alternative_origins;
 use crate::auth::state::{get_config as get_state_config, insert_config as insert_state_config};
 use crate::auth::types::config::AuthenticationConfig;
+use crate::manifest::http::certify_manifest;
 
 pub fn set_config(config: &AuthenticationConfig) -> Result<(), String> {
     insert_state_config(config);
 
+    certify_manifest();
+
     update_alternative_origins(config)
 }
 
@@ -0,0 +1,3 @@
+mod msg;
+pub mod store;
+pub mod types;
@@ -0,0 +1,4 @@
+pub const ERROR_UNSUPPORTED_CONFIG_BUNDLE_VERSION: &str =
+    "Unsupported configuration bundle version.";
+pub const ERROR_INVALID_INIT_CONFIG_BUNDLE: &str =
+    "The configuration bundle of the init arguments cannot be decoded:";
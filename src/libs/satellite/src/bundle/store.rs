@@ -0,0 +1,167 @@
+use crate::auth::store::{
+    get_config as get_authentication_config, set_config as set_authentication_config,
+};
+use crate::bundle::msg::{
+    ERROR_INVALID_INIT_CONFIG_BUNDLE, ERROR_UNSUPPORTED_CONFIG_BUNDLE_VERSION,
+};
+use crate::bundle::types::interface::{ConfigBundle, ConfigBundleRules};
+use crate::controllers::store::{get_admin_controllers, set_controllers};
+use crate::db::store::{
+    get_config_store as get_db_config_store, set_config_store as set_db_config_store,
+};
+use crate::rules::store::{
+    get_rule_db, get_rule_storage, get_rules_db, get_rules_storage, set_rule_db, set_rule_storage,
+};
+use crate::storage::store::{
+    get_config_store as get_storage_config_store, get_custom_domains_store,
+    set_config_store as set_storage_config_store, set_domain_store,
+};
+use candid::decode_one;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::interface::SetRule;
+use junobuild_collections::types::rules::Rule;
+use junobuild_shared::constants::MAX_NUMBER_OF_SATELLITE_CONTROLLERS;
+use junobuild_shared::controllers::{
+    assert_controller_expiration, assert_controllers, assert_max_number_of_controllers,
+};
+use junobuild_shared::types::core::DomainName;
+use junobuild_shared::types::interface::SatelliteInitConfig;
+use junobuild_shared::types::state::ControllerScope;
+
+/// The version of the format of the bundle. It is incremented when the structure changes in a way that cannot be
+/// applied by the previous Satellites.
+const CONFIG_BUNDLE_VERSION: u64 = 1;
+
+pub fn get_config_bundle_store() -> ConfigBundle {
+    let mut custom_domains: Vec<(DomainName, Option<String>)> = get_custom_domains_store()
+        .into_iter()
+        .map(|(domain_name, custom_domain)| (domain_name, custom_domain.bn_id))
+        .collect();
+    custom_domains.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    ConfigBundle {
+        version: CONFIG_BUNDLE_VERSION,
+        storage: get_storage_config_store(),
+        db: get_db_config_store(),
+        authentication: get_authentication_config(),
+        rules: ConfigBundleRules {
+            db: bundle_rules(get_rules_db()),
+            storage: bundle_rules(get_rules_storage()),
+        },
+        custom_domains,
+    }
+}
+
+/// Applies a configuration bundle on top of the current configuration.
+///
+/// The collections and custom domains of the bundle are created or updated, those that are not part of it are left
+/// untouched. The rules are overwritten regardless of their current version given that a bundle does not carry any.
+/// The authentication config is applied last as its alternative origins are derived from the custom domains.
+pub fn apply_config_bundle_store(bundle: &ConfigBundle) -> Result<(), String> {
+    if bundle.version != CONFIG_BUNDLE_VERSION {
+        return Err(ERROR_UNSUPPORTED_CONFIG_BUNDLE_VERSION.to_string());
+    }
+
+    set_storage_config_store(&bundle.storage)?;
+
+    for (domain_name, bn_id) in &bundle.custom_domains {
+        set_domain_store(domain_name, bn_id)?;
+    }
+
+    for (collection, rule) in &bundle.rules.db {
+        let version = get_rule_db(collection).and_then(|current_rule| current_rule.version);
+
+        set_rule_db(
+            collection.clone(),
+            SetRule {
+                version,
+                ..rule.clone()
+            },
+        )?;
+    }
+
+    for (collection, rule) in &bundle.rules.storage {
+        let version = get_rule_storage(collection).and_then(|current_rule| current_rule.version);
+
+        set_rule_storage(
+            collection.clone(),
+            SetRule {
+                version,
+                ..rule.clone()
+            },
+        )?;
+    }
+
+    if let Some(db) = &bundle.db {
+        set_db_config_store(db)?;
+    }
+
+    if let Some(authentication) = &bundle.authentication {
+        set_authentication_config(authentication)?;
+    }
+
+    Ok(())
+}
+
+/// Applies the configuration provided with the init arguments when the Satellite is installed - i.e. the bundle
+/// first, then the additional controllers with the same assertions as when they are set afterwards.
+pub fn apply_init_config_store(
+    SatelliteInitConfig {
+        bundle,
+        controllers,
+    }: &SatelliteInitConfig,
+) -> Result<(), String> {
+    if let Some(bundle) = bundle {
+        let bundle: ConfigBundle = decode_one(bundle)
+            .map_err(|e| format!("{} {}", ERROR_INVALID_INIT_CONFIG_BUNDLE, e))?;
+
+        apply_config_bundle_store(&bundle)?;
+    }
+
+    for (controller_id, controller) in controllers {
+        let controller_ids = [*controller_id];
+
+        if let ControllerScope::Admin = controller.scope {
+            assert_max_number_of_controllers(
+                &get_admin_controllers(),
+                &controller_ids,
+                MAX_NUMBER_OF_SATELLITE_CONTROLLERS,
+            )?;
+        }
+
+        assert_controllers(&controller_ids)?;
+        assert_controller_expiration(controller)?;
+
+        set_controllers(&controller_ids, controller);
+    }
+
+    Ok(())
+}
+
+fn bundle_rules(rules: Vec<(CollectionKey, Rule)>) -> Vec<(CollectionKey, SetRule)> {
+    let mut rules: Vec<(CollectionKey, SetRule)> = rules
+        .into_iter()
+        .map(|(collection, rule)| (collection, bundle_rule(rule)))
+        .collect();
+
+    rules.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    rules
+}
+
+/// The rule as it can be applied to another Satellite - i.e. without version.
+pub fn bundle_rule(rule: Rule) -> SetRule {
+    SetRule {
+        read: rule.read,
+        write: rule.write,
+        mutable_permissions: rule.mutable_permissions,
+        memory: rule.memory,
+        max_size: rule.max_size,
+        max_capacity: rule.max_capacity,
+        version: None,
+        rate_config: rule.rate_config,
+        user_quota: rule.user_quota,
+        collation: rule.collation,
+        retention: rule.retention,
+    }
+}
@@ -0,0 +1,35 @@
+pub mod interface {
+    use crate::auth::types::config::AuthenticationConfig;
+    use crate::db::types::config::DbConfig;
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_collections::types::interface::SetRule;
+    use junobuild_shared::types::core::DomainName;
+    use junobuild_storage::types::config::StorageConfig;
+    use serde::{Deserialize, Serialize};
+
+    /// The configuration of a Satellite as a single structure that can be exported, versioned and applied to
+    /// reproduce an environment.
+    ///
+    /// - `version`: The version of the format of the bundle.
+    /// - `storage`, `db` and `authentication`: The configurations of the features.
+    /// - `rules`: The collections of the Datastore and the Storage. The system collections are not part of the bundle.
+    /// - `custom_domains`: The custom domains and their optional boundary node IDs.
+    ///
+    /// The entries are sorted by key and the rules are exported without version, so that two bundles can be diffed.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ConfigBundle {
+        pub version: u64,
+        pub storage: StorageConfig,
+        pub db: Option<DbConfig>,
+        pub authentication: Option<AuthenticationConfig>,
+        pub rules: ConfigBundleRules,
+        pub custom_domains: Vec<(DomainName, Option<String>)>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ConfigBundleRules {
+        pub db: Vec<(CollectionKey, SetRule)>,
+        pub storage: Vec<(CollectionKey, SetRule)>,
+    }
+}
@@ -0,0 +1,3 @@
+pub mod state;
+pub mod store;
+pub mod types;
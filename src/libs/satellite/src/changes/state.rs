@@ -0,0 +1,58 @@
+use crate::changes::types::state::{ChangesHeapState, CollectionsVersions};
+use crate::memory::STATE;
+use ic_cdk::api::time;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::list::CollectionVersion;
+
+pub fn get_db_version(collection: &CollectionKey) -> Option<CollectionVersion> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .changes
+            .as_ref()
+            .and_then(|changes| changes.db.get(collection).cloned())
+    })
+}
+
+pub fn get_storage_version(collection: &CollectionKey) -> Option<CollectionVersion> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .changes
+            .as_ref()
+            .and_then(|changes| changes.storage.get(collection).cloned())
+    })
+}
+
+pub fn increment_db_version(collection: &CollectionKey) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let changes = state
+            .heap
+            .changes
+            .get_or_insert_with(ChangesHeapState::default);
+
+        increment_version(collection, &mut changes.db)
+    })
+}
+
+pub fn increment_storage_version(collection: &CollectionKey) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let changes = state
+            .heap
+            .changes
+            .get_or_insert_with(ChangesHeapState::default);
+
+        increment_version(collection, &mut changes.storage)
+    })
+}
+
+fn increment_version(collection: &CollectionKey, versions: &mut CollectionsVersions) {
+    let version = versions.entry(collection.clone()).or_default();
+
+    version.changes += 1;
+    version.last_modified = time();
+}
@@ -0,0 +1,15 @@
+use crate::changes::state::{get_db_version, get_storage_version};
+use crate::types::interface::RulesType;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::list::CollectionVersion;
+
+/// The version of a collection - `None` if none of its documents or assets was ever written.
+pub fn get_collection_version_store(
+    rules_type: &RulesType,
+    collection: &CollectionKey,
+) -> Option<CollectionVersion> {
+    match rules_type {
+        RulesType::Db => get_db_version(collection),
+        RulesType::Storage => get_storage_version(collection),
+    }
+}
@@ -0,0 +1,16 @@
+pub mod state {
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::list::CollectionVersion;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    pub type CollectionsVersions = HashMap<CollectionKey, CollectionVersion>;
+
+    /// The versions are kept when the collections are emptied - or even deleted - so that those never decrease.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct ChangesHeapState {
+        pub db: CollectionsVersions,
+        pub storage: CollectionsVersions,
+    }
+}
@@ -0,0 +1,3 @@
+mod parser;
+pub mod store;
+pub mod types;
@@ -0,0 +1,66 @@
+/// The names of the methods of the service described by a Candid interface - e.g. `build_version` for
+/// `service : { build_version : () -> (text) query }`.
+///
+/// The interface is the one generated by `export_candid!`, in which the service is declared on its own line and its
+/// methods are separated by semicolons outside of their arguments and results.
+pub fn service_methods(candid: &str) -> Vec<String> {
+    let service = match candid
+        .match_indices("service")
+        .find(|(index, _)| *index == 0 || candid[..*index].ends_with('\n'))
+    {
+        None => return vec![],
+        Some((index, _)) => &candid[index..],
+    };
+
+    // The body of the service is the first block outside of the arguments of its initialization.
+    let mut parentheses: usize = 0;
+
+    let body = match service.char_indices().find(|(_, c)| {
+        match c {
+            '(' => parentheses += 1,
+            ')' => parentheses = parentheses.saturating_sub(1),
+            _ => (),
+        }
+
+        *c == '{' && parentheses == 0
+    }) {
+        None => return vec![],
+        Some((index, _)) => &service[index + 1..],
+    };
+
+    let mut methods: Vec<String> = Vec::new();
+    let mut method = String::new();
+    let mut depth: usize = 0;
+
+    for c in body.chars() {
+        match c {
+            '{' | '(' => depth += 1,
+            '}' | ')' if depth > 0 => depth -= 1,
+            '}' => break,
+            ';' if depth == 0 => {
+                push_method_name(&method, &mut methods);
+                method.clear();
+                continue;
+            }
+            _ => (),
+        }
+
+        method.push(c);
+    }
+
+    push_method_name(&method, &mut methods);
+
+    methods.sort();
+
+    methods
+}
+
+fn push_method_name(method: &str, methods: &mut Vec<String>) {
+    if let Some((name, _)) = method.split_once(':') {
+        let name = name.trim().trim_matches('"');
+
+        if !name.is_empty() {
+            methods.push(name.to_string());
+        }
+    }
+}
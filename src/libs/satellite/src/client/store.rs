@@ -0,0 +1,32 @@
+use crate::client::parser::service_methods;
+use crate::client::types::interface::ClientMetadata;
+use crate::extensions::store::list_extensions_store;
+use crate::extensions::types::state::ExtensionKey;
+use crate::hooks::invoke_satellite_extension_candid;
+use crate::manifest::store::get_manifest_features;
+use crate::version::SATELLITE_VERSION;
+
+// The interface of the endpoints of the crate. Those of the serverless functions are generated in the crate of the
+// developer with `include_satellite!`.
+const SATELLITE_CANDID: &str = include_str!("../../satellite.did");
+
+pub fn get_client_metadata_store() -> ClientMetadata {
+    let extension_candid = invoke_satellite_extension_candid();
+
+    let custom_endpoints = service_methods(&extension_candid);
+
+    let extensions: Vec<ExtensionKey> = list_extensions_store()
+        .into_iter()
+        .filter(|(_, extension)| extension.enabled)
+        .map(|(key, _)| key)
+        .collect();
+
+    ClientMetadata {
+        version: SATELLITE_VERSION.to_string(),
+        candid: SATELLITE_CANDID.to_string(),
+        extension_candid,
+        custom_endpoints,
+        features: get_manifest_features(),
+        extensions,
+    }
+}
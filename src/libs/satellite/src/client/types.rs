@@ -0,0 +1,24 @@
+pub mod interface {
+    use crate::extensions::types::state::ExtensionKey;
+    use crate::manifest::types::manifest::ManifestFeatures;
+    use candid::CandidType;
+    use serde::{Deserialize, Serialize};
+
+    /// The metadata from which codegen tooling generates typed clients for the satellite, including the endpoints
+    /// the developer added with their serverless functions.
+    ///
+    /// - `candid`: The interface of the endpoints provided by the satellite crate.
+    /// - `extension_candid`: The interface of the custom endpoints of the serverless functions.
+    /// - `custom_endpoints`: The names of the methods of the extension interface.
+    /// - `features`: The same features as the ones listed by the manifest.
+    /// - `extensions`: The built-in extensions that are enabled.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ClientMetadata {
+        pub version: String,
+        pub candid: String,
+        pub extension_candid: String,
+        pub custom_endpoints: Vec<String>,
+        pub features: ManifestFeatures,
+        pub extensions: Vec<ExtensionKey>,
+    }
+}
@@ -0,0 +1,2 @@
+// Compressing small payloads costs instructions for a negligible gain.
+pub const COMPRESSION_MIN_SIZE: usize = 1024;
@@ -0,0 +1,3 @@
+mod constants;
+mod msg;
+pub mod utils;
@@ -0,0 +1 @@
+pub const ERROR_CORRUPTED_DATA: &str = "The compressed data is corrupted.";
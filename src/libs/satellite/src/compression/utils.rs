@@ -0,0 +1,91 @@
+use crate::compression::constants::COMPRESSION_MIN_SIZE;
+use crate::compression::msg::ERROR_CORRUPTED_DATA;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression as Level;
+use junobuild_collections::types::rules::Compression;
+use junobuild_shared::types::core::Blob;
+use std::io::{Read, Write};
+
+/// Compresses a payload before it is written to stable memory. Returns the payload with the codec that was applied,
+/// which is recorded with the entry. The payload is kept as it is if no codec is set, if it is too small or if it does
+/// not shrink once compressed.
+pub fn compress_data(
+    data: &Blob,
+    compression: &Option<Compression>,
+) -> (Blob, Option<Compression>) {
+    let Some(codec) = compression else {
+        return (data.clone(), None);
+    };
+
+    if data.len() < COMPRESSION_MIN_SIZE {
+        return (data.clone(), None);
+    }
+
+    let compressed = match codec {
+        Compression::Deflate => deflate(data),
+    };
+
+    match compressed {
+        Ok(compressed) if compressed.len() < data.len() => (compressed, Some(codec.clone())),
+        _ => (data.clone(), None),
+    }
+}
+
+/// Compresses the content chunks of an encoding before they are written to stable memory. The codec is recorded with
+/// the encoding, therefore either all its chunks are compressed or none.
+pub fn compress_chunks(
+    chunks: &[Blob],
+    total_length: u128,
+    compression: &Option<Compression>,
+) -> (Vec<Blob>, Option<Compression>) {
+    let Some(codec) = compression else {
+        return (chunks.to_vec(), None);
+    };
+
+    if total_length < COMPRESSION_MIN_SIZE as u128 {
+        return (chunks.to_vec(), None);
+    }
+
+    let compressed: Result<Vec<Blob>, String> = chunks
+        .iter()
+        .map(|chunk| match codec {
+            Compression::Deflate => deflate(chunk),
+        })
+        .collect();
+
+    match compressed {
+        Ok(compressed)
+            if compressed.iter().map(Vec::len).sum::<usize>()
+                < chunks.iter().map(Vec::len).sum::<usize>() =>
+        {
+            (compressed, Some(codec.clone()))
+        }
+        _ => (chunks.to_vec(), None),
+    }
+}
+
+/// Decompresses a payload read from stable memory with the codec recorded with its entry. A payload that cannot be
+/// decompressed is corrupted and is never returned as it is.
+pub fn decompress_data(data: Blob, compression: &Option<Compression>) -> Result<Blob, String> {
+    match compression {
+        None => Ok(data),
+        Some(Compression::Deflate) => inflate(&data),
+    }
+}
+
+fn deflate(data: &[u8]) -> Result<Blob, String> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Level::fast());
+    encoder.write_all(data).map_err(|err| err.to_string())?;
+    encoder.finish().map_err(|err| err.to_string())
+}
+
+fn inflate(compressed: &[u8]) -> Result<Blob, String> {
+    let mut data: Blob = Vec::new();
+
+    DeflateDecoder::new(compressed)
+        .read_to_end(&mut data)
+        .map_err(|err| format!("{} ({})", ERROR_CORRUPTED_DATA, err))?;
+
+    Ok(data)
+}
@@ -0,0 +1,248 @@
+use crate::auth::types::config::AuthenticationConfig;
+use crate::db::types::config::DbConfig;
+use crate::types::interface::RulesType;
+use candid::utils::ArgumentDecoder;
+use candid::{decode_args, Principal};
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::interface::{DelRule, SetRule};
+use junobuild_shared::types::core::DomainName;
+use junobuild_shared::types::interface::{
+    DeleteControllersArgs, DepositCyclesArgs, SetControllersArgs,
+};
+use junobuild_shared::types::list::ListParams;
+use junobuild_shared::types::state::{ControllerScope, Timestamp};
+use junobuild_storage::types::config::StorageConfig;
+use junobuild_storage::types::state::FullPath;
+
+/// The content of a consent message, rendered afterwards for the device of the wallet.
+pub struct ConsentText {
+    pub title: String,
+    pub paragraphs: Vec<String>,
+}
+
+/// Describes a call to one of the sensitive endpoints of the satellite.
+///
+/// Returns `None` if the method is not covered, or an error if its arguments cannot be decoded.
+pub fn consent_text(
+    method: &str,
+    arg: &[u8],
+    utc_offset_minutes: Option<i16>,
+) -> Option<Result<ConsentText, String>> {
+    let text = match method {
+        "set_storage_config" => decode(arg, |(_,): (StorageConfig,)| {
+            text(
+                "Update the storage configuration",
+                vec![
+                    "The headers, rewrites and redirects of the hosting will be replaced."
+                        .to_string(),
+                ],
+            )
+        }),
+        "set_db_config" => decode(arg, |(_,): (DbConfig,)| {
+            text(
+                "Update the datastore configuration",
+                vec!["The limits and aggregates of the datastore will be replaced.".to_string()],
+            )
+        }),
+        "set_auth_config" => decode(arg, |(config,): (AuthenticationConfig,)| {
+            let derivation_origin = config
+                .internet_identity
+                .and_then(|ii| ii.derivation_origin)
+                .unwrap_or("none".to_string());
+
+            text(
+                "Update the authentication configuration",
+                vec![format!("Derivation origin: {}", derivation_origin)],
+            )
+        }),
+        "set_rule" => decode(
+            arg,
+            |(rules_type, collection, _): (RulesType, CollectionKey, SetRule)| {
+                text(
+                    "Set the rules of a collection",
+                    vec![
+                        collection_paragraph(&rules_type, &collection),
+                        "Its permissions, limits and memory will be replaced.".to_string(),
+                    ],
+                )
+            },
+        ),
+        "del_rule" => decode(
+            arg,
+            |(rules_type, collection, _): (RulesType, CollectionKey, DelRule)| {
+                text(
+                    "Delete a collection",
+                    vec![collection_paragraph(&rules_type, &collection)],
+                )
+            },
+        ),
+        "del_docs" => decode(arg, |(collection,): (CollectionKey,)| {
+            text(
+                "Delete all documents",
+                vec![
+                    collection_paragraph(&RulesType::Db, &collection),
+                    "This action cannot be undone.".to_string(),
+                ],
+            )
+        }),
+        "del_filtered_docs" => decode(arg, |(collection, _): (CollectionKey, ListParams)| {
+            text(
+                "Delete the documents matching a filter",
+                vec![
+                    collection_paragraph(&RulesType::Db, &collection),
+                    "This action cannot be undone.".to_string(),
+                ],
+            )
+        }),
+        "del_assets" => decode(arg, |(collection,): (CollectionKey,)| {
+            text(
+                "Delete all assets",
+                vec![
+                    collection_paragraph(&RulesType::Storage, &collection),
+                    "This action cannot be undone.".to_string(),
+                ],
+            )
+        }),
+        "del_filtered_assets" => decode(arg, |(collection, _): (CollectionKey, ListParams)| {
+            text(
+                "Delete the assets matching a filter",
+                vec![
+                    collection_paragraph(&RulesType::Storage, &collection),
+                    "This action cannot be undone.".to_string(),
+                ],
+            )
+        }),
+        "del_asset" => decode(arg, |(collection, full_path): (CollectionKey, FullPath)| {
+            text(
+                "Delete an asset",
+                vec![
+                    collection_paragraph(&RulesType::Storage, &collection),
+                    format!("Path: {}", full_path),
+                ],
+            )
+        }),
+        "set_controllers" => decode(arg, |(args,): (SetControllersArgs,)| {
+            let scope = match args.controller.scope {
+                ControllerScope::Write => "write",
+                ControllerScope::Admin => "admin",
+            };
+
+            let expires_at = args
+                .controller
+                .expires_at
+                .map(|expires_at| format_timestamp(expires_at, utc_offset_minutes))
+                .unwrap_or("never".to_string());
+
+            text(
+                "Grant access to the satellite",
+                vec![
+                    principals_paragraph(&args.controllers),
+                    format!("Scope: {}", scope),
+                    format!("Expires: {}", expires_at),
+                ],
+            )
+        }),
+        "del_controllers" => decode(arg, |(args,): (DeleteControllersArgs,)| {
+            text(
+                "Revoke access to the satellite",
+                vec![principals_paragraph(&args.controllers)],
+            )
+        }),
+        "set_custom_domain" => decode(arg, |(domain_name, _): (DomainName, Option<String>)| {
+            text(
+                "Set a custom domain",
+                vec![format!("Domain: {}", domain_name)],
+            )
+        }),
+        "del_custom_domain" => decode(arg, |(domain_name,): (DomainName,)| {
+            text(
+                "Remove a custom domain",
+                vec![format!("Domain: {}", domain_name)],
+            )
+        }),
+        "deposit_cycles" => decode(arg, |(args,): (DepositCyclesArgs,)| {
+            text(
+                "Transfer cycles",
+                vec![
+                    format!("Amount: {} cycles", args.cycles),
+                    format!("Destination: {}", args.destination_id.to_text()),
+                ],
+            )
+        }),
+        _ => return None,
+    };
+
+    Some(text)
+}
+
+fn decode<T, F>(arg: &[u8], describe: F) -> Result<ConsentText, String>
+where
+    T: for<'a> ArgumentDecoder<'a>,
+    F: FnOnce(T) -> ConsentText,
+{
+    decode_args::<T>(arg)
+        .map(describe)
+        .map_err(|e| e.to_string())
+}
+
+fn text(title: &str, paragraphs: Vec<String>) -> ConsentText {
+    ConsentText {
+        title: title.to_string(),
+        paragraphs,
+    }
+}
+
+fn collection_paragraph(rules_type: &RulesType, collection: &CollectionKey) -> String {
+    match rules_type {
+        RulesType::Db => format!("Datastore collection: {}", collection),
+        RulesType::Storage => format!("Storage collection: {}", collection),
+    }
+}
+
+fn principals_paragraph(principals: &[Principal]) -> String {
+    let principals: Vec<String> = principals.iter().map(|p| p.to_text()).collect();
+
+    format!("Controllers: {}", principals.join(", "))
+}
+
+/// Formats a timestamp in nanoseconds as a UTC - or local, if the wallet provided its offset - date and time.
+fn format_timestamp(timestamp: Timestamp, utc_offset_minutes: Option<i16>) -> String {
+    let offset_minutes = utc_offset_minutes.unwrap_or(0) as i64;
+
+    let seconds = (timestamp / 1_000_000_000) as i64 + offset_minutes * 60;
+
+    let days = seconds.div_euclid(86_400);
+    let seconds_of_day = seconds.rem_euclid(86_400);
+
+    // Converts the days since the epoch to a civil date (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    let zone = match offset_minutes {
+        0 => "UTC".to_string(),
+        offset => format!(
+            "UTC{}{:02}:{:02}",
+            if offset < 0 { "-" } else { "+" },
+            offset.abs() / 60,
+            offset.abs() % 60
+        ),
+    };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} {}",
+        year,
+        month,
+        day,
+        seconds_of_day / 3_600,
+        (seconds_of_day % 3_600) / 60,
+        zone
+    )
+}
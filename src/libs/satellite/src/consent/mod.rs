@@ -0,0 +1,4 @@
+mod messages;
+mod msg;
+pub mod store;
+pub mod types;
@@ -0,0 +1,4 @@
+pub const ERROR_CONSENT_UNSUPPORTED_CALL: &str = "No consent message for this method.";
+pub const ERROR_CONSENT_INVALID_ARG: &str = "The arguments of the call cannot be decoded.";
+pub const ERROR_CONSENT_INVALID_LINE_DISPLAY: &str =
+    "The line display must have at least one character per line and one line per page.";
@@ -0,0 +1,143 @@
+use crate::consent::messages::{consent_text, ConsentText};
+use crate::consent::msg::{
+    ERROR_CONSENT_INVALID_ARG, ERROR_CONSENT_INVALID_LINE_DISPLAY, ERROR_CONSENT_UNSUPPORTED_CALL,
+};
+use crate::consent::types::interface::{
+    Icrc21ConsentInfo, Icrc21ConsentMessage, Icrc21ConsentMessageMetadata,
+    Icrc21ConsentMessageRequest, Icrc21ConsentMessageResponse, Icrc21DeviceSpec, Icrc21Error,
+    Icrc21ErrorInfo, Icrc21LineDisplayPage, SupportedStandard,
+};
+
+/// The messages are only provided in English, whatever the language requested by the wallet.
+const CONSENT_MESSAGE_LANGUAGE: &str = "en";
+
+pub fn supported_standards() -> Vec<SupportedStandard> {
+    vec![
+        SupportedStandard {
+            name: "ICRC-10".to_string(),
+            url: "https://github.com/dfinity/ICRC/blob/main/ICRCs/ICRC-10/ICRC-10.md".to_string(),
+        },
+        SupportedStandard {
+            name: "ICRC-21".to_string(),
+            url: "https://github.com/dfinity/wg-identity-authentication/blob/main/topics/ICRC-21/icrc_21_consent_msg.md".to_string(),
+        },
+    ]
+}
+
+pub fn consent_message_store(
+    request: &Icrc21ConsentMessageRequest,
+) -> Icrc21ConsentMessageResponse {
+    let Icrc21ConsentMessageRequest {
+        method,
+        arg,
+        user_preferences,
+    } = request;
+
+    let metadata = Icrc21ConsentMessageMetadata {
+        language: CONSENT_MESSAGE_LANGUAGE.to_string(),
+        utc_offset_minutes: user_preferences.metadata.utc_offset_minutes,
+    };
+
+    let text = consent_text(method, arg, metadata.utc_offset_minutes)
+        .ok_or_else(|| unsupported(format!("{} ({})", ERROR_CONSENT_UNSUPPORTED_CALL, method)))?
+        .map_err(|e| unsupported(format!("{} ({})", ERROR_CONSENT_INVALID_ARG, e)))?;
+
+    let consent_message = match &user_preferences.device_spec {
+        None | Some(Icrc21DeviceSpec::GenericDisplay) => {
+            Icrc21ConsentMessage::GenericDisplayMessage(generic_display(&text))
+        }
+        Some(Icrc21DeviceSpec::LineDisplay {
+            characters_per_line,
+            lines_per_page,
+        }) => {
+            if *characters_per_line == 0 || *lines_per_page == 0 {
+                return Err(unsupported(ERROR_CONSENT_INVALID_LINE_DISPLAY.to_string()));
+            }
+
+            Icrc21ConsentMessage::LineDisplayMessage {
+                pages: line_display(&text, *characters_per_line, *lines_per_page),
+            }
+        }
+    };
+
+    Ok(Icrc21ConsentInfo {
+        consent_message,
+        metadata,
+    })
+}
+
+fn unsupported(description: String) -> Icrc21Error {
+    Icrc21Error::UnsupportedCanisterCall(Icrc21ErrorInfo { description })
+}
+
+fn generic_display(text: &ConsentText) -> String {
+    let canister = format!("Satellite: {}", ic_cdk::id().to_text());
+
+    let mut paragraphs = vec![format!("# {}", text.title), canister];
+    paragraphs.extend(text.paragraphs.iter().cloned());
+
+    paragraphs.join("\n\n")
+}
+
+/// Wraps the title and the paragraphs to the width of the display, then splits the lines in pages.
+fn line_display(
+    text: &ConsentText,
+    characters_per_line: u16,
+    lines_per_page: u16,
+) -> Vec<Icrc21LineDisplayPage> {
+    let width = characters_per_line as usize;
+
+    let canister = format!("Satellite: {}", ic_cdk::id().to_text());
+
+    let lines: Vec<String> = [&text.title, &canister]
+        .into_iter()
+        .chain(text.paragraphs.iter())
+        .flat_map(|paragraph| wrap(paragraph, width))
+        .collect();
+
+    lines
+        .chunks(lines_per_page as usize)
+        .map(|lines| Icrc21LineDisplayPage {
+            lines: lines.to_vec(),
+        })
+        .collect()
+}
+
+fn wrap(paragraph: &str, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+
+    for word in paragraph.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+
+        // Words longer than a line - e.g. principals - are split
+        while word.len() > width {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+
+            lines.push(word.drain(..width).collect());
+        }
+
+        let word: String = word.into_iter().collect();
+
+        if word.is_empty() {
+            continue;
+        }
+
+        if line.is_empty() {
+            line = word;
+        } else if line.chars().count() + 1 + word.chars().count() <= width {
+            line.push(' ');
+            line.push_str(&word);
+        } else {
+            lines.push(std::mem::replace(&mut line, word));
+        }
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
@@ -0,0 +1,80 @@
+pub mod interface {
+    use candid::{CandidType, Nat};
+    use junobuild_shared::types::core::Blob;
+    use serde::Deserialize;
+
+    /// A request for a human-readable description of a call, as specified by the ICRC-21 standard.
+    ///
+    /// - `method`: The name of the endpoint the wallet is about to call.
+    /// - `arg`: The candid encoded arguments of the call.
+    /// - `user_preferences`: How the message should be rendered.
+    #[derive(CandidType, Deserialize)]
+    pub struct Icrc21ConsentMessageRequest {
+        pub method: String,
+        pub arg: Blob,
+        pub user_preferences: Icrc21ConsentMessageSpec,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct Icrc21ConsentMessageSpec {
+        pub metadata: Icrc21ConsentMessageMetadata,
+        pub device_spec: Option<Icrc21DeviceSpec>,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct Icrc21ConsentMessageMetadata {
+        pub language: String,
+        pub utc_offset_minutes: Option<i16>,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub enum Icrc21DeviceSpec {
+        GenericDisplay,
+        LineDisplay {
+            characters_per_line: u16,
+            lines_per_page: u16,
+        },
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct Icrc21LineDisplayPage {
+        pub lines: Vec<String>,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub enum Icrc21ConsentMessage {
+        GenericDisplayMessage(String),
+        LineDisplayMessage { pages: Vec<Icrc21LineDisplayPage> },
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct Icrc21ConsentInfo {
+        pub consent_message: Icrc21ConsentMessage,
+        pub metadata: Icrc21ConsentMessageMetadata,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct Icrc21ErrorInfo {
+        pub description: String,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub enum Icrc21Error {
+        UnsupportedCanisterCall(Icrc21ErrorInfo),
+        ConsentMessageUnavailable(Icrc21ErrorInfo),
+        InsufficientPayment(Icrc21ErrorInfo),
+        GenericError {
+            error_code: Nat,
+            description: String,
+        },
+    }
+
+    pub type Icrc21ConsentMessageResponse = Result<Icrc21ConsentInfo, Icrc21Error>;
+
+    /// A standard implemented by the satellite, as listed by ICRC-10.
+    #[derive(CandidType, Deserialize)]
+    pub struct SupportedStandard {
+        pub name: String,
+        pub url: String,
+    }
+}
@@ -0,0 +1,45 @@
+use crate::audit::store::record_audit_block;
+use crate::audit::types::state::AuditOperation;
+use crate::controllers::state::replace_expiration_timer;
+use crate::controllers::store::{delete_controllers, get_controllers};
+use ic_cdk::api::time;
+use ic_cdk::id;
+use ic_cdk_timers::{clear_timer, set_timer};
+use junobuild_shared::controllers::{filter_expired_controllers, next_controller_expiration};
+use std::time::Duration;
+
+/// Schedules the removal of the controllers that were granted a temporary access, at the earliest expiration.
+/// Timers do not survive upgrades. This is why the removal is scheduled again in the post_upgrade hook.
+pub fn schedule_controllers_expiration() {
+    let next_expiration = next_controller_expiration(&get_controllers(), time());
+
+    let timer_id = next_expiration.map(|expires_at| {
+        set_timer(
+            Duration::from_nanos(expires_at.saturating_sub(time())),
+            remove_expired_controllers,
+        )
+    });
+
+    if let Some(previous_timer_id) = replace_expiration_timer(timer_id) {
+        clear_timer(previous_timer_id);
+    }
+}
+
+/// Removes the controllers whose expiration has passed - e.g. the accesses granted while the satellite was upgraded -
+/// and schedules the next removal. The deletion is recorded in the audit log as performed by the satellite itself.
+pub fn remove_expired_controllers() {
+    let expired_controllers = filter_expired_controllers(&get_controllers(), time());
+
+    if !expired_controllers.is_empty() {
+        delete_controllers(&expired_controllers);
+
+        record_audit_block(
+            &id(),
+            AuditOperation::DelControllers {
+                controllers: expired_controllers,
+            },
+        );
+    }
+
+    schedule_controllers_expiration();
+}
@@ -1 +1,4 @@
+pub mod expiration;
+mod state;
 pub mod store;
+pub mod types;
@@ -0,0 +1,9 @@
+use crate::memory::STATE;
+use ic_cdk_timers::TimerId;
+
+pub fn replace_expiration_timer(timer_id: Option<TimerId>) -> Option<TimerId> {
+    STATE.with(|state| {
+        let controllers = &mut state.borrow_mut().runtime.controllers;
+        std::mem::replace(&mut controllers.expiration_timer, timer_id)
+    })
+}
@@ -0,0 +1,9 @@
+pub mod state {
+    use ic_cdk_timers::TimerId;
+
+    #[derive(Default, Clone)]
+    pub struct ControllersRuntimeState {
+        // A single timer is scheduled at once, for the earliest expiration.
+        pub expiration_timer: Option<TimerId>,
+    }
+}
@@ -0,0 +1,205 @@
+use crate::db::constants::AGGREGATE_SUM_DECIMALS;
+use crate::db::msg::{ERROR_AGGREGATE_INVALID_DATA, ERROR_AGGREGATE_SUM_OUT_OF_RANGE};
+use crate::db::state::{
+    delete_doc as delete_state_doc, get_config, get_doc as get_state_doc,
+    get_rule as get_state_rule, insert_doc as insert_state_doc,
+};
+use crate::db::types::config::DbConfigAggregate;
+use crate::db::types::interface::SetDoc;
+use crate::db::types::state::Doc;
+use ic_cdk::id;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::core::Key;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_slice, from_str, to_vec, Number, Value};
+use std::collections::BTreeMap;
+
+#[derive(Default, Serialize, Deserialize)]
+struct AggregateData {
+    count: i64,
+    sums: BTreeMap<String, Number>,
+}
+
+/// Applies the change of a child document - set or deleted - to the aggregates configured for its collection.
+/// The previous state of the child is subtracted and its new state added, so that moving a child to another parent updates both aggregates.
+pub fn update_aggregates(
+    collection: &CollectionKey,
+    before: &Option<Doc>,
+    after: &Option<Doc>,
+) -> Result<(), String> {
+    let aggregates = match get_config().and_then(|config| config.aggregates) {
+        None => {
+            return Ok(());
+        }
+        Some(aggregates) => aggregates,
+    };
+
+    for aggregate in aggregates
+        .iter()
+        .filter(|aggregate| aggregate.collection == *collection)
+    {
+        if let Some(before) = before {
+            apply_child(aggregate, before, -1)?;
+        }
+
+        if let Some(after) = after {
+            apply_child(aggregate, after, 1)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn apply_child(aggregate: &DbConfigAggregate, child: &Doc, sign: i64) -> Result<(), String> {
+    // Data that is not a JSON object - or that does not reference a parent - does not count toward any aggregate.
+    let data: Value = match from_slice(&child.data) {
+        Err(_) => {
+            return Ok(());
+        }
+        Ok(data) => data,
+    };
+
+    let parent_key: Key = match data.get(&aggregate.parent_field).and_then(Value::as_str) {
+        None => {
+            return Ok(());
+        }
+        Some(parent_key) => parent_key.to_string(),
+    };
+
+    let rule = get_state_rule(&aggregate.target_collection)?;
+
+    let current_doc = get_state_doc(&aggregate.target_collection, &parent_key, &rule)?;
+
+    let mut aggregate_data: AggregateData = match &current_doc {
+        None => AggregateData::default(),
+        Some(current_doc) => {
+            from_slice(&current_doc.data).map_err(|_| ERROR_AGGREGATE_INVALID_DATA.to_string())?
+        }
+    };
+
+    aggregate_data.count += sign;
+
+    for field in aggregate.sum_fields.iter().flatten() {
+        let value = match data.get(field) {
+            Some(Value::Number(value)) => to_fixed_point(value)?,
+            _ => 0,
+        };
+
+        let sum = match aggregate_data.sums.get(field) {
+            Some(sum) => to_fixed_point(sum)?,
+            None => 0,
+        };
+
+        let sum = value
+            .checked_mul(sign as i128)
+            .and_then(|value| sum.checked_add(value))
+            .ok_or(ERROR_AGGREGATE_SUM_OUT_OF_RANGE.to_string())?;
+
+        aggregate_data
+            .sums
+            .insert(field.clone(), from_fixed_point(sum)?);
+    }
+
+    if aggregate_data.count <= 0 {
+        delete_state_doc(&aggregate.target_collection, &parent_key, &rule)?;
+        return Ok(());
+    }
+
+    let value = SetDoc {
+        data: to_vec(&aggregate_data).map_err(|e| e.to_string())?,
+        description: None,
+        version: None,
+        publish_at: None,
+        unpublish_at: None,
+        attachments: None,
+    };
+
+    // Aggregates are owned by the satellite and written without the permissions of the caller.
+    let doc: Doc = Doc::prepare(id(), &current_doc, value);
+
+    insert_state_doc(&aggregate.target_collection, &parent_key, &doc, &rule)?;
+
+    Ok(())
+}
+
+/// Converts a JSON number to a fixed-point decimal - i.e. an integer of units of 10^-AGGREGATE_SUM_DECIMALS. The number
+/// is read from its decimal representation, therefore e.g. `0.1` is exactly one tenth. Decimal places beyond the
+/// precision are truncated.
+fn to_fixed_point(number: &Number) -> Result<i128, String> {
+    let out_of_range = || ERROR_AGGREGATE_SUM_OUT_OF_RANGE.to_string();
+
+    if let Some(integer) = number.as_i64() {
+        return (integer as i128)
+            .checked_mul(10_i128.pow(AGGREGATE_SUM_DECIMALS))
+            .ok_or_else(out_of_range);
+    }
+
+    if let Some(integer) = number.as_u64() {
+        return (integer as i128)
+            .checked_mul(10_i128.pow(AGGREGATE_SUM_DECIMALS))
+            .ok_or_else(out_of_range);
+    }
+
+    // A floating-point number, e.g. "-12.5" or "1.5e-7".
+    let text = number.to_string();
+
+    let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (
+            mantissa,
+            exponent.parse::<i32>().map_err(|_| out_of_range())?,
+        ),
+        None => (text.as_str(), 0),
+    };
+
+    let (negative, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => (true, mantissa),
+        None => (false, mantissa),
+    };
+
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let digits = [integer, fraction]
+        .concat()
+        .parse::<i128>()
+        .map_err(|_| out_of_range())?;
+
+    let shift = exponent - fraction.len() as i32 + AGGREGATE_SUM_DECIMALS as i32;
+
+    let units = if shift >= 0 {
+        10_i128
+            .checked_pow(shift as u32)
+            .and_then(|factor| digits.checked_mul(factor))
+            .ok_or_else(out_of_range)?
+    } else {
+        10_i128
+            .checked_pow(shift.unsigned_abs())
+            .map_or(0, |divisor| digits / divisor)
+    };
+
+    Ok(if negative { -units } else { units })
+}
+
+/// Converts a fixed-point decimal back to a JSON number - an integer when it has no decimal places.
+fn from_fixed_point(units: i128) -> Result<Number, String> {
+    let scale = 10_i128.pow(AGGREGATE_SUM_DECIMALS);
+
+    let (integer, fraction) = (units / scale, (units % scale).unsigned_abs());
+
+    if fraction == 0 {
+        return i64::try_from(integer)
+            .map(Number::from)
+            .map_err(|_| ERROR_AGGREGATE_SUM_OUT_OF_RANGE.to_string());
+    }
+
+    let sign = if units < 0 { "-" } else { "" };
+
+    let text = format!(
+        "{}{}.{:0width$}",
+        sign,
+        integer.unsigned_abs(),
+        fraction,
+        width = AGGREGATE_SUM_DECIMALS as usize
+    );
+
+    from_str(text.trim_end_matches('0')).map_err(|_| ERROR_AGGREGATE_SUM_OUT_OF_RANGE.to_string())
+}
@@ -1,17 +1,29 @@
-use crate::db::msg::ERROR_CANNOT_WRITE;
+use crate::db::attachments::assert_attachments;
+use crate::db::msg::{
+    ERROR_AGGREGATE_EMPTY_PARENT_FIELD, ERROR_AGGREGATE_SAME_COLLECTION, ERROR_CANNOT_WRITE,
+    ERROR_CODE_CANNOT_WRITE, ERROR_PROOF_OF_WORK_DIFFICULTY, ERROR_VIEW_EMPTY_FIELDS,
+    ERROR_VIEW_SAME_COLLECTION,
+};
+use crate::db::proof_of_work::assert_proof_of_work;
 use crate::db::runtime::increment_and_assert_rate;
+use crate::db::state::{get_config, get_rule as get_state_rule};
 use crate::db::types::config::DbConfig;
 use crate::db::types::state::{DocAssertDelete, DocAssertSet, DocContext};
+use crate::db::views::assert_not_view;
+use crate::gates::assert::{assert_gated_create_permission, assert_gated_write_permission};
 use crate::hooks::{invoke_assert_delete_doc, invoke_assert_set_doc};
+use crate::maintenance::assert::assert_collection_not_locked;
+use crate::quotas::assert::assert_doc_user_quota;
 use crate::rules::assert_stores::assert_user_collection_caller_key;
 use crate::types::store::StoreContext;
 use crate::{DelDoc, Doc, SetDoc};
 use candid::Principal;
-use junobuild_collections::assert_stores::{
-    assert_create_permission, assert_permission, public_permission,
-};
+use junobuild_collections::assert_stores::public_permission;
 use junobuild_collections::types::rules::{Permission, Rule};
-use junobuild_shared::assert::{assert_description_length, assert_max_memory_size, assert_version};
+use junobuild_shared::assert::{
+    assert_description_length, assert_max_memory_size, assert_schedule, assert_version,
+};
+use junobuild_shared::errors::JunoError;
 use junobuild_shared::types::core::Key;
 use junobuild_shared::types::state::{Controllers, Version};
 
@@ -27,14 +39,26 @@ pub fn assert_set_doc(
     rule: &Rule,
     current_doc: &Option<Doc>,
 ) -> Result<(), String> {
+    assert_not_view(config, collection)?;
+
+    assert_collection_not_locked(collection)?;
+
     assert_write_permission(caller, controllers, current_doc, &rule.write)?;
 
+    assert_proof_of_work(caller, config, collection, key, &value.data)?;
+
     assert_memory_size(config)?;
 
+    assert_doc_user_quota(caller, controllers, collection, rule, current_doc, value)?;
+
     assert_write_version(current_doc, value.version)?;
 
     assert_description_length(&value.description)?;
 
+    assert_schedule(&value.publish_at, &value.unpublish_at)?;
+
+    assert_attachments(caller, controllers, config, collection, &value.attachments)?;
+
     assert_user_collection_caller_key(caller, collection, key)?;
 
     invoke_assert_set_doc(
@@ -65,6 +89,10 @@ pub fn assert_delete_doc(
     rule: &Rule,
     current_doc: &Option<Doc>,
 ) -> Result<(), String> {
+    assert_not_view(&get_config(), collection)?;
+
+    assert_collection_not_locked(collection)?;
+
     assert_write_permission(caller, controllers, current_doc, &rule.write)?;
 
     assert_write_version(current_doc, value.version)?;
@@ -86,6 +114,45 @@ pub fn assert_delete_doc(
     Ok(())
 }
 
+pub fn assert_db_config(config: &DbConfig) -> Result<(), String> {
+    for aggregate in config.aggregates.iter().flatten() {
+        if aggregate.parent_field.trim().is_empty() {
+            return Err(ERROR_AGGREGATE_EMPTY_PARENT_FIELD.to_string());
+        }
+
+        if aggregate.collection == aggregate.target_collection {
+            return Err(ERROR_AGGREGATE_SAME_COLLECTION.to_string());
+        }
+
+        // The aggregated and the target collections should exist - i.e. their rules be set first.
+        get_state_rule(&aggregate.collection)?;
+        get_state_rule(&aggregate.target_collection)?;
+    }
+
+    for view in config.views.iter().flatten() {
+        if view.fields.is_empty() {
+            return Err(ERROR_VIEW_EMPTY_FIELDS.to_string());
+        }
+
+        if view.sources.contains(&view.collection) {
+            return Err(ERROR_VIEW_SAME_COLLECTION.to_string());
+        }
+
+        // The collections of the view and its sources should exist - i.e. their rules be set first.
+        for collection in view.sources.iter().chain([&view.collection]) {
+            get_state_rule(collection)?;
+        }
+    }
+
+    for proof in config.proof_of_work.iter().flatten() {
+        if proof.difficulty > 32 {
+            return Err(ERROR_PROOF_OF_WORK_DIFFICULTY.to_string());
+        }
+    }
+
+    Ok(())
+}
+
 fn assert_memory_size(config: &Option<DbConfig>) -> Result<(), String> {
     match config {
         None => Ok(()),
@@ -103,13 +170,13 @@ fn assert_write_permission(
     if !public_permission(rule) {
         match current_doc {
             None => {
-                if !assert_create_permission(rule, caller, controllers) {
-                    return Err(ERROR_CANNOT_WRITE.to_string());
+                if !assert_gated_create_permission(rule, caller, controllers) {
+                    return Err(JunoError::new(ERROR_CODE_CANNOT_WRITE, ERROR_CANNOT_WRITE).into());
                 }
             }
             Some(current_doc) => {
-                if !assert_permission(rule, current_doc.owner, caller, controllers) {
-                    return Err(ERROR_CANNOT_WRITE.to_string());
+                if !assert_gated_write_permission(rule, current_doc.owner, caller, controllers) {
+                    return Err(JunoError::new(ERROR_CODE_CANNOT_WRITE, ERROR_CANNOT_WRITE).into());
                 }
             }
         }
@@ -0,0 +1,95 @@
+use crate::db::msg::{
+    ERROR_ATTACHMENTS_NOT_ENABLED, ERROR_ATTACHMENT_NOT_FOUND, ERROR_ATTACHMENT_NOT_OWNED,
+};
+use crate::db::state::get_config;
+use crate::db::types::config::{DbConfig, DbConfigAttachment};
+use crate::db::types::state::Doc;
+use crate::gc::store::defer_delete_attachments;
+use crate::storage::state::{get_asset as get_state_asset, get_rule as get_state_rule};
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::controllers::is_controller;
+use junobuild_shared::types::state::{Controllers, UserId};
+use junobuild_shared::utils::principal_equal;
+use junobuild_storage::types::state::FullPath;
+
+/// Asserts that the attachments of a document exist in the storage collection configured for its collection. Unless
+/// the caller is a controller, the assets must also belong to the caller - a user cannot attach, and therefore
+/// cascade the deletion of, the files of someone else.
+pub fn assert_attachments(
+    caller: UserId,
+    controllers: &Controllers,
+    config: &Option<DbConfig>,
+    collection: &CollectionKey,
+    attachments: &Option<Vec<FullPath>>,
+) -> Result<(), String> {
+    let attachments = match attachments {
+        None => {
+            return Ok(());
+        }
+        Some(attachments) if attachments.is_empty() => {
+            return Ok(());
+        }
+        Some(attachments) => attachments,
+    };
+
+    let storage_collection = match find_attachment_config(config, collection) {
+        None => {
+            return Err(ERROR_ATTACHMENTS_NOT_ENABLED.to_string());
+        }
+        Some(attachment) => attachment.storage_collection,
+    };
+
+    let rule = get_state_rule(&storage_collection)?;
+
+    for full_path in attachments {
+        match get_state_asset(&storage_collection, full_path, &rule) {
+            None => {
+                return Err(format!("{} {}", ERROR_ATTACHMENT_NOT_FOUND, full_path));
+            }
+            Some(asset) => {
+                if !is_controller(caller, controllers) && !principal_equal(asset.key.owner, caller)
+                {
+                    return Err(format!("{} {}", ERROR_ATTACHMENT_NOT_OWNED, full_path));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Defers the deletion of the attachments of a deleted document to the garbage collection if its collection is
+/// configured to cascade the deletion. Only the assets that still belong to the owner of the document are removed.
+pub fn delete_attachments(collection: &CollectionKey, deleted_doc: &Option<Doc>) {
+    let Some(doc) = deleted_doc else {
+        return;
+    };
+
+    let Some(attachments) = &doc.attachments else {
+        return;
+    };
+
+    let attachment = match find_attachment_config(&get_config(), collection) {
+        Some(attachment) if attachment.cascade_delete.unwrap_or(false) => attachment,
+        _ => {
+            return;
+        }
+    };
+
+    defer_delete_attachments(&attachment.storage_collection, attachments, &doc.owner);
+}
+
+fn find_attachment_config(
+    config: &Option<DbConfig>,
+    collection: &CollectionKey,
+) -> Option<DbConfigAttachment> {
+    config
+        .as_ref()
+        .and_then(|config| config.attachments.as_ref())
+        .and_then(|attachments| {
+            attachments
+                .iter()
+                .find(|attachment| attachment.collection == *collection)
+                .cloned()
+        })
+}
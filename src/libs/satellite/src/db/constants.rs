@@ -0,0 +1,10 @@
+// Same size as the chunks of the assets uploaded by the JS libraries, which keeps each response below the limit.
+pub const DOC_CHUNK_SIZE: usize = 1_900_000;
+
+// The sums of the aggregates are accumulated as fixed-point decimals with this number of decimal places, so that
+// adding and subtracting the fields of the documents does not drift like floating-point numbers.
+pub const AGGREGATE_SUM_DECIMALS: u32 = 9;
+
+// The number of documents deleted within the call of a bulk deletion. The others are deleted in the background by the
+// garbage collection, so that the call does not reach the instruction limit.
+pub const DEL_DOCS_BATCH_SIZE: usize = 100;
@@ -0,0 +1,291 @@
+use crate::api_keys::store::{assert_api_key_scope, authenticate_api_key};
+use crate::db::msg::{ERROR_HTTP_DOC_INVALID_DATA, ERROR_HTTP_DOC_NOT_FOUND};
+use crate::db::state::get_config;
+use crate::db::store::{collect_docs_keys_store, get_api_key_doc_store, get_http_doc_store};
+use crate::db::types::state::Doc;
+use candid::encode_one;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::core::{Blob, Key};
+use junobuild_storage::certification::types::certified::CertifiedAssetHashes;
+use junobuild_storage::constants::{
+    RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_404, RESPONSE_STATUS_CODE_500,
+};
+use junobuild_storage::http::response::{build_static_response, error_response, upgrade_response};
+use junobuild_storage::http::types::{HeaderField, HttpRequest, HttpResponse, StatusCode};
+use junobuild_storage::http::utils::get_header_value;
+use junobuild_storage::runtime::{
+    delete_certified_static, is_certified_static, update_certified_static,
+};
+use junobuild_storage::types::state::FullPath;
+use serde::Serialize;
+use urlencoding::decode;
+
+pub const DB_PATH_PREFIX: &str = "/_juno/db/";
+
+const CONTENT_TYPE_JSON: &str = "application/json; charset=utf-8";
+const CONTENT_TYPE_CANDID: &str = "application/candid";
+
+const RESPONSE_STATUS_CODE_401: StatusCode = 401;
+const RESPONSE_STATUS_CODE_403: StatusCode = 403;
+
+#[derive(Serialize)]
+struct HttpDoc {
+    collection: CollectionKey,
+    key: Key,
+    owner: String,
+    data: serde_json::Value,
+    description: Option<String>,
+    created_at: String,
+    updated_at: String,
+    version: Option<String>,
+}
+
+pub fn is_db_request(HttpRequest { method, url, .. }: &HttpRequest) -> bool {
+    let path = url.split('?').next().unwrap_or_default();
+
+    (method == "GET" || method == "HEAD") && path.starts_with(DB_PATH_PREFIX)
+}
+
+/// The documents of the exposed collections are certified when these are written, therefore these are answered with a
+/// query. The requests providing an API key, as well as the documents not - or not yet - certified, e.g. those whose
+/// publication was scheduled, are upgraded and answered with an update call, whose response goes through consensus.
+pub fn http_request(
+    HttpRequest {
+        url,
+        headers,
+        certificate_version,
+        ..
+    }: &HttpRequest,
+) -> HttpResponse {
+    if get_bearer_secret(headers).is_some() {
+        return upgrade_response();
+    }
+
+    let Some((collection, key)) = parse_path(url) else {
+        return error_response(
+            RESPONSE_STATUS_CODE_404,
+            ERROR_HTTP_DOC_NOT_FOUND.to_string(),
+        );
+    };
+
+    let Some(doc) = get_http_doc_store(collection.clone(), key.clone())
+        .ok()
+        .flatten()
+    else {
+        return error_response(
+            RESPONSE_STATUS_CODE_404,
+            ERROR_HTTP_DOC_NOT_FOUND.to_string(),
+        );
+    };
+
+    let accept_candid = accepts_candid(headers);
+
+    let Ok(body) = doc_body(&collection, &key, doc, accept_candid) else {
+        return upgrade_response();
+    };
+
+    let path = doc_path(&collection, &key);
+    let headers = doc_headers(accept_candid);
+
+    if !is_certified_static(&path, &headers, &body, certificate_version) {
+        return upgrade_response();
+    }
+
+    build_static_response(&path, *certificate_version, &headers, &body)
+}
+
+/// The document is returned as JSON unless the request accepts `application/candid`, in which case it is encoded as
+/// the `Doc` returned by `get_doc`.
+///
+/// Backends can provide an API key in the `Authorization: Bearer` header to read the documents of the collections in
+/// its scope, even if these are not exposed publicly.
+pub fn http_request_update(HttpRequest { url, headers, .. }: HttpRequest) -> HttpResponse {
+    let Some((collection, key)) = parse_path(&url) else {
+        return error_response(
+            RESPONSE_STATUS_CODE_404,
+            ERROR_HTTP_DOC_NOT_FOUND.to_string(),
+        );
+    };
+
+    let doc = match get_bearer_secret(&headers) {
+        None => get_http_doc_store(collection.clone(), key.clone()),
+        Some(secret) => {
+            let api_key = match authenticate_api_key(&secret) {
+                Ok(api_key) => api_key,
+                Err(err) => return error_response(RESPONSE_STATUS_CODE_401, err),
+            };
+
+            if let Err(err) = assert_api_key_scope(&api_key, &collection) {
+                return error_response(RESPONSE_STATUS_CODE_403, err);
+            }
+
+            get_api_key_doc_store(collection.clone(), key.clone())
+        }
+    };
+
+    let doc = match doc.ok().flatten() {
+        Some(doc) => doc,
+        None => {
+            return error_response(
+                RESPONSE_STATUS_CODE_404,
+                ERROR_HTTP_DOC_NOT_FOUND.to_string(),
+            )
+        }
+    };
+
+    let accept_candid = accepts_candid(&headers);
+
+    match doc_body(&collection, &key, doc, accept_candid) {
+        Err(err) => error_response(RESPONSE_STATUS_CODE_500, err),
+        Ok(body) => HttpResponse {
+            body,
+            headers: doc_headers(accept_candid),
+            status_code: RESPONSE_STATUS_CODE_200,
+            streaming_strategy: None,
+            upgrade: None,
+        },
+    }
+}
+
+/// Certifies - or removes from the certification - the responses of a document that was written, in JSON and in
+/// Candid, side by side for its path.
+pub fn certify_http_doc(collection: &CollectionKey, key: &Key) {
+    if !is_http_collection(collection) {
+        return;
+    }
+
+    let path = doc_path(collection, key);
+
+    match get_http_doc_store(collection.clone(), key.clone())
+        .ok()
+        .flatten()
+    {
+        None => delete_certified_static(&path),
+        Some(doc) => update_certified_static(&path, &doc_responses(collection, key, doc)),
+    }
+}
+
+/// Certifies the documents of all the exposed collections while the tree of the certification is (re)built.
+pub fn certify_http_docs(asset_hashes: &mut CertifiedAssetHashes) {
+    let collections = get_config()
+        .and_then(|config| config.http_collections)
+        .unwrap_or_default();
+
+    for collection in collections {
+        let Ok(keys) = collect_docs_keys_store(&collection) else {
+            continue;
+        };
+
+        for key in keys {
+            if let Some(doc) = get_http_doc_store(collection.clone(), key.clone())
+                .ok()
+                .flatten()
+            {
+                let path = doc_path(&collection, &key);
+
+                for (headers, body) in doc_responses(&collection, &key, doc) {
+                    asset_hashes.insert_static_v2(&path, &headers, &body);
+                }
+            }
+        }
+    }
+}
+
+pub fn is_http_collection(collection: &CollectionKey) -> bool {
+    get_config()
+        .and_then(|config| config.http_collections)
+        .is_some_and(|collections| collections.contains(collection))
+}
+
+// The Candid response is inserted first so that the tree v1 - which holds a single response per path - certifies the JSON.
+fn doc_responses(collection: &CollectionKey, key: &Key, doc: Doc) -> Vec<(Vec<HeaderField>, Blob)> {
+    [true, false]
+        .into_iter()
+        .filter_map(|accept_candid| {
+            doc_body(collection, key, doc.clone(), accept_candid)
+                .ok()
+                .map(|body| (doc_headers(accept_candid), body))
+        })
+        .collect()
+}
+
+fn doc_body(
+    collection: &CollectionKey,
+    key: &Key,
+    doc: Doc,
+    accept_candid: bool,
+) -> Result<Blob, String> {
+    if accept_candid {
+        encode_one(&doc).map_err(|err| err.to_string())
+    } else {
+        json_doc(collection.clone(), key.clone(), doc)
+    }
+}
+
+fn doc_headers(accept_candid: bool) -> Vec<HeaderField> {
+    Vec::from([
+        HeaderField(
+            "Content-Type".to_string(),
+            if accept_candid {
+                CONTENT_TYPE_CANDID.to_string()
+            } else {
+                CONTENT_TYPE_JSON.to_string()
+            },
+        ),
+        HeaderField("Cache-Control".to_string(), "no-store".to_string()),
+        HeaderField("Vary".to_string(), "Accept, Authorization".to_string()),
+        HeaderField("Access-Control-Allow-Origin".to_string(), "*".to_string()),
+    ])
+}
+
+fn accepts_candid(headers: &[HeaderField]) -> bool {
+    get_header_value(headers, "Accept").is_some_and(|accept| accept.contains(CONTENT_TYPE_CANDID))
+}
+
+// The decoded path, i.e. the one that is certified
+fn doc_path(collection: &CollectionKey, key: &Key) -> FullPath {
+    format!("{DB_PATH_PREFIX}{collection}/{key}")
+}
+
+fn get_bearer_secret(headers: &[HeaderField]) -> Option<String> {
+    get_header_value(headers, "Authorization").and_then(|value| {
+        value
+            .strip_prefix("Bearer ")
+            .map(|secret| secret.trim().to_string())
+    })
+}
+
+// e.g. /_juno/db/posts/hello%20world => ("posts", "hello world"). The key may contain slashes.
+fn parse_path(url: &str) -> Option<(CollectionKey, Key)> {
+    let path = url.split('?').next().unwrap_or_default();
+
+    let (collection, key) = path.strip_prefix(DB_PATH_PREFIX)?.split_once('/')?;
+
+    let collection = decode(collection).ok()?.to_string();
+    let key = decode(key).ok()?.to_string();
+
+    if collection.is_empty() || key.is_empty() {
+        return None;
+    }
+
+    Some((collection, key))
+}
+
+// The timestamps and versions are serialized as strings because JSON numbers cannot represent all u64 values.
+fn json_doc(collection: CollectionKey, key: Key, doc: Doc) -> Result<Vec<u8>, String> {
+    let data: serde_json::Value =
+        serde_json::from_slice(&doc.data).map_err(|_| ERROR_HTTP_DOC_INVALID_DATA.to_string())?;
+
+    let http_doc = HttpDoc {
+        collection,
+        key,
+        owner: doc.owner.to_text(),
+        data,
+        description: doc.description,
+        created_at: doc.created_at.to_string(),
+        updated_at: doc.updated_at.to_string(),
+        version: doc.version.map(|version| version.to_string()),
+    };
+
+    serde_json::to_vec(&http_doc).map_err(|err| err.to_string())
+}
@@ -1,4 +1,4 @@
-use crate::db::types::state::{DbHeapState, Doc, StableKey};
+use crate::db::types::state::{DbHeapState, Doc, StableDoc, StableKey};
 use crate::SetDoc;
 use candid::Principal;
 use ic_cdk::api::time;
@@ -8,7 +8,7 @@ use junobuild_collections::constants::DEFAULT_DB_COLLECTIONS;
 use junobuild_collections::types::rules::{Memory, Rule};
 use junobuild_shared::constants::INITIAL_VERSION;
 use junobuild_shared::serializers::{deserialize_from_bytes, serialize_to_bytes};
-use junobuild_shared::types::state::Timestamped;
+use junobuild_shared::types::state::{Moderated, ModerationStatus, Scheduled, Timestamped};
 use junobuild_shared::types::state::{Timestamp, UserId, Version};
 use std::borrow::Cow;
 use std::cmp::Ordering;
@@ -37,6 +37,10 @@ impl Default for DbHeapState {
                         updated_at: now,
                         version: rule.version,
                         rate_config: rule.rate_config,
+                        user_quota: rule.user_quota,
+                        collation: rule.collation,
+                        retention: rule.retention,
+                        compression: None,
                     },
                 )
             })),
@@ -63,6 +67,22 @@ impl Timestamped for Doc {
     }
 }
 
+impl Scheduled for Doc {
+    fn publish_at(&self) -> Option<Timestamp> {
+        self.publish_at
+    }
+
+    fn unpublish_at(&self) -> Option<Timestamp> {
+        self.unpublish_at
+    }
+}
+
+impl Moderated for Doc {
+    fn moderation(&self) -> Option<ModerationStatus> {
+        self.moderation.clone()
+    }
+}
+
 impl Storable for Doc {
     fn to_bytes(&self) -> Cow<[u8]> {
         serialize_to_bytes(self)
@@ -75,6 +95,18 @@ impl Storable for Doc {
     const BOUND: Bound = Bound::Unbounded;
 }
 
+impl Storable for StableDoc {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
 impl Storable for StableKey {
     fn to_bytes(&self) -> Cow<[u8]> {
         serialize_to_bytes(self)
@@ -115,6 +147,10 @@ impl Doc {
             created_at,
             updated_at,
             version: Some(version),
+            publish_at: user_doc.publish_at,
+            unpublish_at: user_doc.unpublish_at,
+            moderation: None,
+            attachments: user_doc.attachments,
         }
     }
 }
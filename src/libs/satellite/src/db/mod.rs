@@ -1,8 +1,15 @@
+pub mod aggregates;
 mod assert;
+mod attachments;
+mod constants;
+pub mod http;
 pub mod impls;
 mod msg;
+mod patch;
+mod proof_of_work;
 mod runtime;
-mod state;
+pub mod state;
 pub mod store;
 pub mod types;
 mod utils;
+pub mod views;
@@ -1,2 +1,26 @@
 /// Db
 pub const ERROR_CANNOT_WRITE: &str = "Cannot write.";
+pub const ERROR_CODE_CANNOT_WRITE: &str = "error_cannot_write";
+pub const ERROR_AGGREGATE_INVALID_DATA: &str = "Aggregate document data cannot be parsed.";
+pub const ERROR_AGGREGATE_EMPTY_PARENT_FIELD: &str = "Aggregate parent field cannot be empty.";
+pub const ERROR_AGGREGATE_SAME_COLLECTION: &str =
+    "Aggregate target collection must differ from the aggregated collection.";
+pub const ERROR_AGGREGATE_SUM_OUT_OF_RANGE: &str = "Aggregate sum is out of range.";
+pub const ERROR_PROOF_OF_WORK_INVALID: &str = "Invalid proof of work.";
+pub const ERROR_DOC_CHUNK_OUT_OF_RANGE: &str = "Document chunk index out of range.";
+pub const ERROR_PROOF_OF_WORK_DIFFICULTY: &str = "Proof of work difficulty cannot exceed 32 bits.";
+pub const ERROR_HTTP_DOC_NOT_FOUND: &str = "Document not found.";
+pub const ERROR_HTTP_DOC_INVALID_DATA: &str = "Document data is not JSON.";
+pub const ERROR_ATTACHMENTS_NOT_ENABLED: &str = "Attachments are not enabled for this collection.";
+pub const ERROR_ATTACHMENT_NOT_FOUND: &str = "Attached asset not found:";
+pub const ERROR_ATTACHMENT_NOT_OWNED: &str = "Attached asset does not belong to the caller:";
+pub const ERROR_VIEW_READ_ONLY: &str = "The documents of a view cannot be written.";
+pub const ERROR_VIEW_EMPTY_FIELDS: &str = "A view must project at least one field.";
+pub const ERROR_VIEW_SAME_COLLECTION: &str = "A view cannot be one of its sources.";
+pub const ERROR_PATCH_DOC_NOT_FOUND: &str = "The document to patch does not exist.";
+pub const ERROR_PATCH_DOC_INVALID_DATA: &str = "The data of the document is not JSON.";
+pub const ERROR_PATCH_INVALID: &str = "The patch is not valid.";
+pub const ERROR_PATCH_INVALID_POINTER: &str = "Invalid JSON pointer:";
+pub const ERROR_PATCH_PATH_NOT_FOUND: &str = "Path not found:";
+pub const ERROR_PATCH_TEST_FAILED: &str = "Test operation failed:";
+pub const ERROR_PATCH_MOVE_INTO_CHILD: &str = "A value cannot be moved into one of its children.";
@@ -0,0 +1,188 @@
+use crate::db::msg::{
+    ERROR_PATCH_DOC_INVALID_DATA, ERROR_PATCH_INVALID, ERROR_PATCH_INVALID_POINTER,
+    ERROR_PATCH_MOVE_INTO_CHILD, ERROR_PATCH_PATH_NOT_FOUND, ERROR_PATCH_TEST_FAILED,
+};
+use crate::db::types::interface::DocPatch;
+use junobuild_shared::types::core::Blob;
+use serde::Deserialize;
+use serde_json::{from_slice, from_str, to_vec, Map, Value};
+
+/// An operation of a JSON Patch (RFC 6902).
+#[derive(Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+enum PatchOperation {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+/// Applies a patch to the data of a document - i.e. its JSON encoding, as the documents are written by the client
+/// libraries - and returns the encoding of the patched data.
+///
+/// The operations of a JSON Patch are applied all or nothing: if one of them fails, the data is left untouched.
+pub fn apply_doc_patch(data: &Blob, patch: &DocPatch) -> Result<Blob, String> {
+    let mut value: Value =
+        from_slice(data).map_err(|_| ERROR_PATCH_DOC_INVALID_DATA.to_string())?;
+
+    match patch {
+        DocPatch::JsonPatch(patch) => {
+            let operations: Vec<PatchOperation> =
+                from_str(patch).map_err(|err| format!("{} ({})", ERROR_PATCH_INVALID, err))?;
+
+            for operation in operations {
+                apply_operation(&mut value, operation)?;
+            }
+        }
+        DocPatch::MergePatch(patch) => {
+            let patch: Value =
+                from_str(patch).map_err(|err| format!("{} ({})", ERROR_PATCH_INVALID, err))?;
+
+            merge_patch(&mut value, &patch);
+        }
+    }
+
+    to_vec(&value).map_err(|err| err.to_string())
+}
+
+fn apply_operation(value: &mut Value, operation: PatchOperation) -> Result<(), String> {
+    match operation {
+        PatchOperation::Add { path, value: new } => add(value, &path, new),
+        PatchOperation::Remove { path } => remove(value, &path).map(|_| ()),
+        PatchOperation::Replace { path, value: new } => {
+            let target = value
+                .pointer_mut(&validate_pointer(&path)?)
+                .ok_or(format!("{} {}", ERROR_PATCH_PATH_NOT_FOUND, path))?;
+
+            *target = new;
+
+            Ok(())
+        }
+        PatchOperation::Move { from, path } => {
+            if path.starts_with(&format!("{}/", from)) {
+                return Err(ERROR_PATCH_MOVE_INTO_CHILD.to_string());
+            }
+
+            let moved = remove(value, &from)?;
+            add(value, &path, moved)
+        }
+        PatchOperation::Copy { from, path } => {
+            let copied = value
+                .pointer(&validate_pointer(&from)?)
+                .ok_or(format!("{} {}", ERROR_PATCH_PATH_NOT_FOUND, from))?
+                .clone();
+
+            add(value, &path, copied)
+        }
+        PatchOperation::Test {
+            path,
+            value: expected,
+        } => match value.pointer(&validate_pointer(&path)?) {
+            Some(current) if *current == expected => Ok(()),
+            _ => Err(format!("{} {}", ERROR_PATCH_TEST_FAILED, path)),
+        },
+    }
+}
+
+fn add(value: &mut Value, path: &str, new: Value) -> Result<(), String> {
+    let Some((parent, token)) = split_pointer(path)? else {
+        *value = new;
+        return Ok(());
+    };
+
+    let parent = value
+        .pointer_mut(&parent)
+        .ok_or(format!("{} {}", ERROR_PATCH_PATH_NOT_FOUND, path))?;
+
+    match parent {
+        Value::Object(map) => {
+            map.insert(token, new);
+            Ok(())
+        }
+        Value::Array(items) => {
+            if token == "-" {
+                items.push(new);
+                return Ok(());
+            }
+
+            match array_index(&token) {
+                Some(index) if index <= items.len() => {
+                    items.insert(index, new);
+                    Ok(())
+                }
+                _ => Err(format!("{} {}", ERROR_PATCH_PATH_NOT_FOUND, path)),
+            }
+        }
+        _ => Err(format!("{} {}", ERROR_PATCH_PATH_NOT_FOUND, path)),
+    }
+}
+
+fn remove(value: &mut Value, path: &str) -> Result<Value, String> {
+    let not_found = || format!("{} {}", ERROR_PATCH_PATH_NOT_FOUND, path);
+
+    let Some((parent, token)) = split_pointer(path)? else {
+        return Ok(std::mem::take(value));
+    };
+
+    match value.pointer_mut(&parent) {
+        Some(Value::Object(map)) => map.remove(&token).ok_or_else(not_found),
+        Some(Value::Array(items)) => match array_index(&token) {
+            Some(index) if index < items.len() => Ok(items.remove(index)),
+            _ => Err(not_found()),
+        },
+        _ => Err(not_found()),
+    }
+}
+
+fn validate_pointer(path: &str) -> Result<String, String> {
+    if !path.is_empty() && !path.starts_with('/') {
+        return Err(format!("{} {}", ERROR_PATCH_INVALID_POINTER, path));
+    }
+
+    Ok(path.to_string())
+}
+
+/// Splits a JSON pointer (RFC 6901) into the pointer of the parent and the unescaped last token. Returns `None` for the
+/// pointer of the whole document.
+fn split_pointer(path: &str) -> Result<Option<(String, String)>, String> {
+    validate_pointer(path)?;
+
+    Ok(path.rsplit_once('/').map(|(parent, token)| {
+        (
+            parent.to_string(),
+            token.replace("~1", "/").replace("~0", "~"),
+        )
+    }))
+}
+
+fn array_index(token: &str) -> Option<usize> {
+    if token.len() > 1 && token.starts_with('0') {
+        return None;
+    }
+
+    token.parse::<usize>().ok()
+}
+
+/// Applies a JSON Merge Patch (RFC 7386) - i.e. the objects are merged recursively and `null` removes a field.
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
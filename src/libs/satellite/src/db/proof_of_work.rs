@@ -0,0 +1,67 @@
+use crate::db::msg::ERROR_PROOF_OF_WORK_INVALID;
+use crate::db::types::config::DbConfig;
+use candid::Principal;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::core::{Blob, Key};
+use junobuild_shared::utils::principal_not_anonymous;
+use sha2::{Digest, Sha256};
+
+/// Anonymous writes to a collection configured with a proof of work must be accompanied by a key for which
+/// `sha256("{collection}:{key}:" + data)` starts with at least `difficulty` zero bits.
+///
+/// The key of the document serves as the nonce, so that the client can search for a proof without any additional
+/// information being transmitted. Writes from authenticated callers are not affected.
+pub fn assert_proof_of_work(
+    caller: Principal,
+    config: &Option<DbConfig>,
+    collection: &CollectionKey,
+    key: &Key,
+    data: &Blob,
+) -> Result<(), String> {
+    if principal_not_anonymous(caller) {
+        return Ok(());
+    }
+
+    let difficulty = config
+        .as_ref()
+        .and_then(|config| config.proof_of_work.as_ref())
+        .and_then(|proofs| {
+            proofs
+                .iter()
+                .find(|proof| proof.collection == *collection)
+                .map(|proof| proof.difficulty)
+        });
+
+    match difficulty {
+        None => Ok(()),
+        Some(difficulty) => {
+            if leading_zero_bits(&proof_hash(collection, key, data)) < difficulty as u32 {
+                return Err(ERROR_PROOF_OF_WORK_INVALID.to_string());
+            }
+
+            Ok(())
+        }
+    }
+}
+
+fn proof_hash(collection: &CollectionKey, key: &Key, data: &Blob) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{}:{}:", collection, key).as_bytes());
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+fn leading_zero_bits(hash: &[u8; 32]) -> u32 {
+    let mut bits = 0;
+
+    for byte in hash {
+        if *byte == 0 {
+            bits += 8;
+            continue;
+        }
+
+        return bits + byte.leading_zeros();
+    }
+
+    bits
+}
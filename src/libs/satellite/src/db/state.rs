@@ -1,12 +1,25 @@
+use crate::changes::state::increment_db_version;
+use crate::compression::utils::{compress_data, decompress_data};
+use crate::db::http::certify_http_doc;
 use crate::db::types::config::DbConfig;
-use crate::db::types::state::{Collection, DbHeap, DbHeapState, DbStable, Doc, StableKey};
+use crate::db::types::state::{
+    Collection, DbHeap, DbHeapState, DbStable, Doc, StableDoc, StableKey,
+};
+use crate::defrag::state::usage_mut;
+use crate::defrag::stats::entry_length;
+use crate::defrag::types::state::RegionUsage;
 use crate::memory::STATE;
+use crate::retention::state::update_db_retention_index;
+use crate::revisions::store::record_doc_revision;
+use crate::stats::store::update_doc_stats;
+use ic_cdk::trap;
 use junobuild_collections::msg::msg_db_collection_not_found;
 use junobuild_collections::types::core::CollectionKey;
-use junobuild_collections::types::rules::{Memory, Rule};
+use junobuild_collections::types::rules::{Compression, Memory, Rule};
 use junobuild_collections::utils::range_collection_end;
 use junobuild_shared::types::core::Key;
 use std::collections::BTreeMap;
+use std::ops::Bound::{Excluded, Included, Unbounded};
 use std::ops::RangeBounds;
 
 // ---------------------------------------------------------
@@ -112,7 +125,7 @@ pub fn insert_doc(
     doc: &Doc,
     rule: &Rule,
 ) -> Result<(Option<(Key, Doc)>, Doc), String> {
-    match rule.mem() {
+    let (evicted_doc, previous_doc, doc) = match rule.mem() {
         Memory::Heap => STATE.with(|state| {
             insert_doc_heap(
                 collection,
@@ -123,15 +136,60 @@ pub fn insert_doc(
             )
         }),
         Memory::Stable => STATE.with(|state| {
+            let state = &mut *state.borrow_mut();
+
             insert_doc_stable(
                 collection,
                 key,
                 doc,
                 rule.max_capacity,
-                &mut state.borrow_mut().stable.db,
+                &rule.compression,
+                &mut state.stable.db,
+                &mut usage_mut(&mut state.heap.defrag).db,
             )
         }),
+    }?;
+
+    // The document being updated might be the one evicted to respect the capacity, in which case it is not replaced
+    // by the insertion and is counted once.
+    let current_doc = previous_doc.clone().or_else(|| {
+        evicted_doc
+            .as_ref()
+            .filter(|(evicted_key, _)| evicted_key == key)
+            .map(|(_, evicted)| evicted.clone())
+    });
+
+    record_doc_revision(collection, key, &current_doc);
+
+    if let Some((evicted_key, evicted)) = &evicted_doc {
+        update_doc_stats(collection, Some(evicted), None);
+        update_db_retention_index(collection, evicted_key, Some(evicted.updated_at), None);
+
+        if evicted_key != key {
+            record_doc_revision(collection, evicted_key, &Some(evicted.clone()));
+        }
     }
+
+    update_doc_stats(collection, previous_doc.as_ref(), Some(&doc));
+    update_db_retention_index(
+        collection,
+        key,
+        previous_doc.as_ref().map(|doc| doc.updated_at),
+        Some(doc.updated_at),
+    );
+
+    increment_db_version(collection);
+
+    certify_http_doc(collection, key);
+
+    if let Some((evicted_key, _)) = evicted_doc
+        .as_ref()
+        .filter(|(evicted_key, _)| evicted_key != key)
+    {
+        certify_http_doc(collection, evicted_key);
+    }
+
+    Ok((evicted_doc, doc))
 }
 
 pub fn delete_doc(
@@ -139,13 +197,37 @@ pub fn delete_doc(
     key: &Key,
     rule: &Rule,
 ) -> Result<Option<Doc>, String> {
-    match rule.mem() {
+    let deleted_doc = match rule.mem() {
         Memory::Heap => {
             STATE.with(|state| delete_doc_heap(collection, key, &mut state.borrow_mut().heap.db.db))
         }
-        Memory::Stable => STATE
-            .with(|state| delete_doc_stable(collection, key, &mut state.borrow_mut().stable.db)),
+        Memory::Stable => STATE.with(|state| {
+            let state = &mut *state.borrow_mut();
+
+            delete_doc_stable(
+                collection,
+                key,
+                &mut state.stable.db,
+                &mut usage_mut(&mut state.heap.defrag).db,
+            )
+        }),
+    }?;
+
+    update_doc_stats(collection, deleted_doc.as_ref(), None);
+    update_db_retention_index(
+        collection,
+        key,
+        deleted_doc.as_ref().map(|doc| doc.updated_at),
+        None,
+    );
+
+    if deleted_doc.is_some() {
+        record_doc_revision(collection, key, &deleted_doc);
+        increment_db_version(collection);
+        certify_http_doc(collection, key);
     }
+
+    Ok(deleted_doc)
 }
 
 // Get
@@ -159,7 +241,7 @@ fn get_doc_stable(
 
     match value {
         None => Ok(None),
-        Some(value) => Ok(Some(value)),
+        Some(value) => decompress_doc(value).map(Some),
     }
 }
 
@@ -185,11 +267,61 @@ pub fn get_docs_stable(
     collection: &CollectionKey,
     db: &DbStable,
 ) -> Result<Vec<(StableKey, Doc)>, String> {
-    let items = db.range(filter_docs_range(collection)).collect();
+    let items = iter_docs_stable(collection, db).collect();
 
     Ok(items)
 }
 
+pub fn iter_docs_stable<'a>(
+    collection: &CollectionKey,
+    db: &'a DbStable,
+) -> impl Iterator<Item = (StableKey, Doc)> + 'a {
+    db.range(filter_docs_range(collection))
+        .map(|(key, doc)| (key, read_doc(doc)))
+}
+
+/// The documents of a collection sorted by key, starting after the given key.
+pub fn iter_docs_stable_after<'a>(
+    collection: &CollectionKey,
+    start_after: &Option<Key>,
+    db: &'a DbStable,
+) -> impl Iterator<Item = (StableKey, Doc)> + 'a {
+    let start_key = StableKey {
+        collection: collection.clone(),
+        key: start_after.clone().unwrap_or_default(),
+    };
+
+    let start = match start_after {
+        None => Included(start_key),
+        Some(_) => Excluded(start_key),
+    };
+
+    let end_key = StableKey {
+        collection: range_collection_end(collection).clone(),
+        key: "".to_string(),
+    };
+
+    db.range((start, Excluded(end_key)))
+        .map(|(key, doc)| (key, read_doc(doc)))
+}
+
+pub fn iter_docs_heap_after<'a>(
+    collection: &CollectionKey,
+    start_after: &Option<Key>,
+    db: &'a DbHeap,
+) -> Result<impl Iterator<Item = (&'a Key, &'a Doc)> + 'a, String> {
+    let col = db
+        .get(collection)
+        .ok_or_else(|| msg_db_collection_not_found(collection))?;
+
+    let start = match start_after {
+        None => Unbounded,
+        Some(start_after) => Excluded(start_after.clone()),
+    };
+
+    Ok(col.range::<Key, _>((start, Unbounded)))
+}
+
 pub fn get_docs_heap<'a>(
     collection: &CollectionKey,
     db: &'a DbHeap,
@@ -236,24 +368,44 @@ fn filter_docs_range(collection: &CollectionKey) -> impl RangeBounds<StableKey>
 
 // Insert
 
+/// The document evicted to respect the capacity of the collection, the document replaced by the insertion and the
+/// inserted document.
+type InsertedDoc = (Option<(Key, Doc)>, Option<Doc>, Doc);
+
 fn insert_doc_stable(
     collection: &CollectionKey,
     key: &Key,
     doc: &Doc,
     max_capacity: Option<u32>,
+    compression: &Option<Compression>,
     db: &mut DbStable,
-) -> Result<(Option<(Key, Doc)>, Doc), String> {
-    let evicted_doc = limit_docs_stable_capacity(collection, max_capacity, db)?;
+    usage: &mut RegionUsage,
+) -> Result<InsertedDoc, String> {
+    let evicted_doc = limit_docs_stable_capacity(collection, max_capacity, db, usage)?;
+
+    let stable_key = stable_key(collection, key);
+    let stored = compress_doc(doc, compression);
 
-    db.insert(stable_key(collection, key), doc.clone());
+    let length = entry_length(&stable_key, &stored);
+    let previous = db.insert(stable_key.clone(), stored);
 
-    Ok((evicted_doc.clone(), doc.clone()))
+    usage.update(
+        previous
+            .as_ref()
+            .map(|previous| entry_length(&stable_key, previous)),
+        Some(length),
+    );
+
+    let previous_doc = previous.map(decompress_doc).transpose()?;
+
+    Ok((evicted_doc, previous_doc, doc.clone()))
 }
 
 fn limit_docs_stable_capacity(
     collection: &CollectionKey,
     max_capacity: Option<u32>,
     db: &mut DbStable,
+    usage: &mut RegionUsage,
 ) -> Result<Option<(Key, Doc)>, String> {
     if let Some(max_capacity) = max_capacity {
         let col_length = count_docs_stable(collection, db)?;
@@ -262,7 +414,7 @@ fn limit_docs_stable_capacity(
             let last_item = db.range(filter_docs_range(collection)).next();
 
             if let Some((last_key, _)) = last_item {
-                let evicted_doc = delete_doc_stable(collection, &last_key.key, db)?;
+                let evicted_doc = delete_doc_stable(collection, &last_key.key, db, usage)?;
 
                 return Ok(evicted_doc.map(|doc| (last_key.key.clone(), doc)));
             }
@@ -278,7 +430,7 @@ fn insert_doc_heap(
     doc: &Doc,
     max_capacity: Option<u32>,
     db: &mut DbHeap,
-) -> Result<(Option<(Key, Doc)>, Doc), String> {
+) -> Result<InsertedDoc, String> {
     let col = db.get_mut(collection);
 
     match col {
@@ -286,8 +438,8 @@ fn insert_doc_heap(
         Some(col) => {
             let evicted_doc = limit_docs_heap_capacity(max_capacity, col);
 
-            col.insert(key.clone(), doc.clone());
-            Ok((evicted_doc.clone(), doc.clone()))
+            let previous_doc = col.insert(key.clone(), doc.clone());
+            Ok((evicted_doc, previous_doc, doc.clone()))
         }
     }
 }
@@ -308,10 +460,20 @@ fn delete_doc_stable(
     collection: &CollectionKey,
     key: &Key,
     db: &mut DbStable,
+    usage: &mut RegionUsage,
 ) -> Result<Option<Doc>, String> {
-    let deleted_doc = db.remove(&stable_key(collection, key));
+    let stable_key = stable_key(collection, key);
 
-    Ok(deleted_doc)
+    let deleted_doc = db.remove(&stable_key);
+
+    usage.update(
+        deleted_doc
+            .as_ref()
+            .map(|deleted_doc| entry_length(&stable_key, deleted_doc)),
+        None,
+    );
+
+    deleted_doc.map(decompress_doc).transpose()
 }
 
 fn delete_doc_heap(
@@ -331,6 +493,90 @@ fn delete_doc_heap(
     }
 }
 
+// Compact
+
+/// Writes the documents of a collection in stable memory again with its compression - e.g. those written before it
+/// was enabled - if they shrink. Returns the number of documents written again and the bytes reclaimed.
+pub fn compact_docs_stable(collection: &CollectionKey, keys: &[Key], rule: &Rule) -> (u64, u64) {
+    STATE.with(|state| {
+        let state = &mut *state.borrow_mut();
+
+        let db = &mut state.stable.db;
+        let usage = &mut usage_mut(&mut state.heap.defrag).db;
+
+        let mut rewritten_docs: u64 = 0;
+        let mut reclaimed_bytes: u64 = 0;
+
+        for key in keys {
+            let stable_key = stable_key(collection, key);
+
+            let Some(stored) = db.get(&stable_key) else {
+                continue;
+            };
+
+            let Ok(doc) = decompress_doc(stored.clone()) else {
+                continue;
+            };
+
+            let compacted = compress_doc(&doc, &rule.compression);
+
+            if compacted.data.len() < stored.data.len() {
+                rewritten_docs += 1;
+                reclaimed_bytes += (stored.data.len() - compacted.data.len()) as u64;
+
+                usage.update(
+                    Some(entry_length(&stable_key, &stored)),
+                    Some(entry_length(&stable_key, &compacted)),
+                );
+
+                db.insert(stable_key, compacted);
+            }
+        }
+
+        (rewritten_docs, reclaimed_bytes)
+    })
+}
+
+// Only the data of the documents is compressed - i.e. the other fields remain small.
+
+fn compress_doc(doc: &Doc, compression: &Option<Compression>) -> StableDoc {
+    let (data, compression) = compress_data(&doc.data, compression);
+
+    StableDoc {
+        owner: doc.owner,
+        data,
+        description: doc.description.clone(),
+        created_at: doc.created_at,
+        updated_at: doc.updated_at,
+        version: doc.version,
+        publish_at: doc.publish_at,
+        unpublish_at: doc.unpublish_at,
+        moderation: doc.moderation.clone(),
+        attachments: doc.attachments.clone(),
+        compression,
+    }
+}
+
+fn decompress_doc(doc: StableDoc) -> Result<Doc, String> {
+    Ok(Doc {
+        owner: doc.owner,
+        data: decompress_data(doc.data, &doc.compression)?,
+        description: doc.description,
+        created_at: doc.created_at,
+        updated_at: doc.updated_at,
+        version: doc.version,
+        publish_at: doc.publish_at,
+        unpublish_at: doc.unpublish_at,
+        moderation: doc.moderation,
+        attachments: doc.attachments,
+    })
+}
+
+// The documents are iterated lazily, therefore a corrupted document cannot be reported as an error.
+fn read_doc(doc: StableDoc) -> Doc {
+    decompress_doc(doc).unwrap_or_else(|e| trap(&e))
+}
+
 fn stable_key(collection: &CollectionKey, key: &Key) -> StableKey {
     StableKey {
         collection: collection.clone(),
@@ -1,27 +1,43 @@
+use crate::changes::state::get_db_version;
 use crate::controllers::store::get_controllers;
-use crate::db::assert::{assert_delete_doc, assert_set_doc};
+use crate::db::aggregates::update_aggregates;
+use crate::db::assert::{assert_db_config, assert_delete_doc, assert_set_doc};
+use crate::db::attachments::delete_attachments;
+use crate::db::constants::{DEL_DOCS_BATCH_SIZE, DOC_CHUNK_SIZE};
+use crate::db::msg::{ERROR_DOC_CHUNK_OUT_OF_RANGE, ERROR_PATCH_DOC_NOT_FOUND};
+use crate::db::patch::apply_doc_patch;
 use crate::db::state::{
     count_docs_heap, count_docs_stable, delete_collection as delete_state_collection,
     delete_doc as delete_state_doc, get_config, get_doc as get_state_doc, get_docs_heap,
     get_docs_stable, get_rule as get_state_rule, init_collection as init_state_collection,
     insert_config, insert_doc as insert_state_doc,
-    is_collection_empty as is_state_collection_empty,
+    is_collection_empty as is_state_collection_empty, iter_docs_heap_after, iter_docs_stable_after,
 };
 use crate::db::types::config::DbConfig;
-use crate::db::types::interface::{DelDoc, SetDoc};
+use crate::db::types::interface::{DelDoc, DocChunk, PatchDoc, SetDoc};
 use crate::db::types::state::{Doc, DocContext, DocUpsert};
 use crate::db::utils::filter_values;
+use crate::db::views::update_views;
+use crate::gates::assert::assert_gated_permission;
+use crate::gc::store::defer_delete_docs;
+use crate::maintenance::assert::assert_collection_not_locked;
 use crate::memory::STATE;
+use crate::moderation::store::doc_moderation_on_write;
+use crate::retention::state::collect_db_expired_keys;
+use crate::storage::certified_assets::runtime::init_certified_assets;
 use crate::types::store::StoreContext;
 use candid::Principal;
-use junobuild_collections::assert_stores::assert_permission;
+use ic_cdk::api::time;
 use junobuild_collections::msg::msg_db_collection_not_empty;
 use junobuild_collections::types::core::CollectionKey;
-use junobuild_collections::types::rules::{Memory, Rule};
-use junobuild_shared::list::list_values;
+use junobuild_collections::types::rules::{Memory, Permission, Rule};
+use junobuild_shared::list::{
+    filter_published, limit_returned_bytes, limit_scanned_items, list_values_with_collation,
+    with_scanned_items,
+};
 use junobuild_shared::types::core::Key;
-use junobuild_shared::types::list::{ListParams, ListResults};
-use junobuild_shared::types::state::{Controllers, UserId};
+use junobuild_shared::types::list::{ListLimits, ListParams, ListResults};
+use junobuild_shared::types::state::{Controllers, Timestamp, UserId};
 
 // ---------------------------------------------------------
 // Collection
@@ -93,6 +109,107 @@ pub fn get_doc_store(
     secure_get_doc(&context, key)
 }
 
+/// Get a document of a collection exposed over HTTP.
+///
+/// Only the collections listed in the `http_collections` of the configuration and readable publicly are exposed. The
+/// document is read as an anonymous caller would, i.e. documents that are not published or not approved are ignored.
+///
+/// # Returns
+/// - `Ok(Some(Doc))`: The document is exposed.
+/// - `Ok(None)`: The collection or the document is not exposed or the document does not exist.
+/// - `Err(String)`: The collection does not exist.
+pub fn get_http_doc_store(collection: CollectionKey, key: Key) -> Result<Option<Doc>, String> {
+    let exposed = get_config()
+        .and_then(|config| config.http_collections)
+        .is_some_and(|collections| collections.contains(&collection));
+
+    if !exposed {
+        return Ok(None);
+    }
+
+    let rule = get_state_rule(&collection)?;
+
+    if rule.read != Permission::Public {
+        return Ok(None);
+    }
+
+    let controllers: Controllers = get_controllers();
+
+    let context = StoreContext {
+        caller: Principal::anonymous(),
+        controllers: &controllers,
+        collection: &collection,
+    };
+
+    get_doc_impl(&context, key, &rule)
+}
+
+/// Get a document of a collection read over HTTP with an API key whose scope covers the collection.
+///
+/// The collection does not have to be exposed nor readable publicly, except the private collections whose documents
+/// remain readable by their owners only. As for the exposed collections, the documents that are not published are
+/// ignored.
+///
+/// # Returns
+/// - `Ok(Some(Doc))`: The document can be read with the key.
+/// - `Ok(None)`: The collection is private or the document does not exist.
+/// - `Err(String)`: The collection does not exist.
+pub fn get_api_key_doc_store(collection: CollectionKey, key: Key) -> Result<Option<Doc>, String> {
+    let rule = get_state_rule(&collection)?;
+
+    if rule.read == Permission::Private {
+        return Ok(None);
+    }
+
+    let controllers: Controllers = get_controllers();
+
+    let doc = get_state_doc(&collection, &key, &rule)?;
+
+    Ok(doc.filter(|doc| {
+        filter_published(doc, doc.owner, Principal::anonymous(), &controllers, time())
+    }))
+}
+
+/// Get a part of the data of a document.
+///
+/// The data is stored whole - there is no chunked storage nor upload of the documents - and split in chunks of
+/// `DOC_CHUNK_SIZE` bytes when read. The permissions are the same as for `get_doc_store`.
+///
+/// # Returns
+/// - `Ok(Some(DocChunk))`: The part of the data at the index.
+/// - `Ok(None)`: The document does not exist or the caller cannot read it.
+/// - `Err(String)`: The collection does not exist or the index is out of range.
+pub fn get_doc_chunk_store(
+    caller: UserId,
+    collection: CollectionKey,
+    key: Key,
+    index: u64,
+) -> Result<Option<DocChunk>, String> {
+    let doc = get_doc_store(caller, collection, key)?;
+
+    match doc {
+        None => Ok(None),
+        Some(doc) => {
+            // An empty document is made of a single empty chunk
+            let chunks_length = doc.data.len().div_ceil(DOC_CHUNK_SIZE).max(1);
+
+            if index >= chunks_length as u64 {
+                return Err(ERROR_DOC_CHUNK_OUT_OF_RANGE.to_string());
+            }
+
+            let start = index as usize * DOC_CHUNK_SIZE;
+            let end = (start + DOC_CHUNK_SIZE).min(doc.data.len());
+
+            Ok(Some(DocChunk {
+                content: doc.data[start..end].to_vec(),
+                chunks_length: chunks_length as u64,
+                data_length: doc.data.len() as u64,
+                version: doc.version,
+            }))
+        }
+    }
+}
+
 fn secure_get_doc(context: &StoreContext, key: Key) -> Result<Option<Doc>, String> {
     let rule = get_state_rule(context.collection)?;
     get_doc_impl(context, key, &rule)
@@ -104,7 +221,22 @@ fn get_doc_impl(context: &StoreContext, key: Key, rule: &Rule) -> Result<Option<
     match value {
         None => Ok(None),
         Some(value) => {
-            if !assert_permission(&rule.read, value.owner, context.caller, context.controllers) {
+            if !assert_gated_permission(
+                &rule.read,
+                value.owner,
+                context.caller,
+                context.controllers,
+            ) {
+                return Ok(None);
+            }
+
+            if !filter_published(
+                &value,
+                value.owner,
+                context.caller,
+                context.controllers,
+                time(),
+            ) {
                 return Ok(None);
             }
 
@@ -180,16 +312,87 @@ fn set_doc_impl(
 
     assert_set_doc(context, config, &key, &value, rule, &current_doc)?;
 
-    let doc: Doc = Doc::prepare(context.caller, &current_doc, value);
+    let doc: Doc = Doc {
+        moderation: doc_moderation_on_write(
+            context.caller,
+            context.controllers,
+            context.collection,
+        ),
+        ..Doc::prepare(context.caller, &current_doc, value)
+    };
 
     let (_evicted_doc, after) = insert_state_doc(context.collection, &key, &doc, rule)?;
 
+    update_aggregates(context.collection, &current_doc, &Some(after.clone()))?;
+
+    update_views(context.collection, &key)?;
+
     Ok(DocUpsert {
         before: current_doc,
         after,
     })
 }
 
+/// Patch the data of a document in a collection's store.
+///
+/// The document is read with the permissions of the caller, patched and written back with the same assertions as
+/// `set_doc_store`. The other fields of the document - description, schedule and attachments - are kept.
+///
+/// # Returns
+/// - `Ok(DocContext<DocUpsert>)`: Indicates successful update of the document.
+/// - `Err(String)`: An error message if the document does not exist, cannot be patched or written.
+pub fn patch_doc_store(
+    caller: UserId,
+    collection: CollectionKey,
+    key: Key,
+    PatchDoc { patch, version }: PatchDoc,
+) -> Result<DocContext<DocUpsert>, String> {
+    let current_doc = get_doc_store(caller, collection.clone(), key.clone())?
+        .ok_or(ERROR_PATCH_DOC_NOT_FOUND.to_string())?;
+
+    let data = apply_doc_patch(&current_doc.data, &patch)?;
+
+    let value = SetDoc {
+        data,
+        description: current_doc.description,
+        version: version.or(current_doc.version),
+        publish_at: current_doc.publish_at,
+        unpublish_at: current_doc.unpublish_at,
+        attachments: current_doc.attachments,
+    };
+
+    set_doc_store(caller, collection, key, value)
+}
+
+/// Inserts a document as it is - i.e. with its owner, timestamps and version - without asserting the write. Used to
+/// import the documents exported from another Satellite.
+pub fn import_doc_store(
+    collection: &CollectionKey,
+    key: &Key,
+    doc: &Doc,
+) -> Result<DocContext<DocUpsert>, String> {
+    assert_collection_not_locked(collection)?;
+
+    let rule = get_state_rule(collection)?;
+
+    let current_doc = get_state_doc(collection, key, &rule)?;
+
+    let (_evicted_doc, after) = insert_state_doc(collection, key, doc, &rule)?;
+
+    update_aggregates(collection, &current_doc, &Some(after.clone()))?;
+
+    update_views(collection, key)?;
+
+    Ok(DocContext {
+        key: key.clone(),
+        collection: collection.clone(),
+        data: DocUpsert {
+            before: current_doc,
+            after,
+        },
+    })
+}
+
 // ---------------------------------------------------------
 // List
 // ---------------------------------------------------------
@@ -217,7 +420,10 @@ pub fn list_docs_store(
 ) -> Result<ListResults<Doc>, String> {
     let controllers: Controllers = get_controllers();
 
+    let version = get_db_version(&collection);
+
     secure_get_docs(caller, &controllers, collection, filter)
+        .map(|results| ListResults { version, ..results })
 }
 
 /// Count documents in a collection.
@@ -256,32 +462,78 @@ fn secure_get_docs(
 ) -> Result<ListResults<Doc>, String> {
     let rule = get_state_rule(&collection)?;
 
+    let limits = get_config().and_then(|config| config.list_limits);
+
+    let collation = rule.collation.clone().unwrap_or_default();
+
     match rule.mem() {
         Memory::Heap => STATE.with(|state| {
             let state_ref = state.borrow();
-            let docs = get_docs_heap(&collection, &state_ref.heap.db.db)?;
-            get_docs_impl(&docs, caller, controllers, filter, &rule)
+            let scanned = limit_scanned_items(
+                |start_after| iter_docs_heap_after(&collection, start_after, &state_ref.heap.db.db),
+                |(key, _)| (*key).clone(),
+                filter,
+                &collation,
+                &limits,
+            )?;
+            get_docs_impl(
+                &scanned.items,
+                &scanned.filters,
+                scanned.resume_after.clone(),
+                caller,
+                controllers,
+                &rule,
+                &limits,
+            )
         }),
         Memory::Stable => STATE.with(|state| {
-            let stable = get_docs_stable(&collection, &state.borrow().stable.db)?;
-            let docs: Vec<(&Key, &Doc)> = stable.iter().map(|(key, doc)| (&key.key, doc)).collect();
-            get_docs_impl(&docs, caller, controllers, filter, &rule)
+            let stable = &state.borrow().stable.db;
+            let scanned = limit_scanned_items(
+                |start_after| Ok(iter_docs_stable_after(&collection, start_after, stable)),
+                |(key, _)| key.key.clone(),
+                filter,
+                &collation,
+                &limits,
+            )?;
+            let docs: Vec<(&Key, &Doc)> = scanned
+                .items
+                .iter()
+                .map(|(key, doc)| (&key.key, doc))
+                .collect();
+            get_docs_impl(
+                &docs,
+                &scanned.filters,
+                scanned.resume_after.clone(),
+                caller,
+                controllers,
+                &rule,
+                &limits,
+            )
         }),
     }
 }
 
 fn get_docs_impl<'a>(
     docs: &[(&'a Key, &'a Doc)],
+    filters: &ListParams,
+    resume_after: Option<Key>,
     caller: Principal,
     controllers: &Controllers,
-    filters: &ListParams,
     rule: &Rule,
+    limits: &Option<ListLimits>,
 ) -> Result<ListResults<Doc>, String> {
     let matches = filter_values(caller, controllers, &rule.read, docs, filters);
 
-    let results = list_values(&matches, filters);
+    let results = with_scanned_items(
+        list_values_with_collation(
+            &matches,
+            filters,
+            &rule.collation.clone().unwrap_or_default(),
+        ),
+        resume_after,
+    );
 
-    Ok(results)
+    Ok(limit_returned_bytes(results, limits))
 }
 
 // ---------------------------------------------------------
@@ -351,7 +603,15 @@ fn delete_doc_impl(
 
     assert_delete_doc(context, &key, &value, rule, &current_doc)?;
 
-    delete_state_doc(context.collection, &key, rule)
+    let deleted_doc = delete_state_doc(context.collection, &key, rule)?;
+
+    update_aggregates(context.collection, &deleted_doc, &None)?;
+
+    update_views(context.collection, &key)?;
+
+    delete_attachments(context.collection, &deleted_doc);
+
+    Ok(deleted_doc)
 }
 
 /// Delete multiple documents from a collection's store.
@@ -368,21 +628,147 @@ fn delete_doc_impl(
 /// - `Err(String)`: An error message if the deletion operation fails.
 ///
 /// This function allows you to securely delete multiple documents from a Juno collection's store.
+///
+/// Only the first documents are deleted within the call, the others are queued and deleted in the background by the
+/// garbage collection - those remain readable until then.
 pub fn delete_docs_store(collection: &CollectionKey) -> Result<(), String> {
+    assert_collection_not_locked(collection)?;
+
+    let rule = get_state_rule(collection)?;
+
+    let docs = collect_docs_timestamps(collection, &rule)?;
+
+    let (batch, pending) = docs.split_at(docs.len().min(DEL_DOCS_BATCH_SIZE));
+
+    let keys: Vec<Key> = batch.iter().map(|(key, _)| key.clone()).collect();
+
+    delete_docs_impl(&keys, collection, &rule)?;
+
+    defer_delete_docs(collection, pending, &None);
+
+    Ok(())
+}
+
+/// Collects the keys of all the documents of a collection - i.e. the documents that would be removed by
+/// `delete_docs_store`.
+pub fn collect_docs_keys_store(collection: &CollectionKey) -> Result<Vec<Key>, String> {
     let rule = get_state_rule(collection)?;
 
-    let keys = match rule.mem() {
+    collect_docs_keys(collection, &rule)
+}
+
+fn collect_docs_keys(collection: &CollectionKey, rule: &Rule) -> Result<Vec<Key>, String> {
+    let docs = collect_docs_timestamps(collection, rule)?;
+
+    Ok(docs.into_iter().map(|(key, _)| key).collect())
+}
+
+fn collect_docs_timestamps(
+    collection: &CollectionKey,
+    rule: &Rule,
+) -> Result<Vec<(Key, Timestamp)>, String> {
+    match rule.mem() {
         Memory::Heap => STATE.with(|state| {
-            get_docs_heap(collection, &state.borrow().heap.db.db)
-                .map(|docs| docs.into_iter().map(|(key, _)| key.clone()).collect())
+            get_docs_heap(collection, &state.borrow().heap.db.db).map(|docs| {
+                docs.into_iter()
+                    .map(|(key, doc)| (key.clone(), doc.updated_at))
+                    .collect()
+            })
         }),
         Memory::Stable => STATE.with(|state| {
-            get_docs_stable(collection, &state.borrow().stable.db)
-                .map(|docs| docs.iter().map(|(key, _)| key.key.clone()).collect())
+            get_docs_stable(collection, &state.borrow().stable.db).map(|docs| {
+                docs.into_iter()
+                    .map(|(key, doc)| (key.key, doc.updated_at))
+                    .collect()
+            })
         }),
-    }?;
+    }
+}
+
+/// Deletes a document queued by a bulk deletion, unless it was written again since its deletion was requested.
+pub fn delete_pending_doc_store(
+    collection: &CollectionKey,
+    key: &Key,
+    updated_at: Timestamp,
+) -> Result<Option<Doc>, String> {
+    let rule = get_state_rule(collection)?;
+
+    let current_doc = get_state_doc(collection, key, &rule)?;
+
+    if current_doc.is_none_or(|doc| doc.updated_at != updated_at) {
+        return Ok(None);
+    }
+
+    let deleted_doc = delete_state_doc(collection, key, &rule)?;
+
+    update_aggregates(collection, &deleted_doc, &None)?;
+
+    update_views(collection, key)?;
+
+    delete_attachments(collection, &deleted_doc);
+
+    Ok(deleted_doc)
+}
+
+/// Collects the keys of the documents of a collection that exceed the retention policy of its rule, the oldest first.
+pub fn collect_expired_docs_keys_store(
+    collection: &CollectionKey,
+    now: Timestamp,
+) -> Result<Vec<Key>, String> {
+    let rule = get_state_rule(collection)?;
+
+    Ok(collect_expired_docs_keys(
+        collection,
+        &rule,
+        now,
+        usize::MAX,
+    ))
+}
+
+/// The documents are read from the retention index of the collection, therefore only the expired ones are iterated.
+fn collect_expired_docs_keys(
+    collection: &CollectionKey,
+    rule: &Rule,
+    now: Timestamp,
+    limit: usize,
+) -> Vec<Key> {
+    let Some(retention) = &rule.retention else {
+        return Vec::new();
+    };
 
-    delete_docs_impl(&keys, collection, &rule)
+    collect_db_expired_keys(collection, retention, now, limit)
+}
+
+/// Deletes - at most `limit` - the documents of a collection that exceed the retention policy of its rule, without
+/// assertions given that the purge is performed by the satellite itself.
+pub fn delete_expired_docs_store(
+    collection: &CollectionKey,
+    now: Timestamp,
+    limit: usize,
+) -> Result<Vec<DocContext<Option<Doc>>>, String> {
+    let rule = get_state_rule(collection)?;
+
+    let keys = collect_expired_docs_keys(collection, &rule, now, limit);
+
+    let mut results: Vec<DocContext<Option<Doc>>> = Vec::new();
+
+    for key in keys {
+        let deleted_doc = delete_state_doc(collection, &key, &rule)?;
+
+        update_aggregates(collection, &deleted_doc, &None)?;
+
+        update_views(collection, &key)?;
+
+        delete_attachments(collection, &deleted_doc);
+
+        results.push(DocContext {
+            key,
+            collection: collection.clone(),
+            data: deleted_doc,
+        });
+    }
+
+    Ok(results)
 }
 
 fn delete_docs_impl(
@@ -391,7 +777,13 @@ fn delete_docs_impl(
     rule: &Rule,
 ) -> Result<(), String> {
     for key in keys {
-        delete_state_doc(collection, key, rule)?;
+        let deleted_doc = delete_state_doc(collection, key, rule)?;
+
+        update_aggregates(collection, &deleted_doc, &None)?;
+
+        update_views(collection, key)?;
+
+        delete_attachments(collection, &deleted_doc);
     }
 
     Ok(())
@@ -448,6 +840,9 @@ pub fn count_collection_docs_store(collection: &CollectionKey) -> Result<usize,
 ///
 /// This function enables batch deletion of documents in a Juno collection's store that match the given
 /// filter criteria, providing context information for each deleted document or error messages.
+///
+/// All the matching documents are asserted within the call but only the first ones are deleted and returned. The
+/// others are deleted in the background by the garbage collection, which emits their deletion once done.
 pub fn delete_filtered_docs_store(
     caller: Principal,
     collection: CollectionKey,
@@ -472,9 +867,13 @@ fn delete_filtered_docs_store_impl(
 ) -> Result<Vec<DocContext<Option<Doc>>>, String> {
     let rule = get_state_rule(context.collection)?;
 
+    let (batch, pending) = docs
+        .items
+        .split_at(docs.items.len().min(DEL_DOCS_BATCH_SIZE));
+
     let mut results: Vec<DocContext<Option<Doc>>> = Vec::new();
 
-    for (key, doc) in &docs.items {
+    for (key, doc) in batch {
         let value = DelDoc {
             version: doc.version,
         };
@@ -490,6 +889,22 @@ fn delete_filtered_docs_store_impl(
         results.push(doc_context);
     }
 
+    // The other documents are asserted within the call as well, only their deletion is deferred.
+    for (key, doc) in pending {
+        let value = DelDoc {
+            version: doc.version,
+        };
+
+        assert_delete_doc(context, key, &value, &rule, &Some(doc.clone()))?;
+    }
+
+    let pending: Vec<(Key, Timestamp)> = pending
+        .iter()
+        .map(|(key, doc)| (key.clone(), doc.updated_at))
+        .collect();
+
+    defer_delete_docs(context.collection, &pending, &Some(context.caller));
+
     Ok(results)
 }
 
@@ -497,8 +912,19 @@ fn delete_filtered_docs_store_impl(
 // Config
 // ---------------------------------------------------------
 
-pub fn set_config_store(config: &DbConfig) {
+pub fn set_config_store(config: &DbConfig) -> Result<(), String> {
+    assert_db_config(config)?;
+
+    let http_collections = get_config().and_then(|current| current.http_collections);
+
     insert_config(config);
+
+    // The documents served over HTTP are certified for the exposed collections only
+    if http_collections != config.http_collections {
+        init_certified_assets();
+    }
+
+    Ok(())
 }
 
 pub fn get_config_store() -> Option<DbConfig> {
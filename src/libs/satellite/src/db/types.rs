@@ -4,18 +4,19 @@ pub mod state {
     use candid::CandidType;
     use ic_stable_structures::StableBTreeMap;
     use junobuild_collections::types::core::CollectionKey;
-    use junobuild_collections::types::rules::Rules;
+    use junobuild_collections::types::rules::{Compression, Rules};
     use junobuild_shared::rate::types::RateTokenStore;
     use junobuild_shared::types::core::{Blob, Key};
     use junobuild_shared::types::memory::Memory;
-    use junobuild_shared::types::state::{Timestamp, UserId, Version};
+    use junobuild_shared::types::state::{ModerationStatus, Timestamp, UserId, Version};
+    use junobuild_storage::types::state::FullPath;
     use serde::{Deserialize, Serialize};
     use std::collections::{BTreeMap, HashMap};
 
     pub type Collection = BTreeMap<Key, Doc>;
     pub type DbHeap = HashMap<CollectionKey, Collection>;
 
-    pub type DbStable = StableBTreeMap<StableKey, Doc, Memory>;
+    pub type DbStable = StableBTreeMap<StableKey, StableDoc, Memory>;
 
     #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct StableKey {
@@ -44,6 +45,10 @@ pub mod state {
     /// - `created_at`: A `u64` timestamp for the document's creation.
     /// - `updated_at`: A `u64` timestamp for the document's last update.
     /// - `version`: A `u64` number for the document's version. The field is optional for backwards compatibility but, will be populated to 1 on the first create or update.
+    /// - `publish_at`: An optional `u64` timestamp from which the document is published.
+    /// - `unpublish_at`: An optional `u64` timestamp from which the document is no longer published.
+    /// - `moderation`: An optional `ModerationStatus` for documents of collections that require a review. Until approved, only its owner and the controllers can read it.
+    /// - `attachments`: An optional list of the `FullPath` of the assets attached to the document.
     ///
     /// This struct is used to store and manage documents within a collection's store.
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -54,6 +59,27 @@ pub mod state {
         pub created_at: Timestamp,
         pub updated_at: Timestamp,
         pub version: Option<Version>,
+        pub publish_at: Option<Timestamp>,
+        pub unpublish_at: Option<Timestamp>,
+        pub moderation: Option<ModerationStatus>,
+        pub attachments: Option<Vec<FullPath>>,
+    }
+
+    /// A document as it is written to stable memory, with the codec of its data if it was compressed. The documents
+    /// written before the compression was introduced have none.
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct StableDoc {
+        pub owner: UserId,
+        pub data: Blob,
+        pub description: Option<String>,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+        pub version: Option<Version>,
+        pub publish_at: Option<Timestamp>,
+        pub unpublish_at: Option<Timestamp>,
+        pub moderation: Option<ModerationStatus>,
+        pub attachments: Option<Vec<FullPath>>,
+        pub compression: Option<Compression>,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -84,21 +110,78 @@ pub mod state {
 
 pub mod config {
     use candid::{CandidType, Deserialize};
+    use junobuild_collections::types::core::CollectionKey;
     use junobuild_shared::types::config::ConfigMaxMemorySize;
+    use junobuild_shared::types::list::ListLimits;
     use serde::Serialize;
 
     pub type DbConfigMaxMemorySize = ConfigMaxMemorySize;
 
+    /// The documents of the collections listed in `http_collections` - if their rule is publicly readable - are
+    /// served as JSON, or Candid, over HTTP at `/_juno/db/{collection}/{key}`.
+    ///
+    /// The previous states of the documents of the collections listed in `history_collections` are kept for a limited
+    /// period, so that the controllers can read a document as it was at a past point in time.
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
     pub struct DbConfig {
         pub max_memory_size: Option<DbConfigMaxMemorySize>,
+        pub aggregates: Option<Vec<DbConfigAggregate>>,
+        pub proof_of_work: Option<Vec<DbConfigProofOfWork>>,
+        pub list_limits: Option<ListLimits>,
+        pub http_collections: Option<Vec<CollectionKey>>,
+        pub attachments: Option<Vec<DbConfigAttachment>>,
+        pub history_collections: Option<Vec<CollectionKey>>,
+        pub views: Option<Vec<DbConfigView>>,
+    }
+
+    /// An aggregate maintained by the satellite each time a document of `collection` is set or deleted.
+    /// The key of the parent is read from the field `parent_field` of the JSON data of the child - e.g. a comment with `{ "post_id": "abc" }` counts toward the aggregate "abc".
+    /// The aggregate document - `{ "count": 1, "sums": { "likes": 3 } }` - is written with the parent key in `target_collection`.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct DbConfigAggregate {
+        pub collection: CollectionKey,
+        pub parent_field: String,
+        pub target_collection: CollectionKey,
+        pub sum_fields: Option<Vec<String>>,
+    }
+
+    /// A proof of work required for the writes of anonymous callers in `collection` - e.g. to accept the entries of a
+    /// public feedback form without an authentication while making spamming costly.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct DbConfigProofOfWork {
+        pub collection: CollectionKey,
+        pub difficulty: u8,
+    }
+
+    /// The documents of `collection` can reference assets of `storage_collection` as attachments. The assets must
+    /// exist - and belong to the caller if not a controller - when the document is set. With `cascade_delete`, the
+    /// attached assets of the owner of the document are deleted together with the document.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct DbConfigAttachment {
+        pub collection: CollectionKey,
+        pub storage_collection: CollectionKey,
+        pub cascade_delete: Option<bool>,
+    }
+
+    /// A read-only view maintained by the satellite each time a document of one of the `sources` is set or deleted.
+    /// The documents of `collection` join, by key, the `fields` of the JSON data of the documents of the sources - e.g.
+    /// a `public_posts` view over a private `posts` collection exposing only the title and the summary.
+    ///
+    /// The view is listed like any collection according to its own rule. The documents that existed before the view
+    /// was configured are projected the next time they are modified.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct DbConfigView {
+        pub collection: CollectionKey,
+        pub sources: Vec<CollectionKey>,
+        pub fields: Vec<String>,
     }
 }
 
 pub mod interface {
     use candid::CandidType;
     use junobuild_shared::types::core::Blob;
-    use junobuild_shared::types::state::Version;
+    use junobuild_shared::types::state::{Timestamp, Version};
+    use junobuild_storage::types::state::FullPath;
     use serde::{Deserialize, Serialize};
 
     /// Parameters for setting a document.
@@ -109,6 +192,9 @@ pub mod interface {
     /// - `description`: An optional `String` providing additional description for the document. This field is optional.
     /// - `version`: An optional `u64` version representing the last version of the document to ensure
     ///   update consistency. This field is optional - i.e. first time a document is saved, it can be left empty but following updates require the current version to be passed.
+    /// - `publish_at`: An optional `u64` timestamp to schedule the publication of the document. Until then, only its owner and the controllers can read it.
+    /// - `unpublish_at`: An optional `u64` timestamp to schedule the end of the publication of the document. From then, only its owner and the controllers can read it.
+    /// - `attachments`: An optional list of the `FullPath` of the assets to attach to the document. Only accepted for the collections configured with attachments.
     ///
     /// `SetDoc` is used to provide parameters for setting or updating a document in the collection's store.
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
@@ -116,6 +202,9 @@ pub mod interface {
         pub data: Blob,
         pub description: Option<String>,
         pub version: Option<Version>,
+        pub publish_at: Option<Timestamp>,
+        pub unpublish_at: Option<Timestamp>,
+        pub attachments: Option<Vec<FullPath>>,
     }
 
     /// Parameters for deleting a document.
@@ -130,4 +219,36 @@ pub mod interface {
     pub struct DelDoc {
         pub version: Option<Version>,
     }
+
+    /// A part of the data of a document.
+    ///
+    /// Large documents can be read in parts to not exceed the response size limit of `get_doc` - e.g. documents grown
+    /// with `patch_doc` or written by the serverless functions. The documents are only split when read, their data is
+    /// still written whole within a single call. The total number of chunks and the version are provided with each
+    /// part, so a client can detect that the document changed while it was reading it.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct DocChunk {
+        pub content: Blob,
+        pub chunks_length: u64,
+        pub data_length: u64,
+        pub version: Option<Version>,
+    }
+
+    /// Parameters for patching the data of a document.
+    ///
+    /// The patch is applied to the current data of the document, which must be encoded in JSON. Without `version`,
+    /// the patch is applied to the latest version, so concurrent partial updates of distinct fields do not overwrite
+    /// each other. With a `version`, the patch is rejected if the document was updated in the meantime.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct PatchDoc {
+        pub patch: DocPatch,
+        pub version: Option<Version>,
+    }
+
+    /// A JSON Patch (RFC 6902) - i.e. a list of operations - or a JSON Merge Patch (RFC 7386), encoded in JSON.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum DocPatch {
+        JsonPatch(String),
+        MergePatch(String),
+    }
 }
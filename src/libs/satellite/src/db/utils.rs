@@ -1,8 +1,11 @@
 use crate::db::types::state::Doc;
+use crate::gates::assert::assert_gated_permission;
 use candid::Principal;
-use junobuild_collections::assert_stores::assert_permission;
+use ic_cdk::api::time;
 use junobuild_collections::types::rules::Permission;
-use junobuild_shared::list::{filter_timestamps, matcher_regex};
+use junobuild_shared::list::{
+    filter_expression, filter_published, filter_timestamps, matcher_regex,
+};
 use junobuild_shared::types::core::Key;
 use junobuild_shared::types::list::ListParams;
 use junobuild_shared::types::state::{Controllers, UserId};
@@ -22,13 +25,17 @@ pub fn filter_values<'a>(
 ) -> Vec<(&'a Key, &'a Doc)> {
     let (regex_key, regex_description) = matcher_regex(matcher);
 
+    let now = time();
+
     col.iter()
         .filter_map(|(key, doc)| {
             if filter_key_matcher(&regex_key, key)
                 && filter_description_matcher(&regex_description, &doc.description)
                 && filter_owner(owner, &doc.owner)
                 && filter_timestamps(matcher, *doc)
-                && assert_permission(rule, doc.owner, caller, controllers)
+                && filter_expression(matcher, key, &doc.description, &doc.owner, *doc)
+                && assert_gated_permission(rule, doc.owner, caller, controllers)
+                && filter_published(*doc, doc.owner, caller, controllers, now)
             {
                 Some((*key, *doc))
             } else {
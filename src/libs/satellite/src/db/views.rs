@@ -0,0 +1,147 @@
+use crate::db::aggregates::update_aggregates;
+use crate::db::msg::ERROR_VIEW_READ_ONLY;
+use crate::db::state::{
+    delete_doc as delete_state_doc, get_config, get_doc as get_state_doc,
+    get_rule as get_state_rule, insert_doc as insert_state_doc,
+};
+use crate::db::types::config::{DbConfig, DbConfigView};
+use crate::db::types::interface::SetDoc;
+use crate::db::types::state::{Doc, DocContext, DocUpsert};
+use crate::events::store::emit;
+use crate::events::types::interface::SatelliteEvent;
+use ic_cdk::id;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::Timestamp;
+use serde_json::{from_slice, to_vec, Map, Value};
+
+/// The documents of a view are written by the satellite only.
+pub fn assert_not_view(
+    config: &Option<DbConfig>,
+    collection: &CollectionKey,
+) -> Result<(), String> {
+    let is_view = config
+        .as_ref()
+        .and_then(|config| config.views.as_ref())
+        .is_some_and(|views| views.iter().any(|view| view.collection == *collection));
+
+    if is_view {
+        return Err(ERROR_VIEW_READ_ONLY.to_string());
+    }
+
+    Ok(())
+}
+
+/// Recomputes the documents of the views sourced by a collection each time one of its documents is set or deleted.
+pub fn update_views(collection: &CollectionKey, key: &Key) -> Result<(), String> {
+    let views = match get_config().and_then(|config| config.views) {
+        None => {
+            return Ok(());
+        }
+        Some(views) => views,
+    };
+
+    for view in views
+        .iter()
+        .filter(|view| view.sources.contains(collection))
+    {
+        update_view(view, key)?;
+    }
+
+    Ok(())
+}
+
+/// The document of a view joins, by key, the projected fields of the documents of its sources - the first source
+/// that provides a field wins. It is only published while all the documents it is made of are published.
+///
+/// Like any document, its changes update the aggregates of the view and are emitted as events of the satellite.
+fn update_view(view: &DbConfigView, key: &Key) -> Result<(), String> {
+    let mut data: Map<String, Value> = Map::new();
+    let mut publish_at: Option<Timestamp> = None;
+    let mut unpublish_at: Option<Timestamp> = None;
+    let mut found = false;
+
+    for source in &view.sources {
+        let rule = get_state_rule(source)?;
+
+        let Some(doc) = get_state_doc(source, key, &rule)? else {
+            continue;
+        };
+
+        found = true;
+
+        publish_at = publish_at.max(doc.publish_at);
+        unpublish_at = match (unpublish_at, doc.unpublish_at) {
+            (Some(current), Some(unpublish)) => Some(current.min(unpublish)),
+            (current, unpublish) => current.or(unpublish),
+        };
+
+        // Data that is not a JSON object does not provide any field to the view.
+        let Ok(Value::Object(source_data)) = from_slice::<Value>(&doc.data) else {
+            continue;
+        };
+
+        for field in &view.fields {
+            if data.contains_key(field) {
+                continue;
+            }
+
+            if let Some(value) = source_data.get(field) {
+                data.insert(field.clone(), value.clone());
+            }
+        }
+    }
+
+    let rule = get_state_rule(&view.collection)?;
+
+    let current_doc = get_state_doc(&view.collection, key, &rule)?;
+
+    if !found {
+        if current_doc.is_some() {
+            let deleted_doc = delete_state_doc(&view.collection, key, &rule)?;
+
+            update_aggregates(&view.collection, &deleted_doc, &None)?;
+
+            emit(
+                &id(),
+                SatelliteEvent::DeleteDoc(DocContext {
+                    key: key.clone(),
+                    collection: view.collection.clone(),
+                    data: deleted_doc,
+                }),
+            );
+        }
+
+        return Ok(());
+    }
+
+    let value = SetDoc {
+        data: to_vec(&data).map_err(|e| e.to_string())?,
+        description: None,
+        version: None,
+        publish_at,
+        unpublish_at,
+        attachments: None,
+    };
+
+    // Views are owned by the satellite and written without the permissions of the caller.
+    let doc: Doc = Doc::prepare(id(), &current_doc, value);
+
+    let (_evicted_doc, after) = insert_state_doc(&view.collection, key, &doc, &rule)?;
+
+    update_aggregates(&view.collection, &current_doc, &Some(after.clone()))?;
+
+    emit(
+        &id(),
+        SatelliteEvent::SetDoc(DocContext {
+            key: key.clone(),
+            collection: view.collection.clone(),
+            data: DocUpsert {
+                before: current_doc,
+                after,
+            },
+        }),
+    );
+
+    Ok(())
+}
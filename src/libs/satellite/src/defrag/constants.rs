@@ -0,0 +1,3 @@
+// The number of content chunks scanned in each timer. A chunk weighs up to ~2 MB, therefore the batch is kept small so
+// that the instructions of a single message remain bounded.
+pub const COMPACTION_CHUNKS_PER_STEP: usize = 20;
@@ -0,0 +1,16 @@
+use crate::defrag::types::state::RegionUsage;
+
+impl RegionUsage {
+    /// Replaces the length of an entry - `None` if the entry did not exist before or does not exist anymore.
+    pub fn update(&mut self, before: Option<u64>, after: Option<u64>) {
+        if let Some(before) = before {
+            self.entries = self.entries.saturating_sub(1);
+            self.used = self.used.saturating_sub(before);
+        }
+
+        if let Some(after) = after {
+            self.entries += 1;
+            self.used += after;
+        }
+    }
+}
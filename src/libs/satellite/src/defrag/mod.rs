@@ -0,0 +1,7 @@
+mod constants;
+mod impls;
+mod msg;
+pub mod state;
+pub mod stats;
+pub mod store;
+pub mod types;
@@ -0,0 +1 @@
+pub const ERROR_COMPACTION_RUNNING: &str = "A compaction of the content chunks is already running.";
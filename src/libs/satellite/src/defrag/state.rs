@@ -0,0 +1,78 @@
+use crate::defrag::types::interface::CompactionReport;
+use crate::defrag::types::state::DefragHeapState;
+use crate::memory::STATE;
+use crate::storage::types::state::StableEncodingChunkKey;
+use junobuild_shared::types::state::Timestamp;
+
+pub fn get_report() -> Option<CompactionReport> {
+    STATE.with(|state| state.borrow().runtime.defrag.report.clone())
+}
+
+pub fn is_compaction_running() -> bool {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .runtime
+            .defrag
+            .report
+            .as_ref()
+            .is_some_and(|report| report.completed_at.is_none())
+    })
+}
+
+pub fn init_report(report: CompactionReport) {
+    STATE.with(|state| {
+        let defrag = &mut state.borrow_mut().runtime.defrag;
+
+        defrag.report = Some(report);
+        defrag.cursor = None;
+    })
+}
+
+pub fn get_cursor() -> Option<StableEncodingChunkKey> {
+    STATE.with(|state| state.borrow().runtime.defrag.cursor.clone())
+}
+
+pub fn insert_results(
+    cursor: StableEncodingChunkKey,
+    scanned_chunks: u64,
+    removed_chunks: u64,
+    reclaimed_bytes: u64,
+) {
+    STATE.with(|state| {
+        let defrag = &mut state.borrow_mut().runtime.defrag;
+
+        defrag.cursor = Some(cursor);
+
+        if let Some(report) = &mut defrag.report {
+            report.scanned_chunks += scanned_chunks;
+            report.removed_chunks += removed_chunks;
+            report.reclaimed_bytes += reclaimed_bytes;
+        }
+    })
+}
+
+pub fn complete_report(completed_at: Timestamp) {
+    STATE.with(|state| {
+        let defrag = &mut state.borrow_mut().runtime.defrag;
+
+        defrag.cursor = None;
+
+        if let Some(report) = &mut defrag.report {
+            report.completed_at = Some(completed_at);
+        }
+    })
+}
+
+pub fn is_usage_initialized() -> bool {
+    STATE.with(|state| state.borrow().heap.defrag.is_some())
+}
+
+pub fn insert_usage(usage: DefragHeapState) {
+    STATE.with(|state| state.borrow_mut().heap.defrag = Some(usage))
+}
+
+/// The usage maintained by the writes in the stable memory, which receive it alongside the region they modify.
+pub fn usage_mut(defrag: &mut Option<DefragHeapState>) -> &mut DefragHeapState {
+    defrag.get_or_insert_with(DefragHeapState::default)
+}
@@ -0,0 +1,108 @@
+use crate::defrag::types::interface::{MemoryRegion, MemoryRegionStats};
+use crate::defrag::types::state::{DefragHeapState, RegionUsage};
+use crate::memory::{get_memory_region, STATE};
+use crate::types::state::StableState;
+use ic_cdk::api::stable::WASM_PAGE_SIZE_IN_BYTES;
+use ic_stable_structures::{Memory as _, Storable};
+use junobuild_storage::types::store::Asset;
+use std::mem;
+
+pub fn collect_region_stats() -> Vec<MemoryRegionStats> {
+    let (usage, content_chunks) = STATE.with(|state| {
+        let state = state.borrow();
+
+        (
+            state.heap.defrag.clone().unwrap_or_default(),
+            state.stable.content_chunks.len(),
+        )
+    });
+
+    [
+        (MemoryRegion::Upgrades, upgrades_usage()),
+        (MemoryRegion::Db, usage.db),
+        (MemoryRegion::Assets, usage.assets),
+        (
+            MemoryRegion::ContentChunks,
+            RegionUsage {
+                entries: content_chunks,
+                used: usage.content_chunks,
+            },
+        ),
+        (MemoryRegion::Audit, usage.audit),
+        (MemoryRegion::Revisions, usage.revisions),
+    ]
+    .into_iter()
+    .map(|(region, usage)| region_stats(region, usage))
+    .collect()
+}
+
+fn region_stats(
+    region: MemoryRegion,
+    RegionUsage { entries, used }: RegionUsage,
+) -> MemoryRegionStats {
+    let allocated = get_memory_region(&region).size() * WASM_PAGE_SIZE_IN_BYTES;
+
+    MemoryRegionStats {
+        region,
+        entries,
+        allocated,
+        used,
+        fragmented: allocated.saturating_sub(used),
+    }
+}
+
+/// The bytes of an entry, as serialized in its region.
+pub fn entry_length<K: Storable, V: Storable>(key: &K, value: &V) -> u64 {
+    (key.to_bytes().len() + value.to_bytes().len()) as u64
+}
+
+/// Walks the regions once to count their usage - i.e. when a satellite that did not maintain it yet is upgraded.
+pub fn count_region_usage(stable: &StableState) -> DefragHeapState {
+    DefragHeapState {
+        db: entries_usage(stable.db.iter()),
+        assets: entries_usage(stable.assets.iter()),
+        content_chunks: stable
+            .assets
+            .iter()
+            .map(|(_, asset)| encodings_length(&asset))
+            .sum(),
+        audit: entries_usage(stable.audit.iter()),
+        revisions: entries_usage(stable.revisions.iter()),
+    }
+}
+
+// The chunks are not iterated given their size. The used bytes are those referenced by the encodings of the stable
+// assets, the chunks that are not referenced anymore are therefore accounted as fragmented.
+pub fn encodings_length(asset: &Asset) -> u64 {
+    asset
+        .encodings
+        .values()
+        .map(|encoding| encoding.total_length)
+        .sum::<u128>() as u64
+}
+
+fn entries_usage<K: Storable, V: Storable>(entries: impl Iterator<Item = (K, V)>) -> RegionUsage {
+    let mut usage = RegionUsage::default();
+
+    for (key, value) in entries {
+        usage.update(None, Some(entry_length(&key, &value)));
+    }
+
+    usage
+}
+
+// The heap is serialized on upgrade, prefixed with its length. See write_pre_upgrade.
+fn upgrades_usage() -> RegionUsage {
+    let memory = get_memory_region(&MemoryRegion::Upgrades);
+
+    if memory.size() == 0 {
+        return RegionUsage::default();
+    }
+
+    let mut length_bytes = [0; mem::size_of::<u32>()];
+    memory.read(0, &mut length_bytes);
+
+    let used = mem::size_of::<u32>() as u64 + u32::from_le_bytes(length_bytes) as u64;
+
+    RegionUsage { entries: 1, used }
+}
@@ -0,0 +1,147 @@
+use crate::defrag::constants::COMPACTION_CHUNKS_PER_STEP;
+use crate::defrag::msg::ERROR_COMPACTION_RUNNING;
+use crate::defrag::state::{
+    complete_report, get_cursor, get_report, init_report, insert_results, insert_usage,
+    is_compaction_running, is_usage_initialized,
+};
+use crate::defrag::stats::{collect_region_stats, count_region_usage};
+use crate::defrag::types::interface::{CompactionReport, MemoryRegionStats};
+use crate::deployment::constants::PREVIOUS_CHUNKS_PREFIX;
+use crate::memory::STATE;
+use crate::storage::types::state::{StableEncodingChunkKey, StableKey};
+use crate::types::state::State;
+use ic_cdk::api::time;
+use ic_cdk_timers::set_timer;
+use junobuild_shared::serializers::deserialize_from_bytes;
+use junobuild_storage::types::store::Asset;
+use std::borrow::Cow;
+use std::ops::Bound;
+use std::time::Duration;
+
+pub fn get_memory_stats_store() -> Vec<MemoryRegionStats> {
+    collect_region_stats()
+}
+
+pub fn get_compaction_report_store() -> Option<CompactionReport> {
+    get_report()
+}
+
+/// Counts the usage of the regions once, when a satellite that did not maintain it yet is upgraded. The usage is then
+/// updated incrementally.
+pub fn init_region_usage_store() {
+    if is_usage_initialized() {
+        return;
+    }
+
+    let usage = STATE.with(|state| count_region_usage(&state.borrow().stable));
+
+    insert_usage(usage);
+}
+
+/// Starts the compaction of the content chunks. The chunks are scanned in batches, each in its own timer, and those
+/// that are not referenced by an encoding anymore - e.g. left by an asset uploaded again with fewer chunks - are
+/// removed. The space is reused by the next uploads, the region itself does not shrink.
+pub fn compact_content_chunks_store() -> Result<(), String> {
+    if is_compaction_running() {
+        return Err(ERROR_COMPACTION_RUNNING.to_string());
+    }
+
+    init_report(CompactionReport {
+        started_at: time(),
+        completed_at: None,
+        scanned_chunks: 0,
+        removed_chunks: 0,
+        reclaimed_bytes: 0,
+    });
+
+    set_timer(Duration::ZERO, run_next_step);
+
+    Ok(())
+}
+
+fn run_next_step() {
+    let cursor = get_cursor();
+
+    // The chunks are checked and removed within the same message, so that an asset uploaded meanwhile is never
+    // considered.
+    let results = STATE.with(|state| compact_chunks(&cursor, &mut state.borrow_mut()));
+
+    match results {
+        None => complete_report(time()),
+        Some((last_key, scanned_chunks, removed_chunks, reclaimed_bytes)) => {
+            insert_results(last_key, scanned_chunks, removed_chunks, reclaimed_bytes);
+
+            set_timer(Duration::ZERO, run_next_step);
+        }
+    }
+}
+
+fn compact_chunks(
+    cursor: &Option<StableEncodingChunkKey>,
+    state: &mut State,
+) -> Option<(StableEncodingChunkKey, u64, u64, u64)> {
+    let start = cursor.clone().map_or(Bound::Unbounded, Bound::Excluded);
+
+    let batch: Vec<(StableEncodingChunkKey, u64)> = state
+        .stable
+        .content_chunks
+        .range((start, Bound::Unbounded))
+        .take(COMPACTION_CHUNKS_PER_STEP)
+        .map(|(key, chunk)| (key, chunk.len() as u64))
+        .collect();
+
+    let last_key = batch.last()?.0.clone();
+
+    let orphans: Vec<(StableEncodingChunkKey, u64)> = batch
+        .iter()
+        .filter(|(key, _)| !is_content_chunk_referenced(key, state))
+        .cloned()
+        .collect();
+
+    for (key, _) in orphans.iter() {
+        state.stable.content_chunks.remove(key);
+    }
+
+    let reclaimed_bytes: u64 = orphans.iter().map(|(_, length)| length).sum();
+
+    Some((
+        last_key,
+        batch.len() as u64,
+        orphans.len() as u64,
+        reclaimed_bytes,
+    ))
+}
+
+// A chunk key does not contain the collection, therefore the asset is searched in each collection of the storage. The
+// chunks copied for the previous assets of a canary deployment are referenced by those.
+pub fn is_content_chunk_referenced(key: &StableEncodingChunkKey, state: &State) -> bool {
+    if let Some(full_path) = key.full_path.strip_prefix(PREVIOUS_CHUNKS_PREFIX) {
+        let previous_asset = state
+            .heap
+            .canary
+            .as_ref()
+            .and_then(|canary| canary.previous_assets.get(full_path).cloned())
+            .flatten();
+
+        return is_encoding_chunk(previous_asset, key);
+    }
+
+    state.heap.storage.rules.keys().any(|collection| {
+        let asset = state.stable.assets.get(&StableKey {
+            collection: collection.clone(),
+            full_path: key.full_path.clone(),
+        });
+
+        is_encoding_chunk(asset, key)
+    })
+}
+
+fn is_encoding_chunk(asset: Option<Asset>, key: &StableEncodingChunkKey) -> bool {
+    asset
+        .and_then(|asset| asset.encodings.get(&key.encoding_type).cloned())
+        .and_then(|encoding| encoding.content_chunks.get(key.chunk_index).cloned())
+        .is_some_and(|chunk| {
+            let referenced: StableEncodingChunkKey = deserialize_from_bytes(Cow::Owned(chunk));
+            referenced == *key
+        })
+}
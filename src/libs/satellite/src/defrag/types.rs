@@ -0,0 +1,81 @@
+pub mod state {
+    use crate::defrag::types::interface::CompactionReport;
+    use crate::storage::types::state::StableEncodingChunkKey;
+    use candid::CandidType;
+    use serde::{Deserialize, Serialize};
+
+    /// The usage of the regions of the stable memory, maintained each time an entry is inserted, replaced or removed,
+    /// so that the statistics do not iterate the regions.
+    ///
+    /// - `content_chunks`: The bytes of the encodings of the stable assets. The chunks themselves are counted by their map.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct DefragHeapState {
+        pub db: RegionUsage,
+        pub assets: RegionUsage,
+        pub content_chunks: u64,
+        pub audit: RegionUsage,
+        pub revisions: RegionUsage,
+    }
+
+    /// - `used`: The bytes of the keys and values of the entries, as serialized in the region.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct RegionUsage {
+        pub entries: u64,
+        pub used: u64,
+    }
+
+    /// - `cursor`: The last content chunk scanned. The next step resumes the scan after it.
+    #[derive(Default, Clone)]
+    pub struct DefragRuntimeState {
+        pub report: Option<CompactionReport>,
+        pub cursor: Option<StableEncodingChunkKey>,
+    }
+}
+
+pub mod interface {
+    use candid::CandidType;
+    use junobuild_shared::types::state::Timestamp;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum MemoryRegion {
+        Upgrades,
+        Db,
+        Assets,
+        ContentChunks,
+        Audit,
+        Revisions,
+    }
+
+    /// The statistics of a region of the stable memory, in bytes.
+    ///
+    /// - `allocated`: The pages grown by the region. A region never shrinks, the space freed by a deletion is reused by
+    ///   the next insertions.
+    /// - `used`: The bytes of the entries - for the content chunks, the length of the encodings of the stable assets.
+    ///   The upgrades region holds the heap serialized on the last upgrade.
+    /// - `fragmented`: The allocated bytes that are not used - i.e. the free space and the overhead of the structure,
+    ///   as well as the chunks left by the replaced assets until these are compacted and by the deleted assets until
+    ///   these are collected.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct MemoryRegionStats {
+        pub region: MemoryRegion,
+        pub entries: u64,
+        pub allocated: u64,
+        pub used: u64,
+        pub fragmented: u64,
+    }
+
+    /// The outcome of the last compaction of the content chunks.
+    ///
+    /// - `completed_at`: `None` while the compaction is still running.
+    /// - `removed_chunks`: The chunks that were not referenced by any encoding of an asset anymore.
+    /// - `reclaimed_bytes`: The length of the removed chunks, now available to the next uploads.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct CompactionReport {
+        pub started_at: Timestamp,
+        pub completed_at: Option<Timestamp>,
+        pub scanned_chunks: u64,
+        pub removed_chunks: u64,
+        pub reclaimed_bytes: u64,
+    }
+}
@@ -0,0 +1,10 @@
+use crate::deployment::msg::ERROR_CANARY_DEPLOYMENT_INVALID_PERCENTAGE;
+use crate::deployment::types::interface::SetCanaryDeployment;
+
+pub fn assert_set_canary_deployment(deployment: &SetCanaryDeployment) -> Result<(), String> {
+    if deployment.percentage > 100 {
+        return Err(ERROR_CANARY_DEPLOYMENT_INVALID_PERCENTAGE.to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,2 @@
+// Full paths start with a slash, therefore no asset can write chunks with this prefix
+pub const PREVIOUS_CHUNKS_PREFIX: &str = "#canary";
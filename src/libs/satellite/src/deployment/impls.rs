@@ -0,0 +1,17 @@
+use crate::deployment::types::interface::CanaryDeploymentStatus;
+use crate::deployment::types::state::CanaryDeployment;
+
+impl From<&CanaryDeployment> for CanaryDeploymentStatus {
+    fn from(canary: &CanaryDeployment) -> Self {
+        let mut paths: Vec<_> = canary.previous_assets.keys().cloned().collect();
+        paths.sort();
+
+        CanaryDeploymentStatus {
+            percentage: canary.percentage,
+            session: canary.session.clone(),
+            paths,
+            created_at: canary.created_at,
+            updated_at: canary.updated_at,
+        }
+    }
+}
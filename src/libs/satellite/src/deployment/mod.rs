@@ -0,0 +1,9 @@
+mod assert;
+pub mod constants;
+mod impls;
+mod msg;
+pub mod routing;
+mod state;
+pub mod store;
+pub mod strategy_impls;
+pub mod types;
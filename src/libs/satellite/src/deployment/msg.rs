@@ -0,0 +1,3 @@
+pub const ERROR_CANARY_DEPLOYMENT_INVALID_PERCENTAGE: &str =
+    "Canary deployment percentage must be between 0 and 100.";
+pub const ERROR_NO_CANARY_DEPLOYMENT: &str = "No canary deployment in progress.";
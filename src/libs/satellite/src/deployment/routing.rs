@@ -0,0 +1,63 @@
+use crate::deployment::state::{get_canary_deployment, get_previous_asset};
+use crate::deployment::types::state::CanaryDeployment;
+use junobuild_storage::http::types::{HeaderField, StreamingCallbackToken};
+use junobuild_storage::http::utils::{get_cookie_value, get_header_value};
+use junobuild_storage::types::config::StorageConfigRewriteSession;
+use sha2::{Digest, Sha256};
+
+const PREVIEW_HEADER: &str = "X-Juno-Canary";
+const PREVIEW_COOKIE: &str = "juno_canary";
+
+/// Whether a request should be served the assets as they were before the canary deployment started.
+///
+/// Requests carrying the preview header or cookie are always served the new assets. Other visitors are bucketed with their
+/// session identifier - 0 to 99 - and served the new assets if their bucket is below the percentage of the deployment.
+pub fn is_previous_deployment_request(req_headers: &[HeaderField]) -> bool {
+    match get_canary_deployment() {
+        None => false,
+        Some(canary) => !is_canary_request(&canary, req_headers),
+    }
+}
+
+/// The streaming callback carries no request headers. A token is resolved against the previous assets if its checksum matches
+/// the one of the previous version of the asset.
+pub fn is_previous_deployment_token(
+    StreamingCallbackToken {
+        full_path,
+        sha256,
+        encoding_type,
+        ..
+    }: &StreamingCallbackToken,
+) -> bool {
+    let previous_asset = get_previous_asset(full_path).flatten();
+
+    match (previous_asset, sha256) {
+        (Some(asset), Some(sha256)) => asset
+            .encodings
+            .get(encoding_type)
+            .is_some_and(|encoding| encoding.sha256.as_slice() == sha256.as_slice()),
+        _ => false,
+    }
+}
+
+fn is_canary_request(canary: &CanaryDeployment, req_headers: &[HeaderField]) -> bool {
+    if get_header_value(req_headers, PREVIEW_HEADER).is_some()
+        || get_cookie_value(req_headers, PREVIEW_COOKIE).is_some()
+    {
+        return true;
+    }
+
+    let session_id = match &canary.session {
+        None => None,
+        Some(StorageConfigRewriteSession::Cookie(name)) => get_cookie_value(req_headers, name),
+        Some(StorageConfigRewriteSession::Header(name)) => get_header_value(req_headers, name),
+    };
+
+    session_id.is_some_and(|session_id| bucket(&session_id) < u32::from(canary.percentage))
+}
+
+fn bucket(session_id: &str) -> u32 {
+    let hash = Sha256::digest(session_id.as_bytes());
+
+    u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]) % 100
+}
@@ -0,0 +1,51 @@
+use crate::deployment::types::state::CanaryDeployment;
+use crate::memory::STATE;
+use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::store::Asset;
+
+pub fn get_canary_deployment() -> Option<CanaryDeployment> {
+    STATE.with(|state| state.borrow().heap.canary.clone())
+}
+
+pub fn insert_canary_deployment(deployment: &CanaryDeployment) {
+    STATE.with(|state| state.borrow_mut().heap.canary = Some(deployment.clone()))
+}
+
+pub fn delete_canary_deployment() -> Option<CanaryDeployment> {
+    STATE.with(|state| state.borrow_mut().heap.canary.take())
+}
+
+/// Returns `None` if the path was not overwritten by the deployment, `Some(None)` if it was introduced by the deployment.
+pub fn get_previous_asset(full_path: &FullPath) -> Option<Option<Asset>> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .canary
+            .as_ref()
+            .and_then(|canary| canary.previous_assets.get(full_path).cloned())
+    })
+}
+
+pub fn insert_previous_asset(full_path: &FullPath, asset: &Option<Asset>) {
+    STATE.with(|state| {
+        insert_previous_asset_impl(full_path, asset, &mut state.borrow_mut().heap.canary)
+    })
+}
+
+fn insert_previous_asset_impl(
+    full_path: &FullPath,
+    asset: &Option<Asset>,
+    state: &mut Option<CanaryDeployment>,
+) {
+    if let Some(canary) = state {
+        canary
+            .previous_assets
+            .entry(full_path.clone())
+            .or_insert_with(|| asset.clone());
+    }
+}
+
+pub fn is_canary_deployment_active() -> bool {
+    STATE.with(|state| state.borrow().heap.canary.is_some())
+}
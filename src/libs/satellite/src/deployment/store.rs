@@ -0,0 +1,114 @@
+use crate::deployment::assert::assert_set_canary_deployment;
+use crate::deployment::constants::PREVIOUS_CHUNKS_PREFIX;
+use crate::deployment::msg::ERROR_NO_CANARY_DEPLOYMENT;
+use crate::deployment::state::{
+    delete_canary_deployment, get_canary_deployment, get_previous_asset, insert_canary_deployment,
+    insert_previous_asset, is_canary_deployment_active,
+};
+use crate::deployment::types::interface::{CanaryDeploymentStatus, SetCanaryDeployment};
+use crate::deployment::types::state::CanaryDeployment;
+use crate::gc::store::defer_delete_content_chunks;
+use crate::storage::certified_assets::runtime::init_certified_assets as init_runtime_certified_assets;
+use crate::storage::state::{get_asset, get_rule, snapshot_content_chunks_stable};
+use crate::storage::types::state::StableEncodingChunkKey;
+use ic_cdk::api::time;
+use junobuild_collections::constants::ASSET_COLLECTION_KEY;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{Memory, Rule};
+use junobuild_storage::runtime::update_certified_asset as update_runtime_certified_asset;
+use junobuild_storage::types::config::StorageConfig;
+use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::store::Asset;
+
+/// Starts a canary deployment or - if one is already in progress - updates its percentage and session while keeping the
+/// assets that were live before it started.
+pub fn set_canary_deployment_store(
+    deployment: &SetCanaryDeployment,
+) -> Result<CanaryDeploymentStatus, String> {
+    assert_set_canary_deployment(deployment)?;
+
+    let current_deployment = get_canary_deployment();
+
+    let now = time();
+
+    let canary = CanaryDeployment {
+        percentage: deployment.percentage,
+        session: deployment.session.clone(),
+        previous_assets: current_deployment
+            .as_ref()
+            .map(|current| current.previous_assets.clone())
+            .unwrap_or_default(),
+        created_at: current_deployment
+            .as_ref()
+            .map(|current| current.created_at)
+            .unwrap_or(now),
+        updated_at: now,
+    };
+
+    insert_canary_deployment(&canary);
+
+    Ok(CanaryDeploymentStatus::from(&canary))
+}
+
+pub fn get_canary_deployment_store() -> Option<CanaryDeploymentStatus> {
+    get_canary_deployment().map(|canary| CanaryDeploymentStatus::from(&canary))
+}
+
+/// Rolls out the assets committed during the canary deployment to everyone.
+///
+/// The previous assets are dropped - their copied content chunks are removed in the background - and the certification is
+/// initialized again to remove their hashes from the tree.
+pub fn finalize_deployment_store() -> Result<(), String> {
+    let canary = delete_canary_deployment().ok_or(ERROR_NO_CANARY_DEPLOYMENT.to_string())?;
+
+    for asset in canary.previous_assets.values().flatten() {
+        if is_stable_asset(asset) {
+            defer_delete_content_chunks(asset);
+        }
+    }
+
+    init_runtime_certified_assets();
+
+    Ok(())
+}
+
+/// Keeps a copy of an asset of the #dapp collection before it gets overwritten or deleted while a canary deployment is in
+/// progress.
+///
+/// Only the first version - i.e. the one live before the deployment started - is kept. The content chunks of a stable asset
+/// are copied, given that the new version is written under the same keys.
+pub fn capture_previous_asset(collection: &CollectionKey, full_path: &FullPath, rule: &Rule) {
+    if collection != ASSET_COLLECTION_KEY
+        || !is_canary_deployment_active()
+        || get_previous_asset(full_path).is_some()
+    {
+        return;
+    }
+
+    let asset = get_asset(collection, full_path, rule).map(|asset| match rule.mem() {
+        Memory::Heap => asset,
+        Memory::Stable => snapshot_content_chunks_stable(&asset, previous_encoding_chunk_key),
+    });
+
+    insert_previous_asset(full_path, &asset);
+}
+
+/// Certifies again the previous version of a path once its asset is deleted during a canary deployment, given that removing
+/// the asset from the tree removes all the responses of the path.
+pub fn recertify_previous_asset(full_path: &FullPath, config: &StorageConfig) {
+    if let Some(Some(asset)) = get_previous_asset(full_path) {
+        update_runtime_certified_asset(&asset, config);
+    }
+}
+
+/// The memory of the previous assets is the one of their collection.
+pub fn is_stable_asset(asset: &Asset) -> bool {
+    get_rule(&asset.key.collection).is_ok_and(|rule| matches!(rule.mem(), Memory::Stable))
+}
+
+fn previous_encoding_chunk_key(key: &StableEncodingChunkKey) -> StableEncodingChunkKey {
+    StableEncodingChunkKey {
+        full_path: format!("{}{}", PREVIOUS_CHUNKS_PREFIX, key.full_path),
+        ..key.clone()
+    }
+}
@@ -0,0 +1,89 @@
+use crate::deployment::state::get_previous_asset;
+use crate::deployment::store::is_stable_asset;
+use crate::storage::store::filter_public_asset;
+use crate::storage::strategy_impls::StorageState;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{Memory, Rule};
+use junobuild_shared::types::core::Blob;
+use junobuild_shared::types::domain::CustomDomains;
+use junobuild_storage::strategies::StorageStateStrategy;
+use junobuild_storage::types::config::StorageConfig;
+use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::store::{Asset, AssetEncoding};
+
+/// Serves the assets as they were before the canary deployment started. The paths not overwritten by the deployment are
+/// resolved as usual.
+pub struct PreviousDeploymentStorageState;
+
+impl StorageStateStrategy for PreviousDeploymentStorageState {
+    fn get_content_chunks(
+        &self,
+        encoding: &AssetEncoding,
+        chunk_index: usize,
+        memory: &Memory,
+    ) -> Option<Blob> {
+        StorageState.get_content_chunks(encoding, chunk_index, memory)
+    }
+
+    fn get_public_asset(
+        &self,
+        full_path: FullPath,
+        token: Option<String>,
+    ) -> Option<(Asset, Memory)> {
+        match get_previous_asset(&full_path) {
+            Some(previous_asset) => {
+                let memory = match &previous_asset {
+                    Some(asset) if is_stable_asset(asset) => Memory::Stable,
+                    _ => Memory::Heap,
+                };
+
+                filter_public_asset(previous_asset, memory, token)
+            }
+            None => StorageState.get_public_asset(full_path, token),
+        }
+    }
+
+    fn get_rule(&self, collection: &CollectionKey) -> Result<Rule, String> {
+        StorageState.get_rule(collection)
+    }
+
+    fn get_config(&self) -> StorageConfig {
+        StorageState.get_config()
+    }
+
+    fn get_domains(&self) -> CustomDomains {
+        StorageState.get_domains()
+    }
+
+    fn get_random_seed(&self) -> Option<[u8; 32]> {
+        StorageState.get_random_seed()
+    }
+
+    fn get_asset(
+        &self,
+        collection: &CollectionKey,
+        full_path: &FullPath,
+        rule: &Rule,
+    ) -> Option<Asset> {
+        StorageState.get_asset(collection, full_path, rule)
+    }
+
+    fn insert_asset(
+        &self,
+        collection: &CollectionKey,
+        full_path: &FullPath,
+        asset: &Asset,
+        rule: &Rule,
+    ) {
+        StorageState.insert_asset(collection, full_path, asset, rule)
+    }
+
+    fn delete_asset(
+        &self,
+        collection: &CollectionKey,
+        full_path: &FullPath,
+        rule: &Rule,
+    ) -> Option<Asset> {
+        StorageState.delete_asset(collection, full_path, rule)
+    }
+}
@@ -0,0 +1,52 @@
+pub mod state {
+    use candid::CandidType;
+    use junobuild_shared::types::state::Timestamp;
+    use junobuild_storage::types::config::StorageConfigRewriteSession;
+    use junobuild_storage::types::state::FullPath;
+    use junobuild_storage::types::store::Asset;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    pub type PreviousAssets = HashMap<FullPath, Option<Asset>>;
+
+    /// A progressive deployment of the assets of the #dapp collection.
+    ///
+    /// - `percentage`: The share of the visitors - 0 to 100 - served with the assets committed since the deployment started.
+    /// - `session`: The cookie or header identifying a visitor. The bucket of a visitor is derived from it, so that a visitor keeps seeing the same version. Requests without the identifier are served the previous assets.
+    /// - `previous_assets`: The assets as they were before being overwritten by the deployment - `None` for the paths introduced by the deployment.
+    /// - `created_at` and `updated_at`: The timestamps of the deployment.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct CanaryDeployment {
+        pub percentage: u8,
+        pub session: Option<StorageConfigRewriteSession>,
+        pub previous_assets: PreviousAssets,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+    }
+}
+
+pub mod interface {
+    use candid::CandidType;
+    use junobuild_shared::types::state::Timestamp;
+    use junobuild_storage::types::config::StorageConfigRewriteSession;
+    use junobuild_storage::types::state::FullPath;
+    use serde::Deserialize;
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct SetCanaryDeployment {
+        pub percentage: u8,
+        pub session: Option<StorageConfigRewriteSession>,
+    }
+
+    /// The state of a canary deployment without the content of the previous assets.
+    ///
+    /// - `paths`: The paths of the #dapp collection committed since the deployment started.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct CanaryDeploymentStatus {
+        pub percentage: u8,
+        pub session: Option<StorageConfigRewriteSession>,
+        pub paths: Vec<FullPath>,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+    }
+}
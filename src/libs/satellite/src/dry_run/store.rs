@@ -0,0 +1,131 @@
+use crate::db::store::{
+    collect_docs_keys_store, collect_expired_docs_keys_store, count_collection_docs_store,
+    list_docs_store,
+};
+use crate::dry_run::types::interface::{DryRunOperation, DryRunReport};
+use crate::memory::STATE;
+use crate::storage::store::{
+    assert_assets_collection_empty_store, collect_delete_assets_store,
+    collect_expired_assets_store, list_assets_store,
+};
+use crate::types::interface::RulesType;
+use candid::Principal;
+use ic_cdk::api::time;
+use junobuild_collections::msg::msg_db_collection_not_empty;
+use junobuild_collections::store::{del_rule, set_rule};
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::interface::{DelRule, SetRule};
+use junobuild_collections::types::rules::Rules;
+use junobuild_shared::types::core::Key;
+
+/// Evaluates a destructive operation and reports what it would affect.
+///
+/// The state is only read - the rules are for example set or deleted on a copy - therefore the report can be
+/// collected in a query.
+pub fn dry_run_store(caller: Principal, operation: &DryRunOperation) -> DryRunReport {
+    match operation {
+        DryRunOperation::DelDocs { collection } => keys_report(collect_docs_keys_store(collection)),
+        DryRunOperation::DelFilteredDocs { collection, filter } => keys_report(
+            list_docs_store(caller, collection.clone(), filter)
+                .map(|docs| docs.items.into_iter().map(|(key, _)| key).collect()),
+        ),
+        DryRunOperation::DelAssets { collection } => {
+            keys_report(collect_delete_assets_store(collection))
+        }
+        DryRunOperation::DelFilteredAssets { collection, filter } => {
+            keys_report(list_assets_store(caller, collection, filter).map(|assets| {
+                assets
+                    .items
+                    .into_iter()
+                    .map(|(_, asset)| asset.key.full_path)
+                    .collect()
+            }))
+        }
+        DryRunOperation::SetRule {
+            rules_type,
+            collection,
+            rule,
+        } => dry_run_set_rule(rules_type, collection, rule),
+        DryRunOperation::DelRule {
+            rules_type,
+            collection,
+            rule,
+        } => dry_run_del_rule(rules_type, collection, rule),
+        DryRunOperation::PurgeRetention {
+            rules_type,
+            collection,
+        } => keys_report(match rules_type {
+            RulesType::Db => collect_expired_docs_keys_store(collection, time()),
+            RulesType::Storage => collect_expired_assets_store(collection, time()),
+        }),
+    }
+}
+
+fn keys_report(keys: Result<Vec<Key>, String>) -> DryRunReport {
+    match keys {
+        Ok(keys) => DryRunReport {
+            keys,
+            ..DryRunReport::default()
+        },
+        Err(error) => DryRunReport {
+            error: Some(error),
+            ..DryRunReport::default()
+        },
+    }
+}
+
+fn dry_run_set_rule(
+    rules_type: &RulesType,
+    collection: &CollectionKey,
+    rule: &SetRule,
+) -> DryRunReport {
+    let mut rules = get_rules_copy(rules_type);
+
+    let storage_checks = matches!(rules_type, RulesType::Storage);
+
+    match set_rule(collection.clone(), rule.clone(), storage_checks, &mut rules) {
+        Ok(rule) => DryRunReport {
+            rule: Some(rule),
+            ..DryRunReport::default()
+        },
+        Err(error) => DryRunReport {
+            error: Some(error),
+            ..DryRunReport::default()
+        },
+    }
+}
+
+fn dry_run_del_rule(
+    rules_type: &RulesType,
+    collection: &CollectionKey,
+    rule: &DelRule,
+) -> DryRunReport {
+    // Only an empty collection can be removed.
+    let empty = match rules_type {
+        RulesType::Db => count_collection_docs_store(collection).and_then(|count| {
+            if count > 0 {
+                return Err(msg_db_collection_not_empty(collection));
+            }
+
+            Ok(())
+        }),
+        RulesType::Storage => assert_assets_collection_empty_store(collection),
+    };
+
+    let mut rules = get_rules_copy(rules_type);
+
+    match empty.and_then(|_| del_rule(collection.clone(), rule.clone(), &mut rules)) {
+        Ok(_) => DryRunReport::default(),
+        Err(error) => DryRunReport {
+            error: Some(error),
+            ..DryRunReport::default()
+        },
+    }
+}
+
+fn get_rules_copy(rules_type: &RulesType) -> Rules {
+    STATE.with(|state| match rules_type {
+        RulesType::Db => state.borrow().heap.db.rules.clone(),
+        RulesType::Storage => state.borrow().heap.storage.rules.clone(),
+    })
+}
@@ -0,0 +1,58 @@
+pub mod interface {
+    use crate::types::interface::RulesType;
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_collections::types::interface::{DelRule, SetRule};
+    use junobuild_collections::types::rules::Rule;
+    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::list::ListParams;
+    use serde::Deserialize;
+
+    /// The destructive admin operations that can be evaluated without being applied. Each variant takes the same
+    /// parameters as its endpoint.
+    #[derive(CandidType, Deserialize)]
+    pub enum DryRunOperation {
+        DelDocs {
+            collection: CollectionKey,
+        },
+        DelFilteredDocs {
+            collection: CollectionKey,
+            filter: ListParams,
+        },
+        DelAssets {
+            collection: CollectionKey,
+        },
+        DelFilteredAssets {
+            collection: CollectionKey,
+            filter: ListParams,
+        },
+        SetRule {
+            rules_type: RulesType,
+            collection: CollectionKey,
+            rule: SetRule,
+        },
+        DelRule {
+            rules_type: RulesType,
+            collection: CollectionKey,
+            rule: DelRule,
+        },
+        /// The documents or assets that exceed the retention policy of the collection, regardless of the number of
+        /// deletions performed per run by the purge.
+        PurgeRetention {
+            rules_type: RulesType,
+            collection: CollectionKey,
+        },
+    }
+
+    /// What an operation would do if it was applied.
+    ///
+    /// - `keys`: The keys of the documents - or the full paths of the assets - that would be deleted.
+    /// - `rule`: The rule as it would be saved, for a rule change.
+    /// - `error`: The error the operation would fail with. Nothing would be modified in that case.
+    #[derive(CandidType, Deserialize, Default)]
+    pub struct DryRunReport {
+        pub keys: Vec<Key>,
+        pub rule: Option<Rule>,
+        pub error: Option<String>,
+    }
+}
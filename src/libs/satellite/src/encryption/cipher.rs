@@ -0,0 +1,161 @@
+use crate::encryption::constants::{
+    CIPHER_KEY_DOMAIN, ENVELOPE_OVERHEAD, ENVELOPE_VERSION, KEY_LENGTH, NONCE_LENGTH,
+};
+use crate::encryption::msg::{
+    ERROR_ENCRYPTION_DECRYPTION_FAILED, ERROR_ENCRYPTION_INVALID_ENVELOPE,
+};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use hkdf::Hkdf;
+use junobuild_shared::types::core::Blob;
+use sha2::Sha256;
+
+type Key = [u8; KEY_LENGTH];
+
+/// The key used to encrypt the data of an owner, expanded with HKDF-SHA256 from the vetKey derived for that owner.
+pub fn derive_cipher_key(vetkey: &[u8]) -> Key {
+    let mut key = [0u8; KEY_LENGTH];
+
+    Hkdf::<Sha256>::new(None, vetkey)
+        .expand(CIPHER_KEY_DOMAIN.as_bytes(), &mut key)
+        .expect("The length of the key is a valid output length for HKDF.");
+
+    key
+}
+
+/// Encrypts the data with XChaCha20-Poly1305.
+///
+/// The envelope is `version || nonce || ciphertext || tag`. The version is authenticated as associated data and the
+/// nonce must be random - its length of 24 bytes makes a collision negligible.
+pub fn seal(key: &Key, nonce: &[u8; NONCE_LENGTH], data: &[u8]) -> Result<Blob, String> {
+    let header = [ENVELOPE_VERSION];
+
+    let ciphertext = XChaCha20Poly1305::new(key.into())
+        .encrypt(
+            XNonce::from_slice(nonce),
+            Payload {
+                msg: data,
+                aad: &header,
+            },
+        )
+        .map_err(|_| ERROR_ENCRYPTION_INVALID_ENVELOPE.to_string())?;
+
+    let mut envelope: Blob = Vec::with_capacity(ENVELOPE_OVERHEAD + data.len());
+    envelope.extend_from_slice(&header);
+    envelope.extend_from_slice(nonce);
+    envelope.extend_from_slice(&ciphertext);
+
+    Ok(envelope)
+}
+
+pub fn open(key: &Key, envelope: &[u8]) -> Result<Blob, String> {
+    if envelope.len() < ENVELOPE_OVERHEAD || envelope[0] != ENVELOPE_VERSION {
+        return Err(ERROR_ENCRYPTION_INVALID_ENVELOPE.to_string());
+    }
+
+    let (header, content) = envelope.split_at(1);
+    let (nonce, ciphertext) = content.split_at(NONCE_LENGTH);
+
+    XChaCha20Poly1305::new(key.into())
+        .decrypt(
+            XNonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: header,
+            },
+        )
+        .map_err(|_| ERROR_ENCRYPTION_DECRYPTION_FAILED.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::constants::TAG_LENGTH;
+
+    const NONCE: [u8; NONCE_LENGTH] = [7u8; NONCE_LENGTH];
+
+    fn key(vetkey: u8) -> Key {
+        derive_cipher_key(&[vetkey; 48])
+    }
+
+    #[test]
+    fn opens_sealed_data() {
+        let envelope = seal(&key(1), &NONCE, b"hello").unwrap();
+
+        assert_eq!(envelope.len(), ENVELOPE_OVERHEAD + 5);
+        assert_eq!(open(&key(1), &envelope).unwrap(), b"hello".to_vec());
+    }
+
+    #[test]
+    fn opens_empty_data() {
+        let envelope = seal(&key(1), &NONCE, &[]).unwrap();
+
+        assert_eq!(open(&key(1), &envelope).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn derives_distinct_keys() {
+        assert_ne!(key(1), key(2));
+    }
+
+    #[test]
+    fn rejects_another_key() {
+        let envelope = seal(&key(1), &NONCE, b"hello").unwrap();
+
+        assert_eq!(
+            open(&key(2), &envelope),
+            Err(ERROR_ENCRYPTION_DECRYPTION_FAILED.to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_altered_ciphertext() {
+        let mut envelope = seal(&key(1), &NONCE, b"hello").unwrap();
+        envelope[1 + NONCE_LENGTH] ^= 1;
+
+        assert_eq!(
+            open(&key(1), &envelope),
+            Err(ERROR_ENCRYPTION_DECRYPTION_FAILED.to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_altered_nonce() {
+        let mut envelope = seal(&key(1), &NONCE, b"hello").unwrap();
+        envelope[1] ^= 1;
+
+        assert_eq!(
+            open(&key(1), &envelope),
+            Err(ERROR_ENCRYPTION_DECRYPTION_FAILED.to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_version() {
+        let mut envelope = seal(&key(1), &NONCE, b"hello").unwrap();
+        envelope[0] = ENVELOPE_VERSION + 1;
+
+        assert_eq!(
+            open(&key(1), &envelope),
+            Err(ERROR_ENCRYPTION_INVALID_ENVELOPE.to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_envelope() {
+        let envelope = seal(&key(1), &NONCE, b"hello").unwrap();
+
+        assert_eq!(
+            open(&key(1), &envelope[..TAG_LENGTH]),
+            Err(ERROR_ENCRYPTION_INVALID_ENVELOPE.to_string())
+        );
+    }
+
+    #[test]
+    fn seals_distinctly_with_distinct_nonces() {
+        let first = seal(&key(1), &NONCE, b"hello").unwrap();
+        let second = seal(&key(1), &[8u8; NONCE_LENGTH], b"hello").unwrap();
+
+        assert_ne!(first, second);
+    }
+}
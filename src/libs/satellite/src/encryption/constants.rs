@@ -0,0 +1,31 @@
+pub const ENVELOPE_VERSION: u8 = 1;
+
+pub const KEY_LENGTH: usize = 32;
+pub const NONCE_LENGTH: usize = 24;
+pub const TAG_LENGTH: usize = 16;
+
+// Version, nonce and tag - i.e. the length of an envelope that encrypts no data.
+pub const ENVELOPE_OVERHEAD: usize = 1 + NONCE_LENGTH + TAG_LENGTH;
+
+// The threshold key of the IC from which the keys of the owners are derived.
+pub const VETKD_KEY_NAME: &str = "key_1";
+pub const VETKD_CONTEXT: &[u8] = b"juno:encryption";
+
+// The expected maximum of cycles required by the management canister to derive a key. Unused cycles are refunded.
+pub const VETKD_DERIVE_KEY_CYCLES: u128 = 26_153_846_153;
+
+// A transport key that is the identity of G1 makes the management canister return the derived key unencrypted.
+pub const VETKD_IDENTITY_TRANSPORT_KEY: [u8; 48] = {
+    let mut key = [0u8; 48];
+    key[0] = 0xc0;
+    key
+};
+
+// An encrypted key is the concatenation of a G1, a G2 and a G1 points. With the identity as transport key, the last one is the key.
+pub const VETKD_ENCRYPTED_KEY_LENGTH: usize = 192;
+pub const VETKD_KEY_OFFSET: usize = 144;
+
+pub const CIPHER_KEY_DOMAIN: &str = "juno:encryption:cipher";
+
+/// The number of keys of owners kept in memory to avoid deriving them again on each call.
+pub const MAX_CACHED_KEYS: usize = 100;
@@ -0,0 +1,53 @@
+use crate::encryption::cipher::derive_cipher_key;
+use crate::encryption::constants::{
+    KEY_LENGTH, VETKD_CONTEXT, VETKD_DERIVE_KEY_CYCLES, VETKD_ENCRYPTED_KEY_LENGTH,
+    VETKD_IDENTITY_TRANSPORT_KEY, VETKD_KEY_NAME, VETKD_KEY_OFFSET,
+};
+use crate::encryption::msg::ERROR_ENCRYPTION_KEY_DERIVATION_FAILED;
+use crate::encryption::runtime::{get_cached_key, insert_cached_key};
+use crate::encryption::types::interface::{
+    VetKDCurve, VetKDDeriveKeyArgs, VetKDDeriveKeyResult, VetKDKeyId,
+};
+use candid::Principal;
+use ic_cdk::api::call::call_with_payment128;
+use junobuild_shared::types::state::UserId;
+
+/// The key of an owner, derived by the threshold vetKD protocol of the IC for the satellite and the owner. The
+/// satellite holds no secret: the same key is derived again by the subnet whenever it is not in memory.
+///
+/// The key is requested unencrypted - with the identity as transport key - because it is used by the satellite itself,
+/// never handed out to a user.
+pub async fn get_owner_key(owner: &UserId) -> Result<[u8; KEY_LENGTH], String> {
+    if let Some(key) = get_cached_key(owner) {
+        return Ok(key);
+    }
+
+    let args = VetKDDeriveKeyArgs {
+        input: owner.as_slice().to_vec(),
+        context: VETKD_CONTEXT.to_vec(),
+        transport_public_key: VETKD_IDENTITY_TRANSPORT_KEY.to_vec(),
+        key_id: VetKDKeyId {
+            curve: VetKDCurve::Bls12_381G2,
+            name: VETKD_KEY_NAME.to_string(),
+        },
+    };
+
+    let (result,): (VetKDDeriveKeyResult,) = call_with_payment128(
+        Principal::management_canister(),
+        "vetkd_derive_key",
+        (args,),
+        VETKD_DERIVE_KEY_CYCLES,
+    )
+    .await
+    .map_err(|(_, message)| format!("{} ({})", ERROR_ENCRYPTION_KEY_DERIVATION_FAILED, message))?;
+
+    if result.encrypted_key.len() != VETKD_ENCRYPTED_KEY_LENGTH {
+        return Err(ERROR_ENCRYPTION_KEY_DERIVATION_FAILED.to_string());
+    }
+
+    let key = derive_cipher_key(&result.encrypted_key[VETKD_KEY_OFFSET..]);
+
+    insert_cached_key(owner, &key);
+
+    Ok(key)
+}
@@ -0,0 +1,7 @@
+mod cipher;
+mod constants;
+mod keys;
+mod msg;
+mod runtime;
+pub mod store;
+pub mod types;
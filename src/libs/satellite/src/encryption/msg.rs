@@ -0,0 +1,6 @@
+pub const ERROR_ENCRYPTION_KEY_DERIVATION_FAILED: &str = "Encryption key cannot be derived.";
+pub const ERROR_ENCRYPTION_NO_RANDOMNESS: &str = "No randomness available to encrypt the data.";
+pub const ERROR_ENCRYPTION_INVALID_ENVELOPE: &str =
+    "Encrypted data is malformed or its version is not supported.";
+pub const ERROR_ENCRYPTION_DECRYPTION_FAILED: &str =
+    "Encrypted data cannot be decrypted with the key of this owner.";
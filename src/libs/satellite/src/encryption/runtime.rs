@@ -0,0 +1,42 @@
+use crate::encryption::constants::{KEY_LENGTH, MAX_CACHED_KEYS};
+use crate::encryption::types::state::CachedKey;
+use crate::memory::STATE;
+use ic_cdk::api::time;
+use junobuild_shared::types::state::UserId;
+
+pub fn get_cached_key(owner: &UserId) -> Option<[u8; KEY_LENGTH]> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .runtime
+            .encryption
+            .keys
+            .get(owner)
+            .map(|cached| cached.key)
+    })
+}
+
+pub fn insert_cached_key(owner: &UserId, key: &[u8; KEY_LENGTH]) {
+    STATE.with(|state| {
+        let keys = &mut state.borrow_mut().runtime.encryption.keys;
+
+        if keys.len() >= MAX_CACHED_KEYS && !keys.contains_key(owner) {
+            let oldest = keys
+                .iter()
+                .min_by_key(|(_, cached)| cached.cached_at)
+                .map(|(owner, _)| *owner);
+
+            if let Some(oldest) = oldest {
+                keys.remove(&oldest);
+            }
+        }
+
+        keys.insert(
+            *owner,
+            CachedKey {
+                key: *key,
+                cached_at: time(),
+            },
+        );
+    });
+}
@@ -0,0 +1,48 @@
+use crate::encryption::cipher::{open, seal};
+use crate::encryption::constants::NONCE_LENGTH;
+use crate::encryption::keys::get_owner_key;
+use crate::encryption::msg::ERROR_ENCRYPTION_NO_RANDOMNESS;
+use crate::random::random_seed;
+use junobuild_shared::types::core::Blob;
+use junobuild_shared::types::state::UserId;
+
+/// Encrypt data for an owner.
+///
+/// The data is encrypted with XChaCha20-Poly1305 and a random nonce, with a key derived for the owner with vetKD. The
+/// satellite keeps no secret in its state - the keys are derived by the IC and only cached in memory. The returned
+/// envelope can be saved as is - e.g. as a field of a document.
+///
+/// Deriving a key that is not cached costs cycles, the management canister charging each derivation.
+///
+/// # Parameters
+/// - `owner`: The `UserId` of the owner of the data. Only the key of this owner can decrypt it.
+/// - `data`: The data to encrypt.
+///
+/// # Returns
+/// - `Ok(Blob)`: The encrypted envelope.
+/// - `Err(String)`: An error message if the key cannot be derived or the randomness is not yet initialized.
+pub async fn encrypt_blob_store(owner: &UserId, data: &[u8]) -> Result<Blob, String> {
+    let seed = random_seed().ok_or(ERROR_ENCRYPTION_NO_RANDOMNESS.to_string())?;
+
+    let nonce: [u8; NONCE_LENGTH] = seed[..NONCE_LENGTH].try_into().unwrap();
+
+    let key = get_owner_key(owner).await?;
+
+    seal(&key, &nonce, data)
+}
+
+/// Decrypt data previously encrypted for an owner with `encrypt_blob_store`.
+///
+/// # Parameters
+/// - `owner`: The `UserId` of the owner for whom the data was encrypted.
+/// - `envelope`: The encrypted envelope.
+///
+/// # Returns
+/// - `Ok(Blob)`: The decrypted data.
+/// - `Err(String)`: An error message if the key cannot be derived, or if the envelope is malformed, was altered or was
+///   encrypted for another owner.
+pub async fn decrypt_blob_store(owner: &UserId, envelope: &[u8]) -> Result<Blob, String> {
+    let key = get_owner_key(owner).await?;
+
+    open(&key, envelope)
+}
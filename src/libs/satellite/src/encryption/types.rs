@@ -0,0 +1,47 @@
+pub mod state {
+    use crate::encryption::constants::KEY_LENGTH;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use std::collections::HashMap;
+
+    /// The keys of the owners recently derived with vetKD. They live only in memory - they are never saved in the
+    /// state and therefore dropped on upgrade - so that the data cannot be decrypted with a snapshot of the state.
+    #[derive(Default, Clone)]
+    pub struct EncryptionRuntimeState {
+        pub keys: HashMap<UserId, CachedKey>,
+    }
+
+    #[derive(Clone)]
+    pub struct CachedKey {
+        pub key: [u8; KEY_LENGTH],
+        pub cached_at: Timestamp,
+    }
+}
+
+pub mod interface {
+    use candid::{CandidType, Deserialize};
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub enum VetKDCurve {
+        #[serde(rename = "bls12_381_g2")]
+        Bls12_381G2,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct VetKDKeyId {
+        pub curve: VetKDCurve,
+        pub name: String,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct VetKDDeriveKeyArgs {
+        pub input: Vec<u8>,
+        pub context: Vec<u8>,
+        pub transport_public_key: Vec<u8>,
+        pub key_id: VetKDKeyId,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct VetKDDeriveKeyResult {
+        pub encrypted_key: Vec<u8>,
+    }
+}
@@ -0,0 +1,3 @@
+mod runtime;
+pub mod store;
+pub mod types;
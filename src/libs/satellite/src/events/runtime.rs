@@ -0,0 +1,17 @@
+use crate::events::types::interface::EventSubscriber;
+use crate::memory::STATE;
+
+pub fn get_subscribers() -> Vec<EventSubscriber> {
+    STATE.with(|state| state.borrow().runtime.events.subscribers.clone())
+}
+
+pub fn insert_subscriber(subscriber: EventSubscriber) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .runtime
+            .events
+            .subscribers
+            .push(subscriber)
+    })
+}
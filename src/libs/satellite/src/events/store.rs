@@ -0,0 +1,48 @@
+use crate::audit::store::record_audit_event;
+use crate::events::runtime::{get_subscribers, insert_subscriber};
+use crate::events::types::interface::{EventSubscriber, OnEventContext, SatelliteEvent};
+use crate::hooks::invoke_on_event;
+use crate::requests::store::{get_request_id, resume_request};
+use crate::webhooks::store::notify_webhooks;
+use ic_cdk_timers::set_timer;
+use junobuild_shared::types::state::UserId;
+use std::time::Duration;
+
+/// The built-in modules that are notified of each event, in order. The serverless hooks are invoked through this
+/// subscription when their feature is enabled.
+const BUILT_IN_SUBSCRIBERS: [fn(&UserId, &SatelliteEvent); 3] =
+    [record_audit_event, notify_webhooks, invoke_on_event];
+
+/// Notifies the built-in modules and the registered subscribers of a change that has been applied.
+///
+/// The registered subscribers are called asynchronously, like the hooks, therefore a failure of one of them does not
+/// revert the change.
+pub fn emit(caller: &UserId, event: SatelliteEvent) {
+    for subscriber in BUILT_IN_SUBSCRIBERS {
+        subscriber(caller, &event);
+    }
+
+    let request_id = get_request_id();
+
+    for subscriber in get_subscribers() {
+        let context: OnEventContext = OnEventContext {
+            caller: *caller,
+            data: event.clone(),
+            request_id: request_id.clone(),
+        };
+
+        set_timer(Duration::ZERO, move || {
+            let _request = resume_request(&context.request_id);
+
+            subscriber(context)
+        });
+    }
+}
+
+/// Registers a function - e.g. within Serverless Functions - that is called with each event emitted by the satellite.
+///
+/// The subscriptions live in the runtime memory, which is reset when the satellite is upgraded. They should therefore
+/// be registered in both `on_init` and `on_post_upgrade`.
+pub fn subscribe(subscriber: EventSubscriber) {
+    insert_subscriber(subscriber);
+}
@@ -0,0 +1,54 @@
+pub mod state {
+    use crate::events::types::interface::EventSubscriber;
+
+    #[derive(Default, Clone)]
+    pub struct EventsRuntimeState {
+        pub subscribers: Vec<EventSubscriber>,
+    }
+}
+
+pub mod interface {
+    use crate::auth::types::config::AuthenticationConfig;
+    use crate::bundle::types::interface::ConfigBundle;
+    use crate::db::types::config::DbConfig;
+    use crate::db::types::state::{Doc, DocContext, DocUpsert};
+    use crate::types::hooks::HookContext;
+    use crate::types::interface::RulesType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_collections::types::rules::Rule;
+    use junobuild_storage::types::config::StorageConfig;
+    use junobuild_storage::types::store::Asset;
+
+    /// The events emitted by the satellite once a change has been applied - i.e. the datastore, the storage, the
+    /// authentication and the configuration.
+    #[derive(Clone)]
+    pub enum SatelliteEvent {
+        SetDoc(DocContext<DocUpsert>),
+        SetManyDocs(Vec<DocContext<DocUpsert>>),
+        DeleteDoc(DocContext<Option<Doc>>),
+        DeleteManyDocs(Vec<DocContext<Option<Doc>>>),
+        DeleteFilteredDocs(Vec<DocContext<Option<Doc>>>),
+        UploadAsset(Asset),
+        DeleteAsset(Option<Asset>),
+        DeleteManyAssets(Vec<Option<Asset>>),
+        DeleteFilteredAssets(Vec<Option<Asset>>),
+        SetAuthConfig(AuthenticationConfig),
+        SetDbConfig(DbConfig),
+        SetStorageConfig(StorageConfig),
+        ApplyConfigBundle(ConfigBundle),
+        SetRule {
+            rules_type: RulesType,
+            collection: CollectionKey,
+            rule: Rule,
+        },
+        DeleteRule {
+            rules_type: RulesType,
+            collection: CollectionKey,
+        },
+    }
+
+    /// A type alias for the context provided to the subscribers of the events.
+    pub type OnEventContext = HookContext<SatelliteEvent>;
+
+    pub type EventSubscriber = fn(OnEventContext);
+}
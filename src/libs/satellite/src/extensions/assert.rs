@@ -0,0 +1,45 @@
+use crate::extensions::constants::{
+    EXTENSION_MAX_SETTINGS, EXTENSION_MAX_SETTING_KEY_LENGTH, EXTENSION_MAX_SETTING_VALUE_LENGTH,
+};
+use crate::extensions::msg::{
+    ERROR_EXTENSION_SETTING_EMPTY_KEY, ERROR_EXTENSION_SETTING_KEY_TOO_LONG,
+    ERROR_EXTENSION_SETTING_VALUE_TOO_LONG, ERROR_EXTENSION_TOO_MANY_SETTINGS,
+};
+use crate::extensions::types::interface::SetExtensionConfig;
+use crate::extensions::types::state::Extension;
+use junobuild_shared::assert::assert_version;
+
+pub fn assert_set_extension_config(
+    current_extension: &Option<Extension>,
+    user_config: &SetExtensionConfig,
+) -> Result<(), String> {
+    assert_version(
+        user_config.version,
+        current_extension
+            .as_ref()
+            .and_then(|extension| extension.version),
+    )?;
+
+    if user_config.settings.len() > EXTENSION_MAX_SETTINGS {
+        return Err(format!(
+            "{} {} settings.",
+            ERROR_EXTENSION_TOO_MANY_SETTINGS, EXTENSION_MAX_SETTINGS
+        ));
+    }
+
+    for (key, value) in user_config.settings.iter() {
+        if key.trim().is_empty() {
+            return Err(ERROR_EXTENSION_SETTING_EMPTY_KEY.to_string());
+        }
+
+        if key.len() > EXTENSION_MAX_SETTING_KEY_LENGTH {
+            return Err(ERROR_EXTENSION_SETTING_KEY_TOO_LONG.to_string());
+        }
+
+        if value.len() > EXTENSION_MAX_SETTING_VALUE_LENGTH {
+            return Err(ERROR_EXTENSION_SETTING_VALUE_TOO_LONG.to_string());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub const EXTENSION_MAX_SETTINGS: usize = 32;
+pub const EXTENSION_MAX_SETTING_KEY_LENGTH: usize = 64;
+pub const EXTENSION_MAX_SETTING_VALUE_LENGTH: usize = 1024;
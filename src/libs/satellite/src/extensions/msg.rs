@@ -0,0 +1,7 @@
+pub const ERROR_EXTENSION_TOO_MANY_SETTINGS: &str = "An extension cannot have more than";
+pub const ERROR_EXTENSION_SETTING_EMPTY_KEY: &str =
+    "The key of a setting of an extension cannot be empty.";
+pub const ERROR_EXTENSION_SETTING_KEY_TOO_LONG: &str =
+    "The key of a setting of an extension is too long.";
+pub const ERROR_EXTENSION_SETTING_VALUE_TOO_LONG: &str =
+    "The value of a setting of an extension is too long.";
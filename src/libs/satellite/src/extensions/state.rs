@@ -0,0 +1,42 @@
+use crate::extensions::types::state::{Extension, ExtensionKey, Extensions, ExtensionsHeapState};
+use crate::memory::STATE;
+
+pub fn get_extension(key: &ExtensionKey) -> Option<Extension> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.extensions.get(key).cloned())
+    })
+}
+
+pub fn get_extensions() -> Extensions {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .extensions
+            .as_ref()
+            .map(|extensions| extensions.extensions.clone())
+            .unwrap_or_default()
+    })
+}
+
+pub fn insert_extension(key: &ExtensionKey, extension: &Extension) {
+    STATE.with(|state| {
+        insert_extension_impl(key, extension, &mut state.borrow_mut().heap.extensions)
+    })
+}
+
+fn insert_extension_impl(
+    key: &ExtensionKey,
+    extension: &Extension,
+    state: &mut Option<ExtensionsHeapState>,
+) {
+    state
+        .get_or_insert_with(ExtensionsHeapState::default)
+        .extensions
+        .insert(key.clone(), extension.clone());
+}
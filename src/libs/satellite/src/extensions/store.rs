@@ -0,0 +1,85 @@
+use crate::extensions::assert::assert_set_extension_config;
+use crate::extensions::state::{get_extension, get_extensions, insert_extension};
+use crate::extensions::types::interface::SetExtensionConfig;
+use crate::extensions::types::state::{Extension, ExtensionKey, ExtensionSettings};
+use crate::manifest::http::certify_manifest;
+use ic_cdk::api::time;
+use junobuild_shared::constants::INITIAL_VERSION;
+
+/// Enable, disable or configure an extension.
+///
+/// The version of the current configuration must be provided for an update, as for documents or rules.
+pub fn set_extension_config_store(
+    key: ExtensionKey,
+    user_config: SetExtensionConfig,
+) -> Result<Extension, String> {
+    let current_extension = get_extension(&key);
+
+    assert_set_extension_config(&current_extension, &user_config)?;
+
+    let now = time();
+
+    let extension = Extension {
+        enabled: user_config.enabled,
+        settings: user_config.settings,
+        created_at: current_extension
+            .as_ref()
+            .map(|extension| extension.created_at)
+            .unwrap_or(now),
+        updated_at: now,
+        version: Some(
+            current_extension
+                .as_ref()
+                .and_then(|extension| extension.version)
+                .map(|version| version + 1)
+                .unwrap_or(INITIAL_VERSION),
+        ),
+    };
+
+    insert_extension(&key, &extension);
+
+    // The manifest lists the enabled modules
+    certify_manifest();
+
+    Ok(extension)
+}
+
+/// Enable or disable an extension on behalf of its module - e.g. through the former endpoint of its configuration. Its
+/// settings are kept and its current version is applied.
+pub fn set_extension_enabled_store(key: ExtensionKey, enabled: bool) -> Result<Extension, String> {
+    let current_extension = get_extension(&key);
+
+    let user_config = SetExtensionConfig {
+        enabled,
+        settings: current_extension
+            .as_ref()
+            .map(|extension| extension.settings.clone())
+            .unwrap_or_default(),
+        version: current_extension.and_then(|extension| extension.version),
+    };
+
+    set_extension_config_store(key, user_config)
+}
+
+pub fn get_extension_store(key: &ExtensionKey) -> Option<Extension> {
+    get_extension(key)
+}
+
+/// List the configurations of the extensions that were configured at least once.
+pub fn list_extensions_store() -> Vec<(ExtensionKey, Extension)> {
+    get_extensions().into_iter().collect()
+}
+
+/// Whether an extension is enabled. An extension that was never configured is disabled.
+pub fn is_extension_enabled_store(key: &ExtensionKey) -> bool {
+    get_extension(key)
+        .map(|extension| extension.enabled)
+        .unwrap_or(false)
+}
+
+/// The settings of an extension, provided it is enabled.
+pub fn get_extension_settings_store(key: &ExtensionKey) -> Option<ExtensionSettings> {
+    get_extension(key)
+        .filter(|extension| extension.enabled)
+        .map(|extension| extension.settings)
+}
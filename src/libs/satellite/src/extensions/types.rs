@@ -0,0 +1,52 @@
+pub mod state {
+    use candid::CandidType;
+    use junobuild_shared::types::state::{Timestamp, Version};
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    pub type ExtensionSettings = HashMap<String, String>;
+
+    pub type Extensions = HashMap<ExtensionKey, Extension>;
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct ExtensionsHeapState {
+        pub extensions: Extensions,
+    }
+
+    /// The optional modules built into the satellite. Each module reads its toggle and settings - i.e. its state - in
+    /// the registry under its own key instead of adding its own configuration to the heap.
+    ///
+    /// - `Traces`: The collection of the instructions executed by the update endpoints.
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+    pub enum ExtensionKey {
+        Traces,
+    }
+
+    /// Represents the configuration of an extension.
+    ///
+    /// - `enabled`: Whether the module is active. Disabling a module keeps its settings.
+    /// - `settings`: The settings of the module, as key-value pairs. Their meaning is up to each module.
+    /// - `created_at`, `updated_at` and `version`: The usual timestamps and version of the entities of the satellite.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct Extension {
+        pub enabled: bool,
+        pub settings: ExtensionSettings,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+        pub version: Option<Version>,
+    }
+}
+
+pub mod interface {
+    use crate::extensions::types::state::ExtensionSettings;
+    use candid::CandidType;
+    use junobuild_shared::types::state::Version;
+    use serde::Deserialize;
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct SetExtensionConfig {
+        pub enabled: bool,
+        pub settings: ExtensionSettings,
+        pub version: Option<Version>,
+    }
+}
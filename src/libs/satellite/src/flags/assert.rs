@@ -0,0 +1,39 @@
+use crate::flags::msg::{ERROR_FEATURE_FLAG_EMPTY_KEY, ERROR_FEATURE_FLAG_INVALID_PERCENTAGE};
+use crate::flags::types::interface::{DelFeatureFlag, SetFeatureFlag};
+use crate::flags::types::state::{FeatureFlag, FeatureFlagKey, FeatureFlagRule};
+use junobuild_shared::assert::{assert_description_length, assert_version};
+
+pub fn assert_set_feature_flag(
+    key: &FeatureFlagKey,
+    current_flag: &Option<FeatureFlag>,
+    user_flag: &SetFeatureFlag,
+) -> Result<(), String> {
+    if key.trim().is_empty() {
+        return Err(ERROR_FEATURE_FLAG_EMPTY_KEY.to_string());
+    }
+
+    assert_version(
+        user_flag.version,
+        current_flag.as_ref().and_then(|flag| flag.version),
+    )?;
+
+    assert_description_length(&user_flag.description)?;
+
+    if let FeatureFlagRule::Percentage(percentage) = user_flag.rule {
+        if percentage > 100 {
+            return Err(ERROR_FEATURE_FLAG_INVALID_PERCENTAGE.to_string());
+        }
+    }
+
+    Ok(())
+}
+
+pub fn assert_delete_feature_flag(
+    current_flag: &Option<FeatureFlag>,
+    user_flag: &DelFeatureFlag,
+) -> Result<(), String> {
+    assert_version(
+        user_flag.version,
+        current_flag.as_ref().and_then(|flag| flag.version),
+    )
+}
@@ -0,0 +1,40 @@
+use crate::flags::types::state::{FeatureFlag, FeatureFlagKey, FeatureFlagRole, FeatureFlagRule};
+use junobuild_shared::controllers::{is_admin_controller, is_controller};
+use junobuild_shared::types::state::{Controllers, UserId};
+use junobuild_shared::utils::{principal_anonymous, principal_not_anonymous};
+use sha2::{Digest, Sha256};
+
+pub fn is_enabled(
+    key: &FeatureFlagKey,
+    flag: &FeatureFlag,
+    caller: UserId,
+    controllers: &Controllers,
+) -> bool {
+    match &flag.rule {
+        FeatureFlagRule::Boolean(enabled) => *enabled,
+        FeatureFlagRule::Percentage(percentage) => bucket(key, caller) < u32::from(*percentage),
+        FeatureFlagRule::Targeted(roles) => {
+            roles.iter().any(|role| has_role(role, caller, controllers))
+        }
+    }
+}
+
+fn has_role(role: &FeatureFlagRole, caller: UserId, controllers: &Controllers) -> bool {
+    match role {
+        FeatureFlagRole::Admin => is_admin_controller(caller, controllers),
+        FeatureFlagRole::Controller => is_controller(caller, controllers),
+        FeatureFlagRole::User => principal_not_anonymous(caller),
+        FeatureFlagRole::Anonymous => principal_anonymous(caller),
+    }
+}
+
+// The bucket - 0 to 99 - is derived from both the key and the caller, so that a caller enabled for a flag is not necessarily enabled for all the others with the same percentage.
+fn bucket(key: &FeatureFlagKey, caller: UserId) -> u32 {
+    let mut hasher = Sha256::new();
+    hasher.update(key.as_bytes());
+    hasher.update(caller.as_slice());
+
+    let hash = hasher.finalize();
+
+    u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]) % 100
+}
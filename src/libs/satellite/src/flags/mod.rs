@@ -0,0 +1,6 @@
+mod assert;
+mod evaluate;
+mod msg;
+mod state;
+pub mod store;
+pub mod types;
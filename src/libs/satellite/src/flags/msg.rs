@@ -0,0 +1,3 @@
+pub const ERROR_FEATURE_FLAG_EMPTY_KEY: &str = "Feature flag key cannot be empty.";
+pub const ERROR_FEATURE_FLAG_INVALID_PERCENTAGE: &str =
+    "Feature flag percentage must be between 0 and 100.";
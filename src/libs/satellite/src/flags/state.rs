@@ -0,0 +1,52 @@
+use crate::flags::types::state::{
+    FeatureFlag, FeatureFlagKey, FeatureFlags, FeatureFlagsHeapState,
+};
+use crate::memory::STATE;
+
+pub fn get_flag(key: &FeatureFlagKey) -> Option<FeatureFlag> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .flags
+            .as_ref()
+            .and_then(|flags| flags.flags.get(key).cloned())
+    })
+}
+
+pub fn get_flags() -> FeatureFlags {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .flags
+            .as_ref()
+            .map(|flags| flags.flags.clone())
+            .unwrap_or_default()
+    })
+}
+
+pub fn insert_flag(key: &FeatureFlagKey, flag: &FeatureFlag) {
+    STATE.with(|state| insert_flag_impl(key, flag, &mut state.borrow_mut().heap.flags))
+}
+
+pub fn delete_flag(key: &FeatureFlagKey) {
+    STATE.with(|state| delete_flag_impl(key, &mut state.borrow_mut().heap.flags))
+}
+
+fn insert_flag_impl(
+    key: &FeatureFlagKey,
+    flag: &FeatureFlag,
+    state: &mut Option<FeatureFlagsHeapState>,
+) {
+    state
+        .get_or_insert_with(FeatureFlagsHeapState::default)
+        .flags
+        .insert(key.clone(), flag.clone());
+}
+
+fn delete_flag_impl(key: &FeatureFlagKey, state: &mut Option<FeatureFlagsHeapState>) {
+    if let Some(state) = state {
+        state.flags.remove(key);
+    }
+}
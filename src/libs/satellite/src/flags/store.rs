@@ -0,0 +1,89 @@
+use crate::controllers::store::get_controllers;
+use crate::flags::assert::{assert_delete_feature_flag, assert_set_feature_flag};
+use crate::flags::evaluate::is_enabled;
+use crate::flags::state::{delete_flag, get_flag, get_flags, insert_flag};
+use crate::flags::types::interface::{DelFeatureFlag, SetFeatureFlag};
+use crate::flags::types::state::{FeatureFlag, FeatureFlagKey};
+use crate::manifest::http::certify_manifest;
+use ic_cdk::api::time;
+use junobuild_shared::constants::INITIAL_VERSION;
+use junobuild_shared::types::state::UserId;
+
+/// Create or update a feature flag.
+///
+/// The version of the current flag must be provided for an update, as for documents or rules.
+pub fn set_feature_flag_store(
+    key: FeatureFlagKey,
+    user_flag: SetFeatureFlag,
+) -> Result<FeatureFlag, String> {
+    let current_flag = get_flag(&key);
+
+    assert_set_feature_flag(&key, &current_flag, &user_flag)?;
+
+    let now = time();
+
+    let flag = FeatureFlag {
+        rule: user_flag.rule,
+        description: user_flag.description,
+        created_at: current_flag
+            .as_ref()
+            .map(|flag| flag.created_at)
+            .unwrap_or(now),
+        updated_at: now,
+        version: Some(
+            current_flag
+                .as_ref()
+                .and_then(|flag| flag.version)
+                .map(|version| version + 1)
+                .unwrap_or(INITIAL_VERSION),
+        ),
+    };
+
+    insert_flag(&key, &flag);
+
+    certify_manifest();
+
+    Ok(flag)
+}
+
+/// Delete a feature flag. Deleting a flag that does not exist is a no-op.
+pub fn delete_feature_flag_store(
+    key: FeatureFlagKey,
+    user_flag: DelFeatureFlag,
+) -> Result<(), String> {
+    let current_flag = get_flag(&key);
+
+    assert_delete_feature_flag(&current_flag, &user_flag)?;
+
+    delete_flag(&key);
+
+    certify_manifest();
+
+    Ok(())
+}
+
+/// List the definitions of all feature flags.
+pub fn list_feature_flags_store() -> Vec<(FeatureFlagKey, FeatureFlag)> {
+    get_flags().into_iter().collect()
+}
+
+/// Evaluate a feature flag for a caller.
+///
+/// Returns `false` if the flag does not exist. The HTTP requests being anonymous, those can be evaluated with `Principal::anonymous()` as caller.
+pub fn evaluate_feature_flag_store(caller: UserId, key: &FeatureFlagKey) -> bool {
+    let controllers = get_controllers();
+
+    get_flag(key)
+        .map(|flag| is_enabled(key, &flag, caller, &controllers))
+        .unwrap_or(false)
+}
+
+/// Evaluate all feature flags for a caller.
+pub fn evaluate_feature_flags_store(caller: UserId) -> Vec<(FeatureFlagKey, bool)> {
+    let controllers = get_controllers();
+
+    get_flags()
+        .iter()
+        .map(|(key, flag)| (key.clone(), is_enabled(key, flag, caller, &controllers)))
+        .collect()
+}
@@ -0,0 +1,70 @@
+pub mod state {
+    use candid::CandidType;
+    use junobuild_shared::types::state::{Timestamp, Version};
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    pub type FeatureFlagKey = String;
+
+    pub type FeatureFlags = HashMap<FeatureFlagKey, FeatureFlag>;
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct FeatureFlagsHeapState {
+        pub flags: FeatureFlags,
+    }
+
+    /// Represents a feature flag defined by the controllers.
+    ///
+    /// - `rule`: How the flag is evaluated for a caller.
+    /// - `description`: An optional description, limited to 1024 characters.
+    /// - `created_at`, `updated_at` and `version`: The usual timestamps and version of the entities of the satellite.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct FeatureFlag {
+        pub rule: FeatureFlagRule,
+        pub description: Option<String>,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+        pub version: Option<Version>,
+    }
+
+    /// - `Boolean`: The flag is either enabled or disabled for everyone.
+    /// - `Percentage`: The flag is enabled for a stable share - 0 to 100 - of the callers. A given caller always lands in the same bucket for a given flag.
+    /// - `Targeted`: The flag is enabled for the callers having one of the roles.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum FeatureFlagRule {
+        Boolean(bool),
+        Percentage(u8),
+        Targeted(Vec<FeatureFlagRole>),
+    }
+
+    /// - `Admin`: The admin controllers.
+    /// - `Controller`: Any controller, admin or with write scope.
+    /// - `User`: Any authenticated - i.e. non-anonymous - caller.
+    /// - `Anonymous`: The anonymous caller, which is notably the caller of the HTTP requests.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq)]
+    pub enum FeatureFlagRole {
+        Admin,
+        Controller,
+        User,
+        Anonymous,
+    }
+}
+
+pub mod interface {
+    use crate::flags::types::state::FeatureFlagRule;
+    use candid::CandidType;
+    use junobuild_shared::types::state::Version;
+    use serde::Deserialize;
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct SetFeatureFlag {
+        pub rule: FeatureFlagRule,
+        pub description: Option<String>,
+        pub version: Option<Version>,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct DelFeatureFlag {
+        pub version: Option<Version>,
+    }
+}
@@ -0,0 +1,53 @@
+use crate::gates::store::is_token_holder;
+use candid::Principal;
+use junobuild_collections::assert_stores::{assert_create_permission, assert_permission};
+use junobuild_collections::types::rules::Permission;
+use junobuild_shared::controllers::is_controller;
+use junobuild_shared::types::state::Controllers;
+use junobuild_shared::utils::principal_equal;
+
+/// Asserts the permission of the caller on a document, granting the verified holders of a token gated collection
+/// the same access as everyone has on a public collection.
+pub fn assert_gated_permission(
+    permission: &Permission,
+    owner: Principal,
+    caller: Principal,
+    controllers: &Controllers,
+) -> bool {
+    match permission {
+        Permission::TokenGated(gate) => {
+            is_controller(caller, controllers) || is_token_holder(&caller, gate)
+        }
+        _ => assert_permission(permission, owner, caller, controllers),
+    }
+}
+
+/// Asserts the permission of the caller to update or delete a document. The holders of a token gated collection can only
+/// modify the documents they own - i.e. like on a managed collection.
+pub fn assert_gated_write_permission(
+    permission: &Permission,
+    owner: Principal,
+    caller: Principal,
+    controllers: &Controllers,
+) -> bool {
+    match permission {
+        Permission::TokenGated(gate) => {
+            is_controller(caller, controllers)
+                || (principal_equal(caller, owner) && is_token_holder(&caller, gate))
+        }
+        _ => assert_permission(permission, owner, caller, controllers),
+    }
+}
+
+pub fn assert_gated_create_permission(
+    permission: &Permission,
+    caller: Principal,
+    controllers: &Controllers,
+) -> bool {
+    match permission {
+        Permission::TokenGated(gate) => {
+            is_controller(caller, controllers) || is_token_holder(&caller, gate)
+        }
+        _ => assert_create_permission(permission, caller, controllers),
+    }
+}
@@ -0,0 +1,14 @@
+use junobuild_shared::rate::types::RateConfig;
+
+/// The duration in nanoseconds during which a verified balance is trusted - i.e. ten minutes - before it has to be
+/// verified again with the ledger.
+pub const TOKEN_HOLDING_TTL_NS: u64 = 600_000_000_000;
+
+/// The balances kept at most. The expired ones are removed first, then the oldest.
+pub const TOKEN_HOLDINGS_MAX_LENGTH: usize = 10_000;
+
+/// The balances fetched from the ledgers, across all callers - i.e. ten per second with bursts of one hundred.
+pub const TOKEN_VERIFICATIONS_RATE_CONFIG: RateConfig = RateConfig {
+    time_per_token_ns: 100_000_000,
+    max_tokens: 100,
+};
@@ -0,0 +1,4 @@
+pub const ERROR_COLLECTION_NOT_TOKEN_GATED: &str = "The collection is not token gated.";
+pub const ERROR_TOKEN_GATE_ANONYMOUS: &str =
+    "The holdings of an anonymous caller cannot be verified.";
+pub const ERROR_TOKEN_BALANCE_UNAVAILABLE: &str = "The balance cannot be fetched from the ledger.";
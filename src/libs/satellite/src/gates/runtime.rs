@@ -0,0 +1,51 @@
+use crate::gates::constants::{
+    TOKEN_HOLDINGS_MAX_LENGTH, TOKEN_HOLDING_TTL_NS, TOKEN_VERIFICATIONS_RATE_CONFIG,
+};
+use crate::gates::types::state::{TokenHolding, TokenHoldingKey, TokenHoldings};
+use crate::memory::STATE;
+use junobuild_shared::rate::utils::increment_and_assert_rate;
+use junobuild_shared::types::state::Timestamp;
+
+pub fn get_holding(key: &TokenHoldingKey) -> Option<TokenHolding> {
+    STATE.with(|state| state.borrow().runtime.gates.holdings.get(key).cloned())
+}
+
+pub fn insert_holding(key: TokenHoldingKey, holding: TokenHolding) {
+    STATE.with(|state| {
+        insert_holding_impl(key, holding, &mut state.borrow_mut().runtime.gates.holdings)
+    });
+}
+
+pub fn increment_and_assert_verifications_rate() -> Result<(), String> {
+    STATE.with(|state| {
+        increment_and_assert_rate(
+            &TOKEN_VERIFICATIONS_RATE_CONFIG,
+            &mut state.borrow_mut().runtime.gates.verifications,
+        )
+    })
+}
+
+fn insert_holding_impl(key: TokenHoldingKey, holding: TokenHolding, holdings: &mut TokenHoldings) {
+    if !holdings.contains_key(&key) && holdings.len() >= TOKEN_HOLDINGS_MAX_LENGTH {
+        let now = holding.verified_at;
+
+        holdings.retain(|_, holding| !is_expired(holding, now));
+
+        if holdings.len() >= TOKEN_HOLDINGS_MAX_LENGTH {
+            let oldest = holdings
+                .iter()
+                .min_by_key(|(_, holding)| holding.verified_at)
+                .map(|(key, _)| *key);
+
+            if let Some(oldest) = oldest {
+                holdings.remove(&oldest);
+            }
+        }
+    }
+
+    holdings.insert(key, holding);
+}
+
+pub fn is_expired(holding: &TokenHolding, now: Timestamp) -> bool {
+    holding.verified_at.saturating_add(TOKEN_HOLDING_TTL_NS) <= now
+}
@@ -0,0 +1,93 @@
+use crate::db::state::get_rule;
+use crate::gates::msg::{
+    ERROR_COLLECTION_NOT_TOKEN_GATED, ERROR_TOKEN_BALANCE_UNAVAILABLE, ERROR_TOKEN_GATE_ANONYMOUS,
+};
+use crate::gates::runtime::{
+    get_holding, increment_and_assert_verifications_rate, insert_holding, is_expired,
+};
+use crate::gates::types::state::TokenHolding;
+use ic_cdk::api::time;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{Permission, TokenGate, TokenStandard};
+use junobuild_shared::ledger::icrc::{icrc7_balance_of, icrc_balance_of};
+use junobuild_shared::types::state::UserId;
+use junobuild_shared::utils::principal_not_anonymous;
+
+/// Fetches the balances of the caller from the ledgers of the token gates of a collection - i.e. of its read and write
+/// permissions - and keeps them for the assertions of the next calls.
+///
+/// A caller whose balance was already verified as sufficient is not verified again until it expires. The calls to the
+/// ledgers are limited across all callers, given that each new principal would otherwise trigger one.
+///
+/// # Returns
+/// - `Ok(true)`: The caller holds enough tokens for every gate of the collection.
+/// - `Ok(false)`: The balance of the caller is below the minimum of at least one gate.
+/// - `Err(String)`: The collection is not token gated or a ledger cannot be reached.
+pub async fn verify_token_gate_store(
+    caller: UserId,
+    collection: &CollectionKey,
+) -> Result<bool, String> {
+    if !principal_not_anonymous(caller) {
+        return Err(ERROR_TOKEN_GATE_ANONYMOUS.to_string());
+    }
+
+    let rule = get_rule(collection)?;
+
+    let gates: Vec<TokenGate> = [rule.read, rule.write]
+        .into_iter()
+        .filter_map(|permission| match permission {
+            Permission::TokenGated(gate) => Some(*gate),
+            _ => None,
+        })
+        .collect();
+
+    if gates.is_empty() {
+        return Err(ERROR_COLLECTION_NOT_TOKEN_GATED.to_string());
+    }
+
+    for (index, gate) in gates.iter().enumerate() {
+        // The read and write permissions might be gated by the same ledger, whose balance is then fetched once
+        let fetched = gates[..index]
+            .iter()
+            .any(|other| other.ledger_id == gate.ledger_id && other.standard == gate.standard);
+
+        if fetched || is_token_holder(&caller, gate) {
+            continue;
+        }
+
+        fetch_holding(caller, gate).await?;
+    }
+
+    Ok(gates.iter().all(|gate| is_token_holder(&caller, gate)))
+}
+
+async fn fetch_holding(caller: UserId, gate: &TokenGate) -> Result<(), String> {
+    increment_and_assert_verifications_rate()?;
+
+    let balance = match gate.standard {
+        TokenStandard::Icrc1 => icrc_balance_of(gate.ledger_id, caller).await,
+        TokenStandard::Icrc7 => icrc7_balance_of(gate.ledger_id, caller).await,
+    }
+    .map_err(|(_, message)| format!("{} ({})", ERROR_TOKEN_BALANCE_UNAVAILABLE, message))?;
+
+    insert_holding(
+        (caller, gate.ledger_id),
+        TokenHolding {
+            standard: gate.standard.clone(),
+            balance,
+            verified_at: time(),
+        },
+    );
+
+    Ok(())
+}
+
+/// Whether the last balance of the caller verified for the ledger of a gate - if not yet expired - reaches its
+/// minimum.
+pub fn is_token_holder(caller: &UserId, gate: &TokenGate) -> bool {
+    get_holding(&(*caller, gate.ledger_id)).is_some_and(|holding| {
+        holding.standard == gate.standard
+            && !is_expired(&holding, time())
+            && holding.balance >= gate.min_balance
+    })
+}
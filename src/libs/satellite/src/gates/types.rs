@@ -0,0 +1,25 @@
+pub mod state {
+    use candid::{Nat, Principal};
+    use junobuild_collections::types::rules::TokenStandard;
+    use junobuild_shared::rate::types::RateTokens;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use std::collections::HashMap;
+
+    pub type TokenHoldingKey = (UserId, Principal);
+    pub type TokenHoldings = HashMap<TokenHoldingKey, TokenHolding>;
+
+    /// - `holdings`: The balances of the callers fetched from the ledgers, by caller and ledger.
+    /// - `verifications`: The tokens limiting the calls to the ledgers.
+    #[derive(Default, Clone)]
+    pub struct TokenGatesRuntimeState {
+        pub holdings: TokenHoldings,
+        pub verifications: RateTokens,
+    }
+
+    #[derive(Clone)]
+    pub struct TokenHolding {
+        pub standard: TokenStandard,
+        pub balance: Nat,
+        pub verified_at: Timestamp,
+    }
+}
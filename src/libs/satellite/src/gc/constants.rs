@@ -0,0 +1,9 @@
+// The number of content chunks removed in each timer. A chunk weighs up to ~2 MB, therefore the batch is kept small so
+// that the instructions of a single message remain bounded.
+pub const GC_CHUNKS_PER_STEP: usize = 20;
+
+// The number of documents deleted in each timer, with the aggregates, views and attachments they affect.
+pub const GC_DOCS_PER_STEP: usize = 100;
+
+// The number of attachments deleted in each timer. Their content chunks are themselves collected afterwards.
+pub const GC_ATTACHMENTS_PER_STEP: usize = 50;
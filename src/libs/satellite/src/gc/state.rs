@@ -0,0 +1,83 @@
+use crate::db::types::state::StableKey as DbStableKey;
+use crate::gc::types::state::{GcAttachment, GcDoc};
+use crate::memory::STATE;
+use crate::storage::types::state::{StableEncodingChunkKey, StableKey as StorageStableKey};
+
+pub fn enqueue_chunks(chunks: Vec<StableEncodingChunkKey>) {
+    STATE.with(|state| {
+        let gc_chunks = &mut state.borrow_mut().stable.gc_chunks;
+
+        for chunk in chunks {
+            gc_chunks.insert(chunk, ());
+        }
+    })
+}
+
+pub fn enqueue_docs(docs: Vec<(DbStableKey, GcDoc)>) {
+    STATE.with(|state| {
+        let gc_docs = &mut state.borrow_mut().stable.gc_docs;
+
+        for (key, doc) in docs {
+            gc_docs.insert(key, doc);
+        }
+    })
+}
+
+pub fn enqueue_attachments(attachments: Vec<(StorageStableKey, GcAttachment)>) {
+    STATE.with(|state| {
+        let gc_attachments = &mut state.borrow_mut().stable.gc_attachments;
+
+        for (key, attachment) in attachments {
+            gc_attachments.insert(key, attachment);
+        }
+    })
+}
+
+pub fn take_docs(limit: usize) -> Vec<(DbStableKey, GcDoc)> {
+    STATE.with(|state| {
+        let gc_docs = &mut state.borrow_mut().stable.gc_docs;
+
+        let docs: Vec<(DbStableKey, GcDoc)> = gc_docs.iter().take(limit).collect();
+
+        for (key, _) in &docs {
+            gc_docs.remove(key);
+        }
+
+        docs
+    })
+}
+
+pub fn take_attachments(limit: usize) -> Vec<(StorageStableKey, GcAttachment)> {
+    STATE.with(|state| {
+        let gc_attachments = &mut state.borrow_mut().stable.gc_attachments;
+
+        let attachments: Vec<(StorageStableKey, GcAttachment)> =
+            gc_attachments.iter().take(limit).collect();
+
+        for (key, _) in &attachments {
+            gc_attachments.remove(key);
+        }
+
+        attachments
+    })
+}
+
+pub fn count_pending_chunks() -> usize {
+    STATE.with(|state| state.borrow().stable.gc_chunks.len() as usize)
+}
+
+pub fn count_pending_docs() -> usize {
+    STATE.with(|state| state.borrow().stable.gc_docs.len() as usize)
+}
+
+pub fn count_pending_attachments() -> usize {
+    STATE.with(|state| state.borrow().stable.gc_attachments.len() as usize)
+}
+
+pub fn is_scheduled() -> bool {
+    STATE.with(|state| state.borrow().runtime.gc.scheduled)
+}
+
+pub fn set_scheduled(scheduled: bool) {
+    STATE.with(|state| state.borrow_mut().runtime.gc.scheduled = scheduled)
+}
@@ -0,0 +1,211 @@
+use crate::db::store::delete_pending_doc_store;
+use crate::db::types::state::{Doc, DocContext, StableKey as DbStableKey};
+use crate::defrag::store::is_content_chunk_referenced;
+use crate::events::store::emit;
+use crate::events::types::interface::SatelliteEvent;
+use crate::gc::constants::{GC_ATTACHMENTS_PER_STEP, GC_CHUNKS_PER_STEP, GC_DOCS_PER_STEP};
+use crate::gc::state::{
+    count_pending_attachments, count_pending_chunks, count_pending_docs, enqueue_attachments,
+    enqueue_chunks, enqueue_docs, is_scheduled, set_scheduled, take_attachments, take_docs,
+};
+use crate::gc::types::state::{GcAttachment, GcDoc};
+use crate::memory::STATE;
+use crate::safe_mode::store::is_safe_mode;
+use crate::storage::store::delete_owned_assets_store;
+use crate::storage::types::state::{StableEncodingChunkKey, StableKey as StorageStableKey};
+use crate::types::state::State;
+use ic_cdk_timers::set_timer;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::serializers::deserialize_from_bytes;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::{Timestamp, UserId};
+use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::store::Asset;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Defers the removal of the content chunks of a deleted stable asset to the garbage collection, so that the deletion
+/// itself does not depend on the size of the asset.
+pub fn defer_delete_content_chunks(asset: &Asset) {
+    let chunks: Vec<StableEncodingChunkKey> = asset
+        .encodings
+        .values()
+        .flat_map(|encoding| encoding.content_chunks.iter())
+        .map(|chunk| deserialize_from_bytes(Cow::Borrowed(chunk)))
+        .collect();
+
+    if chunks.is_empty() {
+        return;
+    }
+
+    enqueue_chunks(chunks);
+
+    schedule_gc();
+}
+
+/// Defers the deletion of the documents of a bulk deletion that exceed the batch deleted within the call, so that
+/// deleting thousands of documents does not reach the instruction limit.
+pub fn defer_delete_docs(
+    collection: &CollectionKey,
+    docs: &[(Key, Timestamp)],
+    caller: &Option<UserId>,
+) {
+    if docs.is_empty() {
+        return;
+    }
+
+    enqueue_docs(
+        docs.iter()
+            .map(|(key, updated_at)| {
+                (
+                    DbStableKey {
+                        collection: collection.clone(),
+                        key: key.clone(),
+                    },
+                    GcDoc {
+                        updated_at: *updated_at,
+                        caller: *caller,
+                    },
+                )
+            })
+            .collect(),
+    );
+
+    schedule_gc();
+}
+
+/// Defers the cascade deletion of the attachments of a deleted document.
+pub fn defer_delete_attachments(
+    collection: &CollectionKey,
+    full_paths: &[FullPath],
+    owner: &UserId,
+) {
+    if full_paths.is_empty() {
+        return;
+    }
+
+    enqueue_attachments(
+        full_paths
+            .iter()
+            .map(|full_path| {
+                (
+                    StorageStableKey {
+                        collection: collection.clone(),
+                        full_path: full_path.clone(),
+                    },
+                    GcAttachment { owner: *owner },
+                )
+            })
+            .collect(),
+    );
+
+    schedule_gc();
+}
+
+pub fn count_pending_chunks_store() -> usize {
+    count_pending_chunks()
+}
+
+pub fn count_pending_docs_store() -> usize {
+    count_pending_docs()
+}
+
+/// Schedules the garbage collection if deletions are pending and no timer is already running. Timers do not survive
+/// upgrades, this is why it is called again in the post_upgrade hook.
+pub fn schedule_gc() {
+    if is_scheduled() || !is_pending() {
+        return;
+    }
+
+    set_scheduled(true);
+
+    set_timer(Duration::ZERO, run_next_step);
+}
+
+fn is_pending() -> bool {
+    count_pending_docs() > 0 || count_pending_attachments() > 0 || count_pending_chunks() > 0
+}
+
+fn run_next_step() {
+    // A chunk is removed when no asset references it anymore. In safe mode, a collection might be missing, therefore
+    // the collection is paused until a controller acknowledges the issues.
+    if is_safe_mode() {
+        set_scheduled(false);
+        return;
+    }
+
+    // The documents are deleted first given that their attachments and then the content chunks of those are in turn
+    // queued.
+    collect_docs();
+
+    collect_attachments();
+
+    STATE.with(|state| collect_chunks(&mut state.borrow_mut()));
+
+    if !is_pending() {
+        set_scheduled(false);
+        return;
+    }
+
+    set_timer(Duration::ZERO, run_next_step);
+}
+
+fn collect_docs() {
+    let mut deleted_docs: HashMap<UserId, Vec<DocContext<Option<Doc>>>> = HashMap::new();
+
+    for (DbStableKey { collection, key }, GcDoc { updated_at, caller }) in
+        take_docs(GC_DOCS_PER_STEP)
+    {
+        // A document that cannot be deleted - e.g. its collection was deleted meanwhile - is dropped from the queue
+        // rather than trapping, which would stall the garbage collection.
+        let Ok(Some(deleted_doc)) = delete_pending_doc_store(&collection, &key, updated_at) else {
+            continue;
+        };
+
+        if let Some(caller) = caller {
+            deleted_docs.entry(caller).or_default().push(DocContext {
+                key,
+                collection,
+                data: Some(deleted_doc),
+            });
+        }
+    }
+
+    for (caller, docs) in deleted_docs {
+        emit(&caller, SatelliteEvent::DeleteFilteredDocs(docs));
+    }
+}
+
+fn collect_attachments() {
+    for (
+        StorageStableKey {
+            collection,
+            full_path,
+        },
+        GcAttachment { owner },
+    ) in take_attachments(GC_ATTACHMENTS_PER_STEP)
+    {
+        let _ = delete_owned_assets_store(&collection, &[full_path], &owner);
+    }
+}
+
+fn collect_chunks(state: &mut State) {
+    let batch: Vec<StableEncodingChunkKey> = state
+        .stable
+        .gc_chunks
+        .iter()
+        .take(GC_CHUNKS_PER_STEP)
+        .map(|(key, _)| key)
+        .collect();
+
+    for key in batch {
+        state.stable.gc_chunks.remove(&key);
+
+        // The chunk keys are derived from the full path of the assets. A chunk that was written again by an upload
+        // to the same path meanwhile is kept.
+        if !is_content_chunk_referenced(&key, state) {
+            state.stable.content_chunks.remove(&key);
+        }
+    }
+}
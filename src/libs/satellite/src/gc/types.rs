@@ -0,0 +1,41 @@
+pub mod state {
+    use crate::db::types::state::StableKey as DbStableKey;
+    use crate::storage::types::state::{StableEncodingChunkKey, StableKey as StorageStableKey};
+    use candid::CandidType;
+    use ic_stable_structures::StableBTreeMap;
+    use junobuild_shared::types::memory::Memory;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use serde::{Deserialize, Serialize};
+
+    /// The content chunks of the deleted stable assets, awaiting their removal by the garbage collection.
+    pub type GcChunksStable = StableBTreeMap<StableEncodingChunkKey, (), Memory>;
+
+    /// The documents of the bulk deletions that exceed the batch deleted within the call, awaiting their deletion by
+    /// the garbage collection.
+    pub type GcDocsStable = StableBTreeMap<DbStableKey, GcDoc, Memory>;
+
+    /// The attachments of the deleted documents, awaiting their cascade deletion by the garbage collection.
+    pub type GcAttachmentsStable = StableBTreeMap<StorageStableKey, GcAttachment, Memory>;
+
+    /// - `updated_at`: The timestamp of the document when its deletion was requested. A document written again
+    ///   meanwhile is kept.
+    /// - `caller`: The caller to whom the deletion is attributed when it is emitted - `None` if the deletion emits no
+    ///   event.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct GcDoc {
+        pub updated_at: Timestamp,
+        pub caller: Option<UserId>,
+    }
+
+    /// - `owner`: The owner of the deleted document. Only the assets it owns are deleted.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct GcAttachment {
+        pub owner: UserId,
+    }
+
+    /// - `scheduled`: Whether a timer of the garbage collection is pending.
+    #[derive(Default, Clone)]
+    pub struct GcRuntimeState {
+        pub scheduled: bool,
+    }
+}
@@ -1,4 +1,9 @@
 use crate::memory::STATE;
+use crate::rate_limit::store::assert_rate_limit;
+use crate::requests::store::request_error;
+use crate::safe_mode::store::assert_not_safe_mode;
+use crate::safe_mode::store::is_safe_mode_without_admin;
+use ic_cdk::api::is_controller as is_canister_controller;
 use ic_cdk::caller;
 use junobuild_shared::controllers::{is_admin_controller, is_controller};
 use junobuild_shared::types::state::Controllers;
@@ -10,7 +15,9 @@ pub fn caller_is_admin_controller() -> Result<(), String> {
     if is_admin_controller(caller, &controllers) {
         Ok(())
     } else {
-        Err("Caller is not an admin controller of the satellite.".to_string())
+        Err(request_error(
+            "Caller is not an admin controller of the satellite.",
+        ))
     }
 }
 
@@ -21,6 +28,41 @@ pub fn caller_is_controller() -> Result<(), String> {
     if is_controller(caller, &controllers) {
         Ok(())
     } else {
-        Err("Caller is not a controller of the satellite.".to_string())
+        Err(request_error(
+            "Caller is not a controller of the satellite.",
+        ))
     }
 }
+
+/// The controllers' endpoints that change the state of the satellite are blocked while it is in safe mode.
+pub fn caller_is_admin_controller_and_not_safe_mode() -> Result<(), String> {
+    caller_is_admin_controller()?;
+    assert_not_safe_mode().map_err(|e| request_error(&e))
+}
+
+pub fn caller_is_controller_and_not_safe_mode() -> Result<(), String> {
+    caller_is_controller()?;
+    assert_not_safe_mode().map_err(|e| request_error(&e))
+}
+
+pub fn caller_is_within_rate_limit() -> Result<(), String> {
+    assert_rate_limit(caller()).map_err(|e| request_error(&e))
+}
+
+/// Leaving the safe mode requires an admin controller. If the self-test found that there is none, the controllers of
+/// the canister - who can upgrade it anyway - acknowledge instead, otherwise the satellite could never leave it.
+pub fn caller_can_acknowledge_safe_mode() -> Result<(), String> {
+    let caller = caller();
+
+    if is_safe_mode_without_admin() && is_canister_controller(&caller) {
+        return Ok(());
+    }
+
+    caller_is_admin_controller()
+}
+
+/// The writes of the datastore and the storage are blocked while the satellite is in safe mode.
+pub fn caller_can_write() -> Result<(), String> {
+    assert_not_safe_mode().map_err(|e| request_error(&e))?;
+    assert_rate_limit(caller()).map_err(|e| request_error(&e))
+}
@@ -0,0 +1,53 @@
+use crate::version::SATELLITE_VERSION;
+use junobuild_shared::types::core::Blob;
+use junobuild_storage::certification::types::certified::CertifiedAssetHashes;
+use junobuild_storage::http::response::build_static_response;
+use junobuild_storage::http::types::{HeaderField, HttpRequest, HttpResponse};
+
+pub const HEALTH_PATH: &str = "/_juno/health";
+
+pub fn is_health_request(HttpRequest { method, url, .. }: &HttpRequest) -> bool {
+    let path = url.split('?').next().unwrap_or_default();
+
+    (method == "GET" || method == "HEAD") && path == HEALTH_PATH
+}
+
+/// Only the liveness of the satellite and its version are served over HTTP. Those do not change until the next upgrade,
+/// therefore the response is certified and answered with a query. The detailed report - e.g. cycles and memory - is
+/// reserved to the controllers with the `health` endpoint.
+pub fn http_request(
+    HttpRequest {
+        certificate_version,
+        ..
+    }: HttpRequest,
+) -> HttpResponse {
+    build_static_response(
+        HEALTH_PATH,
+        certificate_version,
+        &health_headers(),
+        &health_body(),
+    )
+}
+
+pub fn certify_health(asset_hashes: &mut CertifiedAssetHashes) {
+    asset_hashes.insert_static_v2(&HEALTH_PATH.to_string(), &health_headers(), &health_body());
+}
+
+fn health_headers() -> Vec<HeaderField> {
+    Vec::from([
+        HeaderField(
+            "Content-Type".to_string(),
+            "application/json; charset=utf-8".to_string(),
+        ),
+        HeaderField("Cache-Control".to_string(), "no-cache".to_string()),
+    ])
+}
+
+fn health_body() -> Blob {
+    serde_json::json!({
+        "status": "ok",
+        "version": SATELLITE_VERSION,
+    })
+    .to_string()
+    .into_bytes()
+}
@@ -0,0 +1,4 @@
+pub mod http;
+mod runtime;
+pub mod store;
+pub mod types;
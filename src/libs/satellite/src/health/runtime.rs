@@ -0,0 +1,20 @@
+use crate::health::types::state::HealthRuntimeState;
+use crate::memory::STATE;
+use ic_cdk::api::time;
+
+pub fn get_health() -> HealthRuntimeState {
+    STATE.with(|state| state.borrow().runtime.health.clone())
+}
+
+pub fn set_upgraded() {
+    STATE.with(|state| {
+        let health = &mut state.borrow_mut().runtime.health;
+
+        health.upgraded_at = Some(time());
+        health.certification_pending = true;
+    })
+}
+
+pub fn set_certification_completed() {
+    STATE.with(|state| state.borrow_mut().runtime.health.certification_pending = false)
+}
@@ -0,0 +1,45 @@
+use crate::gc::store::{count_pending_chunks_store, count_pending_docs_store};
+use crate::health::runtime::{
+    get_health as get_runtime_health,
+    set_certification_completed as set_runtime_certification_completed,
+    set_upgraded as set_runtime_upgraded,
+};
+use crate::health::types::interface::Health;
+use crate::health::types::state::HealthRuntimeState;
+use crate::safe_mode::store::is_safe_mode;
+use crate::version::SATELLITE_VERSION;
+use ic_cdk::api::{canister_balance128, time};
+use junobuild_shared::canister::memory_size;
+use junobuild_storage::runtime::count_batches;
+
+pub fn get_health() -> Health {
+    let HealthRuntimeState {
+        upgraded_at,
+        certification_pending,
+    } = get_runtime_health();
+
+    Health {
+        version: SATELLITE_VERSION.to_string(),
+        memory_size: memory_size(),
+        cycles: canister_balance128(),
+        pending_upload_batches: count_batches() as u64,
+        certification_pending,
+        safe_mode: is_safe_mode(),
+        pending_deleted_chunks: count_pending_chunks_store() as u64,
+        pending_deleted_docs: count_pending_docs_store() as u64,
+        upgraded_at,
+        timestamp: time(),
+    }
+}
+
+pub fn set_upgraded() {
+    set_runtime_upgraded();
+}
+
+pub fn set_certification_completed() {
+    set_runtime_certification_completed();
+}
+
+pub fn is_certification_pending() -> bool {
+    get_runtime_health().certification_pending
+}
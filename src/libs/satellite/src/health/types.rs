@@ -0,0 +1,30 @@
+pub mod state {
+    use junobuild_shared::types::state::Timestamp;
+
+    #[derive(Default, Clone)]
+    pub struct HealthRuntimeState {
+        pub upgraded_at: Option<Timestamp>,
+        pub certification_pending: bool,
+    }
+}
+
+pub mod interface {
+    use candid::CandidType;
+    use junobuild_shared::types::interface::MemorySize;
+    use junobuild_shared::types::state::Timestamp;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct Health {
+        pub version: String,
+        pub memory_size: MemorySize,
+        pub cycles: u128,
+        pub pending_upload_batches: u64,
+        pub certification_pending: bool,
+        pub safe_mode: bool,
+        pub pending_deleted_chunks: u64,
+        pub pending_deleted_docs: u64,
+        pub upgraded_at: Option<Timestamp>,
+        pub timestamp: Timestamp,
+    }
+}
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 use crate::db::types::state::{Doc, DocAssertDelete, DocAssertSet, DocContext, DocUpsert};
+use crate::events::types::interface::SatelliteEvent;
 use crate::types::hooks::{
     AssertDeleteAssetContext, AssertDeleteDocContext, AssertSetDocContext,
     AssertUploadAssetContext, OnDeleteAssetContext, OnDeleteDocContext,
@@ -54,6 +55,32 @@ extern "Rust" {
 
     fn juno_on_init();
     fn juno_on_post_upgrade();
+
+    fn juno_satellite_extension_candid() -> String;
+}
+
+/// The subscription of the hooks to the events of the satellite.
+pub fn invoke_on_event(caller: &UserId, event: &SatelliteEvent) {
+    match event {
+        SatelliteEvent::SetDoc(doc) => invoke_on_set_doc(caller, doc),
+        SatelliteEvent::SetManyDocs(docs) => invoke_on_set_many_docs(caller, docs),
+        SatelliteEvent::DeleteDoc(doc) => invoke_on_delete_doc(caller, doc),
+        SatelliteEvent::DeleteManyDocs(docs) => invoke_on_delete_many_docs(caller, docs),
+        SatelliteEvent::DeleteFilteredDocs(docs) => invoke_on_delete_filtered_docs(caller, docs),
+        SatelliteEvent::UploadAsset(asset) => invoke_upload_asset(caller, asset),
+        SatelliteEvent::DeleteAsset(asset) => invoke_on_delete_asset(caller, asset),
+        SatelliteEvent::DeleteManyAssets(assets) => invoke_on_delete_many_assets(caller, assets),
+        SatelliteEvent::DeleteFilteredAssets(assets) => {
+            invoke_on_delete_filtered_assets(caller, assets)
+        }
+        // There are no hooks for the configuration
+        SatelliteEvent::SetAuthConfig(_)
+        | SatelliteEvent::SetDbConfig(_)
+        | SatelliteEvent::SetStorageConfig(_)
+        | SatelliteEvent::ApplyConfigBundle(_)
+        | SatelliteEvent::SetRule { .. }
+        | SatelliteEvent::DeleteRule { .. } => (),
+    }
 }
 
 #[allow(unused_variables)]
@@ -63,6 +90,7 @@ pub fn invoke_on_set_doc(caller: &UserId, doc: &DocContext<DocUpsert>) {
         let context: OnSetDocContext = OnSetDocContext {
             caller: *caller,
             data: doc.clone(),
+            request_id: crate::requests::store::get_request_id(),
         };
 
         unsafe {
@@ -70,6 +98,8 @@ pub fn invoke_on_set_doc(caller: &UserId, doc: &DocContext<DocUpsert>) {
 
             if should_invoke_doc_hook(collections, &context) {
                 set_timer(Duration::ZERO, || {
+                    let _request = crate::requests::store::resume_request(&context.request_id);
+
                     juno_on_set_doc(context);
                 });
             }
@@ -90,9 +120,12 @@ pub fn invoke_on_set_many_docs(caller: &UserId, docs: &[DocContext<DocUpsert>])
                 let context: OnSetManyDocsContext = OnSetManyDocsContext {
                     caller: *caller,
                     data: filtered_docs.clone(),
+                    request_id: crate::requests::store::get_request_id(),
                 };
 
                 set_timer(Duration::ZERO, || {
+                    let _request = crate::requests::store::resume_request(&context.request_id);
+
                     juno_on_set_many_docs(context);
                 });
             }
@@ -107,6 +140,7 @@ pub fn invoke_on_delete_doc(caller: &UserId, doc: &DocContext<Option<Doc>>) {
         let context: OnDeleteDocContext = OnDeleteDocContext {
             caller: *caller,
             data: doc.clone(),
+            request_id: crate::requests::store::get_request_id(),
         };
 
         unsafe {
@@ -114,6 +148,8 @@ pub fn invoke_on_delete_doc(caller: &UserId, doc: &DocContext<Option<Doc>>) {
 
             if should_invoke_doc_hook(collections, &context) {
                 set_timer(Duration::ZERO, || {
+                    let _request = crate::requests::store::resume_request(&context.request_id);
+
                     juno_on_delete_doc(context);
                 });
             }
@@ -134,9 +170,12 @@ pub fn invoke_on_delete_many_docs(caller: &UserId, docs: &[DocContext<Option<Doc
                 let context: OnDeleteManyDocsContext = OnDeleteManyDocsContext {
                     caller: *caller,
                     data: filtered_docs.clone(),
+                    request_id: crate::requests::store::get_request_id(),
                 };
 
                 set_timer(Duration::ZERO, || {
+                    let _request = crate::requests::store::resume_request(&context.request_id);
+
                     juno_on_delete_many_docs(context);
                 });
             }
@@ -157,9 +196,12 @@ pub fn invoke_on_delete_filtered_docs(caller: &UserId, docs: &[DocContext<Option
                 let context: OnDeleteFilteredDocsContext = OnDeleteFilteredDocsContext {
                     caller: *caller,
                     data: filtered_docs.clone(),
+                    request_id: crate::requests::store::get_request_id(),
                 };
 
                 set_timer(Duration::ZERO, || {
+                    let _request = crate::requests::store::resume_request(&context.request_id);
+
                     juno_on_delete_filtered_docs(context);
                 });
             }
@@ -179,6 +221,7 @@ pub fn invoke_upload_asset(caller: &UserId, asset: &Asset) {
         let context: OnUploadAssetContext = OnUploadAssetContext {
             caller: *caller,
             data: asset.clone(),
+            request_id: crate::requests::store::get_request_id(),
         };
 
         unsafe {
@@ -186,6 +229,8 @@ pub fn invoke_upload_asset(caller: &UserId, asset: &Asset) {
 
             if should_invoke_asset_hook(collections, &context.data.key.collection) {
                 set_timer(Duration::ZERO, || {
+                    let _request = crate::requests::store::resume_request(&context.request_id);
+
                     juno_on_upload_asset(context);
                 });
             }
@@ -213,9 +258,12 @@ pub fn invoke_on_delete_asset(caller: &UserId, asset: &Option<Asset>) {
                 let context: OnDeleteAssetContext = OnDeleteAssetContext {
                     caller: *caller,
                     data: asset.clone(),
+                    request_id: crate::requests::store::get_request_id(),
                 };
 
                 set_timer(Duration::ZERO, || {
+                    let _request = crate::requests::store::resume_request(&context.request_id);
+
                     juno_on_delete_asset(context);
                 });
             }
@@ -236,9 +284,12 @@ pub fn invoke_on_delete_many_assets(caller: &UserId, assets: &[Option<Asset>]) {
                 let context: OnDeleteManyAssetsContext = OnDeleteManyAssetsContext {
                     caller: *caller,
                     data: filtered_assets.clone(),
+                    request_id: crate::requests::store::get_request_id(),
                 };
 
                 set_timer(Duration::ZERO, || {
+                    let _request = crate::requests::store::resume_request(&context.request_id);
+
                     juno_on_delete_many_assets(context);
                 });
             }
@@ -259,9 +310,12 @@ pub fn invoke_on_delete_filtered_assets(caller: &UserId, assets: &[Option<Asset>
                 let context: OnDeleteFilteredAssetsContext = OnDeleteFilteredAssetsContext {
                     caller: *caller,
                     data: filtered_assets.clone(),
+                    request_id: crate::requests::store::get_request_id(),
                 };
 
                 set_timer(Duration::ZERO, || {
+                    let _request = crate::requests::store::resume_request(&context.request_id);
+
                     juno_on_delete_filtered_assets(context);
                 });
             }
@@ -279,6 +333,7 @@ pub fn invoke_assert_set_doc(
         let context: AssertSetDocContext = AssertSetDocContext {
             caller: *caller,
             data: doc.clone(),
+            request_id: crate::requests::store::get_request_id(),
         };
 
         unsafe {
@@ -303,6 +358,7 @@ pub fn invoke_assert_delete_doc(
         let context: AssertDeleteDocContext = AssertDeleteDocContext {
             caller: *caller,
             data: doc.clone(),
+            request_id: crate::requests::store::get_request_id(),
         };
 
         unsafe {
@@ -332,6 +388,7 @@ pub fn invoke_assert_upload_asset(
         let context: AssertUploadAssetContext = AssertUploadAssetContext {
             caller: *caller,
             data: asset.clone(),
+            request_id: crate::requests::store::get_request_id(),
         };
 
         unsafe {
@@ -358,6 +415,7 @@ pub fn invoke_assert_delete_asset(caller: &UserId, asset: &Asset) -> Result<(),
         let context: AssertDeleteAssetContext = AssertDeleteAssetContext {
             caller: *caller,
             data: asset.clone(),
+            request_id: crate::requests::store::get_request_id(),
         };
 
         unsafe {
@@ -396,6 +454,12 @@ pub fn invoke_on_post_upgrade() {
     }
 }
 
+/// The interface of the custom endpoints of the serverless functions, which is generated by `include_satellite!` in
+/// the crate of the developer.
+pub fn invoke_satellite_extension_candid() -> String {
+    unsafe { juno_satellite_extension_candid() }
+}
+
 fn should_invoke_doc_hook<T>(
     collections: Option<Vec<String>>,
     context: &HookContext<DocContext<T>>,
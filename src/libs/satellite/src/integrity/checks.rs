@@ -0,0 +1,186 @@
+use crate::db::state::{count_docs_heap, count_docs_stable};
+use crate::integrity::types::interface::IntegrityIssue;
+use crate::storage::state::iter_assets_stable;
+use crate::storage::types::state::{ContentChunksStable, StableEncodingChunkKey};
+use crate::types::interface::RulesType;
+use crate::types::state::State;
+use ic_cdk::api::time;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::Memory;
+use junobuild_shared::serializers::deserialize_from_bytes;
+use junobuild_shared::utils::is_published;
+use junobuild_storage::constants::ENCODING_CERTIFICATION_ORDER;
+use junobuild_storage::heap_utils::collect_assets_heap;
+use junobuild_storage::types::store::{Asset, AssetEncoding};
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+pub fn check_docs(collection: &CollectionKey, state: &State) -> Vec<IntegrityIssue> {
+    let rule = match state.heap.db.rules.get(collection) {
+        None => return Vec::new(),
+        Some(rule) => rule,
+    };
+
+    let found = match rule.mem() {
+        Memory::Heap => count_docs_heap(collection, &state.heap.db.db).unwrap_or_default(),
+        Memory::Stable => count_docs_stable(collection, &state.stable.db).unwrap_or_default(),
+    } as u64;
+
+    let counted = state
+        .heap
+        .stats
+        .as_ref()
+        .and_then(|stats| stats.db.get(collection))
+        .map_or(0, |counters| counters.items_count);
+
+    if counted == found {
+        return Vec::new();
+    }
+
+    Vec::from([IntegrityIssue::DocsCount {
+        collection: collection.clone(),
+        counted,
+        found,
+    }])
+}
+
+/// Compares the number of assets with the statistics, then verifies the chunks of each encoding and that each
+/// published asset is certified. The alternative paths are not verified given that they do not hold content.
+pub fn check_assets(
+    collection: &CollectionKey,
+    certified_urls: &HashSet<String>,
+    state: &State,
+) -> Vec<IntegrityIssue> {
+    let rule = match state.heap.storage.rules.get(collection) {
+        None => return Vec::new(),
+        Some(rule) => rule,
+    };
+
+    let mut issues: Vec<IntegrityIssue> = Vec::new();
+
+    let mut found: u64 = 0;
+
+    let mut check_asset = |asset: &Asset, memory: &Memory| {
+        found += 1;
+
+        let chunks_valid = asset.encodings.values().all(|encoding| match memory {
+            Memory::Heap => is_heap_encoding_valid(encoding),
+            Memory::Stable => is_stable_encoding_valid(encoding, &state.stable.content_chunks),
+        });
+
+        if !chunks_valid {
+            issues.push(IntegrityIssue::MissingContentChunks {
+                collection: collection.clone(),
+                full_path: asset.key.full_path.clone(),
+            });
+        }
+
+        if is_certifiable(asset) && !certified_urls.contains(&asset.key.full_path) {
+            issues.push(IntegrityIssue::UncertifiedAsset {
+                collection: collection.clone(),
+                full_path: asset.key.full_path.clone(),
+            });
+        }
+    };
+
+    match rule.mem() {
+        Memory::Heap => {
+            for (_, asset) in collect_assets_heap(collection, &state.heap.storage.assets) {
+                check_asset(asset, &Memory::Heap);
+            }
+        }
+        Memory::Stable => {
+            for (_, asset) in iter_assets_stable(collection, &state.stable.assets) {
+                check_asset(&asset, &Memory::Stable);
+            }
+        }
+    }
+
+    let counted = state
+        .heap
+        .stats
+        .as_ref()
+        .and_then(|stats| stats.storage.get(collection))
+        .map_or(0, |counters| counters.items_count);
+
+    if counted != found {
+        issues.insert(
+            0,
+            IntegrityIssue::AssetsCount {
+                collection: collection.clone(),
+                counted,
+                found,
+            },
+        );
+    }
+
+    issues
+}
+
+/// The documents and assets on the heap must belong to a collection with a rule. The stable memories are not
+/// scanned given that their items are only reachable through the ranges of the collections with a rule.
+pub fn check_rules(state: &State) -> Vec<IntegrityIssue> {
+    let db_collections = state
+        .heap
+        .db
+        .db
+        .iter()
+        .filter(|(_, docs)| !docs.is_empty())
+        .map(|(collection, _)| collection);
+
+    let mut issues: Vec<IntegrityIssue> = db_collections
+        .filter(|collection| !state.heap.db.rules.contains_key(*collection))
+        .map(|collection| IntegrityIssue::MissingRule {
+            rules_type: RulesType::Db,
+            collection: collection.clone(),
+        })
+        .collect();
+
+    let storage_collections: HashSet<&CollectionKey> = state
+        .heap
+        .storage
+        .assets
+        .values()
+        .map(|asset| &asset.key.collection)
+        .filter(|collection| !state.heap.storage.rules.contains_key(*collection))
+        .collect();
+
+    issues.extend(
+        storage_collections
+            .into_iter()
+            .map(|collection| IntegrityIssue::MissingRule {
+                rules_type: RulesType::Storage,
+                collection: collection.clone(),
+            }),
+    );
+
+    issues
+}
+
+fn is_heap_encoding_valid(encoding: &AssetEncoding) -> bool {
+    let length: u128 = encoding
+        .content_chunks
+        .iter()
+        .map(|chunk| chunk.len() as u128)
+        .sum();
+
+    length == encoding.total_length
+}
+
+fn is_stable_encoding_valid(
+    encoding: &AssetEncoding,
+    content_chunks: &ContentChunksStable,
+) -> bool {
+    encoding.content_chunks.iter().all(|chunk| {
+        let key: StableEncodingChunkKey = deserialize_from_bytes(Cow::Borrowed(chunk));
+        content_chunks.contains_key(&key)
+    })
+}
+
+// Same conditions as the insertion of an asset in the certification tree
+fn is_certifiable(asset: &Asset) -> bool {
+    is_published(asset, time())
+        && ENCODING_CERTIFICATION_ORDER
+            .iter()
+            .any(|encoding_type| asset.encodings.contains_key(*encoding_type))
+}
@@ -0,0 +1,5 @@
+// The issues found beyond this number are only counted, so that a badly broken state does not fill the heap
+pub const INTEGRITY_MAX_ISSUES: usize = 100;
+
+// The delay before the checks of the assets are tried again while the certification is still being built
+pub const INTEGRITY_CERTIFICATION_RETRY_SECS: u64 = 1;
@@ -0,0 +1,5 @@
+mod checks;
+mod constants;
+mod state;
+pub mod store;
+pub mod types;
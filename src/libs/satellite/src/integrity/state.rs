@@ -0,0 +1,56 @@
+use crate::integrity::constants::INTEGRITY_MAX_ISSUES;
+use crate::integrity::types::interface::{IntegrityIssue, IntegrityReport};
+use crate::integrity::types::state::IntegrityStep;
+use crate::memory::STATE;
+use std::collections::VecDeque;
+
+pub fn get_report() -> Option<IntegrityReport> {
+    STATE.with(|state| state.borrow().runtime.integrity.report.clone())
+}
+
+pub fn init_report(report: IntegrityReport, steps: VecDeque<IntegrityStep>) {
+    STATE.with(|state| {
+        let integrity = &mut state.borrow_mut().runtime.integrity;
+
+        integrity.report = Some(report);
+        integrity.pending = steps;
+    })
+}
+
+pub fn next_step() -> Option<IntegrityStep> {
+    STATE.with(|state| state.borrow_mut().runtime.integrity.pending.pop_front())
+}
+
+pub fn postpone_step(step: IntegrityStep) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .runtime
+            .integrity
+            .pending
+            .push_front(step)
+    })
+}
+
+pub fn insert_issues(issues: Vec<IntegrityIssue>, collection_checked: bool) {
+    STATE.with(|state| {
+        if let Some(report) = &mut state.borrow_mut().runtime.integrity.report {
+            report.issues_count += issues.len() as u64;
+
+            let available = INTEGRITY_MAX_ISSUES.saturating_sub(report.issues.len());
+            report.issues.extend(issues.into_iter().take(available));
+
+            if collection_checked {
+                report.checked_collections += 1;
+            }
+        }
+    })
+}
+
+pub fn complete_report(completed_at: u64) {
+    STATE.with(|state| {
+        if let Some(report) = &mut state.borrow_mut().runtime.integrity.report {
+            report.completed_at = Some(completed_at);
+        }
+    })
+}
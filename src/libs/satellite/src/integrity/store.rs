@@ -0,0 +1,92 @@
+use crate::health::store::is_certification_pending;
+use crate::integrity::checks::{check_assets, check_docs, check_rules};
+use crate::integrity::constants::INTEGRITY_CERTIFICATION_RETRY_SECS;
+use crate::integrity::state::{
+    complete_report, get_report, init_report, insert_issues, next_step, postpone_step,
+};
+use crate::integrity::types::interface::IntegrityReport;
+use crate::integrity::types::state::IntegrityStep;
+use crate::memory::STATE;
+use crate::version::SATELLITE_VERSION;
+use ic_cdk::api::time;
+use ic_cdk_timers::set_timer;
+use junobuild_storage::runtime::list_certified_paths;
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+pub fn get_integrity_report_store() -> Option<IntegrityReport> {
+    get_report()
+}
+
+/// Schedules the checks of the state restored by the post_upgrade hook. Each collection is checked in its own timer,
+/// and the assets only once the certification - also built in the background - is completed.
+pub fn defer_integrity_checks() {
+    let steps: VecDeque<IntegrityStep> = STATE.with(|state| {
+        let state = state.borrow();
+
+        let docs = state.heap.db.rules.keys().cloned().map(IntegrityStep::Docs);
+        let assets = state
+            .heap
+            .storage
+            .rules
+            .keys()
+            .cloned()
+            .map(IntegrityStep::Assets);
+
+        docs.chain(assets).chain([IntegrityStep::Rules]).collect()
+    });
+
+    init_report(
+        IntegrityReport {
+            version: SATELLITE_VERSION.to_string(),
+            started_at: time(),
+            completed_at: None,
+            checked_collections: 0,
+            issues_count: 0,
+            issues: Vec::new(),
+        },
+        steps,
+    );
+
+    set_timer(Duration::ZERO, run_next_step);
+}
+
+fn run_next_step() {
+    let step = match next_step() {
+        None => {
+            complete_report(time());
+            return;
+        }
+        Some(step) => step,
+    };
+
+    if matches!(step, IntegrityStep::Assets(_)) && is_certification_pending() {
+        postpone_step(step);
+        set_timer(
+            Duration::from_secs(INTEGRITY_CERTIFICATION_RETRY_SECS),
+            run_next_step,
+        );
+        return;
+    }
+
+    let (issues, collection_checked) = STATE.with(|state| {
+        let state = state.borrow();
+
+        match &step {
+            IntegrityStep::Docs(collection) => (check_docs(collection, &state), true),
+            IntegrityStep::Assets(collection) => {
+                let certified_urls: HashSet<String> = list_certified_paths()
+                    .into_iter()
+                    .map(|path| path.url)
+                    .collect();
+
+                (check_assets(collection, &certified_urls, &state), true)
+            }
+            IntegrityStep::Rules => (check_rules(&state), false),
+        }
+    });
+
+    insert_issues(issues, collection_checked);
+
+    set_timer(Duration::ZERO, run_next_step);
+}
@@ -0,0 +1,74 @@
+pub mod state {
+    use crate::integrity::types::interface::IntegrityReport;
+    use junobuild_collections::types::core::CollectionKey;
+    use std::collections::VecDeque;
+
+    #[derive(Default, Clone)]
+    pub struct IntegrityRuntimeState {
+        pub report: Option<IntegrityReport>,
+        pub pending: VecDeque<IntegrityStep>,
+    }
+
+    /// A unit of work of the checks, performed in its own timer so that the instructions of a single message remain
+    /// bounded by the size of a collection.
+    #[derive(Clone)]
+    pub enum IntegrityStep {
+        Docs(CollectionKey),
+        Assets(CollectionKey),
+        Rules,
+    }
+}
+
+pub mod interface {
+    use crate::types::interface::RulesType;
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::state::Timestamp;
+    use junobuild_storage::types::state::FullPath;
+    use serde::{Deserialize, Serialize};
+
+    /// The outcome of the checks performed after the last upgrade.
+    ///
+    /// - `completed_at`: `None` while the checks are still running.
+    /// - `issues`: The first issues found. The others are only counted in `issues_count`.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct IntegrityReport {
+        pub version: String,
+        pub started_at: Timestamp,
+        pub completed_at: Option<Timestamp>,
+        pub checked_collections: u64,
+        pub issues_count: u64,
+        pub issues: Vec<IntegrityIssue>,
+    }
+
+    /// - `DocsCount` and `AssetsCount`: The number of items maintained by the statistics differs from the number of
+    ///   items actually found in the collection.
+    /// - `MissingRule`: Items were found for a collection without rule.
+    /// - `MissingContentChunks`: The chunks of an encoding of an asset are missing or do not sum up to its length.
+    /// - `UncertifiedAsset`: A published asset is not part of the certification tree and cannot be served.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum IntegrityIssue {
+        DocsCount {
+            collection: CollectionKey,
+            counted: u64,
+            found: u64,
+        },
+        AssetsCount {
+            collection: CollectionKey,
+            counted: u64,
+            found: u64,
+        },
+        MissingRule {
+            rules_type: RulesType,
+            collection: CollectionKey,
+        },
+        MissingContentChunks {
+            collection: CollectionKey,
+            full_path: FullPath,
+        },
+        UncertifiedAsset {
+            collection: CollectionKey,
+            full_path: FullPath,
+        },
+    }
+}
@@ -1,45 +1,133 @@
 #![doc = include_str!("../README.md")]
 
+mod analytics;
+mod api_keys;
+mod approvals;
+mod audit;
 mod auth;
+mod bundle;
+mod changes;
+mod client;
+mod compression;
+mod consent;
 mod controllers;
 mod db;
+mod defrag;
+mod deployment;
+mod dry_run;
+mod encryption;
+mod events;
+mod extensions;
+mod flags;
+mod gates;
+mod gc;
 mod guards;
+mod health;
 mod hooks;
 mod impls;
+mod integrity;
+mod links;
 mod logs;
+mod maintenance;
+mod manifest;
 mod memory;
+mod moderation;
+mod ownership;
+mod query;
+mod quotas;
 mod random;
+mod rate_limit;
+mod requests;
+mod retention;
+mod revisions;
 mod rules;
+mod safe_mode;
 mod satellite;
+mod stats;
 mod storage;
+mod traces;
+mod transfer;
 mod types;
+mod uploads;
+mod users;
 mod version;
-
+mod webhooks;
+
+use crate::analytics::types::config::AnalyticsConfig;
+use crate::api_keys::types::interface::{CreateApiKeyResult, SetApiKey};
+use crate::api_keys::types::state::{ApiKey, ApiKeyId};
+use crate::approvals::types::config::ApprovalsConfig;
+use crate::approvals::types::state::{ActionId, ControllerAction, PendingAction};
+use crate::audit::types::interface::{AuditBlocks, ListAuditBlocks};
 use crate::auth::types::config::AuthenticationConfig;
+use crate::bundle::types::interface::ConfigBundle;
+use crate::client::types::interface::ClientMetadata;
+use crate::consent::types::interface::{
+    Icrc21ConsentMessageRequest, Icrc21ConsentMessageResponse, SupportedStandard,
+};
 use crate::db::types::config::DbConfig;
-use crate::guards::{caller_is_admin_controller, caller_is_controller};
+use crate::db::types::interface::DocChunk;
+use crate::defrag::types::interface::{CompactionReport, MemoryRegionStats};
+use crate::deployment::types::interface::{CanaryDeploymentStatus, SetCanaryDeployment};
+use crate::dry_run::types::interface::{DryRunOperation, DryRunReport};
+use crate::extensions::types::interface::SetExtensionConfig;
+use crate::extensions::types::state::{Extension, ExtensionKey};
+use crate::flags::types::interface::{DelFeatureFlag, SetFeatureFlag};
+use crate::flags::types::state::{FeatureFlag, FeatureFlagKey};
+use crate::guards::{
+    caller_can_acknowledge_safe_mode, caller_can_write, caller_is_admin_controller,
+    caller_is_admin_controller_and_not_safe_mode, caller_is_controller,
+    caller_is_controller_and_not_safe_mode, caller_is_within_rate_limit,
+};
+use crate::health::types::interface::Health;
+use crate::integrity::types::interface::IntegrityReport;
+use crate::links::types::interface::AssetLinksReport;
+use crate::maintenance::types::interface::CollectionJobReport;
+use crate::moderation::types::config::ModerationConfig;
+use crate::ownership::types::interface::{SetAssetOwner, SetDocOwner};
+use crate::query::types::interface::QueryEstimate;
+use crate::quotas::types::config::QuotaThresholds;
+use crate::rate_limit::types::config::RateLimitConfig;
+use crate::requests::store::{request_error, start_request};
+use crate::safe_mode::types::interface::SelfTestReport;
+use crate::stats::types::interface::CollectionStats;
+use crate::storage::types::interface::DiffAssetsArgs;
+use crate::traces::types::config::TracesConfig;
+use crate::traces::types::interface::DailyEndpointTrace;
 use crate::types::interface::{Config, RulesType};
+use crate::uploads::types::interface::{CreateUploadTokenResult, SetUploadToken};
+use crate::uploads::types::state::{UploadToken, UploadTokenId};
+use crate::users::types::interface::{ImportUsers, ImportUsersReport};
 use crate::version::SATELLITE_VERSION;
+use crate::webhooks::types::interface::{SetWebhook, WebhookNoSecret};
+use crate::webhooks::types::state::{WebhookDelivery, WebhookDeliveryId, WebhookId};
+use ic_cdk::api::management_canister::http_request::{
+    HttpResponse as HttpOutcallResponse, TransformArgs,
+};
 use ic_cdk::api::trap;
 use ic_cdk_macros::{init, post_upgrade, pre_upgrade, query, update};
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::interface::{DelRule, SetRule};
-use junobuild_collections::types::rules::Rule;
+use junobuild_collections::types::rules::{Compression, Rule};
 use junobuild_shared::types::core::DomainName;
 use junobuild_shared::types::core::{Blob, Key};
 use junobuild_shared::types::domain::CustomDomains;
 use junobuild_shared::types::interface::{
-    DeleteControllersArgs, DepositCyclesArgs, MemorySize, SetControllersArgs,
+    DeleteControllersArgs, DepositCyclesArgs, ExportChunk, ExportChunkArgs, ImportChunkArgs,
+    MemorySize, QuotaStatus, SetControllersArgs,
 };
+use junobuild_shared::types::list::CollectionVersion;
 use junobuild_shared::types::list::ListParams;
 use junobuild_shared::types::list::ListResults;
-use junobuild_shared::types::state::Controllers;
+use junobuild_shared::types::list::{ListPaginate, Page};
+use junobuild_shared::types::state::{Controller, ControllerId, Controllers, Timestamp};
 use junobuild_storage::http::types::{
     HttpRequest, HttpResponse, StreamingCallbackHttpResponse, StreamingCallbackToken,
 };
 use junobuild_storage::types::config::StorageConfig;
 use junobuild_storage::types::interface::{
-    AssetNoContent, CommitBatch, InitAssetKey, InitUploadResult, UploadChunk, UploadChunkResult,
+    AssetNoContent, CertifiedPath, CommitBatch, InitAssetKey, InitUploadResult, UploadChunk,
+    UploadChunkResult,
 };
 use junobuild_storage::types::state::FullPath;
 
@@ -51,15 +139,25 @@ use junobuild_storage::types::state::FullPath;
 pub use crate::controllers::store::{get_admin_controllers, get_controllers};
 pub use crate::db::store::{
     count_collection_docs_store, count_docs_store, delete_doc_store, delete_docs_store,
-    delete_filtered_docs_store, get_doc_store, list_docs_store, set_doc_store,
+    delete_filtered_docs_store, get_doc_store, list_docs_store, patch_doc_store, set_doc_store,
 };
-pub use crate::db::types::interface::{DelDoc, SetDoc};
+pub use crate::db::types::interface::{DelDoc, DocPatch, PatchDoc, SetDoc};
 pub use crate::db::types::state::Doc;
+pub use crate::encryption::store::{decrypt_blob_store, encrypt_blob_store};
+pub use crate::events::store::subscribe;
+pub use crate::events::types::interface::{EventSubscriber, OnEventContext, SatelliteEvent};
+pub use crate::extensions::store::{get_extension_settings_store, is_extension_enabled_store};
+pub use crate::extensions::types::state::ExtensionSettings;
+pub use crate::flags::store::evaluate_feature_flag_store;
 pub use crate::logs::loggers::{
     debug, debug_with_data, error, error_with_data, info, info_with_data, log, log_with_data, warn,
     warn_with_data,
 };
 pub use crate::logs::types::logs::{Log, LogLevel};
+pub use crate::moderation::store::{set_asset_moderation_store, set_doc_moderation_store};
+pub use crate::moderation::types::interface::SetModeration;
+pub use crate::requests::store::get_request_id;
+pub use crate::requests::types::state::RequestId;
 pub use crate::storage::handlers::set_asset_handler;
 pub use crate::storage::store::{
     count_assets_store, count_collection_assets_store, delete_asset_store, delete_assets_store,
@@ -71,6 +169,7 @@ pub use crate::types::hooks::{
     OnDeleteFilteredAssetsContext, OnDeleteFilteredDocsContext, OnDeleteManyAssetsContext,
     OnDeleteManyDocsContext, OnSetDocContext, OnSetManyDocsContext, OnUploadAssetContext,
 };
+pub use junobuild_shared::errors::JunoError;
 // ============================================================================================
 // END: Re-exported Types
 // ============================================================================================
@@ -102,11 +201,21 @@ pub fn post_upgrade() {
 // ---------------------------------------------------------
 
 #[doc(hidden)]
-#[update]
+#[update(guard = "caller_can_write")]
 pub fn set_doc(collection: CollectionKey, key: Key, doc: SetDoc) -> Doc {
+    let _request = start_request();
+
     satellite::set_doc(collection, key, doc)
 }
 
+#[doc(hidden)]
+#[update(guard = "caller_can_write")]
+pub fn patch_doc(collection: CollectionKey, key: Key, patch: PatchDoc) -> Doc {
+    let _request = start_request();
+
+    satellite::patch_doc(collection, key, patch)
+}
+
 #[doc(hidden)]
 #[query]
 pub fn get_doc(collection: CollectionKey, key: Key) -> Option<Doc> {
@@ -114,8 +223,22 @@ pub fn get_doc(collection: CollectionKey, key: Key) -> Option<Doc> {
 }
 
 #[doc(hidden)]
-#[update]
+#[query(guard = "caller_is_controller")]
+pub fn get_doc_at(collection: CollectionKey, key: Key, timestamp: Timestamp) -> Option<Doc> {
+    satellite::get_doc_at(collection, key, timestamp)
+}
+
+#[doc(hidden)]
+#[query]
+pub fn get_doc_chunk(collection: CollectionKey, key: Key, index: u64) -> Option<DocChunk> {
+    satellite::get_doc_chunk(collection, key, index)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_can_write")]
 pub fn del_doc(collection: CollectionKey, key: Key, doc: DelDoc) {
+    let _request = start_request();
+
     satellite::del_doc(collection, key, doc);
 }
 
@@ -125,6 +248,12 @@ pub fn list_docs(collection: CollectionKey, filter: ListParams) -> ListResults<D
     satellite::list_docs(collection, filter)
 }
 
+#[doc(hidden)]
+#[query]
+pub fn list_docs_page(collection: CollectionKey, filter: ListParams) -> Page<(Key, Doc)> {
+    satellite::list_docs_page(collection, filter)
+}
+
 #[doc(hidden)]
 #[query]
 pub fn count_docs(collection: CollectionKey, filter: ListParams) -> usize {
@@ -138,26 +267,42 @@ pub fn get_many_docs(docs: Vec<(CollectionKey, Key)>) -> Vec<(Key, Option<Doc>)>
 }
 
 #[doc(hidden)]
-#[update]
+#[update(guard = "caller_can_write")]
 pub fn set_many_docs(docs: Vec<(CollectionKey, Key, SetDoc)>) -> Vec<(Key, Doc)> {
+    let _request = start_request();
+
     satellite::set_many_docs(docs)
 }
 
 #[doc(hidden)]
-#[update]
+#[update(guard = "caller_can_write")]
 pub fn del_many_docs(docs: Vec<(CollectionKey, Key, DelDoc)>) {
+    let _request = start_request();
+
     satellite::del_many_docs(docs)
 }
 
 #[doc(hidden)]
-#[update]
+#[update(guard = "caller_can_write")]
 pub fn del_filtered_docs(collection: CollectionKey, filter: ListParams) {
+    let _request = start_request();
+
     satellite::del_filtered_docs(collection, filter)
 }
 
 #[doc(hidden)]
-#[update(guard = "caller_is_controller")]
+#[update(guard = "caller_can_write")]
+pub async fn verify_token_gate(collection: CollectionKey) -> bool {
+    let request = start_request();
+
+    satellite::verify_token_gate(collection, &request).await
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_controller_and_not_safe_mode")]
 pub fn del_docs(collection: CollectionKey) {
+    let _request = start_request();
+
     satellite::del_docs(collection)
 }
 
@@ -184,30 +329,385 @@ pub fn list_rules(rules_type: RulesType) -> Vec<(CollectionKey, Rule)> {
 }
 
 #[doc(hidden)]
-#[update(guard = "caller_is_admin_controller")]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
 pub fn set_rule(rules_type: RulesType, collection: CollectionKey, rule: SetRule) -> Rule {
+    let _request = start_request();
+
     satellite::set_rule(rules_type, collection, rule)
 }
 
 #[doc(hidden)]
-#[update(guard = "caller_is_admin_controller")]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn set_rule_compression(
+    rules_type: RulesType,
+    collection: CollectionKey,
+    compression: Option<Compression>,
+) -> Rule {
+    let _request = start_request();
+
+    satellite::set_rule_compression(rules_type, collection, compression)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
 pub fn del_rule(rules_type: RulesType, collection: CollectionKey, rule: DelRule) {
+    let _request = start_request();
+
     satellite::del_rule(rules_type, collection, rule)
 }
 
+// ---------------------------------------------------------
+// Dry run
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn dry_run(operation: DryRunOperation) -> DryRunReport {
+    satellite::dry_run(operation)
+}
+
+// ---------------------------------------------------------
+// Stats
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn get_collection_stats(collection: CollectionKey) -> CollectionStats {
+    satellite::get_collection_stats(collection)
+}
+
+#[doc(hidden)]
+#[query]
+pub fn get_collection_version(
+    rules_type: RulesType,
+    collection: CollectionKey,
+) -> Option<CollectionVersion> {
+    satellite::get_collection_version(&rules_type, &collection)
+}
+
+// ---------------------------------------------------------
+// Quotas
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn set_quota_thresholds(thresholds: QuotaThresholds) {
+    let _request = start_request();
+
+    satellite::set_quota_thresholds(thresholds);
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn get_quota_thresholds() -> Option<QuotaThresholds> {
+    satellite::get_quota_thresholds()
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn get_quota_status() -> Option<QuotaStatus> {
+    satellite::get_quota_status()
+}
+
+// ---------------------------------------------------------
+// Transfer
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn export_chunk(args: ExportChunkArgs) -> ExportChunk {
+    satellite::export_chunk(args)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn import_chunk(args: ImportChunkArgs) {
+    let _request = start_request();
+
+    satellite::import_chunk(args);
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn import_users(args: ImportUsers) -> ImportUsersReport {
+    let _request = start_request();
+
+    satellite::import_users(args)
+}
+
+// ---------------------------------------------------------
+// Query
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn estimate_query(
+    rules_type: RulesType,
+    collection: CollectionKey,
+    filter: ListParams,
+) -> QueryEstimate {
+    satellite::estimate_query(rules_type, collection, filter)
+}
+
+// ---------------------------------------------------------
+// Ownership
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_controller_and_not_safe_mode")]
+pub fn set_doc_owner(collection: CollectionKey, key: Key, owner: SetDocOwner) -> Doc {
+    let _request = start_request();
+
+    satellite::set_doc_owner(collection, key, owner)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_controller_and_not_safe_mode")]
+pub fn set_asset_owner(
+    collection: CollectionKey,
+    full_path: FullPath,
+    owner: SetAssetOwner,
+) -> AssetNoContent {
+    let _request = start_request();
+
+    satellite::set_asset_owner(collection, full_path, owner)
+}
+
+// ---------------------------------------------------------
+// Moderation
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn set_moderation_config(config: ModerationConfig) {
+    let _request = start_request();
+
+    satellite::set_moderation_config(config);
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn get_moderation_config() -> Option<ModerationConfig> {
+    satellite::get_moderation_config()
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_controller_and_not_safe_mode")]
+pub fn set_doc_moderation(collection: CollectionKey, key: Key, moderation: SetModeration) -> Doc {
+    let _request = start_request();
+
+    satellite::set_doc_moderation(collection, key, moderation)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_controller_and_not_safe_mode")]
+pub fn set_asset_moderation(
+    collection: CollectionKey,
+    full_path: FullPath,
+    moderation: SetModeration,
+) -> AssetNoContent {
+    let _request = start_request();
+
+    satellite::set_asset_moderation(collection, full_path, moderation)
+}
+
+// ---------------------------------------------------------
+// Approvals
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn set_approvals_config(config: ApprovalsConfig) {
+    let _request = start_request();
+
+    satellite::set_approvals_config(config);
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn get_approvals_config() -> Option<ApprovalsConfig> {
+    satellite::get_approvals_config()
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn propose_action(action: ControllerAction) -> ActionId {
+    let _request = start_request();
+
+    satellite::propose_action(action)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn confirm_action(id: ActionId) {
+    let _request = start_request();
+
+    satellite::confirm_action(id);
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn cancel_action(id: ActionId) {
+    let _request = start_request();
+
+    satellite::cancel_action(id);
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn list_pending_actions() -> Vec<(ActionId, PendingAction)> {
+    satellite::list_pending_actions()
+}
+
+// ---------------------------------------------------------
+// Traces
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn set_traces_config(config: TracesConfig) {
+    let _request = start_request();
+
+    satellite::set_traces_config(config);
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn get_traces_config() -> Option<TracesConfig> {
+    satellite::get_traces_config()
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn list_traces() -> Vec<DailyEndpointTrace> {
+    satellite::list_traces()
+}
+
+// ---------------------------------------------------------
+// Rate limit
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn set_rate_limit_config(config: Option<RateLimitConfig>) {
+    let _request = start_request();
+
+    satellite::set_rate_limit_config(config);
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn get_rate_limit_config() -> Option<RateLimitConfig> {
+    satellite::get_rate_limit_config()
+}
+
+// ---------------------------------------------------------
+// Uploads
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn create_upload_token(token: SetUploadToken) -> CreateUploadTokenResult {
+    let _request = start_request();
+
+    satellite::create_upload_token(token)
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn list_upload_tokens() -> Vec<(UploadTokenId, UploadToken)> {
+    satellite::list_upload_tokens()
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn del_upload_token(id: UploadTokenId) {
+    let _request = start_request();
+
+    satellite::del_upload_token(id);
+}
+
+// ---------------------------------------------------------
+// API keys
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn create_api_key(api_key: SetApiKey) -> CreateApiKeyResult {
+    let _request = start_request();
+
+    satellite::create_api_key(api_key)
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn list_api_keys() -> Vec<(ApiKeyId, ApiKey)> {
+    satellite::list_api_keys()
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn del_api_key(id: ApiKeyId) {
+    let _request = start_request();
+
+    satellite::del_api_key(id);
+}
+
+// ---------------------------------------------------------
+// Webhooks
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn set_webhook(id: WebhookId, webhook: SetWebhook) {
+    let _request = start_request();
+
+    satellite::set_webhook(id, webhook);
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn list_webhooks() -> Vec<(WebhookId, WebhookNoSecret)> {
+    satellite::list_webhooks()
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn del_webhook(id: WebhookId) {
+    let _request = start_request();
+
+    satellite::del_webhook(id);
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn list_webhook_deliveries() -> Vec<(WebhookDeliveryId, WebhookDelivery)> {
+    satellite::list_webhook_deliveries()
+}
+
+#[doc(hidden)]
+#[query]
+pub fn transform_webhook_response(args: TransformArgs) -> HttpOutcallResponse {
+    satellite::transform_webhook_response(args)
+}
+
 // ---------------------------------------------------------
 // Controllers
 // ---------------------------------------------------------
 
 #[doc(hidden)]
-#[update(guard = "caller_is_admin_controller")]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
 pub fn set_controllers(args: SetControllersArgs) -> Controllers {
+    let _request = start_request();
+
     satellite::set_controllers(args)
 }
 
 #[doc(hidden)]
-#[update(guard = "caller_is_admin_controller")]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
 pub fn del_controllers(args: DeleteControllersArgs) -> Controllers {
+    let _request = start_request();
+
     satellite::del_controllers(args)
 }
 
@@ -217,6 +717,12 @@ pub fn list_controllers() -> Controllers {
     satellite::list_controllers()
 }
 
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn list_controllers_page(paginate: ListPaginate) -> Page<(ControllerId, Controller)> {
+    satellite::list_controllers_page(paginate)
+}
+
 // ---------------------------------------------------------
 // Custom domains
 // ---------------------------------------------------------
@@ -228,17 +734,31 @@ pub fn list_custom_domains() -> CustomDomains {
 }
 
 #[doc(hidden)]
-#[update(guard = "caller_is_admin_controller")]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
 pub fn set_custom_domain(domain_name: DomainName, bn_id: Option<String>) {
+    let _request = start_request();
+
     satellite::set_custom_domain(domain_name, bn_id);
 }
 
 #[doc(hidden)]
-#[update(guard = "caller_is_admin_controller")]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
 pub fn del_custom_domain(domain_name: DomainName) {
+    let _request = start_request();
+
     satellite::del_custom_domain(domain_name);
 }
 
+// ---------------------------------------------------------
+// Audit
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn list_audit_blocks(args: ListAuditBlocks) -> AuditBlocks {
+    satellite::list_audit_blocks(args)
+}
+
 // ---------------------------------------------------------
 // Config
 // ---------------------------------------------------------
@@ -246,16 +766,36 @@ pub fn del_custom_domain(domain_name: DomainName) {
 #[doc(hidden)]
 #[update(guard = "caller_is_admin_controller")]
 pub fn get_config() -> Config {
+    let _request = start_request();
+
     satellite::get_config()
 }
 
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller")]
+pub fn get_config_bundle() -> ConfigBundle {
+    let _request = start_request();
+
+    satellite::get_config_bundle()
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn apply_config_bundle(bundle: ConfigBundle) {
+    let _request = start_request();
+
+    satellite::apply_config_bundle(bundle);
+}
+
 // ---------------------------------------------------------
 // Authentication config
 // ---------------------------------------------------------
 
 #[doc(hidden)]
-#[update(guard = "caller_is_admin_controller")]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
 pub fn set_auth_config(config: AuthenticationConfig) {
+    let _request = start_request();
+
     satellite::set_auth_config(config);
 }
 
@@ -265,13 +805,175 @@ pub fn get_auth_config() -> Option<AuthenticationConfig> {
     satellite::get_auth_config()
 }
 
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn set_analytics_config(config: AnalyticsConfig) {
+    let _request = start_request();
+
+    satellite::set_analytics_config(config);
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn get_analytics_config() -> Option<AnalyticsConfig> {
+    satellite::get_analytics_config()
+}
+
 // ---------------------------------------------------------
-// Db config
+// Feature flags
 // ---------------------------------------------------------
 
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn set_feature_flag(key: FeatureFlagKey, flag: SetFeatureFlag) -> FeatureFlag {
+    let _request = start_request();
+
+    satellite::set_feature_flag(key, flag)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn del_feature_flag(key: FeatureFlagKey, flag: DelFeatureFlag) {
+    let _request = start_request();
+
+    satellite::del_feature_flag(key, flag)
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn list_feature_flags() -> Vec<(FeatureFlagKey, FeatureFlag)> {
+    satellite::list_feature_flags()
+}
+
+#[doc(hidden)]
+#[query]
+pub fn get_feature_flags() -> Vec<(FeatureFlagKey, bool)> {
+    satellite::get_feature_flags()
+}
+
+// ---------------------------------------------------------
+// Extensions
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn set_extension_config(key: ExtensionKey, config: SetExtensionConfig) -> Extension {
+    let _request = start_request();
+
+    satellite::set_extension_config(key, config)
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_admin_controller")]
+pub fn list_extensions() -> Vec<(ExtensionKey, Extension)> {
+    satellite::list_extensions()
+}
+
+// ---------------------------------------------------------
+// Client
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[query]
+pub fn get_client_metadata() -> ClientMetadata {
+    satellite::get_client_metadata()
+}
+
+// ---------------------------------------------------------
+// Health
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn health() -> Health {
+    satellite::health()
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn get_integrity_report() -> Option<IntegrityReport> {
+    satellite::get_integrity_report()
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn get_self_test_report() -> Option<SelfTestReport> {
+    satellite::get_self_test_report()
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_can_acknowledge_safe_mode")]
+pub fn acknowledge_safe_mode() {
+    let _request = start_request();
+
+    satellite::acknowledge_safe_mode();
+}
+
 #[doc(hidden)]
 #[update(guard = "caller_is_admin_controller")]
+pub fn analyze_asset_links() {
+    let _request = start_request();
+
+    satellite::analyze_asset_links();
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn get_asset_links_report() -> Option<AssetLinksReport> {
+    satellite::get_asset_links_report()
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn get_memory_stats() -> Vec<MemoryRegionStats> {
+    satellite::get_memory_stats()
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn compact_content_chunks() {
+    let _request = start_request();
+
+    satellite::compact_content_chunks();
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn get_compaction_report() -> Option<CompactionReport> {
+    satellite::get_compaction_report()
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn rebuild_indexes(collection: CollectionKey) {
+    let _request = start_request();
+
+    satellite::rebuild_indexes(collection);
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
+pub fn compact_collection(collection: CollectionKey) {
+    let _request = start_request();
+
+    satellite::compact_collection(collection);
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn get_collection_job_report() -> Option<CollectionJobReport> {
+    satellite::get_collection_job_report()
+}
+
+// ---------------------------------------------------------
+// Db config
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
 pub fn set_db_config(config: DbConfig) {
+    let _request = start_request();
+
     satellite::set_db_config(config);
 }
 
@@ -286,8 +988,10 @@ pub fn get_db_config() -> Option<DbConfig> {
 // ---------------------------------------------------------
 
 #[doc(hidden)]
-#[update(guard = "caller_is_admin_controller")]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
 pub fn set_storage_config(config: StorageConfig) {
+    let _request = start_request();
+
     satellite::set_storage_config(config);
 }
 
@@ -297,6 +1001,32 @@ pub fn get_storage_config() -> StorageConfig {
     satellite::get_storage_config()
 }
 
+// ---------------------------------------------------------
+// Canary deployment
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_controller_and_not_safe_mode")]
+pub fn set_canary_deployment(deployment: SetCanaryDeployment) -> CanaryDeploymentStatus {
+    let _request = start_request();
+
+    satellite::set_canary_deployment(deployment)
+}
+
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn get_canary_deployment() -> Option<CanaryDeploymentStatus> {
+    satellite::get_canary_deployment()
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_is_controller_and_not_safe_mode")]
+pub fn finalize_deployment() {
+    let _request = start_request();
+
+    satellite::finalize_deployment();
+}
+
 // ---------------------------------------------------------
 // Http
 // ---------------------------------------------------------
@@ -307,6 +1037,14 @@ pub fn http_request(request: HttpRequest) -> HttpResponse {
     satellite::http_request(request)
 }
 
+#[doc(hidden)]
+#[update]
+pub async fn http_request_update(request: HttpRequest) -> HttpResponse {
+    let current = start_request();
+
+    satellite::http_request_update(request, &current).await
+}
+
 #[doc(hidden)]
 #[query]
 pub fn http_request_streaming_callback(
@@ -320,20 +1058,26 @@ pub fn http_request_streaming_callback(
 //
 
 #[doc(hidden)]
-#[update]
+#[update(guard = "caller_can_write")]
 pub fn init_asset_upload(init: InitAssetKey) -> InitUploadResult {
+    let _request = start_request();
+
     satellite::init_asset_upload(init)
 }
 
 #[doc(hidden)]
-#[update]
+#[update(guard = "caller_can_write")]
 pub fn upload_asset_chunk(chunk: UploadChunk) -> UploadChunkResult {
+    let _request = start_request();
+
     satellite::upload_asset_chunk(chunk)
 }
 
 #[doc(hidden)]
-#[update]
+#[update(guard = "caller_can_write")]
 pub fn commit_asset_upload(commit: CommitBatch) {
+    let _request = start_request();
+
     satellite::commit_asset_upload(commit);
 }
 
@@ -343,6 +1087,15 @@ pub fn list_assets(collection: CollectionKey, filter: ListParams) -> ListResults
     satellite::list_assets(collection, filter)
 }
 
+#[doc(hidden)]
+#[query]
+pub fn list_assets_page(
+    collection: CollectionKey,
+    filter: ListParams,
+) -> Page<(FullPath, AssetNoContent)> {
+    satellite::list_assets_page(collection, filter)
+}
+
 #[doc(hidden)]
 #[query]
 pub fn count_assets(collection: CollectionKey, filter: ListParams) -> usize {
@@ -350,26 +1103,40 @@ pub fn count_assets(collection: CollectionKey, filter: ListParams) -> usize {
 }
 
 #[doc(hidden)]
-#[update]
+#[query(guard = "caller_is_controller")]
+pub fn diff_assets(args: DiffAssetsArgs) -> Vec<FullPath> {
+    satellite::diff_assets(args)
+}
+
+#[doc(hidden)]
+#[update(guard = "caller_can_write")]
 pub fn del_asset(collection: CollectionKey, full_path: FullPath) {
+    let _request = start_request();
+
     satellite::del_asset(collection, full_path);
 }
 
 #[doc(hidden)]
-#[update]
+#[update(guard = "caller_can_write")]
 pub fn del_many_assets(assets: Vec<(CollectionKey, String)>) {
+    let _request = start_request();
+
     satellite::del_many_assets(assets);
 }
 
 #[doc(hidden)]
-#[update]
+#[update(guard = "caller_can_write")]
 pub fn del_filtered_assets(collection: CollectionKey, filter: ListParams) {
+    let _request = start_request();
+
     satellite::del_filtered_assets(collection, filter)
 }
 
 #[doc(hidden)]
-#[update(guard = "caller_is_controller")]
+#[update(guard = "caller_is_controller_and_not_safe_mode")]
 pub fn del_assets(collection: CollectionKey) {
+    let _request = start_request();
+
     satellite::del_assets(collection);
 }
 
@@ -379,6 +1146,12 @@ pub fn count_collection_assets(collection: CollectionKey) -> usize {
     satellite::count_collection_assets(collection)
 }
 
+#[doc(hidden)]
+#[query(guard = "caller_is_controller")]
+pub fn list_certified_paths() -> Vec<CertifiedPath> {
+    satellite::list_certified_paths()
+}
+
 #[doc(hidden)]
 #[query]
 pub fn get_asset(collection: CollectionKey, full_path: FullPath) -> Option<AssetNoContent> {
@@ -393,16 +1166,40 @@ pub fn get_many_assets(
     satellite::get_many_assets(assets)
 }
 
+// ---------------------------------------------------------
+// Consent messages
+// ---------------------------------------------------------
+
+#[doc(hidden)]
+#[update(guard = "caller_is_within_rate_limit")]
+pub fn icrc21_canister_call_consent_message(
+    request: Icrc21ConsentMessageRequest,
+) -> Icrc21ConsentMessageResponse {
+    let _request = start_request();
+
+    satellite::icrc21_canister_call_consent_message(request)
+}
+
+#[doc(hidden)]
+#[query]
+pub fn icrc10_supported_standards() -> Vec<SupportedStandard> {
+    satellite::icrc10_supported_standards()
+}
+
 // ---------------------------------------------------------
 // Mgmt
 // ---------------------------------------------------------
 
 #[doc(hidden)]
-#[update(guard = "caller_is_admin_controller")]
+#[update(guard = "caller_is_admin_controller_and_not_safe_mode")]
 pub async fn deposit_cycles(args: DepositCyclesArgs) {
-    junobuild_shared::mgmt::ic::deposit_cycles(args)
-        .await
-        .unwrap_or_else(|e| trap(&e))
+    let request = start_request();
+
+    let result = junobuild_shared::mgmt::ic::deposit_cycles(args).await;
+
+    request.resume();
+
+    result.unwrap_or_else(|e| trap(&request_error(&e)))
 }
 
 #[doc(hidden)]
@@ -436,16 +1233,35 @@ pub fn memory_size() -> MemorySize {
 macro_rules! include_satellite {
     () => {
         use junobuild_satellite::{
-            commit_asset_upload, count_assets, count_collection_assets, count_collection_docs,
-            count_docs, del_asset, del_assets, del_controllers, del_custom_domain, del_doc,
-            del_docs, del_filtered_assets, del_filtered_docs, del_many_assets, del_many_docs,
-            del_rule, deposit_cycles, get_asset, get_auth_config, get_config, get_db_config,
-            get_doc, get_many_assets, get_many_docs, get_storage_config, http_request,
-            http_request_streaming_callback, init, init_asset_upload, list_assets,
-            list_controllers, list_custom_domains, list_docs, list_rules, memory_size,
-            post_upgrade, pre_upgrade, set_auth_config, set_controllers, set_custom_domain,
-            set_db_config, set_doc, set_many_docs, set_rule, set_storage_config,
-            upload_asset_chunk, version,
+            acknowledge_safe_mode, analyze_asset_links, apply_config_bundle, cancel_action,
+            commit_asset_upload, compact_collection, compact_content_chunks, confirm_action,
+            count_assets, count_collection_assets, count_collection_docs, count_docs,
+            create_api_key, create_upload_token, del_api_key, del_asset, del_assets,
+            del_controllers, del_custom_domain, del_doc, del_docs, del_feature_flag,
+            del_filtered_assets, del_filtered_docs, del_many_assets, del_many_docs, del_rule,
+            del_upload_token, del_webhook, deposit_cycles, diff_assets, dry_run, estimate_query,
+            export_chunk, finalize_deployment, get_analytics_config, get_approvals_config,
+            get_asset, get_asset_links_report, get_auth_config, get_canary_deployment,
+            get_client_metadata, get_collection_job_report, get_collection_stats,
+            get_collection_version, get_compaction_report, get_config, get_config_bundle,
+            get_db_config, get_doc, get_doc_at, get_doc_chunk, get_feature_flags,
+            get_integrity_report, get_many_assets, get_many_docs, get_memory_stats,
+            get_moderation_config, get_quota_status, get_quota_thresholds, get_rate_limit_config,
+            get_self_test_report, get_storage_config, get_traces_config, health, http_request,
+            http_request_streaming_callback, http_request_update, icrc10_supported_standards,
+            icrc21_canister_call_consent_message, import_chunk, import_users, init,
+            init_asset_upload, list_api_keys, list_assets, list_assets_page, list_audit_blocks,
+            list_certified_paths, list_controllers, list_controllers_page, list_custom_domains,
+            list_docs, list_docs_page, list_extensions, list_feature_flags, list_pending_actions,
+            list_rules, list_traces, list_upload_tokens, list_webhook_deliveries, list_webhooks,
+            memory_size, patch_doc, post_upgrade, pre_upgrade, propose_action, rebuild_indexes,
+            set_analytics_config, set_approvals_config, set_asset_moderation, set_asset_owner,
+            set_auth_config, set_canary_deployment, set_controllers, set_custom_domain,
+            set_db_config, set_doc, set_doc_moderation, set_doc_owner, set_extension_config,
+            set_feature_flag, set_many_docs, set_moderation_config, set_quota_thresholds,
+            set_rate_limit_config, set_rule, set_rule_compression, set_storage_config,
+            set_traces_config, set_webhook, transform_webhook_response, upload_asset_chunk,
+            verify_token_gate, version,
         };
 
         #[ic_cdk::query]
@@ -453,6 +1269,13 @@ macro_rules! include_satellite {
             env!("CARGO_PKG_VERSION").to_string()
         }
 
+        // The interface of the endpoints declared in the crate of the developer - i.e. the custom endpoints of the
+        // serverless functions - returned with the metadata of the satellite to generate typed clients.
+        #[no_mangle]
+        pub extern "Rust" fn juno_satellite_extension_candid() -> String {
+            __export_service()
+        }
+
         ic_cdk::export_candid!();
     };
 }
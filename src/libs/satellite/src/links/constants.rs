@@ -0,0 +1,9 @@
+// The number of assets parsed in each timer, so that the instructions of a single message remain bounded
+pub const LINKS_ASSETS_PER_STEP: usize = 20;
+
+// The orphans and broken links found beyond this number are only counted, so that a large site does not fill the heap
+pub const LINKS_MAX_RESULTS: usize = 100;
+
+// An analysis that did not progress for 10 minutes (1min * 10) - e.g. because a step ran out of instructions or the
+// satellite was upgraded meanwhile - is considered interrupted. A new analysis can then be started.
+pub const LINKS_ANALYSIS_TIMEOUT_NS: u64 = 60_000_000_000 * 10;
@@ -0,0 +1,6 @@
+mod constants;
+mod msg;
+mod parse;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,2 @@
+pub const ERROR_LINKS_ANALYSIS_RUNNING: &str =
+    "An analysis of the links of the assets is already running.";
@@ -0,0 +1,99 @@
+use junobuild_storage::types::state::FullPath;
+use regex::Regex;
+use std::sync::LazyLock;
+use urlencoding::decode;
+
+// The patterns are compiled once per instance of the canister rather than for each parsed asset.
+
+static ATTRIBUTES: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?i)\b(?:href|src|poster)\s*=\s*["']([^"']+)["']"#).unwrap());
+
+static SRCSETS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?i)\bsrcset\s*=\s*["']([^"']+)["']"#).unwrap());
+
+static URLS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?i)url\(\s*["']?([^"')]+?)["']?\s*\)"#).unwrap());
+
+static IMPORTS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?i)@import\s+["']([^"']+)["']"#).unwrap());
+
+static SCHEME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-zA-Z][a-zA-Z0-9+.-]*:").unwrap());
+
+pub fn is_parsable(full_path: &FullPath) -> bool {
+    let path = full_path.to_lowercase();
+
+    path.ends_with(".html") || path.ends_with(".htm") || path.ends_with(".css")
+}
+
+/// Extracts the links of an HTML or CSS content - i.e. the `href`, `src`, `poster` and `srcset` attributes and the
+/// `url()` and `@import` of the stylesheets, those being also found in the inline styles of the HTML pages.
+pub fn extract_links(content: &str) -> Vec<String> {
+    // e.g. srcset="/logo.png 1x, /logo@2x.png 2x"
+    let srcset_links = SRCSETS.captures_iter(content).flat_map(|captures| {
+        captures[1]
+            .split(',')
+            .filter_map(|candidate| candidate.split_whitespace().next())
+            .map(|link| link.to_string())
+            .collect::<Vec<String>>()
+    });
+
+    ATTRIBUTES
+        .captures_iter(content)
+        .chain(URLS.captures_iter(content))
+        .chain(IMPORTS.captures_iter(content))
+        .map(|captures| captures[1].trim().to_string())
+        .chain(srcset_links)
+        .collect()
+}
+
+/// Resolves a link relative to the asset that contains it - e.g. `../logo.png` in `/blog/post.html` resolves to
+/// `/logo.png`. Returns `None` for the external links, the anchors and the other schemes such as `mailto:` or `data:`.
+pub fn resolve_link(link: &str, source: &FullPath) -> Option<String> {
+    if link.is_empty() || link.starts_with('#') || link.starts_with("//") || SCHEME.is_match(link) {
+        return None;
+    }
+
+    // The query and the fragment are not part of the path of an asset
+    let path = link.split(['?', '#']).next().unwrap_or_default();
+
+    if path.is_empty() {
+        return None;
+    }
+
+    let path = decode(path).ok()?.to_string();
+
+    let joined = if path.starts_with('/') {
+        path
+    } else {
+        let directory = source
+            .rsplit_once('/')
+            .map_or("", |(directory, _)| directory);
+        format!("{}/{}", directory, path)
+    };
+
+    Some(normalize_path(&joined))
+}
+
+// e.g. /blog/./images/../logo.png -> /blog/logo.png. A trailing slash is kept given that it targets an index.html.
+fn normalize_path(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => (),
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+
+    let trailing_slash = path.ends_with('/') && !segments.is_empty();
+
+    format!(
+        "/{}{}",
+        segments.join("/"),
+        if trailing_slash { "/" } else { "" }
+    )
+}
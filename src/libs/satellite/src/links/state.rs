@@ -0,0 +1,93 @@
+use crate::links::constants::{LINKS_ANALYSIS_TIMEOUT_NS, LINKS_MAX_RESULTS};
+use crate::links::types::interface::{AssetLinksReport, BrokenLink};
+use crate::links::types::state::LinksRuntimeState;
+use crate::memory::STATE;
+use ic_cdk::api::time;
+use junobuild_shared::types::state::Timestamp;
+use junobuild_storage::types::state::FullPath;
+use std::collections::{HashSet, VecDeque};
+
+pub fn get_report() -> Option<AssetLinksReport> {
+    STATE.with(|state| state.borrow().runtime.links.report.clone())
+}
+
+pub fn is_analysis_running() -> bool {
+    STATE.with(|state| is_analysis_running_impl(&state.borrow().runtime.links, time()))
+}
+
+fn is_analysis_running_impl(links: &LinksRuntimeState, now: Timestamp) -> bool {
+    let running = links
+        .report
+        .as_ref()
+        .is_some_and(|report| report.completed_at.is_none());
+
+    let interrupted = links
+        .stepped_at
+        .is_some_and(|stepped_at| now.saturating_sub(stepped_at) > LINKS_ANALYSIS_TIMEOUT_NS);
+
+    running && !interrupted
+}
+
+pub fn init_report(report: AssetLinksReport, pending: VecDeque<FullPath>) {
+    STATE.with(|state| {
+        let links = &mut state.borrow_mut().runtime.links;
+
+        links.stepped_at = Some(report.started_at);
+        links.report = Some(report);
+        links.pending = pending;
+        links.referenced = HashSet::new();
+    })
+}
+
+pub fn next_batch(length: usize) -> Vec<FullPath> {
+    STATE.with(|state| {
+        let pending = &mut state.borrow_mut().runtime.links.pending;
+
+        let length = length.min(pending.len());
+
+        pending.drain(..length).collect()
+    })
+}
+
+pub fn insert_results(
+    parsed_assets: u64,
+    links_count: u64,
+    referenced: Vec<FullPath>,
+    broken_links: Vec<BrokenLink>,
+) {
+    STATE.with(|state| {
+        let links = &mut state.borrow_mut().runtime.links;
+
+        links.referenced.extend(referenced);
+        links.stepped_at = Some(time());
+
+        if let Some(report) = &mut links.report {
+            report.parsed_assets += parsed_assets;
+            report.links_count += links_count;
+            report.broken_links_count += broken_links.len() as u64;
+
+            let available = LINKS_MAX_RESULTS.saturating_sub(report.broken_links.len());
+            report
+                .broken_links
+                .extend(broken_links.into_iter().take(available));
+        }
+    })
+}
+
+pub fn get_referenced() -> HashSet<FullPath> {
+    STATE.with(|state| state.borrow().runtime.links.referenced.clone())
+}
+
+pub fn complete_report(orphans: Vec<FullPath>, completed_at: Timestamp) {
+    STATE.with(|state| {
+        let links = &mut state.borrow_mut().runtime.links;
+
+        links.referenced = HashSet::new();
+
+        if let Some(report) = &mut links.report {
+            report.orphans_count = orphans.len() as u64;
+            report.orphans = orphans.into_iter().take(LINKS_MAX_RESULTS).collect();
+            report.completed_at = Some(completed_at);
+        }
+    })
+}
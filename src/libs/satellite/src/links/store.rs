@@ -0,0 +1,168 @@
+use crate::links::constants::LINKS_ASSETS_PER_STEP;
+use crate::links::msg::ERROR_LINKS_ANALYSIS_RUNNING;
+use crate::links::parse::{extract_links, is_parsable, resolve_link};
+use crate::links::state::{
+    complete_report, get_referenced, get_report, init_report, insert_results, is_analysis_running,
+    next_batch,
+};
+use crate::links::types::interface::{AssetLinksReport, BrokenLink};
+use crate::links::types::state::LinkTarget;
+use crate::storage::store::{
+    collect_delete_assets_store, get_config_store, get_content_chunks_store, get_public_asset_store,
+};
+use crate::storage::strategy_impls::StorageState;
+use ic_cdk::api::time;
+use ic_cdk_timers::set_timer;
+use junobuild_collections::constants::ASSET_COLLECTION_KEY;
+use junobuild_storage::constants::ASSET_ENCODING_NO_COMPRESSION;
+use junobuild_storage::routing::get_routing;
+use junobuild_storage::types::http_request::{Routing, RoutingDefault, RoutingRewrite};
+use junobuild_storage::types::state::FullPath;
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+pub fn get_asset_links_report_store() -> Option<AssetLinksReport> {
+    get_report()
+}
+
+/// Starts the analysis of the links of the HTML and CSS assets of the `#dapp` collection. The assets are parsed in
+/// batches, each in its own timer, and the orphans are collected once all of them are parsed.
+pub fn analyze_asset_links_store() -> Result<(), String> {
+    if is_analysis_running() {
+        return Err(ERROR_LINKS_ANALYSIS_RUNNING.to_string());
+    }
+
+    let pending: VecDeque<FullPath> =
+        collect_delete_assets_store(&ASSET_COLLECTION_KEY.to_string())?
+            .into_iter()
+            .filter(is_parsable)
+            .collect();
+
+    init_report(
+        AssetLinksReport {
+            started_at: time(),
+            completed_at: None,
+            parsed_assets: 0,
+            links_count: 0,
+            orphans_count: 0,
+            orphans: Vec::new(),
+            broken_links_count: 0,
+            broken_links: Vec::new(),
+        },
+        pending,
+    );
+
+    set_timer(Duration::ZERO, run_next_step);
+
+    Ok(())
+}
+
+fn run_next_step() {
+    let batch = next_batch(LINKS_ASSETS_PER_STEP);
+
+    if batch.is_empty() {
+        complete_analysis();
+        return;
+    }
+
+    let mut parsed_assets: u64 = 0;
+    let mut links_count: u64 = 0;
+    let mut referenced: Vec<FullPath> = Vec::new();
+    let mut broken_links: Vec<BrokenLink> = Vec::new();
+
+    for source in batch {
+        let Some(content) = read_content(&source) else {
+            continue;
+        };
+
+        parsed_assets += 1;
+
+        let targets = extract_links(&content)
+            .into_iter()
+            .filter_map(|link| resolve_link(&link, &source));
+
+        for target in targets {
+            links_count += 1;
+
+            match route_target(&target) {
+                LinkTarget::Asset(full_path) => referenced.push(full_path),
+                LinkTarget::Elsewhere => (),
+                LinkTarget::NotFound => broken_links.push(BrokenLink {
+                    source: source.clone(),
+                    target,
+                }),
+            }
+        }
+    }
+
+    insert_results(parsed_assets, links_count, referenced, broken_links);
+
+    set_timer(Duration::ZERO, run_next_step);
+}
+
+fn complete_analysis() {
+    let referenced = get_referenced();
+    let entry_points = entry_points();
+
+    let orphans: Vec<FullPath> = collect_delete_assets_store(&ASSET_COLLECTION_KEY.to_string())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|full_path| !referenced.contains(full_path) && !entry_points.contains(full_path))
+        .filter(|full_path| !full_path.starts_with("/.well-known/"))
+        .collect();
+
+    complete_report(orphans, time());
+}
+
+// The assets that are requested without being referenced - i.e. the pages served for the root, the errors and the
+// rewrites.
+fn entry_points() -> HashSet<FullPath> {
+    let config = get_config_store();
+
+    let rewrites = config.rewrites.values().cloned();
+
+    let weighted_rewrites = config
+        .unwrap_weighted_rewrites()
+        .into_values()
+        .flat_map(|rewrite| rewrite.destinations)
+        .map(|weighted_destination| weighted_destination.destination);
+
+    ["/index.html".to_string(), "/404.html".to_string()]
+        .into_iter()
+        .chain(rewrites)
+        .chain(weighted_rewrites)
+        .collect()
+}
+
+// Only the uncompressed encoding is parsed. The assets uploaded exclusively compressed are skipped.
+fn read_content(full_path: &FullPath) -> Option<String> {
+    let (asset, memory) = get_public_asset_store(full_path.clone(), None)?;
+
+    let encoding = asset.encodings.get(ASSET_ENCODING_NO_COMPRESSION)?;
+
+    let mut content: Vec<u8> = Vec::new();
+
+    for index in 0..encoding.content_chunks.len() {
+        content.extend(get_content_chunks_store(encoding, index, &memory)?);
+    }
+
+    String::from_utf8(content).ok()
+}
+
+// The targets are resolved like the HTTP requests - i.e. with the alternative paths, the rewrites and the redirects.
+fn route_target(target: &str) -> LinkTarget {
+    match get_routing(target.to_string(), &[], true, &StorageState) {
+        Ok(Routing::Default(RoutingDefault {
+            asset: Some((asset, _)),
+            ..
+        }))
+        | Ok(Routing::Rewrite(RoutingRewrite {
+            asset: Some((asset, _)),
+            ..
+        })) => LinkTarget::Asset(asset.key.full_path),
+        Ok(Routing::Redirect(_)) | Ok(Routing::RedirectRaw(_)) | Ok(Routing::Delegation(_)) => {
+            LinkTarget::Elsewhere
+        }
+        _ => LinkTarget::NotFound,
+    }
+}
@@ -0,0 +1,63 @@
+pub mod state {
+    use crate::links::types::interface::AssetLinksReport;
+    use junobuild_shared::types::state::Timestamp;
+    use junobuild_storage::types::state::FullPath;
+    use std::collections::{HashSet, VecDeque};
+
+    /// - `pending`: The HTML and CSS assets that remain to be parsed.
+    /// - `referenced`: The assets served for the links found so far.
+    /// - `stepped_at`: When the analysis last progressed, to detect an analysis that was interrupted.
+    #[derive(Default, Clone)]
+    pub struct LinksRuntimeState {
+        pub report: Option<AssetLinksReport>,
+        pub pending: VecDeque<FullPath>,
+        pub referenced: HashSet<FullPath>,
+        pub stepped_at: Option<Timestamp>,
+    }
+
+    /// - `Asset`: The link is served with an asset of the satellite.
+    /// - `Elsewhere`: The link is redirected or delegated to another canister.
+    /// - `NotFound`: The link is broken.
+    pub enum LinkTarget {
+        Asset(FullPath),
+        Elsewhere,
+        NotFound,
+    }
+}
+
+pub mod interface {
+    use candid::CandidType;
+    use junobuild_shared::types::state::Timestamp;
+    use junobuild_storage::types::state::FullPath;
+    use serde::{Deserialize, Serialize};
+
+    /// The outcome of the last analysis of the links between the assets of the `#dapp` collection.
+    ///
+    /// - `completed_at`: `None` while the analysis is still running - or if it was interrupted, in which case a new
+    ///   analysis can be started.
+    /// - `orphans`: The first assets that are not referenced by any HTML or CSS asset. The entry points - e.g.
+    ///   `/index.html` or the destinations of the rewrites - are never reported. Assets only referenced from scripts
+    ///   are reported as well, given that those are not parsed.
+    /// - `broken_links`: The first internal links that do not resolve to any asset.
+    ///
+    /// Both lists are limited, the other results are only counted.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct AssetLinksReport {
+        pub started_at: Timestamp,
+        pub completed_at: Option<Timestamp>,
+        pub parsed_assets: u64,
+        pub links_count: u64,
+        pub orphans_count: u64,
+        pub orphans: Vec<FullPath>,
+        pub broken_links_count: u64,
+        pub broken_links: Vec<BrokenLink>,
+    }
+
+    /// - `source`: The asset that contains the link.
+    /// - `target`: The link, resolved relative to the source - e.g. `/images/logo.png`.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct BrokenLink {
+        pub source: FullPath,
+        pub target: String,
+    }
+}
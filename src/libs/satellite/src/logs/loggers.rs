@@ -1,5 +1,6 @@
 use crate::logs::types::logs::{Log, LogLevel};
 use crate::memory::STATE;
+use crate::requests::store::get_request_id;
 use crate::{set_doc_store, Key, SetDoc};
 use ic_cdk::api::time;
 use ic_cdk::id;
@@ -88,12 +89,16 @@ fn set_log<T: Serialize>(level: LogLevel, message: String, data: Option<&T>) ->
         level,
         message,
         data: log_data,
+        request_id: get_request_id(),
     };
 
     let doc: SetDoc = SetDoc {
         description: None,
         data: encode_doc_data(&log)?,
         version: None,
+        publish_at: None,
+        unpublish_at: None,
+        attachments: None,
     };
 
     set_doc_store(id(), LOG_COLLECTION_KEY.to_string(), key, doc)?;
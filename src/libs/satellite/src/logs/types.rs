@@ -1,4 +1,5 @@
 pub mod logs {
+    use crate::requests::types::state::RequestId;
     use crate::Blob;
     use candid::{CandidType, Deserialize};
     use serde::Serialize;
@@ -11,10 +12,12 @@ pub mod logs {
         Error,
     }
 
+    /// A log is recorded with the id of the request it was written by, if any, to trace it back to a call.
     #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
     pub struct Log {
         pub level: LogLevel,
         pub message: String,
         pub data: Option<Blob>,
+        pub request_id: Option<RequestId>,
     }
 }
@@ -0,0 +1,11 @@
+use crate::maintenance::msg::ERROR_COLLECTION_JOB_LOCKED;
+use crate::maintenance::state::is_collection_locked;
+use junobuild_collections::types::core::CollectionKey;
+
+pub fn assert_collection_not_locked(collection: &CollectionKey) -> Result<(), String> {
+    if is_collection_locked(collection) {
+        return Err(ERROR_COLLECTION_JOB_LOCKED.to_string());
+    }
+
+    Ok(())
+}
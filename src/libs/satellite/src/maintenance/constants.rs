@@ -0,0 +1,8 @@
+// The number of documents processed in each timer. Each document may update the documents of the views and the
+// aggregates it is part of, therefore the batch is kept small so that the instructions of a single message remain
+// bounded.
+pub const COLLECTION_JOB_DOCS_PER_STEP: usize = 100;
+
+// A job that did not progress for 10 minutes (1min * 10) - e.g. because a step ran out of instructions - is
+// considered interrupted. The collection is unlocked and a new job can be started.
+pub const COLLECTION_JOB_TIMEOUT_NS: u64 = 60_000_000_000 * 10;
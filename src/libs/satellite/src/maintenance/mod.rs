@@ -0,0 +1,6 @@
+pub mod assert;
+mod constants;
+mod msg;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,5 @@
+pub const ERROR_COLLECTION_JOB_RUNNING: &str = "A job is already running on a collection.";
+pub const ERROR_COLLECTION_JOB_LOCKED: &str =
+    "The collection cannot be written while its indexes are rebuilt.";
+pub const ERROR_COLLECTION_JOB_HEAP: &str =
+    "Only the collections in stable memory can be compacted.";
@@ -0,0 +1,122 @@
+use crate::maintenance::constants::COLLECTION_JOB_TIMEOUT_NS;
+use crate::maintenance::types::interface::{CollectionJobKind, CollectionJobReport};
+use crate::maintenance::types::state::{CollectionJobStep, MaintenanceRuntimeState};
+use crate::memory::STATE;
+use crate::stats::types::runtime::StatsItem;
+use crate::stats::types::state::CollectionCounters;
+use ic_cdk::api::time;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::Timestamp;
+use std::collections::VecDeque;
+
+pub fn get_report() -> Option<CollectionJobReport> {
+    STATE.with(|state| state.borrow().runtime.maintenance.report.clone())
+}
+
+pub fn is_job_running() -> bool {
+    STATE.with(|state| is_job_running_impl(&state.borrow().runtime.maintenance, time()))
+}
+
+/// The documents of a collection cannot be written while its indexes are rebuilt, given that the documents scanned
+/// so far would not be counted again.
+pub fn is_collection_locked(collection: &CollectionKey) -> bool {
+    STATE.with(|state| {
+        let maintenance = &state.borrow().runtime.maintenance;
+
+        is_job_running_impl(maintenance, time())
+            && maintenance.report.as_ref().is_some_and(|report| {
+                report.kind == CollectionJobKind::RebuildIndexes && report.collection == *collection
+            })
+    })
+}
+
+fn is_job_running_impl(maintenance: &MaintenanceRuntimeState, now: Timestamp) -> bool {
+    let running = maintenance
+        .report
+        .as_ref()
+        .is_some_and(|report| report.completed_at.is_none());
+
+    let interrupted = maintenance
+        .stepped_at
+        .is_some_and(|stepped_at| now.saturating_sub(stepped_at) > COLLECTION_JOB_TIMEOUT_NS);
+
+    running && !interrupted
+}
+
+pub fn init_job(report: CollectionJobReport, steps: VecDeque<CollectionJobStep>) {
+    STATE.with(|state| {
+        let maintenance = &mut state.borrow_mut().runtime.maintenance;
+
+        maintenance.stepped_at = Some(report.started_at);
+        maintenance.report = Some(report);
+        maintenance.steps = steps;
+        maintenance.cursor = None;
+        maintenance.counters = CollectionCounters::default();
+    })
+}
+
+pub fn get_next_step() -> Option<(CollectionKey, CollectionJobStep, Option<Key>)> {
+    STATE.with(|state| {
+        let maintenance = &state.borrow().runtime.maintenance;
+
+        let collection = maintenance.report.as_ref()?.collection.clone();
+        let step = maintenance.steps.front()?.clone();
+
+        Some((collection, step, maintenance.cursor.clone()))
+    })
+}
+
+pub fn insert_results(
+    cursor: Key,
+    processed_docs: u64,
+    rewritten_docs: u64,
+    reclaimed_bytes: u64,
+    items: Vec<StatsItem>,
+) {
+    STATE.with(|state| {
+        let maintenance = &mut state.borrow_mut().runtime.maintenance;
+
+        maintenance.cursor = Some(cursor);
+        maintenance.stepped_at = Some(time());
+
+        for item in items.iter() {
+            maintenance.counters.add(item);
+        }
+
+        if let Some(report) = &mut maintenance.report {
+            report.processed_docs += processed_docs;
+            report.rewritten_docs += rewritten_docs;
+            report.reclaimed_bytes += reclaimed_bytes;
+        }
+    })
+}
+
+pub fn complete_step() {
+    STATE.with(|state| {
+        let maintenance = &mut state.borrow_mut().runtime.maintenance;
+
+        maintenance.steps.pop_front();
+        maintenance.cursor = None;
+        maintenance.stepped_at = Some(time());
+    })
+}
+
+pub fn take_counters() -> CollectionCounters {
+    STATE.with(|state| std::mem::take(&mut state.borrow_mut().runtime.maintenance.counters))
+}
+
+pub fn complete_report(error: Option<String>, completed_at: Timestamp) {
+    STATE.with(|state| {
+        let maintenance = &mut state.borrow_mut().runtime.maintenance;
+
+        maintenance.steps = VecDeque::new();
+        maintenance.cursor = None;
+        maintenance.counters = CollectionCounters::default();
+
+        if let Some(report) = &mut maintenance.report {
+            report.completed_at = Some(completed_at);
+            report.error = error;
+        }
+    })
+}
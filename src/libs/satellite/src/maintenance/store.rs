@@ -0,0 +1,243 @@
+use crate::changes::state::increment_db_version;
+use crate::db::aggregates::update_aggregates;
+use crate::db::state::{
+    compact_docs_stable, delete_doc as delete_state_doc, get_config, get_rule as get_state_rule,
+    iter_docs_heap_after, iter_docs_stable_after,
+};
+use crate::db::types::state::Doc;
+use crate::db::views::update_views;
+use crate::maintenance::constants::COLLECTION_JOB_DOCS_PER_STEP;
+use crate::maintenance::msg::{ERROR_COLLECTION_JOB_HEAP, ERROR_COLLECTION_JOB_RUNNING};
+use crate::maintenance::state::{
+    complete_report, complete_step, get_next_step, get_report, init_job, insert_results,
+    is_job_running, take_counters,
+};
+use crate::maintenance::types::interface::{CollectionJobKind, CollectionJobReport};
+use crate::maintenance::types::state::CollectionJobStep;
+use crate::memory::STATE;
+use crate::quotas::store::refresh_status as refresh_quota_status;
+use crate::stats::state::{get_db_counters, set_db_counters};
+use crate::stats::types::runtime::StatsItem;
+use ic_cdk::api::time;
+use ic_cdk_timers::set_timer;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::Memory;
+use junobuild_shared::types::core::Key;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+pub fn get_collection_job_report_store() -> Option<CollectionJobReport> {
+    get_report()
+}
+
+/// Starts deriving again the statistics, the aggregates, the views and the version of a collection - e.g. after those
+/// went out of sync with its documents. The documents of the aggregates computed from the collection are deleted
+/// first, then its documents are scanned in batches, each in its own timer, and finally the documents of its views
+/// are recomputed, which removes those whose sources no longer exist.
+///
+/// The collection cannot be written until the job completes.
+pub fn rebuild_indexes_store(collection: &CollectionKey) -> Result<(), String> {
+    if is_job_running() {
+        return Err(ERROR_COLLECTION_JOB_RUNNING.to_string());
+    }
+
+    get_state_rule(collection)?;
+
+    let config = get_config();
+
+    let mut steps: VecDeque<CollectionJobStep> = VecDeque::new();
+
+    for aggregate in config
+        .as_ref()
+        .and_then(|config| config.aggregates.as_ref())
+        .into_iter()
+        .flatten()
+        .filter(|aggregate| aggregate.collection == *collection)
+    {
+        steps.push_back(CollectionJobStep::ResetAggregates(
+            aggregate.target_collection.clone(),
+        ));
+    }
+
+    steps.push_back(CollectionJobStep::ScanDocs);
+
+    for view in config
+        .as_ref()
+        .and_then(|config| config.views.as_ref())
+        .into_iter()
+        .flatten()
+        .filter(|view| view.sources.contains(collection))
+    {
+        steps.push_back(CollectionJobStep::PruneView(view.collection.clone()));
+    }
+
+    start_job(CollectionJobKind::RebuildIndexes, collection, steps);
+
+    Ok(())
+}
+
+/// Starts compressing the documents of a collection in stable memory that were written before its compression was
+/// enabled. The documents are only written again if they shrink. The space is reused by the next writes, the memory
+/// itself does not shrink.
+pub fn compact_collection_store(collection: &CollectionKey) -> Result<(), String> {
+    if is_job_running() {
+        return Err(ERROR_COLLECTION_JOB_RUNNING.to_string());
+    }
+
+    let rule = get_state_rule(collection)?;
+
+    if matches!(rule.mem(), Memory::Heap) {
+        return Err(ERROR_COLLECTION_JOB_HEAP.to_string());
+    }
+
+    start_job(
+        CollectionJobKind::CompactCollection,
+        collection,
+        VecDeque::from([CollectionJobStep::CompactDocs]),
+    );
+
+    Ok(())
+}
+
+fn start_job(
+    kind: CollectionJobKind,
+    collection: &CollectionKey,
+    steps: VecDeque<CollectionJobStep>,
+) {
+    let total_docs = get_db_counters(collection).map_or(0, |counters| counters.items_count);
+
+    init_job(
+        CollectionJobReport {
+            kind,
+            collection: collection.clone(),
+            started_at: time(),
+            completed_at: None,
+            total_docs,
+            processed_docs: 0,
+            rewritten_docs: 0,
+            reclaimed_bytes: 0,
+            error: None,
+        },
+        steps,
+    );
+
+    set_timer(Duration::ZERO, run_next_step);
+}
+
+fn run_next_step() {
+    let Some((collection, step, cursor)) = get_next_step() else {
+        complete_job();
+        return;
+    };
+
+    match run_step(&collection, &step, &cursor) {
+        Ok(_) => {
+            set_timer(Duration::ZERO, run_next_step);
+        }
+        Err(err) => complete_report(Some(err), time()),
+    }
+}
+
+fn run_step(
+    collection: &CollectionKey,
+    step: &CollectionJobStep,
+    cursor: &Option<Key>,
+) -> Result<(), String> {
+    let docs_collection = match step {
+        CollectionJobStep::ResetAggregates(target) | CollectionJobStep::PruneView(target) => target,
+        CollectionJobStep::ScanDocs | CollectionJobStep::CompactDocs => collection,
+    };
+
+    let docs = next_docs(docs_collection, cursor)?;
+
+    let Some(last_key) = docs.last().map(|(key, _)| key.clone()) else {
+        complete_step();
+        return Ok(());
+    };
+
+    let length = docs.len() as u64;
+
+    match step {
+        CollectionJobStep::ResetAggregates(target) => {
+            let rule = get_state_rule(target)?;
+
+            for (key, _) in docs {
+                delete_state_doc(target, &key, &rule)?;
+            }
+
+            insert_results(last_key, 0, length, 0, Vec::new());
+        }
+        CollectionJobStep::ScanDocs => {
+            let mut items: Vec<StatsItem> = Vec::new();
+
+            for (key, doc) in docs {
+                items.push(StatsItem::from(&doc));
+
+                update_aggregates(collection, &None, &Some(doc))?;
+
+                update_views(collection, &key)?;
+            }
+
+            insert_results(last_key, length, 0, 0, items);
+        }
+        CollectionJobStep::PruneView(_) => {
+            // The views are recomputed from the documents of their sources, with the same key
+            for (key, _) in docs {
+                update_views(collection, &key)?;
+            }
+
+            insert_results(last_key, 0, length, 0, Vec::new());
+        }
+        CollectionJobStep::CompactDocs => {
+            let rule = get_state_rule(collection)?;
+
+            let keys: Vec<Key> = docs.into_iter().map(|(key, _)| key).collect();
+
+            let (rewritten_docs, reclaimed_bytes) = compact_docs_stable(collection, &keys, &rule);
+
+            insert_results(
+                last_key,
+                length,
+                rewritten_docs,
+                reclaimed_bytes,
+                Vec::new(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn complete_job() {
+    let rebuilt = get_report().filter(|report| report.kind == CollectionJobKind::RebuildIndexes);
+
+    if let Some(report) = rebuilt {
+        set_db_counters(&report.collection, take_counters());
+
+        // The clients that cache the collection are notified that its documents might have been derived differently
+        increment_db_version(&report.collection);
+
+        refresh_quota_status();
+    }
+
+    complete_report(None, time());
+}
+
+fn next_docs(collection: &CollectionKey, cursor: &Option<Key>) -> Result<Vec<(Key, Doc)>, String> {
+    let rule = get_state_rule(collection)?;
+
+    STATE.with(|state| {
+        let state = state.borrow();
+
+        match rule.mem() {
+            Memory::Heap => Ok(iter_docs_heap_after(collection, cursor, &state.heap.db.db)?
+                .take(COLLECTION_JOB_DOCS_PER_STEP)
+                .map(|(key, doc)| (key.clone(), doc.clone()))
+                .collect()),
+            Memory::Stable => Ok(iter_docs_stable_after(collection, cursor, &state.stable.db)
+                .take(COLLECTION_JOB_DOCS_PER_STEP)
+                .map(|(key, doc)| (key.key, doc))
+                .collect()),
+        }
+    })
+}
@@ -0,0 +1,73 @@
+pub mod state {
+    use crate::maintenance::types::interface::CollectionJobReport;
+    use crate::stats::types::state::CollectionCounters;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::state::Timestamp;
+    use std::collections::VecDeque;
+
+    /// - `steps`: The steps that remain, the first one being processed.
+    /// - `cursor`: The last document processed by the current step. The next batch resumes after it.
+    /// - `counters`: The statistics of the documents scanned so far, which replace those of the collection once the
+    ///   indexes are rebuilt.
+    /// - `stepped_at`: When the job last progressed, to detect a job that was interrupted.
+    #[derive(Default, Clone)]
+    pub struct MaintenanceRuntimeState {
+        pub report: Option<CollectionJobReport>,
+        pub steps: VecDeque<CollectionJobStep>,
+        pub cursor: Option<Key>,
+        pub counters: CollectionCounters,
+        pub stepped_at: Option<Timestamp>,
+    }
+
+    /// - `ResetAggregates`: Deletes the documents of the target collection of an aggregate, to count them again.
+    /// - `ScanDocs`: Counts the documents of the collection and applies them to its aggregates and views.
+    /// - `PruneView`: Recomputes the documents of a view, which removes those whose sources were deleted.
+    /// - `CompactDocs`: Writes the documents of the collection again with its compression.
+    #[derive(Clone)]
+    pub enum CollectionJobStep {
+        ResetAggregates(CollectionKey),
+        ScanDocs,
+        PruneView(CollectionKey),
+        CompactDocs,
+    }
+}
+
+pub mod interface {
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::state::Timestamp;
+    use serde::{Deserialize, Serialize};
+
+    /// - `RebuildIndexes`: Derives the statistics, the aggregates, the views and the version of a collection again
+    ///   from its documents.
+    /// - `CompactCollection`: Compresses the documents that were written before the compression of the collection
+    ///   was enabled.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq)]
+    pub enum CollectionJobKind {
+        RebuildIndexes,
+        CompactCollection,
+    }
+
+    /// The progress of the last job run on a collection.
+    ///
+    /// - `completed_at`: `None` while the job is still running.
+    /// - `total_docs`: The documents of the collection when the job started, according to its statistics.
+    /// - `processed_docs`: The documents of the collection processed so far.
+    /// - `rewritten_docs`: The documents written again - i.e. the documents of the views and the aggregates that were
+    ///   derived again, or the documents that were compressed.
+    /// - `reclaimed_bytes`: The bytes saved by the compression of the documents.
+    /// - `error`: The reason why the job stopped before it completed, if any.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct CollectionJobReport {
+        pub kind: CollectionJobKind,
+        pub collection: CollectionKey,
+        pub started_at: Timestamp,
+        pub completed_at: Option<Timestamp>,
+        pub total_docs: u64,
+        pub processed_docs: u64,
+        pub rewritten_docs: u64,
+        pub reclaimed_bytes: u64,
+        pub error: Option<String>,
+    }
+}
@@ -0,0 +1,80 @@
+use crate::manifest::store::get_manifest;
+use junobuild_shared::types::core::Blob;
+use junobuild_storage::certification::types::certified::CertifiedAssetHashes;
+use junobuild_storage::constants::{RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_500};
+use junobuild_storage::http::response::{build_static_response, error_response, upgrade_response};
+use junobuild_storage::http::types::{HeaderField, HttpRequest, HttpResponse};
+use junobuild_storage::runtime::{is_certified_static, update_certified_static};
+
+pub const MANIFEST_PATH: &str = "/_juno/manifest.json";
+
+pub fn is_manifest_request(HttpRequest { method, url, .. }: &HttpRequest) -> bool {
+    let path = url.split('?').next().unwrap_or_default();
+
+    (method == "GET" || method == "HEAD") && path == MANIFEST_PATH
+}
+
+/// The manifest is certified again each time one of the rules or configurations it describes is modified, therefore
+/// it is answered with a query. Should the manifest not be certified yet - e.g. right after an upgrade, until the
+/// certification is rebuilt - the request is upgraded and answered with an update call.
+pub fn http_request(
+    HttpRequest {
+        certificate_version,
+        ..
+    }: &HttpRequest,
+) -> HttpResponse {
+    let Ok(body) = manifest_body() else {
+        return upgrade_response();
+    };
+
+    let path = MANIFEST_PATH.to_string();
+    let headers = manifest_headers();
+
+    if !is_certified_static(&path, &headers, &body, certificate_version) {
+        return upgrade_response();
+    }
+
+    build_static_response(&path, *certificate_version, &headers, &body)
+}
+
+pub fn http_request_update(_request: HttpRequest) -> HttpResponse {
+    match manifest_body() {
+        Err(err) => error_response(RESPONSE_STATUS_CODE_500, err),
+        Ok(body) => HttpResponse {
+            body,
+            headers: manifest_headers(),
+            status_code: RESPONSE_STATUS_CODE_200,
+            streaming_strategy: None,
+            upgrade: None,
+        },
+    }
+}
+
+/// Certifies the manifest after one of the rules or configurations it describes was modified.
+pub fn certify_manifest() {
+    if let Ok(body) = manifest_body() {
+        update_certified_static(&MANIFEST_PATH.to_string(), &[(manifest_headers(), body)]);
+    }
+}
+
+/// Certifies the manifest while the tree of the certification is (re)built.
+pub fn certify_manifest_response(asset_hashes: &mut CertifiedAssetHashes) {
+    if let Ok(body) = manifest_body() {
+        asset_hashes.insert_static_v2(&MANIFEST_PATH.to_string(), &manifest_headers(), &body);
+    }
+}
+
+fn manifest_body() -> Result<Blob, String> {
+    serde_json::to_vec(&get_manifest()).map_err(|err| err.to_string())
+}
+
+fn manifest_headers() -> Vec<HeaderField> {
+    Vec::from([
+        HeaderField(
+            "Content-Type".to_string(),
+            "application/json; charset=utf-8".to_string(),
+        ),
+        HeaderField("Cache-Control".to_string(), "no-store".to_string()),
+        HeaderField("Access-Control-Allow-Origin".to_string(), "*".to_string()),
+    ])
+}
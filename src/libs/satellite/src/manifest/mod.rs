@@ -0,0 +1,3 @@
+pub mod http;
+pub mod store;
+pub mod types;
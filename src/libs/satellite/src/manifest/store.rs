@@ -0,0 +1,64 @@
+use crate::analytics::http::{ANALYTICS_PAGE_VIEW_PATH, ANALYTICS_TRACK_EVENT_PATH};
+use crate::analytics::store::get_config as get_analytics_config;
+use crate::auth::store::get_config as get_auth_config;
+use crate::flags::store::list_feature_flags_store;
+use crate::health::http::HEALTH_PATH;
+use crate::manifest::http::MANIFEST_PATH;
+use crate::manifest::types::manifest::{
+    Manifest, ManifestCollections, ManifestFeatures, ManifestVersions,
+};
+use crate::moderation::store::get_config as get_moderation_config;
+use crate::rules::store::{get_rules_db, get_rules_storage};
+use crate::traces::store::get_config as get_traces_config;
+use crate::version::SATELLITE_VERSION;
+use junobuild_collections::assert_stores::public_permission;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::Rule;
+
+pub fn get_manifest() -> Manifest {
+    let features = get_manifest_features();
+
+    let collections = ManifestCollections {
+        db: public_collections(get_rules_db()),
+        storage: public_collections(get_rules_storage()),
+    };
+
+    let mut endpoints: Vec<String> = vec![HEALTH_PATH.to_string(), MANIFEST_PATH.to_string()];
+
+    if features.analytics {
+        endpoints.push(ANALYTICS_PAGE_VIEW_PATH.to_string());
+        endpoints.push(ANALYTICS_TRACK_EVENT_PATH.to_string());
+    }
+
+    Manifest {
+        versions: ManifestVersions {
+            satellite: SATELLITE_VERSION.to_string(),
+        },
+        features,
+        collections,
+        endpoints,
+    }
+}
+
+pub fn get_manifest_features() -> ManifestFeatures {
+    ManifestFeatures {
+        authentication: get_auth_config().is_some_and(|config| config.internet_identity.is_some()),
+        analytics: get_analytics_config().is_some_and(|config| config.orbiter_id.is_some()),
+        feature_flags: !list_feature_flags_store().is_empty(),
+        moderation: get_moderation_config()
+            .is_some_and(|config| !config.db.is_empty() || !config.storage.is_empty()),
+        traces: get_traces_config().is_some_and(|config| config.enabled),
+    }
+}
+
+fn public_collections(rules: Vec<(CollectionKey, Rule)>) -> Vec<CollectionKey> {
+    let mut collections: Vec<CollectionKey> = rules
+        .into_iter()
+        .filter(|(_, rule)| public_permission(&rule.read))
+        .map(|(collection, _)| collection)
+        .collect();
+
+    collections.sort();
+
+    collections
+}
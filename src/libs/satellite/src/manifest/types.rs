@@ -0,0 +1,38 @@
+pub mod manifest {
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use serde::{Deserialize, Serialize};
+
+    /// The description of the satellite served as JSON on `/_juno/manifest.json`, which lets tooling and SDKs
+    /// introspect its capabilities at runtime.
+    ///
+    /// - `collections`: Only the collections readable by anyone are listed.
+    /// - `endpoints`: The HTTP endpoints served by the satellite next to the assets.
+    #[derive(Serialize)]
+    pub struct Manifest {
+        pub versions: ManifestVersions,
+        pub features: ManifestFeatures,
+        pub collections: ManifestCollections,
+        pub endpoints: Vec<String>,
+    }
+
+    #[derive(Serialize)]
+    pub struct ManifestVersions {
+        pub satellite: String,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ManifestFeatures {
+        pub authentication: bool,
+        pub analytics: bool,
+        pub feature_flags: bool,
+        pub moderation: bool,
+        pub traces: bool,
+    }
+
+    #[derive(Serialize)]
+    pub struct ManifestCollections {
+        pub db: Vec<CollectionKey>,
+        pub storage: Vec<CollectionKey>,
+    }
+}
@@ -1,3 +1,4 @@
+use crate::defrag::types::interface::MemoryRegion;
 use crate::types::state::{StableState, State};
 use ic_stable_structures::memory_manager::{MemoryId, MemoryManager};
 use ic_stable_structures::DefaultMemoryImpl;
@@ -9,6 +10,11 @@ const UPGRADES: MemoryId = MemoryId::new(0);
 const DB: MemoryId = MemoryId::new(1);
 const ASSETS: MemoryId = MemoryId::new(2);
 const CONTENT_CHUNKS: MemoryId = MemoryId::new(3);
+const AUDIT: MemoryId = MemoryId::new(4);
+const REVISIONS: MemoryId = MemoryId::new(5);
+const GC_CHUNKS: MemoryId = MemoryId::new(6);
+const GC_DOCS: MemoryId = MemoryId::new(7);
+const GC_ATTACHMENTS: MemoryId = MemoryId::new(8);
 
 thread_local! {
     pub static STATE: RefCell<State> = RefCell::default();
@@ -33,10 +39,46 @@ fn get_memory_content_chunks() -> Memory {
     MEMORY_MANAGER.with(|m| m.borrow().get(CONTENT_CHUNKS))
 }
 
+fn get_memory_audit() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(AUDIT))
+}
+
+fn get_memory_revisions() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(REVISIONS))
+}
+
+fn get_memory_gc_chunks() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(GC_CHUNKS))
+}
+
+fn get_memory_gc_docs() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(GC_DOCS))
+}
+
+fn get_memory_gc_attachments() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(GC_ATTACHMENTS))
+}
+
+pub fn get_memory_region(region: &MemoryRegion) -> Memory {
+    match region {
+        MemoryRegion::Upgrades => get_memory_upgrades(),
+        MemoryRegion::Db => get_memory_db(),
+        MemoryRegion::Assets => get_memory_assets(),
+        MemoryRegion::ContentChunks => get_memory_content_chunks(),
+        MemoryRegion::Audit => get_memory_audit(),
+        MemoryRegion::Revisions => get_memory_revisions(),
+    }
+}
+
 pub fn init_stable_state() -> StableState {
     StableState {
         db: StableBTreeMap::init(get_memory_db()),
         assets: StableBTreeMap::init(get_memory_assets()),
         content_chunks: StableBTreeMap::init(get_memory_content_chunks()),
+        audit: StableBTreeMap::init(get_memory_audit()),
+        revisions: StableBTreeMap::init(get_memory_revisions()),
+        gc_chunks: StableBTreeMap::init(get_memory_gc_chunks()),
+        gc_docs: StableBTreeMap::init(get_memory_gc_docs()),
+        gc_attachments: StableBTreeMap::init(get_memory_gc_attachments()),
     }
 }
@@ -0,0 +1,16 @@
+use crate::moderation::msg::ERROR_COLLECTION_NOT_MODERATED;
+use crate::moderation::types::interface::SetModeration;
+use junobuild_shared::assert::assert_version;
+use junobuild_shared::types::state::Version;
+
+pub fn assert_set_moderation(
+    moderated: bool,
+    value: &SetModeration,
+    current_version: Option<Version>,
+) -> Result<(), String> {
+    if !moderated {
+        return Err(ERROR_COLLECTION_NOT_MODERATED.to_string());
+    }
+
+    assert_version(value.version, current_version)
+}
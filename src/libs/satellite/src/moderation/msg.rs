@@ -0,0 +1,2 @@
+pub const ERROR_COLLECTION_NOT_MODERATED: &str = "Collection does not require moderation.";
+pub const ERROR_DOC_NOT_FOUND: &str = "Document not found.";
@@ -0,0 +1,33 @@
+use crate::memory::STATE;
+use crate::moderation::types::config::ModerationConfig;
+use crate::moderation::types::state::ModerationHeapState;
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+pub fn get_config() -> Option<ModerationConfig> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .moderation
+            .as_ref()
+            .map(|moderation| moderation.config.clone())
+    })
+}
+
+pub fn insert_config(config: &ModerationConfig) {
+    STATE.with(|state| insert_config_impl(config, &mut state.borrow_mut().heap.moderation))
+}
+
+fn insert_config_impl(config: &ModerationConfig, state: &mut Option<ModerationHeapState>) {
+    match state {
+        None => {
+            *state = Some(ModerationHeapState {
+                config: config.clone(),
+            })
+        }
+        Some(state) => state.config = config.clone(),
+    }
+}
@@ -0,0 +1,181 @@
+use crate::db::state::{
+    get_doc as get_state_doc, get_rule as get_state_db_rule, insert_doc as insert_state_doc,
+};
+use crate::db::types::state::{Doc, DocContext, DocUpsert};
+use crate::manifest::http::certify_manifest;
+use crate::moderation::assert::assert_set_moderation;
+use crate::moderation::msg::ERROR_DOC_NOT_FOUND;
+use crate::moderation::state::{
+    get_config as get_state_config, insert_config as insert_state_config,
+};
+use crate::moderation::types::config::ModerationConfig;
+use crate::moderation::types::interface::SetModeration;
+use crate::storage::state::{
+    get_asset as get_state_asset, get_config as get_storage_config,
+    get_rule as get_state_storage_rule, insert_asset as insert_state_asset,
+};
+use candid::Principal;
+use ic_cdk::api::time;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::controllers::is_controller;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::{Controllers, ModerationStatus};
+use junobuild_storage::msg::ERROR_ASSET_NOT_FOUND;
+use junobuild_storage::runtime::update_certified_asset as update_runtime_certified_asset;
+use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::store::{Asset, AssetKey};
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+pub fn set_config(config: &ModerationConfig) {
+    insert_state_config(config);
+
+    certify_manifest();
+}
+
+pub fn get_config() -> Option<ModerationConfig> {
+    get_state_config()
+}
+
+fn is_db_moderated(collection: &CollectionKey) -> bool {
+    get_state_config().is_some_and(|config| config.db.contains(collection))
+}
+
+fn is_storage_moderated(collection: &CollectionKey) -> bool {
+    get_state_config().is_some_and(|config| config.storage.contains(collection))
+}
+
+// ---------------------------------------------------------
+// Write
+// ---------------------------------------------------------
+
+/// The moderation status of a document that is written: in a moderated collection, the changes of the users have to
+/// be reviewed again while those of the controllers are approved right away.
+pub fn doc_moderation_on_write(
+    caller: Principal,
+    controllers: &Controllers,
+    collection: &CollectionKey,
+) -> Option<ModerationStatus> {
+    moderation_on_write(caller, controllers, is_db_moderated(collection))
+}
+
+/// Applies the moderation status to an asset that was just committed. The storage does not know about moderation,
+/// that is why the asset is saved again with its status when the collection requires a review.
+pub fn moderate_committed_asset(
+    caller: Principal,
+    controllers: &Controllers,
+    asset: Asset,
+) -> Result<Asset, String> {
+    let moderation = moderation_on_write(
+        caller,
+        controllers,
+        is_storage_moderated(&asset.key.collection),
+    );
+
+    if moderation.is_none() {
+        return Ok(asset);
+    }
+
+    let rule = get_state_storage_rule(&asset.key.collection)?;
+
+    let asset = Asset {
+        key: AssetKey {
+            moderation,
+            ..asset.key.clone()
+        },
+        ..asset
+    };
+
+    insert_state_asset(&asset.key.collection, &asset.key.full_path, &asset, &rule);
+
+    Ok(asset)
+}
+
+fn moderation_on_write(
+    caller: Principal,
+    controllers: &Controllers,
+    moderated: bool,
+) -> Option<ModerationStatus> {
+    if !moderated {
+        return None;
+    }
+
+    if is_controller(caller, controllers) {
+        return Some(ModerationStatus::Approved);
+    }
+
+    Some(ModerationStatus::Pending)
+}
+
+// ---------------------------------------------------------
+// Status
+// ---------------------------------------------------------
+
+/// Sets the moderation status of a document. Only its status, version and update timestamp change.
+pub fn set_doc_moderation_store(
+    collection: &CollectionKey,
+    key: &Key,
+    value: &SetModeration,
+) -> Result<DocContext<DocUpsert>, String> {
+    let rule = get_state_db_rule(collection)?;
+
+    let current_doc =
+        get_state_doc(collection, key, &rule)?.ok_or_else(|| ERROR_DOC_NOT_FOUND.to_string())?;
+
+    assert_set_moderation(is_db_moderated(collection), value, current_doc.version)?;
+
+    let doc = Doc {
+        moderation: Some(value.status.clone()),
+        updated_at: time(),
+        version: Some(current_doc.version.unwrap_or_default() + 1),
+        ..current_doc.clone()
+    };
+
+    let (_evicted_doc, after) = insert_state_doc(collection, key, &doc, &rule)?;
+
+    Ok(DocContext {
+        key: key.clone(),
+        collection: collection.clone(),
+        data: DocUpsert {
+            before: Some(current_doc),
+            after,
+        },
+    })
+}
+
+/// Sets the moderation status of an asset. The certification is updated accordingly, given that only approved assets
+/// are served.
+pub fn set_asset_moderation_store(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    value: &SetModeration,
+) -> Result<Asset, String> {
+    let rule = get_state_storage_rule(collection)?;
+
+    let current_asset = get_state_asset(collection, full_path, &rule)
+        .ok_or_else(|| ERROR_ASSET_NOT_FOUND.to_string())?;
+
+    assert_set_moderation(
+        is_storage_moderated(collection),
+        value,
+        current_asset.version,
+    )?;
+
+    let asset = Asset {
+        key: AssetKey {
+            moderation: Some(value.status.clone()),
+            ..current_asset.key.clone()
+        },
+        updated_at: time(),
+        version: Some(current_asset.version.unwrap_or_default() + 1),
+        ..current_asset
+    };
+
+    insert_state_asset(collection, full_path, &asset, &rule);
+
+    update_runtime_certified_asset(&asset, &get_storage_config());
+
+    Ok(asset)
+}
@@ -0,0 +1,37 @@
+pub mod state {
+    use crate::moderation::types::config::ModerationConfig;
+    use candid::CandidType;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct ModerationHeapState {
+        pub config: ModerationConfig,
+    }
+}
+
+pub mod config {
+    use candid::{CandidType, Deserialize};
+    use junobuild_collections::types::core::CollectionKey;
+    use serde::Serialize;
+
+    /// The collections of the datastore and the storage in which documents, respectively assets, have to be approved
+    /// before being listed and served publicly.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct ModerationConfig {
+        pub db: Vec<CollectionKey>,
+        pub storage: Vec<CollectionKey>,
+    }
+}
+
+pub mod interface {
+    use candid::CandidType;
+    use junobuild_shared::types::state::{ModerationStatus, Version};
+    use serde::Deserialize;
+
+    /// The new moderation status of a document or an asset - `version` being its current version, as for any update.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct SetModeration {
+        pub status: ModerationStatus,
+        pub version: Option<Version>,
+    }
+}
@@ -0,0 +1,16 @@
+use crate::ownership::msg::ERROR_OWNER_ANONYMOUS;
+use junobuild_shared::assert::assert_version;
+use junobuild_shared::types::state::{UserId, Version};
+use junobuild_shared::utils::principal_anonymous;
+
+pub fn assert_set_owner(
+    owner: UserId,
+    user_version: Option<Version>,
+    current_version: Option<Version>,
+) -> Result<(), String> {
+    if principal_anonymous(owner) {
+        return Err(ERROR_OWNER_ANONYMOUS.to_string());
+    }
+
+    assert_version(user_version, current_version)
+}
@@ -0,0 +1,4 @@
+mod assert;
+mod msg;
+pub mod store;
+pub mod types;
@@ -0,0 +1,2 @@
+pub const ERROR_OWNER_ANONYMOUS: &str = "The owner cannot be anonymous.";
+pub const ERROR_DOC_NOT_FOUND: &str = "Document not found.";
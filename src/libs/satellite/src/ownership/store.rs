@@ -0,0 +1,78 @@
+use crate::db::state::{
+    get_doc as get_state_doc, get_rule as get_state_db_rule, insert_doc as insert_state_doc,
+};
+use crate::db::types::state::{Doc, DocContext, DocUpsert};
+use crate::ownership::assert::assert_set_owner;
+use crate::ownership::msg::ERROR_DOC_NOT_FOUND;
+use crate::ownership::types::interface::{SetAssetOwner, SetDocOwner};
+use crate::storage::state::{
+    get_asset as get_state_asset, get_rule as get_state_storage_rule,
+    insert_asset as insert_state_asset,
+};
+use ic_cdk::api::time;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::core::Key;
+use junobuild_storage::msg::ERROR_ASSET_NOT_FOUND;
+use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::store::{Asset, AssetKey};
+
+/// Reassigns a document to another owner. The data of the document are left untouched, only its owner, version and
+/// update timestamp change.
+pub fn set_doc_owner_store(
+    collection: &CollectionKey,
+    key: &Key,
+    value: &SetDocOwner,
+) -> Result<DocContext<DocUpsert>, String> {
+    let rule = get_state_db_rule(collection)?;
+
+    let current_doc =
+        get_state_doc(collection, key, &rule)?.ok_or_else(|| ERROR_DOC_NOT_FOUND.to_string())?;
+
+    assert_set_owner(value.owner, value.version, current_doc.version)?;
+
+    let doc = Doc {
+        owner: value.owner,
+        updated_at: time(),
+        version: Some(current_doc.version.unwrap_or_default() + 1),
+        ..current_doc.clone()
+    };
+
+    let (_evicted_doc, after) = insert_state_doc(collection, key, &doc, &rule)?;
+
+    Ok(DocContext {
+        key: key.clone(),
+        collection: collection.clone(),
+        data: DocUpsert {
+            before: Some(current_doc),
+            after,
+        },
+    })
+}
+
+/// Reassigns an asset to another owner. The content - and therefore the certification - of the asset does not change.
+pub fn set_asset_owner_store(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    value: &SetAssetOwner,
+) -> Result<Asset, String> {
+    let rule = get_state_storage_rule(collection)?;
+
+    let current_asset = get_state_asset(collection, full_path, &rule)
+        .ok_or_else(|| ERROR_ASSET_NOT_FOUND.to_string())?;
+
+    assert_set_owner(value.owner, value.version, current_asset.version)?;
+
+    let asset = Asset {
+        key: AssetKey {
+            owner: value.owner,
+            ..current_asset.key.clone()
+        },
+        updated_at: time(),
+        version: Some(current_asset.version.unwrap_or_default() + 1),
+        ..current_asset
+    };
+
+    insert_state_asset(collection, full_path, &asset, &rule);
+
+    Ok(asset)
+}
@@ -0,0 +1,19 @@
+pub mod interface {
+    use candid::CandidType;
+    use junobuild_shared::types::state::{UserId, Version};
+    use serde::{Deserialize, Serialize};
+
+    /// The new owner of a document - `version` being the current version of the document, as for any update.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct SetDocOwner {
+        pub owner: UserId,
+        pub version: Option<Version>,
+    }
+
+    /// The new owner of an asset - `version` being the current version of the asset.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct SetAssetOwner {
+        pub owner: UserId,
+        pub version: Option<Version>,
+    }
+}
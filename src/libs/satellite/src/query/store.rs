@@ -0,0 +1,61 @@
+use crate::db::store::{count_collection_docs_store, get_config_store, list_docs_store};
+use crate::query::types::interface::QueryEstimate;
+use crate::storage::store::{
+    count_collection_assets_store, get_config_store as get_storage_config_store, list_assets_store,
+};
+use crate::types::interface::RulesType;
+use candid::{CandidType, Principal};
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::list::encoded_size;
+use junobuild_shared::types::list::{ListLimits, ListParams, ListResults};
+
+/// Performs a listing and reports its cost instead of its results, for developers to find out which filters
+/// scan too many entries or return too much data before those are used in production.
+pub fn estimate_query_store(
+    caller: Principal,
+    rules_type: &RulesType,
+    collection: &CollectionKey,
+    filter: &ListParams,
+) -> Result<QueryEstimate, String> {
+    match rules_type {
+        RulesType::Db => {
+            let results = list_docs_store(caller, collection.clone(), filter)?;
+            let collection_length = count_collection_docs_store(collection)?;
+            let limits = get_config_store().and_then(|config| config.list_limits);
+
+            Ok(estimate(&results, collection_length, &limits))
+        }
+        RulesType::Storage => {
+            let results = list_assets_store(caller, collection, filter)?;
+            let collection_length = count_collection_assets_store(collection)?;
+            let limits = get_storage_config_store().list_limits;
+
+            Ok(estimate(&results, collection_length, &limits))
+        }
+    }
+}
+
+fn estimate<T: CandidType>(
+    results: &ListResults<T>,
+    collection_length: usize,
+    limits: &Option<ListLimits>,
+) -> QueryEstimate {
+    let scanned_items = limits
+        .as_ref()
+        .and_then(|limits| limits.max_scanned_items)
+        .map_or(collection_length, |max_scanned_items| {
+            collection_length.min(max_scanned_items)
+        });
+
+    QueryEstimate {
+        scanned_items,
+        matches_length: results.matches_length,
+        items_length: results.items_length,
+        returned_bytes: results
+            .items
+            .iter()
+            .map(|(key, item)| encoded_size(key, item))
+            .sum(),
+        truncated: results.truncated.clone(),
+    }
+}
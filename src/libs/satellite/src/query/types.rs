@@ -0,0 +1,21 @@
+pub mod interface {
+    use candid::CandidType;
+    use junobuild_shared::types::list::ListTruncated;
+    use serde::Deserialize;
+
+    /// The cost of a listing, as it would be performed with the same filter.
+    ///
+    /// - `scanned_items`: The number of entries of the collection that are read and filtered.
+    /// - `matches_length`: The number of entries matching the filter.
+    /// - `items_length`: The number of entries that are returned.
+    /// - `returned_bytes`: The size, Candid encoded, of the entries that are returned.
+    /// - `truncated`: The limit that would cut short the listing, if any.
+    #[derive(CandidType, Deserialize)]
+    pub struct QueryEstimate {
+        pub scanned_items: usize,
+        pub matches_length: usize,
+        pub items_length: usize,
+        pub returned_bytes: usize,
+        pub truncated: Option<ListTruncated>,
+    }
+}
@@ -0,0 +1,108 @@
+use crate::db::types::interface::SetDoc;
+use crate::db::types::state::Doc;
+use crate::quotas::msg::{
+    ERROR_CODE_USER_QUOTA_MAX_BYTES, ERROR_CODE_USER_QUOTA_MAX_ITEMS, ERROR_USER_QUOTA_MAX_BYTES,
+    ERROR_USER_QUOTA_MAX_ITEMS,
+};
+use crate::stats::state::{get_db_owner_counters, get_storage_owner_counters};
+use crate::stats::types::runtime::StatsItem;
+use crate::stats::types::state::OwnerCounters;
+use candid::Principal;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{Rule, UserQuota};
+use junobuild_shared::controllers::is_controller;
+use junobuild_shared::errors::JunoError;
+use junobuild_shared::types::state::{Controllers, UserId};
+use junobuild_storage::types::store::Asset;
+
+pub fn assert_doc_user_quota(
+    caller: Principal,
+    controllers: &Controllers,
+    collection: &CollectionKey,
+    rule: &Rule,
+    current_doc: &Option<Doc>,
+    value: &SetDoc,
+) -> Result<(), String> {
+    let owner = current_doc.as_ref().map_or(caller, |doc| doc.owner);
+
+    assert_user_quota(
+        caller,
+        controllers,
+        &rule.user_quota,
+        || get_db_owner_counters(collection, &owner),
+        current_doc.as_ref().map(|doc| StatsItem::from(doc).bytes),
+        value.data.len() as u128,
+    )
+}
+
+pub fn assert_asset_user_quota(
+    caller: Principal,
+    controllers: &Controllers,
+    owner: &UserId,
+    collection: &CollectionKey,
+    rule: &Rule,
+    current: &Option<Asset>,
+    bytes: u128,
+) -> Result<(), String> {
+    assert_user_quota(
+        caller,
+        controllers,
+        &rule.user_quota,
+        || get_storage_owner_counters(collection, owner),
+        current.as_ref().map(|asset| StatsItem::from(asset).bytes),
+        bytes,
+    )
+}
+
+/// Asserts the usage of the owner - as counted by the statistics - once the item is written.
+/// A new item counts as one more item, an update only replaces the size of the current item.
+fn assert_user_quota(
+    caller: Principal,
+    controllers: &Controllers,
+    quota: &Option<UserQuota>,
+    usage: impl FnOnce() -> Option<OwnerCounters>,
+    current_bytes: Option<u128>,
+    bytes: u128,
+) -> Result<(), String> {
+    let quota = match quota {
+        None => {
+            return Ok(());
+        }
+        Some(quota) => quota,
+    };
+
+    if is_controller(caller, controllers) {
+        return Ok(());
+    }
+
+    let usage = usage().unwrap_or_default();
+
+    if let Some(max_items) = quota.max_items {
+        if current_bytes.is_none() && usage.items_count >= max_items {
+            return Err(JunoError::new(
+                ERROR_CODE_USER_QUOTA_MAX_ITEMS,
+                ERROR_USER_QUOTA_MAX_ITEMS,
+            )
+            .with_param("max_items", max_items)
+            .into());
+        }
+    }
+
+    if let Some(max_bytes) = quota.max_bytes {
+        let total_bytes = usage
+            .items_bytes
+            .saturating_sub(current_bytes.unwrap_or_default())
+            + bytes;
+
+        if total_bytes > max_bytes {
+            return Err(JunoError::new(
+                ERROR_CODE_USER_QUOTA_MAX_BYTES,
+                ERROR_USER_QUOTA_MAX_BYTES,
+            )
+            .with_param("max_bytes", max_bytes)
+            .into());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod assert;
+mod msg;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,4 @@
+pub const ERROR_USER_QUOTA_MAX_ITEMS: &str = "Maximal number of items per user reached.";
+pub const ERROR_CODE_USER_QUOTA_MAX_ITEMS: &str = "error_user_quota_max_items";
+pub const ERROR_USER_QUOTA_MAX_BYTES: &str = "Maximal size per user exceeded.";
+pub const ERROR_CODE_USER_QUOTA_MAX_BYTES: &str = "error_user_quota_max_bytes";
@@ -0,0 +1,48 @@
+use crate::memory::STATE;
+use crate::quotas::types::config::QuotaThresholds;
+use crate::quotas::types::state::QuotasHeapState;
+use junobuild_shared::types::interface::QuotaStatus;
+
+pub fn get_thresholds() -> Option<QuotaThresholds> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .quotas
+            .as_ref()
+            .and_then(|quotas| quotas.thresholds.clone())
+    })
+}
+
+pub fn insert_thresholds(thresholds: &QuotaThresholds) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .quotas
+            .get_or_insert_with(QuotasHeapState::default)
+            .thresholds = Some(thresholds.clone())
+    })
+}
+
+pub fn get_status() -> Option<QuotaStatus> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .quotas
+            .as_ref()
+            .and_then(|quotas| quotas.status.clone())
+    })
+}
+
+pub fn insert_status(status: &QuotaStatus) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .quotas
+            .get_or_insert_with(QuotasHeapState::default)
+            .status = Some(status.clone())
+    })
+}
@@ -0,0 +1,71 @@
+use crate::quotas::state::{
+    get_status as get_state_status, get_thresholds as get_state_thresholds,
+    insert_status as insert_state_status, insert_thresholds as insert_state_thresholds,
+};
+use crate::quotas::types::config::QuotaThresholds;
+use crate::stats::state::get_db_items_count;
+use ic_cdk::api::time;
+use junobuild_shared::canister::memory_size;
+use junobuild_shared::types::interface::{QuotaStatus, QuotaWarning};
+
+pub fn set_thresholds(thresholds: &QuotaThresholds) {
+    insert_state_thresholds(thresholds);
+
+    refresh_status();
+}
+
+pub fn get_thresholds() -> Option<QuotaThresholds> {
+    get_state_thresholds()
+}
+
+pub fn get_status() -> Option<QuotaStatus> {
+    get_state_status()
+}
+
+/// Evaluates the usage against the thresholds once items are written or deleted. A status is recorded only when the
+/// warnings change - i.e. when a threshold is crossed - and mission control picks it up with its monitoring.
+pub fn refresh_status() {
+    let thresholds = match get_state_thresholds() {
+        None => return,
+        Some(thresholds) => thresholds,
+    };
+
+    let memory = memory_size();
+    let memory_size = (memory.heap + memory.stable) as u64;
+
+    let docs_count = get_db_items_count();
+
+    let mut warnings: Vec<QuotaWarning> = Vec::new();
+
+    if thresholds
+        .memory_size
+        .is_some_and(|threshold| memory_size >= threshold)
+    {
+        warnings.push(QuotaWarning::MemorySize);
+    }
+
+    if thresholds
+        .docs_count
+        .is_some_and(|threshold| docs_count >= threshold)
+    {
+        warnings.push(QuotaWarning::DocsCount);
+    }
+
+    let current_warnings = get_state_status().map(|status| status.warnings);
+
+    let unchanged = match &current_warnings {
+        None => warnings.is_empty(),
+        Some(current_warnings) => current_warnings == &warnings,
+    };
+
+    if unchanged {
+        return;
+    }
+
+    insert_state_status(&QuotaStatus {
+        memory_size,
+        docs_count,
+        warnings,
+        updated_at: time(),
+    });
+}
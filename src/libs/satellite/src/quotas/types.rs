@@ -0,0 +1,27 @@
+pub mod state {
+    use crate::quotas::types::config::QuotaThresholds;
+    use candid::CandidType;
+    use junobuild_shared::types::interface::QuotaStatus;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct QuotasHeapState {
+        pub thresholds: Option<QuotaThresholds>,
+        pub status: Option<QuotaStatus>,
+    }
+}
+
+pub mod config {
+    use candid::{CandidType, Deserialize};
+    use serde::Serialize;
+
+    /// Soft limits above which the satellite reports a warning - writes are not rejected.
+    ///
+    /// - `memory_size`: The heap and stable memory used by the satellite, in bytes.
+    /// - `docs_count`: The number of documents across all the collections of the datastore.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct QuotaThresholds {
+        pub memory_size: Option<u64>,
+        pub docs_count: Option<u64>,
+    }
+}
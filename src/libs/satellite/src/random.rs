@@ -1,4 +1,5 @@
 use crate::memory::STATE;
+use crate::safe_mode::store::log_self_test;
 use candid::Principal;
 use getrandom::register_custom_getrandom;
 use getrandom::Error;
@@ -21,12 +22,22 @@ async fn set_random_seed() {
     match result {
         // We do nothing in case of error to not block initialization but, getrandom will throw errors
         Err(_) => (),
-        Ok((seed,)) => STATE.with(|state| {
-            state.borrow_mut().runtime.rng = Some(StdRng::from_seed(seed));
-        }),
+        Ok((seed,)) => {
+            STATE.with(|state| {
+                state.borrow_mut().runtime.rng = Some(StdRng::from_seed(seed));
+            });
+
+            log_self_test();
+        }
     }
 }
 
+pub fn random_seed() -> Option<[u8; 32]> {
+    let mut seed = [0u8; 32];
+
+    custom_getrandom(&mut seed).ok().map(|_| seed)
+}
+
 fn custom_getrandom(buf: &mut [u8]) -> Result<(), Error> {
     STATE.with(|state| {
         let rng = &mut state.borrow_mut().runtime.rng;
@@ -0,0 +1,31 @@
+use crate::rate_limit::msg::{ERROR_RATE_LIMIT_NO_TIME_PER_TOKEN, ERROR_RATE_LIMIT_NO_TOKENS};
+use crate::rate_limit::types::config::RateLimitConfig;
+use junobuild_shared::rate::types::RateConfig;
+
+pub fn assert_rate_limit_config(
+    RateLimitConfig {
+        caller,
+        anonymous,
+        http,
+    }: &RateLimitConfig,
+) -> Result<(), String> {
+    let configs = [Some(caller), anonymous.as_ref(), http.as_ref()];
+
+    for config in configs.into_iter().flatten() {
+        assert_rate_config(config)?;
+    }
+
+    Ok(())
+}
+
+pub fn assert_rate_config(config: &RateConfig) -> Result<(), String> {
+    if config.max_tokens == 0 {
+        return Err(ERROR_RATE_LIMIT_NO_TOKENS.to_string());
+    }
+
+    if config.time_per_token_ns == 0 {
+        return Err(ERROR_RATE_LIMIT_NO_TIME_PER_TOKEN.to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+/// The origins of the HTTP requests are provided by the clients. Above this number of buckets, the requests of
+/// the new origins share a single bucket so that the tokens cannot grow the memory without bound.
+pub const MAX_HTTP_RATE_ORIGINS: usize = 1_000;
+
+pub const HTTP_RATE_OTHER_ORIGINS_KEY: &str = "*";
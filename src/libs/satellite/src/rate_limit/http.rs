@@ -0,0 +1,20 @@
+use crate::rate_limit::store::assert_http_rate_limit;
+use junobuild_storage::http::response::error_response;
+use junobuild_storage::http::types::{HttpRequest, HttpResponse, StatusCode};
+use junobuild_storage::http::utils::get_header_value;
+
+const RESPONSE_STATUS_CODE_429: StatusCode = 429;
+
+/// The HTTP requests answered with an update call are issued anonymously by the gateways, therefore these are limited
+/// per origin - or per host for the requests that are not issued by a browser.
+///
+/// Returns the response to answer if the request is rejected.
+pub fn rate_limited_response(HttpRequest { headers, .. }: &HttpRequest) -> Option<HttpResponse> {
+    let origin = get_header_value(headers, "Origin")
+        .or_else(|| get_header_value(headers, "Host"))
+        .unwrap_or_default();
+
+    assert_http_rate_limit(&origin)
+        .err()
+        .map(|err| error_response(RESPONSE_STATUS_CODE_429, err))
+}
@@ -0,0 +1,7 @@
+pub mod assert;
+mod constants;
+pub mod http;
+mod msg;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,4 @@
+pub const ERROR_RATE_LIMIT_NO_TOKENS: &str =
+    "The maximal number of tokens of a rate limit must be greater than zero.";
+pub const ERROR_RATE_LIMIT_NO_TIME_PER_TOKEN: &str =
+    "The time per token of a rate limit must be greater than zero.";
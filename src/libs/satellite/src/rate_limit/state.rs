@@ -0,0 +1,76 @@
+use crate::memory::STATE;
+use crate::rate_limit::constants::{HTTP_RATE_OTHER_ORIGINS_KEY, MAX_HTTP_RATE_ORIGINS};
+use crate::rate_limit::types::config::RateLimitConfig;
+use crate::rate_limit::types::state::RateLimitHeapState;
+use junobuild_shared::rate::types::{RateConfig, RateTokenStore};
+use junobuild_shared::rate::utils::increment_and_assert_rate_store;
+use junobuild_shared::types::state::UserId;
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+pub fn get_config() -> Option<RateLimitConfig> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .rate_limit
+            .as_ref()
+            .and_then(|rate_limit| rate_limit.config.clone())
+    })
+}
+
+pub fn insert_config(config: &Option<RateLimitConfig>) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+
+        state
+            .heap
+            .rate_limit
+            .get_or_insert_with(RateLimitHeapState::default)
+            .config = config.clone();
+
+        // The tokens accumulated with a previous configuration are reset
+        state.runtime.rate_limit.rate_tokens.clear();
+        state.runtime.rate_limit.http_rate_tokens.clear();
+    })
+}
+
+// ---------------------------------------------------------
+// Tokens
+// ---------------------------------------------------------
+
+pub fn increment_and_assert_rate(caller: UserId, config: &RateConfig) -> Result<(), String> {
+    STATE.with(|state| {
+        increment_and_assert_rate_store(
+            &caller.to_text(),
+            &Some(config.clone()),
+            &mut state.borrow_mut().runtime.rate_limit.rate_tokens,
+        )
+    })
+}
+
+pub fn increment_and_assert_http_rate(origin: &str, config: &RateConfig) -> Result<(), String> {
+    STATE.with(|state| {
+        increment_and_assert_http_rate_impl(
+            origin,
+            config,
+            &mut state.borrow_mut().runtime.rate_limit.http_rate_tokens,
+        )
+    })
+}
+
+fn increment_and_assert_http_rate_impl(
+    origin: &str,
+    config: &RateConfig,
+    rate_tokens: &mut RateTokenStore,
+) -> Result<(), String> {
+    let key = if rate_tokens.contains_key(origin) || rate_tokens.len() < MAX_HTTP_RATE_ORIGINS {
+        origin
+    } else {
+        HTTP_RATE_OTHER_ORIGINS_KEY
+    };
+
+    increment_and_assert_rate_store(&key.to_string(), &Some(config.clone()), rate_tokens)
+}
@@ -0,0 +1,59 @@
+use crate::controllers::store::get_controllers;
+use crate::rate_limit::assert::assert_rate_limit_config;
+use crate::rate_limit::state::{
+    get_config as get_state_config, increment_and_assert_http_rate, increment_and_assert_rate,
+    insert_config as insert_state_config,
+};
+use crate::rate_limit::types::config::RateLimitConfig;
+use candid::Principal;
+use junobuild_shared::controllers::is_controller;
+use junobuild_shared::types::state::UserId;
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+pub fn set_config_store(config: &Option<RateLimitConfig>) -> Result<(), String> {
+    if let Some(config) = config {
+        assert_rate_limit_config(config)?;
+    }
+
+    insert_state_config(config);
+
+    Ok(())
+}
+
+pub fn get_config_store() -> Option<RateLimitConfig> {
+    get_state_config()
+}
+
+// ---------------------------------------------------------
+// Rate
+// ---------------------------------------------------------
+
+/// Deducts a token from the bucket of the caller and rejects the call if it is empty. The controllers are not limited.
+pub fn assert_rate_limit(caller: UserId) -> Result<(), String> {
+    let Some(config) = get_state_config() else {
+        return Ok(());
+    };
+
+    if is_controller(caller, &get_controllers()) {
+        return Ok(());
+    }
+
+    if caller == Principal::anonymous() {
+        return increment_and_assert_rate(caller, &config.anonymous.unwrap_or(config.caller));
+    }
+
+    increment_and_assert_rate(caller, &config.caller)
+}
+
+/// Deducts a token from the bucket of the origin of an HTTP request answered with an update call - the gateways
+/// calling anonymously - and rejects the request if it is empty.
+pub fn assert_http_rate_limit(origin: &str) -> Result<(), String> {
+    let Some(config) = get_state_config() else {
+        return Ok(());
+    };
+
+    increment_and_assert_http_rate(origin, &config.http.unwrap_or(config.caller))
+}
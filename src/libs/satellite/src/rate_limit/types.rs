@@ -0,0 +1,39 @@
+pub mod state {
+    use crate::rate_limit::types::config::RateLimitConfig;
+    use candid::CandidType;
+    use junobuild_shared::rate::types::RateTokenStore;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct RateLimitHeapState {
+        pub config: Option<RateLimitConfig>,
+    }
+
+    /// The tokens of the callers, keyed by the textual representation of their principal, and of the origins of the
+    /// HTTP requests. Like the rates of the collections, they are not persisted across upgrades.
+    #[derive(Default, Clone)]
+    pub struct RateLimitRuntimeState {
+        pub rate_tokens: RateTokenStore,
+        pub http_rate_tokens: RateTokenStore,
+    }
+}
+
+pub mod config {
+    use candid::CandidType;
+    use junobuild_shared::rate::types::RateConfig;
+    use serde::{Deserialize, Serialize};
+
+    /// The token buckets applied to the update calls that are not issued by a controller, regardless of the
+    /// collections. A bucket can burst up to `max_tokens` calls, after which a token is refilled every
+    /// `time_per_token_ns`.
+    ///
+    /// - `caller`: The bucket of each authenticated caller.
+    /// - `anonymous`: The bucket shared by the anonymous callers, which cannot be told apart. The one of `caller` if not set.
+    /// - `http`: The bucket of each origin of the HTTP requests answered with an update call. The one of `caller` if not set.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct RateLimitConfig {
+        pub caller: RateConfig,
+        pub anonymous: Option<RateConfig>,
+        pub http: Option<RateConfig>,
+    }
+}
@@ -0,0 +1,2 @@
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+pub const REQUEST_ID_PARAM: &str = "request_id";
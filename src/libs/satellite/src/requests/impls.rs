@@ -0,0 +1,16 @@
+use crate::requests::runtime::set_current;
+use crate::requests::types::runtime::Request;
+
+impl Request {
+    /// Makes the request the current one again - e.g. after an await, during which other calls might have been
+    /// executed.
+    pub fn resume(&self) {
+        set_current(Some(self.id.clone()));
+    }
+}
+
+impl Drop for Request {
+    fn drop(&mut self) {
+        set_current(None);
+    }
+}
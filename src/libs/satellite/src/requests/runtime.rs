@@ -0,0 +1,20 @@
+use crate::memory::STATE;
+use crate::requests::types::state::RequestId;
+
+pub fn increment_counter() -> u64 {
+    STATE.with(|state| {
+        let requests = &mut state.borrow_mut().runtime.requests;
+
+        requests.counter = requests.counter.wrapping_add(1);
+
+        requests.counter
+    })
+}
+
+pub fn get_current() -> Option<RequestId> {
+    STATE.with(|state| state.borrow().runtime.requests.current.clone())
+}
+
+pub fn set_current(current: Option<RequestId>) {
+    STATE.with(|state| state.borrow_mut().runtime.requests.current = current)
+}
@@ -0,0 +1,55 @@
+use crate::requests::constants::{REQUEST_ID_HEADER, REQUEST_ID_PARAM};
+use crate::requests::runtime::{get_current, increment_counter, set_current};
+use crate::requests::types::runtime::Request;
+use crate::requests::types::state::RequestId;
+use ic_cdk::api::time;
+use junobuild_shared::errors::JunoError;
+use junobuild_storage::http::types::{HeaderField, HttpResponse};
+
+/// Generates the id of a new request and makes it the current one until the returned request is dropped. It should be
+/// called at the beginning of each update endpoint, before any work is done.
+pub fn start_request() -> Request {
+    let id: RequestId = format!("{}-{}", time(), increment_counter());
+
+    set_current(Some(id.clone()));
+
+    Request { id }
+}
+
+/// Makes a request started by a previous message - e.g. provided to a hook executed by a timer - the current one, so
+/// that the work it triggers can be traced back to the original call.
+pub fn resume_request(id: &Option<RequestId>) -> Option<Request> {
+    id.as_ref().map(|id| {
+        set_current(Some(id.clone()));
+
+        Request { id: id.clone() }
+    })
+}
+
+/// The id of the request being executed, if any.
+pub fn get_request_id() -> Option<RequestId> {
+    get_current()
+}
+
+/// Encodes an error the satellite rejects a call with as a `JunoError` - a plain text error with the code
+/// `error_unknown` - and adds the id of the current request as a parameter, for the callers to report which request
+/// failed.
+pub fn request_error(error: &str) -> String {
+    let error = JunoError::from_message(error);
+
+    match get_request_id() {
+        Some(id) => error.with_param(REQUEST_ID_PARAM, id),
+        None => error,
+    }
+    .to_string()
+}
+
+/// Adds the id of the request to the headers of an HTTP response - including the error responses - for the clients to
+/// correlate a response with the logs and webhooks of the request.
+pub fn with_request_id_header(mut response: HttpResponse, id: &RequestId) -> HttpResponse {
+    response
+        .headers
+        .push(HeaderField(REQUEST_ID_HEADER.to_string(), id.clone()));
+
+    response
+}
@@ -0,0 +1,23 @@
+pub mod state {
+    /// The correlation id of a call - i.e. the time at which it was executed and a counter of the calls.
+    pub type RequestId = String;
+
+    #[derive(Default, Clone)]
+    pub struct RequestsRuntimeState {
+        pub counter: u64,
+        pub current: Option<RequestId>,
+    }
+}
+
+pub mod runtime {
+    use crate::requests::types::state::RequestId;
+
+    /// The request being executed. It is the current one until it is dropped - i.e. at the end of the call that started
+    /// or resumed it - so that it is never carried over to the messages that follow.
+    ///
+    /// The messages of other calls might start and end their own request while a call awaits, therefore an async call
+    /// makes its request the current one again after each await.
+    pub struct Request {
+        pub id: RequestId,
+    }
+}
@@ -0,0 +1,4 @@
+pub const RETENTION_PURGE_INTERVAL_SECS: u64 = 60 * 60;
+
+// The instructions of a timer are limited, therefore the purge of large collections is spread over several runs.
+pub const RETENTION_PURGE_MAX_DELETIONS: usize = 500;
@@ -0,0 +1,4 @@
+mod constants;
+pub mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,184 @@
+use crate::memory::STATE;
+use crate::retention::types::state::{RetentionHeapState, RetentionIndex, RetentionIndexes};
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::RetentionPolicy;
+use junobuild_collections::utils::filter_expired_keys;
+use junobuild_shared::types::state::Timestamp;
+
+pub fn is_retention_initialized() -> bool {
+    STATE.with(|state| state.borrow().heap.retention.is_some())
+}
+
+pub fn insert_retention(retention: RetentionHeapState) {
+    STATE.with(|state| state.borrow_mut().heap.retention = Some(retention))
+}
+
+pub fn is_db_retention_indexed(collection: &CollectionKey) -> bool {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .retention
+            .as_ref()
+            .is_some_and(|retention| retention.db.contains_key(collection))
+    })
+}
+
+pub fn is_storage_retention_indexed(collection: &CollectionKey) -> bool {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .retention
+            .as_ref()
+            .is_some_and(|retention| retention.storage.contains_key(collection))
+    })
+}
+
+/// Replaces the index of a collection - e.g. once a retention policy is set - or removes it.
+pub fn set_db_retention_index(collection: &CollectionKey, index: Option<RetentionIndex>) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let retention = state
+            .heap
+            .retention
+            .get_or_insert_with(RetentionHeapState::default);
+
+        set_retention_index(collection, index, &mut retention.db)
+    })
+}
+
+pub fn set_storage_retention_index(collection: &CollectionKey, index: Option<RetentionIndex>) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let retention = state
+            .heap
+            .retention
+            .get_or_insert_with(RetentionHeapState::default);
+
+        set_retention_index(collection, index, &mut retention.storage)
+    })
+}
+
+/// Applies the change of a document - its last update before and after - to the index of its collection, if indexed.
+pub fn update_db_retention_index(
+    collection: &CollectionKey,
+    key: &str,
+    before: Option<Timestamp>,
+    after: Option<Timestamp>,
+) {
+    STATE.with(|state| {
+        if let Some(retention) = state.borrow_mut().heap.retention.as_mut() {
+            update_retention_index(collection, key, before, after, &mut retention.db)
+        }
+    })
+}
+
+pub fn update_storage_retention_index(
+    collection: &CollectionKey,
+    full_path: &str,
+    before: Option<Timestamp>,
+    after: Option<Timestamp>,
+) {
+    STATE.with(|state| {
+        if let Some(retention) = state.borrow_mut().heap.retention.as_mut() {
+            update_retention_index(collection, full_path, before, after, &mut retention.storage)
+        }
+    })
+}
+
+pub fn collect_db_expired_keys(
+    collection: &CollectionKey,
+    retention: &RetentionPolicy,
+    now: Timestamp,
+    limit: usize,
+) -> Vec<String> {
+    STATE.with(|state| {
+        collect_expired_keys(
+            collection,
+            retention,
+            now,
+            limit,
+            state
+                .borrow()
+                .heap
+                .retention
+                .as_ref()
+                .map(|retention| &retention.db),
+        )
+    })
+}
+
+pub fn collect_storage_expired_keys(
+    collection: &CollectionKey,
+    retention: &RetentionPolicy,
+    now: Timestamp,
+    limit: usize,
+) -> Vec<String> {
+    STATE.with(|state| {
+        collect_expired_keys(
+            collection,
+            retention,
+            now,
+            limit,
+            state
+                .borrow()
+                .heap
+                .retention
+                .as_ref()
+                .map(|retention| &retention.storage),
+        )
+    })
+}
+
+fn set_retention_index(
+    collection: &CollectionKey,
+    index: Option<RetentionIndex>,
+    indexes: &mut RetentionIndexes,
+) {
+    match index {
+        None => {
+            indexes.remove(collection);
+        }
+        Some(index) => {
+            indexes.insert(collection.clone(), index);
+        }
+    }
+}
+
+fn update_retention_index(
+    collection: &CollectionKey,
+    key: &str,
+    before: Option<Timestamp>,
+    after: Option<Timestamp>,
+    indexes: &mut RetentionIndexes,
+) {
+    let Some(index) = indexes.get_mut(collection) else {
+        return;
+    };
+
+    if let Some(before) = before {
+        index.remove(&(before, key.to_string()));
+    }
+
+    if let Some(after) = after {
+        index.insert((after, key.to_string()));
+    }
+}
+
+fn collect_expired_keys(
+    collection: &CollectionKey,
+    retention: &RetentionPolicy,
+    now: Timestamp,
+    limit: usize,
+    indexes: Option<&RetentionIndexes>,
+) -> Vec<String> {
+    indexes
+        .and_then(|indexes| indexes.get(collection))
+        .map_or(Vec::new(), |index| {
+            filter_expired_keys(index, retention, now)
+                .take(limit)
+                .cloned()
+                .collect()
+        })
+}
@@ -0,0 +1,179 @@
+use crate::db::state::{get_docs_heap, iter_docs_stable};
+use crate::db::store::delete_expired_docs_store;
+use crate::events::store::emit;
+use crate::events::types::interface::SatelliteEvent;
+use crate::memory::STATE;
+use crate::retention::constants::{RETENTION_PURGE_INTERVAL_SECS, RETENTION_PURGE_MAX_DELETIONS};
+use crate::retention::state::{
+    insert_retention, is_db_retention_indexed, is_retention_initialized,
+    is_storage_retention_indexed, set_db_retention_index, set_storage_retention_index,
+};
+use crate::retention::types::state::{RetentionHeapState, RetentionIndex};
+use crate::revisions::store::prune_revisions_store;
+use crate::safe_mode::store::is_safe_mode;
+use crate::storage::state::iter_assets_stable;
+use crate::storage::store::delete_expired_assets_store;
+use crate::types::state::State;
+use ic_cdk::api::time;
+use ic_cdk::id;
+use ic_cdk_timers::set_timer_interval;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::{Memory, Rule, Rules};
+use junobuild_storage::heap_utils::collect_assets_heap;
+use junobuild_storage::utils::should_include_asset_for_deletion;
+use std::time::Duration;
+
+/// Timers do not survive upgrades. This is why the purge is scheduled again in the post_upgrade hook.
+pub fn init_retention_purge() {
+    set_timer_interval(
+        Duration::from_secs(RETENTION_PURGE_INTERVAL_SECS),
+        purge_expired,
+    );
+}
+
+/// Deletes the documents and assets that exceed the retention policies of their collections. The deletions are
+/// emitted as if they were performed by the satellite itself. The revisions of the history that exceed its window are
+/// pruned as well.
+fn purge_expired() {
+    // The purge writes to the state, therefore it is paused as long as the satellite is in safe mode.
+    if is_safe_mode() {
+        return;
+    }
+
+    let now = time();
+
+    prune_revisions_store(now);
+
+    let mut remaining = RETENTION_PURGE_MAX_DELETIONS;
+
+    let (db_collections, storage_collections) = STATE.with(|state| {
+        let state = state.borrow();
+
+        (
+            collections_with_retention(&state.heap.db.rules),
+            collections_with_retention(&state.heap.storage.rules),
+        )
+    });
+
+    for collection in db_collections {
+        if remaining == 0 {
+            return;
+        }
+
+        if let Ok(docs) = delete_expired_docs_store(&collection, now, remaining) {
+            if !docs.is_empty() {
+                remaining -= docs.len();
+                emit(&id(), SatelliteEvent::DeleteManyDocs(docs));
+            }
+        }
+    }
+
+    for collection in storage_collections {
+        if remaining == 0 {
+            return;
+        }
+
+        if let Ok(assets) = delete_expired_assets_store(&collection, now, remaining) {
+            if !assets.is_empty() {
+                remaining -= assets.len();
+                emit(&id(), SatelliteEvent::DeleteManyAssets(assets));
+            }
+        }
+    }
+}
+
+fn collections_with_retention(rules: &Rules) -> Vec<CollectionKey> {
+    rules
+        .iter()
+        .filter(|(_, rule)| rule.retention.is_some())
+        .map(|(collection, _)| collection.clone())
+        .collect()
+}
+
+/// Indexes the documents and assets of the collections that have a retention policy once, when a satellite that did
+/// not maintain the index yet is upgraded. The index is then updated incrementally.
+pub fn init_retention_store() {
+    if is_retention_initialized() {
+        return;
+    }
+
+    let retention = STATE.with(|state| {
+        let state = state.borrow();
+
+        let mut retention = RetentionHeapState::default();
+
+        for (collection, rule) in &state.heap.db.rules {
+            if rule.retention.is_some() {
+                retention
+                    .db
+                    .insert(collection.clone(), index_docs(collection, rule, &state));
+            }
+        }
+
+        for (collection, rule) in &state.heap.storage.rules {
+            if rule.retention.is_some() {
+                retention
+                    .storage
+                    .insert(collection.clone(), index_assets(collection, rule, &state));
+            }
+        }
+
+        retention
+    });
+
+    insert_retention(retention);
+}
+
+/// Indexes the documents of a collection when a retention policy is set to its rule, or drops the index when the
+/// policy is removed.
+pub fn index_db_retention_store(collection: &CollectionKey, rule: &Rule) {
+    match (&rule.retention, is_db_retention_indexed(collection)) {
+        (None, _) => set_db_retention_index(collection, None),
+        (Some(_), true) => (),
+        (Some(_), false) => {
+            let index = STATE.with(|state| index_docs(collection, rule, &state.borrow()));
+            set_db_retention_index(collection, Some(index));
+        }
+    }
+}
+
+pub fn index_storage_retention_store(collection: &CollectionKey, rule: &Rule) {
+    match (&rule.retention, is_storage_retention_indexed(collection)) {
+        (None, _) => set_storage_retention_index(collection, None),
+        (Some(_), true) => (),
+        (Some(_), false) => {
+            let index = STATE.with(|state| index_assets(collection, rule, &state.borrow()));
+            set_storage_retention_index(collection, Some(index));
+        }
+    }
+}
+
+fn index_docs(collection: &CollectionKey, rule: &Rule, state: &State) -> RetentionIndex {
+    match rule.mem() {
+        Memory::Heap => get_docs_heap(collection, &state.heap.db.db)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, doc)| (doc.updated_at, key.clone()))
+            .collect(),
+        Memory::Stable => iter_docs_stable(collection, &state.stable.db)
+            .map(|(key, doc)| (doc.updated_at, key.key))
+            .collect(),
+    }
+}
+
+fn index_assets(collection: &CollectionKey, rule: &Rule, state: &State) -> RetentionIndex {
+    let entries: RetentionIndex = match rule.mem() {
+        Memory::Heap => collect_assets_heap(collection, &state.heap.storage.assets)
+            .into_iter()
+            .map(|(_, asset)| (asset.updated_at, asset.key.full_path.clone()))
+            .collect(),
+        Memory::Stable => iter_assets_stable(collection, &state.stable.assets)
+            .map(|(_, asset)| (asset.updated_at, asset.key.full_path))
+            .collect(),
+    };
+
+    entries
+        .into_iter()
+        .filter(|(_, full_path)| should_include_asset_for_deletion(collection, full_path))
+        .collect()
+}
@@ -0,0 +1,20 @@
+pub mod state {
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::state::Timestamp;
+    use serde::{Deserialize, Serialize};
+    use std::collections::{BTreeSet, HashMap};
+
+    // The keys of the documents - or the full paths of the assets - of a collection with their last update, the oldest first
+    pub type RetentionIndex = BTreeSet<(Timestamp, String)>;
+
+    pub type RetentionIndexes = HashMap<CollectionKey, RetentionIndex>;
+
+    /// The entries of the collections that have a retention policy, indexed by their last update so that the purge
+    /// reads the expired ones only. A collection is indexed when a retention policy is set to its rule.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct RetentionHeapState {
+        pub db: RetentionIndexes,
+        pub storage: RetentionIndexes,
+    }
+}
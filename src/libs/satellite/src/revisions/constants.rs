@@ -0,0 +1,8 @@
+// The revisions are kept for a week, long enough to investigate a report of a user.
+pub const REVISIONS_WINDOW_NS: u64 = 7 * 24 * 60 * 60 * 1_000_000_000;
+
+// A document updated in a loop should not grow the heap indefinitely within the window.
+pub const REVISIONS_MAX_PER_DOC: usize = 50;
+
+// The revisions are pruned periodically in slices, the next pruning resuming where the previous one stopped.
+pub const REVISIONS_PRUNE_MAX_SCAN: usize = 1_000;
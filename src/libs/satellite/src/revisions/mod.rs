@@ -0,0 +1,6 @@
+mod constants;
+mod impls;
+mod msg;
+mod state;
+pub mod store;
+pub mod types;
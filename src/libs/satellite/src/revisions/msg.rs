@@ -0,0 +1,5 @@
+pub const ERROR_REVISIONS_NOT_ENABLED: &str = "The history is not enabled for this collection.";
+pub const ERROR_REVISION_OUTSIDE_WINDOW: &str =
+    "The requested time is outside the retention window of the history.";
+pub const ERROR_REVISION_NOT_RETAINED: &str =
+    "The state of the document at the requested time is no longer retained.";
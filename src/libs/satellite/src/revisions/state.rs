@@ -0,0 +1,164 @@
+use crate::defrag::state::usage_mut;
+use crate::defrag::stats::entry_length;
+use crate::defrag::types::state::RegionUsage;
+use crate::memory::STATE;
+use crate::revisions::constants::{REVISIONS_MAX_PER_DOC, REVISIONS_PRUNE_MAX_SCAN};
+use crate::revisions::types::state::{
+    DocRevision, RevisionKey, RevisionsHeapState, RevisionsStable,
+};
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::Timestamp;
+use std::ops::Bound::{Excluded, Unbounded};
+use std::ops::RangeInclusive;
+
+pub fn get_revisions(collection: &CollectionKey, key: &Key) -> Vec<DocRevision> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .stable
+            .revisions
+            .values_range(doc_revisions_range(collection, key))
+            .collect()
+    })
+}
+
+pub fn insert_revision(
+    collection: &CollectionKey,
+    key: &Key,
+    revision: DocRevision,
+    expired_before: Timestamp,
+) {
+    STATE.with(|state| {
+        let state = &mut *state.borrow_mut();
+
+        insert_revision_impl(
+            collection,
+            key,
+            revision,
+            expired_before,
+            &mut state.stable.revisions,
+            &mut usage_mut(&mut state.heap.defrag).revisions,
+        )
+    })
+}
+
+fn insert_revision_impl(
+    collection: &CollectionKey,
+    key: &Key,
+    revision: DocRevision,
+    expired_before: Timestamp,
+    revisions: &mut RevisionsStable,
+    usage: &mut RegionUsage,
+) {
+    let expired: Vec<RevisionKey> = revisions
+        .keys_range(revision_key(collection, key, 0)..revision_key(collection, key, expired_before))
+        .collect();
+
+    for revision_key in expired {
+        remove_revision(&revision_key, revisions, usage);
+    }
+
+    // A document modified more than once within the same message keeps its state prior to the first modification.
+    let new_key = revision_key(collection, key, revision.replaced_at);
+
+    if !revisions.contains_key(&new_key) {
+        usage.update(None, Some(entry_length(&new_key, &revision)));
+        revisions.insert(new_key, revision);
+    }
+
+    let count = revisions
+        .keys_range(doc_revisions_range(collection, key))
+        .count();
+
+    if count > REVISIONS_MAX_PER_DOC {
+        let excess: Vec<RevisionKey> = revisions
+            .keys_range(doc_revisions_range(collection, key))
+            .take(count - REVISIONS_MAX_PER_DOC)
+            .collect();
+
+        for revision_key in excess {
+            remove_revision(&revision_key, revisions, usage);
+        }
+    }
+}
+
+pub fn take_heap_revisions() -> Option<RevisionsHeapState> {
+    STATE.with(|state| state.borrow_mut().heap.revisions.take())
+}
+
+pub fn insert_migrated_revision(collection: &CollectionKey, key: &Key, revision: DocRevision) {
+    STATE.with(|state| {
+        let state = &mut *state.borrow_mut();
+
+        let revision_key = revision_key(collection, key, revision.replaced_at);
+        let length = entry_length(&revision_key, &revision);
+
+        let previous = state
+            .stable
+            .revisions
+            .insert(revision_key.clone(), revision);
+
+        usage_mut(&mut state.heap.defrag).revisions.update(
+            previous.map(|previous| entry_length(&revision_key, &previous)),
+            Some(length),
+        );
+    })
+}
+
+/// Scans a slice of the history - resuming after the revision at which the previous pruning stopped - and removes the
+/// revisions replaced before the given time.
+pub fn prune_revisions(expired_before: Timestamp) {
+    STATE.with(|state| {
+        let state = &mut *state.borrow_mut();
+
+        let start = match state.runtime.revisions.prune_cursor.take() {
+            None => Unbounded,
+            Some(cursor) => Excluded(cursor),
+        };
+
+        let scanned: Vec<RevisionKey> = state
+            .stable
+            .revisions
+            .keys_range((start, Unbounded))
+            .take(REVISIONS_PRUNE_MAX_SCAN)
+            .collect();
+
+        if scanned.len() == REVISIONS_PRUNE_MAX_SCAN {
+            state.runtime.revisions.prune_cursor = scanned.last().cloned();
+        }
+
+        let usage = &mut usage_mut(&mut state.heap.defrag).revisions;
+
+        for revision_key in scanned {
+            if revision_key.replaced_at < expired_before {
+                remove_revision(&revision_key, &mut state.stable.revisions, usage);
+            }
+        }
+    })
+}
+
+fn remove_revision(
+    revision_key: &RevisionKey,
+    revisions: &mut RevisionsStable,
+    usage: &mut RegionUsage,
+) {
+    let removed = revisions.remove(revision_key);
+
+    usage.update(
+        removed.map(|removed| entry_length(revision_key, &removed)),
+        None,
+    );
+}
+
+fn doc_revisions_range(collection: &CollectionKey, key: &Key) -> RangeInclusive<RevisionKey> {
+    revision_key(collection, key, Timestamp::MIN)..=revision_key(collection, key, Timestamp::MAX)
+}
+
+fn revision_key(collection: &CollectionKey, key: &Key, replaced_at: Timestamp) -> RevisionKey {
+    RevisionKey {
+        collection: collection.clone(),
+        key: key.clone(),
+        replaced_at,
+    }
+}
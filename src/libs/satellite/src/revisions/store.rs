@@ -0,0 +1,97 @@
+use crate::db::state::{get_config, get_doc as get_state_doc, get_rule as get_state_rule};
+use crate::db::types::state::Doc;
+use crate::revisions::constants::REVISIONS_WINDOW_NS;
+use crate::revisions::msg::{
+    ERROR_REVISIONS_NOT_ENABLED, ERROR_REVISION_NOT_RETAINED, ERROR_REVISION_OUTSIDE_WINDOW,
+};
+use crate::revisions::state::{
+    get_revisions, insert_migrated_revision, insert_revision, prune_revisions, take_heap_revisions,
+};
+use crate::revisions::types::state::DocRevision;
+use ic_cdk::api::time;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::Timestamp;
+
+/// Keeps the state of a document before it is set or deleted, if the history is enabled for its collection.
+pub fn record_doc_revision(collection: &CollectionKey, key: &Key, before: &Option<Doc>) {
+    if !is_history_enabled(collection) {
+        return;
+    }
+
+    let now = time();
+
+    insert_revision(
+        collection,
+        key,
+        DocRevision {
+            doc: before.clone(),
+            replaced_at: now,
+        },
+        now.saturating_sub(REVISIONS_WINDOW_NS),
+    );
+}
+
+/// Removes the revisions older than the retention window, including those of the documents that are no longer
+/// modified.
+pub fn prune_revisions_store(now: Timestamp) {
+    prune_revisions(now.saturating_sub(REVISIONS_WINDOW_NS));
+}
+
+/// The previous versions of the satellite kept the revisions on the heap. They are moved to the stable memory once,
+/// on upgrade.
+pub fn migrate_heap_revisions() {
+    let Some(heap) = take_heap_revisions() else {
+        return;
+    };
+
+    for (doc_key, revisions) in heap.docs {
+        for revision in revisions {
+            insert_migrated_revision(&doc_key.collection, &doc_key.key, revision);
+        }
+    }
+}
+
+/// Returns the state of a document at a past point in time within the retention window - i.e. the first revision
+/// replaced after this time or, if the document was not modified since, its current state.
+pub fn get_doc_at_store(
+    collection: &CollectionKey,
+    key: &Key,
+    at: Timestamp,
+) -> Result<Option<Doc>, String> {
+    if !is_history_enabled(collection) {
+        return Err(ERROR_REVISIONS_NOT_ENABLED.to_string());
+    }
+
+    let now = time();
+
+    if at < now.saturating_sub(REVISIONS_WINDOW_NS) {
+        return Err(ERROR_REVISION_OUTSIDE_WINDOW.to_string());
+    }
+
+    let revision = get_revisions(collection, key)
+        .into_iter()
+        .find(|revision| revision.replaced_at > at);
+
+    let doc = match revision {
+        Some(revision) => revision.doc,
+        None => {
+            let rule = get_state_rule(collection)?;
+            get_state_doc(collection, key, &rule)?
+        }
+    };
+
+    // The document was modified after the requested time without a revision - e.g. the history was enabled
+    // afterwards or the older revisions were dropped to respect the maximal number per document.
+    if doc.as_ref().is_some_and(|doc| doc.updated_at > at) {
+        return Err(ERROR_REVISION_NOT_RETAINED.to_string());
+    }
+
+    Ok(doc)
+}
+
+fn is_history_enabled(collection: &CollectionKey) -> bool {
+    get_config()
+        .and_then(|config| config.history_collections)
+        .is_some_and(|collections| collections.contains(collection))
+}
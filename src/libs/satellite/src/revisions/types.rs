@@ -0,0 +1,44 @@
+pub mod state {
+    use crate::db::types::state::{Doc, StableKey};
+    use candid::CandidType;
+    use ic_stable_structures::StableBTreeMap;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::core::Key;
+    use junobuild_shared::types::memory::Memory;
+    use junobuild_shared::types::state::Timestamp;
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    pub type RevisionsStable = StableBTreeMap<RevisionKey, DocRevision, Memory>;
+
+    /// The revisions of the documents sorted by collection, key and time - i.e. the revisions of a document are
+    /// contiguous and ordered from the oldest to the most recent.
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct RevisionKey {
+        pub collection: CollectionKey,
+        pub key: Key,
+        pub replaced_at: Timestamp,
+    }
+
+    pub type DocsRevisions = BTreeMap<StableKey, Vec<DocRevision>>;
+
+    /// The revisions were kept on the heap by the previous versions. They are moved to the stable memory on upgrade.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct RevisionsHeapState {
+        pub docs: DocsRevisions,
+    }
+
+    #[derive(Default, Clone)]
+    pub struct RevisionsRuntimeState {
+        /// The revision after which the next pruning resumes, if it did not reach the end of the history.
+        pub prune_cursor: Option<RevisionKey>,
+    }
+
+    /// The state of a document until it was replaced - or deleted - at `replaced_at`. An empty `doc` means that the
+    /// document did not exist yet.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct DocRevision {
+        pub doc: Option<Doc>,
+        pub replaced_at: Timestamp,
+    }
+}
@@ -1,10 +1,15 @@
+use crate::db::http::is_http_collection;
 use crate::db::store::{delete_collection_store, init_collection_store};
+use crate::manifest::http::certify_manifest;
 use crate::memory::STATE;
+use crate::retention::state::{set_db_retention_index, set_storage_retention_index};
+use crate::retention::store::{index_db_retention_store, index_storage_retention_store};
+use crate::storage::certified_assets::runtime::init_certified_assets;
 use crate::storage::store::assert_assets_collection_empty_store;
-use junobuild_collections::store::{del_rule, filter_rules, set_rule};
+use junobuild_collections::store::{del_rule, filter_rules, set_rule, set_rule_compression};
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::interface::{DelRule, SetRule};
-use junobuild_collections::types::rules::{Memory, Rule};
+use junobuild_collections::types::rules::{Compression, Memory, Rule};
 
 // ---------------------------------------------------------
 // Rules
@@ -39,17 +44,61 @@ pub fn set_rule_db(collection: CollectionKey, rule: SetRule) -> Result<Rule, Str
     // If the collection does not exist yet we initialize it
     init_collection_store(&collection, &rule.memory.clone().unwrap_or(Memory::Stable));
 
+    index_db_retention_store(&collection, &rule);
+
+    // The documents of an exposed collection are certified only if these can be read publicly. Rebuilding the
+    // certification also certifies the manifest, which lists the public collections.
+    if is_http_collection(&collection) {
+        init_certified_assets();
+    } else {
+        certify_manifest();
+    }
+
     Ok(rule)
 }
 
 pub fn set_rule_storage(collection: CollectionKey, rule: SetRule) -> Result<Rule, String> {
-    STATE.with(|state| {
+    let rule = STATE.with(|state| {
         set_rule(
-            collection,
+            collection.clone(),
             rule,
             true,
             &mut state.borrow_mut().heap.storage.rules,
         )
+    })?;
+
+    index_storage_retention_store(&collection, &rule);
+
+    certify_manifest();
+
+    Ok(rule)
+}
+
+pub fn set_rule_compression_db(
+    collection: CollectionKey,
+    compression: Option<Compression>,
+) -> Result<Rule, String> {
+    STATE.with(|state| {
+        set_rule_compression(
+            collection,
+            compression,
+            false,
+            &mut state.borrow_mut().heap.db.rules,
+        )
+    })
+}
+
+pub fn set_rule_compression_storage(
+    collection: CollectionKey,
+    compression: Option<Compression>,
+) -> Result<Rule, String> {
+    STATE.with(|state| {
+        set_rule_compression(
+            collection,
+            compression,
+            true,
+            &mut state.borrow_mut().heap.storage.rules,
+        )
     })
 }
 
@@ -65,6 +114,10 @@ pub fn del_rule_db(collection: CollectionKey, rule: DelRule) -> Result<(), Strin
         )
     })?;
 
+    set_db_retention_index(&collection, None);
+
+    certify_manifest();
+
     Ok(())
 }
 
@@ -72,5 +125,17 @@ pub fn del_rule_storage(collection: CollectionKey, rule: DelRule) -> Result<(),
     // Only unused rule can be removed
     assert_assets_collection_empty_store(&collection)?;
 
-    STATE.with(|state| del_rule(collection, rule, &mut state.borrow_mut().heap.storage.rules))
+    STATE.with(|state| {
+        del_rule(
+            collection.clone(),
+            rule,
+            &mut state.borrow_mut().heap.storage.rules,
+        )
+    })?;
+
+    set_storage_retention_index(&collection, None);
+
+    certify_manifest();
+
+    Ok(())
 }
@@ -0,0 +1,75 @@
+use crate::safe_mode::types::interface::SelfTestIssue;
+use crate::types::interface::RulesType;
+use crate::types::state::State;
+use ic_cdk::api::time;
+use junobuild_collections::constants::{DEFAULT_ASSETS_COLLECTIONS, DEFAULT_DB_COLLECTIONS};
+use junobuild_collections::types::rules::Memory;
+use junobuild_shared::types::state::ControllerScope;
+
+// The checks are performed within the init and post_upgrade hooks, therefore they only iterate the controllers and
+// the rules - i.e. never the documents or the assets.
+
+pub fn check_controllers(state: &State) -> Vec<SelfTestIssue> {
+    let now = time();
+
+    let admin = state.heap.controllers.values().any(|controller| {
+        matches!(controller.scope, ControllerScope::Admin)
+            && controller
+                .expires_at
+                .map_or(true, |expires_at| expires_at > now)
+    });
+
+    if admin {
+        return Vec::new();
+    }
+
+    Vec::from([SelfTestIssue::NoAdminController])
+}
+
+pub fn check_system_collections(state: &State) -> Vec<SelfTestIssue> {
+    let db = DEFAULT_DB_COLLECTIONS
+        .iter()
+        .filter(|(collection, _)| !state.heap.db.rules.contains_key(*collection))
+        .map(|(collection, _)| SelfTestIssue::MissingSystemCollection {
+            rules_type: RulesType::Db,
+            collection: collection.to_string(),
+        });
+
+    let storage = DEFAULT_ASSETS_COLLECTIONS
+        .iter()
+        .filter(|(collection, _)| !state.heap.storage.rules.contains_key(*collection))
+        .map(|(collection, _)| SelfTestIssue::MissingSystemCollection {
+            rules_type: RulesType::Storage,
+            collection: collection.to_string(),
+        });
+
+    db.chain(storage).collect()
+}
+
+pub fn check_heap_collections(state: &State) -> Vec<SelfTestIssue> {
+    let missing_collections = state
+        .heap
+        .db
+        .rules
+        .iter()
+        .filter(|(collection, rule)| {
+            matches!(rule.mem(), Memory::Heap) && !state.heap.db.db.contains_key(*collection)
+        })
+        .map(|(collection, _)| SelfTestIssue::MissingCollection {
+            collection: collection.clone(),
+        });
+
+    let missing_rules = state
+        .heap
+        .db
+        .db
+        .iter()
+        .filter(|(collection, docs)| {
+            !docs.is_empty() && !state.heap.db.rules.contains_key(*collection)
+        })
+        .map(|(collection, _)| SelfTestIssue::MissingRule {
+            collection: collection.clone(),
+        });
+
+    missing_collections.chain(missing_rules).collect()
+}
@@ -0,0 +1,3 @@
+pub const ERROR_SAFE_MODE: &str =
+    "The satellite is in safe mode. Writes are blocked until a controller acknowledges the issues found at startup.";
+pub const ERROR_NOT_IN_SAFE_MODE: &str = "The satellite is not in safe mode.";
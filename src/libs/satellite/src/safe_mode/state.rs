@@ -0,0 +1,10 @@
+use crate::memory::STATE;
+use crate::safe_mode::types::interface::SelfTestReport;
+
+pub fn get_report() -> Option<SelfTestReport> {
+    STATE.with(|state| state.borrow().runtime.safe_mode.report.clone())
+}
+
+pub fn insert_report(report: SelfTestReport) {
+    STATE.with(|state| state.borrow_mut().runtime.safe_mode.report = Some(report))
+}
@@ -0,0 +1,93 @@
+use crate::logs::loggers::warn;
+use crate::memory::STATE;
+use crate::safe_mode::checks::{
+    check_controllers, check_heap_collections, check_system_collections,
+};
+use crate::safe_mode::msg::{ERROR_NOT_IN_SAFE_MODE, ERROR_SAFE_MODE};
+use crate::safe_mode::state::{get_report, insert_report};
+use crate::safe_mode::types::interface::{SelfTestIssue, SelfTestReport};
+use crate::version::SATELLITE_VERSION;
+use ic_cdk::api::time;
+use junobuild_shared::types::state::UserId;
+
+pub fn get_self_test_report_store() -> Option<SelfTestReport> {
+    get_report()
+}
+
+/// Checks the invariants of the state when the satellite starts. If one of them does not hold, the satellite boots in
+/// safe mode: the reads are served but the writes are blocked, so that a corrupted state is not made worse.
+pub fn run_self_test() {
+    let issues = STATE.with(|state| {
+        let state = state.borrow();
+
+        [
+            check_controllers(&state),
+            check_system_collections(&state),
+            check_heap_collections(&state),
+        ]
+        .concat()
+    });
+
+    let safe_mode = !issues.is_empty();
+
+    insert_report(SelfTestReport {
+        version: SATELLITE_VERSION.to_string(),
+        checked_at: time(),
+        issues,
+        safe_mode,
+        acknowledged_at: None,
+        acknowledged_by: None,
+    });
+}
+
+/// Leaves the safe mode. The issues remain part of the report, and are checked again on the next upgrade.
+pub fn acknowledge_safe_mode_store(caller: UserId) -> Result<(), String> {
+    let report = get_report()
+        .filter(|report| report.safe_mode)
+        .ok_or(ERROR_NOT_IN_SAFE_MODE.to_string())?;
+
+    insert_report(SelfTestReport {
+        safe_mode: false,
+        acknowledged_at: Some(time()),
+        acknowledged_by: Some(caller),
+        ..report
+    });
+
+    Ok(())
+}
+
+/// The logs are keyed with a random nonce, therefore the findings are logged once the random number generator is
+/// initialized rather than within the hooks.
+pub fn log_self_test() {
+    let Some(report) = get_report().filter(|report| report.safe_mode) else {
+        return;
+    };
+
+    // A failure to log does not prevent the satellite from starting.
+    let _ = warn(format!(
+        "The self-test found {} issue(s). The satellite starts in safe mode.",
+        report.issues.len()
+    ));
+}
+
+pub fn is_safe_mode_without_admin() -> bool {
+    get_report().is_some_and(|report| {
+        report.safe_mode
+            && report
+                .issues
+                .iter()
+                .any(|issue| matches!(issue, SelfTestIssue::NoAdminController))
+    })
+}
+
+pub fn is_safe_mode() -> bool {
+    get_report().is_some_and(|report| report.safe_mode)
+}
+
+pub fn assert_not_safe_mode() -> Result<(), String> {
+    if is_safe_mode() {
+        return Err(ERROR_SAFE_MODE.to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+pub mod state {
+    use crate::safe_mode::types::interface::SelfTestReport;
+
+    #[derive(Default, Clone)]
+    pub struct SafeModeRuntimeState {
+        pub report: Option<SelfTestReport>,
+    }
+}
+
+pub mod interface {
+    use crate::types::interface::RulesType;
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use serde::{Deserialize, Serialize};
+
+    /// The outcome of the invariant checks performed when the satellite starts - i.e. on init and post_upgrade.
+    ///
+    /// - `safe_mode`: The writes of the datastore and the storage, as well as the endpoints of the controllers that
+    ///   change the state, are blocked. Set when issues are found and until an admin controller acknowledges them.
+    /// - `acknowledged_at` and `acknowledged_by`: When and by whom the safe mode was left.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct SelfTestReport {
+        pub version: String,
+        pub checked_at: Timestamp,
+        pub issues: Vec<SelfTestIssue>,
+        pub safe_mode: bool,
+        pub acknowledged_at: Option<Timestamp>,
+        pub acknowledged_by: Option<UserId>,
+    }
+
+    /// - `NoAdminController`: No admin controller, that has not expired, can administrate the satellite.
+    /// - `MissingSystemCollection`: The rule of a collection created by the satellite itself - e.g. `#user` - is missing.
+    /// - `MissingCollection`: A collection of the heap has a rule but no entry in the datastore.
+    /// - `MissingRule`: Documents were found on the heap for a collection without rule.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum SelfTestIssue {
+        NoAdminController,
+        MissingSystemCollection {
+            rules_type: RulesType,
+            collection: CollectionKey,
+        },
+        MissingCollection {
+            collection: CollectionKey,
+        },
+        MissingRule {
+            collection: CollectionKey,
+        },
+    }
+}
@@ -1,81 +1,234 @@
+use crate::analytics::http::{
+    http_request as http_request_analytics, http_request_update as http_request_update_analytics,
+    is_analytics_request,
+};
+use crate::analytics::store::{
+    get_config as get_analytics_config_store, set_config as set_analytics_config_store,
+};
+use crate::analytics::types::config::AnalyticsConfig;
+use crate::api_keys::store::{create_api_key_store, delete_api_key_store, list_api_keys_store};
+use crate::api_keys::types::interface::{CreateApiKeyResult, SetApiKey};
+use crate::api_keys::types::state::{ApiKey, ApiKeyId};
+use crate::approvals::store::{
+    apply_config_store as apply_approvals_config_store, assert_approval_not_required,
+    cancel_action_store, confirm_action_store, get_config_store as get_approvals_config_store,
+    list_pending_actions_store, propose_action_store,
+    set_config_store as set_approvals_config_store,
+};
+use crate::approvals::types::config::ApprovalsConfig;
+use crate::approvals::types::state::{ActionId, ControllerAction, PendingAction};
+use crate::audit::store::{list_audit_blocks_store, record_audit_block};
+use crate::audit::types::interface::{AuditBlocks, ListAuditBlocks};
+use crate::audit::types::state::AuditOperation;
 use crate::auth::store::{
     get_config as get_authentication_config, set_config as set_authentication_config,
 };
 use crate::auth::types::config::AuthenticationConfig;
+use crate::bundle::store::{
+    apply_config_bundle_store, apply_init_config_store, get_config_bundle_store,
+};
+use crate::bundle::types::interface::ConfigBundle;
+use crate::changes::store::get_collection_version_store;
+use crate::client::store::get_client_metadata_store;
+use crate::client::types::interface::ClientMetadata;
+use crate::consent::store::{consent_message_store, supported_standards};
+use crate::consent::types::interface::{
+    Icrc21ConsentMessageRequest, Icrc21ConsentMessageResponse, SupportedStandard,
+};
+use crate::controllers::expiration::{remove_expired_controllers, schedule_controllers_expiration};
 use crate::controllers::store::get_admin_controllers;
 use crate::controllers::store::{
     delete_controllers as delete_controllers_store, get_controllers,
     set_controllers as set_controllers_store,
 };
+use crate::db::http::{
+    http_request as http_request_db, http_request_update as http_request_update_db, is_db_request,
+};
 use crate::db::store::{
     count_collection_docs_store, count_docs_store, delete_doc_store, delete_docs_store,
-    delete_filtered_docs_store, get_config_store as get_db_config_store, get_doc_store,
-    list_docs_store, set_config_store as set_db_config_store, set_doc_store,
+    delete_filtered_docs_store, get_config_store as get_db_config_store, get_doc_chunk_store,
+    get_doc_store, list_docs_store, patch_doc_store, set_config_store as set_db_config_store,
+    set_doc_store,
 };
 use crate::db::types::config::DbConfig;
-use crate::db::types::interface::{DelDoc, SetDoc};
+use crate::db::types::interface::{DelDoc, DocChunk, PatchDoc, SetDoc};
 use crate::db::types::state::{Doc, DocContext, DocUpsert};
-use crate::hooks::{
-    invoke_on_delete_asset, invoke_on_delete_doc, invoke_on_delete_filtered_assets,
-    invoke_on_delete_filtered_docs, invoke_on_delete_many_assets, invoke_on_delete_many_docs,
-    invoke_on_init, invoke_on_post_upgrade, invoke_on_set_doc, invoke_on_set_many_docs,
-    invoke_upload_asset,
+use crate::defrag::store::{
+    compact_content_chunks_store, get_compaction_report_store, get_memory_stats_store,
+    init_region_usage_store,
+};
+use crate::defrag::types::interface::{CompactionReport, MemoryRegionStats};
+use crate::defrag::types::state::DefragHeapState;
+use crate::deployment::routing::{is_previous_deployment_request, is_previous_deployment_token};
+use crate::deployment::store::{
+    finalize_deployment_store, get_canary_deployment_store, set_canary_deployment_store,
+};
+use crate::deployment::strategy_impls::PreviousDeploymentStorageState;
+use crate::deployment::types::interface::{CanaryDeploymentStatus, SetCanaryDeployment};
+use crate::dry_run::store::dry_run_store;
+use crate::dry_run::types::interface::{DryRunOperation, DryRunReport};
+use crate::events::store::emit;
+use crate::events::types::interface::SatelliteEvent;
+use crate::extensions::store::{list_extensions_store, set_extension_config_store};
+use crate::extensions::types::interface::SetExtensionConfig;
+use crate::extensions::types::state::{Extension, ExtensionKey};
+use crate::flags::store::{
+    delete_feature_flag_store, evaluate_feature_flags_store, list_feature_flags_store,
+    set_feature_flag_store,
+};
+use crate::flags::types::interface::{DelFeatureFlag, SetFeatureFlag};
+use crate::flags::types::state::{FeatureFlag, FeatureFlagKey};
+use crate::gates::store::verify_token_gate_store;
+use crate::gc::store::schedule_gc;
+use crate::health::http::{http_request as http_request_health, is_health_request};
+use crate::health::store::{get_health as get_health_store, set_upgraded};
+use crate::health::types::interface::Health;
+use crate::hooks::{invoke_on_init, invoke_on_post_upgrade};
+use crate::integrity::store::{defer_integrity_checks, get_integrity_report_store};
+use crate::integrity::types::interface::IntegrityReport;
+use crate::links::store::{analyze_asset_links_store, get_asset_links_report_store};
+use crate::links::types::interface::AssetLinksReport;
+use crate::maintenance::store::{
+    compact_collection_store, get_collection_job_report_store, rebuild_indexes_store,
+};
+use crate::maintenance::types::interface::CollectionJobReport;
+use crate::manifest::http::{
+    http_request as http_request_manifest, http_request_update as http_request_update_manifest,
+    is_manifest_request,
 };
 use crate::memory::{get_memory_upgrades, init_stable_state, STATE};
+use crate::moderation::store::{
+    get_config as get_moderation_config_store, set_asset_moderation_store,
+    set_config as set_moderation_config_store, set_doc_moderation_store,
+};
+use crate::moderation::types::config::ModerationConfig;
+use crate::moderation::types::interface::SetModeration;
+use crate::ownership::store::{set_asset_owner_store, set_doc_owner_store};
+use crate::ownership::types::interface::{SetAssetOwner, SetDocOwner};
+use crate::query::store::estimate_query_store;
+use crate::query::types::interface::QueryEstimate;
+use crate::quotas::store::{
+    get_status as get_quota_status_store, get_thresholds as get_quota_thresholds_store,
+    set_thresholds as set_quota_thresholds_store,
+};
+use crate::quotas::types::config::QuotaThresholds;
 use crate::random::defer_init_random_seed;
+use crate::rate_limit::http::rate_limited_response;
+use crate::rate_limit::store::{
+    get_config_store as get_rate_limit_config_store,
+    set_config_store as set_rate_limit_config_store,
+};
+use crate::rate_limit::types::config::RateLimitConfig;
+use crate::requests::store::{request_error, with_request_id_header};
+use crate::requests::types::runtime::Request;
+use crate::retention::store::{init_retention_purge, init_retention_store};
+use crate::retention::types::state::RetentionHeapState;
+use crate::revisions::store::{get_doc_at_store, migrate_heap_revisions};
 use crate::rules::store::{
     del_rule_db, del_rule_storage, get_rule_db, get_rule_storage, get_rules_db, get_rules_storage,
-    set_rule_db, set_rule_storage,
+    set_rule_compression_db, set_rule_compression_storage, set_rule_db, set_rule_storage,
 };
+use crate::safe_mode::store::{
+    acknowledge_safe_mode_store, get_self_test_report_store, run_self_test,
+};
+use crate::safe_mode::types::interface::SelfTestReport;
+use crate::stats::store::{get_collection_stats_store, init_stats_store};
+use crate::stats::types::interface::CollectionStats;
+use crate::stats::types::state::StatsHeapState;
+use crate::storage::certified_assets::runtime::init_certified_assets;
 use crate::storage::certified_assets::upgrade::defer_init_certified_assets;
 use crate::storage::store::{
     commit_batch_store, count_assets_store, count_collection_assets_store, create_batch_store,
     create_chunk_store, delete_asset_store, delete_assets_store, delete_domain_store,
-    delete_filtered_assets_store, get_asset_store, get_config_store as get_storage_config_store,
-    get_custom_domains_store, list_assets_store, set_config_store as set_storage_config_store,
-    set_domain_store,
+    delete_filtered_assets_store, diff_assets_store, get_asset_store,
+    get_config_store as get_storage_config_store, get_custom_domains_store, list_assets_store,
+    list_certified_paths_store, set_config_store as set_storage_config_store, set_domain_store,
 };
 use crate::storage::strategy_impls::StorageState;
+use crate::storage::types::interface::DiffAssetsArgs;
+use crate::traces::store::{
+    get_config as get_traces_config_store, list_traces_store, record_trace,
+    set_config as set_traces_config_store,
+};
+use crate::traces::types::config::TracesConfig;
+use crate::traces::types::interface::DailyEndpointTrace;
+use crate::transfer::store::{export_chunk_store, import_chunk_store};
 use crate::types::interface::{Config, RulesType};
 use crate::types::state::{HeapState, RuntimeState, State};
+use crate::uploads::http::{http_request_update as http_request_update_upload, is_upload_request};
+use crate::uploads::store::{
+    create_upload_token_store, delete_upload_token_store, list_upload_tokens_store,
+};
+use crate::uploads::types::interface::{CreateUploadTokenResult, SetUploadToken};
+use crate::uploads::types::state::{UploadToken, UploadTokenId};
+use crate::users::store::import_users_store;
+use crate::users::types::interface::{ImportUsers, ImportUsersReport};
+use crate::version::SATELLITE_VERSION;
+use crate::webhooks::store::{
+    delete_webhook_store, list_webhook_deliveries_store, list_webhooks_store,
+    resume_webhook_deliveries, set_webhook_store, transform_webhook_response_store,
+};
+use crate::webhooks::types::interface::{SetWebhook, WebhookNoSecret};
+use crate::webhooks::types::state::{WebhookDelivery, WebhookDeliveryId, WebhookId};
 use ciborium::{from_reader, into_writer};
 use ic_cdk::api::call::{arg_data, ArgDecoderConfig};
+use ic_cdk::api::management_canister::http_request::{
+    HttpResponse as HttpOutcallResponse, TransformArgs,
+};
 use ic_cdk::api::{caller, trap};
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::interface::{DelRule, SetRule};
-use junobuild_collections::types::rules::Rule;
+use junobuild_collections::types::rules::{Compression, Rule};
 use junobuild_shared::constants::MAX_NUMBER_OF_SATELLITE_CONTROLLERS;
 use junobuild_shared::controllers::{
-    assert_controllers, assert_max_number_of_controllers, init_controllers,
+    assert_controller_expiration, assert_controllers, assert_max_number_of_controllers,
+    init_controllers, is_controller,
 };
+use junobuild_shared::list::{list_page, paginate_page};
 use junobuild_shared::types::core::{DomainName, Key};
 use junobuild_shared::types::domain::CustomDomains;
-use junobuild_shared::types::interface::{DeleteControllersArgs, SegmentArgs, SetControllersArgs};
+use junobuild_shared::types::interface::{
+    DeleteControllersArgs, ExportChunk, ExportChunkArgs, ImportChunkArgs, QuotaStatus,
+    SatelliteArgs, SetControllersArgs,
+};
+use junobuild_shared::types::list::CollectionVersion;
 use junobuild_shared::types::list::ListParams;
 use junobuild_shared::types::list::ListResults;
+use junobuild_shared::types::list::{ListPaginate, Page};
 use junobuild_shared::types::memory::Memory;
-use junobuild_shared::types::state::{ControllerScope, Controllers};
+use junobuild_shared::types::state::{
+    Controller, ControllerId, ControllerScope, Controllers, Timestamp, UserId,
+};
 use junobuild_shared::upgrade::{read_post_upgrade, write_pre_upgrade};
+use junobuild_storage::http::response::upgrade_response;
 use junobuild_storage::http::types::{
     HttpRequest, HttpResponse, StreamingCallbackHttpResponse, StreamingCallbackToken,
 };
 use junobuild_storage::http_request::{
     http_request as http_request_storage,
     http_request_streaming_callback as http_request_streaming_callback_storage,
+    http_request_update as http_request_update_storage,
 };
 use junobuild_storage::types::config::StorageConfig;
 use junobuild_storage::types::interface::{
-    AssetNoContent, CommitBatch, InitAssetKey, InitUploadResult, UploadChunk, UploadChunkResult,
+    AssetNoContent, CertifiedPath, CommitBatch, InitAssetKey, InitUploadResult, UploadChunk,
+    UploadChunkResult,
 };
 use junobuild_storage::types::state::FullPath;
 use junobuild_storage::types::store::Asset;
 
 pub fn init() {
-    let call_arg = arg_data::<(Option<SegmentArgs>,)>(ArgDecoderConfig::default()).0;
-    let SegmentArgs { controllers } = call_arg.unwrap();
+    let call_arg = arg_data::<(Option<SatelliteArgs>,)>(ArgDecoderConfig::default()).0;
+    let SatelliteArgs {
+        controllers,
+        init_config,
+    } = call_arg.unwrap();
 
     let heap = HeapState {
         controllers: init_controllers(&controllers),
+        stats: Some(StatsHeapState::default()),
+        defrag: Some(DefragHeapState::default()),
+        retention: Some(RetentionHeapState::default()),
         ..HeapState::default()
     };
 
@@ -87,6 +240,21 @@ pub fn init() {
         };
     });
 
+    if let Some(init_config) = init_config {
+        apply_init_config_store(&init_config).unwrap_or_else(|e| trap(&request_error(&e)));
+
+        schedule_controllers_expiration();
+    }
+
+    run_self_test();
+
+    // There are no assets yet but the health and the manifest are certified from the start
+    init_certified_assets();
+
+    defer_init_random_seed();
+
+    init_retention_purge();
+
     invoke_on_init();
 }
 
@@ -107,8 +275,34 @@ pub fn post_upgrade() {
         .expect("Failed to decode the state of the satellite in post_upgrade hook.");
     STATE.with(|s| *s.borrow_mut() = state);
 
+    migrate_heap_revisions();
+
+    set_upgraded();
+
+    record_audit_block(
+        &caller(),
+        AuditOperation::Upgrade {
+            version: SATELLITE_VERSION.to_string(),
+        },
+    );
+
+    init_stats_store();
+    init_region_usage_store();
+    init_retention_store();
+
+    run_self_test();
+
     defer_init_certified_assets();
     defer_init_random_seed();
+    defer_integrity_checks();
+
+    resume_webhook_deliveries();
+
+    init_retention_purge();
+
+    schedule_gc();
+
+    remove_expired_controllers();
 
     invoke_on_post_upgrade();
 }
@@ -124,11 +318,34 @@ pub fn set_doc(collection: CollectionKey, key: Key, doc: SetDoc) -> Doc {
 
     match result {
         Ok(doc) => {
-            invoke_on_set_doc(&caller, &doc);
+            let after = doc.data.after.clone();
+
+            emit(&caller, SatelliteEvent::SetDoc(doc));
 
-            doc.data.after
+            record_trace("set_doc");
+
+            after
         }
-        Err(error) => trap(&error),
+        Err(error) => trap(&request_error(&error)),
+    }
+}
+
+pub fn patch_doc(collection: CollectionKey, key: Key, patch: PatchDoc) -> Doc {
+    let caller = caller();
+
+    let result = patch_doc_store(caller, collection, key, patch);
+
+    match result {
+        Ok(doc) => {
+            let after = doc.data.after.clone();
+
+            emit(&caller, SatelliteEvent::SetDoc(doc));
+
+            record_trace("patch_doc");
+
+            after
+        }
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
@@ -139,16 +356,29 @@ pub fn get_doc(collection: CollectionKey, key: Key) -> Option<Doc> {
 
     match result {
         Ok(value) => value,
-        Err(error) => trap(&error),
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
+pub fn get_doc_at(collection: CollectionKey, key: Key, timestamp: Timestamp) -> Option<Doc> {
+    get_doc_at_store(&collection, &key, timestamp).unwrap_or_else(|e| trap(&request_error(&e)))
+}
+
+pub fn get_doc_chunk(collection: CollectionKey, key: Key, index: u64) -> Option<DocChunk> {
+    let caller = caller();
+
+    get_doc_chunk_store(caller, collection, key, index).unwrap_or_else(|e| trap(&request_error(&e)))
+}
+
 pub fn del_doc(collection: CollectionKey, key: Key, doc: DelDoc) {
     let caller = caller();
 
-    let deleted_doc = delete_doc_store(caller, collection, key, doc).unwrap_or_else(|e| trap(&e));
+    let deleted_doc =
+        delete_doc_store(caller, collection, key, doc).unwrap_or_else(|e| trap(&request_error(&e)));
 
-    invoke_on_delete_doc(&caller, &deleted_doc);
+    emit(&caller, SatelliteEvent::DeleteDoc(deleted_doc));
+
+    record_trace("del_doc");
 }
 
 pub fn list_docs(collection: CollectionKey, filter: ListParams) -> ListResults<Doc> {
@@ -158,10 +388,14 @@ pub fn list_docs(collection: CollectionKey, filter: ListParams) -> ListResults<D
 
     match result {
         Ok(value) => value,
-        Err(error) => trap(&error),
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
+pub fn list_docs_page(collection: CollectionKey, filter: ListParams) -> Page<(Key, Doc)> {
+    list_page(|filter| list_docs(collection, filter), &filter)
+}
+
 pub fn count_docs(collection: CollectionKey, filter: ListParams) -> usize {
     let caller = caller();
 
@@ -169,7 +403,7 @@ pub fn count_docs(collection: CollectionKey, filter: ListParams) -> usize {
 
     match result {
         Ok(value) => value,
-        Err(error) => trap(&error),
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
@@ -189,15 +423,17 @@ pub fn set_many_docs(docs: Vec<(CollectionKey, Key, SetDoc)>) -> Vec<(Key, Doc)>
     let mut results: Vec<(Key, Doc)> = Vec::new();
 
     for (collection, key, doc) in docs {
-        let result =
-            set_doc_store(caller, collection, key.clone(), doc).unwrap_or_else(|e| trap(&e));
+        let result = set_doc_store(caller, collection, key.clone(), doc)
+            .unwrap_or_else(|e| trap(&request_error(&e)));
 
         results.push((result.key.clone(), result.data.after.clone()));
 
         hook_payload.push(result);
     }
 
-    invoke_on_set_many_docs(&caller, &hook_payload);
+    emit(&caller, SatelliteEvent::SetManyDocs(hook_payload));
+
+    record_trace("set_many_docs");
 
     results
 }
@@ -208,29 +444,58 @@ pub fn del_many_docs(docs: Vec<(CollectionKey, Key, DelDoc)>) {
     let mut results: Vec<DocContext<Option<Doc>>> = Vec::new();
 
     for (collection, key, doc) in docs {
-        let deleted_doc =
-            delete_doc_store(caller, collection, key.clone(), doc).unwrap_or_else(|e| trap(&e));
+        let deleted_doc = delete_doc_store(caller, collection, key.clone(), doc)
+            .unwrap_or_else(|e| trap(&request_error(&e)));
         results.push(deleted_doc);
     }
 
-    invoke_on_delete_many_docs(&caller, &results);
+    emit(&caller, SatelliteEvent::DeleteManyDocs(results));
+
+    record_trace("del_many_docs");
 }
 
 pub fn del_filtered_docs(collection: CollectionKey, filter: ListParams) {
     let caller = caller();
 
-    let results =
-        delete_filtered_docs_store(caller, collection, &filter).unwrap_or_else(|e| trap(&e));
+    // The controllers are not limited to the documents they own
+    if is_controller(caller, &get_controllers()) {
+        assert_approval_not_required().unwrap_or_else(|e| trap(&request_error(&e)));
+    }
 
-    invoke_on_delete_filtered_docs(&caller, &results);
+    apply_del_filtered_docs(caller, collection, filter);
+}
+
+fn apply_del_filtered_docs(caller: UserId, collection: CollectionKey, filter: ListParams) {
+    let results = delete_filtered_docs_store(caller, collection, &filter)
+        .unwrap_or_else(|e| trap(&request_error(&e)));
+
+    emit(&caller, SatelliteEvent::DeleteFilteredDocs(results));
+
+    record_trace("del_filtered_docs");
+}
+
+pub async fn verify_token_gate(collection: CollectionKey, request: &Request) -> bool {
+    let caller = caller();
+
+    let result = verify_token_gate_store(caller, &collection).await;
+
+    request.resume();
+
+    result.unwrap_or_else(|e| trap(&request_error(&e)))
 }
 
 pub fn del_docs(collection: CollectionKey) {
+    assert_approval_not_required().unwrap_or_else(|e| trap(&request_error(&e)));
+
+    apply_del_docs(collection);
+}
+
+fn apply_del_docs(collection: CollectionKey) {
     let result = delete_docs_store(&collection);
 
     match result {
         Ok(_) => (),
-        Err(error) => trap(&["Documents cannot be deleted: ", &error].join("")),
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
@@ -239,7 +504,7 @@ pub fn count_collection_docs(collection: CollectionKey) -> usize {
 
     match result {
         Ok(value) => value,
-        Err(error) => trap(&error),
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
@@ -262,24 +527,429 @@ pub fn list_rules(rules_type: RulesType) -> Vec<(CollectionKey, Rule)> {
 }
 
 pub fn set_rule(rules_type: RulesType, collection: CollectionKey, rule: SetRule) -> Rule {
-    match rules_type {
-        RulesType::Db => set_rule_db(collection, rule).unwrap_or_else(|e| trap(&e)),
-        RulesType::Storage => set_rule_storage(collection, rule).unwrap_or_else(|e| trap(&e)),
+    if changes_permissions(&rules_type, &collection, &rule) {
+        assert_approval_not_required().unwrap_or_else(|e| trap(&request_error(&e)));
     }
+
+    apply_set_rule(rules_type, collection, rule)
+}
+
+/// Changing the permissions of an existing collection - e.g. opening the writes to the public - is privilege-changing.
+fn changes_permissions(rules_type: &RulesType, collection: &CollectionKey, rule: &SetRule) -> bool {
+    get_rule(rules_type, collection)
+        .is_some_and(|current| current.read != rule.read || current.write != rule.write)
+}
+
+fn apply_set_rule(rules_type: RulesType, collection: CollectionKey, rule: SetRule) -> Rule {
+    let result = match rules_type {
+        RulesType::Db => {
+            set_rule_db(collection.clone(), rule).unwrap_or_else(|e| trap(&request_error(&e)))
+        }
+        RulesType::Storage => {
+            set_rule_storage(collection.clone(), rule).unwrap_or_else(|e| trap(&request_error(&e)))
+        }
+    };
+
+    emit(
+        &caller(),
+        SatelliteEvent::SetRule {
+            rules_type,
+            collection,
+            rule: result.clone(),
+        },
+    );
+
+    result
+}
+
+pub fn set_rule_compression(
+    rules_type: RulesType,
+    collection: CollectionKey,
+    compression: Option<Compression>,
+) -> Rule {
+    let result = match rules_type {
+        RulesType::Db => set_rule_compression_db(collection.clone(), compression),
+        RulesType::Storage => set_rule_compression_storage(collection.clone(), compression),
+    }
+    .unwrap_or_else(|e| trap(&request_error(&e)));
+
+    emit(
+        &caller(),
+        SatelliteEvent::SetRule {
+            rules_type,
+            collection,
+            rule: result.clone(),
+        },
+    );
+
+    result
 }
 
 pub fn del_rule(rules_type: RulesType, collection: CollectionKey, rule: DelRule) {
+    assert_approval_not_required().unwrap_or_else(|e| trap(&request_error(&e)));
+
+    apply_del_rule(rules_type, collection, rule);
+}
+
+fn apply_del_rule(rules_type: RulesType, collection: CollectionKey, rule: DelRule) {
     match rules_type {
-        RulesType::Db => del_rule_db(collection, rule).unwrap_or_else(|e| trap(&e)),
-        RulesType::Storage => del_rule_storage(collection, rule).unwrap_or_else(|e| trap(&e)),
+        RulesType::Db => {
+            del_rule_db(collection.clone(), rule).unwrap_or_else(|e| trap(&request_error(&e)))
+        }
+        RulesType::Storage => {
+            del_rule_storage(collection.clone(), rule).unwrap_or_else(|e| trap(&request_error(&e)))
+        }
+    }
+
+    emit(
+        &caller(),
+        SatelliteEvent::DeleteRule {
+            rules_type,
+            collection,
+        },
+    );
+}
+
+// ---------------------------------------------------------
+// Dry run
+// ---------------------------------------------------------
+
+pub fn dry_run(operation: DryRunOperation) -> DryRunReport {
+    let caller = caller();
+
+    dry_run_store(caller, &operation)
+}
+
+// ---------------------------------------------------------
+// Stats
+// ---------------------------------------------------------
+
+pub fn get_collection_stats(collection: CollectionKey) -> CollectionStats {
+    get_collection_stats_store(&collection)
+}
+
+pub fn get_collection_version(
+    rules_type: &RulesType,
+    collection: &CollectionKey,
+) -> Option<CollectionVersion> {
+    get_collection_version_store(rules_type, collection)
+}
+
+// ---------------------------------------------------------
+// Quotas
+// ---------------------------------------------------------
+
+pub fn set_quota_thresholds(thresholds: QuotaThresholds) {
+    set_quota_thresholds_store(&thresholds);
+}
+
+pub fn get_quota_thresholds() -> Option<QuotaThresholds> {
+    get_quota_thresholds_store()
+}
+
+pub fn get_quota_status() -> Option<QuotaStatus> {
+    get_quota_status_store()
+}
+
+// ---------------------------------------------------------
+// Transfer
+// ---------------------------------------------------------
+
+pub fn export_chunk(args: ExportChunkArgs) -> ExportChunk {
+    export_chunk_store(&args).unwrap_or_else(|e| trap(&request_error(&e)))
+}
+
+pub fn import_chunk(args: ImportChunkArgs) {
+    let caller = caller();
+
+    let events = import_chunk_store(caller, &args).unwrap_or_else(|e| trap(&request_error(&e)));
+
+    for event in events {
+        emit(&caller, event);
     }
 }
 
+pub fn import_users(args: ImportUsers) -> ImportUsersReport {
+    import_users_store(&args).unwrap_or_else(|e| trap(&request_error(&e)))
+}
+
+// ---------------------------------------------------------
+// Query
+// ---------------------------------------------------------
+
+pub fn estimate_query(
+    rules_type: RulesType,
+    collection: CollectionKey,
+    filter: ListParams,
+) -> QueryEstimate {
+    let caller = caller();
+
+    estimate_query_store(caller, &rules_type, &collection, &filter)
+        .unwrap_or_else(|e| trap(&request_error(&e)))
+}
+
+// ---------------------------------------------------------
+// Ownership
+// ---------------------------------------------------------
+
+pub fn set_doc_owner(collection: CollectionKey, key: Key, owner: SetDocOwner) -> Doc {
+    assert_approval_not_required().unwrap_or_else(|e| trap(&request_error(&e)));
+
+    apply_set_doc_owner(collection, key, owner)
+}
+
+fn apply_set_doc_owner(collection: CollectionKey, key: Key, owner: SetDocOwner) -> Doc {
+    let caller = caller();
+
+    let doc =
+        set_doc_owner_store(&collection, &key, &owner).unwrap_or_else(|e| trap(&request_error(&e)));
+
+    let after = doc.data.after.clone();
+
+    emit(&caller, SatelliteEvent::SetDoc(doc));
+
+    after
+}
+
+pub fn set_asset_owner(
+    collection: CollectionKey,
+    full_path: FullPath,
+    owner: SetAssetOwner,
+) -> AssetNoContent {
+    assert_approval_not_required().unwrap_or_else(|e| trap(&request_error(&e)));
+
+    apply_set_asset_owner(collection, full_path, owner)
+}
+
+fn apply_set_asset_owner(
+    collection: CollectionKey,
+    full_path: FullPath,
+    owner: SetAssetOwner,
+) -> AssetNoContent {
+    let caller = caller();
+
+    let asset = set_asset_owner_store(&collection, &full_path, &owner)
+        .unwrap_or_else(|e| trap(&request_error(&e)));
+
+    let result = AssetNoContent::from(&asset);
+
+    emit(&caller, SatelliteEvent::UploadAsset(asset));
+
+    result
+}
+
+// ---------------------------------------------------------
+// Moderation
+// ---------------------------------------------------------
+
+pub fn set_moderation_config(config: ModerationConfig) {
+    set_moderation_config_store(&config);
+}
+
+pub fn get_moderation_config() -> Option<ModerationConfig> {
+    get_moderation_config_store()
+}
+
+pub fn set_doc_moderation(collection: CollectionKey, key: Key, moderation: SetModeration) -> Doc {
+    let caller = caller();
+
+    let doc = set_doc_moderation_store(&collection, &key, &moderation)
+        .unwrap_or_else(|e| trap(&request_error(&e)));
+
+    let after = doc.data.after.clone();
+
+    emit(&caller, SatelliteEvent::SetDoc(doc));
+
+    after
+}
+
+pub fn set_asset_moderation(
+    collection: CollectionKey,
+    full_path: FullPath,
+    moderation: SetModeration,
+) -> AssetNoContent {
+    let caller = caller();
+
+    let asset = set_asset_moderation_store(&collection, &full_path, &moderation)
+        .unwrap_or_else(|e| trap(&request_error(&e)));
+
+    let result = AssetNoContent::from(&asset);
+
+    emit(&caller, SatelliteEvent::UploadAsset(asset));
+
+    result
+}
+
+// ---------------------------------------------------------
+// Approvals
+// ---------------------------------------------------------
+
+pub fn set_approvals_config(config: ApprovalsConfig) {
+    set_approvals_config_store(&config).unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+pub fn get_approvals_config() -> Option<ApprovalsConfig> {
+    get_approvals_config_store()
+}
+
+pub fn propose_action(action: ControllerAction) -> ActionId {
+    propose_action_store(caller(), &action).unwrap_or_else(|e| trap(&request_error(&e)))
+}
+
+pub fn confirm_action(id: ActionId) {
+    let action = confirm_action_store(caller(), &id).unwrap_or_else(|e| trap(&request_error(&e)));
+
+    match action {
+        ControllerAction::DelRule {
+            rules_type,
+            collection,
+            rule,
+        } => apply_del_rule(rules_type, collection, rule),
+        ControllerAction::DelDocs { collection } => apply_del_docs(collection),
+        ControllerAction::DelAssets { collection } => apply_del_assets(collection),
+        ControllerAction::DelFilteredDocs { collection, filter } => {
+            apply_del_filtered_docs(caller(), collection, filter)
+        }
+        ControllerAction::DelFilteredAssets { collection, filter } => {
+            apply_del_filtered_assets(caller(), collection, filter)
+        }
+        ControllerAction::SetRule {
+            rules_type,
+            collection,
+            rule,
+        } => {
+            apply_set_rule(rules_type, collection, rule);
+        }
+        ControllerAction::SetDocOwner {
+            collection,
+            key,
+            owner,
+        } => {
+            apply_set_doc_owner(collection, key, owner);
+        }
+        ControllerAction::SetAssetOwner {
+            collection,
+            full_path,
+            owner,
+        } => {
+            apply_set_asset_owner(collection, full_path, owner);
+        }
+        ControllerAction::SetControllers(args) => {
+            apply_set_controllers(args);
+        }
+        ControllerAction::DelControllers(args) => {
+            apply_del_controllers(args);
+        }
+        ControllerAction::ApplyConfigBundle(bundle) => apply_bundle(*bundle),
+        ControllerAction::SetApprovalsConfig(config) => {
+            apply_approvals_config_store(&config).unwrap_or_else(|e| trap(&request_error(&e)))
+        }
+    }
+}
+
+pub fn cancel_action(id: ActionId) {
+    cancel_action_store(&id).unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+pub fn list_pending_actions() -> Vec<(ActionId, PendingAction)> {
+    list_pending_actions_store()
+}
+
+// ---------------------------------------------------------
+// Traces
+// ---------------------------------------------------------
+
+pub fn set_traces_config(config: TracesConfig) {
+    set_traces_config_store(&config).unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+pub fn get_traces_config() -> Option<TracesConfig> {
+    get_traces_config_store()
+}
+
+pub fn list_traces() -> Vec<DailyEndpointTrace> {
+    list_traces_store()
+}
+
+// ---------------------------------------------------------
+// Rate limit
+// ---------------------------------------------------------
+
+pub fn set_rate_limit_config(config: Option<RateLimitConfig>) {
+    set_rate_limit_config_store(&config).unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+pub fn get_rate_limit_config() -> Option<RateLimitConfig> {
+    get_rate_limit_config_store()
+}
+
+// ---------------------------------------------------------
+// Uploads
+// ---------------------------------------------------------
+
+pub fn create_upload_token(token: SetUploadToken) -> CreateUploadTokenResult {
+    create_upload_token_store(&token).unwrap_or_else(|e| trap(&request_error(&e)))
+}
+
+pub fn list_upload_tokens() -> Vec<(UploadTokenId, UploadToken)> {
+    list_upload_tokens_store()
+}
+
+pub fn del_upload_token(id: UploadTokenId) {
+    delete_upload_token_store(&id).unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+// ---------------------------------------------------------
+// API keys
+// ---------------------------------------------------------
+
+pub fn create_api_key(api_key: SetApiKey) -> CreateApiKeyResult {
+    create_api_key_store(&api_key).unwrap_or_else(|e| trap(&request_error(&e)))
+}
+
+pub fn list_api_keys() -> Vec<(ApiKeyId, ApiKey)> {
+    list_api_keys_store()
+}
+
+pub fn del_api_key(id: ApiKeyId) {
+    delete_api_key_store(&id).unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+// ---------------------------------------------------------
+// Webhooks
+// ---------------------------------------------------------
+
+pub fn set_webhook(id: WebhookId, webhook: SetWebhook) {
+    set_webhook_store(&id, &webhook).unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+pub fn list_webhooks() -> Vec<(WebhookId, WebhookNoSecret)> {
+    list_webhooks_store()
+}
+
+pub fn del_webhook(id: WebhookId) {
+    delete_webhook_store(&id).unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+pub fn list_webhook_deliveries() -> Vec<(WebhookDeliveryId, WebhookDelivery)> {
+    list_webhook_deliveries_store()
+}
+
+pub fn transform_webhook_response(args: TransformArgs) -> HttpOutcallResponse {
+    transform_webhook_response_store(args)
+}
+
 // ---------------------------------------------------------
 // Controllers
 // ---------------------------------------------------------
 
-pub fn set_controllers(
+pub fn set_controllers(args: SetControllersArgs) -> Controllers {
+    assert_approval_not_required().unwrap_or_else(|e| trap(&request_error(&e)));
+
+    apply_set_controllers(args)
+}
+
+fn apply_set_controllers(
     SetControllersArgs {
         controllers,
         controller,
@@ -295,21 +965,42 @@ pub fn set_controllers(
             );
 
             if let Err(err) = max_controllers {
-                trap(&err)
+                trap(&request_error(&err))
             }
         }
     }
 
-    assert_controllers(&controllers).unwrap_or_else(|e| trap(&e));
+    assert_controllers(&controllers).unwrap_or_else(|e| trap(&request_error(&e)));
+    assert_controller_expiration(&controller).unwrap_or_else(|e| trap(&request_error(&e)));
 
     set_controllers_store(&controllers, &controller);
+
+    schedule_controllers_expiration();
+
+    record_audit_block(
+        &caller(),
+        AuditOperation::SetControllers {
+            controllers,
+            scope: controller.scope,
+        },
+    );
+
     get_controllers()
 }
 
-pub fn del_controllers(
+pub fn del_controllers(args: DeleteControllersArgs) -> Controllers {
+    assert_approval_not_required().unwrap_or_else(|e| trap(&request_error(&e)));
+
+    apply_del_controllers(args)
+}
+
+fn apply_del_controllers(
     DeleteControllersArgs { controllers }: DeleteControllersArgs,
 ) -> Controllers {
     delete_controllers_store(&controllers);
+
+    record_audit_block(&caller(), AuditOperation::DelControllers { controllers });
+
     get_controllers()
 }
 
@@ -317,6 +1008,10 @@ pub fn list_controllers() -> Controllers {
     get_controllers()
 }
 
+pub fn list_controllers_page(paginate: ListPaginate) -> Page<(ControllerId, Controller)> {
+    paginate_page(get_controllers().into_iter(), &paginate)
+}
+
 // ---------------------------------------------------------
 // Config
 // ---------------------------------------------------------
@@ -333,6 +1028,22 @@ pub fn get_config() -> Config {
     }
 }
 
+pub fn get_config_bundle() -> ConfigBundle {
+    get_config_bundle_store()
+}
+
+pub fn apply_config_bundle(bundle: ConfigBundle) {
+    assert_approval_not_required().unwrap_or_else(|e| trap(&request_error(&e)));
+
+    apply_bundle(bundle);
+}
+
+fn apply_bundle(bundle: ConfigBundle) {
+    apply_config_bundle_store(&bundle).unwrap_or_else(|e| trap(&request_error(&e)));
+
+    emit(&caller(), SatelliteEvent::ApplyConfigBundle(bundle));
+}
+
 // ---------------------------------------------------------
 // Custom domains
 // ---------------------------------------------------------
@@ -342,11 +1053,23 @@ pub fn list_custom_domains() -> CustomDomains {
 }
 
 pub fn set_custom_domain(domain_name: DomainName, bn_id: Option<String>) {
-    set_domain_store(&domain_name, &bn_id).unwrap_or_else(|e| trap(&e));
+    set_domain_store(&domain_name, &bn_id).unwrap_or_else(|e| trap(&request_error(&e)));
+
+    record_audit_block(&caller(), AuditOperation::SetCustomDomain { domain_name });
 }
 
 pub fn del_custom_domain(domain_name: DomainName) {
-    delete_domain_store(&domain_name).unwrap_or_else(|e| trap(&e));
+    delete_domain_store(&domain_name).unwrap_or_else(|e| trap(&request_error(&e)));
+
+    record_audit_block(&caller(), AuditOperation::DelCustomDomain { domain_name });
+}
+
+// ---------------------------------------------------------
+// Audit
+// ---------------------------------------------------------
+
+pub fn list_audit_blocks(args: ListAuditBlocks) -> AuditBlocks {
+    list_audit_blocks_store(&args)
 }
 
 // ---------------------------------------------------------
@@ -354,19 +1077,133 @@ pub fn del_custom_domain(domain_name: DomainName) {
 // ---------------------------------------------------------
 
 pub fn set_auth_config(config: AuthenticationConfig) {
-    set_authentication_config(&config).unwrap_or_else(|e| trap(&e));
+    set_authentication_config(&config).unwrap_or_else(|e| trap(&request_error(&e)));
+
+    emit(&caller(), SatelliteEvent::SetAuthConfig(config));
 }
 
 pub fn get_auth_config() -> Option<AuthenticationConfig> {
     get_authentication_config()
 }
 
+// ---------------------------------------------------------
+// Analytics config
+// ---------------------------------------------------------
+
+pub fn set_analytics_config(config: AnalyticsConfig) {
+    set_analytics_config_store(&config).unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+pub fn get_analytics_config() -> Option<AnalyticsConfig> {
+    get_analytics_config_store()
+}
+
+// ---------------------------------------------------------
+// Feature flags
+// ---------------------------------------------------------
+
+pub fn set_feature_flag(key: FeatureFlagKey, flag: SetFeatureFlag) -> FeatureFlag {
+    set_feature_flag_store(key, flag).unwrap_or_else(|e| trap(&request_error(&e)))
+}
+
+pub fn del_feature_flag(key: FeatureFlagKey, flag: DelFeatureFlag) {
+    delete_feature_flag_store(key, flag).unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+pub fn list_feature_flags() -> Vec<(FeatureFlagKey, FeatureFlag)> {
+    list_feature_flags_store()
+}
+
+pub fn get_feature_flags() -> Vec<(FeatureFlagKey, bool)> {
+    evaluate_feature_flags_store(caller())
+}
+
+// ---------------------------------------------------------
+// Extensions
+// ---------------------------------------------------------
+
+pub fn set_extension_config(key: ExtensionKey, config: SetExtensionConfig) -> Extension {
+    set_extension_config_store(key, config).unwrap_or_else(|e| trap(&request_error(&e)))
+}
+
+pub fn list_extensions() -> Vec<(ExtensionKey, Extension)> {
+    list_extensions_store()
+}
+
+// ---------------------------------------------------------
+// Client
+// ---------------------------------------------------------
+
+pub fn get_client_metadata() -> ClientMetadata {
+    get_client_metadata_store()
+}
+
+// ---------------------------------------------------------
+// Health
+// ---------------------------------------------------------
+
+pub fn health() -> Health {
+    get_health_store()
+}
+
+pub fn get_integrity_report() -> Option<IntegrityReport> {
+    get_integrity_report_store()
+}
+
+pub fn get_self_test_report() -> Option<SelfTestReport> {
+    get_self_test_report_store()
+}
+
+pub fn acknowledge_safe_mode() {
+    let caller = caller();
+
+    acknowledge_safe_mode_store(caller).unwrap_or_else(|e| trap(&request_error(&e)));
+
+    record_audit_block(&caller, AuditOperation::AcknowledgeSafeMode);
+
+    schedule_gc();
+}
+
+pub fn analyze_asset_links() {
+    analyze_asset_links_store().unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+pub fn get_asset_links_report() -> Option<AssetLinksReport> {
+    get_asset_links_report_store()
+}
+
+pub fn get_memory_stats() -> Vec<MemoryRegionStats> {
+    get_memory_stats_store()
+}
+
+pub fn compact_content_chunks() {
+    compact_content_chunks_store().unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+pub fn get_compaction_report() -> Option<CompactionReport> {
+    get_compaction_report_store()
+}
+
+pub fn rebuild_indexes(collection: CollectionKey) {
+    rebuild_indexes_store(&collection).unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+pub fn compact_collection(collection: CollectionKey) {
+    compact_collection_store(&collection).unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
+pub fn get_collection_job_report() -> Option<CollectionJobReport> {
+    get_collection_job_report_store()
+}
+
 // ---------------------------------------------------------
 // Db config
 // ---------------------------------------------------------
 
 pub fn set_db_config(config: DbConfig) {
-    set_db_config_store(&config);
+    set_db_config_store(&config).unwrap_or_else(|e| trap(&request_error(&e)));
+
+    emit(&caller(), SatelliteEvent::SetDbConfig(config));
 }
 
 pub fn get_db_config() -> Option<DbConfig> {
@@ -378,24 +1215,95 @@ pub fn get_db_config() -> Option<DbConfig> {
 // ---------------------------------------------------------
 
 pub fn set_storage_config(config: StorageConfig) {
-    set_storage_config_store(&config);
+    set_storage_config_store(&config).unwrap_or_else(|e| trap(&request_error(&e)));
+
+    emit(&caller(), SatelliteEvent::SetStorageConfig(config));
 }
 
 pub fn get_storage_config() -> StorageConfig {
     get_storage_config_store()
 }
 
+// ---------------------------------------------------------
+// Canary deployment
+// ---------------------------------------------------------
+
+pub fn set_canary_deployment(deployment: SetCanaryDeployment) -> CanaryDeploymentStatus {
+    set_canary_deployment_store(&deployment).unwrap_or_else(|e| trap(&request_error(&e)))
+}
+
+pub fn get_canary_deployment() -> Option<CanaryDeploymentStatus> {
+    get_canary_deployment_store()
+}
+
+pub fn finalize_deployment() {
+    finalize_deployment_store().unwrap_or_else(|e| trap(&request_error(&e)));
+}
+
 // ---------------------------------------------------------
 // Http
 // ---------------------------------------------------------
 
 pub fn http_request(request: HttpRequest) -> HttpResponse {
+    if is_analytics_request(&request) {
+        return http_request_analytics(&request);
+    }
+
+    if is_health_request(&request) {
+        return http_request_health(request);
+    }
+
+    if is_db_request(&request) {
+        return http_request_db(&request);
+    }
+
+    if is_manifest_request(&request) {
+        return http_request_manifest(&request);
+    }
+
+    if is_upload_request(&request) {
+        return upgrade_response();
+    }
+
+    if is_previous_deployment_request(&request.headers) {
+        return http_request_storage(request, &PreviousDeploymentStorageState);
+    }
+
     http_request_storage(request, &StorageState)
 }
 
+pub async fn http_request_update(request: HttpRequest, current: &Request) -> HttpResponse {
+    let response = if let Some(response) = rate_limited_response(&request) {
+        response
+    } else if is_analytics_request(&request) {
+        let response = http_request_update_analytics(request).await;
+
+        current.resume();
+
+        response
+    } else if is_db_request(&request) {
+        http_request_update_db(request)
+    } else if is_manifest_request(&request) {
+        http_request_update_manifest(request)
+    } else if is_upload_request(&request) {
+        http_request_update_upload(request)
+    } else {
+        http_request_update_storage(request, &StorageState)
+    };
+
+    with_request_id_header(response, &current.id)
+}
+
 pub fn http_request_streaming_callback(
     streaming_callback_token: StreamingCallbackToken,
 ) -> StreamingCallbackHttpResponse {
+    if is_previous_deployment_token(&streaming_callback_token) {
+        return http_request_streaming_callback_storage(
+            streaming_callback_token,
+            &PreviousDeploymentStorageState,
+        );
+    }
+
     http_request_streaming_callback_storage(streaming_callback_token, &StorageState)
 }
 
@@ -405,11 +1313,16 @@ pub fn http_request_streaming_callback(
 
 pub fn init_asset_upload(init: InitAssetKey) -> InitUploadResult {
     let caller = caller();
+
     let result = create_batch_store(caller, init);
 
     match result {
-        Ok(batch_id) => InitUploadResult { batch_id },
-        Err(error) => trap(&error),
+        Ok(batch_id) => {
+            record_trace("init_asset_upload");
+
+            InitUploadResult { batch_id }
+        }
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
@@ -419,17 +1332,23 @@ pub fn upload_asset_chunk(chunk: UploadChunk) -> UploadChunkResult {
     let result = create_chunk_store(caller, chunk);
 
     match result {
-        Ok(chunk_id) => UploadChunkResult { chunk_id },
-        Err(error) => trap(&error),
+        Ok(chunk_id) => {
+            record_trace("upload_asset_chunk");
+
+            UploadChunkResult { chunk_id }
+        }
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
 pub fn commit_asset_upload(commit: CommitBatch) {
     let caller = caller();
 
-    let asset = commit_batch_store(caller, commit).unwrap_or_else(|e| trap(&e));
+    let asset = commit_batch_store(caller, commit).unwrap_or_else(|e| trap(&request_error(&e)));
+
+    emit(&caller, SatelliteEvent::UploadAsset(asset));
 
-    invoke_upload_asset(&caller, &asset);
+    record_trace("commit_asset_upload");
 }
 
 pub fn list_assets(collection: CollectionKey, filter: ListParams) -> ListResults<AssetNoContent> {
@@ -439,10 +1358,17 @@ pub fn list_assets(collection: CollectionKey, filter: ListParams) -> ListResults
 
     match result {
         Ok(result) => result,
-        Err(error) => trap(&["Assets cannot be listed: ".to_string(), error].join("")),
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
+pub fn list_assets_page(
+    collection: CollectionKey,
+    filter: ListParams,
+) -> Page<(FullPath, AssetNoContent)> {
+    list_page(|filter| list_assets(collection, filter), &filter)
+}
+
 pub fn count_assets(collection: CollectionKey, filter: ListParams) -> usize {
     let caller = caller();
 
@@ -450,19 +1376,27 @@ pub fn count_assets(collection: CollectionKey, filter: ListParams) -> usize {
 
     match result {
         Ok(result) => result,
-        Err(error) => trap(&["Assets cannot be counted: ".to_string(), error].join("")),
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
+pub fn diff_assets(DiffAssetsArgs { collection, assets }: DiffAssetsArgs) -> Vec<FullPath> {
+    diff_assets_store(&collection, &assets).unwrap_or_else(|e| trap(&request_error(&e)))
+}
+
 pub fn del_asset(collection: CollectionKey, full_path: FullPath) {
     let caller = caller();
 
     let result = delete_asset_store(caller, &collection, full_path);
 
     match result {
-        Ok(asset) => invoke_on_delete_asset(&caller, &asset),
-        Err(error) => trap(&["Asset cannot be deleted: ", &error].join("")),
+        Ok(asset) => emit(&caller, SatelliteEvent::DeleteAsset(asset)),
+        Err(error) => trap(&request_error(
+            &["Asset cannot be deleted: ", &error].join(""),
+        )),
     }
+
+    record_trace("del_asset");
 }
 
 pub fn del_many_assets(assets: Vec<(CollectionKey, String)>) {
@@ -471,38 +1405,61 @@ pub fn del_many_assets(assets: Vec<(CollectionKey, String)>) {
     let mut results: Vec<Option<Asset>> = Vec::new();
 
     for (collection, full_path) in assets {
-        let deleted_asset =
-            delete_asset_store(caller, &collection, full_path).unwrap_or_else(|e| trap(&e));
+        let deleted_asset = delete_asset_store(caller, &collection, full_path)
+            .unwrap_or_else(|e| trap(&request_error(&e)));
         results.push(deleted_asset);
     }
 
-    invoke_on_delete_many_assets(&caller, &results);
+    emit(&caller, SatelliteEvent::DeleteManyAssets(results));
+
+    record_trace("del_many_assets");
 }
 
 pub fn del_filtered_assets(collection: CollectionKey, filter: ListParams) {
     let caller = caller();
 
-    let results =
-        delete_filtered_assets_store(caller, collection, &filter).unwrap_or_else(|e| trap(&e));
+    // The controllers are not limited to the assets they own
+    if is_controller(caller, &get_controllers()) {
+        assert_approval_not_required().unwrap_or_else(|e| trap(&request_error(&e)));
+    }
+
+    apply_del_filtered_assets(caller, collection, filter);
+}
+
+fn apply_del_filtered_assets(caller: UserId, collection: CollectionKey, filter: ListParams) {
+    let results = delete_filtered_assets_store(caller, collection, &filter)
+        .unwrap_or_else(|e| trap(&request_error(&e)));
+
+    emit(&caller, SatelliteEvent::DeleteFilteredAssets(results));
 
-    invoke_on_delete_filtered_assets(&caller, &results);
+    record_trace("del_filtered_assets");
 }
 
 pub fn del_assets(collection: CollectionKey) {
+    assert_approval_not_required().unwrap_or_else(|e| trap(&request_error(&e)));
+
+    apply_del_assets(collection);
+}
+
+fn apply_del_assets(collection: CollectionKey) {
     let result = delete_assets_store(&collection);
 
     match result {
         Ok(_) => (),
-        Err(error) => trap(&["Assets cannot be deleted: ", &error].join("")),
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
+pub fn list_certified_paths() -> Vec<CertifiedPath> {
+    list_certified_paths_store()
+}
+
 pub fn count_collection_assets(collection: CollectionKey) -> usize {
     let result = count_collection_assets_store(&collection);
 
     match result {
         Ok(value) => value,
-        Err(error) => trap(&error),
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
@@ -513,7 +1470,7 @@ pub fn get_asset(collection: CollectionKey, full_path: FullPath) -> Option<Asset
 
     match result {
         Ok(asset) => asset.map(|asset| AssetNoContent::from(&asset)),
-        Err(error) => trap(&error),
+        Err(error) => trap(&request_error(&error)),
     }
 }
 
@@ -528,3 +1485,17 @@ pub fn get_many_assets(
         })
         .collect()
 }
+
+// ---------------------------------------------------------
+// Consent messages
+// ---------------------------------------------------------
+
+pub fn icrc21_canister_call_consent_message(
+    request: Icrc21ConsentMessageRequest,
+) -> Icrc21ConsentMessageResponse {
+    consent_message_store(&request)
+}
+
+pub fn icrc10_supported_standards() -> Vec<SupportedStandard> {
+    supported_standards()
+}
@@ -0,0 +1,116 @@
+use crate::db::types::state::Doc;
+use crate::stats::types::interface::ItemsStats;
+use crate::stats::types::runtime::StatsItem;
+use crate::stats::types::state::{CollectionCounters, OwnerCounters, TimestampsCounters};
+use junobuild_shared::types::state::{Timestamp, UserId};
+use junobuild_storage::types::store::Asset;
+
+const TOP_OWNERS: usize = 10;
+
+impl From<&Doc> for StatsItem {
+    fn from(doc: &Doc) -> Self {
+        StatsItem {
+            owner: doc.owner,
+            bytes: doc.data.len() as u128,
+            created_at: doc.created_at,
+            updated_at: doc.updated_at,
+        }
+    }
+}
+
+impl From<&Asset> for StatsItem {
+    fn from(asset: &Asset) -> Self {
+        StatsItem {
+            owner: asset.key.owner,
+            bytes: asset
+                .encodings
+                .values()
+                .map(|encoding| encoding.total_length)
+                .sum(),
+            created_at: asset.created_at,
+            updated_at: asset.updated_at,
+        }
+    }
+}
+
+impl CollectionCounters {
+    pub fn add(&mut self, item: &StatsItem) {
+        self.items_count += 1;
+        self.items_bytes += item.bytes;
+
+        increment_timestamp(&mut self.created_at, item.created_at);
+        increment_timestamp(&mut self.updated_at, item.updated_at);
+
+        let owner = self.owners.entry(item.owner).or_default();
+        owner.items_count += 1;
+        owner.items_bytes += item.bytes;
+    }
+
+    pub fn remove(&mut self, item: &StatsItem) {
+        self.items_count = self.items_count.saturating_sub(1);
+        self.items_bytes = self.items_bytes.saturating_sub(item.bytes);
+
+        decrement_timestamp(&mut self.created_at, item.created_at);
+        decrement_timestamp(&mut self.updated_at, item.updated_at);
+
+        if let Some(owner) = self.owners.get_mut(&item.owner) {
+            owner.items_count = owner.items_count.saturating_sub(1);
+            owner.items_bytes = owner.items_bytes.saturating_sub(item.bytes);
+
+            if owner.items_count == 0 {
+                self.owners.remove(&item.owner);
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items_count == 0
+    }
+
+    pub fn min_created_at(&self) -> Option<Timestamp> {
+        self.created_at.keys().next().copied()
+    }
+
+    pub fn max_updated_at(&self) -> Option<Timestamp> {
+        self.updated_at.keys().next_back().copied()
+    }
+}
+
+fn increment_timestamp(timestamps: &mut TimestampsCounters, timestamp: Timestamp) {
+    *timestamps.entry(timestamp).or_default() += 1;
+}
+
+fn decrement_timestamp(timestamps: &mut TimestampsCounters, timestamp: Timestamp) {
+    if let Some(count) = timestamps.get_mut(&timestamp) {
+        *count = count.saturating_sub(1);
+
+        if *count == 0 {
+            timestamps.remove(&timestamp);
+        }
+    }
+}
+
+impl From<&CollectionCounters> for ItemsStats {
+    fn from(counters: &CollectionCounters) -> Self {
+        let mut top_owners: Vec<(UserId, OwnerCounters)> = counters
+            .owners
+            .iter()
+            .map(|(owner, owner_counters)| (*owner, owner_counters.clone()))
+            .collect();
+
+        top_owners.sort_by(|(_, a), (_, b)| {
+            b.items_count
+                .cmp(&a.items_count)
+                .then(b.items_bytes.cmp(&a.items_bytes))
+        });
+        top_owners.truncate(TOP_OWNERS);
+
+        ItemsStats {
+            items_count: counters.items_count,
+            items_bytes: counters.items_bytes,
+            min_created_at: counters.min_created_at(),
+            max_updated_at: counters.max_updated_at(),
+            top_owners,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod impls;
+pub mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,133 @@
+use crate::memory::STATE;
+use crate::stats::types::runtime::StatsItem;
+use crate::stats::types::state::{
+    CollectionCounters, CollectionsCounters, OwnerCounters, StatsHeapState,
+};
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::state::UserId;
+
+pub fn get_db_counters(collection: &CollectionKey) -> Option<CollectionCounters> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .stats
+            .as_ref()
+            .and_then(|stats| stats.db.get(collection).cloned())
+    })
+}
+
+pub fn get_storage_counters(collection: &CollectionKey) -> Option<CollectionCounters> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .stats
+            .as_ref()
+            .and_then(|stats| stats.storage.get(collection).cloned())
+    })
+}
+
+pub fn get_db_owner_counters(collection: &CollectionKey, owner: &UserId) -> Option<OwnerCounters> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .stats
+            .as_ref()
+            .and_then(|stats| stats.db.get(collection))
+            .and_then(|counters| counters.owners.get(owner).cloned())
+    })
+}
+
+pub fn get_storage_owner_counters(
+    collection: &CollectionKey,
+    owner: &UserId,
+) -> Option<OwnerCounters> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .stats
+            .as_ref()
+            .and_then(|stats| stats.storage.get(collection))
+            .and_then(|counters| counters.owners.get(owner).cloned())
+    })
+}
+
+pub fn get_db_items_count() -> u64 {
+    STATE.with(|state| {
+        state.borrow().heap.stats.as_ref().map_or(0, |stats| {
+            stats.db.values().map(|counters| counters.items_count).sum()
+        })
+    })
+}
+
+pub fn is_stats_initialized() -> bool {
+    STATE.with(|state| state.borrow().heap.stats.is_some())
+}
+
+pub fn insert_stats(stats: StatsHeapState) {
+    STATE.with(|state| state.borrow_mut().heap.stats = Some(stats))
+}
+
+pub fn update_db_counters(
+    collection: &CollectionKey,
+    before: &Option<StatsItem>,
+    after: &Option<StatsItem>,
+) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let stats = state.heap.stats.get_or_insert_with(StatsHeapState::default);
+
+        update_counters(collection, before, after, &mut stats.db)
+    })
+}
+
+pub fn update_storage_counters(
+    collection: &CollectionKey,
+    before: &Option<StatsItem>,
+    after: &Option<StatsItem>,
+) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let stats = state.heap.stats.get_or_insert_with(StatsHeapState::default);
+
+        update_counters(collection, before, after, &mut stats.storage)
+    })
+}
+
+/// Replaces the counters of a collection - e.g. once those were counted again from its documents.
+pub fn set_db_counters(collection: &CollectionKey, counters: CollectionCounters) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let stats = state.heap.stats.get_or_insert_with(StatsHeapState::default);
+
+        if counters.is_empty() {
+            stats.db.remove(collection);
+        } else {
+            stats.db.insert(collection.clone(), counters);
+        }
+    })
+}
+
+fn update_counters(
+    collection: &CollectionKey,
+    before: &Option<StatsItem>,
+    after: &Option<StatsItem>,
+    counters: &mut CollectionsCounters,
+) {
+    let collection_counters = counters.entry(collection.clone()).or_default();
+
+    if let Some(before) = before {
+        collection_counters.remove(before);
+    }
+
+    if let Some(after) = after {
+        collection_counters.add(after);
+    }
+
+    if collection_counters.is_empty() {
+        counters.remove(collection);
+    }
+}
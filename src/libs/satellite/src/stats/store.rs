@@ -0,0 +1,110 @@
+use crate::db::state::{get_docs_heap, get_docs_stable};
+use crate::db::types::state::Doc;
+use crate::memory::STATE;
+use crate::quotas::store::refresh_status as refresh_quota_status;
+use crate::stats::state::{
+    get_db_counters, get_storage_counters, insert_stats, is_stats_initialized, update_db_counters,
+    update_storage_counters,
+};
+use crate::stats::types::interface::{CollectionStats, ItemsStats};
+use crate::stats::types::runtime::StatsItem;
+use crate::stats::types::state::{CollectionCounters, StatsHeapState};
+use crate::storage::state::get_assets_stable;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::rules::Memory;
+use junobuild_storage::heap_utils::collect_assets_heap;
+use junobuild_storage::types::store::Asset;
+
+pub fn get_collection_stats_store(collection: &CollectionKey) -> CollectionStats {
+    CollectionStats {
+        docs: get_db_counters(collection).map(|counters| ItemsStats::from(&counters)),
+        assets: get_storage_counters(collection).map(|counters| ItemsStats::from(&counters)),
+    }
+}
+
+pub fn update_doc_stats(collection: &CollectionKey, before: Option<&Doc>, after: Option<&Doc>) {
+    update_db_counters(
+        collection,
+        &before.map(StatsItem::from),
+        &after.map(StatsItem::from),
+    );
+
+    refresh_quota_status();
+}
+
+pub fn update_asset_stats(
+    collection: &CollectionKey,
+    before: Option<&Asset>,
+    after: Option<&Asset>,
+) {
+    update_storage_counters(
+        collection,
+        &before.map(StatsItem::from),
+        &after.map(StatsItem::from),
+    );
+
+    refresh_quota_status();
+}
+
+/// Counts the existing documents and assets once, when a satellite that did not maintain the statistics yet is
+/// upgraded. The counters are then updated incrementally.
+pub fn init_stats_store() {
+    if is_stats_initialized() {
+        return;
+    }
+
+    let stats = STATE.with(|state| {
+        let state = state.borrow();
+
+        let mut stats = StatsHeapState::default();
+
+        for (collection, rule) in &state.heap.db.rules {
+            let mut counters = CollectionCounters::default();
+
+            match rule.mem() {
+                Memory::Heap => {
+                    for (_, doc) in get_docs_heap(collection, &state.heap.db.db).unwrap_or_default()
+                    {
+                        counters.add(&StatsItem::from(doc));
+                    }
+                }
+                Memory::Stable => {
+                    for (_, doc) in
+                        get_docs_stable(collection, &state.stable.db).unwrap_or_default()
+                    {
+                        counters.add(&StatsItem::from(&doc));
+                    }
+                }
+            }
+
+            if !counters.is_empty() {
+                stats.db.insert(collection.clone(), counters);
+            }
+        }
+
+        for (collection, rule) in &state.heap.storage.rules {
+            let mut counters = CollectionCounters::default();
+
+            match rule.mem() {
+                Memory::Heap => {
+                    for (_, asset) in collect_assets_heap(collection, &state.heap.storage.assets) {
+                        counters.add(&StatsItem::from(asset));
+                    }
+                }
+                Memory::Stable => {
+                    for (_, asset) in get_assets_stable(collection, &state.stable.assets) {
+                        counters.add(&StatsItem::from(&asset));
+                    }
+                }
+            }
+
+            if !counters.is_empty() {
+                stats.storage.insert(collection.clone(), counters);
+            }
+        }
+
+        stats
+    });
+
+    insert_stats(stats);
+}
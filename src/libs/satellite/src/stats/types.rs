@@ -0,0 +1,73 @@
+pub mod state {
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use serde::{Deserialize, Serialize};
+    use std::collections::{BTreeMap, HashMap};
+
+    pub type CollectionsCounters = HashMap<CollectionKey, CollectionCounters>;
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct StatsHeapState {
+        pub db: CollectionsCounters,
+        pub storage: CollectionsCounters,
+    }
+
+    /// The counters of a collection, maintained each time a document or an asset is inserted, updated or deleted.
+    ///
+    /// - `created_at` and `updated_at`: The number of items per timestamp, so that the bounds of the timestamps
+    ///   remain exact when items are deleted.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct CollectionCounters {
+        pub items_count: u64,
+        pub items_bytes: u128,
+        pub created_at: TimestampsCounters,
+        pub updated_at: TimestampsCounters,
+        pub owners: HashMap<UserId, OwnerCounters>,
+    }
+
+    pub type TimestampsCounters = BTreeMap<Timestamp, u64>;
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct OwnerCounters {
+        pub items_count: u64,
+        pub items_bytes: u128,
+    }
+}
+
+pub mod runtime {
+    use junobuild_shared::types::state::{Timestamp, UserId};
+
+    /// The part of a document or an asset that counts toward the statistics.
+    pub struct StatsItem {
+        pub owner: UserId,
+        pub bytes: u128,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+    }
+}
+
+pub mod interface {
+    use crate::stats::types::state::OwnerCounters;
+    use candid::CandidType;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use serde::Deserialize;
+
+    /// The statistics of the documents and the assets of a collection - `None` if there is no such collection or
+    /// if it contains no items.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct CollectionStats {
+        pub docs: Option<ItemsStats>,
+        pub assets: Option<ItemsStats>,
+    }
+
+    /// - `top_owners`: The owners contributing the most items, in descending order.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct ItemsStats {
+        pub items_count: u64,
+        pub items_bytes: u128,
+        pub min_created_at: Option<Timestamp>,
+        pub max_updated_at: Option<Timestamp>,
+        pub top_owners: Vec<(UserId, OwnerCounters)>,
+    }
+}
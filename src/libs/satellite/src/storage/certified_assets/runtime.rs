@@ -1,4 +1,8 @@
+use crate::db::http::certify_http_docs;
+use crate::health::http::certify_health;
+use crate::manifest::http::certify_manifest_response;
 use crate::memory::STATE;
+use crate::storage::schedule::schedule_certified_asset;
 use crate::storage::strategy_impls::StorageState;
 use crate::types::state::State;
 use junobuild_storage::certification::types::certified::CertifiedAssetHashes;
@@ -13,13 +17,27 @@ fn init_certified_assets_impl(state: &State) {
 
     let config = &state.heap.storage.config;
 
+    // The previous assets of a canary deployment are inserted first, so that the tree v1 - which holds a single hash per path -
+    // certifies the new assets. Both versions are certified in the tree v2.
+    if let Some(canary) = &state.heap.canary {
+        for asset in canary.previous_assets.values().flatten() {
+            asset_hashes.insert(asset, config);
+        }
+    }
+
     for (_key, asset) in state.heap.storage.assets.iter() {
         asset_hashes.insert(asset, config);
+        schedule_certified_asset(asset);
     }
 
     for (_key, asset) in state.stable.assets.iter() {
         asset_hashes.insert(&asset, config);
+        schedule_certified_asset(&asset);
     }
 
+    certify_health(&mut asset_hashes);
+    certify_http_docs(&mut asset_hashes);
+    certify_manifest_response(&mut asset_hashes);
+
     extend_and_init_certified_assets(&mut asset_hashes, config, &StorageState)
 }
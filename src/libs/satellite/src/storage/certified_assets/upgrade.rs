@@ -1,14 +1,20 @@
+use crate::health::store::set_certification_completed;
 use crate::storage::certified_assets::runtime::init_certified_assets as init_runtime_certified_assets;
+use crate::storage::warm::warm_hot_assets;
 use ic_cdk::spawn;
 use ic_cdk_timers::set_timer;
 use std::time::Duration;
 
 /// If required, building the certification asset hashes is something we can also initiate "manually."
 /// This is why we can avoid blocking the post_upgrade process. That way, the execution instructions are specifically scoped to the operation.
+/// The same background task also warms the hot assets, so that the first visitors after a deploy do not hit the stable memory.
 pub fn defer_init_certified_assets() {
     set_timer(Duration::ZERO, || spawn(init_certified_assets()));
 }
 
 async fn init_certified_assets() {
     init_runtime_certified_assets();
+    warm_hot_assets();
+
+    set_certification_completed();
 }
@@ -1,4 +1,5 @@
 use crate::controllers::store::get_controllers;
+use crate::storage::schedule::schedule_certified_asset;
 use crate::storage::state::{get_asset, get_config, get_rule, insert_asset, insert_asset_encoding};
 use ic_cdk::id;
 use junobuild_collections::assert_stores::assert_permission;
@@ -84,5 +85,7 @@ fn set_asset_handler_impl(
 
     update_runtime_certified_asset(&asset, &config);
 
+    schedule_certified_asset(&asset);
+
     Ok(())
 }
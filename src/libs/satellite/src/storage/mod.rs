@@ -1,7 +1,9 @@
 pub mod certified_assets;
 pub mod handlers;
 mod impls;
-mod state;
+mod schedule;
+pub mod state;
 pub mod store;
 pub mod strategy_impls;
 pub mod types;
+mod warm;
@@ -0,0 +1,69 @@
+use crate::storage::state::{
+    get_config, get_public_asset, get_schedule_timer, insert_scheduled_asset,
+    next_scheduled_asset_timestamp, replace_schedule_timer, take_due_scheduled_assets,
+};
+use ic_cdk::api::time;
+use ic_cdk_timers::{clear_timer, set_timer};
+use junobuild_storage::runtime::update_certified_asset as update_runtime_certified_asset;
+use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::store::Asset;
+use std::time::Duration;
+
+/// Assets with a publication schedule in the future are certified - respectively removed from the certification - at their publish_at and unpublish_at timestamps.
+/// The schedules are collected in the runtime state and a single timer is scheduled at once, for the earliest one. When it fires, the assets due are re-evaluated as they are stored and the timer is scheduled for the next schedule.
+/// That way, a schedule replaced by a new upload or an asset deleted in the meantime is handled as well.
+/// Timers do not survive upgrades. This is why the schedules are also collected again when the certification is initialized.
+pub fn schedule_certified_asset(asset: &Asset) {
+    let now = time();
+
+    for timestamp in [asset.key.publish_at, asset.key.unpublish_at]
+        .into_iter()
+        .flatten()
+        .filter(|timestamp| *timestamp > now)
+    {
+        insert_scheduled_asset(timestamp, &asset.key.full_path);
+    }
+
+    schedule_next_certified_assets();
+}
+
+fn schedule_next_certified_assets() {
+    let next_timestamp = next_scheduled_asset_timestamp();
+
+    // The current timer already fires for the earliest schedule
+    if get_schedule_timer().map(|(_, timestamp)| timestamp) == next_timestamp {
+        return;
+    }
+
+    let timer = next_timestamp.map(|timestamp| {
+        let timer_id = set_timer(
+            Duration::from_nanos(timestamp.saturating_sub(time())),
+            refresh_scheduled_certified_assets,
+        );
+
+        (timer_id, timestamp)
+    });
+
+    if let Some((previous_timer_id, _)) = replace_schedule_timer(timer) {
+        clear_timer(previous_timer_id);
+    }
+}
+
+fn refresh_scheduled_certified_assets() {
+    // The timer has fired
+    replace_schedule_timer(None);
+
+    for full_path in take_due_scheduled_assets(time()) {
+        refresh_certified_asset(&full_path);
+    }
+
+    schedule_next_certified_assets();
+}
+
+fn refresh_certified_asset(full_path: &FullPath) {
+    let (asset, _) = get_public_asset(full_path);
+
+    if let Some(asset) = asset {
+        update_runtime_certified_asset(&asset, &get_config());
+    }
+}
@@ -1,20 +1,34 @@
+use crate::changes::state::increment_storage_version;
+use crate::compression::utils::{compress_chunks, decompress_data};
+use crate::defrag::state::usage_mut;
+use crate::defrag::stats::{encodings_length, entry_length};
+use crate::defrag::types::state::DefragHeapState;
+use crate::deployment::store::capture_previous_asset;
+use crate::gc::store::defer_delete_content_chunks;
 use crate::memory::STATE;
+use crate::retention::state::update_storage_retention_index;
+use crate::stats::store::update_asset_stats;
 use crate::storage::types::state::{
-    AssetsStable, ContentChunksStable, StableEncodingChunkKey, StableKey,
+    AssetsStable, ContentChunksStable, HotAssets, ScheduledAssets, StableEncodingChunkKey,
+    StableKey,
 };
-use crate::types::state::StableState;
+use ic_cdk_timers::TimerId;
 use junobuild_collections::msg::msg_storage_collection_not_found;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::{Memory, Rule};
 use junobuild_collections::utils::range_collection_end;
-use junobuild_shared::serializers::deserialize_from_bytes;
+use junobuild_shared::serializers::{deserialize_from_bytes, serialize_to_bytes};
 use junobuild_shared::types::core::{Blob, DomainName};
 use junobuild_shared::types::domain::{CustomDomain, CustomDomains};
+use junobuild_shared::types::state::Timestamp;
 use junobuild_storage::stable_utils::insert_asset_encoding_stable;
 use junobuild_storage::types::config::StorageConfig;
 use junobuild_storage::types::state::{AssetsHeap, FullPath, StorageHeapState};
 use junobuild_storage::types::store::{Asset, AssetEncoding};
+use junobuild_storage::utils::should_include_asset_for_deletion;
 use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::ops::Bound::{Excluded, Included};
 use std::ops::RangeBounds;
 
 // ---------------------------------------------------------
@@ -29,7 +43,17 @@ pub fn get_public_asset(full_path: &FullPath) -> (Option<Asset>, Memory) {
     match heap_asset {
         Some(heap_asset) => (Some(heap_asset), Memory::Heap),
         None => {
-            STATE.with(|state| get_public_asset_stable(full_path, &state.borrow().stable.assets))
+            // Hot assets are stable assets which content chunks have been loaded on the heap.
+            let hot_asset = STATE.with(|state| {
+                get_asset_heap(full_path, &state.borrow().runtime.storage.hot_assets)
+            });
+
+            match hot_asset {
+                Some(hot_asset) => (Some(hot_asset), Memory::Heap),
+                None => STATE.with(|state| {
+                    get_public_asset_stable(full_path, &state.borrow().stable.assets)
+                }),
+            }
         }
     }
 }
@@ -91,6 +115,8 @@ pub fn insert_asset_encoding(
     asset: &mut Asset,
     rule: &Rule,
 ) {
+    capture_previous_asset(&asset.key.collection, full_path, rule);
+
     match rule.mem() {
         Memory::Heap => {
             asset
@@ -98,10 +124,27 @@ pub fn insert_asset_encoding(
                 .insert(encoding_type.to_owned(), encoding.clone());
         }
         Memory::Stable => STATE.with(|state| {
+            delete_hot_asset(
+                full_path,
+                &mut state.borrow_mut().runtime.storage.hot_assets,
+            );
+
+            let (content_chunks, compression) = compress_chunks(
+                &encoding.content_chunks,
+                encoding.total_length,
+                &rule.compression,
+            );
+
+            let encoding = AssetEncoding {
+                content_chunks,
+                compression,
+                ..encoding.clone()
+            };
+
             insert_asset_encoding_stable(
                 full_path,
                 encoding_type,
-                encoding,
+                &encoding,
                 asset,
                 stable_encoding_chunk_key,
                 &mut state.borrow_mut().stable.content_chunks,
@@ -111,7 +154,9 @@ pub fn insert_asset_encoding(
 }
 
 pub fn insert_asset(collection: &CollectionKey, full_path: &FullPath, asset: &Asset, rule: &Rule) {
-    match rule.mem() {
+    capture_previous_asset(collection, full_path, rule);
+
+    let current_asset = match rule.mem() {
         Memory::Heap => STATE.with(|state| {
             insert_asset_heap(
                 full_path,
@@ -120,14 +165,24 @@ pub fn insert_asset(collection: &CollectionKey, full_path: &FullPath, asset: &As
             )
         }),
         Memory::Stable => STATE.with(|state| {
+            let state = &mut *state.borrow_mut();
+
+            delete_hot_asset(full_path, &mut state.runtime.storage.hot_assets);
+
             insert_asset_stable(
                 collection,
                 full_path,
                 asset,
-                &mut state.borrow_mut().stable.assets,
+                &mut state.stable.assets,
+                usage_mut(&mut state.heap.defrag),
             )
         }),
-    }
+    };
+
+    update_asset_stats(collection, current_asset.as_ref(), Some(asset));
+    update_asset_retention_index(collection, full_path, current_asset.as_ref(), Some(asset));
+
+    increment_storage_version(collection);
 }
 
 pub fn delete_asset(
@@ -135,15 +190,199 @@ pub fn delete_asset(
     full_path: &FullPath,
     rule: &Rule,
 ) -> Option<Asset> {
-    match rule.mem() {
+    capture_previous_asset(collection, full_path, rule);
+
+    let deleted_asset = match rule.mem() {
         Memory::Heap => STATE.with(|state| {
             delete_asset_heap(full_path, &mut state.borrow_mut().heap.storage.assets)
         }),
-        Memory::Stable => STATE.with(|state| {
-            delete_content_chunks_stable(collection, full_path, &mut state.borrow_mut().stable);
-            delete_asset_stable(collection, full_path, &mut state.borrow_mut().stable.assets)
-        }),
+        Memory::Stable => {
+            let deleted_asset = STATE.with(|state| {
+                let state = &mut *state.borrow_mut();
+
+                delete_hot_asset(full_path, &mut state.runtime.storage.hot_assets);
+                delete_asset_stable(
+                    collection,
+                    full_path,
+                    &mut state.stable.assets,
+                    usage_mut(&mut state.heap.defrag),
+                )
+            });
+
+            // The asset is tombstoned right away, its content chunks are removed in the background.
+            if let Some(deleted_asset) = &deleted_asset {
+                defer_delete_content_chunks(deleted_asset);
+            }
+
+            deleted_asset
+        }
+    };
+
+    update_asset_stats(collection, deleted_asset.as_ref(), None);
+    update_asset_retention_index(collection, full_path, deleted_asset.as_ref(), None);
+
+    if deleted_asset.is_some() {
+        increment_storage_version(collection);
     }
+
+    deleted_asset
+}
+
+// The assets that are never purged - e.g. the well-known files - are not indexed.
+fn update_asset_retention_index(
+    collection: &CollectionKey,
+    full_path: &FullPath,
+    before: Option<&Asset>,
+    after: Option<&Asset>,
+) {
+    if !should_include_asset_for_deletion(collection, full_path) {
+        return;
+    }
+
+    update_storage_retention_index(
+        collection,
+        full_path,
+        before.map(|asset| asset.updated_at),
+        after.map(|asset| asset.updated_at),
+    );
+}
+
+/// Copies the content chunks of a stable asset under the keys derived with `snapshot_key`, so that the copy remains untouched
+/// when the asset is overwritten. Returns the asset referencing the copied chunks.
+pub fn snapshot_content_chunks_stable(
+    asset: &Asset,
+    snapshot_key: impl Fn(&StableEncodingChunkKey) -> StableEncodingChunkKey,
+) -> Asset {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let content_chunks = &mut state.stable.content_chunks;
+
+        let mut snapshot = asset.clone();
+
+        for (encoding_type, encoding) in asset.encodings.iter() {
+            let chunks: Vec<Blob> = encoding
+                .content_chunks
+                .iter()
+                .map(|chunk| {
+                    let key: StableEncodingChunkKey = deserialize_from_bytes(Cow::Borrowed(chunk));
+                    let copy_key = snapshot_key(&key);
+
+                    if let Some(content) = content_chunks.get(&key) {
+                        content_chunks.insert(copy_key.clone(), content);
+                    }
+
+                    serialize_to_bytes(&copy_key).into_owned()
+                })
+                .collect();
+
+            snapshot.encodings.insert(
+                encoding_type.clone(),
+                AssetEncoding {
+                    content_chunks: chunks,
+                    ..encoding.clone()
+                },
+            );
+        }
+
+        snapshot
+    })
+}
+
+/// Loads the content chunks of a public stable asset on the heap, so that it can be served without reading the stable memory.
+/// Returns `false` if the asset does not exist, is not stable or is larger than the maximal size.
+pub fn insert_hot_asset(full_path: &FullPath, max_size: u128) -> bool {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+
+        let (asset, _) = get_public_asset_stable(full_path, &state.stable.assets);
+
+        let hot_asset = asset.and_then(|asset| {
+            inline_content_chunks_stable(asset, max_size, &state.stable.content_chunks)
+        });
+
+        match hot_asset {
+            None => false,
+            Some(hot_asset) => {
+                state
+                    .runtime
+                    .storage
+                    .hot_assets
+                    .insert(full_path.clone(), hot_asset);
+                true
+            }
+        }
+    })
+}
+
+pub fn clear_hot_assets() {
+    STATE.with(|state| state.borrow_mut().runtime.storage.hot_assets.clear());
+}
+
+// ---------------------------------------------------------
+// Schedules
+// ---------------------------------------------------------
+
+pub fn insert_scheduled_asset(timestamp: Timestamp, full_path: &FullPath) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .runtime
+            .storage
+            .scheduled_assets
+            .insert((timestamp, full_path.clone()))
+    });
+}
+
+pub fn next_scheduled_asset_timestamp() -> Option<Timestamp> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .runtime
+            .storage
+            .scheduled_assets
+            .first()
+            .map(|(timestamp, _)| *timestamp)
+    })
+}
+
+/// Removes the schedules due at the given time and returns the paths of their assets.
+pub fn take_due_scheduled_assets(now: Timestamp) -> BTreeSet<FullPath> {
+    STATE.with(|state| {
+        take_due_scheduled_assets_impl(
+            now,
+            &mut state.borrow_mut().runtime.storage.scheduled_assets,
+        )
+    })
+}
+
+fn take_due_scheduled_assets_impl(
+    now: Timestamp,
+    scheduled_assets: &mut ScheduledAssets,
+) -> BTreeSet<FullPath> {
+    let mut full_paths = BTreeSet::new();
+
+    while let Some((timestamp, _)) = scheduled_assets.first() {
+        if *timestamp > now {
+            break;
+        }
+
+        if let Some((_, full_path)) = scheduled_assets.pop_first() {
+            full_paths.insert(full_path);
+        }
+    }
+
+    full_paths
+}
+
+pub fn get_schedule_timer() -> Option<(TimerId, Timestamp)> {
+    STATE.with(|state| state.borrow().runtime.storage.schedule_timer)
+}
+
+pub fn replace_schedule_timer(timer: Option<(TimerId, Timestamp)>) -> Option<(TimerId, Timestamp)> {
+    STATE.with(|state| {
+        let storage = &mut state.borrow_mut().runtime.storage;
+        std::mem::replace(&mut storage.schedule_timer, timer)
+    })
 }
 
 // Get
@@ -163,7 +402,45 @@ fn get_content_chunks_stable(
 ) -> Option<Blob> {
     let key: StableEncodingChunkKey =
         deserialize_from_bytes(Cow::Owned(encoding.content_chunks[chunk_index].clone()));
-    content_chunks.get(&key)
+    // A corrupted chunk is not served compressed but, as missing.
+    content_chunks
+        .get(&key)
+        .and_then(|chunk| decompress_data(chunk, &encoding.compression).ok())
+}
+
+fn inline_content_chunks_stable(
+    asset: Asset,
+    max_size: u128,
+    content_chunks: &ContentChunksStable,
+) -> Option<Asset> {
+    let total_length: u128 = asset
+        .encodings
+        .values()
+        .map(|encoding| encoding.total_length)
+        .sum();
+
+    if total_length > max_size {
+        return None;
+    }
+
+    let mut hot_asset = asset.clone();
+
+    for (encoding_type, encoding) in asset.encodings.iter() {
+        let chunks: Option<Vec<Blob>> = (0..encoding.content_chunks.len())
+            .map(|chunk_index| get_content_chunks_stable(encoding, chunk_index, content_chunks))
+            .collect();
+
+        hot_asset.encodings.insert(
+            encoding_type.clone(),
+            AssetEncoding {
+                content_chunks: chunks?,
+                compression: None,
+                ..encoding.clone()
+            },
+        );
+    }
+
+    Some(hot_asset)
 }
 
 fn get_asset_heap(full_path: &FullPath, assets: &AssetsHeap) -> Option<Asset> {
@@ -177,29 +454,32 @@ fn delete_asset_stable(
     collection: &CollectionKey,
     full_path: &FullPath,
     assets: &mut AssetsStable,
+    usage: &mut DefragHeapState,
 ) -> Option<Asset> {
-    assets.remove(&stable_full_path(collection, full_path))
-}
+    let stable_key = stable_full_path(collection, full_path);
 
-fn delete_content_chunks_stable(
-    collection: &CollectionKey,
-    full_path: &FullPath,
-    state: &mut StableState,
-) {
-    if let Some(asset) = get_asset_stable(collection, full_path, &state.assets) {
-        for (_, encoding) in asset.encodings.iter() {
-            for chunk in encoding.content_chunks.iter() {
-                let key: StableEncodingChunkKey = deserialize_from_bytes(Cow::Owned(chunk.clone()));
-                state.content_chunks.remove(&key);
-            }
-        }
+    let deleted_asset = assets.remove(&stable_key);
+
+    if let Some(deleted_asset) = &deleted_asset {
+        usage
+            .assets
+            .update(Some(entry_length(&stable_key, deleted_asset)), None);
+        usage.content_chunks = usage
+            .content_chunks
+            .saturating_sub(encodings_length(deleted_asset));
     }
+
+    deleted_asset
 }
 
 fn delete_asset_heap(full_path: &FullPath, assets: &mut AssetsHeap) -> Option<Asset> {
     assets.remove(full_path)
 }
 
+fn delete_hot_asset(full_path: &FullPath, hot_assets: &mut HotAssets) {
+    hot_assets.remove(full_path);
+}
+
 // Insert
 
 fn insert_asset_stable(
@@ -207,12 +487,33 @@ fn insert_asset_stable(
     full_path: &FullPath,
     asset: &Asset,
     assets: &mut AssetsStable,
-) {
-    assets.insert(stable_full_path(collection, full_path), asset.clone());
+    usage: &mut DefragHeapState,
+) -> Option<Asset> {
+    let stable_key = stable_full_path(collection, full_path);
+
+    let previous = assets.insert(stable_key.clone(), asset.clone());
+
+    usage.assets.update(
+        previous
+            .as_ref()
+            .map(|previous| entry_length(&stable_key, previous)),
+        Some(entry_length(&stable_key, asset)),
+    );
+
+    usage.content_chunks = usage
+        .content_chunks
+        .saturating_sub(previous.as_ref().map_or(0, encodings_length))
+        + encodings_length(asset);
+
+    previous
 }
 
-fn insert_asset_heap(full_path: &FullPath, asset: &Asset, assets: &mut AssetsHeap) {
-    assets.insert(full_path.clone(), asset.clone());
+fn insert_asset_heap(
+    full_path: &FullPath,
+    asset: &Asset,
+    assets: &mut AssetsHeap,
+) -> Option<Asset> {
+    assets.insert(full_path.clone(), asset.clone())
 }
 
 // List
@@ -221,7 +522,44 @@ pub fn get_assets_stable(
     collection: &CollectionKey,
     assets: &AssetsStable,
 ) -> Vec<(StableKey, Asset)> {
-    assets.range(filter_assets_range(collection)).collect()
+    iter_assets_stable(collection, assets).collect()
+}
+
+pub fn iter_assets_stable<'a>(
+    collection: &CollectionKey,
+    assets: &'a AssetsStable,
+) -> impl Iterator<Item = (StableKey, Asset)> + 'a {
+    assets.range(filter_assets_range(collection))
+}
+
+/// The assets of a collection sorted by full path, starting after the given path.
+pub fn iter_assets_stable_after<'a>(
+    collection: &CollectionKey,
+    start_after: &Option<FullPath>,
+    assets: &'a AssetsStable,
+) -> impl Iterator<Item = (StableKey, Asset)> + 'a {
+    let start_key = stable_full_path(collection, &start_after.clone().unwrap_or_default());
+
+    let start = match start_after {
+        None => Included(start_key),
+        Some(_) => Excluded(start_key),
+    };
+
+    let end_key = stable_full_path(&range_collection_end(collection), &"".to_string());
+
+    assets.range((start, Excluded(end_key)))
+}
+
+/// The assets of a collection sorted by full path, starting at the given path - i.e. including it.
+pub fn iter_assets_stable_from<'a>(
+    collection: &CollectionKey,
+    start_at: &FullPath,
+    assets: &'a AssetsStable,
+) -> impl Iterator<Item = (StableKey, Asset)> + 'a {
+    let start_key = stable_full_path(collection, start_at);
+    let end_key = stable_full_path(&range_collection_end(collection), &"".to_string());
+
+    assets.range((Included(start_key), Excluded(end_key)))
 }
 
 pub fn count_assets_stable(collection: &CollectionKey, assets: &AssetsStable) -> usize {
@@ -1,45 +1,60 @@
+use crate::changes::state::get_storage_version;
 use crate::controllers::store::get_controllers;
+use crate::deployment::store::recertify_previous_asset;
 use crate::hooks::invoke_assert_delete_asset;
 use crate::memory::STATE;
+use crate::moderation::store::moderate_committed_asset;
+use crate::retention::state::collect_storage_expired_keys;
 use candid::Principal;
+use ic_cdk::api::time;
 use junobuild_collections::assert_stores::{assert_permission, public_permission};
 use junobuild_collections::msg::msg_storage_collection_not_empty;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::{Memory, Rule};
 use junobuild_shared::controllers::is_controller;
-use junobuild_shared::list::list_values;
-use junobuild_shared::types::state::Controllers;
+use junobuild_shared::errors::JunoError;
+use junobuild_shared::list::{
+    filter_published, limit_returned_bytes, limit_scanned_items, list_values_with_collation,
+    with_scanned_items,
+};
+use junobuild_shared::types::state::{Controllers, Timestamp, UserId};
+use junobuild_shared::utils::{is_published, principal_equal};
 
 use crate::rules::assert_stores::is_known_user;
 use crate::storage::certified_assets::runtime::init_certified_assets as init_runtime_certified_assets;
+use crate::storage::schedule::schedule_certified_asset;
 use crate::storage::state::{
     count_assets_stable, delete_asset as delete_state_asset, delete_domain as delete_state_domain,
     get_asset as get_state_asset, get_assets_stable, get_config as get_state_config, get_config,
     get_content_chunks as get_state_content_chunks, get_domain as get_state_domain,
     get_domains as get_state_domains, get_public_asset as get_state_public_asset,
     get_rule as get_state_rule, insert_config as insert_state_config,
-    insert_domain as insert_state_domain,
+    insert_domain as insert_state_domain, iter_assets_stable_after,
 };
 use crate::storage::strategy_impls::{StorageAssertions, StorageState, StorageUpload};
+use crate::storage::warm::warm_hot_assets;
 use crate::types::store::StoreContext;
 use junobuild_shared::types::core::{Blob, DomainName};
 use junobuild_shared::types::domain::CustomDomains;
-use junobuild_shared::types::list::{ListParams, ListResults};
+use junobuild_shared::types::list::{ListLimits, ListParams, ListResults};
 use junobuild_storage::constants::{ROOT_404_HTML, ROOT_INDEX_HTML};
 use junobuild_storage::heap_utils::{
     collect_assets_heap, collect_delete_assets_heap, count_assets_heap,
 };
-use junobuild_storage::msg::{ERROR_ASSET_NOT_FOUND, UPLOAD_NOT_ALLOWED};
+use junobuild_storage::msg::{ERROR_ASSET_NOT_FOUND, ERROR_UPLOAD_NOT_ALLOWED, UPLOAD_NOT_ALLOWED};
 use junobuild_storage::runtime::{
     delete_certified_asset as delete_runtime_certified_asset,
     increment_and_assert_rate as increment_and_assert_rate_runtime,
+    list_certified_paths as list_runtime_certified_paths,
     update_certified_asset as update_runtime_certified_asset,
 };
 use junobuild_storage::store::{commit_batch as commit_batch_storage, create_batch, create_chunk};
 use junobuild_storage::types::config::StorageConfig;
-use junobuild_storage::types::interface::{AssetNoContent, CommitBatch, InitAssetKey, UploadChunk};
+use junobuild_storage::types::interface::{
+    AssetNoContent, CertifiedPath, CommitBatch, InitAssetKey, UploadChunk,
+};
 use junobuild_storage::types::runtime_state::{BatchId, ChunkId};
-use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::state::{AssetsHeap, FullPath};
 use junobuild_storage::types::store::{Asset, AssetEncoding};
 use junobuild_storage::utils::{
     filter_collection_values, filter_values, get_token_protected_asset, map_asset_no_content,
@@ -47,6 +62,7 @@ use junobuild_storage::utils::{
 };
 use junobuild_storage::well_known::update::update_custom_domains_asset;
 use junobuild_storage::well_known::utils::build_custom_domain;
+use junobuild_storage::wildcard_domains::assert_wildcard_domains;
 
 // ---------------------------------------------------------
 // Getter, list and delete
@@ -113,7 +129,20 @@ pub fn delete_asset_store(
 pub fn delete_assets_store(collection: &CollectionKey) -> Result<(), String> {
     let rule = get_state_rule(collection)?;
 
-    let full_paths = match rule.mem() {
+    let full_paths = collect_delete_assets(collection, &rule);
+
+    delete_assets_impl(&full_paths, collection, &rule)
+}
+
+/// Collects the full paths of the assets of a collection that would be removed by `delete_assets_store`.
+pub fn collect_delete_assets_store(collection: &CollectionKey) -> Result<Vec<FullPath>, String> {
+    let rule = get_state_rule(collection)?;
+
+    Ok(collect_delete_assets(collection, &rule))
+}
+
+fn collect_delete_assets(collection: &CollectionKey, rule: &Rule) -> Vec<FullPath> {
+    match rule.mem() {
         Memory::Heap => STATE.with(|state| {
             let state_ref = state.borrow();
             collect_delete_assets_heap(collection, &state_ref.heap.storage.assets)
@@ -128,9 +157,90 @@ pub fn delete_assets_store(collection: &CollectionKey) -> Result<(), String> {
                 .map(|(_, asset)| asset.key.full_path.clone())
                 .collect()
         }),
+    }
+}
+
+/// Collects the full paths of the assets of a collection that exceed the retention policy of its rule, the oldest
+/// first.
+pub fn collect_expired_assets_store(
+    collection: &CollectionKey,
+    now: Timestamp,
+) -> Result<Vec<FullPath>, String> {
+    let rule = get_state_rule(collection)?;
+
+    Ok(collect_expired_assets(collection, &rule, now, usize::MAX))
+}
+
+/// The assets are read from the retention index of the collection, therefore only the expired ones are iterated.
+fn collect_expired_assets(
+    collection: &CollectionKey,
+    rule: &Rule,
+    now: Timestamp,
+    limit: usize,
+) -> Vec<FullPath> {
+    let Some(retention) = &rule.retention else {
+        return Vec::new();
     };
 
-    delete_assets_impl(&full_paths, collection, &rule)
+    collect_storage_expired_keys(collection, retention, now, limit)
+}
+
+/// Deletes - at most `limit` - the assets of a collection that exceed the retention policy of its rule, without
+/// assertions given that the purge is performed by the satellite itself.
+pub fn delete_expired_assets_store(
+    collection: &CollectionKey,
+    now: Timestamp,
+    limit: usize,
+) -> Result<Vec<Option<Asset>>, String> {
+    let rule = get_state_rule(collection)?;
+
+    let full_paths = collect_expired_assets(collection, &rule, now, limit);
+
+    let results = full_paths
+        .iter()
+        .map(|full_path| {
+            let deleted_asset = delete_state_asset(collection, full_path, &rule);
+
+            if let Some(deleted_asset) = &deleted_asset {
+                delete_runtime_certified_asset(deleted_asset, &get_config());
+                recertify_previous_asset(full_path, &get_config());
+            }
+
+            deleted_asset
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Deletes the assets of a collection owned by `owner` among `full_paths`, without assertions given that the deletion
+/// is performed by the satellite itself - e.g. the attachments of a deleted document. The other paths are skipped.
+pub fn delete_owned_assets_store(
+    collection: &CollectionKey,
+    full_paths: &[FullPath],
+    owner: &UserId,
+) -> Result<Vec<Asset>, String> {
+    let rule = get_state_rule(collection)?;
+
+    let deleted_assets = full_paths
+        .iter()
+        .filter(|full_path| {
+            get_state_asset(collection, full_path, &rule)
+                .is_some_and(|asset| principal_equal(asset.key.owner, *owner))
+        })
+        .filter_map(|full_path| {
+            let deleted_asset = delete_state_asset(collection, full_path, &rule);
+
+            if let Some(deleted_asset) = &deleted_asset {
+                delete_runtime_certified_asset(deleted_asset, &get_config());
+                recertify_previous_asset(full_path, &get_config());
+            }
+
+            deleted_asset
+        })
+        .collect();
+
+    Ok(deleted_assets)
 }
 
 /// List assets in a collection.
@@ -164,7 +274,10 @@ pub fn list_assets_store(
         collection,
     };
 
-    secure_list_assets_impl(&context, filters)
+    secure_list_assets_impl(&context, filters).map(|results| ListResults {
+        version: get_storage_version(collection),
+        ..results
+    })
 }
 
 /// Count assets in a collection.
@@ -195,6 +308,41 @@ pub fn count_assets_store(
     Ok(results.items_length)
 }
 
+/// Compare a manifest of assets with those stored in a collection.
+///
+/// This function returns the paths of the manifest that do not match an asset of the collection - either because no
+/// asset exists at this path or because none of its encodings has the provided SHA-256. Deploy tooling can therefore
+/// upload only the files that changed, and skip the deployment entirely if the list is empty.
+///
+/// # Parameters
+/// - `collection`: A reference to the `CollectionKey` representing the collection to compare with.
+/// - `assets`: The `FullPath` of the assets with the SHA-256 of their content.
+///
+/// # Returns
+/// - `Ok(Vec<FullPath>)`: The paths of the assets that differ from what is stored.
+/// - `Err(String)`: An error message if the collection does not exist.
+pub fn diff_assets_store(
+    collection: &CollectionKey,
+    assets: &[(FullPath, Blob)],
+) -> Result<Vec<FullPath>, String> {
+    let rule = get_state_rule(collection)?;
+
+    let changed = assets
+        .iter()
+        .filter(|(full_path, sha256)| {
+            get_state_asset(collection, full_path, &rule).map_or(true, |asset| {
+                !asset
+                    .encodings
+                    .values()
+                    .any(|encoding| encoding.sha256.as_slice() == sha256.as_slice())
+            })
+        })
+        .map(|(full_path, _)| full_path.clone())
+        .collect();
+
+    Ok(changed)
+}
+
 /// Get an asset from a collection's store.
 ///
 /// This function retrieves an asset from a collection's store based on the specified parameters.
@@ -257,6 +405,16 @@ fn get_asset_impl(
                 return Ok(None);
             }
 
+            if !filter_published(
+                &asset,
+                asset.key.owner,
+                context.caller,
+                context.controllers,
+                time(),
+            ) {
+                return Ok(None);
+            }
+
             Ok(Some(asset))
         }
     }
@@ -268,7 +426,16 @@ pub fn get_public_asset_store(
 ) -> Option<(Asset, Memory)> {
     let (asset, memory) = get_state_public_asset(&full_path);
 
-    match asset {
+    filter_public_asset(asset, memory, token)
+}
+
+/// Assets not yet - or no longer - published are not served, those protected with a token only if the matching token is provided.
+pub fn filter_public_asset(
+    asset: Option<Asset>,
+    memory: Memory,
+    token: Option<String>,
+) -> Option<(Asset, Memory)> {
+    match asset.filter(|asset| is_published(asset, time())) {
         None => None,
         Some(asset) => match &asset.key.token {
             None => Some((asset.clone(), memory)),
@@ -319,32 +486,98 @@ fn secure_list_assets_impl(
 ) -> Result<ListResults<AssetNoContent>, String> {
     let rule = get_state_rule(context.collection)?;
 
+    let limits = get_config().list_limits;
+
+    let collation = rule.collation.clone().unwrap_or_default();
+
     match rule.mem() {
         Memory::Heap => STATE.with(|state| {
             let state_ref = state.borrow();
-            let assets = collect_assets_heap(context.collection, &state_ref.heap.storage.assets);
-            Ok(list_assets_impl(&assets, context, &rule, filters))
+            let scanned = limit_scanned_items(
+                |start_after| {
+                    Ok(iter_assets_heap_after(
+                        context.collection,
+                        start_after,
+                        &state_ref.heap.storage.assets,
+                    ))
+                },
+                |(full_path, _)| (*full_path).clone(),
+                filters,
+                &collation,
+                &limits,
+            )?;
+            Ok(list_assets_impl(
+                &scanned.items,
+                &scanned.filters,
+                scanned.resume_after.clone(),
+                context,
+                &rule,
+                &limits,
+            ))
         }),
         Memory::Stable => STATE.with(|state| {
-            let stable = get_assets_stable(context.collection, &state.borrow().stable.assets);
-            let assets: Vec<(&FullPath, &Asset)> = stable
+            let stable = &state.borrow().stable.assets;
+            let scanned = limit_scanned_items(
+                |start_after| {
+                    Ok(iter_assets_stable_after(
+                        context.collection,
+                        start_after,
+                        stable,
+                    ))
+                },
+                |(key, _)| key.full_path.clone(),
+                filters,
+                &collation,
+                &limits,
+            )?;
+            let assets: Vec<(&FullPath, &Asset)> = scanned
+                .items
                 .iter()
                 .map(|(_, asset)| (&asset.key.full_path, asset))
                 .collect();
-            Ok(list_assets_impl(&assets, context, &rule, filters))
+            Ok(list_assets_impl(
+                &assets,
+                &scanned.filters,
+                scanned.resume_after.clone(),
+                context,
+                &rule,
+                &limits,
+            ))
         }),
     }
 }
 
+/// The heap assets are not sorted in memory, those of the collection are therefore sorted by full path to be scanned
+/// in the same order as the stable assets.
+fn iter_assets_heap_after<'a>(
+    collection: &CollectionKey,
+    start_after: &Option<FullPath>,
+    assets: &'a AssetsHeap,
+) -> impl Iterator<Item = (&'a FullPath, &'a Asset)> {
+    let mut assets = collect_assets_heap(collection, assets);
+
+    assets.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let start_after = start_after.clone();
+
+    assets.into_iter().skip_while(move |(full_path, _)| {
+        start_after
+            .as_ref()
+            .map_or(false, |start_after| *full_path <= start_after)
+    })
+}
+
 fn list_assets_impl(
     assets: &[(&FullPath, &Asset)],
+    filters: &ListParams,
+    resume_after: Option<FullPath>,
     &StoreContext {
         caller,
         controllers,
         collection,
     }: &StoreContext,
     rule: &Rule,
-    filters: &ListParams,
+    limits: &Option<ListLimits>,
 ) -> ListResults<AssetNoContent> {
     let matches = filter_values(
         caller,
@@ -355,9 +588,13 @@ fn list_assets_impl(
         assets,
     );
 
-    let values = list_values(&matches, filters);
+    let values = list_values_with_collation(
+        &matches,
+        filters,
+        &rule.collation.clone().unwrap_or_default(),
+    );
 
-    ListResults::<AssetNoContent> {
+    let results = ListResults::<AssetNoContent> {
         items: values
             .items
             .into_iter()
@@ -367,7 +604,12 @@ fn list_assets_impl(
         items_page: values.items_page,
         matches_length: values.matches_length,
         matches_pages: values.matches_pages,
-    }
+        truncated: values.truncated,
+        resume_after: values.resume_after,
+        version: values.version,
+    };
+
+    limit_returned_bytes(with_scanned_items(results, resume_after), limits)
 }
 
 fn secure_delete_asset_impl(
@@ -405,7 +647,8 @@ fn delete_asset_impl(
             invoke_assert_delete_asset(&context.caller, &asset)?;
 
             let deleted = delete_state_asset(context.collection, &full_path, rule);
-            delete_runtime_certified_asset(&asset);
+            delete_runtime_certified_asset(&asset, config);
+            recertify_previous_asset(&full_path, config);
 
             // We just removed the rewrite for /404.html in the certification tree therefore if /index.html exists, we want to reintroduce it as rewrite
             if *full_path == *ROOT_404_HTML {
@@ -432,7 +675,8 @@ fn delete_assets_impl(
         match deleted_asset {
             None => {}
             Some(deleted_asset) => {
-                delete_runtime_certified_asset(&deleted_asset);
+                delete_runtime_certified_asset(&deleted_asset, &get_config());
+                recertify_previous_asset(full_path, &get_config());
             }
         }
     }
@@ -559,10 +803,14 @@ pub fn commit_batch_store(caller: Principal, commit_batch: CommitBatch) -> Resul
         &StorageUpload,
     )?;
 
+    let asset = moderate_committed_asset(caller, &controllers, asset)?;
+
     let config = get_config();
 
     update_runtime_certified_asset(&asset, &config);
 
+    schedule_certified_asset(&asset);
+
     Ok(asset)
 }
 
@@ -578,7 +826,7 @@ fn secure_create_batch_impl(
         || is_known_user(caller)
         || is_controller(caller, controllers))
     {
-        return Err(UPLOAD_NOT_ALLOWED.to_string());
+        return Err(JunoError::new(ERROR_UPLOAD_NOT_ALLOWED, UPLOAD_NOT_ALLOWED).into());
     }
 
     create_batch(caller, controllers, config, init, None, &StorageState)
@@ -588,16 +836,30 @@ fn secure_create_batch_impl(
 // Config
 // ---------------------------------------------------------
 
-pub fn set_config_store(config: &StorageConfig) {
+pub fn set_config_store(config: &StorageConfig) -> Result<(), String> {
+    assert_wildcard_domains(config)?;
+
     insert_state_config(config);
 
     init_runtime_certified_assets();
+    warm_hot_assets();
+
+    Ok(())
 }
 
 pub fn get_config_store() -> StorageConfig {
     get_state_config()
 }
 
+// ---------------------------------------------------------
+// Certification
+// ---------------------------------------------------------
+
+/// A sitemap of the certification tree, to debug why a path is served without certification.
+pub fn list_certified_paths_store() -> Vec<CertifiedPath> {
+    list_runtime_certified_paths()
+}
+
 // ---------------------------------------------------------
 // Domain
 // ---------------------------------------------------------
@@ -1,4 +1,7 @@
+use crate::controllers::store::get_controllers;
 use crate::hooks::invoke_assert_upload_asset;
+use crate::quotas::assert::assert_asset_user_quota;
+use crate::random::random_seed;
 use crate::storage::state::{
     delete_asset, get_asset, get_config, get_domains, get_rule, insert_asset, insert_asset_encoding,
 };
@@ -14,7 +17,7 @@ use junobuild_storage::strategies::{
 use junobuild_storage::types::config::StorageConfig;
 use junobuild_storage::types::state::FullPath;
 use junobuild_storage::types::store::{
-    Asset, AssetAssertUpload, AssetEncoding, Batch, EncodingType, ReferenceId,
+    Asset, AssetAssertUpload, AssetEncoding, AssetKey, Batch, EncodingType, ReferenceId,
 };
 
 pub struct StorageAssertions;
@@ -27,6 +30,27 @@ impl StorageAssertionsStrategy for StorageAssertions {
     ) -> Result<(), String> {
         invoke_assert_upload_asset(caller, asset)
     }
+
+    fn assert_user_quota(
+        &self,
+        caller: &Principal,
+        key: &AssetKey,
+        rule: &Rule,
+        current: &Option<Asset>,
+        bytes: u128,
+    ) -> Result<(), String> {
+        let controllers = get_controllers();
+
+        assert_asset_user_quota(
+            *caller,
+            &controllers,
+            &key.owner,
+            &key.collection,
+            rule,
+            current,
+            bytes,
+        )
+    }
 }
 
 pub struct StorageState;
@@ -61,6 +85,10 @@ impl StorageStateStrategy for StorageState {
         get_domains()
     }
 
+    fn get_random_seed(&self) -> Option<[u8; 32]> {
+        random_seed()
+    }
+
     fn get_asset(
         &self,
         collection: &CollectionKey,
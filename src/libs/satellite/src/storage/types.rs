@@ -1,16 +1,25 @@
 pub mod state {
     use candid::CandidType;
+    use ic_cdk_timers::TimerId;
     use ic_stable_structures::StableBTreeMap;
     use junobuild_collections::types::core::CollectionKey;
     use junobuild_shared::types::core::Blob;
     use junobuild_shared::types::memory::Memory;
+    use junobuild_shared::types::state::Timestamp;
     use junobuild_storage::types::state::FullPath;
     use junobuild_storage::types::store::{Asset, EncodingType};
     use serde::{Deserialize, Serialize};
+    use std::collections::{BTreeSet, HashMap};
 
     pub type AssetsStable = StableBTreeMap<StableKey, Asset, Memory>;
     pub type ContentChunksStable = StableBTreeMap<StableEncodingChunkKey, Blob, Memory>;
 
+    // Assets of the stable memory which content chunks are kept on the heap - e.g. the destinations of the rewrites
+    pub type HotAssets = HashMap<FullPath, Asset>;
+
+    // The publish_at and unpublish_at timestamps in the future of the assets, ordered by time
+    pub type ScheduledAssets = BTreeSet<(Timestamp, FullPath)>;
+
     #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct StableKey {
         pub collection: CollectionKey,
@@ -23,4 +32,27 @@ pub mod state {
         pub encoding_type: EncodingType,
         pub chunk_index: usize,
     }
+
+    #[derive(Default, Clone)]
+    pub struct StorageRuntimeState {
+        pub hot_assets: HotAssets,
+        pub scheduled_assets: ScheduledAssets,
+        // A single timer is scheduled at once, for the earliest schedule, with the timestamp it fires at.
+        pub schedule_timer: Option<(TimerId, Timestamp)>,
+    }
+}
+
+pub mod interface {
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::core::Blob;
+    use junobuild_storage::types::state::FullPath;
+    use serde::Deserialize;
+
+    /// The assets a deployment is about to upload, each with the SHA-256 of its content.
+    #[derive(CandidType, Deserialize)]
+    pub struct DiffAssetsArgs {
+        pub collection: CollectionKey,
+        pub assets: Vec<(FullPath, Blob)>,
+    }
 }
@@ -0,0 +1,27 @@
+use crate::storage::state::{clear_hot_assets, get_config, insert_hot_asset};
+use junobuild_storage::types::state::FullPath;
+
+// Assets larger than 2 MB are not kept on the heap.
+const HOT_ASSET_MAX_SIZE: u128 = 2 * 1024 * 1024;
+
+/// Loads on the heap the content of the stable assets which are likely to be requested by the first visitors after a deploy - i.e. the destinations of the rewrites.
+/// That way those are not read from the stable memory on each request.
+pub fn warm_hot_assets() {
+    clear_hot_assets();
+
+    let config = get_config();
+
+    let rewrites = config.rewrites.values().cloned();
+
+    let weighted_rewrites = config
+        .unwrap_weighted_rewrites()
+        .into_values()
+        .flat_map(|rewrite| rewrite.destinations)
+        .map(|weighted_destination| weighted_destination.destination);
+
+    let destinations: Vec<FullPath> = rewrites.chain(weighted_rewrites).collect();
+
+    for destination in destinations {
+        insert_hot_asset(&destination, HOT_ASSET_MAX_SIZE);
+    }
+}
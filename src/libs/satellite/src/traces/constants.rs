@@ -0,0 +1,4 @@
+pub const DAY_NS: u64 = 86_400_000_000_000;
+
+// The traces of the days older than the retention are dropped when a new day is recorded.
+pub const TRACES_RETENTION_DAYS: u64 = 30;
@@ -0,0 +1,4 @@
+mod constants;
+mod state;
+pub mod store;
+pub mod types;
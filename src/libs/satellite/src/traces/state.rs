@@ -0,0 +1,54 @@
+use crate::memory::STATE;
+use crate::traces::types::state::{EndpointTraces, TraceDay, TracesHeapState};
+
+// ---------------------------------------------------------
+// Traces
+// ---------------------------------------------------------
+
+pub fn get_traces() -> EndpointTraces {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .traces
+            .as_ref()
+            .map(|traces| traces.traces.clone())
+            .unwrap_or_default()
+    })
+}
+
+pub fn update_trace(endpoint: &str, day: TraceDay, instructions: u64, retained_from: TraceDay) {
+    STATE.with(|state| {
+        update_trace_impl(
+            endpoint,
+            day,
+            instructions,
+            retained_from,
+            &mut state.borrow_mut().heap.traces,
+        )
+    })
+}
+
+fn update_trace_impl(
+    endpoint: &str,
+    day: TraceDay,
+    instructions: u64,
+    retained_from: TraceDay,
+    state: &mut Option<TracesHeapState>,
+) {
+    let traces = &mut state.get_or_insert_with(TracesHeapState::default).traces;
+
+    if !traces.contains_key(&day) {
+        traces.retain(|traced_day, _| *traced_day >= retained_from);
+    }
+
+    let trace = traces
+        .entry(day)
+        .or_default()
+        .entry(endpoint.to_string())
+        .or_default();
+
+    trace.calls = trace.calls.saturating_add(1);
+    trace.instructions = trace.instructions.saturating_add(instructions as u128);
+    trace.max_instructions = trace.max_instructions.max(instructions);
+}
@@ -0,0 +1,65 @@
+use crate::extensions::store::{
+    get_extension_store, is_extension_enabled_store, set_extension_enabled_store,
+};
+use crate::extensions::types::state::ExtensionKey;
+use crate::traces::constants::{DAY_NS, TRACES_RETENTION_DAYS};
+use crate::traces::state::{get_traces, update_trace};
+use crate::traces::types::config::TracesConfig;
+use crate::traces::types::interface::DailyEndpointTrace;
+use ic_cdk::api::{performance_counter, time};
+
+// ---------------------------------------------------------
+// Config
+// ---------------------------------------------------------
+
+// The toggle of the traces is the one of their extension in the registry.
+
+pub fn set_config(config: &TracesConfig) -> Result<(), String> {
+    set_extension_enabled_store(ExtensionKey::Traces, config.enabled)?;
+
+    Ok(())
+}
+
+pub fn get_config() -> Option<TracesConfig> {
+    get_extension_store(&ExtensionKey::Traces).map(|extension| TracesConfig {
+        enabled: extension.enabled,
+    })
+}
+
+// ---------------------------------------------------------
+// Traces
+// ---------------------------------------------------------
+
+pub fn list_traces_store() -> Vec<DailyEndpointTrace> {
+    get_traces()
+        .into_iter()
+        .flat_map(|(day, traces)| {
+            traces
+                .into_iter()
+                .map(move |(endpoint, trace)| DailyEndpointTrace {
+                    day,
+                    endpoint,
+                    trace,
+                })
+        })
+        .collect()
+}
+
+/// Adds the instructions executed so far by the current message to the traces of the endpoint for the day, if the
+/// collection of the traces is enabled.
+///
+/// It should be called at the end of an update endpoint, once the work is done. A call that traps is not recorded,
+/// since its changes to the state are reverted.
+pub fn record_trace(endpoint: &str) {
+    if !is_extension_enabled_store(&ExtensionKey::Traces) {
+        return;
+    }
+
+    // Counter type 0: the instructions executed in the current message.
+    let instructions = performance_counter(0);
+
+    let day = time() / DAY_NS * DAY_NS;
+    let retained_from = day.saturating_sub(TRACES_RETENTION_DAYS * DAY_NS);
+
+    update_trace(endpoint, day, instructions, retained_from);
+}
@@ -0,0 +1,55 @@
+pub mod state {
+    use candid::CandidType;
+    use junobuild_shared::types::state::Timestamp;
+    use serde::{Deserialize, Serialize};
+    use std::collections::{BTreeMap, HashMap};
+
+    pub type EndpointName = String;
+
+    /// The beginning of a day - midnight UTC - in nanoseconds.
+    pub type TraceDay = Timestamp;
+
+    pub type DailyTraces = HashMap<EndpointName, EndpointTrace>;
+    pub type EndpointTraces = BTreeMap<TraceDay, DailyTraces>;
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct TracesHeapState {
+        pub traces: EndpointTraces,
+    }
+
+    /// The calls of an endpoint over a day.
+    ///
+    /// - `instructions`: The sum of the instructions executed by the calls.
+    /// - `max_instructions`: The most instructions executed by a single call.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct EndpointTrace {
+        pub calls: u64,
+        pub instructions: u128,
+        pub max_instructions: u64,
+    }
+}
+
+pub mod config {
+    use candid::{CandidType, Deserialize};
+    use serde::Serialize;
+
+    /// Whether the satellite collects the instructions executed by its update endpoints. Queries cannot be traced
+    /// given that the changes they make to the state are discarded.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct TracesConfig {
+        pub enabled: bool,
+    }
+}
+
+pub mod interface {
+    use crate::traces::types::state::{EndpointName, EndpointTrace, TraceDay};
+    use candid::CandidType;
+    use serde::Deserialize;
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct DailyEndpointTrace {
+        pub day: TraceDay,
+        pub endpoint: EndpointName,
+        pub trace: EndpointTrace,
+    }
+}
@@ -0,0 +1,4 @@
+/// The budget of the encoded entries - documents with their keys, slices of content with the metadata of their asset -
+/// exported per chunk. It is half the 2 MiB limit of the response of an inter-canister call, which leaves room for a
+/// single document larger than the budget - the first of a chunk is always exported - and the encoding of the chunk.
+pub const EXPORT_CHUNK_MAX_BYTES: usize = 1_000_000;
@@ -0,0 +1,5 @@
+mod constants;
+mod msg;
+mod runtime;
+pub mod store;
+pub mod types;
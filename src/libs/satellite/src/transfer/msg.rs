@@ -0,0 +1,3 @@
+pub const ERROR_INVALID_EXPORT_CURSOR: &str = "Invalid export cursor.";
+pub const ERROR_INVALID_IMPORT_DATA: &str = "Invalid import data.";
+pub const ERROR_IMPORT_BATCH_NOT_FOUND: &str = "No import in progress for the asset.";
@@ -0,0 +1,21 @@
+use crate::memory::STATE;
+use crate::transfer::types::state::{ImportBatch, ImportBatchKey};
+
+pub fn get_batch(key: &ImportBatchKey) -> Option<ImportBatch> {
+    STATE.with(|state| state.borrow().runtime.transfer.batches.get(key).cloned())
+}
+
+pub fn insert_batch(key: ImportBatchKey, batch: ImportBatch) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .runtime
+            .transfer
+            .batches
+            .insert(key, batch)
+    });
+}
+
+pub fn delete_batch(key: &ImportBatchKey) {
+    STATE.with(|state| state.borrow_mut().runtime.transfer.batches.remove(key));
+}
@@ -0,0 +1,489 @@
+use crate::auth::store::{
+    get_config as get_authentication_config, set_config as set_authentication_config,
+};
+use crate::bundle::store::bundle_rule;
+use crate::db::state::{
+    get_rule as get_state_db_rule, iter_docs_heap_after, iter_docs_stable_after,
+};
+use crate::db::store::{
+    get_config_store as get_db_config_store, import_doc_store,
+    set_config_store as set_db_config_store,
+};
+use crate::db::types::state::{Doc, DocContext, DocUpsert};
+use crate::events::types::interface::SatelliteEvent;
+use crate::memory::STATE;
+use crate::ownership::store::set_asset_owner_store;
+use crate::ownership::types::interface::SetAssetOwner;
+use crate::rules::store::{get_rule_db, get_rule_storage, set_rule_db, set_rule_storage};
+use crate::storage::state::{get_rule as get_state_storage_rule, iter_assets_stable_from};
+use crate::storage::store::{
+    commit_batch_store, create_batch_store, create_chunk_store,
+    get_config_store as get_storage_config_store, get_content_chunks_store,
+    set_config_store as set_storage_config_store,
+};
+use crate::transfer::constants::EXPORT_CHUNK_MAX_BYTES;
+use crate::transfer::msg::{
+    ERROR_IMPORT_BATCH_NOT_FOUND, ERROR_INVALID_EXPORT_CURSOR, ERROR_INVALID_IMPORT_DATA,
+};
+use crate::transfer::runtime::{delete_batch, get_batch, insert_batch};
+use crate::transfer::types::interface::{
+    ExportAssetPosition, ExportCursor, ExportedAssetChunk, ExportedCollection, ExportedConfig,
+};
+use crate::transfer::types::state::{ImportBatch, ImportBatchKey};
+use crate::types::interface::RulesType;
+use candid::{decode_one, encode_one, CandidType, Principal};
+use junobuild_collections::msg::{msg_db_collection_not_found, msg_storage_collection_not_found};
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_collections::types::interface::SetRule;
+use junobuild_collections::types::rules::Memory;
+use junobuild_shared::list::encoded_size;
+use junobuild_shared::types::core::{Blob, Key};
+use junobuild_shared::types::interface::{
+    ExportChunk, ExportChunkArgs, ImportChunkArgs, TransferSection,
+};
+use junobuild_storage::heap_utils::collect_assets_heap;
+use junobuild_storage::http::utils::slice_body;
+use junobuild_storage::types::interface::{CommitBatch, InitAssetKey, UploadChunk};
+use junobuild_storage::types::state::FullPath;
+use junobuild_storage::types::store::{Asset, EncodingType};
+use serde::de::DeserializeOwned;
+
+// ---------------------------------------------------------
+// Export
+// ---------------------------------------------------------
+
+/// Exports the next chunk of a section. A collection is exported with its rule first - i.e. when no cursor is
+/// provided - followed by its documents or assets, the assets being sliced to fit the size of a chunk.
+///
+/// The collection is read anew for each chunk, the changes made while it is exported are therefore only included if
+/// they concern the entries that have not yet been exported.
+pub fn export_chunk_store(
+    ExportChunkArgs { section, cursor }: &ExportChunkArgs,
+) -> Result<ExportChunk, String> {
+    let cursor: Option<ExportCursor> = match cursor {
+        None => None,
+        Some(cursor) => Some(decode(cursor).map_err(|_| ERROR_INVALID_EXPORT_CURSOR.to_string())?),
+    };
+
+    let (data, cursor) = match (section, cursor) {
+        (TransferSection::Config, _) => (encode(&export_config())?, None),
+        (TransferSection::Db(collection), None) => {
+            let rule =
+                get_rule_db(collection).ok_or_else(|| msg_db_collection_not_found(collection))?;
+
+            (
+                encode(&ExportedCollection::Rule(bundle_rule(rule)))?,
+                Some(ExportCursor::Docs { start_after: None }),
+            )
+        }
+        (TransferSection::Db(collection), Some(ExportCursor::Docs { start_after })) => {
+            export_docs(collection, &start_after)?
+        }
+        (TransferSection::Storage(collection), None) => {
+            let rule = get_rule_storage(collection)
+                .ok_or_else(|| msg_storage_collection_not_found(collection))?;
+
+            (
+                encode(&ExportedCollection::Rule(bundle_rule(rule)))?,
+                Some(ExportCursor::Assets { position: None }),
+            )
+        }
+        (TransferSection::Storage(collection), Some(ExportCursor::Assets { position })) => {
+            export_assets(collection, &position)?
+        }
+        _ => return Err(ERROR_INVALID_EXPORT_CURSOR.to_string()),
+    };
+
+    Ok(ExportChunk {
+        data,
+        cursor: cursor.map(|cursor| encode(&cursor)).transpose()?,
+    })
+}
+
+fn export_config() -> ExportedConfig {
+    ExportedConfig {
+        db: get_db_config_store(),
+        storage: get_storage_config_store(),
+        authentication: get_authentication_config(),
+    }
+}
+
+fn export_docs(
+    collection: &CollectionKey,
+    start_after: &Option<Key>,
+) -> Result<(Blob, Option<ExportCursor>), String> {
+    let rule = get_state_db_rule(collection)?;
+
+    let (docs, remaining) = match rule.mem() {
+        Memory::Heap => STATE.with(|state| {
+            let state_ref = state.borrow();
+            let docs = iter_docs_heap_after(collection, start_after, &state_ref.heap.db.db)?;
+            Ok::<_, String>(take_docs(docs.map(|(key, doc)| (key.clone(), doc.clone()))))
+        })?,
+        Memory::Stable => STATE.with(|state| {
+            take_docs(
+                iter_docs_stable_after(collection, start_after, &state.borrow().stable.db)
+                    .map(|(key, doc)| (key.key, doc)),
+            )
+        }),
+    };
+
+    let cursor = if remaining {
+        docs.last().map(|(key, _)| ExportCursor::Docs {
+            start_after: Some(key.clone()),
+        })
+    } else {
+        None
+    };
+
+    Ok((encode(&ExportedCollection::Docs(docs))?, cursor))
+}
+
+/// Takes the documents as long as they fit in the size of a chunk - at least one - and returns whether some remain.
+fn take_docs(docs: impl Iterator<Item = (Key, Doc)>) -> (Vec<(Key, Doc)>, bool) {
+    let mut bytes: usize = 0;
+    let mut items: Vec<(Key, Doc)> = Vec::new();
+
+    for (key, doc) in docs {
+        let size = encoded_size(&key, &doc);
+
+        if !items.is_empty() && bytes + size > EXPORT_CHUNK_MAX_BYTES {
+            return (items, true);
+        }
+
+        bytes += size;
+        items.push((key, doc));
+    }
+
+    (items, false)
+}
+
+/// The assets are read from the position of the cursor on, in the order of their full paths, until the size of a chunk
+/// is reached. The stable memory is read as a range; the assets on the heap - which are not ordered - are sorted by
+/// reference and only those that are exported are cloned.
+fn export_assets(
+    collection: &CollectionKey,
+    position: &Option<ExportAssetPosition>,
+) -> Result<(Blob, Option<ExportCursor>), String> {
+    let rule = get_state_storage_rule(collection)?;
+
+    let start_at: FullPath = position
+        .as_ref()
+        .map_or_else(FullPath::new, |position| position.full_path.clone());
+
+    let (chunks, cursor) = STATE.with(|state| {
+        let state = state.borrow();
+
+        match rule.mem() {
+            Memory::Heap => {
+                let mut assets: Vec<&Asset> =
+                    collect_assets_heap(collection, &state.heap.storage.assets)
+                        .into_iter()
+                        .filter(|(full_path, _)| **full_path >= start_at)
+                        .map(|(_, asset)| asset)
+                        .collect();
+
+                assets.sort_by(|a, b| a.key.full_path.cmp(&b.key.full_path));
+
+                take_asset_chunks(assets.into_iter().cloned(), position, &rule.mem())
+            }
+            Memory::Stable => take_asset_chunks(
+                iter_assets_stable_from(collection, &start_at, &state.stable.assets)
+                    .map(|(_, asset)| asset),
+                position,
+                &rule.mem(),
+            ),
+        }
+    });
+
+    Ok((encode(&ExportedCollection::Assets(chunks))?, cursor))
+}
+
+/// Slices the content of the assets - starting at the position - until the size of a chunk is reached. The metadata of
+/// each slice counts toward the size as well. Returns the position of the next slice if some content remains.
+fn take_asset_chunks(
+    assets: impl Iterator<Item = Asset>,
+    position: &Option<ExportAssetPosition>,
+    memory: &Memory,
+) -> (Vec<ExportedAssetChunk>, Option<ExportCursor>) {
+    let mut chunks: Vec<ExportedAssetChunk> = Vec::new();
+    let mut bytes: usize = 0;
+
+    for asset in assets {
+        let full_path = &asset.key.full_path;
+
+        let mut encoding_types: Vec<&EncodingType> = asset.encodings.keys().collect();
+        encoding_types.sort();
+
+        let metadata_bytes = encoded_size(full_path, &(&asset.key, &asset.headers));
+
+        for encoding_type in encoding_types {
+            let (start_index, start_offset) = match position {
+                Some(position)
+                    if (full_path, encoding_type)
+                        < (&position.full_path, &position.encoding_type) =>
+                {
+                    continue
+                }
+                Some(position)
+                    if *full_path == position.full_path
+                        && *encoding_type == position.encoding_type =>
+                {
+                    (position.chunk_index, position.offset)
+                }
+                _ => (0, 0),
+            };
+
+            let encoding = &asset.encodings[encoding_type];
+            let chunks_length = encoding.content_chunks.len();
+
+            for chunk_index in start_index..chunks_length {
+                let chunk =
+                    get_content_chunks_store(encoding, chunk_index, memory).unwrap_or_default();
+
+                let mut offset = if chunk_index == start_index {
+                    start_offset
+                } else {
+                    0
+                };
+
+                loop {
+                    if !chunks.is_empty() && bytes + metadata_bytes >= EXPORT_CHUNK_MAX_BYTES {
+                        let cursor = ExportCursor::Assets {
+                            position: Some(ExportAssetPosition {
+                                full_path: full_path.clone(),
+                                encoding_type: encoding_type.clone(),
+                                chunk_index,
+                                offset,
+                            }),
+                        };
+
+                        return (chunks, Some(cursor));
+                    }
+
+                    let (content, next_offset) = slice_body(
+                        &chunk,
+                        offset,
+                        EXPORT_CHUNK_MAX_BYTES
+                            .saturating_sub(bytes + metadata_bytes)
+                            .max(1),
+                    );
+
+                    bytes += content.len() + metadata_bytes;
+
+                    chunks.push(ExportedAssetChunk {
+                        key: asset.key.clone(),
+                        headers: asset.headers.clone(),
+                        encoding_type: encoding_type.clone(),
+                        content,
+                        first: chunk_index == 0 && offset == 0,
+                        last: next_offset.is_none() && chunk_index + 1 == chunks_length,
+                    });
+
+                    match next_offset {
+                        None => break,
+                        Some(next_offset) => offset = next_offset,
+                    }
+                }
+            }
+        }
+    }
+
+    (chunks, None)
+}
+
+// ---------------------------------------------------------
+// Import
+// ---------------------------------------------------------
+
+/// Imports a chunk exported from another Satellite. The documents are inserted as they are while the assets are
+/// uploaded again - in batches - before being handed over to their original owners.
+///
+/// Returns the events of the changes that were applied, to be emitted like those of the other writes.
+pub fn import_chunk_store(
+    caller: Principal,
+    ImportChunkArgs { section, data }: &ImportChunkArgs,
+) -> Result<Vec<SatelliteEvent>, String> {
+    match section {
+        TransferSection::Config => {
+            let config: ExportedConfig =
+                decode(data).map_err(|_| ERROR_INVALID_IMPORT_DATA.to_string())?;
+
+            import_config(config)
+        }
+        TransferSection::Db(collection) => {
+            match decode(data).map_err(|_| ERROR_INVALID_IMPORT_DATA.to_string())? {
+                ExportedCollection::Rule(rule) => {
+                    let version = get_rule_db(collection).and_then(|current| current.version);
+
+                    let rule = set_rule_db(collection.clone(), SetRule { version, ..rule })?;
+
+                    Ok(vec![SatelliteEvent::SetRule {
+                        rules_type: RulesType::Db,
+                        collection: collection.clone(),
+                        rule,
+                    }])
+                }
+                ExportedCollection::Docs(docs) => {
+                    let docs = docs
+                        .iter()
+                        .map(|(key, doc)| import_doc_store(collection, key, doc))
+                        .collect::<Result<Vec<DocContext<DocUpsert>>, String>>()?;
+
+                    Ok(vec![SatelliteEvent::SetManyDocs(docs)])
+                }
+                ExportedCollection::Assets(_) => Err(ERROR_INVALID_IMPORT_DATA.to_string()),
+            }
+        }
+        TransferSection::Storage(collection) => {
+            match decode(data).map_err(|_| ERROR_INVALID_IMPORT_DATA.to_string())? {
+                ExportedCollection::Rule(rule) => {
+                    let version = get_rule_storage(collection).and_then(|current| current.version);
+
+                    let rule = set_rule_storage(collection.clone(), SetRule { version, ..rule })?;
+
+                    Ok(vec![SatelliteEvent::SetRule {
+                        rules_type: RulesType::Storage,
+                        collection: collection.clone(),
+                        rule,
+                    }])
+                }
+                ExportedCollection::Assets(chunks) => {
+                    let mut events: Vec<SatelliteEvent> = Vec::new();
+
+                    // An asset is uploaded once its last slice is imported
+                    for chunk in chunks {
+                        if let Some(asset) = import_asset_chunk(caller, collection, chunk)? {
+                            events.push(SatelliteEvent::UploadAsset(asset));
+                        }
+                    }
+
+                    Ok(events)
+                }
+                ExportedCollection::Docs(_) => Err(ERROR_INVALID_IMPORT_DATA.to_string()),
+            }
+        }
+    }
+}
+
+fn import_config(
+    ExportedConfig {
+        db,
+        storage,
+        authentication,
+    }: ExportedConfig,
+) -> Result<Vec<SatelliteEvent>, String> {
+    set_storage_config_store(&storage)?;
+
+    let mut events = vec![SatelliteEvent::SetStorageConfig(storage)];
+
+    if let Some(db) = db {
+        set_db_config_store(&db)?;
+        events.push(SatelliteEvent::SetDbConfig(db));
+    }
+
+    if let Some(authentication) = authentication {
+        set_authentication_config(&authentication)?;
+        events.push(SatelliteEvent::SetAuthConfig(authentication));
+    }
+
+    Ok(events)
+}
+
+fn import_asset_chunk(
+    caller: Principal,
+    collection: &CollectionKey,
+    ExportedAssetChunk {
+        key,
+        headers,
+        encoding_type,
+        content,
+        first,
+        last,
+    }: ExportedAssetChunk,
+) -> Result<Option<Asset>, String> {
+    let batch_key: ImportBatchKey = (key.full_path.clone(), encoding_type.clone());
+
+    let ImportBatch {
+        batch_id,
+        mut chunk_ids,
+    } = if first {
+        let batch_id = create_batch_store(
+            caller,
+            InitAssetKey {
+                name: key.name.clone(),
+                full_path: key.full_path.clone(),
+                token: key.token.clone(),
+                collection: collection.clone(),
+                encoding_type: Some(encoding_type),
+                description: key.description.clone(),
+                publish_at: key.publish_at,
+                unpublish_at: key.unpublish_at,
+            },
+        )?;
+
+        ImportBatch {
+            batch_id,
+            chunk_ids: Vec::new(),
+        }
+    } else {
+        get_batch(&batch_key).ok_or_else(|| ERROR_IMPORT_BATCH_NOT_FOUND.to_string())?
+    };
+
+    let chunk_id = create_chunk_store(
+        caller,
+        UploadChunk {
+            batch_id,
+            content,
+            order_id: Some(chunk_ids.len() as u128),
+        },
+    )?;
+
+    chunk_ids.push(chunk_id);
+
+    if !last {
+        insert_batch(
+            batch_key,
+            ImportBatch {
+                batch_id,
+                chunk_ids,
+            },
+        );
+
+        return Ok(None);
+    }
+
+    delete_batch(&batch_key);
+
+    let asset = commit_batch_store(
+        caller,
+        CommitBatch {
+            batch_id,
+            headers,
+            chunk_ids,
+        },
+    )?;
+
+    if asset.key.owner == key.owner {
+        return Ok(Some(asset));
+    }
+
+    let asset = set_asset_owner_store(
+        collection,
+        &key.full_path,
+        &SetAssetOwner {
+            owner: key.owner,
+            version: asset.version,
+        },
+    )?;
+
+    Ok(Some(asset))
+}
+
+fn encode<T: CandidType>(value: &T) -> Result<Blob, String> {
+    encode_one(value).map_err(|e| e.to_string())
+}
+
+fn decode<T: CandidType + DeserializeOwned>(bytes: &Blob) -> Result<T, String> {
+    decode_one(bytes).map_err(|e| e.to_string())
+}
@@ -0,0 +1,80 @@
+pub mod state {
+    use junobuild_storage::types::runtime_state::{BatchId, ChunkId};
+    use junobuild_storage::types::state::FullPath;
+    use junobuild_storage::types::store::EncodingType;
+    use std::collections::HashMap;
+
+    pub type ImportBatchKey = (FullPath, EncodingType);
+
+    #[derive(Default, Clone)]
+    pub struct TransferRuntimeState {
+        pub batches: HashMap<ImportBatchKey, ImportBatch>,
+    }
+
+    /// The upload batch of an encoding of an asset being imported and the chunks uploaded so far.
+    #[derive(Clone)]
+    pub struct ImportBatch {
+        pub batch_id: BatchId,
+        pub chunk_ids: Vec<ChunkId>,
+    }
+}
+
+pub mod interface {
+    use crate::auth::types::config::AuthenticationConfig;
+    use crate::db::types::config::DbConfig;
+    use crate::db::types::state::Doc;
+    use candid::CandidType;
+    use junobuild_collections::types::interface::SetRule;
+    use junobuild_shared::types::core::{Blob, Key};
+    use junobuild_storage::http::types::HeaderField;
+    use junobuild_storage::types::config::StorageConfig;
+    use junobuild_storage::types::state::FullPath;
+    use junobuild_storage::types::store::{AssetKey, EncodingType};
+    use serde::Deserialize;
+
+    #[derive(CandidType, Deserialize)]
+    pub struct ExportedConfig {
+        pub db: Option<DbConfig>,
+        pub storage: StorageConfig,
+        pub authentication: Option<AuthenticationConfig>,
+    }
+
+    /// The chunks of a collection: its rule first, then its documents or assets.
+    #[derive(CandidType, Deserialize)]
+    pub enum ExportedCollection {
+        Rule(SetRule),
+        Docs(Vec<(Key, Doc)>),
+        Assets(Vec<ExportedAssetChunk>),
+    }
+
+    /// A slice of a content chunk of an encoding of an asset. `first` and `last` are set on the slices that start and
+    /// complete the encoding.
+    #[derive(CandidType, Deserialize)]
+    pub struct ExportedAssetChunk {
+        pub key: AssetKey,
+        pub headers: Vec<HeaderField>,
+        pub encoding_type: EncodingType,
+        pub content: Blob,
+        pub first: bool,
+        pub last: bool,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub enum ExportCursor {
+        Docs {
+            start_after: Option<Key>,
+        },
+        Assets {
+            position: Option<ExportAssetPosition>,
+        },
+    }
+
+    /// The position of the next slice of content to export.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct ExportAssetPosition {
+        pub full_path: FullPath,
+        pub encoding_type: EncodingType,
+        pub chunk_index: usize,
+        pub offset: usize,
+    }
+}
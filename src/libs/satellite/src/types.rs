@@ -1,8 +1,42 @@
 pub mod state {
+    use crate::analytics::types::state::{AnalyticsHeapState, AnalyticsRuntimeState};
+    use crate::api_keys::types::state::ApiKeysHeapState;
+    use crate::approvals::types::state::ApprovalsHeapState;
+    use crate::audit::types::state::AuditLogStable;
     use crate::auth::types::state::AuthenticationHeapState;
+    use crate::changes::types::state::ChangesHeapState;
+    use crate::controllers::types::state::ControllersRuntimeState;
     use crate::db::types::state::{DbHeapState, DbRuntimeState, DbStable};
+    use crate::defrag::types::state::{DefragHeapState, DefragRuntimeState};
+    use crate::deployment::types::state::CanaryDeployment;
+    use crate::encryption::types::state::EncryptionRuntimeState;
+    use crate::events::types::state::EventsRuntimeState;
+    use crate::extensions::types::state::ExtensionsHeapState;
+    use crate::flags::types::state::FeatureFlagsHeapState;
+    use crate::gates::types::state::TokenGatesRuntimeState;
+    use crate::gc::types::state::{
+        GcAttachmentsStable, GcChunksStable, GcDocsStable, GcRuntimeState,
+    };
+    use crate::health::types::state::HealthRuntimeState;
+    use crate::integrity::types::state::IntegrityRuntimeState;
+    use crate::links::types::state::LinksRuntimeState;
+    use crate::maintenance::types::state::MaintenanceRuntimeState;
     use crate::memory::init_stable_state;
-    use crate::storage::types::state::{AssetsStable, ContentChunksStable};
+    use crate::moderation::types::state::ModerationHeapState;
+    use crate::quotas::types::state::QuotasHeapState;
+    use crate::rate_limit::types::state::{RateLimitHeapState, RateLimitRuntimeState};
+    use crate::requests::types::state::RequestsRuntimeState;
+    use crate::retention::types::state::RetentionHeapState;
+    use crate::revisions::types::state::{
+        RevisionsHeapState, RevisionsRuntimeState, RevisionsStable,
+    };
+    use crate::safe_mode::types::state::SafeModeRuntimeState;
+    use crate::stats::types::state::StatsHeapState;
+    use crate::storage::types::state::{AssetsStable, ContentChunksStable, StorageRuntimeState};
+    use crate::traces::types::state::TracesHeapState;
+    use crate::transfer::types::state::TransferRuntimeState;
+    use crate::uploads::types::state::UploadsHeapState;
+    use crate::webhooks::types::state::{WebhooksHeapState, WebhooksRuntimeState};
     use candid::CandidType;
     use junobuild_shared::types::state::Controllers;
     use junobuild_storage::types::state::StorageHeapState;
@@ -27,6 +61,11 @@ pub mod state {
         pub db: DbStable,
         pub assets: AssetsStable,
         pub content_chunks: ContentChunksStable,
+        pub audit: AuditLogStable,
+        pub revisions: RevisionsStable,
+        pub gc_chunks: GcChunksStable,
+        pub gc_docs: GcDocsStable,
+        pub gc_attachments: GcAttachmentsStable,
     }
 
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
@@ -35,12 +74,47 @@ pub mod state {
         pub db: DbHeapState,
         pub storage: StorageHeapState,
         pub authentication: Option<AuthenticationHeapState>,
+        pub analytics: Option<AnalyticsHeapState>,
+        pub flags: Option<FeatureFlagsHeapState>,
+        pub canary: Option<CanaryDeployment>,
+        pub stats: Option<StatsHeapState>,
+        pub moderation: Option<ModerationHeapState>,
+        pub traces: Option<TracesHeapState>,
+        pub uploads: Option<UploadsHeapState>,
+        pub quotas: Option<QuotasHeapState>,
+        pub webhooks: Option<WebhooksHeapState>,
+        pub changes: Option<ChangesHeapState>,
+        pub rate_limit: Option<RateLimitHeapState>,
+        pub revisions: Option<RevisionsHeapState>,
+        pub approvals: Option<ApprovalsHeapState>,
+        pub extensions: Option<ExtensionsHeapState>,
+        pub api_keys: Option<ApiKeysHeapState>,
+        pub defrag: Option<DefragHeapState>,
+        pub retention: Option<RetentionHeapState>,
     }
 
     #[derive(Default, Clone)]
     pub struct RuntimeState {
         pub rng: Option<StdRng>, // rng = Random Number Generator
         pub db: DbRuntimeState,
+        pub storage: StorageRuntimeState,
+        pub health: HealthRuntimeState,
+        pub events: EventsRuntimeState,
+        pub transfer: TransferRuntimeState,
+        pub rate_limit: RateLimitRuntimeState,
+        pub integrity: IntegrityRuntimeState,
+        pub links: LinksRuntimeState,
+        pub controllers: ControllersRuntimeState,
+        pub requests: RequestsRuntimeState,
+        pub gates: TokenGatesRuntimeState,
+        pub defrag: DefragRuntimeState,
+        pub safe_mode: SafeModeRuntimeState,
+        pub gc: GcRuntimeState,
+        pub encryption: EncryptionRuntimeState,
+        pub analytics: AnalyticsRuntimeState,
+        pub revisions: RevisionsRuntimeState,
+        pub webhooks: WebhooksRuntimeState,
+        pub maintenance: MaintenanceRuntimeState,
     }
 }
 
@@ -49,9 +123,9 @@ pub mod interface {
     use crate::db::types::config::DbConfig;
     use candid::CandidType;
     use junobuild_storage::types::config::StorageConfig;
-    use serde::Deserialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(CandidType, Deserialize)]
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub enum RulesType {
         Db,
         Storage,
@@ -78,6 +152,7 @@ pub mod store {
 
 pub mod hooks {
     use crate::db::types::state::{DocAssertDelete, DocAssertSet, DocContext, DocUpsert};
+    use crate::requests::types::state::RequestId;
     use crate::Doc;
     use candid::{CandidType, Deserialize};
     use junobuild_shared::types::state::UserId;
@@ -90,6 +165,7 @@ pub mod hooks {
     /// # Fields
     /// - `caller`: A `UserId` representing the caller of the hook.
     /// - `data`: A generic type `T` representing the associated data for the hook.
+    /// - `request_id`: The id of the request that triggered the hook, if any, to correlate its logs and outcalls.
     ///
     /// This context struct is used in various satellite hooks to provide information about the caller
     /// and the specific data related to the hook.
@@ -105,6 +181,7 @@ pub mod hooks {
     pub struct HookContext<T> {
         pub caller: UserId,
         pub data: T,
+        pub request_id: Option<RequestId>,
     }
 
     /// A type alias for the context used in the `on_set_doc` satellite hook.
@@ -0,0 +1,180 @@
+use crate::events::store::emit;
+use crate::events::types::interface::SatelliteEvent;
+use crate::safe_mode::store::assert_not_safe_mode;
+use crate::storage::store::{commit_batch_store, create_batch_store, create_chunk_store};
+use crate::uploads::msg::{
+    ERROR_UPLOAD_NOT_MULTIPART, ERROR_UPLOAD_NO_FILE, ERROR_UPLOAD_UNAUTHORIZED,
+};
+use crate::uploads::multipart::{get_boundary, parse_multipart};
+use crate::uploads::store::authenticate_upload_token;
+use crate::uploads::types::runtime::MultipartPart;
+use crate::uploads::types::state::UploadToken;
+use junobuild_storage::http::types::{HeaderField, HttpRequest, HttpResponse, StatusCode};
+use junobuild_storage::http::utils::get_header_value;
+use junobuild_storage::types::interface::{CommitBatch, InitAssetKey, UploadChunk};
+use serde::Serialize;
+
+pub const UPLOAD_PATH: &str = "/_juno/storage/upload";
+
+const RESPONSE_STATUS_CODE_201: StatusCode = 201;
+const RESPONSE_STATUS_CODE_400: StatusCode = 400;
+const RESPONSE_STATUS_CODE_401: StatusCode = 401;
+const RESPONSE_STATUS_CODE_415: StatusCode = 415;
+const RESPONSE_STATUS_CODE_503: StatusCode = 503;
+
+#[derive(Serialize)]
+struct UploadResponse {
+    full_paths: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct UploadErrorResponse {
+    error: String,
+}
+
+pub fn is_upload_request(HttpRequest { method, url, .. }: &HttpRequest) -> bool {
+    let path = url.split('?').next().unwrap_or_default();
+
+    method == "POST" && path == UPLOAD_PATH
+}
+
+/// Plain HTML forms and clients without an agent can post `multipart/form-data` with an upload token in the
+/// `Authorization: Bearer` header. Each file of the form is saved in the collection of the token - at
+/// `/{collection}/{filename}` - on behalf of its owner, through the same batch, chunk and commit steps as an upload
+/// with calls.
+pub fn http_request_update(HttpRequest { body, headers, .. }: HttpRequest) -> HttpResponse {
+    if let Err(err) = assert_not_safe_mode() {
+        return error_response(RESPONSE_STATUS_CODE_503, err);
+    }
+
+    let token = match authenticate(&headers) {
+        Ok(token) => token,
+        Err(err) => return error_response(RESPONSE_STATUS_CODE_401, err),
+    };
+
+    let boundary = match get_header_value(&headers, "Content-Type")
+        .as_deref()
+        .and_then(get_boundary)
+    {
+        Some(boundary) => boundary,
+        None => {
+            return error_response(
+                RESPONSE_STATUS_CODE_415,
+                ERROR_UPLOAD_NOT_MULTIPART.to_string(),
+            )
+        }
+    };
+
+    let files: Vec<MultipartPart> = match parse_multipart(&body, &boundary) {
+        Ok(parts) => parts
+            .into_iter()
+            .filter(|part| part.filename.as_ref().is_some_and(|name| !name.is_empty()))
+            .collect(),
+        Err(err) => return error_response(RESPONSE_STATUS_CODE_400, err),
+    };
+
+    if files.is_empty() {
+        return error_response(RESPONSE_STATUS_CODE_400, ERROR_UPLOAD_NO_FILE.to_string());
+    }
+
+    let mut full_paths: Vec<String> = Vec::new();
+
+    for file in files {
+        match upload_file(&token, file) {
+            Ok(full_path) => full_paths.push(full_path),
+            Err(err) => return error_response(RESPONSE_STATUS_CODE_400, err),
+        }
+    }
+
+    json_response(RESPONSE_STATUS_CODE_201, &UploadResponse { full_paths })
+}
+
+fn authenticate(headers: &[HeaderField]) -> Result<UploadToken, String> {
+    let secret = get_header_value(headers, "Authorization")
+        .and_then(|value| {
+            value
+                .strip_prefix("Bearer ")
+                .map(|secret| secret.trim().to_string())
+        })
+        .ok_or_else(|| ERROR_UPLOAD_UNAUTHORIZED.to_string())?;
+
+    authenticate_upload_token(&secret)
+}
+
+fn upload_file(
+    UploadToken {
+        collection, owner, ..
+    }: &UploadToken,
+    MultipartPart {
+        filename,
+        content_type,
+        content,
+        ..
+    }: MultipartPart,
+) -> Result<String, String> {
+    // Browsers may send the path of the file on the device - e.g. C:\fakepath\hello.txt - only its name is kept.
+    let name = filename
+        .unwrap_or_default()
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .to_string();
+
+    let full_path = format!("/{}/{}", collection.trim_start_matches('#'), name);
+
+    let batch_id = create_batch_store(
+        *owner,
+        InitAssetKey {
+            name,
+            full_path: full_path.clone(),
+            token: None,
+            collection: collection.clone(),
+            encoding_type: None,
+            description: None,
+            publish_at: None,
+            unpublish_at: None,
+        },
+    )?;
+
+    let chunk_id = create_chunk_store(
+        *owner,
+        UploadChunk {
+            batch_id,
+            content,
+            order_id: Some(0),
+        },
+    )?;
+
+    let asset = commit_batch_store(
+        *owner,
+        CommitBatch {
+            batch_id,
+            headers: Vec::from([HeaderField(
+                "Content-Type".to_string(),
+                content_type.unwrap_or_else(|| "application/octet-stream".to_string()),
+            )]),
+            chunk_ids: Vec::from([chunk_id]),
+        },
+    )?;
+
+    emit(owner, SatelliteEvent::UploadAsset(asset));
+
+    Ok(full_path)
+}
+
+fn error_response(status_code: StatusCode, error: String) -> HttpResponse {
+    json_response(status_code, &UploadErrorResponse { error })
+}
+
+fn json_response<T: Serialize>(status_code: StatusCode, payload: &T) -> HttpResponse {
+    HttpResponse {
+        body: serde_json::to_vec(payload).unwrap_or_default(),
+        headers: Vec::from([HeaderField(
+            "Content-Type".to_string(),
+            "application/json; charset=utf-8".to_string(),
+        )]),
+        status_code,
+        streaming_strategy: None,
+        upgrade: None,
+    }
+}
@@ -0,0 +1,6 @@
+pub mod http;
+mod msg;
+mod multipart;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,11 @@
+pub const ERROR_UPLOAD_TOKEN_UNKNOWN_COLLECTION: &str =
+    "Upload tokens require an existing storage collection.";
+pub const ERROR_UPLOAD_TOKEN_EXPIRES_IN_THE_PAST: &str =
+    "Upload token expiration must be in the future.";
+pub const ERROR_UPLOAD_TOKEN_NOT_FOUND: &str = "Upload token not found.";
+pub const ERROR_UPLOAD_UNAUTHORIZED: &str = "Missing, unknown or expired upload token.";
+pub const ERROR_UPLOAD_NOT_MULTIPART: &str = "Uploads must be sent as multipart/form-data.";
+pub const ERROR_UPLOAD_NO_FILE: &str = "The form contains no file.";
+pub const ERROR_UPLOAD_INVALID_FORM: &str = "Invalid multipart form data.";
+pub const ERROR_RANDOM_NOT_INITIALIZED: &str =
+    "The random number generator has not been initialized.";
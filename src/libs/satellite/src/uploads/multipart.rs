@@ -0,0 +1,103 @@
+use crate::uploads::msg::ERROR_UPLOAD_INVALID_FORM;
+use crate::uploads::types::runtime::MultipartPart;
+
+const CRLF: &[u8] = b"\r\n";
+const HEADERS_END: &[u8] = b"\r\n\r\n";
+
+/// Extracts the boundary of a `multipart/form-data` content type - e.g. `multipart/form-data; boundary=abc`.
+pub fn get_boundary(content_type: &str) -> Option<String> {
+    let mut params = content_type.split(';').map(str::trim);
+
+    let mime_type = params.next()?;
+
+    if !mime_type.eq_ignore_ascii_case("multipart/form-data") {
+        return None;
+    }
+
+    params
+        .filter_map(|param| param.split_once('='))
+        .find(|(key, _)| key.trim().eq_ignore_ascii_case("boundary"))
+        .map(|(_, value)| value.trim().trim_matches('"').to_string())
+        .filter(|boundary| !boundary.is_empty())
+}
+
+/// Splits a body into its parts according RFC 7578. The preamble and epilogue are ignored.
+pub fn parse_multipart(body: &[u8], boundary: &str) -> Result<Vec<MultipartPart>, String> {
+    let delimiter = [b"--", boundary.as_bytes()].concat();
+    let next_delimiter = [CRLF, &delimiter].concat();
+
+    let mut position = find(body, &delimiter, 0)
+        .ok_or_else(|| ERROR_UPLOAD_INVALID_FORM.to_string())?
+        + delimiter.len();
+
+    let mut parts: Vec<MultipartPart> = Vec::new();
+
+    loop {
+        let rest = &body[position..];
+
+        // The closing delimiter
+        if rest.starts_with(b"--") {
+            return Ok(parts);
+        }
+
+        if !rest.starts_with(CRLF) {
+            return Err(ERROR_UPLOAD_INVALID_FORM.to_string());
+        }
+
+        let headers_start = position + CRLF.len();
+
+        let headers_end = find(body, HEADERS_END, headers_start)
+            .ok_or_else(|| ERROR_UPLOAD_INVALID_FORM.to_string())?;
+
+        let content_start = headers_end + HEADERS_END.len();
+
+        let content_end = find(body, &next_delimiter, content_start)
+            .ok_or_else(|| ERROR_UPLOAD_INVALID_FORM.to_string())?;
+
+        let headers = String::from_utf8_lossy(&body[headers_start..headers_end]);
+
+        parts.push(map_part(&headers, &body[content_start..content_end]));
+
+        position = content_end + next_delimiter.len();
+    }
+}
+
+fn map_part(headers: &str, content: &[u8]) -> MultipartPart {
+    let mut part = MultipartPart {
+        name: None,
+        filename: None,
+        content_type: None,
+        content: content.to_vec(),
+    };
+
+    for (key, value) in headers
+        .split("\r\n")
+        .filter_map(|line| line.split_once(':'))
+    {
+        let key = key.trim();
+
+        if key.eq_ignore_ascii_case("Content-Disposition") {
+            for (param, param_value) in value.split(';').filter_map(|param| param.split_once('=')) {
+                let param_value = param_value.trim().trim_matches('"').to_string();
+
+                match param.trim() {
+                    "name" => part.name = Some(param_value),
+                    "filename" => part.filename = Some(param_value),
+                    _ => (),
+                }
+            }
+        } else if key.eq_ignore_ascii_case("Content-Type") {
+            part.content_type = Some(value.trim().to_string());
+        }
+    }
+
+    part
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|index| from + index)
+}
@@ -0,0 +1,50 @@
+use crate::memory::STATE;
+use crate::uploads::types::state::{UploadToken, UploadTokenId, UploadTokens, UploadsHeapState};
+
+pub fn get_token(id: &UploadTokenId) -> Option<UploadToken> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .uploads
+            .as_ref()
+            .and_then(|uploads| uploads.tokens.get(id).cloned())
+    })
+}
+
+pub fn get_tokens() -> UploadTokens {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .uploads
+            .as_ref()
+            .map(|uploads| uploads.tokens.clone())
+            .unwrap_or_default()
+    })
+}
+
+pub fn insert_token(id: &UploadTokenId, token: &UploadToken) {
+    STATE.with(|state| insert_token_impl(id, token, &mut state.borrow_mut().heap.uploads))
+}
+
+pub fn delete_token(id: &UploadTokenId) {
+    STATE.with(|state| delete_token_impl(id, &mut state.borrow_mut().heap.uploads))
+}
+
+fn insert_token_impl(
+    id: &UploadTokenId,
+    token: &UploadToken,
+    state: &mut Option<UploadsHeapState>,
+) {
+    state
+        .get_or_insert_with(UploadsHeapState::default)
+        .tokens
+        .insert(id.clone(), token.clone());
+}
+
+fn delete_token_impl(id: &UploadTokenId, state: &mut Option<UploadsHeapState>) {
+    if let Some(state) = state {
+        state.tokens.remove(id);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::memory::STATE;
+use crate::rules::store::get_rule_storage;
+use crate::uploads::msg::{
+    ERROR_RANDOM_NOT_INITIALIZED, ERROR_UPLOAD_TOKEN_EXPIRES_IN_THE_PAST,
+    ERROR_UPLOAD_TOKEN_NOT_FOUND, ERROR_UPLOAD_TOKEN_UNKNOWN_COLLECTION, ERROR_UPLOAD_UNAUTHORIZED,
+};
+use crate::uploads::state::{delete_token, get_token, get_tokens, insert_token};
+use crate::uploads::types::interface::{CreateUploadTokenResult, SetUploadToken};
+use crate::uploads::types::state::{UploadToken, UploadTokenId};
+use hex::encode;
+use ic_cdk::api::time;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+pub fn create_upload_token_store(
+    token: &SetUploadToken,
+) -> Result<CreateUploadTokenResult, String> {
+    if get_rule_storage(&token.collection).is_none() {
+        return Err(ERROR_UPLOAD_TOKEN_UNKNOWN_COLLECTION.to_string());
+    }
+
+    let now = time();
+
+    if token.expires_at.is_some_and(|expires_at| expires_at <= now) {
+        return Err(ERROR_UPLOAD_TOKEN_EXPIRES_IN_THE_PAST.to_string());
+    }
+
+    let secret = random_secret()?;
+    let id = hash_secret(&secret);
+
+    insert_token(
+        &id,
+        &UploadToken {
+            collection: token.collection.clone(),
+            owner: token.owner,
+            expires_at: token.expires_at,
+            created_at: now,
+        },
+    );
+
+    Ok(CreateUploadTokenResult { id, secret })
+}
+
+pub fn list_upload_tokens_store() -> Vec<(UploadTokenId, UploadToken)> {
+    get_tokens().into_iter().collect()
+}
+
+pub fn delete_upload_token_store(id: &UploadTokenId) -> Result<(), String> {
+    if get_token(id).is_none() {
+        return Err(ERROR_UPLOAD_TOKEN_NOT_FOUND.to_string());
+    }
+
+    delete_token(id);
+
+    Ok(())
+}
+
+pub fn authenticate_upload_token(secret: &str) -> Result<UploadToken, String> {
+    let token =
+        get_token(&hash_secret(secret)).ok_or_else(|| ERROR_UPLOAD_UNAUTHORIZED.to_string())?;
+
+    if token
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= time())
+    {
+        return Err(ERROR_UPLOAD_UNAUTHORIZED.to_string());
+    }
+
+    Ok(token)
+}
+
+pub fn random_secret() -> Result<String, String> {
+    let mut bytes = [0u8; 32];
+
+    STATE.with(|state| {
+        let rng = &mut state.borrow_mut().runtime.rng;
+
+        match rng {
+            None => Err(ERROR_RANDOM_NOT_INITIALIZED.to_string()),
+            Some(rng) => {
+                rng.fill_bytes(&mut bytes);
+                Ok(())
+            }
+        }
+    })?;
+
+    Ok(encode(bytes))
+}
+
+pub fn hash_secret(secret: &str) -> UploadTokenId {
+    encode(Sha256::digest(secret.as_bytes()))
+}
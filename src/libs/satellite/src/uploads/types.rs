@@ -0,0 +1,61 @@
+pub mod state {
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    /// The SHA-256 of the secret of the token, in hexadecimal. The secret itself is not kept by the satellite.
+    pub type UploadTokenId = String;
+
+    pub type UploadTokens = HashMap<UploadTokenId, UploadToken>;
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct UploadsHeapState {
+        pub tokens: UploadTokens,
+    }
+
+    /// A token which lets plain HTTP clients - e.g. an HTML form - upload files in a collection of the storage.
+    ///
+    /// - `owner`: The files are uploaded on behalf of this user, therefore the permissions and quotas of the
+    ///   collection apply as if the user uploaded them with a call.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct UploadToken {
+        pub collection: CollectionKey,
+        pub owner: UserId,
+        pub expires_at: Option<Timestamp>,
+        pub created_at: Timestamp,
+    }
+}
+
+pub mod interface {
+    use crate::uploads::types::state::UploadTokenId;
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use serde::Deserialize;
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct SetUploadToken {
+        pub collection: CollectionKey,
+        pub owner: UserId,
+        pub expires_at: Option<Timestamp>,
+    }
+
+    /// The secret is returned only once, when the token is created.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct CreateUploadTokenResult {
+        pub id: UploadTokenId,
+        pub secret: String,
+    }
+}
+
+pub mod runtime {
+    /// A part of a `multipart/form-data` body. `filename` is only provided for the files.
+    pub struct MultipartPart {
+        pub name: Option<String>,
+        pub filename: Option<String>,
+        pub content_type: Option<String>,
+        pub content: Vec<u8>,
+    }
+}
@@ -0,0 +1,2 @@
+// The users are imported in batches to remain within the instruction limit of an update call.
+pub const IMPORT_USERS_MAX_LENGTH: usize = 100;
@@ -0,0 +1,4 @@
+mod constants;
+mod msg;
+pub mod store;
+pub mod types;
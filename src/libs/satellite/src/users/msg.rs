@@ -0,0 +1,21 @@
+pub const ERROR_IMPORT_USERS_MAX_LENGTH: &str = "Too many users in the batch.";
+
+pub fn msg_import_user_anonymous(index: usize) -> String {
+    format!("User at index {} cannot be anonymous.", index)
+}
+
+pub fn msg_import_user_duplicate(index: usize) -> String {
+    format!("User at index {} is provided more than once.", index)
+}
+
+pub fn msg_import_user_created_in_future(index: usize) -> String {
+    format!("User at index {} cannot be created in the future.", index)
+}
+
+pub fn msg_import_user_invalid_data(index: usize) -> String {
+    format!("Data of the user at index {} is not a JSON object.", index)
+}
+
+pub fn msg_import_user_exists(index: usize) -> String {
+    format!("User at index {} already exists.", index)
+}
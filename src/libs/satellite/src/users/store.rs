@@ -0,0 +1,122 @@
+use crate::db::state::{get_doc as get_state_doc, get_rule as get_state_rule};
+use crate::db::store::import_doc_store;
+use crate::db::types::state::Doc;
+use crate::users::constants::IMPORT_USERS_MAX_LENGTH;
+use crate::users::msg::{
+    msg_import_user_anonymous, msg_import_user_created_in_future, msg_import_user_duplicate,
+    msg_import_user_exists, msg_import_user_invalid_data, ERROR_IMPORT_USERS_MAX_LENGTH,
+};
+use crate::users::types::interface::{
+    ImportUser, ImportUserConflict, ImportUsers, ImportUsersReport,
+};
+use ic_cdk::api::time;
+use junobuild_collections::constants::DEFAULT_DB_COLLECTIONS;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::constants::INITIAL_VERSION;
+use junobuild_shared::types::state::{Timestamp, UserId};
+use junobuild_shared::utils::principal_anonymous;
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Imports a batch of users - e.g. migrated from another backend - with their original identifiers, which preserves
+/// the ownership of the documents and assets imported for them.
+///
+/// The batch is validated as a whole before any user is saved. The assertions of the datastore do not apply given
+/// that the users are not created by themselves.
+pub fn import_users_store(
+    ImportUsers { users, conflict }: &ImportUsers,
+) -> Result<ImportUsersReport, String> {
+    if users.len() > IMPORT_USERS_MAX_LENGTH {
+        return Err(ERROR_IMPORT_USERS_MAX_LENGTH.to_string());
+    }
+
+    let collection: CollectionKey = DEFAULT_DB_COLLECTIONS[0].0.to_string();
+    let rule = get_state_rule(&collection)?;
+
+    let conflict = conflict.clone().unwrap_or_default();
+    let now = time();
+
+    let mut user_ids: HashSet<UserId> = HashSet::new();
+    let mut current_docs: Vec<Option<Doc>> = Vec::with_capacity(users.len());
+
+    for (index, user) in users.iter().enumerate() {
+        assert_import_user(index, user, now, &mut user_ids)?;
+
+        let current_doc = get_state_doc(&collection, &user.user_id.to_text(), &rule)?;
+
+        if current_doc.is_some() && conflict == ImportUserConflict::Reject {
+            return Err(msg_import_user_exists(index));
+        }
+
+        current_docs.push(current_doc);
+    }
+
+    let mut report = ImportUsersReport::default();
+
+    for (user, current_doc) in users.iter().zip(current_docs) {
+        if current_doc.is_some() && conflict == ImportUserConflict::Skip {
+            report.skipped.push(user.user_id);
+            continue;
+        }
+
+        let doc = prepare_user_doc(user, &current_doc, now);
+
+        import_doc_store(&collection, &user.user_id.to_text(), &doc)?;
+
+        report.imported.push(user.user_id);
+    }
+
+    Ok(report)
+}
+
+fn assert_import_user(
+    index: usize,
+    ImportUser {
+        user_id,
+        data,
+        created_at,
+    }: &ImportUser,
+    now: Timestamp,
+    user_ids: &mut HashSet<UserId>,
+) -> Result<(), String> {
+    if principal_anonymous(*user_id) {
+        return Err(msg_import_user_anonymous(index));
+    }
+
+    if !user_ids.insert(*user_id) {
+        return Err(msg_import_user_duplicate(index));
+    }
+
+    if created_at.map_or(false, |created_at| created_at > now) {
+        return Err(msg_import_user_created_in_future(index));
+    }
+
+    match serde_json::from_slice::<Value>(data) {
+        Ok(Value::Object(_)) => Ok(()),
+        _ => Err(msg_import_user_invalid_data(index)),
+    }
+}
+
+fn prepare_user_doc(user: &ImportUser, current_doc: &Option<Doc>, now: Timestamp) -> Doc {
+    let created_at = user
+        .created_at
+        .or(current_doc.as_ref().map(|doc| doc.created_at))
+        .unwrap_or(now);
+
+    let version = current_doc
+        .as_ref()
+        .map_or(INITIAL_VERSION, |doc| doc.version.unwrap_or_default() + 1);
+
+    Doc {
+        owner: user.user_id,
+        data: user.data.clone(),
+        description: None,
+        created_at,
+        updated_at: now,
+        version: Some(version),
+        publish_at: None,
+        unpublish_at: None,
+        moderation: None,
+        attachments: None,
+    }
+}
@@ -0,0 +1,42 @@
+pub mod interface {
+    use candid::CandidType;
+    use junobuild_shared::types::core::Blob;
+    use junobuild_shared::types::state::{Timestamp, UserId};
+    use serde::Deserialize;
+
+    /// A user migrated from another backend.
+    ///
+    /// - `data`: The profile of the user, as it would be provided to `set_doc` - i.e. a JSON object.
+    /// - `created_at`: When the user signed up originally. The time of the import if not provided.
+    #[derive(CandidType, Deserialize)]
+    pub struct ImportUser {
+        pub user_id: UserId,
+        pub data: Blob,
+        pub created_at: Option<Timestamp>,
+    }
+
+    /// What to do with the users that already exist in the satellite.
+    ///
+    /// - `Skip`: They are kept as they are.
+    /// - `Overwrite`: Their profile is replaced, their creation is kept unless the import provides one.
+    /// - `Reject`: The whole batch is rejected.
+    #[derive(CandidType, Deserialize, Default, Clone, PartialEq)]
+    pub enum ImportUserConflict {
+        #[default]
+        Skip,
+        Overwrite,
+        Reject,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct ImportUsers {
+        pub users: Vec<ImportUser>,
+        pub conflict: Option<ImportUserConflict>,
+    }
+
+    #[derive(CandidType, Deserialize, Default)]
+    pub struct ImportUsersReport {
+        pub imported: Vec<UserId>,
+        pub skipped: Vec<UserId>,
+    }
+}
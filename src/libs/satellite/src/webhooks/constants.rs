@@ -0,0 +1,17 @@
+pub const WEBHOOKS_MAX_LENGTH: usize = 10;
+
+// The oldest completed deliveries are dropped from the log when a new one is recorded.
+pub const WEBHOOK_DELIVERIES_MAX_LENGTH: usize = 100;
+
+pub const WEBHOOK_MAX_ATTEMPTS: u8 = 5;
+
+// The delay before the first retry, doubled for each of the following attempts.
+pub const WEBHOOK_RETRY_DELAY_SECS: u64 = 10;
+
+// The transform function reduces the response to its status, but its headers count toward the limit as well.
+pub const WEBHOOK_MAX_RESPONSE_BYTES: u64 = 16_384;
+
+pub const WEBHOOK_TRANSFORM_METHOD: &str = "transform_webhook_response";
+
+// The cost of the outcalls is estimated for the largest subnets. The cycles that are not consumed are refunded.
+pub const WEBHOOK_SUBNET_SIZE: u128 = 34;
@@ -0,0 +1,165 @@
+use crate::webhooks::constants::{
+    WEBHOOK_MAX_ATTEMPTS, WEBHOOK_MAX_RESPONSE_BYTES, WEBHOOK_RETRY_DELAY_SECS,
+    WEBHOOK_SUBNET_SIZE, WEBHOOK_TRANSFORM_METHOD,
+};
+use crate::webhooks::msg::{
+    ERROR_WEBHOOK_DISABLED, ERROR_WEBHOOK_OUTCALL_FAILED, ERROR_WEBHOOK_UNEXPECTED_STATUS,
+};
+use crate::webhooks::signature::sign_payload;
+use crate::webhooks::state::{
+    begin_delivery, end_delivery, get_delivery, get_webhook, update_delivery,
+};
+use crate::webhooks::types::state::{
+    Webhook, WebhookDelivery, WebhookDeliveryId, WebhookDeliveryStatus,
+};
+use ic_cdk::api::management_canister::http_request::{
+    http_request, CanisterHttpRequestArgument, HttpHeader, HttpMethod, TransformContext,
+};
+use ic_cdk::api::time;
+use ic_cdk::spawn;
+use ic_cdk_timers::set_timer;
+use std::time::Duration;
+
+pub fn schedule_delivery(id: WebhookDeliveryId, delay: Duration) {
+    set_timer(delay, move || spawn(deliver(id)));
+}
+
+async fn deliver(id: WebhookDeliveryId) {
+    let Some(delivery) = get_delivery(&id) else {
+        return;
+    };
+
+    if delivery.status != WebhookDeliveryStatus::Pending {
+        return;
+    }
+
+    let webhook = get_webhook(&delivery.webhook_id).filter(|webhook| webhook.enabled);
+
+    let Some(webhook) = webhook else {
+        update_delivery(
+            &id,
+            &WebhookDelivery {
+                status: WebhookDeliveryStatus::Failed,
+                error: Some(ERROR_WEBHOOK_DISABLED.to_string()),
+                updated_at: time(),
+                ..delivery
+            },
+        );
+
+        return;
+    };
+
+    if !begin_delivery(&id) {
+        return;
+    }
+
+    let attempts = delivery.attempts.saturating_add(1);
+
+    let result = send(&id, &webhook, &delivery).await;
+
+    end_delivery(&id);
+
+    let (response_status, error) = match result {
+        Ok(status) if (200..300).contains(&status) => (Some(status), None),
+        Ok(status) => (
+            Some(status),
+            Some(format!("{} ({})", ERROR_WEBHOOK_UNEXPECTED_STATUS, status)),
+        ),
+        Err(e) => (None, Some(e)),
+    };
+
+    let status = match error {
+        None => WebhookDeliveryStatus::Delivered,
+        Some(_) if attempts < WEBHOOK_MAX_ATTEMPTS => WebhookDeliveryStatus::Pending,
+        Some(_) => WebhookDeliveryStatus::Failed,
+    };
+
+    let retry = status == WebhookDeliveryStatus::Pending;
+
+    update_delivery(
+        &id,
+        &WebhookDelivery {
+            status,
+            attempts,
+            response_status,
+            error,
+            updated_at: time(),
+            ..delivery
+        },
+    );
+
+    if retry {
+        schedule_delivery(id, retry_delay(attempts));
+    }
+}
+
+/// Each replica of the subnet performs the outcall, therefore an endpoint can receive the same attempt several times. The
+/// endpoints are expected to use the `X-Juno-Delivery` header - unique per delivery and identical across replicas and
+/// attempts - as an idempotency key.
+async fn send(
+    id: &WebhookDeliveryId,
+    webhook: &Webhook,
+    delivery: &WebhookDelivery,
+) -> Result<u16, String> {
+    let timestamp = time().to_string();
+    let signature = sign_payload(&webhook.secret, &timestamp, &delivery.payload);
+
+    let mut headers = vec![
+        header("Content-Type", "application/json"),
+        header("X-Juno-Delivery", &id.to_string()),
+        header("X-Juno-Timestamp", &timestamp),
+        header("X-Juno-Signature", &format!("sha256={}", signature)),
+    ];
+
+    if let Some(request_id) = &delivery.request_id {
+        headers.push(header("X-Juno-Request-Id", request_id));
+    }
+
+    let request = CanisterHttpRequestArgument {
+        url: webhook.url.clone(),
+        max_response_bytes: Some(WEBHOOK_MAX_RESPONSE_BYTES),
+        method: HttpMethod::POST,
+        headers,
+        body: Some(delivery.payload.as_bytes().to_vec()),
+        transform: Some(TransformContext::from_name(
+            WEBHOOK_TRANSFORM_METHOD.to_string(),
+            vec![],
+        )),
+    };
+
+    let cycles = outcall_cycles(&request);
+
+    let (response,) = http_request(request, cycles)
+        .await
+        .map_err(|(_, message)| format!("{} ({})", ERROR_WEBHOOK_OUTCALL_FAILED, message))?;
+
+    Ok(u16::try_from(&response.status.0).unwrap_or_default())
+}
+
+fn header(name: &str, value: &str) -> HttpHeader {
+    HttpHeader {
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+fn retry_delay(attempts: u8) -> Duration {
+    Duration::from_secs(WEBHOOK_RETRY_DELAY_SECS << attempts.saturating_sub(1))
+}
+
+/// The cost of an HTTP outcall according to the size of its request and the maximal size of its response.
+fn outcall_cycles(request: &CanisterHttpRequestArgument) -> u128 {
+    let request_bytes = request.url.len()
+        + request
+            .headers
+            .iter()
+            .map(|HttpHeader { name, value }| name.len() + value.len())
+            .sum::<usize>()
+        + request.body.as_ref().map_or(0, |body| body.len());
+
+    let n = WEBHOOK_SUBNET_SIZE;
+
+    (3_000_000 + 60_000 * n) * n
+        + 400 * n * request_bytes as u128
+        + 800 * n * WEBHOOK_MAX_RESPONSE_BYTES as u128
+}
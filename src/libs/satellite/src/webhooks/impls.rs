@@ -0,0 +1,15 @@
+use crate::webhooks::types::interface::WebhookNoSecret;
+use crate::webhooks::types::state::Webhook;
+
+impl From<&Webhook> for WebhookNoSecret {
+    fn from(webhook: &Webhook) -> Self {
+        WebhookNoSecret {
+            url: webhook.url.clone(),
+            events: webhook.events.clone(),
+            collections: webhook.collections.clone(),
+            enabled: webhook.enabled,
+            created_at: webhook.created_at,
+            updated_at: webhook.updated_at,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod constants;
+mod delivery;
+mod impls;
+mod msg;
+mod payload;
+mod signature;
+mod state;
+pub mod store;
+pub mod types;
@@ -0,0 +1,8 @@
+pub const ERROR_WEBHOOK_INVALID_URL: &str = "Webhook URL must be a valid https URL.";
+pub const ERROR_WEBHOOK_NO_EVENTS: &str = "Webhooks must subscribe to at least one event.";
+pub const ERROR_WEBHOOK_MISSING_SECRET: &str = "Webhook secret missing or empty.";
+pub const ERROR_WEBHOOK_MAX_WEBHOOKS: &str = "Maximum number of webhooks reached.";
+pub const ERROR_WEBHOOK_NOT_FOUND: &str = "Webhook not found.";
+pub const ERROR_WEBHOOK_DISABLED: &str = "Webhook deleted or disabled before the delivery.";
+pub const ERROR_WEBHOOK_UNEXPECTED_STATUS: &str = "Webhook endpoint responded with an error.";
+pub const ERROR_WEBHOOK_OUTCALL_FAILED: &str = "Webhook HTTP outcall failed.";
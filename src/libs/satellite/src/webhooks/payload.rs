@@ -0,0 +1,103 @@
+use crate::db::types::state::{Doc, DocContext};
+use crate::events::types::interface::SatelliteEvent;
+use crate::requests::types::state::RequestId;
+use crate::webhooks::types::payload::{WebhookChange, WebhookPayload};
+use crate::webhooks::types::state::WebhookEvent;
+use ic_cdk::api::time;
+use ic_cdk::id;
+use junobuild_collections::types::core::CollectionKey;
+use junobuild_shared::types::core::Key;
+use junobuild_shared::types::state::UserId;
+use junobuild_storage::types::store::Asset;
+
+/// The changes of the datastore and the storage covered by the webhooks. The other events are not delivered.
+pub fn event_changes(event: &SatelliteEvent) -> Option<(WebhookEvent, Vec<WebhookChange>)> {
+    match event {
+        SatelliteEvent::SetDoc(context) => Some((
+            WebhookEvent::SetDoc,
+            vec![doc_change(
+                &context.collection,
+                &context.key,
+                &context.data.after,
+            )],
+        )),
+        SatelliteEvent::SetManyDocs(contexts) => Some((
+            WebhookEvent::SetDoc,
+            contexts
+                .iter()
+                .map(|context| doc_change(&context.collection, &context.key, &context.data.after))
+                .collect(),
+        )),
+        SatelliteEvent::DeleteDoc(context) => Some((
+            WebhookEvent::DeleteDoc,
+            deleted_doc_changes(std::slice::from_ref(context)),
+        )),
+        SatelliteEvent::DeleteManyDocs(contexts) | SatelliteEvent::DeleteFilteredDocs(contexts) => {
+            Some((WebhookEvent::DeleteDoc, deleted_doc_changes(contexts)))
+        }
+        SatelliteEvent::UploadAsset(asset) => {
+            Some((WebhookEvent::UploadAsset, vec![asset_change(asset)]))
+        }
+        SatelliteEvent::DeleteAsset(asset) => Some((
+            WebhookEvent::DeleteAsset,
+            asset.iter().map(asset_change).collect(),
+        )),
+        SatelliteEvent::DeleteManyAssets(assets) | SatelliteEvent::DeleteFilteredAssets(assets) => {
+            Some((
+                WebhookEvent::DeleteAsset,
+                assets.iter().flatten().map(asset_change).collect(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+pub fn create_payload(
+    caller: &UserId,
+    event: &WebhookEvent,
+    changes: Vec<WebhookChange>,
+    request_id: &Option<RequestId>,
+) -> Result<String, String> {
+    let payload = WebhookPayload {
+        event: event.clone(),
+        satellite_id: id().to_text(),
+        caller: caller.to_text(),
+        timestamp: time().to_string(),
+        request_id: request_id.clone(),
+        changes,
+    };
+
+    serde_json::to_string(&payload).map_err(|e| e.to_string())
+}
+
+fn deleted_doc_changes(contexts: &[DocContext<Option<Doc>>]) -> Vec<WebhookChange> {
+    contexts
+        .iter()
+        .filter_map(|context| {
+            context
+                .data
+                .as_ref()
+                .map(|doc| doc_change(&context.collection, &context.key, doc))
+        })
+        .collect()
+}
+
+fn doc_change(collection: &CollectionKey, key: &Key, doc: &Doc) -> WebhookChange {
+    WebhookChange {
+        collection: collection.clone(),
+        key: key.clone(),
+        owner: doc.owner.to_text(),
+        version: doc.version.map(|version| version.to_string()),
+        updated_at: doc.updated_at.to_string(),
+    }
+}
+
+fn asset_change(asset: &Asset) -> WebhookChange {
+    WebhookChange {
+        collection: asset.key.collection.clone(),
+        key: asset.key.full_path.clone(),
+        owner: asset.key.owner.to_text(),
+        version: asset.version.map(|version| version.to_string()),
+        updated_at: asset.updated_at.to_string(),
+    }
+}
@@ -0,0 +1,17 @@
+use hex::encode;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Signs the timestamp and the body of a delivery - i.e. `{timestamp}.{body}` - with HMAC-SHA256, in hexadecimal.
+///
+/// Including the timestamp lets the endpoints reject the payloads that are replayed later on.
+pub fn sign_payload(secret: &str, timestamp: &str, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take a key of any size");
+
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+
+    encode(mac.finalize().into_bytes())
+}
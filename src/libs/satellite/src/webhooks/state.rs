@@ -0,0 +1,147 @@
+use crate::memory::STATE;
+use crate::webhooks::constants::WEBHOOK_DELIVERIES_MAX_LENGTH;
+use crate::webhooks::types::state::{
+    Webhook, WebhookDeliveries, WebhookDelivery, WebhookDeliveryId, WebhookDeliveryStatus,
+    WebhookId, Webhooks, WebhooksHeapState,
+};
+
+// ---------------------------------------------------------
+// Webhooks
+// ---------------------------------------------------------
+
+pub fn get_webhook(id: &WebhookId) -> Option<Webhook> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .webhooks
+            .as_ref()
+            .and_then(|webhooks| webhooks.webhooks.get(id).cloned())
+    })
+}
+
+pub fn get_webhooks() -> Webhooks {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .webhooks
+            .as_ref()
+            .map(|webhooks| webhooks.webhooks.clone())
+            .unwrap_or_default()
+    })
+}
+
+pub fn insert_webhook(id: &WebhookId, webhook: &Webhook) {
+    STATE.with(|state| insert_webhook_impl(id, webhook, &mut state.borrow_mut().heap.webhooks))
+}
+
+pub fn delete_webhook(id: &WebhookId) {
+    STATE.with(|state| delete_webhook_impl(id, &mut state.borrow_mut().heap.webhooks))
+}
+
+fn insert_webhook_impl(id: &WebhookId, webhook: &Webhook, state: &mut Option<WebhooksHeapState>) {
+    state
+        .get_or_insert_with(WebhooksHeapState::default)
+        .webhooks
+        .insert(id.clone(), webhook.clone());
+}
+
+fn delete_webhook_impl(id: &WebhookId, state: &mut Option<WebhooksHeapState>) {
+    if let Some(state) = state {
+        state.webhooks.remove(id);
+    }
+}
+
+// ---------------------------------------------------------
+// Deliveries
+// ---------------------------------------------------------
+
+pub fn get_delivery(id: &WebhookDeliveryId) -> Option<WebhookDelivery> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .webhooks
+            .as_ref()
+            .and_then(|webhooks| webhooks.deliveries.get(id).cloned())
+    })
+}
+
+pub fn get_deliveries() -> WebhookDeliveries {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .webhooks
+            .as_ref()
+            .map(|webhooks| webhooks.deliveries.clone())
+            .unwrap_or_default()
+    })
+}
+
+/// Appends a delivery to the log and returns its identifier, or `None` if the log is full of pending deliveries.
+pub fn insert_delivery(delivery: &WebhookDelivery) -> Option<WebhookDeliveryId> {
+    STATE.with(|state| insert_delivery_impl(delivery, &mut state.borrow_mut().heap.webhooks))
+}
+
+/// Updates a delivery, unless it has been dropped from the log in the meantime.
+pub fn update_delivery(id: &WebhookDeliveryId, delivery: &WebhookDelivery) {
+    STATE.with(|state| update_delivery_impl(id, delivery, &mut state.borrow_mut().heap.webhooks))
+}
+
+fn insert_delivery_impl(
+    delivery: &WebhookDelivery,
+    state: &mut Option<WebhooksHeapState>,
+) -> Option<WebhookDeliveryId> {
+    let deliveries = &mut state
+        .get_or_insert_with(WebhooksHeapState::default)
+        .deliveries;
+
+    // Only the deliveries that are completed - i.e. delivered or failed - can be dropped, the pending ones are still
+    // scheduled.
+    if deliveries.len() >= WEBHOOK_DELIVERIES_MAX_LENGTH {
+        let completed = deliveries
+            .iter()
+            .find(|(_, delivery)| delivery.status != WebhookDeliveryStatus::Pending)
+            .map(|(id, _)| *id)?;
+
+        deliveries.remove(&completed);
+    }
+
+    let id = deliveries
+        .last_key_value()
+        .map_or(0, |(last_id, _)| last_id + 1);
+
+    deliveries.insert(id, delivery.clone());
+
+    Some(id)
+}
+
+fn update_delivery_impl(
+    id: &WebhookDeliveryId,
+    delivery: &WebhookDelivery,
+    state: &mut Option<WebhooksHeapState>,
+) {
+    if let Some(current) = state
+        .as_mut()
+        .and_then(|state| state.deliveries.get_mut(id))
+    {
+        *current = delivery.clone();
+    }
+}
+
+// ---------------------------------------------------------
+// Runtime
+// ---------------------------------------------------------
+
+/// Marks a delivery as being sent. Returns `false` if it is already in flight.
+pub fn begin_delivery(id: &WebhookDeliveryId) -> bool {
+    STATE.with(|state| state.borrow_mut().runtime.webhooks.in_flight.insert(*id))
+}
+
+pub fn end_delivery(id: &WebhookDeliveryId) {
+    STATE.with(|state| {
+        state.borrow_mut().runtime.webhooks.in_flight.remove(id);
+    })
+}
@@ -0,0 +1,172 @@
+use crate::events::types::interface::SatelliteEvent;
+use crate::requests::store::get_request_id;
+use crate::webhooks::constants::WEBHOOKS_MAX_LENGTH;
+use crate::webhooks::delivery::schedule_delivery;
+use crate::webhooks::msg::{
+    ERROR_WEBHOOK_INVALID_URL, ERROR_WEBHOOK_MAX_WEBHOOKS, ERROR_WEBHOOK_MISSING_SECRET,
+    ERROR_WEBHOOK_NOT_FOUND, ERROR_WEBHOOK_NO_EVENTS,
+};
+use crate::webhooks::payload::{create_payload, event_changes};
+use crate::webhooks::state::{
+    delete_webhook, get_deliveries, get_webhook, get_webhooks, insert_delivery, insert_webhook,
+};
+use crate::webhooks::types::interface::{SetWebhook, WebhookNoSecret};
+use crate::webhooks::types::state::{
+    Webhook, WebhookDelivery, WebhookDeliveryId, WebhookDeliveryStatus, WebhookId,
+};
+use ic_cdk::api::management_canister::http_request::{HttpResponse, TransformArgs};
+use ic_cdk::api::time;
+use junobuild_shared::types::state::UserId;
+use std::time::Duration;
+use url::Url;
+
+// ---------------------------------------------------------
+// Webhooks
+// ---------------------------------------------------------
+
+pub fn set_webhook_store(id: &WebhookId, webhook: &SetWebhook) -> Result<(), String> {
+    let current = get_webhook(id);
+
+    if current.is_none() && get_webhooks().len() >= WEBHOOKS_MAX_LENGTH {
+        return Err(ERROR_WEBHOOK_MAX_WEBHOOKS.to_string());
+    }
+
+    assert_url(&webhook.url)?;
+
+    if webhook.events.is_empty() {
+        return Err(ERROR_WEBHOOK_NO_EVENTS.to_string());
+    }
+
+    let secret = webhook
+        .secret
+        .clone()
+        .or_else(|| current.as_ref().map(|current| current.secret.clone()))
+        .filter(|secret| !secret.is_empty())
+        .ok_or_else(|| ERROR_WEBHOOK_MISSING_SECRET.to_string())?;
+
+    let now = time();
+
+    insert_webhook(
+        id,
+        &Webhook {
+            url: webhook.url.clone(),
+            secret,
+            events: webhook.events.clone(),
+            collections: webhook.collections.clone(),
+            enabled: webhook.enabled,
+            created_at: current.map_or(now, |current| current.created_at),
+            updated_at: now,
+        },
+    );
+
+    Ok(())
+}
+
+pub fn list_webhooks_store() -> Vec<(WebhookId, WebhookNoSecret)> {
+    get_webhooks()
+        .into_iter()
+        .map(|(id, webhook)| (id, WebhookNoSecret::from(&webhook)))
+        .collect()
+}
+
+pub fn delete_webhook_store(id: &WebhookId) -> Result<(), String> {
+    if get_webhook(id).is_none() {
+        return Err(ERROR_WEBHOOK_NOT_FOUND.to_string());
+    }
+
+    delete_webhook(id);
+
+    Ok(())
+}
+
+fn assert_url(url: &str) -> Result<(), String> {
+    match Url::parse(url) {
+        Ok(url) if url.scheme() == "https" && url.host().is_some() => Ok(()),
+        _ => Err(ERROR_WEBHOOK_INVALID_URL.to_string()),
+    }
+}
+
+// ---------------------------------------------------------
+// Deliveries
+// ---------------------------------------------------------
+
+pub fn list_webhook_deliveries_store() -> Vec<(WebhookDeliveryId, WebhookDelivery)> {
+    get_deliveries().into_iter().collect()
+}
+
+/// Records a delivery for each enabled webhook that observes the event and the collections of its changes. The
+/// deliveries are sent asynchronously - i.e. once the change has been applied.
+pub fn notify_webhooks(caller: &UserId, event: &SatelliteEvent) {
+    let Some((webhook_event, changes)) = event_changes(event) else {
+        return;
+    };
+
+    let request_id = get_request_id();
+
+    for (webhook_id, webhook) in get_webhooks() {
+        if !webhook.enabled || !webhook.events.contains(&webhook_event) {
+            continue;
+        }
+
+        let changes: Vec<_> = changes
+            .iter()
+            .filter(|change| {
+                webhook
+                    .collections
+                    .as_ref()
+                    .map_or(true, |collections| collections.contains(&change.collection))
+            })
+            .cloned()
+            .collect();
+
+        if changes.is_empty() {
+            continue;
+        }
+
+        // The payload only contains text, therefore its serialization is not expected to fail
+        let Ok(payload) = create_payload(caller, &webhook_event, changes, &request_id) else {
+            continue;
+        };
+
+        let now = time();
+
+        // The event is not delivered to the webhook if the log is full of deliveries that are still pending
+        let Some(id) = insert_delivery(&WebhookDelivery {
+            webhook_id,
+            event: webhook_event.clone(),
+            payload,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            response_status: None,
+            error: None,
+            request_id: request_id.clone(),
+            created_at: now,
+            updated_at: now,
+        }) else {
+            continue;
+        };
+
+        schedule_delivery(id, Duration::ZERO);
+    }
+}
+
+/// The timers do not survive an upgrade, therefore the deliveries that were still pending are scheduled again.
+pub fn resume_webhook_deliveries() {
+    for (id, delivery) in get_deliveries() {
+        if delivery.status == WebhookDeliveryStatus::Pending {
+            schedule_delivery(id, Duration::ZERO);
+        }
+    }
+}
+
+/// Reduces the responses of the endpoints to their status, for the replicas to reach a consensus regardless of the
+/// headers - e.g. dates - and bodies.
+pub fn transform_webhook_response_store(
+    TransformArgs { response, .. }: TransformArgs,
+) -> HttpResponse {
+    HttpResponse {
+        status: response.status,
+        headers: vec![],
+        body: vec![],
+    }
+}
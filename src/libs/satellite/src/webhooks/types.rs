@@ -0,0 +1,136 @@
+pub mod state {
+    use crate::requests::types::state::RequestId;
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::state::Timestamp;
+    use serde::{Deserialize, Serialize};
+    use std::collections::{BTreeMap, HashMap, HashSet};
+
+    pub type WebhookId = String;
+
+    pub type Webhooks = HashMap<WebhookId, Webhook>;
+
+    pub type WebhookDeliveryId = u64;
+
+    pub type WebhookDeliveries = BTreeMap<WebhookDeliveryId, WebhookDelivery>;
+
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct WebhooksHeapState {
+        pub webhooks: Webhooks,
+        pub deliveries: WebhookDeliveries,
+    }
+
+    /// The deliveries whose outcall is awaited, for a delivery not to be sent twice concurrently - e.g. when it is
+    /// resumed after an upgrade while a retry was scheduled.
+    #[derive(Default, Clone)]
+    pub struct WebhooksRuntimeState {
+        pub in_flight: HashSet<WebhookDeliveryId>,
+    }
+
+    /// An endpoint notified of the changes of the datastore and the storage.
+    ///
+    /// - `secret`: The key of the HMAC-SHA256 signature of the payloads, which lets the endpoint verify that those
+    ///   were sent by the satellite.
+    /// - `collections`: The collections to observe. All collections are observed if none is provided.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct Webhook {
+        pub url: String,
+        pub secret: String,
+        pub events: Vec<WebhookEvent>,
+        pub collections: Option<Vec<CollectionKey>>,
+        pub enabled: bool,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq)]
+    pub enum WebhookEvent {
+        SetDoc,
+        DeleteDoc,
+        UploadAsset,
+        DeleteAsset,
+    }
+
+    /// The delivery of a payload to a webhook. It remains pending until it is accepted by the endpoint or until the
+    /// last attempt failed. The id of the request that triggered it is sent along, to correlate it with the call.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct WebhookDelivery {
+        pub webhook_id: WebhookId,
+        pub event: WebhookEvent,
+        pub payload: String,
+        pub status: WebhookDeliveryStatus,
+        pub attempts: u8,
+        pub response_status: Option<u16>,
+        pub error: Option<String>,
+        pub request_id: Option<RequestId>,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq)]
+    pub enum WebhookDeliveryStatus {
+        Pending,
+        Delivered,
+        Failed,
+    }
+}
+
+pub mod interface {
+    use crate::webhooks::types::state::WebhookEvent;
+    use candid::CandidType;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::state::Timestamp;
+    use serde::Deserialize;
+
+    /// The secret is required to create a webhook. It is kept as is when not provided to update one.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct SetWebhook {
+        pub url: String,
+        pub secret: Option<String>,
+        pub events: Vec<WebhookEvent>,
+        pub collections: Option<Vec<CollectionKey>>,
+        pub enabled: bool,
+    }
+
+    /// A webhook as listed - i.e. without its secret.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct WebhookNoSecret {
+        pub url: String,
+        pub events: Vec<WebhookEvent>,
+        pub collections: Option<Vec<CollectionKey>>,
+        pub enabled: bool,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+    }
+}
+
+pub mod payload {
+    use crate::requests::types::state::RequestId;
+    use crate::webhooks::types::state::WebhookEvent;
+    use junobuild_collections::types::core::CollectionKey;
+    use junobuild_shared::types::core::Key;
+    use serde::Serialize;
+
+    // The JSON delivered to the webhooks. The principals are encoded as text and the numbers as strings, given that the
+    // timestamps in nanoseconds exceed the precision of the numbers in JavaScript.
+
+    #[derive(Serialize)]
+    pub struct WebhookPayload {
+        pub event: WebhookEvent,
+        pub satellite_id: String,
+        pub caller: String,
+        pub timestamp: String,
+        pub request_id: Option<RequestId>,
+        pub changes: Vec<WebhookChange>,
+    }
+
+    /// A document or an asset - in which case the key is its full path - that has been set or deleted.
+    #[derive(Serialize, Clone)]
+    pub struct WebhookChange {
+        pub collection: CollectionKey,
+        pub key: Key,
+        pub owner: String,
+        pub version: Option<String>,
+        pub updated_at: String,
+    }
+}
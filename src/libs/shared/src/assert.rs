@@ -1,11 +1,13 @@
 use crate::canister::memory_size;
+use crate::errors::JunoError;
 use crate::msg::{
-    ERROR_NO_TIMESTAMP, ERROR_NO_VERSION, ERROR_TIMESTAMP_OUTDATED_OR_FUTURE,
-    ERROR_VERSION_OUTDATED_OR_FUTURE,
+    ERROR_DESCRIPTION_TOO_LONG, ERROR_HEAP_MEMORY_EXCEEDED, ERROR_NO_TIMESTAMP, ERROR_NO_VERSION,
+    ERROR_STABLE_MEMORY_EXCEEDED, ERROR_TIMESTAMP_OUTDATED_OR_FUTURE,
+    ERROR_UNPUBLISH_BEFORE_PUBLISH, ERROR_VERSION_OUTDATED_OR_FUTURE,
 };
 use crate::types::config::ConfigMaxMemorySize;
 use crate::types::interface::MemorySize;
-use crate::types::state::Version;
+use crate::types::state::{Timestamp, Version};
 
 /// Asserts the validity of a given user timestamp against the current timestamp.
 /// e.g. the timestamp of an existing entity persisted in a smart contract.
@@ -77,12 +79,11 @@ pub fn assert_timestamp(user_timestamp: Option<u64>, current_timestamp: u64) ->
 /// # Returns
 /// - `Ok(())` if the `user_version` matches the `current_version` or if no specific `current_version` is provided.
 /// - `Err(String)` if:
-///   - The `user_version` is `None`, indicating no version was provided. The error string
-///     will be `ERROR_NO_VERSION.to_string()`, where `ERROR_NO_VERSION` is a constant string
-///     describing the error.
+///   - The `user_version` is `None`, indicating no version was provided. The error is a `JunoError`
+///     with the code `ERROR_NO_VERSION`.
 ///   - The `user_version` does not match the `current_version`, indicating either an incorrect
-///     or incompatible version. The error string will format to include the error description
-///     (from a constant `ERROR_VERSION_MISMATCH`), the required version, and the provided user version.
+///     or incompatible version. The error is a `JunoError` with the code `ERROR_VERSION_OUTDATED_OR_FUTURE`
+///     and the parameters `current_version` and `user_version`.
 ///
 /// # Examples
 /// ```
@@ -106,14 +107,20 @@ pub fn assert_version(
         None => (),
         Some(current_version) => match user_version {
             None => {
-                return Err(ERROR_NO_VERSION.to_string());
+                return Err(JunoError::new(ERROR_NO_VERSION, "No version provided.").into());
             }
             Some(user_version) => {
                 if current_version != user_version {
-                    return Err(format!(
-                        "{} ({} - {})",
-                        ERROR_VERSION_OUTDATED_OR_FUTURE, current_version, user_version
-                    ));
+                    return Err(JunoError::new(
+                        ERROR_VERSION_OUTDATED_OR_FUTURE,
+                        format!(
+                            "Version is outdated or in the future ({} - {}).",
+                            current_version, user_version
+                        ),
+                    )
+                    .with_param("current_version", current_version)
+                    .with_param("user_version", user_version)
+                    .into());
                 }
             }
         },
@@ -136,7 +143,7 @@ pub fn assert_version(
 /// # Returns
 ///
 /// - `Ok(())`: If the description is valid (either `None` or within the length limit).
-/// - `Err(String)`: If the description exceeds 1024 characters, containing an error message.
+/// - `Err(String)`: If the description exceeds 1024 characters, containing a `JunoError` with the code `ERROR_DESCRIPTION_TOO_LONG`.
 ///
 /// # Examples
 ///
@@ -145,10 +152,7 @@ pub fn assert_version(
 /// assert_eq!(assert_description_length(&valid_description), Ok(()));
 ///
 /// let invalid_description = Some(String::from("a".repeat(1025)));
-/// assert_eq!(
-///     assert_description_length(&invalid_description),
-///     Err(String::from("Description field should not contains more than 1024 characters."))
-/// );
+/// assert!(assert_description_length(&invalid_description).is_err());
 ///
 /// let none_description: Option<String> = None;
 /// assert_eq!(assert_description_length(&none_description), Ok(()));
@@ -158,9 +162,12 @@ pub fn assert_description_length(description: &Option<String>) -> Result<(), Str
         None => (),
         Some(description) => {
             if description.len() > 1024 {
-                return Err(
-                    "Description field should not contains more than 1024 characters.".to_string(),
-                );
+                return Err(JunoError::new(
+                    ERROR_DESCRIPTION_TOO_LONG,
+                    "Description field should not contains more than 1024 characters.",
+                )
+                .with_param("max_length", 1024)
+                .into());
             }
         }
     }
@@ -168,6 +175,49 @@ pub fn assert_description_length(description: &Option<String>) -> Result<(), Str
     Ok(())
 }
 
+/// Validates the publication schedule of entities such as documents or assets.
+///
+/// Ensures that, when both are provided, the `unpublish_at` timestamp comes after the `publish_at` timestamp.
+///
+/// # Parameters
+///
+/// - `publish_at`: An optional timestamp from which the entity is published.
+/// - `unpublish_at`: An optional timestamp from which the entity is no longer published.
+///
+/// # Returns
+///
+/// - `Ok(())`: If the schedule is valid.
+/// - `Err(String)`: If the entity would be unpublished before or when it gets published.
+///
+/// # Examples
+///
+/// ```
+/// assert!(assert_schedule(&Some(1000), &Some(2000)).is_ok());
+/// assert!(assert_schedule(&None, &Some(2000)).is_ok());
+/// assert!(assert_schedule(&Some(2000), &Some(1000)).is_err());
+/// ```
+pub fn assert_schedule(
+    publish_at: &Option<Timestamp>,
+    unpublish_at: &Option<Timestamp>,
+) -> Result<(), String> {
+    if let (Some(publish_at), Some(unpublish_at)) = (publish_at, unpublish_at) {
+        if unpublish_at <= publish_at {
+            return Err(JunoError::new(
+                ERROR_UNPUBLISH_BEFORE_PUBLISH,
+                format!(
+                    "Unpublish timestamp must be after the publish timestamp ({} - {}).",
+                    publish_at, unpublish_at
+                ),
+            )
+            .with_param("publish_at", publish_at)
+            .with_param("unpublish_at", unpublish_at)
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
 pub fn assert_max_memory_size(
     config_max_memory_size: &Option<ConfigMaxMemorySize>,
 ) -> Result<(), String> {
@@ -176,19 +226,31 @@ pub fn assert_max_memory_size(
 
         if let Some(max_heap) = max_memory_size.heap {
             if heap > max_heap {
-                return Err(format!(
-                    "Heap memory usage exceeded: {} bytes used, {} bytes allowed.",
-                    heap, max_heap
-                ));
+                return Err(JunoError::new(
+                    ERROR_HEAP_MEMORY_EXCEEDED,
+                    format!(
+                        "Heap memory usage exceeded: {} bytes used, {} bytes allowed.",
+                        heap, max_heap
+                    ),
+                )
+                .with_param("used", heap)
+                .with_param("allowed", max_heap)
+                .into());
             }
         }
 
         if let Some(max_stable) = max_memory_size.stable {
             if stable > max_stable {
-                return Err(format!(
-                    "Stable memory usage exceeded: {} bytes used, {} bytes allowed.",
-                    stable, max_stable
-                ));
+                return Err(JunoError::new(
+                    ERROR_STABLE_MEMORY_EXCEEDED,
+                    format!(
+                        "Stable memory usage exceeded: {} bytes used, {} bytes allowed.",
+                        stable, max_stable
+                    ),
+                )
+                .with_param("used", stable)
+                .with_param("allowed", max_stable)
+                .into());
             }
         }
     }
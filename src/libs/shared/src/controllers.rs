@@ -1,7 +1,9 @@
 use crate::constants::REVOKED_CONTROLLERS;
 use crate::env::{CONSOLE, OBSERVATORY};
 use crate::types::interface::SetController;
-use crate::types::state::{Controller, ControllerId, ControllerScope, Controllers, UserId};
+use crate::types::state::{
+    Controller, ControllerId, ControllerScope, Controllers, Timestamp, UserId,
+};
 use crate::utils::{principal_anonymous, principal_equal, principal_not_anonymous};
 use candid::Principal;
 use ic_cdk::api::{is_controller as is_canister_controller, time};
@@ -82,13 +84,15 @@ pub fn delete_controllers(remove_controllers: &[UserId], controllers: &mut Contr
 /// - `controllers`: Reference to the current set of controllers.
 ///
 /// # Returns
-/// `true` if the caller is a controller (not anonymous, calling itself or one of the known controllers), otherwise `false`.
+/// `true` if the caller is a controller (not anonymous, calling itself or one of the known controllers that has not expired), otherwise `false`.
 pub fn is_controller(caller: UserId, controllers: &Controllers) -> bool {
+    let now = time();
+
     principal_not_anonymous(caller)
         && (caller_is_self(caller)
-            || controllers
-                .iter()
-                .any(|(&controller_id, _)| principal_equal(controller_id, caller)))
+            || controllers.iter().any(|(&controller_id, controller)| {
+                principal_equal(controller_id, caller) && !controller_expired(controller, now)
+            }))
 }
 
 /// Checks if a caller is an admin controller.
@@ -98,15 +102,19 @@ pub fn is_controller(caller: UserId, controllers: &Controllers) -> bool {
 /// - `controllers`: Reference to the current set of controllers.
 ///
 /// # Returns
-/// `true` if the caller is an admin controller, otherwise `false`.
+/// `true` if the caller is an admin controller that has not expired, otherwise `false`.
 pub fn is_admin_controller(caller: UserId, controllers: &Controllers) -> bool {
+    let now = time();
+
     is_canister_controller(&caller)
         && principal_not_anonymous(caller)
         && controllers
             .iter()
             .any(|(&controller_id, controller)| match controller.scope {
                 ControllerScope::Write => false,
-                ControllerScope::Admin => principal_equal(controller_id, caller),
+                ControllerScope::Admin => {
+                    principal_equal(controller_id, caller) && !controller_expired(controller, now)
+                }
             })
 }
 
@@ -205,6 +213,54 @@ fn assert_no_revoked_controller(controllers_ids: &[ControllerId]) -> Result<(),
     }
 }
 
+/// Asserts that the expiration of a controller, if any, is set in the future.
+///
+/// # Arguments
+/// - `controller`: `SetController` data to validate.
+///
+/// # Returns
+/// `Ok(())` if the controller does not expire or expires in the future, or `Err(String)` if the expiration is already past.
+pub fn assert_controller_expiration(controller: &SetController) -> Result<(), String> {
+    match controller.expires_at {
+        Some(expires_at) if expires_at <= time() => {
+            Err("The expiration of the controller must be in the future.".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Collects the controllers whose expiration has passed.
+///
+/// # Arguments
+/// - `controllers`: Reference to the current set of controllers.
+/// - `now`: The current timestamp.
+///
+/// # Returns
+/// A vector of the `ControllerId` that have expired.
+pub fn filter_expired_controllers(controllers: &Controllers, now: Timestamp) -> Vec<ControllerId> {
+    controllers
+        .iter()
+        .filter(|(_, controller)| controller_expired(controller, now))
+        .map(|(controller_id, _)| *controller_id)
+        .collect()
+}
+
+/// Finds the earliest expiration among the controllers that have not yet expired.
+///
+/// # Arguments
+/// - `controllers`: Reference to the current set of controllers.
+/// - `now`: The current timestamp.
+///
+/// # Returns
+/// The next expiration `Timestamp`, or `None` if no controller is set to expire.
+pub fn next_controller_expiration(controllers: &Controllers, now: Timestamp) -> Option<Timestamp> {
+    controllers
+        .values()
+        .filter_map(|controller| controller.expires_at)
+        .filter(|expires_at| *expires_at > now)
+        .min()
+}
+
 /// Checks if the caller is the console.
 ///
 /// # Arguments
@@ -262,6 +318,12 @@ pub fn filter_admin_controllers(controllers: &Controllers) -> Controllers {
         .collect()
 }
 
+fn controller_expired(controller: &Controller, now: Timestamp) -> bool {
+    controller
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= now)
+}
+
 fn controller_revoked(controller_id: &ControllerId) -> bool {
     REVOKED_CONTROLLERS.iter().any(|revoked_controller_id| {
         principal_equal(
@@ -0,0 +1,83 @@
+use crate::msg::ERROR_UNKNOWN;
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// The version of the JSON encoding of the errors, increased with each breaking change of their structure.
+pub const JUNO_ERROR_VERSION: u8 = 1;
+
+/// A validation error that frontends can localize.
+///
+/// Errors are still surfaced as `String` - the canister rejects with the message - but a `JunoError` is encoded
+/// as JSON, e.g. `{"version":1,"code":"error_cannot_write","message":"Cannot write.","params":{}}`. The
+/// machine-readable `code` and its `params` can be used to display a translated message, the `message` being the
+/// English fallback.
+///
+/// The satellite rejects all its calls with this encoding - the errors without a specific code being reported with
+/// `error_unknown` - starting with the version 1 of the encoding.
+///
+/// Serverless functions can return such errors from their assertions to provide the same structure to the clients.
+///
+/// # Examples
+///
+/// ```
+/// use junobuild_shared::errors::JunoError;
+///
+/// let error: String = JunoError::new("error_title_too_long", "Title is too long.")
+///     .with_param("max_length", 100)
+///     .into();
+/// ```
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct JunoError {
+    #[serde(default = "default_version")]
+    pub version: u8,
+    pub code: String,
+    pub message: String,
+    pub params: BTreeMap<String, String>,
+}
+
+impl JunoError {
+    pub fn new(code: &str, message: impl Into<String>) -> Self {
+        JunoError {
+            version: JUNO_ERROR_VERSION,
+            code: code.to_string(),
+            message: message.into(),
+            params: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_param(mut self, name: &str, value: impl ToString) -> Self {
+        self.params.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Decodes an error previously encoded as a `String`. Returns `None` for plain text errors.
+    pub fn decode(error: &str) -> Option<Self> {
+        serde_json::from_str(error).ok()
+    }
+
+    /// Decodes an error previously encoded as a `String` or wraps a plain text error with the code `error_unknown`.
+    pub fn from_message(error: &str) -> Self {
+        Self::decode(error).unwrap_or_else(|| JunoError::new(ERROR_UNKNOWN, error))
+    }
+}
+
+fn default_version() -> u8 {
+    JUNO_ERROR_VERSION
+}
+
+impl Display for JunoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match serde_json::to_string(self) {
+            Ok(json) => write!(f, "{}", json),
+            Err(_) => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl From<JunoError> for String {
+    fn from(error: JunoError) -> Self {
+        error.to_string()
+    }
+}
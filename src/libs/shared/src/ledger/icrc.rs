@@ -1,7 +1,8 @@
 use crate::ledger::types::icrc::IcrcTransferResult;
-use candid::Principal;
+use candid::{Nat, Principal};
 use ic_cdk::api::call::CallResult;
 use ic_cdk::call;
+use icrc_ledger_types::icrc1::account::Account;
 use icrc_ledger_types::icrc1::transfer::TransferArg;
 
 /// Initiates a transfer of tokens on a specified ledger using the provided ICRC-1 arguments.
@@ -22,3 +23,39 @@ pub async fn icrc_transfer_token(
     let (result,) = call(ledger_id, "icrc1_transfer", (args,)).await?;
     Ok(result)
 }
+
+/// Fetches the balance of the default account of an owner on an ICRC-1 ledger.
+///
+/// # Arguments
+/// * `ledger_id` - A `Principal` representing the ID of the ledger.
+/// * `owner` - The `Principal` owning the account.
+///
+/// # Returns
+/// A `CallResult<Nat>` with the balance in the smallest unit of the token.
+pub async fn icrc_balance_of(ledger_id: Principal, owner: Principal) -> CallResult<Nat> {
+    let account = Account {
+        owner,
+        subaccount: None,
+    };
+
+    let (balance,) = call(ledger_id, "icrc1_balance_of", (account,)).await?;
+    Ok(balance)
+}
+
+/// Fetches the number of NFTs held by the default account of an owner on an ICRC-7 ledger.
+///
+/// # Arguments
+/// * `ledger_id` - A `Principal` representing the ID of the collection of NFTs.
+/// * `owner` - The `Principal` owning the account.
+///
+/// # Returns
+/// A `CallResult<Nat>` with the number of tokens held.
+pub async fn icrc7_balance_of(ledger_id: Principal, owner: Principal) -> CallResult<Nat> {
+    let accounts = vec![Account {
+        owner,
+        subaccount: None,
+    }];
+
+    let (balances,): (Vec<Nat>,) = call(ledger_id, "icrc7_balance_of", (accounts,)).await?;
+    Ok(balances.into_iter().next().unwrap_or_default())
+}
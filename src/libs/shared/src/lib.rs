@@ -8,6 +8,7 @@ pub mod controllers;
 pub mod day;
 #[doc(hidden)]
 pub mod env;
+pub mod errors;
 #[doc(hidden)]
 pub mod impls;
 pub mod ledger;
@@ -1,18 +1,37 @@
+use crate::controllers::is_controller;
+use crate::errors::JunoError;
+use crate::msg::ERROR_LIST_MAX_SCANNED_ITEMS_EXCEEDED;
 use crate::types::core::Key;
 use crate::types::list::{
-    ListMatcher, ListOrder, ListOrderField, ListPaginate, ListParams, ListResults, TimestampMatcher,
+    KeyCollation, ListFilter, ListLimits, ListMatcher, ListOrder, ListOrderField, ListPaginate,
+    ListParams, ListResults, ListTruncated, Page, PrincipalMatcher, TextMatcher, TimestampMatcher,
 };
-use crate::types::state::Timestamp;
 use crate::types::state::Timestamped;
+use crate::types::state::{Controllers, Moderated, Scheduled, Timestamp, UserId};
+use crate::utils::is_published;
+use candid::{encode_one, CandidType};
 use regex::Regex;
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::str::Chars;
 
 pub fn list_values<'a, T: Clone + Timestamped>(
     matches: &'a [(&'a Key, &'a T)],
     filters: &'a ListParams,
+) -> ListResults<T> {
+    list_values_with_collation(matches, filters, &KeyCollation::Binary)
+}
+
+/// Same as `list_values` but the keys are ordered according to the collation - i.e. when sorted by keys and when no
+/// order is requested, in which case the results otherwise follow the ordering of the store.
+pub fn list_values_with_collation<'a, T: Clone + Timestamped>(
+    matches: &'a [(&'a Key, &'a T)],
+    filters: &'a ListParams,
+    collation: &KeyCollation,
 ) -> ListResults<T> {
     let matches_length = matches.len();
 
-    let ordered = order_values(matches, filters);
+    let ordered = order_values(matches, filters, collation);
 
     let start = start_at(&ordered, filters);
 
@@ -26,9 +45,259 @@ pub fn list_values<'a, T: Clone + Timestamped>(
         matches_length,
         items_page: current_page(start, filters),
         matches_pages: total_pages(matches_length, filters),
+        truncated: None,
+        resume_after: None,
+        version: None,
+    }
+}
+
+/// The entries read by a listing bounded by the maximal number of items to scan.
+///
+/// - `items`: The entries that were scanned.
+/// - `filters`: The parameters to list the scanned entries with. When the scan started after the cursor of the
+///   pagination, the cursor is removed since it is already applied.
+/// - `resume_after`: The last key scanned, if the maximal number of items was reached.
+pub struct ScannedItems<T> {
+    pub items: Vec<T>,
+    pub filters: ListParams,
+    pub resume_after: Option<Key>,
+}
+
+/// Reads the entries of a collection, provided in the order of their keys, up to the maximal number of items to scan.
+///
+/// When the listing follows that order, the scan starts after the cursor of the pagination, so the listing can be
+/// resumed where the scan stopped. Any other order requires every entry to be read first, therefore such a listing
+/// fails if the collection exceeds the limit rather than returning results ordered on a part of the collection.
+pub fn limit_scanned_items<T, I: Iterator<Item = T>>(
+    iter_after: impl FnOnce(&Option<Key>) -> Result<I, String>,
+    key: impl Fn(&T) -> Key,
+    filters: &ListParams,
+    collation: &KeyCollation,
+    limits: &Option<ListLimits>,
+) -> Result<ScannedItems<T>, String> {
+    let max_scanned_items = match limits.as_ref().and_then(|limits| limits.max_scanned_items) {
+        None => {
+            return Ok(ScannedItems {
+                items: iter_after(&None)?.collect(),
+                filters: filters.clone(),
+                resume_after: None,
+            });
+        }
+        Some(max_scanned_items) => max_scanned_items.max(1),
+    };
+
+    if !is_key_ordered(filters, collation) {
+        let items: Vec<T> = iter_after(&None)?
+            .take(max_scanned_items.saturating_add(1))
+            .collect();
+
+        if items.len() > max_scanned_items {
+            return Err(JunoError::new(
+                ERROR_LIST_MAX_SCANNED_ITEMS_EXCEEDED,
+                "The listing exceeds the maximal number of items to scan and cannot be ordered.",
+            )
+            .with_param("max_scanned_items", max_scanned_items)
+            .into());
+        }
+
+        return Ok(ScannedItems {
+            items,
+            filters: filters.clone(),
+            resume_after: None,
+        });
+    }
+
+    let start_after = filters
+        .paginate
+        .as_ref()
+        .and_then(|paginate| paginate.start_after.clone());
+
+    let mut items: Vec<T> = iter_after(&start_after)?
+        .take(max_scanned_items.saturating_add(1))
+        .collect();
+
+    let resume_after = if items.len() > max_scanned_items {
+        items.truncate(max_scanned_items);
+        items.last().map(&key)
+    } else {
+        None
+    };
+
+    Ok(ScannedItems {
+        items,
+        filters: ListParams {
+            paginate: filters.paginate.as_ref().map(|paginate| ListPaginate {
+                start_after: None,
+                limit: paginate.limit,
+            }),
+            ..filters.clone()
+        },
+        resume_after,
+    })
+}
+
+/// Whether the entries are listed in the order of their keys, ascending and byte by byte - i.e. the order in which
+/// they are stored.
+fn is_key_ordered(filters: &ListParams, collation: &KeyCollation) -> bool {
+    let ordered = match &filters.order {
+        None => true,
+        Some(ListOrder { desc, field }) => !desc && matches!(field, ListOrderField::Keys),
+    };
+
+    ordered && *collation == KeyCollation::Binary
+}
+
+/// Flags the results of a listing cut short by the maximal number of items to scan. The listing resumes after the last
+/// returned item if the pagination did not return every match, otherwise after the last scanned entry.
+pub fn with_scanned_items<T>(results: ListResults<T>, resume_after: Option<Key>) -> ListResults<T> {
+    if resume_after.is_none() {
+        return results;
+    }
+
+    let resume_after = if results.items_length < results.matches_length {
+        results.items.last().map(|(key, _)| key.clone())
+    } else {
+        resume_after
+    };
+
+    ListResults {
+        truncated: Some(ListTruncated::MaxScannedItems),
+        resume_after,
+        ..results
+    }
+}
+
+/// Drops the last items of the results that would exceed the maximal size to return. At least the first item is
+/// returned, for the listing to be able to progress with the pagination. When items are dropped, the listing resumes
+/// after the last returned item and the pages, which assume full pages, are no longer provided.
+pub fn limit_returned_bytes<T: CandidType>(
+    results: ListResults<T>,
+    limits: &Option<ListLimits>,
+) -> ListResults<T> {
+    let max_returned_bytes = match limits.as_ref().and_then(|limits| limits.max_returned_bytes) {
+        None => {
+            return results;
+        }
+        Some(max_returned_bytes) => max_returned_bytes,
+    };
+
+    let mut bytes: usize = 0;
+    let mut items: Vec<(Key, T)> = Vec::new();
+    for (key, item) in results.items {
+        bytes += encoded_size(&key, &item);
+
+        if bytes > max_returned_bytes && !items.is_empty() {
+            break;
+        }
+
+        items.push((key, item));
+    }
+
+    if items.len() == results.items_length {
+        return ListResults { items, ..results };
+    }
+
+    ListResults {
+        items_length: items.len(),
+        items_page: None,
+        matches_pages: None,
+        truncated: Some(ListTruncated::MaxReturnedBytes),
+        resume_after: items.last().map(|(key, _)| key.clone()),
+        items,
+        ..results
+    }
+}
+
+/// Lists the entries of a page. One more entry than the limit is listed to find out if a next page exists. Otherwise,
+/// a cursor is returned only when the listing was cut short, in which case the next page may still be empty.
+pub fn list_page<T>(
+    list: impl FnOnce(ListParams) -> ListResults<T>,
+    filters: &ListParams,
+) -> Page<(Key, T)> {
+    let limit = filters
+        .paginate
+        .as_ref()
+        .and_then(|paginate| paginate.limit);
+
+    let results = list(ListParams {
+        paginate: filters.paginate.as_ref().map(|paginate| ListPaginate {
+            start_after: paginate.start_after.clone(),
+            limit: limit.map(|limit| limit.saturating_add(1)),
+        }),
+        ..filters.clone()
+    });
+
+    let mut items = results.items;
+
+    let remaining = limit.map_or(false, |limit| items.len() > limit);
+
+    if let Some(limit) = limit {
+        items.truncate(limit);
+    }
+
+    let next_cursor = if remaining {
+        items.last().map(|(key, _)| key.clone())
+    } else {
+        results.resume_after
+    };
+
+    Page {
+        next_cursor,
+        total_estimate: results.matches_length,
+        items,
     }
 }
 
+/// Paginates entries that are not listed with a matcher - e.g. the controllers. The entries are ordered by the text of
+/// their key, which is the cursor. One more entry is read to find out if a next page exists.
+pub fn paginate_page<K: ToString, T>(
+    entries: impl Iterator<Item = (K, T)>,
+    ListPaginate { start_after, limit }: &ListPaginate,
+) -> Page<(K, T)> {
+    let mut entries: Vec<(Key, (K, T))> = entries
+        .map(|(key, value)| (key.to_string(), (key, value)))
+        .collect();
+
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let total_estimate = entries.len();
+
+    let mut items: Vec<(Key, (K, T))> = entries
+        .into_iter()
+        .skip_while(|(key, _)| {
+            start_after
+                .as_ref()
+                .map_or(false, |start_after| key <= start_after)
+        })
+        .take(limit.map_or(usize::MAX, |limit| limit.saturating_add(1)))
+        .collect();
+
+    let remaining = limit.map_or(false, |limit| items.len() > limit);
+
+    if let Some(limit) = limit {
+        items.truncate(*limit);
+    }
+
+    Page {
+        next_cursor: next_cursor(&items, remaining),
+        items: items.into_iter().map(|(_, entry)| entry).collect(),
+        total_estimate,
+    }
+}
+
+fn next_cursor<T>(items: &[(Key, T)], remaining: bool) -> Option<Key> {
+    if !remaining {
+        return None;
+    }
+
+    items.last().map(|(key, _)| key.clone())
+}
+
+/// The size of an item once Candid encoded - i.e. the size it approximately adds to a response.
+pub fn encoded_size<T: CandidType>(key: &Key, item: &T) -> usize {
+    encode_one((key, item)).map_or(0, |bytes| bytes.len())
+}
+
 fn current_page(start_at: Option<usize>, filters: &ListParams) -> Option<usize> {
     match start_at {
         None => None,
@@ -69,11 +338,15 @@ fn order_values<'a, T: Clone + Timestamped>(
         paginate: _,
         owner: _,
     }: &'a ListParams,
+    collation: &KeyCollation,
 ) -> Vec<(&'a Key, &'a T)> {
     match order {
-        None => matches.to_vec(),
+        None => match collation {
+            KeyCollation::Binary => matches.to_vec(),
+            _ => order_values_with_keys(matches, &false, collation),
+        },
         Some(ListOrder { desc, field }) => match field {
-            ListOrderField::Keys => order_values_with_keys(matches, desc),
+            ListOrderField::Keys => order_values_with_keys(matches, desc, collation),
             ListOrderField::UpdatedAt => order_values_with_updated_at(matches, desc),
             ListOrderField::CreatedAt => order_values_with_created_at(matches, desc),
         },
@@ -113,18 +386,78 @@ fn order_values_with_created_at<'a, T: Clone + Timestamped>(
 fn order_values_with_keys<'a, T: Clone + Timestamped>(
     matches: &'a [(&'a Key, &'a T)],
     desc: &bool,
+    collation: &KeyCollation,
 ) -> Vec<(&'a Key, &'a T)> {
     let mut sorted_matches = matches.to_vec();
 
     if *desc {
-        sorted_matches.sort_by(|(key_a, _), (key_b, _)| key_b.cmp(key_a));
+        sorted_matches.sort_by(|(key_a, _), (key_b, _)| compare_keys(key_b, key_a, collation));
         return sorted_matches;
     }
 
-    sorted_matches.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+    sorted_matches.sort_by(|(key_a, _), (key_b, _)| compare_keys(key_a, key_b, collation));
     sorted_matches
 }
 
+/// Compares two keys according to a collation. Keys that are equal for the collation - e.g. `Apple` and `apple`
+/// ignoring the case - are ordered byte by byte, so the ordering remains deterministic.
+pub fn compare_keys(key_a: &Key, key_b: &Key, collation: &KeyCollation) -> Ordering {
+    let ordering = match collation {
+        KeyCollation::Binary => Ordering::Equal,
+        KeyCollation::CaseInsensitive => key_a.to_lowercase().cmp(&key_b.to_lowercase()),
+        KeyCollation::Numeric => compare_numeric(key_a, key_b),
+    };
+
+    ordering.then_with(|| key_a.cmp(key_b))
+}
+
+fn compare_numeric(key_a: &str, key_b: &str) -> Ordering {
+    let mut chars_a = key_a.chars().peekable();
+    let mut chars_b = key_b.chars().peekable();
+
+    loop {
+        match (chars_a.peek(), chars_b.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) if a.is_ascii_digit() && b.is_ascii_digit() => {
+                let number_a = take_digits(&mut chars_a);
+                let number_b = take_digits(&mut chars_b);
+
+                // Without the leading zeros, the longest sequence is the largest number
+                let ordering = number_a
+                    .len()
+                    .cmp(&number_b.len())
+                    .then_with(|| number_a.cmp(&number_b));
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(a), Some(b)) => {
+                let ordering = a.cmp(b);
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+
+                chars_a.next();
+                chars_b.next();
+            }
+        }
+    }
+}
+
+fn take_digits(chars: &mut Peekable<Chars>) -> String {
+    let mut digits = String::new();
+
+    while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+        digits.push(c);
+    }
+
+    digits.trim_start_matches('0').to_string()
+}
+
 fn paginate_values<T: Clone + Timestamped>(
     matches: Vec<(&Key, &T)>,
     ListParams {
@@ -225,6 +558,32 @@ pub fn filter_timestamps<T: Timestamped>(matcher: &Option<ListMatcher>, item: &T
     true
 }
 
+/// Evaluates the filter expression of the matcher, if any, against the fields of an entry. An empty `And` matches every
+/// entry and an empty `Or` none.
+pub fn filter_expression<T: Timestamped>(
+    matcher: &Option<ListMatcher>,
+    key: &Key,
+    description: &Option<String>,
+    owner: &UserId,
+    item: &T,
+) -> bool {
+    match matcher.as_ref().and_then(|matcher| matcher.filter.as_ref()) {
+        None => true,
+        Some(filter) => match_filter(filter, key, description, owner, item),
+    }
+}
+
+/// Entities that are not yet - or no longer - published, or not approved, remain listed for their owner and the controllers.
+pub fn filter_published<T: Scheduled + Moderated>(
+    item: &T,
+    owner: UserId,
+    caller: UserId,
+    controllers: &Controllers,
+    now: Timestamp,
+) -> bool {
+    owner == caller || is_controller(caller, controllers) || is_published(item, now)
+}
+
 fn match_timestamp(timestamp: Timestamp, filter: &TimestampMatcher) -> bool {
     match filter {
         TimestampMatcher::Equal(ts) => timestamp == *ts,
@@ -233,3 +592,45 @@ fn match_timestamp(timestamp: Timestamp, filter: &TimestampMatcher) -> bool {
         TimestampMatcher::Between(start, end) => timestamp >= *start && timestamp <= *end,
     }
 }
+
+fn match_filter<T: Timestamped>(
+    filter: &ListFilter,
+    key: &Key,
+    description: &Option<String>,
+    owner: &UserId,
+    item: &T,
+) -> bool {
+    match filter {
+        ListFilter::And(filters) => filters
+            .iter()
+            .all(|filter| match_filter(filter, key, description, owner, item)),
+        ListFilter::Or(filters) => filters
+            .iter()
+            .any(|filter| match_filter(filter, key, description, owner, item)),
+        ListFilter::Not(filter) => !match_filter(filter, key, description, owner, item),
+        ListFilter::Key(matcher) => match_text(key, matcher),
+        ListFilter::Description(matcher) => description
+            .as_ref()
+            .map_or(false, |description| match_text(description, matcher)),
+        ListFilter::Owner(matcher) => match_principal(owner, matcher),
+        ListFilter::CreatedAt(matcher) => match_timestamp(item.created_at(), matcher),
+        ListFilter::UpdatedAt(matcher) => match_timestamp(item.updated_at(), matcher),
+    }
+}
+
+fn match_text(text: &str, matcher: &TextMatcher) -> bool {
+    match matcher {
+        TextMatcher::Equal(value) => text == value,
+        TextMatcher::GreaterThan(value) => text > value.as_str(),
+        TextMatcher::LessThan(value) => text < value.as_str(),
+        TextMatcher::Prefix(value) => text.starts_with(value.as_str()),
+        TextMatcher::In(values) => values.iter().any(|value| value == text),
+    }
+}
+
+fn match_principal(principal: &UserId, matcher: &PrincipalMatcher) -> bool {
+    match matcher {
+        PrincipalMatcher::Equal(value) => principal == value,
+        PrincipalMatcher::In(values) => values.contains(principal),
+    }
+}
@@ -9,3 +9,24 @@ pub const ERROR_NO_VERSION: &str = "error_no_version_provided";
 
 /// Error message indicating the provided version is either outdated or in the future.
 pub const ERROR_VERSION_OUTDATED_OR_FUTURE: &str = "error_version_outdated_or_future";
+
+/// Error message indicating the provided unpublish timestamp is not after the publish timestamp.
+pub const ERROR_UNPUBLISH_BEFORE_PUBLISH: &str = "error_unpublish_at_before_publish_at";
+
+/// Error code indicating the description exceeds the maximal length.
+pub const ERROR_DESCRIPTION_TOO_LONG: &str = "error_description_too_long";
+
+/// Error code indicating the heap memory usage exceeds the configured limit.
+pub const ERROR_HEAP_MEMORY_EXCEEDED: &str = "error_heap_memory_exceeded";
+
+/// Error code indicating the stable memory usage exceeds the configured limit.
+pub const ERROR_STABLE_MEMORY_EXCEEDED: &str = "error_stable_memory_exceeded";
+
+/// Error code indicating the rate limit is reached.
+pub const ERROR_RATE_LIMIT_REACHED: &str = "error_rate_limit_reached";
+
+/// Error code indicating a listing exceeds the maximal number of items to scan and cannot be ordered.
+pub const ERROR_LIST_MAX_SCANNED_ITEMS_EXCEEDED: &str = "error_list_max_scanned_items_exceeded";
+
+/// Error code of the errors that do not provide a specific code - i.e. their message is the only information.
+pub const ERROR_UNKNOWN: &str = "error_unknown";
@@ -1,3 +1,5 @@
+use crate::errors::JunoError;
+use crate::msg::ERROR_RATE_LIMIT_REACHED;
 use crate::rate::types::{RateConfig, RateTokenStore, RateTokens};
 use ic_cdk::api::time;
 use std::cmp::min;
@@ -37,6 +39,10 @@ pub fn increment_and_assert_rate(
         tokens.tokens -= 1;
         Ok(())
     } else {
-        Err("Rate limit reached, try again later.".to_string())
+        Err(JunoError::new(
+            ERROR_RATE_LIMIT_REACHED,
+            "Rate limit reached, try again later.",
+        )
+        .into())
     }
 }
@@ -1,4 +1,5 @@
 pub mod state {
+    use crate::rate::types::RateConfig;
     use candid::Principal;
     use candid::{CandidType, Nat};
     use ic_cdk::api::management_canister::main::CanisterStatusType;
@@ -31,6 +32,23 @@ pub mod state {
         fn cmp_created_at(&self, other: &Self) -> Ordering;
     }
 
+    pub trait Scheduled {
+        fn publish_at(&self) -> Option<Timestamp>;
+        fn unpublish_at(&self) -> Option<Timestamp>;
+    }
+
+    /// The review state of an entity - document or asset - saved in a collection that requires moderation.
+    #[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+    pub enum ModerationStatus {
+        Pending,
+        Approved,
+        Rejected,
+    }
+
+    pub trait Moderated {
+        fn moderation(&self) -> Option<ModerationStatus>;
+    }
+
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct Controller {
         pub metadata: Metadata,
@@ -85,6 +103,9 @@ pub mod state {
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct OrbiterSatelliteConfig {
         pub features: Option<OrbiterSatelliteFeatures>,
+        pub sampling: Option<OrbiterSatelliteSampling>,
+        pub rate_config: Option<RateConfig>,
+        pub anonymize: Option<bool>,
         pub created_at: Timestamp,
         pub updated_at: Timestamp,
         pub version: Option<Version>,
@@ -95,25 +116,39 @@ pub mod state {
         pub page_views: bool,
         pub track_events: bool,
         pub performance_metrics: bool,
+        pub error_reports: Option<bool>,
+    }
+
+    /// Percentage - from 0 to 100 - of the sessions whose analytics are recorded.
+    /// None records every session.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct OrbiterSatelliteSampling {
+        pub page_views: Option<u8>,
+        pub track_events: Option<u8>,
+        pub performance_metrics: Option<u8>,
     }
 }
 
 pub mod interface {
     use crate::mgmt::types::cmc::SubnetId;
-    use crate::types::core::Bytes;
+    use crate::types::core::{Blob, Bytes};
     use crate::types::cronjob::CronJobStatusesSegments;
     use crate::types::state::{
-        ControllerId, ControllerScope, Metadata, MissionControlId, Timestamp, UserId,
+        ControllerId, ControllerScope, Metadata, MissionControlId, OrbiterId, SatelliteId,
+        Timestamp, UserId,
     };
     use candid::{CandidType, Principal};
     use ic_ledger_types::BlockIndex;
-    use serde::Deserialize;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
 
     #[derive(CandidType, Deserialize)]
     pub struct CreateCanisterArgs {
         pub user: UserId,
         pub block_index: Option<BlockIndex>,
         pub subnet_id: Option<SubnetId>,
+        pub init_config: Option<SatelliteInitConfig>,
+        pub project_template: Option<String>,
     }
 
     #[derive(CandidType, Deserialize)]
@@ -131,20 +166,40 @@ pub mod interface {
         pub controllers: Vec<ControllerId>,
     }
 
-    #[derive(CandidType, Deserialize, Clone)]
+    /// The arguments to install a Satellite. The controllers are the admin controllers, the optional configuration
+    /// is applied once the Satellite is initialized.
+    #[derive(CandidType, Deserialize)]
+    pub struct SatelliteArgs {
+        pub controllers: Vec<ControllerId>,
+        pub init_config: Option<SatelliteInitConfig>,
+    }
+
+    /// A configuration a Satellite applies when it is installed, so that a new environment comes up configured.
+    ///
+    /// - `bundle`: The Candid encoding of a `ConfigBundle` of the Satellite - i.e. collections, storage, datastore and
+    ///   authentication configurations. It is defined by the Satellite, the console and the mission control only
+    ///   forward it.
+    /// - `controllers`: Additional controllers with their scopes and optional expirations.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct SatelliteInitConfig {
+        pub bundle: Option<Blob>,
+        pub controllers: Vec<(ControllerId, SetController)>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct SetController {
         pub metadata: Metadata,
         pub expires_at: Option<Timestamp>,
         pub scope: ControllerScope,
     }
 
-    #[derive(CandidType, Deserialize)]
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct SetControllersArgs {
         pub controllers: Vec<ControllerId>,
         pub controller: SetController,
     }
 
-    #[derive(CandidType, Deserialize)]
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct DeleteControllersArgs {
         pub controllers: Vec<ControllerId>,
     }
@@ -169,11 +224,105 @@ pub mod interface {
         pub cycles: u128,
     }
 
-    #[derive(CandidType, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct MemorySize {
         pub heap: Bytes,
         pub stable: Bytes,
     }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq)]
+    pub enum QuotaWarning {
+        MemorySize,
+        DocsCount,
+    }
+
+    /// The usage of a satellite when it last crossed - above or back below - one of its soft quota thresholds.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct QuotaStatus {
+        pub memory_size: u64,
+        pub docs_count: u64,
+        pub warnings: Vec<QuotaWarning>,
+        pub updated_at: Timestamp,
+    }
+
+    /// The estimation of when a module will run out of the cycles required to stay above its freezing threshold,
+    /// according to the cycles it burned recently. The `alert` is raised when the freeze is expected within the
+    /// horizon configured in the mission control.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct CyclesFreezePrediction {
+        pub cycles: u128,
+        pub burn_rate_per_day: u128,
+        pub freezing_threshold_cycles: u128,
+        pub days_until_freeze: Option<u64>,
+        pub alert: bool,
+        pub predicted_at: Timestamp,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct GetUsageDigest {
+        pub from: Option<Timestamp>,
+        pub to: Option<Timestamp>,
+    }
+
+    /// The usage of the modules of a mission control over a period. A metric is `None` when it cannot be collected -
+    /// e.g. the module does not answer or its cycles are not monitored.
+    ///
+    /// - `satellites`: The memory used by each satellite at the end of the period.
+    /// - `orbiters`: The page views tracked by each orbiter during the period.
+    /// - `cycles_burned`: The cycles consumed during the period according to the monitoring history.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct UsageDigest {
+        pub from: Timestamp,
+        pub to: Timestamp,
+        pub mission_control_cycles_burned: Option<u128>,
+        pub satellites: HashMap<SatelliteId, SatelliteUsage>,
+        pub orbiters: HashMap<OrbiterId, OrbiterUsage>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct SatelliteUsage {
+        pub memory_size: Option<MemorySize>,
+        pub cycles_burned: Option<u128>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct OrbiterUsage {
+        pub page_views: Option<u32>,
+        pub unique_sessions: Option<u64>,
+        pub top_pages: Vec<(String, u32)>,
+        pub cycles_burned: Option<u128>,
+    }
+
+    /// A part of a satellite that can be exported and imported chunk by chunk - e.g. to clone a satellite.
+    ///
+    /// - `Config`: The configurations of the Datastore, the Storage and the authentication.
+    /// - `Db` and `Storage`: The rule of a collection followed by its documents or assets.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub enum TransferSection {
+        Config,
+        Db(String),
+        Storage(String),
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct ExportChunkArgs {
+        pub section: TransferSection,
+        pub cursor: Option<Blob>,
+    }
+
+    /// The encoded data of a chunk of a section and the cursor to export the next one, `None` once the section is
+    /// exported entirely. The cursor is opaque and should be provided as is.
+    #[derive(CandidType, Deserialize)]
+    pub struct ExportChunk {
+        pub data: Blob,
+        pub cursor: Option<Blob>,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct ImportChunkArgs {
+        pub section: TransferSection,
+        pub data: Blob,
+    }
 }
 
 pub mod cronjob {
@@ -186,6 +335,7 @@ pub mod cronjob {
     pub struct CronJobs {
         pub metadata: Metadata,
         pub statuses: CronJobStatuses,
+        pub digest: Option<CronJobDigest>,
     }
 
     pub type CronJobStatusesSegments = HashMap<Principal, CronJobStatusesConfig>;
@@ -204,6 +354,16 @@ pub mod cronjob {
         pub enabled: bool,
         pub cycles_threshold: Option<u64>,
     }
+
+    /// A weekly report of the usage of the modules of the mission control - storage growth, page views, top pages
+    /// and cycles burned - sent through the notification channels of the user.
+    ///
+    /// - `top_pages`: The number of pages listed per orbiter. Defaults to 5.
+    #[derive(Default, CandidType, Deserialize, Clone)]
+    pub struct CronJobDigest {
+        pub enabled: bool,
+        pub top_pages: Option<u8>,
+    }
 }
 
 pub mod utils {
@@ -271,15 +431,15 @@ pub mod list {
     use crate::types::core::Key;
     use crate::types::state::{Timestamp, UserId};
     use candid::CandidType;
-    use serde::Deserialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Default, CandidType, Deserialize, Clone)]
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
     pub struct ListPaginate {
         pub start_after: Option<Key>,
         pub limit: Option<usize>,
     }
 
-    #[derive(Default, CandidType, Deserialize, Clone)]
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
     pub enum ListOrderField {
         #[default]
         Keys,
@@ -287,13 +447,13 @@ pub mod list {
         UpdatedAt,
     }
 
-    #[derive(Default, CandidType, Deserialize, Clone)]
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
     pub struct ListOrder {
         pub desc: bool,
         pub field: ListOrderField,
     }
 
-    #[derive(CandidType, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub enum TimestampMatcher {
         Equal(Timestamp),
         GreaterThan(Timestamp),
@@ -301,15 +461,53 @@ pub mod list {
         Between(Timestamp, Timestamp),
     }
 
-    #[derive(Default, CandidType, Deserialize, Clone)]
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum TextMatcher {
+        Equal(String),
+        GreaterThan(String),
+        LessThan(String),
+        Prefix(String),
+        In(Vec<String>),
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum PrincipalMatcher {
+        Equal(UserId),
+        In(Vec<UserId>),
+    }
+
+    /// A typed expression on the fields of the documents and assets - the key being the full path of an asset.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum ListFilter {
+        And(Vec<ListFilter>),
+        Or(Vec<ListFilter>),
+        Not(Box<ListFilter>),
+        Key(TextMatcher),
+        Description(TextMatcher),
+        Owner(PrincipalMatcher),
+        CreatedAt(TimestampMatcher),
+        UpdatedAt(TimestampMatcher),
+    }
+
+    /// The conditions an entry must match to be listed.
+    ///
+    /// - `key` and `description`: Regular expressions the key and the description must match.
+    /// - `created_at` and `updated_at`: Conditions on the timestamps.
+    /// - `filter`: A typed expression on the fields.
+    ///
+    /// The conditions are combined with a logical and - i.e. an entry is listed only if it matches each condition that
+    /// is provided, including the `owner` of the parameters. A `filter` therefore narrows the entries matched by the
+    /// other fields, it does not replace them.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
     pub struct ListMatcher {
         pub key: Option<Key>,
         pub description: Option<String>,
         pub created_at: Option<TimestampMatcher>,
         pub updated_at: Option<TimestampMatcher>,
+        pub filter: Option<ListFilter>,
     }
 
-    #[derive(Default, CandidType, Deserialize, Clone)]
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
     pub struct ListParams {
         pub matcher: Option<ListMatcher>,
         pub paginate: Option<ListPaginate>,
@@ -317,6 +515,10 @@ pub mod list {
         pub owner: Option<UserId>,
     }
 
+    /// The results of a listing.
+    ///
+    /// When the listing is cut short by the limits - `truncated` - the lengths and the pages only account for the entries
+    /// that were scanned, and `resume_after` is the key to provide as `start_after` to resume the listing.
     #[derive(Default, CandidType, Deserialize, Clone)]
     pub struct ListResults<T> {
         pub items: Vec<(Key, T)>,
@@ -324,6 +526,61 @@ pub mod list {
         pub items_page: Option<usize>,
         pub matches_length: usize,
         pub matches_pages: Option<usize>,
+        pub truncated: Option<ListTruncated>,
+        pub resume_after: Option<Key>,
+        pub version: Option<CollectionVersion>,
+    }
+
+    /// A page of a listing, the shape shared by the paginated list endpoints.
+    ///
+    /// - `next_cursor`: The key to provide as `start_after` to fetch the next page. `None` once the end of the listing
+    ///   is reached.
+    /// - `total_estimate`: The number of entries that match the listing. It is a lower bound when the listing was cut
+    ///   short by the limits.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct Page<T> {
+        pub items: Vec<T>,
+        pub next_cursor: Option<Key>,
+        pub total_estimate: usize,
+    }
+
+    /// The version of a collection, which lets the clients skip refetching a collection that did not change.
+    ///
+    /// - `changes`: The number of times a document or an asset of the collection was inserted, updated or deleted.
+    ///   It only increases, even if the collection is emptied.
+    /// - `last_modified`: When the last of those changes happened.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct CollectionVersion {
+        pub changes: u64,
+        pub last_modified: Timestamp,
+    }
+
+    /// The limits applied to each listing to prevent a single call from consuming the instruction limit.
+    /// - `max_scanned_items`: The maximal number of entries of the collection that are read and filtered.
+    /// - `max_returned_bytes`: The maximal size, Candid encoded, of the items that are returned.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
+    pub struct ListLimits {
+        pub max_scanned_items: Option<usize>,
+        pub max_returned_bytes: Option<usize>,
+    }
+
+    /// The ordering of the keys of a collection when listed.
+    /// - `Binary`: Byte by byte - e.g. `item10` before `item2`.
+    /// - `CaseInsensitive`: Ignoring the case of the letters - e.g. `apple` before `Banana`.
+    /// - `Numeric`: The sequences of digits are compared by value - e.g. `item2` before `item10`.
+    #[derive(Default, CandidType, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+    pub enum KeyCollation {
+        #[default]
+        Binary,
+        CaseInsensitive,
+        Numeric,
+    }
+
+    /// The limit that cut short a listing - i.e. the results are partial.
+    #[derive(CandidType, Deserialize, Clone, Debug, PartialEq, Eq)]
+    pub enum ListTruncated {
+        MaxScannedItems,
+        MaxReturnedBytes,
     }
 }
 
@@ -1,3 +1,4 @@
+use crate::types::state::{Moderated, ModerationStatus, Scheduled, Timestamp};
 use candid::Principal;
 
 /// Checks if two principals are not equal.
@@ -45,3 +46,30 @@ pub fn principal_not_anonymous(p: Principal) -> bool {
 pub fn principal_anonymous(p: Principal) -> bool {
     principal_equal(p, Principal::anonymous())
 }
+
+/// Checks if a scheduled entity - e.g. a document or an asset - is published at a given time.
+///
+/// An entity is published from its `publish_at` timestamp, inclusive, until its `unpublish_at` timestamp, exclusive.
+/// Entities without schedule are always published. Entities subject to moderation are published only once approved.
+///
+/// # Arguments
+/// * `item` - The entity to check.
+/// * `now` - The current timestamp.
+///
+/// # Returns
+/// True if the entity is published; false otherwise.
+pub fn is_published<T: Scheduled + Moderated>(item: &T, now: Timestamp) -> bool {
+    let published = item
+        .publish_at()
+        .map_or(true, |publish_at| publish_at <= now);
+
+    let unpublished = item
+        .unpublish_at()
+        .map_or(false, |unpublish_at| unpublish_at <= now);
+
+    let approved = item
+        .moderation()
+        .map_or(true, |moderation| moderation == ModerationStatus::Approved);
+
+    published && !unpublished && approved
+}
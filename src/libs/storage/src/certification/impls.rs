@@ -11,13 +11,18 @@ use crate::constants::{
 };
 use crate::http::headers::{build_headers, build_redirect_headers};
 use crate::http::types::{HeaderField, StatusCode};
+use crate::locales::localized_asset_path;
 use crate::types::config::{StorageConfig, StorageConfigIFrame};
+use crate::types::interface::CertifiedPath;
 use crate::types::state::FullPath;
 use crate::types::store::Asset;
 use crate::url::alternative_paths;
+use crate::wildcard_domains::tenant_asset_path;
+use ic_cdk::api::time;
 use ic_certification::{
     fork, fork_hash, labeled, labeled_hash, merge_hash_trees, pruned, AsHashTree, Hash, HashTree,
 };
+use junobuild_shared::utils::is_published;
 use sha2::{Digest, Sha256};
 
 impl CertifiedAssetHashes {
@@ -87,20 +92,32 @@ impl CertifiedAssetHashes {
         }
     }
 
+    /// Lists the urls certified for a response - the full paths of the assets and their alternative paths -
+    /// with the sha256 of the encoding that was certified.
+    pub fn list_paths(&self) -> Vec<CertifiedPath> {
+        self.tree_v1
+            .iter()
+            .map(|(url, sha256)| CertifiedPath {
+                url: url.clone(),
+                sha256: *sha256,
+            })
+            .collect()
+    }
+
     pub fn insert(&mut self, asset: &Asset, config: &StorageConfig) {
+        // Assets scheduled for a later publication - or no longer published - are not served and therefore not certified
+        if !is_published(asset, time()) {
+            return;
+        }
+
         let full_path = asset.key.full_path.clone();
 
-        for encoding_type in ENCODING_CERTIFICATION_ORDER.iter().rev() {
-            if let Some(encoding) = asset.encodings.get(*encoding_type) {
-                self.insert_v1(&full_path, encoding.sha256);
-                self.insert_v2(
-                    &full_path,
-                    &build_headers(asset, encoding, &encoding_type.to_string(), config),
-                    RESPONSE_STATUS_CODE_200,
-                    encoding.sha256,
-                );
+        if let Some((headers, sha256)) = certified_encoding(asset, config) {
+            self.insert_v1(&full_path, sha256);
+            self.insert_v2(&full_path, &headers, RESPONSE_STATUS_CODE_200, sha256);
 
-                return;
+            for path in served_paths(&full_path, config) {
+                self.insert_exact_v2(&path, &headers, RESPONSE_STATUS_CODE_200, sha256);
             }
         }
     }
@@ -127,36 +144,7 @@ impl CertifiedAssetHashes {
         status_code: StatusCode,
         sha256: Hash,
     ) {
-        self.tree_v2.insert(
-            &nested_tree_key(
-                full_path,
-                headers,
-                sha256,
-                EXACT_MATCH_TERMINATOR,
-                status_code,
-            ),
-            vec![],
-        );
-
-        let alt_paths = alternative_paths(full_path);
-
-        match alt_paths {
-            None => (),
-            Some(alt_paths) => {
-                for alt_path in alt_paths {
-                    self.tree_v2.insert(
-                        &nested_tree_key(
-                            &alt_path,
-                            headers,
-                            sha256,
-                            EXACT_MATCH_TERMINATOR,
-                            RESPONSE_STATUS_CODE_200,
-                        ),
-                        vec![],
-                    );
-                }
-            }
-        }
+        self.insert_exact_v2(full_path, headers, status_code, sha256);
 
         // Rewrite ** to /
         if *full_path == *ROOT_INDEX_HTML {
@@ -194,6 +182,45 @@ impl CertifiedAssetHashes {
         }
     }
 
+    fn insert_exact_v2(
+        &mut self,
+        full_path: &FullPath,
+        headers: &[HeaderField],
+        status_code: StatusCode,
+        sha256: Hash,
+    ) {
+        self.tree_v2.insert(
+            &nested_tree_key(
+                full_path,
+                headers,
+                sha256,
+                EXACT_MATCH_TERMINATOR,
+                status_code,
+            ),
+            vec![],
+        );
+
+        let alt_paths = alternative_paths(full_path);
+
+        match alt_paths {
+            None => (),
+            Some(alt_paths) => {
+                for alt_path in alt_paths {
+                    self.tree_v2.insert(
+                        &nested_tree_key(
+                            &alt_path,
+                            headers,
+                            sha256,
+                            EXACT_MATCH_TERMINATOR,
+                            RESPONSE_STATUS_CODE_200,
+                        ),
+                        vec![],
+                    );
+                }
+            }
+        }
+    }
+
     pub fn insert_redirect_v2(
         &mut self,
         full_path: &FullPath,
@@ -208,6 +235,66 @@ impl CertifiedAssetHashes {
         self.insert_v2(full_path, &headers, status_code, sha256);
     }
 
+    pub fn contains_redirect_v2(
+        &self,
+        full_path: &FullPath,
+        status_code: StatusCode,
+        location: &str,
+        iframe: &StorageConfigIFrame,
+    ) -> bool {
+        let headers = build_redirect_headers(location, iframe);
+
+        let sha256 = Sha256::digest(Vec::new().clone()).into();
+
+        self.tree_v2.contains_leaf(&nested_tree_key(
+            full_path,
+            &headers,
+            sha256,
+            EXACT_MATCH_TERMINATOR,
+            status_code,
+        ))
+    }
+
+    pub fn insert_static_v2(&mut self, full_path: &FullPath, headers: &[HeaderField], body: &[u8]) {
+        let sha256: Hash = Sha256::digest(body).into();
+
+        self.tree_v1.insert(full_path.clone(), sha256);
+
+        self.tree_v2.insert(
+            &nested_tree_key(
+                full_path,
+                headers,
+                sha256,
+                EXACT_MATCH_TERMINATOR,
+                RESPONSE_STATUS_CODE_200,
+            ),
+            vec![],
+        );
+    }
+
+    /// Whether the response inserted with `insert_static_v2` is certified for the version of the certification
+    /// requested - i.e. the tree v1 holding a single response per path.
+    pub fn contains_static(
+        &self,
+        full_path: &FullPath,
+        headers: &[HeaderField],
+        body: &[u8],
+        certificate_version: &Option<u16>,
+    ) -> bool {
+        let sha256: Hash = Sha256::digest(body).into();
+
+        match certificate_version {
+            Some(2) => self.tree_v2.contains_leaf(&nested_tree_key(
+                full_path,
+                headers,
+                sha256,
+                EXACT_MATCH_TERMINATOR,
+                RESPONSE_STATUS_CODE_200,
+            )),
+            _ => self.tree_v1.get(full_path.as_bytes()) == Some(&sha256),
+        }
+    }
+
     pub fn insert_rewrite_v2(
         &mut self,
         full_path: &FullPath,
@@ -247,11 +334,32 @@ impl CertifiedAssetHashes {
         );
     }
 
-    pub fn delete(&mut self, asset: &Asset) {
+    pub fn delete(&mut self, asset: &Asset, config: &StorageConfig) {
         let full_path = asset.key.full_path.clone();
 
         self.delete_v1(&full_path);
         self.delete_v2(&full_path);
+
+        // Only the response of the asset is removed, the path being shared with the other locales or tenants
+        if let Some((headers, sha256)) = certified_encoding(asset, config) {
+            for path in served_paths(&full_path, config)
+                .iter()
+                .flat_map(exact_paths)
+            {
+                self.tree_v2.delete(&nested_tree_key(
+                    &path,
+                    &headers,
+                    sha256,
+                    EXACT_MATCH_TERMINATOR,
+                    RESPONSE_STATUS_CODE_200,
+                ));
+            }
+        }
+    }
+
+    pub fn delete_static(&mut self, full_path: &FullPath) {
+        self.tree_v1.delete(full_path.as_bytes());
+        self.delete_from_tree_v2(full_path, EXACT_MATCH_TERMINATOR);
     }
 
     fn delete_v1(&mut self, full_path: &String) {
@@ -305,3 +413,40 @@ impl CertifiedAssetHashes {
             .delete(&nested_tree_path(full_path, terminator));
     }
 }
+
+/// The headers and the sha256 of the encoding that is certified for an asset - i.e. the first one in the order of
+/// certification that the asset provides.
+fn certified_encoding(asset: &Asset, config: &StorageConfig) -> Option<(Vec<HeaderField>, Hash)> {
+    ENCODING_CERTIFICATION_ORDER
+        .iter()
+        .rev()
+        .find_map(|encoding_type| {
+            asset.encodings.get(*encoding_type).map(|encoding| {
+                (
+                    build_headers(asset, encoding, &encoding_type.to_string(), config),
+                    encoding.sha256,
+                )
+            })
+        })
+}
+
+/// The paths for which an asset is served in addition to its own - i.e. the path of a localized asset within the tree
+/// of its locale and the path of an asset of a tenant within its tree. Because the certification is agnostic of the
+/// language and of the host of the request, the responses of the locales and tenants are certified side by side for
+/// these exact paths - e.g. /fr/about.html and /en/about.html are both certified for /about.html.
+fn served_paths(full_path: &FullPath, config: &StorageConfig) -> Vec<FullPath> {
+    [
+        localized_asset_path(full_path, config),
+        tenant_asset_path(full_path, config),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+// The path and its alternative paths - e.g. /about.html and /about
+fn exact_paths(full_path: &FullPath) -> Vec<FullPath> {
+    let mut paths = Vec::from([full_path.clone()]);
+    paths.extend(alternative_paths(full_path).unwrap_or_default());
+    paths
+}
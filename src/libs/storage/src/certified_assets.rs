@@ -1,6 +1,7 @@
 use crate::certification::types::certified::CertifiedAssetHashes;
+use crate::constants::RESPONSE_STATUS_CODE_308;
 use crate::rewrites::rewrite_source_to_path;
-use crate::routing::get_routing;
+use crate::routing::{delegation_redirect_url, get_routing};
 use crate::runtime::init_certified_assets;
 use crate::strategies::StorageStateStrategy;
 use crate::types::config::StorageConfig;
@@ -23,6 +24,26 @@ pub fn extend_and_init_certified_assets(
         }
     }
 
+    // Each destination of a weighted rewrite is certified for the same source path.
+    // The tree entries differ by their body hash, therefore any of the variants can be served.
+    for (source, rewrite) in config.unwrap_weighted_rewrites() {
+        let src_path = rewrite_source_to_path(&source);
+
+        for weighted_destination in rewrite.destinations {
+            if let Ok(Routing::Default(RoutingDefault {
+                url: _,
+                asset: Some((asset, _)),
+            })) = get_routing(
+                weighted_destination.destination,
+                &Vec::new(),
+                false,
+                storage_state,
+            ) {
+                asset_hashes.insert_rewrite_v2(&src_path, &asset, config);
+            }
+        }
+    }
+
     for (source, redirect) in config.unwrap_redirects() {
         asset_hashes.insert_redirect_v2(
             &source,
@@ -32,5 +53,17 @@ pub fn extend_and_init_certified_assets(
         );
     }
 
+    // The location of a delegation depending on the requested path, only those of an exact path can be certified.
+    for (source, delegation) in config.unwrap_delegations() {
+        if source.starts_with('/') && !source.contains('*') {
+            asset_hashes.insert_redirect_v2(
+                &source,
+                RESPONSE_STATUS_CODE_308,
+                &delegation_redirect_url(&source, &delegation),
+                &config.unwrap_iframe(),
+            );
+        }
+    }
+
     init_certified_assets(asset_hashes);
 }
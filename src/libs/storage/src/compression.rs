@@ -0,0 +1,58 @@
+use crate::constants::{ASSET_ENCODING_BROTLI, ASSET_ENCODING_GZIP};
+use crate::types::store::{AssetEncoding, EncodingType};
+use flate2::read::GzDecoder;
+use junobuild_shared::types::core::Blob;
+use sha2::{Digest, Sha256};
+use std::io::{copy, Read};
+
+/// Asserts that a precompressed encoding - gzip or br - matches the identity encoding once decompressed.
+/// Other encodings or assets without identity encoding are not validated.
+pub fn assert_compressed_encoding(
+    encoding_type: &EncodingType,
+    content_chunks: &[Blob],
+    identity: &Option<&AssetEncoding>,
+) -> Result<(), String> {
+    let identity = match identity {
+        None => {
+            return Ok(());
+        }
+        Some(identity) => identity,
+    };
+
+    let content: Vec<u8> = content_chunks.concat();
+
+    // The decompressed content is read up to one byte more than the identity length.
+    // That way a mismatch is detected without fully inflating a content that is larger than expected.
+    let limit = u64::try_from(identity.total_length)
+        .unwrap_or(u64::MAX)
+        .saturating_add(1);
+
+    let sha256 = match encoding_type.as_str() {
+        ASSET_ENCODING_GZIP => decompressed_sha256(GzDecoder::new(content.as_slice()), limit),
+        ASSET_ENCODING_BROTLI => decompressed_sha256(
+            brotli_decompressor::Decompressor::new(content.as_slice(), 4096),
+            limit,
+        ),
+        _ => {
+            return Ok(());
+        }
+    }
+    .map_err(|err| format!("Cannot decompress {} encoding: {}.", encoding_type, err))?;
+
+    if sha256 != identity.sha256 {
+        return Err(format!(
+            "The decompressed {} encoding does not match the identity encoding.",
+            encoding_type
+        ));
+    }
+
+    Ok(())
+}
+
+fn decompressed_sha256(reader: impl Read, limit: u64) -> Result<[u8; 32], String> {
+    let mut hasher = Sha256::new();
+
+    copy(&mut reader.take(limit), &mut hasher).map_err(|err| err.to_string())?;
+
+    Ok(hasher.finalize().into())
+}
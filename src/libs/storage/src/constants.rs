@@ -1,12 +1,14 @@
 use crate::http::types::StatusCode;
 
 pub const ASSET_ENCODING_NO_COMPRESSION: &str = "identity";
+pub const ASSET_ENCODING_GZIP: &str = "gzip";
+pub const ASSET_ENCODING_BROTLI: &str = "br";
 pub const ENCODING_CERTIFICATION_ORDER: &[&str] = &[
     ASSET_ENCODING_NO_COMPRESSION,
-    "gzip",
+    ASSET_ENCODING_GZIP,
     "compress",
     "deflate",
-    "br",
+    ASSET_ENCODING_BROTLI,
 ];
 pub const WELL_KNOWN_CUSTOM_DOMAINS: &str = "/.well-known/ic-domains";
 pub const WELL_KNOWN_II_ALTERNATIVE_ORIGINS: &str = "/.well-known/ii-alternative-origins";
@@ -16,6 +18,9 @@ pub const ROOT_INDEX_HTML: &str = "/index.html";
 pub const ROOT_404_HTML: &str = "/404.html";
 pub const ROOT_PATHS: [&str; 5] = ["/index.html", "/index", "/", "/404", "/404.html"];
 
+// Below the 2 MiB a response of the HTTP gateway can hold with its headers and certificate.
+pub const DEFAULT_MAX_RESPONSE_BODY_SIZE: usize = 1_900_000;
+
 pub const RESPONSE_STATUS_CODE_200: StatusCode = 200;
 pub const RESPONSE_STATUS_CODE_308: StatusCode = 308;
 pub const RESPONSE_STATUS_CODE_404: StatusCode = 404;
@@ -0,0 +1,166 @@
+use crate::http::types::HeaderField;
+use crate::http::utils::{get_header_value, is_raw_request};
+use crate::types::config::StorageConfig;
+use base64::encode;
+use ic_cdk::api::time;
+use junobuild_shared::types::core::Blob;
+use sha2::{Digest, Sha256};
+
+const CSP_HEADER: &str = "Content-Security-Policy";
+
+/// The nonce can only be injected in the responses that are not certified - i.e. served through a raw domain - given
+/// that the certification covers the body as uploaded.
+pub fn is_csp_nonce_enabled(config: &StorageConfig, req_headers: &[HeaderField]) -> bool {
+    if !config.unwrap_csp_nonce() {
+        return false;
+    }
+
+    is_raw_request(req_headers)
+}
+
+pub fn is_html(headers: &[HeaderField]) -> bool {
+    get_header_value(headers, "Content-Type")
+        .map(|content_type| content_type.to_lowercase().starts_with("text/html"))
+        .unwrap_or(false)
+}
+
+/// Injects a nonce in the inline scripts of a page - as a whole, its body has not been split - and allows it in its
+/// Content-Security-Policy.
+pub fn apply_csp_nonce(
+    body: &[u8],
+    headers: &[HeaderField],
+    seed: &[u8],
+    url: &str,
+    req_headers: &[HeaderField],
+) -> (Blob, Vec<HeaderField>) {
+    let nonce = create_nonce(seed, url, req_headers);
+
+    (
+        inject_nonce_body(body, &nonce),
+        inject_nonce_headers(headers, &nonce),
+    )
+}
+
+/// Derives a nonce from a secret seed, the time and the request. The time only progresses from one round to another,
+/// therefore identical requests within a round are answered with the same nonce, which remains unpredictable without
+/// the seed.
+fn create_nonce(seed: &[u8], url: &str, req_headers: &[HeaderField]) -> String {
+    let mut hasher = Sha256::new();
+
+    hasher.update(seed);
+    hasher.update(time().to_be_bytes());
+    hasher.update(url.as_bytes());
+
+    for HeaderField(key, value) in req_headers {
+        hasher.update(key.as_bytes());
+        hasher.update(value.as_bytes());
+    }
+
+    encode(&hasher.finalize()[..16])
+}
+
+/// Adds the nonce to the `<script>` tags that do not already provide one. Bodies that are not valid UTF-8 are left
+/// untouched.
+fn inject_nonce_body(body: &[u8], nonce: &str) -> Blob {
+    let Ok(html) = std::str::from_utf8(body) else {
+        return body.to_vec();
+    };
+
+    // ASCII lowercase keeps the byte offsets of the original HTML
+    let lowercase = html.to_ascii_lowercase();
+
+    let mut result = String::with_capacity(html.len());
+    let mut cursor = 0;
+
+    for (index, tag) in lowercase.match_indices("<script") {
+        if index < cursor {
+            continue;
+        }
+
+        // Excludes tags such as <scripts> or <script-element>
+        match lowercase[index + tag.len()..].chars().next() {
+            Some(c) if c == '>' || c == '/' || c.is_whitespace() => (),
+            _ => continue,
+        }
+
+        let mut end = lowercase[index..]
+            .find('>')
+            .map(|end| index + end)
+            .unwrap_or(html.len());
+
+        if lowercase[..end].ends_with('/') {
+            end -= 1;
+        }
+
+        result.push_str(&html[cursor..end]);
+
+        if !lowercase[index..end].contains("nonce=") {
+            result.push_str(&format!(" nonce=\"{}\"", nonce));
+        }
+
+        cursor = end;
+    }
+
+    result.push_str(&html[cursor..]);
+
+    result.into_bytes()
+}
+
+/// Allows the nonce in the `script-src` directive of the Content-Security-Policy header. A strict policy is added if the
+/// configuration does not provide any.
+fn inject_nonce_headers(headers: &[HeaderField], nonce: &str) -> Vec<HeaderField> {
+    let source = format!("'nonce-{}'", nonce);
+
+    let mut csp_found = false;
+
+    let mut headers: Vec<HeaderField> = headers
+        .iter()
+        .map(|HeaderField(key, value)| {
+            if !key.eq_ignore_ascii_case(CSP_HEADER) {
+                return HeaderField(key.clone(), value.clone());
+            }
+
+            csp_found = true;
+
+            HeaderField(key.clone(), inject_nonce_policy(value, &source))
+        })
+        .collect();
+
+    if !csp_found {
+        headers.push(HeaderField(
+            CSP_HEADER.to_string(),
+            format!(
+                "script-src {} 'strict-dynamic'; object-src 'none'; base-uri 'none'",
+                source
+            ),
+        ));
+    }
+
+    headers
+}
+
+fn inject_nonce_policy(policy: &str, source: &str) -> String {
+    let mut script_src_found = false;
+
+    let mut directives: Vec<String> = policy
+        .split(';')
+        .map(|directive| directive.trim())
+        .filter(|directive| !directive.is_empty())
+        .map(|directive| {
+            let name = directive.split_whitespace().next().unwrap_or_default();
+
+            if name.eq_ignore_ascii_case("script-src") {
+                script_src_found = true;
+                return format!("{} {}", directive, source);
+            }
+
+            directive.to_string()
+        })
+        .collect();
+
+    if !script_src_found {
+        directives.push(format!("script-src {}", source));
+    }
+
+    directives.join("; ")
+}
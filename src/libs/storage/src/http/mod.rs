@@ -1,3 +1,4 @@
+pub mod csp;
 pub mod headers;
 pub mod response;
 pub mod types;
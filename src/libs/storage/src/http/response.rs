@@ -1,11 +1,13 @@
 use crate::constants::{
-    RESPONSE_STATUS_CODE_308, RESPONSE_STATUS_CODE_404, RESPONSE_STATUS_CODE_406,
-    RESPONSE_STATUS_CODE_500,
+    ASSET_ENCODING_NO_COMPRESSION, RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_308,
+    RESPONSE_STATUS_CODE_404, RESPONSE_STATUS_CODE_406, RESPONSE_STATUS_CODE_500,
 };
+use crate::http::csp::{apply_csp_nonce, is_csp_nonce_enabled, is_html};
 use crate::http::headers::build_redirect_headers;
 use crate::http::types::{HeaderField, HttpResponse, StatusCode};
 use crate::http::utils::{
-    build_encodings, build_response_headers, build_response_redirect_headers, streaming_strategy,
+    build_encodings, build_response_headers, build_response_redirect_headers,
+    build_response_static_headers, slice_body, streaming_strategy,
 };
 use crate::strategies::StorageStateStrategy;
 use crate::types::config::{StorageConfigIFrame, StorageConfigRedirect};
@@ -23,7 +25,18 @@ pub fn build_asset_response(
 ) -> HttpResponse {
     match asset {
         Some((asset, memory)) => {
-            let encodings = build_encodings(requested_headers);
+            let config = storage_state.get_config();
+
+            // The nonce is injected in the uncompressed content only
+            let csp_nonce = is_csp_nonce_enabled(&config, &requested_headers)
+                && is_html(&asset.headers)
+                && asset.encodings.contains_key(ASSET_ENCODING_NO_COMPRESSION);
+
+            let encodings = if csp_nonce {
+                vec![ASSET_ENCODING_NO_COMPRESSION.to_string()]
+            } else {
+                build_encodings(requested_headers.clone())
+            };
 
             for encoding_type in encodings.iter() {
                 if let Some(encoding) = asset.encodings.get(encoding_type) {
@@ -34,7 +47,7 @@ pub fn build_asset_response(
                         encoding_type,
                         &certificate_version,
                         &rewrite_source,
-                        &storage_state.get_config(),
+                        &config,
                     );
 
                     let Asset { key, .. } = &asset;
@@ -50,18 +63,49 @@ pub fn build_asset_response(
 
                             match body {
                                 Some(body) => {
+                                    // A chunk exceeding the maximal response size is sent in parts
+                                    // with the streaming strategy instead of failing.
+                                    let (body, next_offset) = slice_body(
+                                        &body,
+                                        0,
+                                        config.unwrap_max_response_body_size(),
+                                    );
+
+                                    // A page split in several parts is served as uploaded
+                                    let seed = if csp_nonce
+                                        && next_offset.is_none()
+                                        && encoding.content_chunks.len() == 1
+                                    {
+                                        storage_state.get_random_seed()
+                                    } else {
+                                        None
+                                    };
+
+                                    let (body, headers) = match seed {
+                                        Some(seed) => apply_csp_nonce(
+                                            &body,
+                                            &headers,
+                                            &seed,
+                                            &requested_url,
+                                            &requested_headers,
+                                        ),
+                                        None => (body, headers),
+                                    };
+
                                     return HttpResponse {
-                                        body: body.clone(),
+                                        body,
                                         headers: headers.clone(),
                                         status_code,
                                         streaming_strategy: streaming_strategy(
                                             key,
+                                            next_offset,
                                             encoding,
                                             encoding_type,
                                             &headers,
                                             &memory,
                                         ),
-                                    }
+                                        upgrade: None,
+                                    };
                                 }
                                 None => {
                                     error_response(
@@ -109,6 +153,27 @@ pub fn build_redirect_response(
         headers: headers.clone(),
         status_code: redirect.status_code,
         streaming_strategy: None,
+        upgrade: None,
+    }
+}
+
+/// A response that does not depend on an asset - e.g. the health of the satellite - and that is certified as is with
+/// `insert_static_v2`.
+pub fn build_static_response(
+    requested_url: &str,
+    certificate_version: Option<u16>,
+    headers: &[HeaderField],
+    body: &[u8],
+) -> HttpResponse {
+    let headers =
+        build_response_static_headers(requested_url, headers, &certificate_version).unwrap();
+
+    HttpResponse {
+        body: body.to_vec(),
+        headers,
+        status_code: RESPONSE_STATUS_CODE_200,
+        streaming_strategy: None,
+        upgrade: None,
     }
 }
 
@@ -123,6 +188,7 @@ pub fn build_redirect_raw_response(
         headers: headers.clone(),
         status_code: RESPONSE_STATUS_CODE_308,
         streaming_strategy: None,
+        upgrade: None,
     }
 }
 
@@ -132,5 +198,17 @@ pub fn error_response(status_code: StatusCode, body: String) -> HttpResponse {
         headers: Vec::new(),
         status_code,
         streaming_strategy: None,
+        upgrade: None,
+    }
+}
+
+/// Asks the HTTP gateway to replay the request as an update call - i.e. to http_request_update.
+pub fn upgrade_response() -> HttpResponse {
+    HttpResponse {
+        body: Vec::new(),
+        headers: Vec::new(),
+        status_code: RESPONSE_STATUS_CODE_200,
+        streaming_strategy: None,
+        upgrade: Some(true),
     }
 }
@@ -26,6 +26,7 @@ pub struct HttpResponse {
     pub headers: Vec<HeaderField>,
     pub status_code: StatusCode,
     pub streaming_strategy: Option<StreamingStrategy>,
+    pub upgrade: Option<bool>,
 }
 
 define_function!(pub CallbackFunc : () -> () query);
@@ -47,6 +48,7 @@ pub struct StreamingCallbackToken {
     pub index: usize,
     pub encoding_type: EncodingType,
     pub memory: Memory,
+    pub offset: Option<usize>,
 }
 
 #[derive(CandidType, Deserialize, Clone)]
@@ -1,5 +1,5 @@
 use crate::certification::cert::{build_asset_certificate_header, build_certified_expression};
-use crate::constants::ASSET_ENCODING_NO_COMPRESSION;
+use crate::constants::{ASSET_ENCODING_NO_COMPRESSION, RAW_DOMAINS};
 use crate::http::headers::{build_headers, build_redirect_headers};
 use crate::http::types::{CallbackFunc, HeaderField, StreamingCallbackToken, StreamingStrategy};
 use crate::memory::STATE;
@@ -8,17 +8,26 @@ use crate::types::runtime_state::StorageRuntimeState;
 use crate::types::store::{Asset, AssetEncoding, AssetKey, EncodingType};
 use ic_cdk::id;
 use junobuild_collections::types::rules::Memory;
+use junobuild_shared::types::core::Blob;
 use serde_bytes::ByteBuf;
 
 pub fn streaming_strategy(
     key: &AssetKey,
+    next_offset: Option<usize>,
     encoding: &AssetEncoding,
     encoding_type: &str,
     headers: &[HeaderField],
     memory: &Memory,
 ) -> Option<StreamingStrategy> {
-    let streaming_token: Option<StreamingCallbackToken> =
-        create_token(key, 0, encoding, encoding_type, headers, memory);
+    let streaming_token: Option<StreamingCallbackToken> = create_token(
+        key,
+        0,
+        next_offset,
+        encoding,
+        encoding_type,
+        headers,
+        memory,
+    );
 
     streaming_token.map(|streaming_token| StreamingStrategy::Callback {
         callback: CallbackFunc::new(id(), "http_request_streaming_callback".to_string()),
@@ -26,29 +35,51 @@ pub fn streaming_strategy(
     })
 }
 
+/// A token either points to the remaining bytes of the current chunk - when it was larger than the maximal response
+/// body size and had to be split - or to the next chunk.
 pub fn create_token(
     key: &AssetKey,
     chunk_index: usize,
+    next_offset: Option<usize>,
     encoding: &AssetEncoding,
     encoding_type: &str,
     headers: &[HeaderField],
     memory: &Memory,
 ) -> Option<StreamingCallbackToken> {
-    if chunk_index + 1 >= encoding.content_chunks.len() {
-        return None;
-    }
+    let (index, offset) = match next_offset {
+        Some(offset) => (chunk_index, Some(offset)),
+        None => {
+            if chunk_index + 1 >= encoding.content_chunks.len() {
+                return None;
+            }
+
+            (chunk_index + 1, None)
+        }
+    };
 
     Some(StreamingCallbackToken {
         full_path: key.full_path.clone(),
         token: key.token.clone(),
         headers: headers.to_owned(),
-        index: chunk_index + 1,
+        index,
         sha256: Some(ByteBuf::from(encoding.sha256)),
         encoding_type: encoding_type.to_owned(),
         memory: memory.clone(),
+        offset,
     })
 }
 
+/// Returns the part of the chunk that fits in a response, starting at the offset, and the offset of the remaining bytes
+/// if there are any left.
+pub fn slice_body(chunk: &[u8], offset: usize, max_size: usize) -> (Blob, Option<usize>) {
+    let start = offset.min(chunk.len());
+    let end = start.saturating_add(max_size).min(chunk.len());
+
+    let next_offset = if end < chunk.len() { Some(end) } else { None };
+
+    (chunk[start..end].to_vec(), next_offset)
+}
+
 pub fn build_response_headers(
     url: &str,
     asset: &Asset,
@@ -74,6 +105,14 @@ pub fn build_response_redirect_headers(
     extend_headers_with_certification(asset_headers, url, certificate_version, &None)
 }
 
+pub fn build_response_static_headers(
+    url: &str,
+    headers: &[HeaderField],
+    certificate_version: &Option<u16>,
+) -> Result<Vec<HeaderField>, &'static str> {
+    extend_headers_with_certification(headers.to_vec(), url, certificate_version, &None)
+}
+
 fn extend_headers_with_certification(
     asset_headers: Vec<HeaderField>,
     url: &str,
@@ -133,3 +172,27 @@ pub fn build_encodings(headers: Vec<HeaderField>) -> Vec<String> {
 
     encodings
 }
+
+pub fn get_header_value(headers: &[HeaderField], name: &str) -> Option<String> {
+    headers
+        .iter()
+        .find(|HeaderField(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|HeaderField(_, value)| value.trim().to_string())
+}
+
+/// Whether the request targets a raw domain - i.e. a domain whose responses are not verified by the HTTP gateway.
+pub fn is_raw_request(headers: &[HeaderField]) -> bool {
+    headers.iter().any(|HeaderField(key, value)| {
+        key.eq_ignore_ascii_case("Host") && RAW_DOMAINS.iter().any(|domain| value.contains(domain))
+    })
+}
+
+pub fn get_cookie_value(headers: &[HeaderField], name: &str) -> Option<String> {
+    headers
+        .iter()
+        .filter(|HeaderField(key, _)| key.eq_ignore_ascii_case("Cookie"))
+        .flat_map(|HeaderField(_, value)| value.split(';'))
+        .filter_map(|cookie| cookie.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.to_string())
+}
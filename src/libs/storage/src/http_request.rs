@@ -1,15 +1,20 @@
-use crate::constants::{RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_405};
+use crate::constants::{
+    RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_308, RESPONSE_STATUS_CODE_405,
+};
 use crate::http::response::{
     build_asset_response, build_redirect_raw_response, build_redirect_response, error_response,
+    upgrade_response,
 };
 use crate::http::types::{
     HttpRequest, HttpResponse, StreamingCallbackHttpResponse, StreamingCallbackToken,
 };
-use crate::http::utils::create_token;
+use crate::http::utils::{create_token, slice_body};
 use crate::routing::get_routing;
+use crate::runtime::is_certified_redirect;
 use crate::strategies::StorageStateStrategy;
+use crate::types::config::StorageConfigRedirect;
 use crate::types::http_request::{
-    Routing, RoutingDefault, RoutingRedirect, RoutingRedirectRaw, RoutingRewrite,
+    Routing, RoutingDefault, RoutingDelegation, RoutingRedirect, RoutingRedirectRaw, RoutingRewrite,
 };
 use ic_cdk::trap;
 
@@ -67,6 +72,24 @@ pub fn http_request(
                 redirect_url,
                 iframe,
             }) => build_redirect_raw_response(&redirect_url, &iframe),
+            // Only the delegations of an exact path - requested without query parameters - are certified. The others
+            // are answered with an update call, whose response goes through consensus.
+            Routing::Delegation(RoutingDelegation {
+                url,
+                redirect_url,
+                iframe,
+            }) => {
+                let redirect = StorageConfigRedirect {
+                    location: redirect_url,
+                    status_code: RESPONSE_STATUS_CODE_308,
+                };
+
+                if is_certified_redirect(&url, &redirect, &iframe) {
+                    build_redirect_response(url, certificate_version, &redirect, &iframe)
+                } else {
+                    upgrade_response()
+                }
+            }
         },
         Err(err) => error_response(
             RESPONSE_STATUS_CODE_405,
@@ -75,6 +98,32 @@ pub fn http_request(
     }
 }
 
+pub fn http_request_update(
+    HttpRequest {
+        method,
+        url,
+        headers: req_headers,
+        body: _,
+        certificate_version: _,
+    }: HttpRequest,
+    storage_state: &impl StorageStateStrategy,
+) -> HttpResponse {
+    if method != "GET" {
+        return error_response(RESPONSE_STATUS_CODE_405, "Method Not Allowed.".to_string());
+    }
+
+    let result = get_routing(url, &req_headers, true, storage_state);
+
+    match result {
+        Ok(Routing::Delegation(RoutingDelegation {
+            url: _,
+            redirect_url,
+            iframe,
+        })) => build_redirect_raw_response(&redirect_url, &iframe),
+        _ => error_response(RESPONSE_STATUS_CODE_405, "Method Not Allowed.".to_string()),
+    }
+}
+
 pub fn http_request_streaming_callback(
     StreamingCallbackToken {
         token,
@@ -84,6 +133,7 @@ pub fn http_request_streaming_callback(
         full_path,
         encoding_type,
         memory: _,
+        offset,
     }: StreamingCallbackToken,
     storage_state: &impl StorageStateStrategy,
 ) -> StreamingCallbackHttpResponse {
@@ -98,17 +148,26 @@ pub fn http_request_streaming_callback(
                     let body = storage_state.get_content_chunks(encoding, index, &memory);
 
                     match body {
-                        Some(body) => StreamingCallbackHttpResponse {
-                            token: create_token(
-                                &asset.key,
-                                index,
-                                encoding,
-                                &encoding_type,
-                                &headers,
-                                &memory,
-                            ),
-                            body: body.clone(),
-                        },
+                        Some(body) => {
+                            let (body, next_offset) = slice_body(
+                                &body,
+                                offset.unwrap_or_default(),
+                                storage_state.get_config().unwrap_max_response_body_size(),
+                            );
+
+                            StreamingCallbackHttpResponse {
+                                token: create_token(
+                                    &asset.key,
+                                    index,
+                                    next_offset,
+                                    encoding,
+                                    &encoding_type,
+                                    &headers,
+                                    &memory,
+                                ),
+                                body,
+                            }
+                        }
                         None => trap("Streamed chunks not found."),
                     }
                 }
@@ -1,7 +1,9 @@
+use crate::constants::DEFAULT_MAX_RESPONSE_BODY_SIZE;
 use crate::http::types::HeaderField;
 use crate::types::config::{
-    StorageConfig, StorageConfigHeaders, StorageConfigIFrame, StorageConfigRawAccess,
-    StorageConfigRedirects, StorageConfigRewrites,
+    StorageConfig, StorageConfigDelegations, StorageConfigHeaders, StorageConfigIFrame,
+    StorageConfigRawAccess, StorageConfigRedirects, StorageConfigRewrites,
+    StorageConfigWeightedRewrites,
 };
 use crate::types::interface::{AssetEncodingNoContent, AssetNoContent};
 use crate::types::state::StorageHeapState;
@@ -14,8 +16,8 @@ use junobuild_collections::types::interface::SetRule;
 use junobuild_collections::types::rules::{Memory, Rule, Rules};
 use junobuild_shared::serializers::{deserialize_from_bytes, serialize_to_bytes};
 use junobuild_shared::types::core::{Blob, Hash, Hashable};
-use junobuild_shared::types::state::Timestamp;
-use junobuild_shared::types::state::Timestamped;
+use junobuild_shared::types::state::{Moderated, ModerationStatus, Timestamp};
+use junobuild_shared::types::state::{Scheduled, Timestamped};
 use sha2::{Digest, Sha256};
 use std::borrow::Cow;
 use std::cmp::Ordering;
@@ -49,6 +51,10 @@ impl StorageHeapState {
                             updated_at: now,
                             version: rule.version,
                             rate_config: rule.rate_config,
+                            user_quota: rule.user_quota,
+                            collation: rule.collation,
+                            retention: rule.retention,
+                            compression: None,
                         },
                     )
                 })
@@ -60,6 +66,13 @@ impl StorageHeapState {
                 iframe: None,
                 raw_access: None,
                 max_memory_size: None,
+                weighted_rewrites: None,
+                locales: None,
+                list_limits: None,
+                delegations: None,
+                max_response_body_size: None,
+                csp_nonce: None,
+                wildcard_domains: None,
             },
             custom_domains: HashMap::new(),
         }
@@ -85,6 +98,7 @@ impl From<&Vec<Blob>> for AssetEncoding {
             content_chunks: content_chunks.clone(),
             total_length,
             sha256,
+            compression: None,
         }
     }
 }
@@ -94,6 +108,27 @@ impl StorageConfig {
         self.redirects.clone().unwrap_or_default()
     }
 
+    pub fn unwrap_weighted_rewrites(&self) -> StorageConfigWeightedRewrites {
+        self.weighted_rewrites.clone().unwrap_or_default()
+    }
+
+    pub fn unwrap_delegations(&self) -> StorageConfigDelegations {
+        self.delegations.clone().unwrap_or_default()
+    }
+
+    /// The bodies above the threshold are split and served with the streaming strategy.
+    pub fn unwrap_max_response_body_size(&self) -> usize {
+        self.max_response_body_size
+            .filter(|size| *size > 0)
+            .and_then(|size| usize::try_from(size).ok())
+            .unwrap_or(DEFAULT_MAX_RESPONSE_BODY_SIZE)
+    }
+
+    /// Injecting a nonce in the pages served through the raw domains is opt-in.
+    pub fn unwrap_csp_nonce(&self) -> bool {
+        self.csp_nonce.unwrap_or(false)
+    }
+
     pub fn unwrap_iframe(&self) -> StorageConfigIFrame {
         self.iframe.clone().unwrap_or(StorageConfigIFrame::Deny)
     }
@@ -162,6 +197,22 @@ impl Storable for Asset {
     const BOUND: Bound = Bound::Unbounded;
 }
 
+impl Scheduled for Asset {
+    fn publish_at(&self) -> Option<Timestamp> {
+        self.key.publish_at
+    }
+
+    fn unpublish_at(&self) -> Option<Timestamp> {
+        self.key.unpublish_at
+    }
+}
+
+impl Moderated for Asset {
+    fn moderation(&self) -> Option<ModerationStatus> {
+        self.key.moderation.clone()
+    }
+}
+
 impl Timestamped for Asset {
     fn created_at(&self) -> Timestamp {
         self.created_at
@@ -199,6 +250,12 @@ impl Hashable for AssetKey {
         if let Some(description) = &self.description {
             hasher.update(description.as_bytes());
         }
+        if let Some(publish_at) = &self.publish_at {
+            hasher.update(publish_at.to_le_bytes());
+        }
+        if let Some(unpublish_at) = &self.unpublish_at {
+            hasher.update(unpublish_at.to_le_bytes());
+        }
         hasher.finalize().into()
     }
 }
@@ -1,10 +1,12 @@
 pub mod certification;
 pub mod certified_assets;
+mod compression;
 pub mod constants;
 pub mod heap_utils;
 pub mod http;
 pub mod http_request;
 pub mod impls;
+pub mod locales;
 mod memory;
 pub mod msg;
 pub mod rewrites;
@@ -17,3 +19,4 @@ pub mod types;
 mod url;
 pub mod utils;
 pub mod well_known;
+pub mod wildcard_domains;
@@ -0,0 +1,137 @@
+use crate::http::types::HeaderField;
+use crate::http::utils::{get_cookie_value, get_header_value};
+use crate::types::config::{StorageConfig, StorageConfigLocales};
+use crate::types::state::FullPath;
+use std::cmp::Ordering;
+
+/// Resolves the paths of the localized asset trees that can serve the requested path.
+/// e.g. with supported locales "en" and "fr", a request /about with Accept-Language: fr-CH, fr;q=0.9 returns [/fr/about, /en/about] if "en" is the default locale.
+/// Returns an empty list if no locales are configured or if the path already targets a localized tree.
+pub fn localized_paths(
+    requested_path: &str,
+    req_headers: &[HeaderField],
+    config: &StorageConfig,
+) -> Vec<FullPath> {
+    let locales = match &config.locales {
+        None => {
+            return Vec::new();
+        }
+        Some(locales) => locales,
+    };
+
+    if locale_of_path(requested_path, locales).is_some() {
+        return Vec::new();
+    }
+
+    let preferred_locale = preferred_locale(req_headers, locales);
+
+    let mut paths: Vec<FullPath> = Vec::from([localize_path(&preferred_locale, requested_path)]);
+
+    if !preferred_locale.eq_ignore_ascii_case(&locales.default_locale) {
+        paths.push(localize_path(&locales.default_locale, requested_path));
+    }
+
+    paths
+}
+
+/// The path of a localized asset within the tree of its locale - i.e. the path for which it is served.
+/// e.g. /fr/about.html returns /about.html for the locale "fr".
+/// Returns `None` if the asset does not belong to one of the localized trees.
+pub fn localized_asset_path(full_path: &str, config: &StorageConfig) -> Option<FullPath> {
+    let locales = config.locales.as_ref()?;
+
+    let locale = locale_of_path(full_path, locales)?;
+
+    let path = full_path
+        .trim_start_matches('/')
+        .get(locale.len()..)?
+        .strip_prefix('/')?;
+
+    Some(format!("/{}", path))
+}
+
+fn localize_path(locale: &str, requested_path: &str) -> FullPath {
+    format!("/{}{}", locale, requested_path)
+}
+
+fn locale_of_path<'a>(path: &str, locales: &'a StorageConfigLocales) -> Option<&'a String> {
+    let first_segment = path.trim_start_matches('/').split('/').next().unwrap_or("");
+
+    supported_locales(locales).find(|locale| locale.eq_ignore_ascii_case(first_segment))
+}
+
+fn supported_locales(locales: &StorageConfigLocales) -> impl Iterator<Item = &String> {
+    locales
+        .supported_locales
+        .iter()
+        .chain(std::iter::once(&locales.default_locale))
+}
+
+// The override cookie - e.g. set when the user explicitly picks a language - wins over the Accept-Language header.
+// If none of the requested languages is supported, either exactly or by their primary subtag (fr-CH -> fr), the default locale is used.
+fn preferred_locale(req_headers: &[HeaderField], locales: &StorageConfigLocales) -> String {
+    let cookie_locale = locales
+        .cookie
+        .as_ref()
+        .and_then(|cookie| get_cookie_value(req_headers, cookie))
+        .and_then(|value| find_supported_locale(&value, locales));
+
+    if let Some(locale) = cookie_locale {
+        return locale;
+    }
+
+    let accept_language = get_header_value(req_headers, "Accept-Language").unwrap_or_default();
+
+    accept_languages(&accept_language)
+        .iter()
+        .find_map(|language| {
+            find_supported_locale(language, locales).or_else(|| {
+                language
+                    .split('-')
+                    .next()
+                    .and_then(|primary| find_supported_locale(primary, locales))
+            })
+        })
+        .unwrap_or(locales.default_locale.clone())
+}
+
+fn find_supported_locale(language: &str, locales: &StorageConfigLocales) -> Option<String> {
+    supported_locales(locales)
+        .find(|locale| locale.eq_ignore_ascii_case(language.trim()))
+        .cloned()
+}
+
+// e.g. "fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5" -> ["fr-CH", "fr", "en"]
+fn accept_languages(accept_language: &str) -> Vec<String> {
+    let mut languages: Vec<(String, f32)> = accept_language
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.trim().split(';');
+
+            let language = parts.next()?.trim();
+
+            if language.is_empty() || language == "*" {
+                return None;
+            }
+
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            if quality <= 0.0 {
+                return None;
+            }
+
+            Some((language.to_string(), quality))
+        })
+        .collect();
+
+    // Stable sort: languages with the same quality keep the order of the header
+    languages.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+
+    languages
+        .into_iter()
+        .map(|(language, _)| language)
+        .collect()
+}
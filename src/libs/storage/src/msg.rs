@@ -1,4 +1,9 @@
 pub const UPLOAD_NOT_ALLOWED: &str = "Caller not allowed to upload data.";
+pub const ERROR_UPLOAD_NOT_ALLOWED: &str = "error_upload_not_allowed";
 pub const SET_NOT_ALLOWED: &str = "Caller not allowed to set data.";
 pub const ERROR_CANNOT_COMMIT_BATCH: &str = "Cannot commit batch.";
 pub const ERROR_ASSET_NOT_FOUND: &str = "No asset.";
+pub const ERROR_ASSET_MAX_SIZE: &str = "error_asset_max_size_exceeded";
+pub const ERROR_INVALID_WILDCARD_DOMAIN: &str = "Wildcard domain must start with *.";
+pub const ERROR_INVALID_WILDCARD_DOMAIN_PREFIX: &str =
+    "Path prefix of the tenants of a wildcard domain must be a non-empty absolute path.";
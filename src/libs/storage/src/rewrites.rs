@@ -1,6 +1,12 @@
 use crate::constants::ROOT_PATHS;
-use crate::types::config::{StorageConfig, StorageConfigRedirect};
+use crate::http::types::HeaderField;
+use crate::http::utils::{get_cookie_value, get_header_value};
+use crate::types::config::{
+    StorageConfig, StorageConfigDelegation, StorageConfigRedirect, StorageConfigRewriteSession,
+    StorageConfigWeightedRewrite,
+};
 use crate::url::{matching_urls as matching_urls_utils, separator};
+use sha2::{Digest, Sha256};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
@@ -14,6 +20,70 @@ pub fn rewrite_url(requested_path: &str, config: &StorageConfig) -> Option<(Stri
         .map(|(source, destination)| (rewrite_source_to_path(source).clone(), destination.clone()))
 }
 
+pub fn weighted_rewrite_url(
+    requested_path: &str,
+    req_headers: &[HeaderField],
+    config: &StorageConfig,
+) -> Option<(String, String)> {
+    let weighted_rewrites = config.unwrap_weighted_rewrites();
+
+    let matches = matching_urls(requested_path, &weighted_rewrites);
+
+    matches.first().and_then(|(source, rewrite)| {
+        weighted_destination(source, rewrite, req_headers)
+            .map(|destination| (rewrite_source_to_path(source), destination))
+    })
+}
+
+// The bucket of a session is derived from a hash of the rewrite source and the session identifier.
+// That way a visitor always lands on the same destination as long as the configuration does not change.
+// Requests without session identifier are routed to the first destination - i.e. the control group.
+fn weighted_destination(
+    source: &str,
+    StorageConfigWeightedRewrite {
+        destinations,
+        session,
+    }: &StorageConfigWeightedRewrite,
+    req_headers: &[HeaderField],
+) -> Option<String> {
+    let total_weight: u64 = destinations.iter().map(|d| u64::from(d.weight)).sum();
+
+    if total_weight == 0 {
+        return None;
+    }
+
+    let session_id = match session {
+        StorageConfigRewriteSession::Cookie(name) => get_cookie_value(req_headers, name),
+        StorageConfigRewriteSession::Header(name) => get_header_value(req_headers, name),
+    };
+
+    let bucket = match session_id {
+        None => 0,
+        Some(session_id) => session_bucket(source, &session_id) % total_weight,
+    };
+
+    let mut cumulative_weight: u64 = 0;
+
+    destinations
+        .iter()
+        .find(|destination| {
+            cumulative_weight += u64::from(destination.weight);
+            bucket < cumulative_weight
+        })
+        .map(|destination| destination.destination.clone())
+}
+
+fn session_bucket(source: &str, session_id: &str) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update(source.as_bytes());
+    hasher.update(session_id.as_bytes());
+    let hash: [u8; 32] = hasher.finalize().into();
+
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&hash[0..8]);
+    u64::from_be_bytes(bytes)
+}
+
 pub fn rewrite_source_to_path(source: &str) -> String {
     [separator(source), source].join("").replace('*', "")
 }
@@ -30,6 +100,17 @@ pub fn redirect_url(requested_path: &str, config: &StorageConfig) -> Option<Stor
     matches.first().map(|(_, redirect)| redirect.clone())
 }
 
+pub fn delegation_url(
+    requested_path: &str,
+    config: &StorageConfig,
+) -> Option<StorageConfigDelegation> {
+    let delegations = config.unwrap_delegations();
+
+    let matches = matching_urls(requested_path, &delegations);
+
+    matches.first().map(|(_, delegation)| delegation.clone())
+}
+
 fn matching_urls<T: Clone>(requested_path: &str, config: &HashMap<String, T>) -> Vec<(String, T)> {
     let mut matches: Vec<(String, T)> = matching_urls_utils(requested_path, config);
 
@@ -1,17 +1,22 @@
 use crate::constants::{
-    RAW_DOMAINS, RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_404, ROOT_404_HTML,
-    ROOT_INDEX_HTML, ROOT_PATH,
+    RESPONSE_STATUS_CODE_200, RESPONSE_STATUS_CODE_404, ROOT_404_HTML, ROOT_INDEX_HTML, ROOT_PATH,
 };
 use crate::http::types::HeaderField;
-use crate::rewrites::{is_root_path, redirect_url, rewrite_url};
+use crate::http::utils::is_raw_request;
+use crate::locales::localized_paths;
+use crate::rewrites::{
+    delegation_url, is_root_path, redirect_url, rewrite_url, weighted_rewrite_url,
+};
 use crate::strategies::StorageStateStrategy;
-use crate::types::config::StorageConfigRawAccess;
+use crate::types::config::{StorageConfigDelegation, StorageConfigRawAccess};
 use crate::types::http_request::{
-    MapUrl, Routing, RoutingDefault, RoutingRedirect, RoutingRedirectRaw, RoutingRewrite,
+    MapUrl, Routing, RoutingDefault, RoutingDelegation, RoutingRedirect, RoutingRedirectRaw,
+    RoutingRewrite,
 };
 use crate::types::state::FullPath;
 use crate::types::store::Asset;
 use crate::url::{map_alternative_paths, map_url};
+use crate::wildcard_domains::tenant_path;
 use ic_cdk::id;
 use junobuild_collections::types::rules::Memory;
 
@@ -43,6 +48,18 @@ pub fn get_routing(
 
     let MapUrl { path, token } = map_url(&url)?;
 
+    // The assets of a tenant take precedence over those of the satellite on its wildcard domain
+    if include_alternative_routing {
+        let tenant = get_routing_tenant(&path, &token, req_headers, storage_state);
+
+        match tenant {
+            None => (),
+            Some(tenant) => {
+                return Ok(tenant);
+            }
+        }
+    }
+
     // We return the asset that matches the effective path
     let asset: Option<(Asset, Memory)> =
         storage_state.get_public_asset(path.clone(), token.clone());
@@ -69,6 +86,16 @@ pub fn get_routing(
     }
 
     if include_alternative_routing {
+        // Search for potential delegation to another canister - e.g. a media satellite
+        let delegation = get_routing_delegation(&url, &path, storage_state);
+
+        match delegation {
+            None => (),
+            Some(delegation) => {
+                return Ok(delegation);
+            }
+        }
+
         // Search for potential redirect
         let redirect = get_routing_redirect(&path, storage_state);
 
@@ -79,8 +106,18 @@ pub fn get_routing(
             }
         }
 
+        // Search for potential localized asset
+        let locale = get_routing_locale(&path, &token, req_headers, storage_state);
+
+        match locale {
+            None => (),
+            Some(locale) => {
+                return Ok(locale);
+            }
+        }
+
         // Search for potential rewrite
-        let rewrite = get_routing_rewrite(&path, &token, storage_state);
+        let rewrite = get_routing_rewrite(&path, &token, req_headers, storage_state);
 
         match rewrite {
             None => (),
@@ -129,14 +166,72 @@ fn get_alternative_asset(
     None
 }
 
+fn get_routing_tenant(
+    path: &FullPath,
+    token: &Option<String>,
+    req_headers: &[HeaderField],
+    storage_state: &impl StorageStateStrategy,
+) -> Option<Routing> {
+    let config = storage_state.get_config();
+
+    // e.g. / on acme.customers.example.com -> /tenants/acme/index.html
+    let tenant_path = tenant_path(path, req_headers, &config)?;
+
+    let tenant_asset: Option<(Asset, Memory)> = storage_state
+        .get_public_asset(tenant_path.clone(), token.clone())
+        .or_else(|| get_alternative_asset(&tenant_path, token, storage_state));
+
+    // Served as the exact path for which the asset of the tenant is certified
+    tenant_asset.map(|asset| {
+        Routing::Default(RoutingDefault {
+            url: path.clone(),
+            asset: Some(asset),
+        })
+    })
+}
+
+fn get_routing_locale(
+    path: &FullPath,
+    token: &Option<String>,
+    req_headers: &[HeaderField],
+    storage_state: &impl StorageStateStrategy,
+) -> Option<Routing> {
+    let config = storage_state.get_config();
+
+    // e.g. / -> /fr/index.html or /about -> /fr/about.html according the Accept-Language of the request
+    for localized_path in localized_paths(path, req_headers, &config) {
+        let localized_asset: Option<(Asset, Memory)> = storage_state
+            .get_public_asset(localized_path.clone(), token.clone())
+            .or_else(|| get_alternative_asset(&localized_path, token, storage_state));
+
+        match localized_asset {
+            None => (),
+            // Served as the exact path for which the localized asset is certified
+            Some(_) => {
+                return Some(Routing::Default(RoutingDefault {
+                    url: path.clone(),
+                    asset: localized_asset,
+                }));
+            }
+        }
+    }
+
+    None
+}
+
 fn get_routing_rewrite(
     path: &FullPath,
     token: &Option<String>,
+    req_headers: &[HeaderField],
     storage_state: &impl StorageStateStrategy,
 ) -> Option<Routing> {
+    let config = storage_state.get_config();
+
     // If we have found no asset, we try a rewrite rule
     // This is for example useful for single-page app to redirect all urls to /index.html
-    let rewrite = rewrite_url(path, &storage_state.get_config());
+    // Weighted rewrites - e.g. for A/B testing - take precedence over the plain rewrites
+    let rewrite =
+        weighted_rewrite_url(path, req_headers, &config).or_else(|| rewrite_url(path, &config));
 
     match rewrite {
         None => (),
@@ -243,14 +338,47 @@ fn get_routing_redirect(
     None
 }
 
+fn get_routing_delegation(
+    url: &str,
+    path: &FullPath,
+    storage_state: &impl StorageStateStrategy,
+) -> Option<Routing> {
+    let config = storage_state.get_config();
+    let delegation = delegation_url(path, &config);
+
+    // The query parameters - e.g. a token - are forwarded to the target canister
+    delegation.map(|delegation| {
+        Routing::Delegation(RoutingDelegation {
+            url: path.clone(),
+            redirect_url: delegation_redirect_url(url, &delegation),
+            iframe: config.unwrap_iframe(),
+        })
+    })
+}
+
+pub fn delegation_redirect_url(
+    url: &str,
+    StorageConfigDelegation {
+        canister_id,
+        raw,
+        domain,
+    }: &StorageConfigDelegation,
+) -> String {
+    let domain = match (domain, raw) {
+        (Some(domain), _) => domain.clone(),
+        (None, Some(true)) => format!("{}.raw.icp0.io", canister_id.to_text()),
+        (None, _) => format!("{}.icp0.io", canister_id.to_text()),
+    };
+
+    format!("https://{domain}{url}")
+}
+
 fn get_routing_redirect_raw(
     url: &String,
     req_headers: &[HeaderField],
     storage_state: &impl StorageStateStrategy,
 ) -> Option<Routing> {
-    let raw = req_headers.iter().any(|HeaderField(key, value)| {
-        key.eq_ignore_ascii_case("Host") && RAW_DOMAINS.iter().any(|domain| value.contains(domain))
-    });
+    let raw = is_raw_request(req_headers);
 
     let config = storage_state.get_config();
 
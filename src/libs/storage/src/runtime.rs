@@ -1,15 +1,20 @@
 use crate::certification::cert::update_certified_data;
 use crate::certification::types::certified::CertifiedAssetHashes;
+use crate::http::types::HeaderField;
 use crate::memory::STATE;
-use crate::types::config::StorageConfig;
+use crate::types::config::{StorageConfig, StorageConfigIFrame, StorageConfigRedirect};
+use crate::types::interface::CertifiedPath;
 use crate::types::runtime_state::{
     BatchId, Batches, ChunkId, Chunks, RuntimeState, StorageRuntimeState,
 };
+use crate::types::state::FullPath;
 use crate::types::store::{Asset, Batch, BatchExpiry, Chunk};
 use ic_cdk::api::time;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_shared::rate::types::RateConfig;
 use junobuild_shared::rate::utils::increment_and_assert_rate_store;
+use junobuild_shared::types::core::Blob;
+use junobuild_shared::utils::is_published;
 use std::collections::HashMap;
 
 // ---------------------------------------------------------
@@ -26,8 +31,57 @@ pub fn update_certified_asset(asset: &Asset, config: &StorageConfig) {
     STATE.with(|state| update_certified_asset_impl(asset, config, &mut state.borrow_mut().runtime));
 }
 
-pub fn delete_certified_asset(asset: &Asset) {
-    STATE.with(|state| delete_certified_asset_impl(asset, &mut state.borrow_mut().runtime));
+pub fn delete_certified_asset(asset: &Asset, config: &StorageConfig) {
+    STATE.with(|state| delete_certified_asset_impl(asset, config, &mut state.borrow_mut().runtime));
+}
+
+/// Replaces the responses certified for a path that is not an asset - e.g. a document served over HTTP.
+pub fn update_certified_static(full_path: &FullPath, responses: &[(Vec<HeaderField>, Blob)]) {
+    STATE.with(|state| {
+        update_certified_static_impl(full_path, responses, &mut state.borrow_mut().runtime)
+    });
+}
+
+pub fn delete_certified_static(full_path: &FullPath) {
+    STATE.with(|state| delete_certified_static_impl(full_path, &mut state.borrow_mut().runtime));
+}
+
+pub fn is_certified_static(
+    full_path: &FullPath,
+    headers: &[HeaderField],
+    body: &[u8],
+    certificate_version: &Option<u16>,
+) -> bool {
+    STATE.with(|state| {
+        state.borrow().runtime.storage.asset_hashes.contains_static(
+            full_path,
+            headers,
+            body,
+            certificate_version,
+        )
+    })
+}
+
+pub fn is_certified_redirect(
+    full_path: &FullPath,
+    StorageConfigRedirect {
+        location,
+        status_code,
+    }: &StorageConfigRedirect,
+    iframe: &StorageConfigIFrame,
+) -> bool {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .runtime
+            .storage
+            .asset_hashes
+            .contains_redirect_v2(full_path, *status_code, location, iframe)
+    })
+}
+
+pub fn list_certified_paths() -> Vec<CertifiedPath> {
+    STATE.with(|state| state.borrow().runtime.storage.asset_hashes.list_paths())
 }
 
 fn init_certified_assets_impl(
@@ -42,16 +96,47 @@ fn init_certified_assets_impl(
 }
 
 fn update_certified_asset_impl(asset: &Asset, config: &StorageConfig, runtime: &mut RuntimeState) {
-    // 1. Replace or insert the new asset in tree
-    runtime.storage.asset_hashes.insert(asset, config);
+    // 1. Replace or insert the new asset in tree - or remove it if it is not or no longer published
+    if is_published(asset, time()) {
+        runtime.storage.asset_hashes.insert(asset, config);
+    } else {
+        runtime.storage.asset_hashes.delete(asset, config);
+    }
 
     // 2. Update the root hash and the canister certified data
     update_certified_data(&runtime.storage.asset_hashes);
 }
 
-fn delete_certified_asset_impl(asset: &Asset, runtime: &mut RuntimeState) {
+fn delete_certified_asset_impl(asset: &Asset, config: &StorageConfig, runtime: &mut RuntimeState) {
     // 1. Remove the asset in tree
-    runtime.storage.asset_hashes.delete(asset);
+    runtime.storage.asset_hashes.delete(asset, config);
+
+    // 2. Update the root hash and the canister certified data
+    update_certified_data(&runtime.storage.asset_hashes);
+}
+
+fn update_certified_static_impl(
+    full_path: &FullPath,
+    responses: &[(Vec<HeaderField>, Blob)],
+    runtime: &mut RuntimeState,
+) {
+    // 1. Replace the responses of the path in tree
+    runtime.storage.asset_hashes.delete_static(full_path);
+
+    for (headers, body) in responses {
+        runtime
+            .storage
+            .asset_hashes
+            .insert_static_v2(full_path, headers, body);
+    }
+
+    // 2. Update the root hash and the canister certified data
+    update_certified_data(&runtime.storage.asset_hashes);
+}
+
+fn delete_certified_static_impl(full_path: &FullPath, runtime: &mut RuntimeState) {
+    // 1. Remove the responses of the path in tree
+    runtime.storage.asset_hashes.delete_static(full_path);
 
     // 2. Update the root hash and the canister certified data
     update_certified_data(&runtime.storage.asset_hashes);
@@ -83,6 +168,10 @@ pub fn insert_batch(batch_id: &BatchId, batch: Batch) {
     })
 }
 
+pub fn count_batches() -> usize {
+    STATE.with(|state| state.borrow().runtime.storage.batches.len())
+}
+
 pub fn clear_expired_batches() {
     STATE.with(|state| clear_expired_batches_impl(&mut state.borrow_mut().runtime.storage.batches));
 }
@@ -144,6 +233,20 @@ pub fn get_chunk(chunk_id: &ChunkId) -> Option<Chunk> {
     })
 }
 
+/// The chunk already uploaded for a given position in a batch, if any.
+pub fn get_chunk_id_by_order(batch_id: &BatchId, order_id: u128) -> Option<ChunkId> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .runtime
+            .storage
+            .chunks
+            .iter()
+            .find(|(_, chunk)| chunk.batch_id == *batch_id && chunk.order_id == order_id)
+            .map(|(chunk_id, _)| *chunk_id)
+    })
+}
+
 pub fn clear_expired_chunks() {
     STATE.with(|state| clear_expired_chunks_impl(&mut state.borrow_mut().runtime.storage));
 }
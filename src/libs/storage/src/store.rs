@@ -1,13 +1,17 @@
+use crate::compression::assert_compressed_encoding;
 use crate::constants::{
     ASSET_ENCODING_NO_COMPRESSION, ENCODING_CERTIFICATION_ORDER, WELL_KNOWN_CUSTOM_DOMAINS,
     WELL_KNOWN_II_ALTERNATIVE_ORIGINS,
 };
-use crate::msg::{ERROR_CANNOT_COMMIT_BATCH, UPLOAD_NOT_ALLOWED};
+use crate::msg::{
+    ERROR_ASSET_MAX_SIZE, ERROR_CANNOT_COMMIT_BATCH, ERROR_UPLOAD_NOT_ALLOWED, UPLOAD_NOT_ALLOWED,
+};
 use crate::runtime::{
     clear_batch as clear_runtime_batch, clear_expired_batches as clear_expired_runtime_batches,
     clear_expired_chunks as clear_expired_runtime_chunks, get_batch as get_runtime_batch,
-    get_chunk as get_runtime_chunk, increment_and_assert_rate,
-    insert_batch as insert_runtime_batch, insert_chunk as insert_runtime_chunk,
+    get_chunk as get_runtime_chunk, get_chunk_id_by_order as get_runtime_chunk_id_by_order,
+    increment_and_assert_rate, insert_batch as insert_runtime_batch,
+    insert_chunk as insert_runtime_chunk,
 };
 use crate::strategies::{StorageAssertionsStrategy, StorageStateStrategy, StorageUploadStrategy};
 use crate::types::config::StorageConfig;
@@ -23,9 +27,12 @@ use junobuild_collections::assert_stores::{assert_create_permission, assert_perm
 use junobuild_collections::constants::{DEFAULT_ASSETS_COLLECTIONS, SYS_COLLECTION_PREFIX};
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::Rule;
-use junobuild_shared::assert::{assert_description_length, assert_max_memory_size};
+use junobuild_shared::assert::{
+    assert_description_length, assert_max_memory_size, assert_schedule,
+};
 use junobuild_shared::constants::INITIAL_VERSION;
 use junobuild_shared::controllers::is_controller;
+use junobuild_shared::errors::JunoError;
 use junobuild_shared::types::core::Blob;
 use junobuild_shared::types::state::Controllers;
 use junobuild_shared::utils::principal_not_equal;
@@ -55,6 +62,8 @@ pub fn create_batch(
 
     assert_description_length(&init.description)?;
 
+    assert_schedule(&init.publish_at, &init.unpublish_at)?;
+
     let rule = storage_state.get_rule(&init.collection)?;
 
     increment_and_assert_rate(&init.collection, &rule.rate_config)?;
@@ -74,6 +83,8 @@ fn create_batch_impl(
         encoding_type,
         full_path,
         description,
+        publish_at,
+        unpublish_at,
     }: InitAssetKey,
     reference_id: Option<ReferenceId>,
 ) -> BatchId {
@@ -91,6 +102,9 @@ fn create_batch_impl(
             token,
             name,
             description,
+            publish_at,
+            unpublish_at,
+            moderation: None,
         };
 
         insert_runtime_batch(
@@ -107,6 +121,11 @@ fn create_batch_impl(
     }
 }
 
+/// Uploads a chunk of a batch.
+///
+/// The upload is idempotent per position - i.e. a chunk uploaded again with the same `order_id` for the same batch,
+/// for example when a client retries after a network failure, replaces the content of the previous one and its ID is
+/// returned. That way a retry cannot duplicate content in the committed asset.
 pub fn create_chunk(
     caller: Principal,
     config: &StorageConfig,
@@ -138,6 +157,21 @@ pub fn create_chunk(
                 },
             );
 
+            if let Some(order_id) = order_id {
+                if let Some(chunk_id) = get_runtime_chunk_id_by_order(&batch_id, order_id) {
+                    insert_runtime_chunk(
+                        &chunk_id,
+                        Chunk {
+                            batch_id,
+                            content,
+                            order_id,
+                        },
+                    );
+
+                    return Ok(chunk_id);
+                }
+            }
+
             unsafe {
                 NEXT_CHUNK_ID += 1;
 
@@ -194,7 +228,7 @@ fn assert_key(
     full_path: &FullPath,
     collection: &CollectionKey,
     controllers: &Controllers,
-) -> Result<(), &'static str> {
+) -> Result<(), String> {
     // /.well-known/ic-domains is automatically generated for custom domains
     assert_well_known_key(full_path, WELL_KNOWN_CUSTOM_DOMAINS)?;
 
@@ -205,12 +239,12 @@ fn assert_key(
 
     // Only controllers can write in collection #dapp
     if collection.clone() == *dapp_collection && !is_controller(caller, controllers) {
-        return Err(UPLOAD_NOT_ALLOWED);
+        return Err(JunoError::new(ERROR_UPLOAD_NOT_ALLOWED, UPLOAD_NOT_ALLOWED).into());
     }
 
     // Only controllers can write in reserved collections starting with #
     if collection.starts_with(SYS_COLLECTION_PREFIX) && !is_controller(caller, controllers) {
-        return Err(UPLOAD_NOT_ALLOWED);
+        return Err(JunoError::new(ERROR_UPLOAD_NOT_ALLOWED, UPLOAD_NOT_ALLOWED).into());
     }
 
     // Asset uploaded by users should be prefixed with the collection. That way developers can organize assets to particular folders.
@@ -221,7 +255,7 @@ fn assert_key(
     if collection.clone() != *dapp_collection
         && !full_path.starts_with(&["/", collection_path, "/"].join(""))
     {
-        return Err("Asset path must be prefixed with collection key.");
+        return Err("Asset path must be prefixed with collection key.".to_string());
     }
 
     Ok(())
@@ -366,10 +400,14 @@ fn commit_chunks(
         headers,
     } = commit_batch;
 
-    // Collect all chunks
-    let mut chunks: Vec<Chunk> = vec![];
+    // Collect all chunks - once, regardless of how many times their IDs are provided
+    let mut chunks: Vec<(ChunkId, Chunk)> = vec![];
+
+    let mut unique_chunk_ids = chunk_ids.clone();
+    unique_chunk_ids.sort();
+    unique_chunk_ids.dedup();
 
-    for chunk_id in chunk_ids.iter() {
+    for chunk_id in unique_chunk_ids.iter() {
         let chunk = get_runtime_chunk(chunk_id);
 
         match chunk {
@@ -381,18 +419,19 @@ fn commit_chunks(
                     return Err("Chunk not included in the provided batch.".to_string());
                 }
 
-                chunks.push(c);
+                chunks.push((*chunk_id, c));
             }
         }
     }
 
-    // Sort with ordering
-    chunks.sort_by(|a, b| a.order_id.cmp(&b.order_id));
+    // Sort with ordering. The chunks are assembled by ascending order_id, those sharing a position - e.g. uploaded
+    // without order_id - by ascending chunk ID, so that the content does not depend on the order of the commit.
+    chunks.sort_by(|(a_id, a), (b_id, b)| a.order_id.cmp(&b.order_id).then(a_id.cmp(b_id)));
 
     let mut content_chunks: Vec<Blob> = vec![];
 
     // Collect content
-    for c in chunks.iter() {
+    for (_, c) in chunks.iter() {
         content_chunks.push(c.content.clone());
     }
 
@@ -435,11 +474,38 @@ fn commit_chunks(
         Some(max_size) => {
             if encoding.total_length > max_size {
                 clear_runtime_batch(&batch_id, &chunk_ids);
-                return Err("Asset exceed max allowed size.".to_string());
+                return Err(
+                    JunoError::new(ERROR_ASSET_MAX_SIZE, "Asset exceed max allowed size.")
+                        .with_param("max_size", max_size)
+                        .into(),
+                );
             }
         }
     }
 
+    // The size of the asset once the encoding is set - i.e. the other encodings remain.
+    let bytes = asset
+        .encodings
+        .iter()
+        .filter(|(current_type, _)| **current_type != encoding_type)
+        .map(|(_, current_encoding)| current_encoding.total_length)
+        .sum::<u128>()
+        + encoding.total_length;
+
+    if let Err(err) = assertions.assert_user_quota(&caller, &asset.key, rule, current, bytes) {
+        clear_runtime_batch(&batch_id, &chunk_ids);
+        return Err(err);
+    }
+
+    if let Err(err) = assert_compressed_encoding(
+        &encoding_type,
+        &content_chunks,
+        &asset.encodings.get(ASSET_ENCODING_NO_COMPRESSION),
+    ) {
+        clear_runtime_batch(&batch_id, &chunk_ids);
+        return Err(err);
+    }
+
     storage_upload.insert_asset_encoding(
         &batch.clone().key.full_path,
         &encoding_type,
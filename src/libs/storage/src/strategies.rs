@@ -1,7 +1,7 @@
 use crate::types::config::StorageConfig;
 use crate::types::state::FullPath;
 use crate::types::store::{
-    Asset, AssetAssertUpload, AssetEncoding, Batch, EncodingType, ReferenceId,
+    Asset, AssetAssertUpload, AssetEncoding, AssetKey, Batch, EncodingType, ReferenceId,
 };
 use candid::Principal;
 use junobuild_collections::types::core::CollectionKey;
@@ -15,6 +15,15 @@ pub trait StorageAssertionsStrategy {
         caller: &Principal,
         asset: &AssetAssertUpload,
     ) -> Result<(), String>;
+
+    fn assert_user_quota(
+        &self,
+        caller: &Principal,
+        key: &AssetKey,
+        rule: &Rule,
+        current: &Option<Asset>,
+        bytes: u128,
+    ) -> Result<(), String>;
 }
 
 pub trait StorageStateStrategy {
@@ -37,6 +46,9 @@ pub trait StorageStateStrategy {
 
     fn get_domains(&self) -> CustomDomains;
 
+    /// Random bytes unknown outside of the canister - e.g. to derive the nonces of the Content-Security-Policy.
+    fn get_random_seed(&self) -> Option<[u8; 32]>;
+
     fn get_asset(
         &self,
         collection: &CollectionKey,
@@ -68,8 +68,9 @@ pub mod store {
     use candid::CandidType;
     use ic_certification::Hash;
     use junobuild_collections::types::core::CollectionKey;
+    use junobuild_collections::types::rules::Compression;
     use junobuild_shared::types::core::Blob;
-    use junobuild_shared::types::state::{Timestamp, UserId, Version};
+    use junobuild_shared::types::state::{ModerationStatus, Timestamp, UserId, Version};
     use serde::{Deserialize, Serialize};
     use std::clone::Clone;
     use std::collections::HashMap;
@@ -90,6 +91,8 @@ pub mod store {
         pub content_chunks: Vec<BlobOrKey>,
         pub total_length: u128,
         pub sha256: Hash,
+        // The codec of the content chunks written to stable memory, if they were compressed
+        pub compression: Option<Compression>,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -106,6 +109,11 @@ pub mod store {
         pub owner: UserId,
         // A description field which can be useful for search purpose
         pub description: Option<String>,
+        // Scheduled publication: the asset is served and listed - except for its owner and the controllers - from publish_at until unpublish_at
+        pub publish_at: Option<Timestamp>,
+        pub unpublish_at: Option<Timestamp>,
+        // Moderation: in collections requiring a review, the asset is served and listed - except for its owner and the controllers - once approved
+        pub moderation: Option<ModerationStatus>,
     }
 
     pub type EncodingType = String;
@@ -163,6 +171,8 @@ pub mod interface {
         pub collection: CollectionKey,
         pub encoding_type: Option<EncodingType>,
         pub description: Option<String>,
+        pub publish_at: Option<Timestamp>,
+        pub unpublish_at: Option<Timestamp>,
     }
 
     #[derive(CandidType)]
@@ -205,18 +215,31 @@ pub mod interface {
         pub total_length: u128,
         pub sha256: Hash,
     }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct CertifiedPath {
+        pub url: String,
+        pub sha256: Hash,
+    }
 }
 
 pub mod config {
     use crate::http::types::{HeaderField, StatusCode};
-    use candid::CandidType;
+    use candid::{CandidType, Principal};
     use junobuild_shared::types::config::ConfigMaxMemorySize;
+    use junobuild_shared::types::core::DomainName;
+    use junobuild_shared::types::list::ListLimits;
     use serde::{Deserialize, Serialize};
     use std::collections::HashMap;
 
     pub type StorageConfigHeaders = HashMap<String, Vec<HeaderField>>;
     pub type StorageConfigRewrites = HashMap<String, String>;
     pub type StorageConfigRedirects = HashMap<String, StorageConfigRedirect>;
+    pub type StorageConfigWeightedRewrites = HashMap<String, StorageConfigWeightedRewrite>;
+    pub type StorageConfigDelegations = HashMap<String, StorageConfigDelegation>;
+    /// The wildcard custom domains - e.g. `*.customers.example.com` - and the path prefix of the assets of their
+    /// tenants, each subdomain being served its own tree of assets.
+    pub type StorageConfigWildcardDomains = HashMap<String, String>;
 
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub enum StorageConfigIFrame {
@@ -241,6 +264,13 @@ pub mod config {
         pub iframe: Option<StorageConfigIFrame>,
         pub raw_access: Option<StorageConfigRawAccess>,
         pub max_memory_size: Option<StorageConfigMaxMemorySize>,
+        pub weighted_rewrites: Option<StorageConfigWeightedRewrites>,
+        pub locales: Option<StorageConfigLocales>,
+        pub list_limits: Option<ListLimits>,
+        pub delegations: Option<StorageConfigDelegations>,
+        pub max_response_body_size: Option<u64>,
+        pub csp_nonce: Option<bool>,
+        pub wildcard_domains: Option<StorageConfigWildcardDomains>,
     }
 
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
@@ -248,6 +278,42 @@ pub mod config {
         pub location: String,
         pub status_code: StatusCode,
     }
+
+    /// Serves the matching paths from another canister - e.g. a satellite dedicated to media.
+    /// The request is answered with a certified redirect to the target canister, which certifies its own assets.
+    /// `raw` redirects to the uncertified raw domain instead. `domain` - e.g. a custom domain of the target - replaces
+    /// the domain derived from the canister ID.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct StorageConfigDelegation {
+        pub canister_id: Principal,
+        pub raw: Option<bool>,
+        pub domain: Option<DomainName>,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct StorageConfigWeightedRewrite {
+        pub destinations: Vec<StorageConfigWeightedDestination>,
+        pub session: StorageConfigRewriteSession,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct StorageConfigWeightedDestination {
+        pub destination: String,
+        pub weight: u32,
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub enum StorageConfigRewriteSession {
+        Cookie(String),
+        Header(String),
+    }
+
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct StorageConfigLocales {
+        pub default_locale: String,
+        pub supported_locales: Vec<String>,
+        pub cookie: Option<String>,
+    }
 }
 
 pub mod http_request {
@@ -269,6 +335,7 @@ pub mod http_request {
         Rewrite(RoutingRewrite),
         Redirect(RoutingRedirect),
         RedirectRaw(RoutingRedirectRaw),
+        Delegation(RoutingDelegation),
     }
 
     #[derive(CandidType, Deserialize, Clone)]
@@ -297,4 +364,11 @@ pub mod http_request {
         pub redirect_url: String,
         pub iframe: StorageConfigIFrame,
     }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct RoutingDelegation {
+        pub url: String,
+        pub redirect_url: String,
+        pub iframe: StorageConfigIFrame,
+    }
 }
@@ -11,7 +11,9 @@ use junobuild_collections::assert_stores::assert_permission;
 use junobuild_collections::types::core::CollectionKey;
 use junobuild_collections::types::rules::Permission;
 use junobuild_shared::constants::INITIAL_VERSION;
-use junobuild_shared::list::{filter_timestamps, matcher_regex};
+use junobuild_shared::list::{
+    filter_expression, filter_published, filter_timestamps, matcher_regex,
+};
 use junobuild_shared::types::core::Blob;
 use junobuild_shared::types::list::ListParams;
 use junobuild_shared::types::state::{Controllers, Timestamp, UserId, Version};
@@ -37,6 +39,8 @@ pub fn filter_values<'a>(
 ) -> Vec<(&'a FullPath, &'a Asset)> {
     let (regex_key, regex_description) = matcher_regex(matcher);
 
+    let now = time();
+
     assets
         .iter()
         .filter_map(|(key, asset)| {
@@ -45,7 +49,15 @@ pub fn filter_values<'a>(
                 && filter_description(&regex_description, asset)
                 && filter_owner(*owner, asset)
                 && filter_timestamps(matcher, *asset)
+                && filter_expression(
+                    matcher,
+                    &asset.key.full_path,
+                    &asset.key.description,
+                    &asset.key.owner,
+                    *asset,
+                )
                 && assert_permission(rule, asset.key.owner, caller, controllers)
+                && filter_published(*asset, asset.key.owner, caller, controllers, now)
             {
                 Some((*key, *asset))
             } else {
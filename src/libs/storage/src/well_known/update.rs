@@ -90,7 +90,7 @@ fn delete_asset(
     let asset = storage_state.delete_asset(&collection, full_path, &rule);
 
     if let Some(asset) = asset {
-        delete_certified_asset(&asset);
+        delete_certified_asset(&asset, &storage_state.get_config());
     }
 
     Ok(())
@@ -16,6 +16,9 @@ pub fn map_custom_domains_asset(custom_domains: &str, existing_asset: Option<Ass
         collection: ASSET_COLLECTION_KEY.to_string(),
         owner: id(),
         description: None,
+        publish_at: None,
+        unpublish_at: None,
+        moderation: None,
     };
 
     let headers = map_content_type_headers("application/octet-stream");
@@ -34,6 +37,9 @@ pub fn map_alternative_origins_asset(
         collection: ASSET_COLLECTION_KEY.to_string(),
         owner: id(),
         description: None,
+        publish_at: None,
+        unpublish_at: None,
+        moderation: None,
     };
 
     let headers = map_content_type_headers("application/json");
@@ -0,0 +1,84 @@
+use crate::http::types::HeaderField;
+use crate::http::utils::get_header_value;
+use crate::msg::{ERROR_INVALID_WILDCARD_DOMAIN, ERROR_INVALID_WILDCARD_DOMAIN_PREFIX};
+use crate::types::config::StorageConfig;
+use crate::types::state::FullPath;
+
+/// Resolves the path of the tree of the tenant targeted by a wildcard custom domain.
+/// e.g. with the wildcard domain "*.customers.example.com" mapped to the prefix "/tenants", a request /about on acme.customers.example.com returns /tenants/acme/about.
+/// Returns `None` if the host of the request does not match any of the wildcard domains.
+pub fn tenant_path(
+    requested_path: &str,
+    req_headers: &[HeaderField],
+    config: &StorageConfig,
+) -> Option<FullPath> {
+    let wildcard_domains = config.wildcard_domains.as_ref()?;
+
+    let host = get_header_value(req_headers, "Host")?;
+
+    // e.g. acme.customers.example.com:443 -> acme.customers.example.com
+    let host = host.split(':').next().unwrap_or_default().to_lowercase();
+
+    wildcard_domains.iter().find_map(|(domain, prefix)| {
+        let suffix = domain.strip_prefix('*')?.to_lowercase();
+
+        let subdomain = host.strip_suffix(&suffix)?;
+
+        // The wildcard matches a single label only
+        if subdomain.is_empty() || subdomain.contains('.') {
+            return None;
+        }
+
+        Some(format!(
+            "{}/{}{}",
+            trim_prefix(prefix),
+            subdomain,
+            requested_path
+        ))
+    })
+}
+
+/// The path of an asset of a tenant within its tree - i.e. the path for which it is served on the subdomain of the tenant.
+/// e.g. /tenants/acme/about.html returns /about.html for the prefix "/tenants".
+/// Returns `None` if the asset does not belong to the tree of a tenant.
+pub fn tenant_asset_path(full_path: &str, config: &StorageConfig) -> Option<FullPath> {
+    let wildcard_domains = config.wildcard_domains.as_ref()?;
+
+    wildcard_domains.values().find_map(|prefix| {
+        let path = full_path
+            .strip_prefix(trim_prefix(prefix))?
+            .strip_prefix('/')?;
+
+        let (_tenant, path) = path.split_once('/')?;
+
+        Some(format!("/{}", path))
+    })
+}
+
+/// The prefixes of the tenants must be a path - e.g. "/tenants". An empty prefix would turn every asset nested in a
+/// folder of the satellite into an asset of a tenant.
+pub fn assert_wildcard_domains(config: &StorageConfig) -> Result<(), String> {
+    let Some(wildcard_domains) = &config.wildcard_domains else {
+        return Ok(());
+    };
+
+    for (domain, prefix) in wildcard_domains {
+        if !domain.starts_with("*.") {
+            return Err(format!("{} ({})", ERROR_INVALID_WILDCARD_DOMAIN, domain));
+        }
+
+        if !prefix.starts_with('/') || trim_prefix(prefix).is_empty() {
+            return Err(format!(
+                "{} ({})",
+                ERROR_INVALID_WILDCARD_DOMAIN_PREFIX, domain
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// e.g. "/tenants/" -> "/tenants" and "/" -> ""
+fn trim_prefix(prefix: &str) -> &str {
+    prefix.trim_end_matches('/')
+}
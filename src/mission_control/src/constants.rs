@@ -1,2 +1,31 @@
 // We archive statuses in memory up to 30 days (1h * 24 * 30).
 pub const RETAIN_ARCHIVE_STATUSES_NS: u64 = 3_600_000_000_000 * 24 * 30;
+
+// The burn rate is computed over the last 7 days (1h * 24 * 7) of the cycles history.
+pub const FREEZE_PREDICTION_WINDOW_NS: u64 = 3_600_000_000_000 * 24 * 7;
+
+// Predictions are computed at most every hour.
+pub const FREEZE_PREDICTION_INTERVAL_NS: u64 = 3_600_000_000_000;
+
+// The freezing threshold of the modules rarely changes, therefore it is refreshed once a day.
+pub const FREEZING_THRESHOLD_REFRESH_NS: u64 = 3_600_000_000_000 * 24;
+
+pub const DEFAULT_FREEZE_ALERT_HORIZON_DAYS: u64 = 30;
+
+// The soft quota statuses of the satellites are collected at most every hour.
+pub const QUOTA_STATUSES_INTERVAL_NS: u64 = 3_600_000_000_000;
+
+// Previews live at most 30 days (1h * 24 * 30).
+pub const MAX_PREVIEW_TTL_NS: u64 = 3_600_000_000_000 * 24 * 30;
+
+// The deletion of an expired preview that failed is retried every hour.
+pub const PREVIEW_DELETION_RETRY_NS: u64 = 3_600_000_000_000;
+
+// The cycles left on an expired preview when its cycles are reclaimed, to cover the calls that stop and delete it.
+pub const PREVIEW_CYCLES_RESERVE: u128 = 100_000_000_000;
+
+// The usage digest covers the last 7 days (1h * 24 * 7) when no period is provided.
+pub const USAGE_DIGEST_DEFAULT_PERIOD_NS: u64 = 3_600_000_000_000 * 24 * 7;
+
+// The number of upgrades kept in the history of each module.
+pub const UPGRADE_HISTORY_MAX_LENGTH: usize = 20;
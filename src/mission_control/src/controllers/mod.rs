@@ -1,4 +1,5 @@
 pub mod mission_control;
+pub mod modules;
 pub mod orbiter;
 pub mod satellite;
 mod segment;
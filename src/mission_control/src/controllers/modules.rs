@@ -0,0 +1,88 @@
+use crate::controllers::segment::{delete_segment_controllers, set_segment_controllers};
+use crate::segments::store::{get_orbiters, get_satellites};
+use crate::types::interface::{ModulesControllersOperation, ModulesControllersResults};
+use candid::Principal;
+use futures::future::join_all;
+use junobuild_shared::controllers::assert_controllers;
+use junobuild_shared::types::state::SegmentId;
+
+/// Applies the same controllers operation to all the satellites and orbiters attached to the mission control.
+///
+/// Modules are updated independently, a failure for one of those does not revert nor interrupt the others. That's why
+/// the outcome is reported per module.
+pub async fn update_modules_controllers(
+    operation: &ModulesControllersOperation,
+) -> Result<ModulesControllersResults, String> {
+    assert_operation(operation)?;
+
+    let satellite_ids: Vec<SegmentId> = get_satellites().into_keys().collect();
+    let orbiter_ids: Vec<SegmentId> = get_orbiters().into_keys().collect();
+
+    let satellites = update_segments_controllers(satellite_ids, operation).await;
+    let orbiters = update_segments_controllers(orbiter_ids, operation).await;
+
+    Ok(ModulesControllersResults {
+        satellites,
+        orbiters,
+    })
+}
+
+fn assert_operation(operation: &ModulesControllersOperation) -> Result<(), String> {
+    match operation {
+        ModulesControllersOperation::Set { controllers, .. } => assert_controllers(controllers),
+        ModulesControllersOperation::Delete { controllers } => {
+            if controllers.is_empty() {
+                return Err("No controllers to delete.".to_string());
+            }
+
+            Ok(())
+        }
+        ModulesControllersOperation::Rotate { from, to, .. } => {
+            if from == to {
+                return Err("The controller to rotate must differ from the new one.".to_string());
+            }
+
+            assert_controllers(&[*to])
+        }
+    }
+}
+
+async fn update_segments_controllers(
+    segment_ids: Vec<SegmentId>,
+    operation: &ModulesControllersOperation,
+) -> Vec<(SegmentId, Result<(), String>)> {
+    join_all(
+        segment_ids
+            .into_iter()
+            .map(|segment_id| update_segment_controllers(segment_id, operation)),
+    )
+    .await
+}
+
+async fn update_segment_controllers(
+    segment_id: Principal,
+    operation: &ModulesControllersOperation,
+) -> (SegmentId, Result<(), String>) {
+    let result = match operation {
+        ModulesControllersOperation::Set {
+            controllers,
+            controller,
+        } => set_segment_controllers(&segment_id, controllers, controller).await,
+        ModulesControllersOperation::Delete { controllers } => {
+            delete_segment_controllers(&segment_id, controllers).await
+        }
+        ModulesControllersOperation::Rotate {
+            from,
+            to,
+            controller,
+        } => {
+            // The new controller is added first, this way a module never ends up without the access that is being rotated.
+            match set_segment_controllers(&segment_id, &[*to], controller).await {
+                Err(err) => Err(err),
+                Ok(_) => delete_segment_controllers(&segment_id, &[*from]).await,
+            }
+        }
+    };
+
+    (segment_id, result)
+}
@@ -1,16 +1,18 @@
 use crate::memory::init_stable_state;
-use crate::types::core::{Segment, SettingsMonitoring};
+use crate::types::console::{ReleaseWasmChunk, TemplateWasmChunk};
+use crate::types::core::{ConsoleWasmChunk, Segment, SettingsMonitoring};
 use crate::types::state::CyclesMonitoringStrategy::BelowThreshold;
 use crate::types::state::{
-    Archive, ArchiveStatuses, CyclesMonitoring, CyclesMonitoringStrategy, HeapState,
-    MissionControlSettings, Monitoring, MonitoringHistory, MonitoringHistoryKey, Orbiter, Orbiters,
-    Satellite, Settings, State, User,
+    Archive, ArchiveStatuses, CyclesFreezeAlert, CyclesMonitoring, CyclesMonitoringStrategy,
+    HeapState, MissionControlSettings, Monitoring, MonitoringHistory, MonitoringHistoryKey,
+    Orbiter, Orbiters, Satellite, Settings, State, User,
 };
 use canfund::manager::options::{CyclesThreshold, FundStrategy};
 use ic_cdk::api::time;
 use ic_stable_structures::storable::Bound;
 use ic_stable_structures::Storable;
 use junobuild_shared::serializers::{deserialize_from_bytes, serialize_to_bytes};
+use junobuild_shared::types::core::{Blob, Hash};
 use junobuild_shared::types::state::{Metadata, OrbiterId, SatelliteId, UserId};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap};
@@ -33,6 +35,9 @@ impl From<&UserId> for HeapState {
             archive: Archive::new(),
             orbiters: Orbiters::new(),
             settings: None,
+            upgrade_history: None,
+            previews: None,
+            freeze_predictions: None,
         }
     }
 }
@@ -244,11 +249,33 @@ impl MissionControlSettings {
 
         MissionControlSettings {
             monitoring: Some(Monitoring::from(strategy)),
+            freeze_alert: None,
             updated_at: now,
             created_at: now,
         }
     }
 
+    pub fn prepare_freeze_alert(
+        current_settings: &Option<MissionControlSettings>,
+        freeze_alert: &CyclesFreezeAlert,
+    ) -> Self {
+        let now = time();
+
+        match current_settings {
+            None => MissionControlSettings {
+                monitoring: None,
+                freeze_alert: Some(freeze_alert.clone()),
+                updated_at: now,
+                created_at: now,
+            },
+            Some(current_settings) => MissionControlSettings {
+                freeze_alert: Some(freeze_alert.clone()),
+                updated_at: now,
+                ..current_settings.clone()
+            },
+        }
+    }
+
     pub fn clone_with_strategy(&self, strategy: &CyclesMonitoringStrategy) -> Self {
         let now = time();
 
@@ -311,3 +338,31 @@ impl Storable for MonitoringHistoryKey {
 
     const BOUND: Bound = Bound::Unbounded;
 }
+
+impl ConsoleWasmChunk for ReleaseWasmChunk {
+    fn chunks_length(&self) -> u64 {
+        self.chunks_length
+    }
+
+    fn sha256(&self) -> Hash {
+        self.sha256
+    }
+
+    fn into_content(self) -> Blob {
+        self.content
+    }
+}
+
+impl ConsoleWasmChunk for TemplateWasmChunk {
+    fn chunks_length(&self) -> u64 {
+        self.chunks_length
+    }
+
+    fn sha256(&self) -> Hash {
+        self.sha256
+    }
+
+    fn into_content(self) -> Blob {
+        self.content
+    }
+}
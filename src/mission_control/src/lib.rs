@@ -5,6 +5,7 @@ mod impls;
 mod memory;
 mod mgmt;
 mod monitoring;
+mod previews;
 mod segments;
 mod store;
 mod types;
@@ -13,6 +14,7 @@ use crate::controllers::mission_control::{
     delete_mission_control_controllers as delete_controllers_to_mission_control,
     set_mission_control_controllers as set_controllers_to_mission_control,
 };
+use crate::controllers::modules::update_modules_controllers as update_modules_controllers_impl;
 use crate::controllers::orbiter::{delete_orbiter_controllers, set_orbiter_controllers};
 use crate::controllers::satellite::{
     add_satellite_controllers as add_satellite_controllers_impl, delete_satellite_controllers,
@@ -24,6 +26,12 @@ use crate::guards::{
 };
 use crate::memory::{get_memory_upgrades, init_runtime_state, init_stable_state, STATE};
 use crate::mgmt::status::collect_statuses;
+use crate::previews::satellite::{
+    create_preview_satellite as create_preview_satellite_impl, delete_preview_satellite,
+};
+use crate::previews::schedule::schedule_previews;
+use crate::previews::store::get_previews;
+use crate::segments::clone::clone_satellite as clone_satellite_impl;
 use crate::segments::orbiter::{
     attach_orbiter, create_orbiter as create_orbiter_console,
     create_orbiter_with_config as create_orbiter_with_config_console, delete_orbiter,
@@ -34,7 +42,11 @@ use crate::segments::satellite::{
     create_satellite_with_config as create_satellite_with_config_console, delete_satellite,
     detach_satellite,
 };
-use crate::segments::store::get_orbiters;
+use crate::segments::store::{get_orbiters, list_upgrade_history as list_upgrade_history_store};
+use crate::segments::template::install_satellite_template as install_satellite_template_impl;
+use crate::segments::upgrade::{
+    upgrade_orbiter as upgrade_orbiter_impl, upgrade_satellite as upgrade_satellite_impl,
+};
 use crate::store::{
     get_settings as get_settings_store, get_user as get_user_store,
     list_mission_control_statuses as list_mission_control_statuses_store,
@@ -42,12 +54,14 @@ use crate::store::{
     list_satellite_statuses as list_satellite_statuses_store, set_metadata as set_metadata_store,
 };
 use crate::types::interface::{
-    CreateCanisterConfig, GetMonitoringHistory, MonitoringStartConfig, MonitoringStatus,
-    MonitoringStopConfig,
+    CloneSatelliteOptions, CreateCanisterConfig, CreatePreviewConfig, GetMonitoringHistory,
+    InstallTemplateArgs, ModulesControllersOperation, ModulesControllersResults,
+    MonitoringStartConfig, MonitoringStatus, MonitoringStopConfig, UpgradeSegmentArgs,
 };
 use crate::types::state::{
-    HeapState, MissionControlSettings, MonitoringHistory, MonitoringHistoryKey, Orbiter, Orbiters,
-    Satellite, Satellites, State, Statuses,
+    CyclesFreezeAlert, FreezePredictions, HeapState, MissionControlSettings, MonitoringHistory,
+    MonitoringHistoryKey, Orbiter, Orbiters, Previews, Satellite, Satellites, State, Statuses,
+    UpgradeAttestation,
 };
 use candid::Principal;
 use ciborium::into_writer;
@@ -62,16 +76,18 @@ use junobuild_shared::ledger::types::icrc::IcrcTransferResult;
 use junobuild_shared::mgmt::cmc::top_up_canister;
 use junobuild_shared::mgmt::ic::deposit_cycles as deposit_cycles_shared;
 use junobuild_shared::types::interface::{
-    DepositCyclesArgs, MissionControlArgs, SetController, StatusesArgs,
+    DepositCyclesArgs, GetUsageDigest, MissionControlArgs, SetController, StatusesArgs, UsageDigest,
 };
 use junobuild_shared::types::state::{
     ControllerId, ControllerScope, Controllers, OrbiterId, SatelliteId, SegmentsStatuses,
 };
 use junobuild_shared::types::state::{Metadata, UserId};
 use junobuild_shared::upgrade::write_pre_upgrade;
+use monitoring::digest::collect_usage_digest;
 use monitoring::monitor::{
-    defer_restart_monitoring, get_monitoring_history as get_any_monitoring_history,
-    get_monitoring_status as get_any_monitoring_status,
+    defer_restart_monitoring, get_freeze_predictions as get_any_freeze_predictions,
+    get_monitoring_history as get_any_monitoring_history,
+    get_monitoring_status as get_any_monitoring_status, set_freeze_alert as set_any_freeze_alert,
     start_monitoring as start_monitoring_with_current_config,
     stop_monitoring as stop_any_monitoring, update_and_start_monitoring_with_config,
     update_and_stop_monitoring_with_config,
@@ -131,6 +147,8 @@ fn post_upgrade() {
     init_runtime_state();
 
     defer_restart_monitoring();
+
+    schedule_previews();
 }
 
 // ---------------------------------------------------------
@@ -156,6 +174,13 @@ async fn create_satellite_with_config(config: CreateCanisterConfig) -> Satellite
         .unwrap_or_else(|e| trap(&e))
 }
 
+#[update(guard = "caller_is_user_or_admin_controller")]
+async fn clone_satellite(source_id: SatelliteId, options: CloneSatelliteOptions) -> Satellite {
+    clone_satellite_impl(&source_id, &options)
+        .await
+        .unwrap_or_else(|e| trap(&e))
+}
+
 #[update(guard = "caller_is_user_or_admin_controller")]
 fn set_satellite_metadata(satellite_id: SatelliteId, metadata: Metadata) -> Satellite {
     set_satellite_metadata_store(&satellite_id, &metadata).unwrap_or_else(|e| trap(&e))
@@ -236,6 +261,46 @@ async fn unset_satellite(satellite_id: SatelliteId) {
         .unwrap_or_else(|e| trap(&e))
 }
 
+#[update(guard = "caller_is_user_or_admin_controller")]
+async fn install_satellite_template(satellite_id: SatelliteId, args: InstallTemplateArgs) {
+    install_satellite_template_impl(&satellite_id, &args)
+        .await
+        .unwrap_or_else(|e| trap(&e))
+}
+
+#[update(guard = "caller_is_user_or_admin_controller")]
+async fn upgrade_satellite(
+    satellite_id: SatelliteId,
+    args: UpgradeSegmentArgs,
+) -> UpgradeAttestation {
+    upgrade_satellite_impl(&satellite_id, &args)
+        .await
+        .unwrap_or_else(|e| trap(&e))
+}
+
+// ---------------------------------------------------------
+// Previews
+// ---------------------------------------------------------
+
+#[update(guard = "caller_is_user_or_admin_controller")]
+async fn create_preview_satellite(config: CreatePreviewConfig) -> Satellite {
+    create_preview_satellite_impl(&config)
+        .await
+        .unwrap_or_else(|e| trap(&e))
+}
+
+#[query(guard = "caller_is_user_or_admin_controller")]
+fn list_previews() -> Previews {
+    get_previews()
+}
+
+#[update(guard = "caller_is_user_or_admin_controller")]
+async fn del_preview_satellite(satellite_id: SatelliteId) {
+    delete_preview_satellite(&satellite_id)
+        .await
+        .unwrap_or_else(|e| trap(&e))
+}
+
 // ---------------------------------------------------------
 // Orbiters
 // ---------------------------------------------------------
@@ -307,6 +372,13 @@ async fn del_orbiter(orbiter_id: OrbiterId, cycles_to_deposit: u128) {
         .unwrap_or_else(|e| trap(&e));
 }
 
+#[update(guard = "caller_is_user_or_admin_controller")]
+async fn upgrade_orbiter(orbiter_id: OrbiterId, args: UpgradeSegmentArgs) -> UpgradeAttestation {
+    upgrade_orbiter_impl(&orbiter_id, &args)
+        .await
+        .unwrap_or_else(|e| trap(&e))
+}
+
 // ---------------------------------------------------------
 // Controllers
 // ---------------------------------------------------------
@@ -356,6 +428,15 @@ async fn del_mission_control_controllers(controllers: Vec<ControllerId>) {
         .unwrap_or_else(|e| trap(&e));
 }
 
+#[update(guard = "caller_is_user_or_admin_controller")]
+async fn update_modules_controllers(
+    operation: ModulesControllersOperation,
+) -> ModulesControllersResults {
+    update_modules_controllers_impl(&operation)
+        .await
+        .unwrap_or_else(|e| trap(&e))
+}
+
 #[query(guard = "caller_is_user_or_admin_controller")]
 fn list_mission_control_controllers() -> Controllers {
     get_controllers()
@@ -394,6 +475,11 @@ async fn deposit_cycles(args: DepositCyclesArgs) {
         .unwrap_or_else(|e| trap(&e))
 }
 
+#[query(guard = "caller_is_user_or_admin_controller")]
+fn list_upgrade_history(segment_id: Principal) -> Vec<UpgradeAttestation> {
+    list_upgrade_history_store(&segment_id)
+}
+
 #[query]
 fn version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
@@ -412,6 +498,13 @@ async fn status(config: StatusesArgs) -> SegmentsStatuses {
     collect_statuses(&id(), &config).await
 }
 
+#[update(guard = "caller_is_user_or_admin_controller_or_juno")]
+async fn get_usage_digest(args: GetUsageDigest) -> UsageDigest {
+    collect_usage_digest(&args)
+        .await
+        .unwrap_or_else(|e| trap(&e))
+}
+
 #[deprecated(
     since = "0.0.14",
     note = "Deprecated with the introduction of monitoring features that include auto top-up capabilities."
@@ -463,6 +556,11 @@ fn update_and_stop_monitoring(config: MonitoringStopConfig) {
     update_and_stop_monitoring_with_config(&config).unwrap_or_else(|e| trap(&e));
 }
 
+#[update(guard = "caller_is_user_or_admin_controller")]
+fn set_freeze_alert(freeze_alert: CyclesFreezeAlert) -> MissionControlSettings {
+    set_any_freeze_alert(&freeze_alert)
+}
+
 #[query(guard = "caller_is_user_or_admin_controller")]
 fn get_monitoring_status() -> MonitoringStatus {
     get_any_monitoring_status()
@@ -475,6 +573,11 @@ fn get_monitoring_history(
     get_any_monitoring_history(&filter)
 }
 
+#[query(guard = "caller_is_user_or_admin_controller_or_juno")]
+fn get_freeze_predictions() -> FreezePredictions {
+    get_any_freeze_predictions()
+}
+
 // ---------------------------------------------------------
 // Wallet
 // ---------------------------------------------------------
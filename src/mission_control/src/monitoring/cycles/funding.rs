@@ -1,4 +1,6 @@
 use crate::monitoring::cycles::history::save_monitoring_history;
+use crate::monitoring::cycles::prediction::defer_freeze_predictions;
+use crate::monitoring::quotas::collect::defer_quota_statuses;
 use crate::types::state::CyclesMonitoringStrategy;
 use canfund::api::cmc::IcCyclesMintingCanister;
 use canfund::api::ledger::IcLedgerCanister;
@@ -52,7 +54,13 @@ fn obtain_cycles_options() -> ObtainCyclesOptions {
 
 fn funding_callback() -> ObserverCallback {
     Rc::new(|records: HashMap<CanisterId, CanisterRecord>| {
+        let segment_ids: Vec<SegmentId> = records.keys().copied().collect();
+
         save_monitoring_history(records);
+
+        defer_quota_statuses(&segment_ids);
+
+        defer_freeze_predictions(segment_ids);
     })
 }
 
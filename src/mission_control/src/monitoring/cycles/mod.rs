@@ -1,6 +1,7 @@
 pub mod config;
 mod funding;
 mod history;
+pub mod prediction;
 mod register;
 mod scheduler;
 pub mod start;
@@ -0,0 +1,166 @@
+use crate::constants::{
+    DEFAULT_FREEZE_ALERT_HORIZON_DAYS, FREEZE_PREDICTION_INTERVAL_NS, FREEZE_PREDICTION_WINDOW_NS,
+    FREEZING_THRESHOLD_REFRESH_NS,
+};
+use crate::monitoring::store::heap::{get_freeze_prediction, set_freeze_prediction};
+use crate::monitoring::store::runtime::{get_freezing_threshold, set_freezing_threshold};
+use crate::monitoring::store::stable::get_monitoring_history;
+use crate::store::get_settings;
+use crate::types::interface::GetMonitoringHistory;
+use crate::types::runtime::FreezingThreshold;
+use crate::types::state::MonitoringHistoryCycles;
+use candid::Nat;
+use ic_cdk::api::time;
+use ic_cdk::spawn;
+use junobuild_shared::mgmt::ic::segment_status;
+use junobuild_shared::types::interface::CyclesFreezePrediction;
+use junobuild_shared::types::state::SegmentId;
+
+const DAY_NS: u128 = 3_600_000_000_000 * 24;
+const DAY_SECONDS: u128 = 60 * 60 * 24;
+
+/// Predictions are refreshed at most once per interval given that the history is collected every few seconds.
+pub fn defer_freeze_predictions(segment_ids: Vec<SegmentId>) {
+    let now = time();
+
+    let outdated_ids: Vec<SegmentId> = segment_ids
+        .into_iter()
+        .filter(|segment_id| {
+            get_freeze_prediction(segment_id).map_or(true, |prediction| {
+                now.saturating_sub(prediction.predicted_at) >= FREEZE_PREDICTION_INTERVAL_NS
+            })
+        })
+        .collect();
+
+    if outdated_ids.is_empty() {
+        return;
+    }
+
+    spawn(update_freeze_predictions(outdated_ids));
+}
+
+async fn update_freeze_predictions(segment_ids: Vec<SegmentId>) {
+    for segment_id in segment_ids {
+        let freezing_threshold = match load_freezing_threshold(&segment_id).await {
+            Ok(freezing_threshold) => freezing_threshold,
+            // The prediction is skipped for this round and retried with the next funding callback.
+            Err(_) => continue,
+        };
+
+        if let Some(prediction) = predict_freeze(&segment_id, &freezing_threshold) {
+            set_freeze_prediction(&segment_id, &prediction);
+        }
+    }
+}
+
+async fn load_freezing_threshold(segment_id: &SegmentId) -> Result<FreezingThreshold, String> {
+    let now = time();
+
+    if let Some(freezing_threshold) = get_freezing_threshold(segment_id) {
+        if now.saturating_sub(freezing_threshold.fetched_at) < FREEZING_THRESHOLD_REFRESH_NS {
+            return Ok(freezing_threshold);
+        }
+    }
+
+    let status = segment_status(*segment_id).await?.status;
+
+    // The canister is frozen once its balance falls below the cycles required to stay idle during the freezing threshold (in seconds).
+    let cycles = status.idle_cycles_burned_per_day * status.settings.freezing_threshold
+        / Nat::from(DAY_SECONDS);
+
+    let freezing_threshold = FreezingThreshold {
+        cycles: u128::try_from(cycles.0).unwrap_or(u128::MAX),
+        fetched_at: now,
+    };
+
+    set_freezing_threshold(segment_id, &freezing_threshold);
+
+    Ok(freezing_threshold)
+}
+
+fn predict_freeze(
+    segment_id: &SegmentId,
+    freezing_threshold: &FreezingThreshold,
+) -> Option<CyclesFreezePrediction> {
+    let now = time();
+
+    let filter = GetMonitoringHistory {
+        segment_id: *segment_id,
+        from: Some(now.saturating_sub(FREEZE_PREDICTION_WINDOW_NS)),
+        to: None,
+    };
+
+    let history: Vec<MonitoringHistoryCycles> = get_monitoring_history(&filter)
+        .into_iter()
+        .filter_map(|(_, history)| history.cycles)
+        .collect();
+
+    let cycles = history.last()?.cycles.amount;
+
+    let burn_rate_per_day = burn_rate_per_day(&history);
+
+    let days_until_freeze = if burn_rate_per_day == 0 {
+        None
+    } else {
+        let days = cycles.saturating_sub(freezing_threshold.cycles) / burn_rate_per_day;
+        Some(u64::try_from(days).unwrap_or(u64::MAX))
+    };
+
+    let horizon_days = get_settings()
+        .and_then(|settings| settings.freeze_alert)
+        .map_or(DEFAULT_FREEZE_ALERT_HORIZON_DAYS, |freeze_alert| {
+            freeze_alert.horizon_days
+        });
+
+    let alert = days_until_freeze.is_some_and(|days| days < horizon_days);
+
+    Some(CyclesFreezePrediction {
+        cycles,
+        burn_rate_per_day,
+        freezing_threshold_cycles: freezing_threshold.cycles,
+        days_until_freeze,
+        alert,
+        predicted_at: now,
+    })
+}
+
+/// Extrapolates the cycles burned over the history to a day.
+fn burn_rate_per_day(history: &[MonitoringHistoryCycles]) -> u128 {
+    let (burned, elapsed) = burned_cycles(history);
+
+    if elapsed == 0 {
+        return 0;
+    }
+
+    burned * DAY_NS / elapsed
+}
+
+/// Sums the cycles burned between consecutive entries of the history - adding back the cycles deposited in between -
+/// and returns them with the duration they cover.
+pub fn burned_cycles(history: &[MonitoringHistoryCycles]) -> (u128, u128) {
+    history
+        .windows(2)
+        .fold((0u128, 0u128), |(burned, elapsed), entries| {
+            let previous = &entries[0];
+            let current = &entries[1];
+
+            let deposited = current
+                .last_deposited_cycles
+                .as_ref()
+                .filter(|deposited| deposited.timestamp > previous.cycles.timestamp)
+                .map_or(0, |deposited| deposited.amount);
+
+            let consumed = previous
+                .cycles
+                .amount
+                .saturating_add(deposited)
+                .saturating_sub(current.cycles.amount);
+
+            let duration = current
+                .cycles
+                .timestamp
+                .saturating_sub(previous.cycles.timestamp);
+
+            (burned + consumed, elapsed + duration as u128)
+        })
+}
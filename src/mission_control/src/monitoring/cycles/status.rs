@@ -1,4 +1,5 @@
 use crate::memory::RUNTIME_STATE;
+use crate::monitoring::store::heap::get_freeze_predictions;
 use crate::types::interface::CyclesMonitoringStatus;
 use crate::types::runtime::RuntimeState;
 
@@ -13,5 +14,6 @@ fn get_cycles_monitoring_status_impl(state: &RuntimeState) -> Option<CyclesMonit
         .map(|fund_manager| CyclesMonitoringStatus {
             running: fund_manager.is_running(),
             monitored_ids: fund_manager.get_canisters().keys().copied().collect(),
+            freeze_predictions: get_freeze_predictions(),
         })
 }
@@ -0,0 +1,108 @@
+use crate::constants::USAGE_DIGEST_DEFAULT_PERIOD_NS;
+use crate::monitoring::cycles::prediction::burned_cycles;
+use crate::monitoring::store::stable::get_monitoring_history;
+use crate::segments::store::{get_orbiters, get_satellites};
+use crate::types::interface::{
+    AnalyticsMetricsPageViews, AnalyticsTop10PageViews, GetAnalytics, GetMonitoringHistory,
+    SatelliteHealth,
+};
+use crate::types::state::MonitoringHistoryCycles;
+use ic_cdk::api::call::CallResult;
+use ic_cdk::api::time;
+use ic_cdk::{call, id};
+use junobuild_shared::types::interface::{
+    GetUsageDigest, MemorySize, OrbiterUsage, SatelliteUsage, UsageDigest,
+};
+use junobuild_shared::types::state::{OrbiterId, SatelliteId, SegmentId, Timestamp};
+use std::collections::HashMap;
+
+/// Collects the usage of the mission control and of its modules over the period. The modules that fail to answer are
+/// reported without the related metrics rather than failing the whole digest.
+pub async fn collect_usage_digest(
+    GetUsageDigest { from, to }: &GetUsageDigest,
+) -> Result<UsageDigest, String> {
+    let to = to.unwrap_or_else(time);
+    let from = from.unwrap_or_else(|| to.saturating_sub(USAGE_DIGEST_DEFAULT_PERIOD_NS));
+
+    if from >= to {
+        return Err("The period of the usage digest is invalid.".to_string());
+    }
+
+    let mut satellites: HashMap<SatelliteId, SatelliteUsage> = HashMap::new();
+
+    for satellite_id in get_satellites().into_keys() {
+        let usage = SatelliteUsage {
+            memory_size: satellite_memory_size(&satellite_id).await,
+            cycles_burned: cycles_burned(&satellite_id, from, to),
+        };
+
+        satellites.insert(satellite_id, usage);
+    }
+
+    let mut orbiters: HashMap<OrbiterId, OrbiterUsage> = HashMap::new();
+
+    for orbiter_id in get_orbiters().into_keys() {
+        let usage = orbiter_usage(&orbiter_id, from, to).await;
+
+        orbiters.insert(orbiter_id, usage);
+    }
+
+    Ok(UsageDigest {
+        from,
+        to,
+        mission_control_cycles_burned: cycles_burned(&id(), from, to),
+        satellites,
+        orbiters,
+    })
+}
+
+async fn satellite_memory_size(satellite_id: &SatelliteId) -> Option<MemorySize> {
+    let result: CallResult<(SatelliteHealth,)> = call(*satellite_id, "health", ()).await;
+
+    result.ok().map(|(health,)| health.memory_size)
+}
+
+async fn orbiter_usage(orbiter_id: &OrbiterId, from: Timestamp, to: Timestamp) -> OrbiterUsage {
+    let args = GetAnalytics {
+        satellite_id: None,
+        from: Some(from),
+        to: Some(to),
+    };
+
+    let metrics: CallResult<(AnalyticsMetricsPageViews,)> =
+        call(*orbiter_id, "get_page_views_analytics_metrics", (&args,)).await;
+
+    let top_10: CallResult<(AnalyticsTop10PageViews,)> =
+        call(*orbiter_id, "get_page_views_analytics_top_10", (&args,)).await;
+
+    let metrics = metrics.ok().map(|(metrics,)| metrics);
+
+    OrbiterUsage {
+        page_views: metrics.as_ref().map(|metrics| metrics.total_page_views),
+        unique_sessions: metrics.as_ref().map(|metrics| metrics.unique_sessions),
+        top_pages: top_10.map_or(vec![], |(top_10,)| top_10.pages),
+        cycles_burned: cycles_burned(orbiter_id, from, to),
+    }
+}
+
+/// At least two entries of the monitoring history are required to measure a consumption.
+fn cycles_burned(segment_id: &SegmentId, from: Timestamp, to: Timestamp) -> Option<u128> {
+    let filter = GetMonitoringHistory {
+        segment_id: *segment_id,
+        from: Some(from),
+        to: Some(to),
+    };
+
+    let history: Vec<MonitoringHistoryCycles> = get_monitoring_history(&filter)
+        .into_iter()
+        .filter_map(|(_, history)| history.cycles)
+        .collect();
+
+    if history.len() < 2 {
+        return None;
+    }
+
+    let (burned, _) = burned_cycles(&history);
+
+    Some(burned)
+}
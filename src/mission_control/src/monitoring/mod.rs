@@ -1,3 +1,5 @@
 mod cycles;
+pub mod digest;
 pub mod monitor;
+mod quotas;
 mod store;
@@ -4,11 +4,19 @@ use crate::monitoring::cycles::config::{
 use crate::monitoring::cycles::start::start_cycles_monitoring;
 use crate::monitoring::cycles::status::get_cycles_monitoring_status;
 use crate::monitoring::cycles::stop::stop_cycles_monitoring;
+use crate::monitoring::quotas::status::get_quota_warnings;
+use crate::monitoring::store::heap::{
+    get_freeze_predictions as get_freeze_predictions_store,
+    set_freeze_alert as set_freeze_alert_store,
+};
 use crate::monitoring::store::stable::get_monitoring_history as get_monitoring_history_store;
 use crate::types::interface::{
     GetMonitoringHistory, MonitoringStartConfig, MonitoringStatus, MonitoringStopConfig,
 };
-use crate::types::state::{MonitoringHistory, MonitoringHistoryKey};
+use crate::types::state::{
+    CyclesFreezeAlert, FreezePredictions, MissionControlSettings, MonitoringHistory,
+    MonitoringHistoryKey,
+};
 use ic_cdk::spawn;
 use ic_cdk::trap;
 use ic_cdk_timers::set_timer;
@@ -51,6 +59,7 @@ pub fn update_and_stop_monitoring_with_config(config: &MonitoringStopConfig) ->
 pub fn get_monitoring_status() -> MonitoringStatus {
     MonitoringStatus {
         cycles: get_cycles_monitoring_status(),
+        quotas: get_quota_warnings(),
     }
 }
 
@@ -59,3 +68,11 @@ pub fn get_monitoring_history(
 ) -> Vec<(MonitoringHistoryKey, MonitoringHistory)> {
     get_monitoring_history_store(filter)
 }
+
+pub fn set_freeze_alert(freeze_alert: &CyclesFreezeAlert) -> MissionControlSettings {
+    set_freeze_alert_store(freeze_alert)
+}
+
+pub fn get_freeze_predictions() -> FreezePredictions {
+    get_freeze_predictions_store()
+}
@@ -0,0 +1,60 @@
+use crate::constants::QUOTA_STATUSES_INTERVAL_NS;
+use crate::monitoring::store::runtime::{get_quota_statuses, set_quota_statuses};
+use crate::segments::store::get_satellites;
+use crate::types::runtime::QuotaStatuses;
+use ic_cdk::api::call::CallResult;
+use ic_cdk::api::time;
+use ic_cdk::{call, spawn};
+use junobuild_shared::types::interface::QuotaStatus;
+use junobuild_shared::types::state::{SatelliteId, SegmentId};
+use std::collections::HashMap;
+
+/// The statuses are collected with the monitoring callback - for the monitored satellites - at most once per interval.
+pub fn defer_quota_statuses(segment_ids: &[SegmentId]) {
+    let now = time();
+
+    if get_quota_statuses().is_some_and(|quota_statuses| {
+        now.saturating_sub(quota_statuses.fetched_at) < QUOTA_STATUSES_INTERVAL_NS
+    }) {
+        return;
+    }
+
+    let satellites = get_satellites();
+
+    let satellite_ids: Vec<SatelliteId> = segment_ids
+        .iter()
+        .filter(|segment_id| satellites.contains_key(segment_id))
+        .copied()
+        .collect();
+
+    if satellite_ids.is_empty() {
+        return;
+    }
+
+    // Flag the collection as started to not spawn concurrent rounds while the satellites are called.
+    set_quota_statuses(QuotaStatuses {
+        fetched_at: now,
+        ..get_quota_statuses().unwrap_or_default()
+    });
+
+    spawn(collect_quota_statuses(satellite_ids));
+}
+
+async fn collect_quota_statuses(satellite_ids: Vec<SatelliteId>) {
+    let mut statuses: HashMap<SatelliteId, QuotaStatus> = HashMap::new();
+
+    for satellite_id in satellite_ids {
+        // Satellites that do not expose the status yet - or that failed to answer - are skipped for this round.
+        let result: CallResult<(Option<QuotaStatus>,)> =
+            call(satellite_id, "get_quota_status", ()).await;
+
+        if let Ok((Some(status),)) = result {
+            statuses.insert(satellite_id, status);
+        }
+    }
+
+    set_quota_statuses(QuotaStatuses {
+        statuses,
+        fetched_at: time(),
+    });
+}
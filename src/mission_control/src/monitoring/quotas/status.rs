@@ -0,0 +1,15 @@
+use crate::monitoring::store::runtime::get_quota_statuses;
+use junobuild_shared::types::interface::QuotaStatus;
+use junobuild_shared::types::state::SatelliteId;
+use std::collections::HashMap;
+
+/// Only the satellites above at least one of their thresholds are reported.
+pub fn get_quota_warnings() -> Option<HashMap<SatelliteId, QuotaStatus>> {
+    get_quota_statuses().map(|quota_statuses| {
+        quota_statuses
+            .statuses
+            .into_iter()
+            .filter(|(_, status)| !status.warnings.is_empty())
+            .collect()
+    })
+}
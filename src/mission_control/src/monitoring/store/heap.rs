@@ -1,13 +1,19 @@
 use crate::memory::STATE;
 use crate::types::state::{
-    CyclesMonitoringStrategy, HeapState, MissionControlSettings, Orbiters, Satellites, Settings,
+    CyclesFreezeAlert, CyclesMonitoringStrategy, FreezePredictions, HeapState,
+    MissionControlSettings, Orbiters, Satellites, Settings,
 };
-use junobuild_shared::types::state::{OrbiterId, SatelliteId};
+use junobuild_shared::types::interface::CyclesFreezePrediction;
+use junobuild_shared::types::state::{OrbiterId, SatelliteId, SegmentId};
 
 pub fn set_mission_control_strategy(strategy: &CyclesMonitoringStrategy) {
     STATE.with(|state| set_mission_control_setting_impl(strategy, &mut state.borrow_mut().heap))
 }
 
+pub fn set_freeze_alert(freeze_alert: &CyclesFreezeAlert) -> MissionControlSettings {
+    STATE.with(|state| set_freeze_alert_impl(freeze_alert, &mut state.borrow_mut().heap))
+}
+
 pub fn enable_mission_control_monitoring() -> Result<(), String> {
     STATE.with(|state| toggle_mission_control_monitoring_impl(true, &mut state.borrow_mut().heap))
 }
@@ -80,6 +86,17 @@ fn set_mission_control_setting_impl(strategy: &CyclesMonitoringStrategy, state:
     );
 }
 
+fn set_freeze_alert_impl(
+    freeze_alert: &CyclesFreezeAlert,
+    state: &mut HeapState,
+) -> MissionControlSettings {
+    let settings = MissionControlSettings::prepare_freeze_alert(&state.settings, freeze_alert);
+
+    state.settings = Some(settings.clone());
+
+    settings
+}
+
 fn toggle_mission_control_monitoring_impl(
     enable: bool,
     state: &mut HeapState,
@@ -171,3 +188,36 @@ fn toggle_orbiter_monitoring_impl(
 
     Ok(())
 }
+
+pub fn get_freeze_predictions() -> FreezePredictions {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .freeze_predictions
+            .clone()
+            .unwrap_or_default()
+    })
+}
+
+pub fn get_freeze_prediction(segment_id: &SegmentId) -> Option<CyclesFreezePrediction> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .freeze_predictions
+            .as_ref()
+            .and_then(|predictions| predictions.get(segment_id).cloned())
+    })
+}
+
+pub fn set_freeze_prediction(segment_id: &SegmentId, prediction: &CyclesFreezePrediction) {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .heap
+            .freeze_predictions
+            .get_or_insert_with(FreezePredictions::new)
+            .insert(*segment_id, prediction.clone())
+    });
+}
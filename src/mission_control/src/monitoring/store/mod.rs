@@ -1,2 +1,3 @@
 pub mod heap;
+pub mod runtime;
 pub mod stable;
@@ -0,0 +1,24 @@
+use crate::memory::RUNTIME_STATE;
+use crate::types::runtime::{FreezingThreshold, QuotaStatuses};
+use junobuild_shared::types::state::SegmentId;
+
+pub fn get_quota_statuses() -> Option<QuotaStatuses> {
+    RUNTIME_STATE.with(|state| state.borrow().quota_statuses.clone())
+}
+
+pub fn set_quota_statuses(quota_statuses: QuotaStatuses) {
+    RUNTIME_STATE.with(|state| state.borrow_mut().quota_statuses = Some(quota_statuses));
+}
+
+pub fn get_freezing_threshold(segment_id: &SegmentId) -> Option<FreezingThreshold> {
+    RUNTIME_STATE.with(|state| state.borrow().freezing_thresholds.get(segment_id).cloned())
+}
+
+pub fn set_freezing_threshold(segment_id: &SegmentId, threshold: &FreezingThreshold) {
+    RUNTIME_STATE.with(|state| {
+        state
+            .borrow_mut()
+            .freezing_thresholds
+            .insert(*segment_id, threshold.clone())
+    });
+}
@@ -0,0 +1,4 @@
+mod msg;
+pub mod satellite;
+pub mod schedule;
+pub mod store;
@@ -0,0 +1,3 @@
+pub const ERROR_PREVIEW_INVALID_TTL: &str =
+    "The time to live of a preview must be between 1 second and 30 days.";
+pub const ERROR_PREVIEW_NOT_FOUND: &str = "Preview not found.";
@@ -0,0 +1,72 @@
+use crate::constants::{MAX_PREVIEW_TTL_NS, PREVIEW_CYCLES_RESERVE};
+use crate::previews::msg::{ERROR_PREVIEW_INVALID_TTL, ERROR_PREVIEW_NOT_FOUND};
+use crate::previews::schedule::schedule_preview_deletion;
+use crate::previews::store::{add_preview, get_preview};
+use crate::segments::satellite::{create_satellite_with_config, delete_satellite};
+use crate::types::interface::{CreateCanisterConfig, CreatePreviewConfig};
+use crate::types::state::{Preview, Satellite};
+use candid::Nat;
+use ic_cdk::api::time;
+use junobuild_shared::mgmt::ic::segment_status;
+use junobuild_shared::types::state::SatelliteId;
+
+const DAY_SECONDS: u128 = 60 * 60 * 24;
+
+/// Creates a satellite registered as a preview. It is deleted, and its cycles reclaimed, once its time to live elapsed.
+pub async fn create_preview_satellite(
+    CreatePreviewConfig {
+        name,
+        subnet_id,
+        ttl_seconds,
+    }: &CreatePreviewConfig,
+) -> Result<Satellite, String> {
+    let ttl = ttl_seconds
+        .checked_mul(1_000_000_000)
+        .filter(|ttl| *ttl > 0 && *ttl <= MAX_PREVIEW_TTL_NS)
+        .ok_or_else(|| ERROR_PREVIEW_INVALID_TTL.to_string())?;
+
+    let satellite = create_satellite_with_config(&CreateCanisterConfig {
+        name: name.clone(),
+        subnet_id: *subnet_id,
+        init_config: None,
+        project_template: None,
+    })
+    .await?;
+
+    let now = time();
+
+    let preview = Preview {
+        created_at: now,
+        expires_at: now.saturating_add(ttl),
+    };
+
+    add_preview(&satellite.satellite_id, &preview);
+
+    schedule_preview_deletion(&satellite.satellite_id, &preview);
+
+    Ok(satellite)
+}
+
+/// Deletes a preview - when it expires or before - and reclaims its cycles.
+pub async fn delete_preview_satellite(satellite_id: &SatelliteId) -> Result<(), String> {
+    if get_preview(satellite_id).is_none() {
+        return Err(ERROR_PREVIEW_NOT_FOUND.to_string());
+    }
+
+    delete_satellite(satellite_id, reclaimable_cycles(satellite_id).await?).await
+}
+
+/// The balance of the preview minus the cycles it needs not to be frozen and a reserve for the calls that delete it.
+async fn reclaimable_cycles(satellite_id: &SatelliteId) -> Result<u128, String> {
+    let status = segment_status(*satellite_id).await?.status;
+
+    let freezing_threshold = status.idle_cycles_burned_per_day * status.settings.freezing_threshold
+        / Nat::from(DAY_SECONDS);
+
+    let balance = u128::try_from(status.cycles.0).unwrap_or_default();
+    let reserve = u128::try_from(freezing_threshold.0)
+        .unwrap_or(u128::MAX)
+        .saturating_add(PREVIEW_CYCLES_RESERVE);
+
+    Ok(balance.saturating_sub(reserve))
+}
@@ -0,0 +1,41 @@
+use crate::constants::PREVIEW_DELETION_RETRY_NS;
+use crate::previews::satellite::delete_preview_satellite;
+use crate::previews::store::{get_preview, get_previews};
+use crate::types::state::Preview;
+use ic_cdk::api::time;
+use ic_cdk::spawn;
+use ic_cdk_timers::set_timer;
+use junobuild_shared::types::state::SatelliteId;
+use std::time::Duration;
+
+/// The timers do not survive an upgrade, the deletions of the previews are therefore scheduled again.
+pub fn schedule_previews() {
+    for (satellite_id, preview) in get_previews() {
+        schedule_preview_deletion(&satellite_id, &preview);
+    }
+}
+
+pub fn schedule_preview_deletion(satellite_id: &SatelliteId, preview: &Preview) {
+    let delay = preview.expires_at.saturating_sub(time());
+
+    schedule_deletion(*satellite_id, delay);
+}
+
+fn schedule_deletion(satellite_id: SatelliteId, delay_ns: u64) {
+    set_timer(Duration::from_nanos(delay_ns), move || {
+        spawn(delete_expired_preview(satellite_id))
+    });
+}
+
+async fn delete_expired_preview(satellite_id: SatelliteId) {
+    // The preview might have been deleted in the meantime.
+    let expired = get_preview(&satellite_id).is_some_and(|preview| preview.expires_at <= time());
+
+    if !expired {
+        return;
+    }
+
+    if delete_preview_satellite(&satellite_id).await.is_err() {
+        schedule_deletion(satellite_id, PREVIEW_DELETION_RETRY_NS);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::memory::STATE;
+use crate::types::state::{HeapState, Preview, Previews};
+use junobuild_shared::types::state::SatelliteId;
+use std::collections::HashMap;
+
+pub fn get_previews() -> Previews {
+    STATE.with(|state| state.borrow().heap.previews.clone().unwrap_or_default())
+}
+
+pub fn get_preview(satellite_id: &SatelliteId) -> Option<Preview> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .previews
+            .as_ref()
+            .and_then(|previews| previews.get(satellite_id).cloned())
+    })
+}
+
+pub fn add_preview(satellite_id: &SatelliteId, preview: &Preview) {
+    STATE.with(|state| add_preview_impl(satellite_id, preview, &mut state.borrow_mut().heap))
+}
+
+pub fn delete_preview(satellite_id: &SatelliteId) {
+    STATE.with(|state| {
+        if let Some(previews) = state.borrow_mut().heap.previews.as_mut() {
+            previews.remove(satellite_id);
+        }
+    })
+}
+
+fn add_preview_impl(satellite_id: &SatelliteId, preview: &Preview, state: &mut HeapState) {
+    state
+        .previews
+        .get_or_insert_with(HashMap::new)
+        .insert(*satellite_id, preview.clone());
+}
@@ -0,0 +1,99 @@
+use crate::segments::msg::SATELLITE_NOT_FOUND;
+use crate::segments::satellite::create_satellite_with_config;
+use crate::segments::store::get_satellite;
+use crate::types::interface::{CloneSatelliteOptions, CreateCanisterConfig};
+use crate::types::state::Satellite;
+use ic_cdk::api::call::CallResult;
+use ic_cdk::call;
+use junobuild_shared::types::interface::{
+    ExportChunk, ExportChunkArgs, ImportChunkArgs, TransferSection,
+};
+use junobuild_shared::types::state::SatelliteId;
+
+/// Creates a new satellite and copies the selected parts of the source satellite in it - e.g. to spin up a staging
+/// environment from production. Each part is exported from the source and imported in the new satellite chunk by
+/// chunk.
+///
+/// The copy is not atomic: if a transfer fails, the new satellite remains attached to the mission control with the
+/// parts copied so far.
+pub async fn clone_satellite(
+    source_id: &SatelliteId,
+    options: &CloneSatelliteOptions,
+) -> Result<Satellite, String> {
+    if get_satellite(source_id).is_none() {
+        return Err(SATELLITE_NOT_FOUND.to_string());
+    }
+
+    let satellite = create_satellite_with_config(&CreateCanisterConfig {
+        name: options.name.clone(),
+        subnet_id: options.subnet_id,
+        init_config: None,
+        project_template: None,
+    })
+    .await?;
+
+    for section in transfer_sections(options) {
+        transfer_section(source_id, &satellite.satellite_id, section).await?;
+    }
+
+    Ok(satellite)
+}
+
+fn transfer_sections(
+    CloneSatelliteOptions {
+        config,
+        db_collections,
+        storage_collections,
+        ..
+    }: &CloneSatelliteOptions,
+) -> Vec<TransferSection> {
+    let config = config.then_some(TransferSection::Config);
+
+    let db = db_collections
+        .iter()
+        .map(|collection| TransferSection::Db(collection.clone()));
+
+    let storage = storage_collections
+        .iter()
+        .map(|collection| TransferSection::Storage(collection.clone()));
+
+    config.into_iter().chain(db).chain(storage).collect()
+}
+
+async fn transfer_section(
+    source_id: &SatelliteId,
+    target_id: &SatelliteId,
+    section: TransferSection,
+) -> Result<(), String> {
+    let mut cursor = None;
+
+    loop {
+        let args = ExportChunkArgs {
+            section: section.clone(),
+            cursor,
+        };
+
+        let result: CallResult<(ExportChunk,)> = call(*source_id, "export_chunk", (args,)).await;
+
+        let ExportChunk { data, cursor: next } = match result {
+            Err((_, message)) => return Err(["Export chunk failed.", &message].join(" - ")),
+            Ok((chunk,)) => chunk,
+        };
+
+        let args = ImportChunkArgs {
+            section: section.clone(),
+            data,
+        };
+
+        let result: CallResult<()> = call(*target_id, "import_chunk", (args,)).await;
+
+        if let Err((_, message)) = result {
+            return Err(["Import chunk failed.", &message].join(" - "));
+        }
+
+        match next {
+            None => return Ok(()),
+            Some(next) => cursor = Some(next),
+        }
+    }
+}
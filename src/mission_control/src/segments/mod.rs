@@ -1,5 +1,9 @@
 mod canister;
+pub mod clone;
 mod msg;
 pub mod orbiter;
 pub mod satellite;
 pub mod store;
+pub mod template;
+pub mod upgrade;
+mod wasm;
@@ -1,2 +1,6 @@
 pub const ORBITER_NOT_FOUND: &str = "Orbiter not found or not owned by this mission control.";
 pub const SATELLITE_NOT_FOUND: &str = "Satellite not found or not owned by this mission control.";
+pub const UPGRADE_CHECKSUM_MISMATCH: &str =
+    "Checksum of the WASM does not match the one of the release.";
+pub const UPGRADE_MODULE_HASH_MISMATCH: &str =
+    "Hash of the module installed does not match the one of the release.";
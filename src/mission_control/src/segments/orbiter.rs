@@ -16,6 +16,8 @@ pub async fn create_orbiter(name: &Option<String>) -> Result<Orbiter, String> {
     let config: CreateCanisterConfig = CreateCanisterConfig {
         name: name.clone(),
         subnet_id: None,
+        init_config: None,
+        project_template: None,
     };
 
     create_canister("get_create_orbiter_fee", create_and_save_orbiter, &config).await
@@ -73,7 +75,9 @@ pub async fn delete_orbiter(orbiter_id: &OrbiterId, cycles_to_deposit: u128) ->
 
 async fn create_and_save_orbiter(
     user: UserId,
-    CreateCanisterConfig { name, subnet_id }: CreateCanisterConfig,
+    CreateCanisterConfig {
+        name, subnet_id, ..
+    }: CreateCanisterConfig,
     block_index: Option<BlockIndex>,
 ) -> Result<Orbiter, String> {
     let console = Principal::from_text(CONSOLE).unwrap();
@@ -82,6 +86,8 @@ async fn create_and_save_orbiter(
         user,
         block_index,
         subnet_id,
+        init_config: None,
+        project_template: None,
     };
 
     let result: CallResult<(OrbiterId,)> = call(console, "create_orbiter", (args,)).await;
@@ -1,3 +1,4 @@
+use crate::previews::store::delete_preview;
 use crate::segments::canister::{create_canister, delete_canister};
 use crate::segments::msg::SATELLITE_NOT_FOUND;
 use crate::segments::store::{
@@ -18,6 +19,8 @@ pub async fn create_satellite(name: &str) -> Result<Satellite, String> {
     let config: CreateCanisterConfig = CreateCanisterConfig {
         name: Some(name.to_string()),
         subnet_id: None,
+        init_config: None,
+        project_template: None,
     };
 
     create_canister(
@@ -51,6 +54,7 @@ pub async fn delete_satellite(
             delete_canister(satellite_id, cycles_to_deposit).await?;
 
             delete_satellite_store(satellite_id);
+            delete_preview(satellite_id);
 
             Ok(())
         }
@@ -59,7 +63,12 @@ pub async fn delete_satellite(
 
 async fn create_and_save_satellite(
     user: UserId,
-    CreateCanisterConfig { name, subnet_id }: CreateCanisterConfig,
+    CreateCanisterConfig {
+        name,
+        subnet_id,
+        init_config,
+        project_template,
+    }: CreateCanisterConfig,
     block_index: Option<BlockIndex>,
 ) -> Result<Satellite, String> {
     let console = Principal::from_text(CONSOLE).unwrap();
@@ -68,6 +77,8 @@ async fn create_and_save_satellite(
         user,
         block_index,
         subnet_id,
+        init_config,
+        project_template,
     };
 
     let result: CallResult<(SatelliteId,)> = call(console, "create_satellite", (args,)).await;
@@ -103,6 +114,7 @@ pub async fn detach_satellite(satellite_id: &SatelliteId) -> Result<(), String>
         None => Err(SATELLITE_NOT_FOUND.to_string()),
         Some(_satellite) => {
             delete_satellite_store(satellite_id);
+            delete_preview(satellite_id);
 
             Ok(())
         }
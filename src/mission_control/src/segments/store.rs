@@ -1,7 +1,10 @@
+use crate::constants::UPGRADE_HISTORY_MAX_LENGTH;
 use crate::memory::STATE;
 use crate::types::core::Segment;
-use crate::types::state::{Orbiter, Orbiters, Satellite, Satellites};
-use junobuild_shared::types::state::{Metadata, OrbiterId, SatelliteId};
+use crate::types::state::{
+    HeapState, Orbiter, Orbiters, Satellite, Satellites, UpgradeAttestation,
+};
+use junobuild_shared::types::state::{Metadata, OrbiterId, SatelliteId, SegmentId};
 use std::collections::HashMap;
 use std::hash::Hash;
 
@@ -122,3 +125,44 @@ fn set_metadata_impl<K: Eq + Hash + Copy, T: Clone + Segment<K>>(
         }
     }
 }
+
+// ---------------------------------------------------------
+// Upgrade history
+// ---------------------------------------------------------
+
+pub fn add_upgrade_attestation(segment_id: &SegmentId, attestation: &UpgradeAttestation) {
+    STATE.with(|state| {
+        add_upgrade_attestation_impl(segment_id, attestation, &mut state.borrow_mut().heap)
+    })
+}
+
+fn add_upgrade_attestation_impl(
+    segment_id: &SegmentId,
+    attestation: &UpgradeAttestation,
+    state: &mut HeapState,
+) {
+    let history = state
+        .upgrade_history
+        .get_or_insert_with(HashMap::new)
+        .entry(*segment_id)
+        .or_default();
+
+    history.push(attestation.clone());
+
+    // The oldest attestations are dropped
+    if history.len() > UPGRADE_HISTORY_MAX_LENGTH {
+        history.drain(..history.len() - UPGRADE_HISTORY_MAX_LENGTH);
+    }
+}
+
+pub fn list_upgrade_history(segment_id: &SegmentId) -> Vec<UpgradeAttestation> {
+    STATE.with(|state| {
+        state
+            .borrow()
+            .heap
+            .upgrade_history
+            .as_ref()
+            .and_then(|history| history.get(segment_id).cloned())
+            .unwrap_or_default()
+    })
+}
@@ -0,0 +1,58 @@
+use crate::segments::msg::SATELLITE_NOT_FOUND;
+use crate::segments::store::get_satellite;
+use crate::segments::wasm::download_wasm;
+use crate::types::console::{GetTemplateWasmChunk, TemplateWasmChunk};
+use crate::types::interface::InstallTemplateArgs;
+use candid::{Encode, Principal};
+use ic_cdk::api::call::CallResult;
+use ic_cdk::api::management_canister::main::CanisterInstallMode;
+use ic_cdk::call;
+use junobuild_shared::env::CONSOLE;
+use junobuild_shared::mgmt::ic::install_code;
+use junobuild_shared::mgmt::types::ic::WasmArg;
+use junobuild_shared::types::state::SatelliteId;
+
+/// Upgrades a satellite with a serverless function template published in the console.
+///
+/// The WASM is fetched chunk by chunk and its checksum is verified against the one registered when the template version was published.
+pub async fn install_satellite_template(
+    satellite_id: &SatelliteId,
+    InstallTemplateArgs { id, version }: &InstallTemplateArgs,
+) -> Result<(), String> {
+    if get_satellite(satellite_id).is_none() {
+        return Err(SATELLITE_NOT_FOUND.to_string());
+    }
+
+    let (wasm, _) =
+        download_wasm(|chunk_index| fetch_template_wasm_chunk(id, version, chunk_index)).await?;
+
+    let wasm_arg = WasmArg {
+        wasm,
+        install_arg: Encode!().unwrap(),
+    };
+
+    install_code(*satellite_id, &wasm_arg, CanisterInstallMode::Upgrade(None))
+        .await
+        .map_err(|(_, message)| ["Install template failed.", &message].join(" - "))
+}
+
+async fn fetch_template_wasm_chunk(
+    id: &str,
+    version: &str,
+    chunk_index: u64,
+) -> Result<TemplateWasmChunk, String> {
+    let console = Principal::from_text(CONSOLE).unwrap();
+
+    let args = GetTemplateWasmChunk {
+        version: version.to_string(),
+        chunk_index,
+    };
+
+    let result: CallResult<(TemplateWasmChunk,)> =
+        call(console, "get_template_wasm_chunk", (id, args)).await;
+
+    match result {
+        Err((_, message)) => Err(["Fetch template failed.", &message].join(" - ")),
+        Ok((chunk,)) => Ok(chunk),
+    }
+}
@@ -0,0 +1,105 @@
+use crate::segments::msg::{
+    ORBITER_NOT_FOUND, SATELLITE_NOT_FOUND, UPGRADE_CHECKSUM_MISMATCH, UPGRADE_MODULE_HASH_MISMATCH,
+};
+use crate::segments::store::{add_upgrade_attestation, get_orbiter, get_satellite};
+use crate::segments::wasm::download_wasm;
+use crate::types::console::{GetReleaseWasmChunk, ReleaseWasmChunk, SegmentType};
+use crate::types::interface::UpgradeSegmentArgs;
+use crate::types::state::UpgradeAttestation;
+use candid::{Encode, Principal};
+use ic_cdk::api::call::CallResult;
+use ic_cdk::api::management_canister::main::CanisterInstallMode;
+use ic_cdk::api::time;
+use ic_cdk::call;
+use junobuild_shared::env::CONSOLE;
+use junobuild_shared::mgmt::ic::{install_code, segment_status};
+use junobuild_shared::mgmt::types::ic::WasmArg;
+use junobuild_shared::types::state::{OrbiterId, SatelliteId, SegmentId};
+
+pub async fn upgrade_satellite(
+    satellite_id: &SatelliteId,
+    args: &UpgradeSegmentArgs,
+) -> Result<UpgradeAttestation, String> {
+    if get_satellite(satellite_id).is_none() {
+        return Err(SATELLITE_NOT_FOUND.to_string());
+    }
+
+    upgrade_segment(satellite_id, SegmentType::Satellite, args).await
+}
+
+pub async fn upgrade_orbiter(
+    orbiter_id: &OrbiterId,
+    args: &UpgradeSegmentArgs,
+) -> Result<UpgradeAttestation, String> {
+    if get_orbiter(orbiter_id).is_none() {
+        return Err(ORBITER_NOT_FOUND.to_string());
+    }
+
+    upgrade_segment(orbiter_id, SegmentType::Orbiter, args).await
+}
+
+/// Upgrades a module with a release published in the console.
+///
+/// The WASM is installed only if its checksum matches both the one published by the console release registry and the
+/// one provided by the caller - published independently of the console. The hash of the module installed is then read
+/// back from the management canister and the attestation is recorded in the upgrade history of the module.
+async fn upgrade_segment(
+    segment_id: &SegmentId,
+    segment_type: SegmentType,
+    UpgradeSegmentArgs { version, sha256 }: &UpgradeSegmentArgs,
+) -> Result<UpgradeAttestation, String> {
+    let (wasm, checksum) =
+        download_wasm(|chunk_index| fetch_release_wasm_chunk(&segment_type, version, chunk_index))
+            .await?;
+
+    if checksum != *sha256 {
+        return Err(UPGRADE_CHECKSUM_MISMATCH.to_string());
+    }
+
+    let wasm_arg = WasmArg {
+        wasm,
+        install_arg: Encode!().unwrap(),
+    };
+
+    install_code(*segment_id, &wasm_arg, CanisterInstallMode::Upgrade(None))
+        .await
+        .map_err(|(_, message)| ["Upgrade failed.", &message].join(" - "))?;
+
+    let module_hash = segment_status(*segment_id).await?.status.module_hash;
+
+    if module_hash.as_deref() != Some(&sha256[..]) {
+        return Err(UPGRADE_MODULE_HASH_MISMATCH.to_string());
+    }
+
+    let attestation = UpgradeAttestation {
+        version: version.clone(),
+        sha256: *sha256,
+        upgraded_at: time(),
+    };
+
+    add_upgrade_attestation(segment_id, &attestation);
+
+    Ok(attestation)
+}
+
+async fn fetch_release_wasm_chunk(
+    segment_type: &SegmentType,
+    version: &str,
+    chunk_index: u64,
+) -> Result<ReleaseWasmChunk, String> {
+    let console = Principal::from_text(CONSOLE).unwrap();
+
+    let args = GetReleaseWasmChunk {
+        segment_type: segment_type.clone(),
+        version: version.to_string(),
+        chunk_index,
+    };
+
+    let result: CallResult<(ReleaseWasmChunk,)> =
+        call(console, "get_release_wasm_chunk", (args,)).await;
+
+    match result {
+        Err((_, message)) => Err(["Fetch release failed.", &message].join(" - ")),
+        Ok((chunk,)) => Ok(chunk),
+    }
+}
@@ -0,0 +1,43 @@
+use crate::types::core::ConsoleWasmChunk;
+use junobuild_shared::types::core::{Blob, Hash};
+use sha2::{Digest, Sha256};
+use std::future::Future;
+
+/// Downloads a WASM published in the console chunk by chunk and verifies its checksum against the one registered by the console.
+///
+/// Returns the WASM and its attested checksum.
+pub async fn download_wasm<F, Fut, C>(fetch_chunk: F) -> Result<(Blob, Hash), String>
+where
+    F: Fn(u64) -> Fut,
+    Fut: Future<Output = Result<C, String>>,
+    C: ConsoleWasmChunk,
+{
+    let chunk = fetch_chunk(0).await?;
+
+    let chunks_length = chunk.chunks_length();
+    let sha256 = chunk.sha256();
+
+    let mut wasm: Blob = chunk.into_content();
+
+    for chunk_index in 1..chunks_length {
+        let chunk = fetch_chunk(chunk_index).await?;
+
+        wasm.extend(chunk.into_content());
+    }
+
+    let checksum = wasm_sha256(&wasm);
+
+    if checksum != sha256 {
+        return Err(
+            "Checksum of the WASM does not match the one published by the console.".to_string(),
+        );
+    }
+
+    Ok((wasm, checksum))
+}
+
+pub fn wasm_sha256(wasm: &[u8]) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(wasm);
+    hasher.finalize().into()
+}
@@ -2,6 +2,8 @@ pub mod state {
     use crate::memory::init_stable_state;
     use candid::{CandidType, Principal};
     use ic_stable_structures::StableBTreeMap;
+    use junobuild_shared::types::core::Hash;
+    use junobuild_shared::types::interface::CyclesFreezePrediction;
     use junobuild_shared::types::memory::Memory;
     use junobuild_shared::types::state::{
         ArchiveTime, Controllers, Metadata, OrbiterId, SegmentId, SegmentStatusResult, Timestamp,
@@ -15,6 +17,12 @@ pub mod state {
 
     pub type Statuses = BTreeMap<ArchiveTime, SegmentStatusResult>;
 
+    pub type UpgradeHistory = HashMap<SegmentId, Vec<UpgradeAttestation>>;
+
+    pub type Previews = HashMap<SatelliteId, Preview>;
+
+    pub type FreezePredictions = HashMap<SegmentId, CyclesFreezePrediction>;
+
     pub type MonitoringHistoryStable =
         StableBTreeMap<MonitoringHistoryKey, MonitoringHistory, Memory>;
 
@@ -40,6 +48,9 @@ pub mod state {
         pub archive: Archive,
         pub orbiters: Orbiters,
         pub settings: Option<MissionControlSettings>,
+        pub upgrade_history: Option<UpgradeHistory>,
+        pub previews: Option<Previews>,
+        pub freeze_predictions: Option<FreezePredictions>,
     }
 
     #[derive(Default, CandidType, Serialize, Deserialize, Clone)]
@@ -53,10 +64,26 @@ pub mod state {
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct MissionControlSettings {
         pub monitoring: Option<Monitoring>,
+        pub freeze_alert: Option<CyclesFreezeAlert>,
         pub created_at: Timestamp,
         pub updated_at: Timestamp,
     }
 
+    /// The checksum of the WASM installed, verified against the one published by the console release registry.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct UpgradeAttestation {
+        pub version: String,
+        pub sha256: Hash,
+        pub upgraded_at: Timestamp,
+    }
+
+    /// An ephemeral satellite - e.g. created per pull request - deleted, and its cycles reclaimed, once it expires.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct Preview {
+        pub created_at: Timestamp,
+        pub expires_at: Timestamp,
+    }
+
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct Satellite {
         pub satellite_id: SatelliteId,
@@ -116,6 +143,12 @@ pub mod state {
         pub fund_cycles: u128,
     }
 
+    /// Modules predicted to freeze within the horizon - in days - are flagged with an alert.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct CyclesFreezeAlert {
+        pub horizon_days: u64,
+    }
+
     #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct MonitoringHistoryKey {
         pub segment_id: SegmentId,
@@ -142,15 +175,33 @@ pub mod state {
 
 pub mod runtime {
     use canfund::FundManager;
+    use junobuild_shared::types::interface::QuotaStatus;
+    use junobuild_shared::types::state::{SatelliteId, SegmentId, Timestamp};
+    use std::collections::HashMap;
 
     #[derive(Default)]
     pub struct RuntimeState {
         pub fund_manager: Option<FundManager>,
+        pub freezing_thresholds: HashMap<SegmentId, FreezingThreshold>,
+        pub quota_statuses: Option<QuotaStatuses>,
+    }
+
+    #[derive(Default, Clone)]
+    pub struct QuotaStatuses {
+        pub statuses: HashMap<SatelliteId, QuotaStatus>,
+        pub fetched_at: Timestamp,
+    }
+
+    #[derive(Clone)]
+    pub struct FreezingThreshold {
+        pub cycles: u128,
+        pub fetched_at: Timestamp,
     }
 }
 
 pub mod core {
     use crate::types::state::Monitoring;
+    use junobuild_shared::types::core::{Blob, Hash};
     use junobuild_shared::types::state::Metadata;
 
     pub trait Segment<K> {
@@ -160,19 +211,93 @@ pub mod core {
     pub trait SettingsMonitoring {
         fn monitoring(&self) -> Option<&Monitoring>;
     }
+
+    /// A chunk of a WASM served by the console - i.e. of a release or of a template.
+    pub trait ConsoleWasmChunk {
+        fn chunks_length(&self) -> u64;
+        fn sha256(&self) -> Hash;
+        fn into_content(self) -> Blob;
+    }
 }
 
 pub mod interface {
     use crate::types::state::CyclesMonitoringStrategy;
     use candid::CandidType;
     use junobuild_shared::mgmt::types::cmc::SubnetId;
-    use junobuild_shared::types::state::{OrbiterId, SatelliteId, SegmentId, Timestamp};
+    use junobuild_shared::types::core::Hash;
+    use junobuild_shared::types::interface::{
+        CyclesFreezePrediction, MemorySize, QuotaStatus, SatelliteInitConfig, SetController,
+    };
+    use junobuild_shared::types::state::{
+        ControllerId, OrbiterId, SatelliteId, SegmentId, Timestamp,
+    };
     use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
 
+    /// The configuration to create a module. The `init_config` and the `project_template` - the id of a template
+    /// defined in the Console - are applied by a Satellite when it is installed and are not used when creating an
+    /// Orbiter.
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct CreateCanisterConfig {
         pub name: Option<String>,
         pub subnet_id: Option<SubnetId>,
+        pub init_config: Option<SatelliteInitConfig>,
+        pub project_template: Option<String>,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub enum ModulesControllersOperation {
+        Set {
+            controllers: Vec<ControllerId>,
+            controller: SetController,
+        },
+        Delete {
+            controllers: Vec<ControllerId>,
+        },
+        Rotate {
+            from: ControllerId,
+            to: ControllerId,
+            controller: SetController,
+        },
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct ModulesControllersResults {
+        pub satellites: Vec<(SatelliteId, Result<(), String>)>,
+        pub orbiters: Vec<(OrbiterId, Result<(), String>)>,
+    }
+
+    /// The options to clone a satellite in a new one - created with the `name` and in the subnet provided. The
+    /// configuration is copied if `config` is set, as well as the listed collections of the Datastore and the
+    /// Storage - each with its rule and its documents or assets.
+    #[derive(CandidType, Deserialize)]
+    pub struct CloneSatelliteOptions {
+        pub name: Option<String>,
+        pub subnet_id: Option<SubnetId>,
+        pub config: bool,
+        pub db_collections: Vec<String>,
+        pub storage_collections: Vec<String>,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct CreatePreviewConfig {
+        pub name: Option<String>,
+        pub subnet_id: Option<SubnetId>,
+        pub ttl_seconds: u64,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct InstallTemplateArgs {
+        pub id: String,
+        pub version: String,
+    }
+
+    /// The `sha256` is the checksum of the release as published independently of the console, e.g. in its release
+    /// notes. The WASM is verified against it before and after it is installed.
+    #[derive(CandidType, Deserialize)]
+    pub struct UpgradeSegmentArgs {
+        pub version: String,
+        pub sha256: Hash,
     }
 
     #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -209,11 +334,14 @@ pub mod interface {
     pub struct CyclesMonitoringStatus {
         pub running: bool,
         pub monitored_ids: Vec<SegmentId>,
+        pub freeze_predictions: HashMap<SegmentId, CyclesFreezePrediction>,
     }
 
+    /// - `quotas`: The last status reported by the satellites that crossed one of their soft quota thresholds.
     #[derive(CandidType, Serialize, Deserialize, Clone)]
     pub struct MonitoringStatus {
         pub cycles: Option<CyclesMonitoringStatus>,
+        pub quotas: Option<HashMap<SatelliteId, QuotaStatus>>,
     }
 
     #[derive(CandidType, Deserialize, Clone)]
@@ -222,4 +350,68 @@ pub mod interface {
         pub from: Option<Timestamp>,
         pub to: Option<Timestamp>,
     }
+
+    /// The fields of the health of a satellite that are read to build the usage digest.
+    #[derive(CandidType, Deserialize)]
+    pub struct SatelliteHealth {
+        pub memory_size: MemorySize,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct GetAnalytics {
+        pub satellite_id: Option<SatelliteId>,
+        pub from: Option<Timestamp>,
+        pub to: Option<Timestamp>,
+    }
+
+    /// The fields of the page views metrics of an orbiter that are read to build the usage digest.
+    #[derive(CandidType, Deserialize)]
+    pub struct AnalyticsMetricsPageViews {
+        pub unique_sessions: u64,
+        pub total_page_views: u32,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct AnalyticsTop10PageViews {
+        pub pages: Vec<(String, u32)>,
+    }
+}
+
+pub mod console {
+    use candid::CandidType;
+    use junobuild_shared::types::core::{Blob, Hash};
+    use serde::Deserialize;
+
+    #[derive(CandidType, Clone)]
+    pub enum SegmentType {
+        Satellite,
+        Orbiter,
+    }
+
+    #[derive(CandidType)]
+    pub struct GetReleaseWasmChunk {
+        pub segment_type: SegmentType,
+        pub version: String,
+        pub chunk_index: u64,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct ReleaseWasmChunk {
+        pub content: Blob,
+        pub chunks_length: u64,
+        pub sha256: Hash,
+    }
+
+    #[derive(CandidType)]
+    pub struct GetTemplateWasmChunk {
+        pub version: String,
+        pub chunk_index: u64,
+    }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct TemplateWasmChunk {
+        pub content: Blob,
+        pub chunks_length: u64,
+        pub sha256: Hash,
+    }
 }
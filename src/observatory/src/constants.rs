@@ -1,2 +1,33 @@
 // 1 hour
 pub const CRON_INTERVAL_NS: u64 = 3_600_000_000_000;
+
+// The usage digest is sent weekly (1h * 24 * 7).
+pub const DIGEST_INTERVAL_NS: u64 = 3_600_000_000_000 * 24 * 7;
+
+pub const DIGEST_DEFAULT_TOP_PAGES: u8 = 5;
+
+pub const NOTIFICATION_CHANNELS_MAX_LENGTH: usize = 10;
+
+pub const NOTIFICATION_CHANNEL_ID_MAX_LENGTH: usize = 64;
+
+// A user can send a test notification once per minute.
+pub const NOTIFICATION_TEST_INTERVAL_NS: u64 = 60_000_000_000;
+
+// The same issues are notified again daily as long as they persist (1h * 24).
+pub const NOTIFICATION_REMINDER_INTERVAL_NS: u64 = 3_600_000_000_000 * 24;
+
+// XChaCha20-Poly1305 - the envelope of a secret is version || nonce || ciphertext || tag.
+pub const SECRET_ENVELOPE_VERSION: u8 = 1;
+pub const SECRET_KEY_LENGTH: usize = 32;
+pub const SECRET_NONCE_LENGTH: usize = 24;
+
+// Aligned with the minimal threshold applied by the mission control when it collects the statuses.
+pub const CYCLES_MIN_THRESHOLD: u64 = 500_000_000_000;
+
+// The transform function reduces the response to its status, but its headers count toward the limit as well.
+pub const NOTIFICATION_MAX_RESPONSE_BYTES: u64 = 16_384;
+
+pub const NOTIFICATION_TRANSFORM_METHOD: &str = "transform_notification_response";
+
+// The cost of the outcalls is estimated for the largest subnets. The cycles that are not consumed are refunded.
+pub const NOTIFICATION_SUBNET_SIZE: u128 = 34;
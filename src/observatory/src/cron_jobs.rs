@@ -1,9 +1,13 @@
-use crate::store::{get_cron_tabs, set_statuses};
-use crate::types::state::CronTab;
+use crate::constants::DIGEST_INTERVAL_NS;
+use crate::notifications::{notify_digest, notify_statuses};
+use crate::store::{get_cron_tabs, get_digest, set_digest, set_statuses};
+use crate::types::state::{ArchiveDigest, CronTab};
 use ic_cdk::api::call::CallResult;
+use ic_cdk::api::time;
 use ic_cdk::{call, spawn};
 use junobuild_shared::types::interface::StatusesArgs;
-use junobuild_shared::types::state::{SegmentsStatuses, UserId};
+use junobuild_shared::types::interface::{CyclesFreezePrediction, GetUsageDigest, UsageDigest};
+use junobuild_shared::types::state::{SegmentId, SegmentsStatuses, UserId};
 use lazy_static::lazy_static;
 use std::sync::Mutex;
 
@@ -22,6 +26,17 @@ pub fn cron_jobs() {
         for (user, cron_tab) in statuses_cron_tabs {
             spawn(collect_statuses(user, cron_tab))
         }
+
+        let now = time();
+
+        let digest_cron_tabs: Vec<(UserId, CronTab)> = get_cron_tabs()
+            .into_iter()
+            .filter(|(user, config)| is_digest_due(user, config, now))
+            .collect();
+
+        for (user, cron_tab) in digest_cron_tabs {
+            spawn(collect_digest(user, cron_tab))
+        }
     }
 }
 
@@ -31,6 +46,10 @@ async fn collect_statuses(user: UserId, cron_tab: CronTab) {
         let result = statuses(&cron_tab).await;
 
         set_statuses(&user, &result);
+
+        let freeze_predictions = freeze_predictions(&cron_tab).await;
+
+        notify_statuses(&user, &cron_tab, &result, &freeze_predictions);
     }
 }
 
@@ -53,3 +72,85 @@ async fn statuses(cron_tab: &CronTab) -> Result<SegmentsStatuses, String> {
         Ok((result,)) => Ok(result),
     }
 }
+
+/// The predictions are an addition to the statuses, therefore a mission control that cannot provide them - e.g. it was
+/// not yet upgraded - is notified without.
+async fn freeze_predictions(cron_tab: &CronTab) -> Vec<(SegmentId, CyclesFreezePrediction)> {
+    let result: CallResult<(Vec<(SegmentId, CyclesFreezePrediction)>,)> =
+        call(cron_tab.mission_control_id, "get_freeze_predictions", ()).await;
+
+    result.map_or_else(|_| Vec::new(), |(predictions,)| predictions)
+}
+
+fn is_digest_due(user: &UserId, cron_tab: &CronTab, now: u64) -> bool {
+    let enabled = cron_tab
+        .cron_jobs
+        .digest
+        .as_ref()
+        .is_some_and(|digest| digest.enabled);
+
+    if !enabled {
+        return false;
+    }
+
+    get_digest(user).map_or(true, |digest| {
+        now.saturating_sub(digest.timestamp) >= DIGEST_INTERVAL_NS
+    })
+}
+
+/// The digest is archived even if it cannot be collected, so that the user is notified of the failure at most once
+/// per interval.
+async fn collect_digest(user: UserId, cron_tab: CronTab) {
+    let previous = get_digest(&user);
+
+    let result = usage_digest(&cron_tab).await;
+
+    let memory_sizes = match &result {
+        Err(_) => previous
+            .as_ref()
+            .map(|previous| previous.memory_sizes.clone())
+            .unwrap_or_default(),
+        Ok(digest) => digest
+            .satellites
+            .iter()
+            .filter_map(|(satellite_id, usage)| {
+                usage
+                    .memory_size
+                    .as_ref()
+                    .map(|memory_size| (memory_size.heap + memory_size.stable) as u64)
+                    // The baseline of a satellite that did not answer is kept for the next digest.
+                    .or_else(|| {
+                        previous
+                            .as_ref()
+                            .and_then(|previous| previous.memory_sizes.get(satellite_id).copied())
+                    })
+                    .map(|memory_size| (*satellite_id, memory_size))
+            })
+            .collect(),
+    };
+
+    set_digest(
+        &user,
+        &ArchiveDigest {
+            timestamp: time(),
+            memory_sizes,
+        },
+    );
+
+    notify_digest(&user, &cron_tab, &result, &previous);
+}
+
+async fn usage_digest(cron_tab: &CronTab) -> Result<UsageDigest, String> {
+    let args = GetUsageDigest {
+        from: None,
+        to: None,
+    };
+
+    let result: CallResult<(UsageDigest,)> =
+        call(cron_tab.mission_control_id, "get_usage_digest", (args,)).await;
+
+    match result {
+        Err((_, message)) => Err(["Cannot get the usage digest.", &message].join(" - ")),
+        Ok((result,)) => Ok(result),
+    }
+}
@@ -0,0 +1,35 @@
+use crate::types::interface::{NotificationChannelKindNoSecret, NotificationChannelNoSecret};
+use crate::types::state::{NotificationChannel, NotificationChannelKind};
+
+impl From<&NotificationChannel> for NotificationChannelNoSecret {
+    fn from(channel: &NotificationChannel) -> Self {
+        NotificationChannelNoSecret {
+            kind: NotificationChannelKindNoSecret::from(&channel.kind),
+            enabled: channel.enabled,
+            created_at: channel.created_at,
+            updated_at: channel.updated_at,
+            version: channel.version,
+            last_error: channel.last_error.clone(),
+            last_failed_at: channel.last_failed_at,
+        }
+    }
+}
+
+impl From<&NotificationChannelKind> for NotificationChannelKindNoSecret {
+    fn from(kind: &NotificationChannelKind) -> Self {
+        match kind {
+            NotificationChannelKind::Webhook { url, headers } => {
+                NotificationChannelKindNoSecret::Webhook {
+                    url: url.clone(),
+                    headers: headers.iter().map(|(name, _)| name.clone()).collect(),
+                }
+            }
+            NotificationChannelKind::Telegram { chat_id, .. } => {
+                NotificationChannelKindNoSecret::Telegram {
+                    chat_id: chat_id.clone(),
+                }
+            }
+            NotificationChannelKind::Slack { .. } => NotificationChannelKindNoSecret::Slack,
+        }
+    }
+}
@@ -2,7 +2,11 @@ mod console;
 mod constants;
 mod cron_jobs;
 mod guards;
+mod impls;
+mod msg;
+mod notifications;
 mod reports;
+mod secrets;
 mod store;
 mod types;
 
@@ -12,13 +16,26 @@ use crate::cron_jobs::cron_jobs;
 use crate::guards::{
     caller_can_execute_cron_jobs, caller_is_admin_controller, caller_is_not_anonymous,
 };
+use crate::notifications::{
+    set_notification_channel as set_notification_channel_store,
+    test_notification_channel as test_notification_channel_store,
+    transform_notification_response as transform_notification_response_store,
+};
 use crate::reports::collect_statuses as collect_statuses_report;
 use crate::store::{
-    delete_controllers, get_cron_tab as get_cron_tab_store, get_statuses as get_statuses_store,
+    delete_controllers, delete_notification_channel, get_cron_tab as get_cron_tab_store,
+    get_digest as get_digest_store, get_statuses as get_statuses_store,
+    list_notification_channels as list_notification_channels_store,
     set_controllers as set_controllers_store, set_cron_tab as set_cron_tab_store,
 };
-use crate::types::interface::{ListStatuses, ListStatusesArgs, SetCronTab};
-use crate::types::state::{Archive, ArchiveStatuses, CronTab, StableState, State};
+use crate::types::interface::{
+    ListStatuses, ListStatusesArgs, NotificationChannelNoSecret, SetCronTab, SetNotificationChannel,
+};
+use crate::types::state::{
+    Archive, ArchiveDigest, ArchiveStatuses, CronTab, NotificationChannelId, RuntimeState,
+    StableState, State,
+};
+use ic_cdk::api::management_canister::http_request::{HttpResponse, TransformArgs};
 use ic_cdk::storage::{stable_restore, stable_save};
 use ic_cdk::{caller, trap};
 use ic_cdk_macros::{export_candid, init, post_upgrade, pre_upgrade, query, update};
@@ -44,8 +61,13 @@ fn init() {
                 cron_tabs: HashMap::new(),
                 archive: Archive {
                     statuses: HashMap::new(),
+                    digests: None,
+                    issues: None,
                 },
+                notification_channels: None,
+                notification_key: None,
             },
+            runtime: RuntimeState::default(),
         };
     });
 }
@@ -59,7 +81,12 @@ fn pre_upgrade() {
 fn post_upgrade() {
     let (stable,): (StableState,) = stable_restore().unwrap();
 
-    STATE.with(|state| *state.borrow_mut() = State { stable });
+    STATE.with(|state| {
+        *state.borrow_mut() = State {
+            stable,
+            runtime: RuntimeState::default(),
+        }
+    });
 
     set_timer_interval(Duration::from_nanos(CRON_INTERVAL_NS), || {
         cron_jobs();
@@ -106,6 +133,53 @@ fn get_cron_tab() -> Option<CronTab> {
     get_cron_tab_store(&user)
 }
 
+// ---------------------------------------------------------
+// Notification channels
+// ---------------------------------------------------------
+
+#[update(guard = "caller_is_not_anonymous")]
+async fn set_notification_channel(
+    id: NotificationChannelId,
+    channel: SetNotificationChannel,
+) -> NotificationChannelNoSecret {
+    let user = caller();
+
+    set_notification_channel_store(&user, &id, &channel)
+        .await
+        .map(|channel| NotificationChannelNoSecret::from(&channel))
+        .unwrap_or_else(|e| trap(&e))
+}
+
+#[update(guard = "caller_is_not_anonymous")]
+fn del_notification_channel(id: NotificationChannelId) {
+    let user = caller();
+
+    delete_notification_channel(&user, &id).unwrap_or_else(|e| trap(&e));
+}
+
+#[query(guard = "caller_is_not_anonymous")]
+fn list_notification_channels() -> Vec<(NotificationChannelId, NotificationChannelNoSecret)> {
+    let user = caller();
+    list_notification_channels_store(&user)
+        .iter()
+        .map(|(id, channel)| (id.clone(), NotificationChannelNoSecret::from(channel)))
+        .collect()
+}
+
+#[update(guard = "caller_is_not_anonymous")]
+async fn test_notification_channel(id: NotificationChannelId) -> u16 {
+    let user = caller();
+
+    test_notification_channel_store(&user, &id)
+        .await
+        .unwrap_or_else(|e| trap(&e))
+}
+
+#[query]
+fn transform_notification_response(args: TransformArgs) -> HttpResponse {
+    transform_notification_response_store(args)
+}
+
 // ---------------------------------------------------------
 // Statuses
 // ---------------------------------------------------------
@@ -116,6 +190,16 @@ fn get_statuses() -> Option<ArchiveStatuses> {
     get_statuses_store(&user)
 }
 
+// ---------------------------------------------------------
+// Digests
+// ---------------------------------------------------------
+
+#[query(guard = "caller_is_not_anonymous")]
+fn get_digest() -> Option<ArchiveDigest> {
+    let user = caller();
+    get_digest_store(&user)
+}
+
 // ---------------------------------------------------------
 // Reports
 // ---------------------------------------------------------
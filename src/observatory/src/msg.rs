@@ -0,0 +1,16 @@
+pub const ERROR_NOTIFICATION_CHANNEL_NOT_FOUND: &str = "Notification channel not found.";
+pub const ERROR_NOTIFICATION_CHANNEL_INVALID_ID: &str =
+    "The ID of a notification channel must be between 1 and 64 characters.";
+pub const ERROR_TOO_MANY_NOTIFICATION_CHANNELS: &str = "Too many notification channels.";
+pub const ERROR_NOTIFICATION_CHANNEL_INVALID_URL: &str =
+    "The URL of a notification channel must use HTTPS.";
+pub const ERROR_NOTIFICATION_CHANNEL_EMPTY_TELEGRAM: &str =
+    "The bot token and the chat ID of a Telegram channel cannot be empty.";
+pub const ERROR_NOTIFICATION_OUTCALL_FAILED: &str = "The notification cannot be sent.";
+pub const ERROR_NOTIFICATION_CHANNEL_NO_CRON_TAB: &str =
+    "A cron tab must be set before the notification channels can be configured.";
+pub const ERROR_NOTIFICATION_TEST_TOO_MANY: &str =
+    "A notification channel can be tested once per minute.";
+pub const ERROR_SECRET_RANDOMNESS: &str =
+    "The randomness to encrypt the secret cannot be obtained.";
+pub const ERROR_SECRET_INVALID: &str = "The secret cannot be decrypted.";
@@ -0,0 +1,509 @@
+use crate::constants::{
+    CYCLES_MIN_THRESHOLD, DIGEST_DEFAULT_TOP_PAGES, NOTIFICATION_CHANNEL_ID_MAX_LENGTH,
+    NOTIFICATION_MAX_RESPONSE_BYTES, NOTIFICATION_REMINDER_INTERVAL_NS, NOTIFICATION_SUBNET_SIZE,
+    NOTIFICATION_TEST_INTERVAL_NS, NOTIFICATION_TRANSFORM_METHOD,
+};
+use crate::msg::{
+    ERROR_NOTIFICATION_CHANNEL_EMPTY_TELEGRAM, ERROR_NOTIFICATION_CHANNEL_INVALID_ID,
+    ERROR_NOTIFICATION_CHANNEL_INVALID_URL, ERROR_NOTIFICATION_CHANNEL_NOT_FOUND,
+    ERROR_NOTIFICATION_CHANNEL_NO_CRON_TAB, ERROR_NOTIFICATION_OUTCALL_FAILED,
+    ERROR_NOTIFICATION_TEST_TOO_MANY,
+};
+use crate::secrets::{decrypt_secret, encrypt_secret};
+use crate::store::{
+    delete_issues, get_cron_tab, get_issues, get_notification_channel, get_notification_tested_at,
+    list_notification_channels, set_issues,
+    set_notification_channel as set_notification_channel_store, set_notification_channel_failure,
+    set_notification_tested_at,
+};
+use crate::types::interface::{SetNotificationChannel, SetNotificationChannelKind};
+use crate::types::state::{
+    ArchiveDigest, ArchiveIssues, CronTab, NotificationChannel, NotificationChannelId,
+    NotificationChannelKind,
+};
+use ic_cdk::api::management_canister::http_request::{
+    http_request, CanisterHttpRequestArgument, HttpHeader, HttpMethod, HttpResponse, TransformArgs,
+    TransformContext,
+};
+use ic_cdk::api::time;
+use ic_cdk::spawn;
+use junobuild_shared::types::core::Blob;
+use junobuild_shared::types::cronjob::CronJobStatusesSegments;
+use junobuild_shared::types::interface::{CyclesFreezePrediction, UsageDigest};
+use junobuild_shared::types::state::{SegmentId, SegmentStatusResult, SegmentsStatuses, UserId};
+use serde_json::json;
+
+/// The channels notify the issues detected by the cron jobs, therefore only the users that have a cron tab - i.e. a
+/// mission control asserted by the console - can configure them.
+pub async fn set_notification_channel(
+    user: &UserId,
+    id: &NotificationChannelId,
+    channel: &SetNotificationChannel,
+) -> Result<NotificationChannel, String> {
+    assert_cron_tab(user)?;
+
+    if id.is_empty() || id.len() > NOTIFICATION_CHANNEL_ID_MAX_LENGTH {
+        return Err(ERROR_NOTIFICATION_CHANNEL_INVALID_ID.to_string());
+    }
+
+    assert_notification_channel_kind(&channel.kind)?;
+
+    let kind = match &channel.kind {
+        SetNotificationChannelKind::Webhook { url, headers } => {
+            let mut encrypted_headers: Vec<(String, Blob)> = Vec::new();
+
+            for (name, value) in headers {
+                encrypted_headers.push((name.clone(), encrypt_secret(value).await?));
+            }
+
+            NotificationChannelKind::Webhook {
+                url: url.clone(),
+                headers: encrypted_headers,
+            }
+        }
+        SetNotificationChannelKind::Telegram { bot_token, chat_id } => {
+            NotificationChannelKind::Telegram {
+                bot_token: encrypt_secret(bot_token).await?,
+                chat_id: chat_id.clone(),
+            }
+        }
+        SetNotificationChannelKind::Slack { url } => NotificationChannelKind::Slack {
+            url: encrypt_secret(url).await?,
+        },
+    };
+
+    set_notification_channel_store(user, id, &kind, channel)
+}
+
+fn assert_cron_tab(user: &UserId) -> Result<(), String> {
+    if get_cron_tab(user).is_none() {
+        return Err(ERROR_NOTIFICATION_CHANNEL_NO_CRON_TAB.to_string());
+    }
+
+    Ok(())
+}
+
+fn assert_notification_channel_kind(kind: &SetNotificationChannelKind) -> Result<(), String> {
+    match kind {
+        SetNotificationChannelKind::Webhook { url, .. }
+        | SetNotificationChannelKind::Slack { url } => {
+            if !url.starts_with("https://") {
+                return Err(ERROR_NOTIFICATION_CHANNEL_INVALID_URL.to_string());
+            }
+        }
+        SetNotificationChannelKind::Telegram { bot_token, chat_id } => {
+            if bot_token.trim().is_empty() || chat_id.trim().is_empty() {
+                return Err(ERROR_NOTIFICATION_CHANNEL_EMPTY_TELEGRAM.to_string());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Sends a test message through a channel of the user - enabled or not - and returns the HTTP status of the response.
+/// Each test costs an outcall, therefore a user can attempt a test once per interval.
+pub async fn test_notification_channel(
+    user: &UserId,
+    id: &NotificationChannelId,
+) -> Result<u16, String> {
+    assert_cron_tab(user)?;
+
+    let now = time();
+
+    if get_notification_tested_at(user)
+        .is_some_and(|tested_at| now.saturating_sub(tested_at) < NOTIFICATION_TEST_INTERVAL_NS)
+    {
+        return Err(ERROR_NOTIFICATION_TEST_TOO_MANY.to_string());
+    }
+
+    set_notification_tested_at(user, now);
+
+    let channel = get_notification_channel(user, id)
+        .ok_or(ERROR_NOTIFICATION_CHANNEL_NOT_FOUND.to_string())?;
+
+    let message = "This is a test notification from the Juno observatory.".to_string();
+
+    send(&channel.kind, "test", &message).await
+}
+
+/// Notifies the enabled channels of the user if the statuses that were collected report issues - i.e. the statuses
+/// cannot be collected, the cycles of a segment are below the threshold configured in the cron tab or the mission
+/// control predicts that a module will soon freeze.
+///
+/// The statuses are collected hourly. Issues that were already notified are notified again only once the reminder
+/// interval has elapsed, while new issues - or issues that recur after being resolved - are notified right away.
+pub fn notify_statuses(
+    user: &UserId,
+    cron_tab: &CronTab,
+    statuses: &Result<SegmentsStatuses, String>,
+    freeze_predictions: &[(SegmentId, CyclesFreezePrediction)],
+) {
+    let mut issues = statuses_issues(cron_tab, statuses);
+
+    issues.extend(freeze_predictions_issues(freeze_predictions));
+
+    if issues.is_empty() {
+        delete_issues(user);
+        return;
+    }
+
+    let now = time();
+
+    let notified = get_issues(user).is_some_and(|previous| {
+        previous.issues == issues
+            && now.saturating_sub(previous.timestamp) < NOTIFICATION_REMINDER_INTERVAL_NS
+    });
+
+    if notified {
+        return;
+    }
+
+    set_issues(
+        user,
+        &ArchiveIssues {
+            timestamp: now,
+            issues: issues.clone(),
+        },
+    );
+
+    let message = [
+        format!(
+            "Juno - {} issue(s) detected for the mission control {}:",
+            issues.len(),
+            cron_tab.mission_control_id.to_text()
+        ),
+        issues.join("\n"),
+    ]
+    .join("\n");
+
+    notify(user, "statuses", &message);
+}
+
+/// Notifies the enabled channels of the user with the usage digest of their mission control - or the reason it
+/// cannot be collected. The storage growth of the satellites is measured against the previous digest.
+pub fn notify_digest(
+    user: &UserId,
+    cron_tab: &CronTab,
+    digest: &Result<UsageDigest, String>,
+    previous: &Option<ArchiveDigest>,
+) {
+    let message = match digest {
+        Err(err) => format!(
+            "Juno - The usage digest of the mission control {} cannot be collected: {}",
+            cron_tab.mission_control_id.to_text(),
+            err
+        ),
+        Ok(digest) => digest_message(cron_tab, digest, previous),
+    };
+
+    notify(user, "digest", &message);
+}
+
+fn notify(user: &UserId, event: &'static str, message: &str) {
+    for (id, channel) in list_notification_channels(user) {
+        if !channel.enabled {
+            continue;
+        }
+
+        let message = message.to_string();
+
+        let user = *user;
+
+        spawn(async move {
+            if let Err(e) = send(&channel.kind, event, &message).await {
+                set_notification_channel_failure(&user, &id, &e, time());
+            }
+        });
+    }
+}
+
+fn statuses_issues(cron_tab: &CronTab, statuses: &Result<SegmentsStatuses, String>) -> Vec<String> {
+    let config = &cron_tab.cron_jobs.statuses;
+
+    let SegmentsStatuses {
+        mission_control,
+        satellites,
+        orbiters,
+    } = match statuses {
+        Err(err) => return vec![format!("- {}", err)],
+        Ok(statuses) => statuses,
+    };
+
+    let mut issues: Vec<String> = Vec::new();
+
+    match mission_control {
+        Err(err) => issues.push(format!("- Mission control: {}", err)),
+        Ok(_) => {
+            // The mission control does not collect the statuses of the satellites and orbiters when its own cycles are
+            // below the threshold.
+            if satellites.is_none() && orbiters.is_none() {
+                issues.push("- Mission control: cycles below the threshold.".to_string());
+            }
+        }
+    }
+
+    segments_issues(
+        "Satellite",
+        satellites,
+        &config.satellites,
+        config.cycles_threshold,
+        &mut issues,
+    );
+    segments_issues(
+        "Orbiter",
+        orbiters,
+        &config.orbiters,
+        config.cycles_threshold,
+        &mut issues,
+    );
+
+    issues
+}
+
+/// The predictions are sorted so that the issues - and therefore the reminders - remain the same as long as the
+/// alerts do. The days are left out for the same reason given that they decrease with each prediction.
+fn freeze_predictions_issues(
+    freeze_predictions: &[(SegmentId, CyclesFreezePrediction)],
+) -> Vec<String> {
+    let mut segment_ids: Vec<String> = freeze_predictions
+        .iter()
+        .filter(|(_, prediction)| prediction.alert)
+        .map(|(segment_id, _)| segment_id.to_text())
+        .collect();
+
+    segment_ids.sort();
+
+    segment_ids
+        .into_iter()
+        .map(|segment_id| format!("- Module {}: predicted to freeze soon.", segment_id))
+        .collect()
+}
+
+fn segments_issues(
+    label: &str,
+    statuses: &Option<Vec<SegmentStatusResult>>,
+    segments_config: &CronJobStatusesSegments,
+    cycles_threshold: Option<u64>,
+    issues: &mut Vec<String>,
+) {
+    for status in statuses.iter().flatten() {
+        match status {
+            Err(err) => issues.push(format!("- {}: {}", label, err)),
+            Ok(status) => {
+                let threshold = segments_config
+                    .get(&status.id)
+                    .and_then(|config| config.cycles_threshold)
+                    .or(cycles_threshold)
+                    .unwrap_or(CYCLES_MIN_THRESHOLD)
+                    .max(CYCLES_MIN_THRESHOLD);
+
+                if status.status.cycles < threshold {
+                    issues.push(format!(
+                        "- {} {}: cycles below the threshold.",
+                        label,
+                        status.id.to_text()
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn digest_message(
+    cron_tab: &CronTab,
+    digest: &UsageDigest,
+    previous: &Option<ArchiveDigest>,
+) -> String {
+    let top_pages = cron_tab
+        .cron_jobs
+        .digest
+        .as_ref()
+        .and_then(|config| config.top_pages)
+        .unwrap_or(DIGEST_DEFAULT_TOP_PAGES) as usize;
+
+    let mut lines: Vec<String> = vec![
+        format!(
+            "Juno - Weekly usage of the mission control {}:",
+            cron_tab.mission_control_id.to_text()
+        ),
+        format!(
+            "- Mission control: {} burned.",
+            format_cycles(&digest.mission_control_cycles_burned)
+        ),
+    ];
+
+    let mut satellites: Vec<_> = digest.satellites.iter().collect();
+    satellites.sort_by_key(|(satellite_id, _)| satellite_id.to_text());
+
+    for (satellite_id, usage) in satellites {
+        let memory_size = usage
+            .memory_size
+            .as_ref()
+            .map(|memory_size| (memory_size.heap + memory_size.stable) as u64);
+
+        let previous_memory_size = previous
+            .as_ref()
+            .and_then(|previous| previous.memory_sizes.get(satellite_id));
+
+        let storage = match (memory_size, previous_memory_size) {
+            (None, _) => "storage unavailable".to_string(),
+            (Some(memory_size), None) => format!("{} of storage", format_bytes(memory_size)),
+            (Some(memory_size), Some(previous_memory_size)) => format!(
+                "{} of storage ({}{} since the last digest)",
+                format_bytes(memory_size),
+                if memory_size >= *previous_memory_size {
+                    "+"
+                } else {
+                    "-"
+                },
+                format_bytes(memory_size.abs_diff(*previous_memory_size))
+            ),
+        };
+
+        lines.push(format!(
+            "- Satellite {}: {}, {} burned.",
+            satellite_id.to_text(),
+            storage,
+            format_cycles(&usage.cycles_burned)
+        ));
+    }
+
+    let mut orbiters: Vec<_> = digest.orbiters.iter().collect();
+    orbiters.sort_by_key(|(orbiter_id, _)| orbiter_id.to_text());
+
+    for (orbiter_id, usage) in orbiters {
+        let page_views = match (usage.page_views, usage.unique_sessions) {
+            (Some(page_views), Some(unique_sessions)) => {
+                format!("{} page views in {} sessions", page_views, unique_sessions)
+            }
+            _ => "page views unavailable".to_string(),
+        };
+
+        lines.push(format!(
+            "- Orbiter {}: {}, {} burned.",
+            orbiter_id.to_text(),
+            page_views,
+            format_cycles(&usage.cycles_burned)
+        ));
+
+        if top_pages > 0 && !usage.top_pages.is_empty() {
+            let pages: Vec<String> = usage
+                .top_pages
+                .iter()
+                .take(top_pages)
+                .map(|(page, views)| format!("{} ({})", page, views))
+                .collect();
+
+            lines.push(format!("  Top pages: {}", pages.join(", ")));
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..1_000 => format!("{} B", bytes),
+        1_000..1_000_000 => format!("{:.1} KB", bytes as f64 / 1_000.0),
+        1_000_000..1_000_000_000 => format!("{:.1} MB", bytes as f64 / 1_000_000.0),
+        _ => format!("{:.2} GB", bytes as f64 / 1_000_000_000.0),
+    }
+}
+
+/// The cycles are expressed in trillions (T). They are unknown when the module is not monitored.
+fn format_cycles(cycles: &Option<u128>) -> String {
+    match cycles {
+        None => "unknown cycles".to_string(),
+        Some(cycles) => format!("{:.3} T cycles", *cycles as f64 / 1_000_000_000_000.0),
+    }
+}
+
+/// Each node of the subnet performs the outcall, therefore the endpoints may receive the same notification more than
+/// once.
+async fn send(kind: &NotificationChannelKind, event: &str, message: &str) -> Result<u16, String> {
+    let (url, mut headers, body) = match kind {
+        NotificationChannelKind::Webhook { url, headers } => (
+            url.clone(),
+            headers
+                .iter()
+                .map(|(name, value)| Ok(header(name, &decrypt_secret(value)?)))
+                .collect::<Result<Vec<HttpHeader>, String>>()?,
+            json!({
+                "event": event,
+                "message": message,
+                "timestamp": time(),
+            }),
+        ),
+        NotificationChannelKind::Telegram { bot_token, chat_id } => (
+            format!(
+                "https://api.telegram.org/bot{}/sendMessage",
+                decrypt_secret(bot_token)?
+            ),
+            vec![],
+            json!({
+                "chat_id": chat_id,
+                "text": message,
+            }),
+        ),
+        NotificationChannelKind::Slack { url } => {
+            (decrypt_secret(url)?, vec![], json!({ "text": message }))
+        }
+    };
+
+    headers.push(header("Content-Type", "application/json"));
+
+    let request = CanisterHttpRequestArgument {
+        url,
+        max_response_bytes: Some(NOTIFICATION_MAX_RESPONSE_BYTES),
+        method: HttpMethod::POST,
+        headers,
+        body: Some(body.to_string().into_bytes()),
+        transform: Some(TransformContext::from_name(
+            NOTIFICATION_TRANSFORM_METHOD.to_string(),
+            vec![],
+        )),
+    };
+
+    let cycles = outcall_cycles(&request);
+
+    let (response,) = http_request(request, cycles)
+        .await
+        .map_err(|(_, message)| format!("{} ({})", ERROR_NOTIFICATION_OUTCALL_FAILED, message))?;
+
+    Ok(u16::try_from(&response.status.0).unwrap_or_default())
+}
+
+pub fn transform_notification_response(
+    TransformArgs { response, .. }: TransformArgs,
+) -> HttpResponse {
+    HttpResponse {
+        status: response.status,
+        headers: vec![],
+        body: vec![],
+    }
+}
+
+fn header(name: &str, value: &str) -> HttpHeader {
+    HttpHeader {
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+/// The cost of an HTTP outcall according to the size of its request and the maximal size of its response.
+fn outcall_cycles(request: &CanisterHttpRequestArgument) -> u128 {
+    let request_bytes = request.url.len()
+        + request
+            .headers
+            .iter()
+            .map(|HttpHeader { name, value }| name.len() + value.len())
+            .sum::<usize>()
+        + request.body.as_ref().map_or(0, |body| body.len());
+
+    let n = NOTIFICATION_SUBNET_SIZE;
+
+    (3_000_000 + 60_000 * n) * n
+        + 400 * n * request_bytes as u128
+        + 800 * n * NOTIFICATION_MAX_RESPONSE_BYTES as u128
+}
@@ -0,0 +1,75 @@
+use crate::constants::{SECRET_ENVELOPE_VERSION, SECRET_KEY_LENGTH, SECRET_NONCE_LENGTH};
+use crate::msg::{ERROR_SECRET_INVALID, ERROR_SECRET_RANDOMNESS};
+use crate::store::{get_notification_key, init_notification_key};
+use candid::Principal;
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use ic_cdk::api::call::CallResult;
+use ic_cdk::call;
+use junobuild_shared::types::core::Blob;
+
+/// Encrypts a secret of a notification channel with XChaCha20-Poly1305. The key is generated with the first secret and
+/// each secret is sealed with a random nonce.
+pub async fn encrypt_secret(secret: &str) -> Result<Blob, String> {
+    let key = match get_notification_key() {
+        Some(key) => key,
+        None => init_notification_key(&raw_rand().await?.to_vec()),
+    };
+
+    let seed = raw_rand().await?;
+    let nonce = &seed[..SECRET_NONCE_LENGTH];
+
+    let header = [SECRET_ENVELOPE_VERSION];
+
+    let ciphertext = cipher(&key)?
+        .encrypt(
+            XNonce::from_slice(nonce),
+            Payload {
+                msg: secret.as_bytes(),
+                aad: &header,
+            },
+        )
+        .map_err(|_| ERROR_SECRET_INVALID.to_string())?;
+
+    Ok([&header[..], nonce, &ciphertext].concat())
+}
+
+pub fn decrypt_secret(envelope: &[u8]) -> Result<String, String> {
+    let key = get_notification_key().ok_or(ERROR_SECRET_INVALID.to_string())?;
+
+    if envelope.len() <= 1 + SECRET_NONCE_LENGTH || envelope[0] != SECRET_ENVELOPE_VERSION {
+        return Err(ERROR_SECRET_INVALID.to_string());
+    }
+
+    let (header, content) = envelope.split_at(1);
+    let (nonce, ciphertext) = content.split_at(SECRET_NONCE_LENGTH);
+
+    let secret = cipher(&key)?
+        .decrypt(
+            XNonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: header,
+            },
+        )
+        .map_err(|_| ERROR_SECRET_INVALID.to_string())?;
+
+    String::from_utf8(secret).map_err(|_| ERROR_SECRET_INVALID.to_string())
+}
+
+fn cipher(key: &[u8]) -> Result<XChaCha20Poly1305, String> {
+    if key.len() != SECRET_KEY_LENGTH {
+        return Err(ERROR_SECRET_INVALID.to_string());
+    }
+
+    Ok(XChaCha20Poly1305::new(key.into()))
+}
+
+async fn raw_rand() -> Result<[u8; 32], String> {
+    let result: CallResult<([u8; 32],)> =
+        call(Principal::management_canister(), "raw_rand", ()).await;
+
+    result
+        .map(|(seed,)| seed)
+        .map_err(|(_, message)| format!("{} ({})", ERROR_SECRET_RANDOMNESS, message))
+}
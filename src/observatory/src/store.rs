@@ -1,5 +1,10 @@
-use crate::types::interface::SetCronTab;
-use crate::types::state::{ArchiveStatuses, CronTab, CronTabs, StableState};
+use crate::constants::NOTIFICATION_CHANNELS_MAX_LENGTH;
+use crate::msg::{ERROR_NOTIFICATION_CHANNEL_NOT_FOUND, ERROR_TOO_MANY_NOTIFICATION_CHANNELS};
+use crate::types::interface::{SetCronTab, SetNotificationChannel};
+use crate::types::state::{
+    ArchiveDigest, ArchiveIssues, ArchiveStatuses, CronTab, CronTabs, NotificationChannel,
+    NotificationChannelId, NotificationChannelKind, RuntimeState, StableState,
+};
 use crate::STATE;
 use ic_cdk::api::time;
 use junobuild_shared::assert::assert_version;
@@ -7,8 +12,10 @@ use junobuild_shared::constants::INITIAL_VERSION;
 use junobuild_shared::controllers::{
     delete_controllers as delete_controllers_impl, set_controllers as set_controllers_impl,
 };
+use junobuild_shared::types::core::Blob;
 use junobuild_shared::types::interface::SetController;
 use junobuild_shared::types::state::{ControllerId, SegmentsStatuses, Timestamp, UserId, Version};
+use std::collections::HashMap;
 
 // ---------------------------------------------------------
 // CronJobs
@@ -137,3 +144,282 @@ fn get_statuses_impl(user: &UserId, state: &StableState) -> Option<ArchiveStatus
     let statuses = state.archive.statuses.get(user);
     statuses.cloned()
 }
+
+// ---------------------------------------------------------
+// Digests
+// ---------------------------------------------------------
+
+pub fn get_digest(user: &UserId) -> Option<ArchiveDigest> {
+    STATE.with(|state| get_digest_impl(user, &state.borrow().stable))
+}
+
+fn get_digest_impl(user: &UserId, state: &StableState) -> Option<ArchiveDigest> {
+    state
+        .archive
+        .digests
+        .as_ref()
+        .and_then(|digests| digests.get(user))
+        .cloned()
+}
+
+pub fn set_digest(user: &UserId, digest: &ArchiveDigest) {
+    STATE.with(|state| set_digest_impl(user, digest, &mut state.borrow_mut().stable))
+}
+
+fn set_digest_impl(user: &UserId, digest: &ArchiveDigest, state: &mut StableState) {
+    state
+        .archive
+        .digests
+        .get_or_insert_with(HashMap::new)
+        .insert(*user, digest.clone());
+}
+
+// ---------------------------------------------------------
+// Notification channels
+// ---------------------------------------------------------
+
+pub fn get_notification_channel(
+    user: &UserId,
+    id: &NotificationChannelId,
+) -> Option<NotificationChannel> {
+    STATE.with(|state| get_notification_channel_impl(user, id, &state.borrow().stable))
+}
+
+fn get_notification_channel_impl(
+    user: &UserId,
+    id: &NotificationChannelId,
+    state: &StableState,
+) -> Option<NotificationChannel> {
+    state
+        .notification_channels
+        .as_ref()
+        .and_then(|channels| channels.get(user))
+        .and_then(|channels| channels.get(id))
+        .cloned()
+}
+
+pub fn list_notification_channels(
+    user: &UserId,
+) -> Vec<(NotificationChannelId, NotificationChannel)> {
+    STATE.with(|state| list_notification_channels_impl(user, &state.borrow().stable))
+}
+
+fn list_notification_channels_impl(
+    user: &UserId,
+    state: &StableState,
+) -> Vec<(NotificationChannelId, NotificationChannel)> {
+    state
+        .notification_channels
+        .as_ref()
+        .and_then(|channels| channels.get(user))
+        .map(|channels| channels.clone().into_iter().collect())
+        .unwrap_or_default()
+}
+
+pub fn set_notification_channel(
+    user: &UserId,
+    id: &NotificationChannelId,
+    kind: &NotificationChannelKind,
+    channel: &SetNotificationChannel,
+) -> Result<NotificationChannel, String> {
+    STATE.with(|state| {
+        set_notification_channel_impl(user, id, kind, channel, &mut state.borrow_mut().stable)
+    })
+}
+
+fn set_notification_channel_impl(
+    user: &UserId,
+    id: &NotificationChannelId,
+    kind: &NotificationChannelKind,
+    channel: &SetNotificationChannel,
+    state: &mut StableState,
+) -> Result<NotificationChannel, String> {
+    let channels = state
+        .notification_channels
+        .get_or_insert_with(HashMap::new)
+        .entry(*user)
+        .or_default();
+
+    let current_channel = channels.get(id);
+
+    match current_channel {
+        None => {
+            if channels.len() >= NOTIFICATION_CHANNELS_MAX_LENGTH {
+                return Err(format!(
+                    "{} Maximum {}.",
+                    ERROR_TOO_MANY_NOTIFICATION_CHANNELS, NOTIFICATION_CHANNELS_MAX_LENGTH
+                ));
+            }
+        }
+        Some(current_channel) => assert_version(channel.version, current_channel.version)?,
+    }
+
+    let now = time();
+
+    let created_at: Timestamp = match current_channel {
+        None => now,
+        Some(current_channel) => current_channel.created_at,
+    };
+
+    let version: Version = match current_channel {
+        None => INITIAL_VERSION,
+        Some(current_channel) => current_channel.version.unwrap_or_default() + 1,
+    };
+
+    let new_channel = NotificationChannel {
+        kind: kind.clone(),
+        enabled: channel.enabled,
+        created_at,
+        updated_at: now,
+        version: Some(version),
+        last_error: None,
+        last_failed_at: None,
+    };
+
+    channels.insert(id.clone(), new_channel.clone());
+
+    Ok(new_channel)
+}
+
+pub fn delete_notification_channel(
+    user: &UserId,
+    id: &NotificationChannelId,
+) -> Result<(), String> {
+    STATE.with(|state| delete_notification_channel_impl(user, id, &mut state.borrow_mut().stable))
+}
+
+fn delete_notification_channel_impl(
+    user: &UserId,
+    id: &NotificationChannelId,
+    state: &mut StableState,
+) -> Result<(), String> {
+    let channels = state
+        .notification_channels
+        .as_mut()
+        .and_then(|channels| channels.get_mut(user))
+        .ok_or(ERROR_NOTIFICATION_CHANNEL_NOT_FOUND.to_string())?;
+
+    channels
+        .remove(id)
+        .ok_or(ERROR_NOTIFICATION_CHANNEL_NOT_FOUND.to_string())?;
+
+    if channels.is_empty() {
+        if let Some(channels) = state.notification_channels.as_mut() {
+            channels.remove(user);
+        }
+    }
+
+    Ok(())
+}
+
+/// Records why a notification could not be sent through a channel, unless the channel was deleted in the meantime. The
+/// version is left unchanged given that the channel is not modified by its user.
+pub fn set_notification_channel_failure(
+    user: &UserId,
+    id: &NotificationChannelId,
+    error: &str,
+    failed_at: Timestamp,
+) {
+    STATE.with(|state| {
+        set_notification_channel_failure_impl(
+            user,
+            id,
+            error,
+            failed_at,
+            &mut state.borrow_mut().stable,
+        )
+    })
+}
+
+fn set_notification_channel_failure_impl(
+    user: &UserId,
+    id: &NotificationChannelId,
+    error: &str,
+    failed_at: Timestamp,
+    state: &mut StableState,
+) {
+    let channel = state
+        .notification_channels
+        .as_mut()
+        .and_then(|channels| channels.get_mut(user))
+        .and_then(|channels| channels.get_mut(id));
+
+    if let Some(channel) = channel {
+        channel.last_error = Some(error.to_string());
+        channel.last_failed_at = Some(failed_at);
+    }
+}
+
+pub fn get_notification_key() -> Option<Blob> {
+    STATE.with(|state| state.borrow().stable.notification_key.clone())
+}
+
+/// Sets the key unless another call generated one in the meantime - i.e. the key is never replaced, otherwise the
+/// secrets already encrypted could no longer be decrypted.
+pub fn init_notification_key(key: &Blob) -> Blob {
+    STATE.with(|state| {
+        state
+            .borrow_mut()
+            .stable
+            .notification_key
+            .get_or_insert_with(|| key.clone())
+            .clone()
+    })
+}
+
+// ---------------------------------------------------------
+// Notification tests
+// ---------------------------------------------------------
+
+pub fn get_notification_tested_at(user: &UserId) -> Option<Timestamp> {
+    STATE.with(|state| state.borrow().runtime.notification_tests.get(user).copied())
+}
+
+pub fn set_notification_tested_at(user: &UserId, tested_at: Timestamp) {
+    STATE.with(|state| {
+        set_notification_tested_at_impl(user, tested_at, &mut state.borrow_mut().runtime)
+    })
+}
+
+fn set_notification_tested_at_impl(user: &UserId, tested_at: Timestamp, state: &mut RuntimeState) {
+    state.notification_tests.insert(*user, tested_at);
+}
+
+// ---------------------------------------------------------
+// Notified issues
+// ---------------------------------------------------------
+
+pub fn get_issues(user: &UserId) -> Option<ArchiveIssues> {
+    STATE.with(|state| get_issues_impl(user, &state.borrow().stable))
+}
+
+fn get_issues_impl(user: &UserId, state: &StableState) -> Option<ArchiveIssues> {
+    state
+        .archive
+        .issues
+        .as_ref()
+        .and_then(|issues| issues.get(user))
+        .cloned()
+}
+
+pub fn set_issues(user: &UserId, issues: &ArchiveIssues) {
+    STATE.with(|state| set_issues_impl(user, issues, &mut state.borrow_mut().stable))
+}
+
+fn set_issues_impl(user: &UserId, issues: &ArchiveIssues, state: &mut StableState) {
+    state
+        .archive
+        .issues
+        .get_or_insert_with(HashMap::new)
+        .insert(*user, issues.clone());
+}
+
+pub fn delete_issues(user: &UserId) {
+    STATE.with(|state| delete_issues_impl(user, &mut state.borrow_mut().stable))
+}
+
+fn delete_issues_impl(user: &UserId, state: &mut StableState) {
+    if let Some(issues) = state.archive.issues.as_mut() {
+        issues.remove(user);
+    }
+}
@@ -1,16 +1,22 @@
 pub mod state {
     use candid::{CandidType, Deserialize};
+    use junobuild_shared::types::core::Blob;
     use junobuild_shared::types::cronjob::CronJobs;
     use junobuild_shared::types::state::{
-        ArchiveTime, Controllers, MissionControlId, SegmentsStatuses, Timestamp, UserId, Version,
+        ArchiveTime, Controllers, MissionControlId, SatelliteId, SegmentsStatuses, Timestamp,
+        UserId, Version,
     };
     use std::collections::HashMap;
 
     pub type CronTabs = HashMap<UserId, CronTab>;
 
+    pub type NotificationChannelId = String;
+    pub type NotificationChannels = HashMap<NotificationChannelId, NotificationChannel>;
+
     #[derive(Default, Clone)]
     pub struct State {
         pub stable: StableState,
+        pub runtime: RuntimeState,
     }
 
     #[derive(Default, CandidType, Deserialize, Clone)]
@@ -18,6 +24,15 @@ pub mod state {
         pub controllers: Controllers,
         pub cron_tabs: CronTabs,
         pub archive: Archive,
+        pub notification_channels: Option<HashMap<UserId, NotificationChannels>>,
+        /// The key used to encrypt the secrets of the notification channels at rest. Generated with the first secret.
+        pub notification_key: Option<Blob>,
+    }
+
+    #[derive(Default, Clone)]
+    pub struct RuntimeState {
+        /// When each user last tested a notification channel.
+        pub notification_tests: HashMap<UserId, Timestamp>,
     }
 
     #[derive(CandidType, Deserialize, Clone)]
@@ -29,9 +44,53 @@ pub mod state {
         pub version: Option<Version>,
     }
 
+    /// A channel through which a user is notified when the collected statuses of their segments report issues.
+    ///
+    /// - `last_error` and `last_failed_at`: The reason and the time of the last notification that could not be sent.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct NotificationChannel {
+        pub kind: NotificationChannelKind,
+        pub enabled: bool,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+        pub version: Option<Version>,
+        pub last_error: Option<String>,
+        pub last_failed_at: Option<Timestamp>,
+    }
+
+    /// The generic webhook receives a JSON payload, Telegram a message sent by a bot to a chat and Slack - or
+    /// any service accepting Slack-compatible incoming webhooks - a text message.
+    ///
+    /// The secrets - the values of the headers of the webhook, the bot token of Telegram and the URL of Slack, which
+    /// embeds its token - are encrypted at rest - i.e. they are never stored or returned in plain text.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub enum NotificationChannelKind {
+        Webhook {
+            url: String,
+            headers: Vec<(String, Blob)>,
+        },
+        Telegram {
+            bot_token: Blob,
+            chat_id: String,
+        },
+        Slack {
+            url: Blob,
+        },
+    }
+
     #[derive(Default, CandidType, Deserialize, Clone)]
     pub struct Archive {
         pub statuses: HashMap<UserId, ArchiveStatuses>,
+        pub digests: Option<HashMap<UserId, ArchiveDigest>>,
+        pub issues: Option<HashMap<UserId, ArchiveIssues>>,
+    }
+
+    /// The last issues of the statuses notified to a user. The same issues are not notified again by the next cron
+    /// jobs, unless they persist longer than the reminder interval.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct ArchiveIssues {
+        pub timestamp: ArchiveTime,
+        pub issues: Vec<String>,
     }
 
     #[derive(CandidType, Deserialize, Clone)]
@@ -39,13 +98,21 @@ pub mod state {
         pub timestamp: ArchiveTime,
         pub statuses: Result<SegmentsStatuses, String>,
     }
+
+    /// The last usage digest sent to a user. The memory used by the satellites - heap and stable - is the baseline of
+    /// the storage growth reported by the next digest.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct ArchiveDigest {
+        pub timestamp: ArchiveTime,
+        pub memory_sizes: HashMap<SatelliteId, u64>,
+    }
 }
 
 pub mod interface {
     use candid::{CandidType, Deserialize};
     use junobuild_shared::types::cronjob::CronJobs;
     use junobuild_shared::types::state::{
-        ArchiveTime, MissionControlId, SegmentsStatuses, Version,
+        ArchiveTime, MissionControlId, SegmentsStatuses, Timestamp, Version,
     };
 
     #[derive(Default, CandidType, Deserialize, Clone)]
@@ -66,4 +133,47 @@ pub mod interface {
         pub cron_jobs: CronJobs,
         pub version: Option<Version>,
     }
+
+    #[derive(CandidType, Deserialize)]
+    pub struct SetNotificationChannel {
+        pub kind: SetNotificationChannelKind,
+        pub enabled: bool,
+        pub version: Option<Version>,
+    }
+
+    /// The kind of a channel as provided by the user. Its secrets are encrypted before being stored.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub enum SetNotificationChannelKind {
+        Webhook {
+            url: String,
+            headers: Vec<(String, String)>,
+        },
+        Telegram {
+            bot_token: String,
+            chat_id: String,
+        },
+        Slack {
+            url: String,
+        },
+    }
+
+    /// A channel as listed - i.e. without its secrets.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct NotificationChannelNoSecret {
+        pub kind: NotificationChannelKindNoSecret,
+        pub enabled: bool,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+        pub version: Option<Version>,
+        pub last_error: Option<String>,
+        pub last_failed_at: Option<Timestamp>,
+    }
+
+    /// The kind of a channel without its secrets. Only the names of the headers of the webhook are provided.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub enum NotificationChannelKindNoSecret {
+        Webhook { url: String, headers: Vec<String> },
+        Telegram { chat_id: String },
+        Slack,
+    }
 }
@@ -1,11 +1,12 @@
 use crate::types::interface::{
     AnalyticsBrowsersPageViews, AnalyticsClientsPageViews, AnalyticsDevicesPageViews,
-    AnalyticsMetricsPageViews, AnalyticsTop10PageViews, AnalyticsTrackEvents,
-    AnalyticsWebVitalsPageMetrics, AnalyticsWebVitalsPerformanceMetrics,
+    AnalyticsErrorReports, AnalyticsErrorReportsGroup, AnalyticsMetricsPageViews,
+    AnalyticsTop10PageViews, AnalyticsTrackEvents, AnalyticsWebVitalsPageMetrics,
+    AnalyticsWebVitalsPerformanceMetrics,
 };
 use crate::types::state::{
-    AnalyticKey, PageView, PerformanceData, PerformanceMetric, PerformanceMetricName, TrackEvent,
-    WebVitalsMetric,
+    AnalyticKey, ErrorReport, PageView, PerformanceData, PerformanceMetric, PerformanceMetricName,
+    TrackEvent, WebVitalsMetric,
 };
 use junobuild_shared::day::calendar_date;
 use junobuild_shared::types::utils::CalendarDate;
@@ -223,6 +224,64 @@ pub fn analytics_track_events(
     }
 }
 
+// The occurrences are grouped by stack hash. The message of a group is the one of its most recent occurrence.
+pub fn analytics_error_reports(
+    error_reports: &Vec<(AnalyticKey, ErrorReport)>,
+) -> AnalyticsErrorReports {
+    let mut groups: HashMap<String, (AnalyticsErrorReportsGroup, HashSet<String>)> = HashMap::new();
+
+    for (
+        AnalyticKey {
+            collected_at,
+            key: _,
+        },
+        ErrorReport {
+            stack_hash,
+            message,
+            session_id,
+            ..
+        },
+    ) in error_reports
+    {
+        let (group, sessions) = groups.entry(stack_hash.clone()).or_insert_with(|| {
+            (
+                AnalyticsErrorReportsGroup {
+                    stack_hash: stack_hash.clone(),
+                    message: message.clone(),
+                    total: 0,
+                    daily_total: HashMap::new(),
+                    unique_sessions: 0,
+                    last_seen_at: *collected_at,
+                },
+                HashSet::new(),
+            )
+        });
+
+        group.total += 1;
+
+        let count = group
+            .daily_total
+            .entry(calendar_date(collected_at))
+            .or_insert(0);
+        *count += 1;
+
+        if *collected_at >= group.last_seen_at {
+            group.last_seen_at = *collected_at;
+            group.message = message.clone();
+        }
+
+        sessions.insert(session_id.clone());
+        group.unique_sessions = sessions.len();
+    }
+
+    let mut groups: Vec<AnalyticsErrorReportsGroup> =
+        groups.into_values().map(|(group, _)| group).collect();
+
+    groups.sort_by(|a, b| b.total.cmp(&a.total).then(a.stack_hash.cmp(&b.stack_hash)));
+
+    AnalyticsErrorReports { groups }
+}
+
 #[derive(Default)]
 struct PerformanceMetricAccumulator {
     sum: f64,
@@ -0,0 +1,118 @@
+use crate::types::interface::{SetErrorReport, SetPageView, SetPerformanceMetric, SetTrackEvent};
+use crate::types::state::{AnalyticKey, SatelliteConfig, SessionId};
+use ic_cdk::api::time;
+use junobuild_shared::types::state::{SatelliteId, Timestamp};
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use std::sync::LazyLock;
+
+const HOUR_NS: u64 = 3_600_000_000_000;
+const DAY_NS: u64 = 24 * HOUR_NS;
+
+static VERSIONS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"/?\d[\w.]*").unwrap());
+
+pub fn is_anonymized(config: &Option<SatelliteConfig>) -> bool {
+    config.as_ref().and_then(|c| c.anonymize).unwrap_or(false)
+}
+
+/// The time of the ingestion, truncated to the hour when the satellite is anonymized.
+pub fn ingestion_time(anonymized: bool) -> Timestamp {
+    let now = time();
+
+    if anonymized {
+        return truncate_timestamp(now);
+    }
+
+    now
+}
+
+pub fn anonymize_page_view(
+    key: AnalyticKey,
+    mut page_view: SetPageView,
+) -> (AnalyticKey, SetPageView) {
+    page_view.user_agent = anonymize_user_agent(&page_view.user_agent);
+    page_view.session_id =
+        anonymize_session_id(&page_view.session_id, &page_view.satellite_id, &key);
+
+    (anonymize_key(key), page_view)
+}
+
+pub fn anonymize_track_event(
+    key: AnalyticKey,
+    mut track_event: SetTrackEvent,
+) -> (AnalyticKey, SetTrackEvent) {
+    track_event.user_agent = anonymize_user_agent(&track_event.user_agent);
+    track_event.session_id =
+        anonymize_session_id(&track_event.session_id, &track_event.satellite_id, &key);
+
+    (anonymize_key(key), track_event)
+}
+
+pub fn anonymize_performance_metric(
+    key: AnalyticKey,
+    mut performance_metric: SetPerformanceMetric,
+) -> (AnalyticKey, SetPerformanceMetric) {
+    performance_metric.user_agent = anonymize_user_agent(&performance_metric.user_agent);
+    performance_metric.session_id = anonymize_session_id(
+        &performance_metric.session_id,
+        &performance_metric.satellite_id,
+        &key,
+    );
+
+    (anonymize_key(key), performance_metric)
+}
+
+pub fn anonymize_error_report(
+    key: AnalyticKey,
+    mut error_report: SetErrorReport,
+) -> (AnalyticKey, SetErrorReport) {
+    error_report.user_agent = anonymize_user_agent(&error_report.user_agent);
+    error_report.session_id =
+        anonymize_session_id(&error_report.session_id, &error_report.satellite_id, &key);
+
+    (anonymize_key(key), error_report)
+}
+
+fn anonymize_key(AnalyticKey { collected_at, key }: AnalyticKey) -> AnalyticKey {
+    AnalyticKey {
+        collected_at: truncate_timestamp(collected_at),
+        key,
+    }
+}
+
+fn truncate_timestamp(timestamp: Timestamp) -> Timestamp {
+    timestamp - timestamp % HOUR_NS
+}
+
+// The session identifier is replaced with a hash that changes every day. That way the analytics of a same day can
+// still be grouped per session without being able to follow a visitor over a longer period.
+// The day is derived from the collection time of the analytic so that an update of an entry keeps its session.
+fn anonymize_session_id(
+    session_id: &SessionId,
+    satellite_id: &SatelliteId,
+    key: &AnalyticKey,
+) -> SessionId {
+    let mut hasher = Sha256::new();
+    hasher.update(satellite_id.as_slice());
+    hasher.update(session_id.as_bytes());
+    hasher.update((key.collected_at / DAY_NS).to_be_bytes());
+
+    let hash = hasher.finalize();
+
+    format!(
+        "{:016x}",
+        u64::from_be_bytes(hash[..8].try_into().unwrap_or_default())
+    )
+}
+
+// The versions are removed from the user agent - e.g. "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X)" becomes
+// "Mozilla (iPhone; CPU iPhone OS like Mac OS X)". The browsers and devices remain recognizable for the analytics.
+fn anonymize_user_agent(user_agent: &Option<String>) -> Option<String> {
+    user_agent.as_ref().map(|user_agent| {
+        VERSIONS
+            .replace_all(user_agent, "")
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+    })
+}
@@ -1,8 +1,10 @@
 use crate::msg::{
-    ERROR_PAGE_VIEWS_FEATURE_DISABLED, ERROR_PERFORMANCE_METRICS_FEATURE_DISABLED,
+    ERROR_ERROR_REPORTS_FEATURE_DISABLED, ERROR_PAGE_VIEWS_FEATURE_DISABLED,
+    ERROR_PERFORMANCE_METRICS_FEATURE_DISABLED, ERROR_SAMPLING_EXCEEDED,
     ERROR_TRACK_EVENTS_FEATURE_DISABLED,
 };
 use crate::types::state::SatelliteConfig;
+use junobuild_shared::types::state::OrbiterSatelliteSampling;
 
 fn assert_feature_enabled<F>(
     config: &Option<SatelliteConfig>,
@@ -42,3 +44,34 @@ pub fn assert_performance_metrics_enabled(config: &Option<SatelliteConfig>) -> R
         ERROR_PERFORMANCE_METRICS_FEATURE_DISABLED,
     )
 }
+
+pub fn assert_error_reports_enabled(config: &Option<SatelliteConfig>) -> Result<(), String> {
+    assert_feature_enabled(
+        config,
+        |c| {
+            c.features
+                .as_ref()
+                .map_or(false, |f| f.error_reports.unwrap_or(false))
+        },
+        ERROR_ERROR_REPORTS_FEATURE_DISABLED,
+    )
+}
+
+pub fn assert_sampling(sampling: &Option<OrbiterSatelliteSampling>) -> Result<(), String> {
+    if let Some(OrbiterSatelliteSampling {
+        page_views,
+        track_events,
+        performance_metrics,
+    }) = sampling
+    {
+        let exceeded = [page_views, track_events, performance_metrics]
+            .iter()
+            .any(|rate| rate.map_or(false, |rate| rate > 100));
+
+        if exceeded {
+            return Err(ERROR_SAMPLING_EXCEEDED.to_string());
+        }
+    }
+
+    Ok(())
+}
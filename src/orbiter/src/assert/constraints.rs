@@ -3,7 +3,7 @@ use crate::constants::{
     STRING_MAX_LENGTH,
 };
 use crate::msg::ERROR_BOT_CALL;
-use crate::types::interface::{SetPageView, SetTrackEvent};
+use crate::types::interface::{SetErrorReport, SetPageView, SetTrackEvent};
 use crate::types::state::AnalyticKey;
 use isbot::Bots;
 use junobuild_shared::types::state::SatelliteId;
@@ -112,6 +112,45 @@ pub fn assert_page_view_length(page_view: &SetPageView) -> Result<(), String> {
     Ok(())
 }
 
+pub fn assert_error_report_length(error_report: &SetErrorReport) -> Result<(), String> {
+    assert_session_id_length(&error_report.session_id)?;
+
+    if error_report.message.len() > LONG_STRING_MAX_LENGTH {
+        return Err(format!(
+            "Error report message is longer than {}.",
+            LONG_STRING_MAX_LENGTH
+        ));
+    }
+
+    if error_report.stack_hash.len() > SHORT_STRING_MAX_LENGTH {
+        return Err(format!(
+            "Error report stack_hash {} is longer than {}.",
+            error_report.stack_hash, SHORT_STRING_MAX_LENGTH
+        ));
+    }
+
+    if error_report.href.len() > LONG_STRING_MAX_LENGTH {
+        return Err(format!(
+            "Error report href {} is longer than {}.",
+            error_report.href, LONG_STRING_MAX_LENGTH
+        ));
+    }
+
+    match error_report.user_agent.clone() {
+        None => {}
+        Some(user_agent) => {
+            if user_agent.len() > STRING_MAX_LENGTH {
+                return Err(format!(
+                    "Error report user_agent {} is longer than {}.",
+                    user_agent, STRING_MAX_LENGTH
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn assert_session_id_length(session_id: &str) -> Result<(), String> {
     if session_id.len() > KEY_MAX_LENGTH {
         return Err(format!(
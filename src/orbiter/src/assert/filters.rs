@@ -0,0 +1,18 @@
+use crate::constants::SATELLITES_PER_QUERY_MAX_LENGTH;
+use crate::msg::{ERROR_NO_SATELLITE_IDS, ERROR_TOO_MANY_SATELLITE_IDS};
+use junobuild_shared::types::state::SatelliteId;
+
+pub fn assert_satellite_ids(satellite_ids: &[SatelliteId]) -> Result<(), String> {
+    if satellite_ids.is_empty() {
+        return Err(ERROR_NO_SATELLITE_IDS.to_string());
+    }
+
+    if satellite_ids.len() > SATELLITES_PER_QUERY_MAX_LENGTH {
+        return Err(format!(
+            "{} Maximum {}.",
+            ERROR_TOO_MANY_SATELLITE_IDS, SATELLITES_PER_QUERY_MAX_LENGTH
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::assert::filters::assert_satellite_ids;
+use crate::types::interface::{GetAnalytics, GetAnalyticsBySatellite};
+use crate::types::state::AnalyticKey;
+use junobuild_shared::types::state::SatelliteId;
+
+/// Collects the entries of each satellite of the filter and analyzes them per satellite and all together. A
+/// satellite provided twice is only accounted once.
+pub fn analytics_by_satellite<T, R>(
+    filter: &GetAnalyticsBySatellite,
+    get_entries: impl Fn(&GetAnalytics) -> Vec<(AnalyticKey, T)>,
+    analyze: impl Fn(&Vec<(AnalyticKey, T)>) -> R,
+) -> Result<(R, Vec<(SatelliteId, R)>), String> {
+    assert_satellite_ids(&filter.satellite_ids)?;
+
+    let mut satellite_ids: Vec<SatelliteId> = Vec::new();
+
+    for satellite_id in filter.satellite_ids.iter() {
+        if !satellite_ids.contains(satellite_id) {
+            satellite_ids.push(*satellite_id);
+        }
+    }
+
+    let mut all_entries: Vec<(AnalyticKey, T)> = Vec::new();
+    let mut satellites: Vec<(SatelliteId, R)> = Vec::new();
+
+    for satellite_id in satellite_ids {
+        let entries = get_entries(&GetAnalytics {
+            satellite_id: Some(satellite_id),
+            from: filter.from,
+            to: filter.to,
+        });
+
+        satellites.push((satellite_id, analyze(&entries)));
+
+        all_entries.extend(entries);
+    }
+
+    Ok((analyze(&all_entries), satellites))
+}
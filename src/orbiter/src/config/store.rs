@@ -1,3 +1,4 @@
+use crate::assert::config::assert_sampling;
 use crate::memory::STATE;
 use crate::types::interface::{DelSatelliteConfig, SetSatelliteConfig};
 use crate::types::state::{SatelliteConfig, SatelliteConfigs};
@@ -33,6 +34,8 @@ fn set_satellite_config_impl(
     config: &SetSatelliteConfig,
     state: &mut SatelliteConfigs,
 ) -> Result<SatelliteConfig, String> {
+    assert_sampling(&config.sampling)?;
+
     let current_config = state.get(satellite_id);
 
     // Validate version
@@ -62,6 +65,9 @@ fn set_satellite_config_impl(
 
     let new_config = SatelliteConfig {
         features: config.features.clone(),
+        sampling: config.sampling.clone(),
+        rate_config: config.rate_config.clone(),
+        anonymize: config.anonymize,
         created_at,
         updated_at,
         version: Some(version),
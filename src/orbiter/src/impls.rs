@@ -8,8 +8,8 @@ use crate::serializers::bounded::{
 use crate::serializers::constants::{ANALYTIC_KEY_MAX_SIZE, ANALYTIC_SATELLITE_KEY_MAX_SIZE};
 use crate::types::memory::{StoredPageView, StoredTrackEvent};
 use crate::types::state::{
-    AnalyticKey, AnalyticSatelliteKey, HeapState, PageView, PerformanceMetric, SatelliteConfigs,
-    State, TrackEvent,
+    AnalyticKey, AnalyticSatelliteKey, ErrorReport, HeapState, PageView, PerformanceMetric,
+    RuntimeState, SatelliteConfigs, State, TrackEvent,
 };
 use ciborium::from_reader;
 use ic_stable_structures::storable::Bound;
@@ -26,6 +26,7 @@ impl Default for State {
                 controllers: Controllers::default(),
                 config: SatelliteConfigs::default(),
             },
+            runtime: RuntimeState::default(),
         }
     }
 }
@@ -102,6 +103,18 @@ impl Storable for PerformanceMetric {
     const BOUND: Bound = Bound::Unbounded;
 }
 
+impl Storable for ErrorReport {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        serialize_to_bytes(self)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_from_bytes(bytes)
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
 impl Storable for AnalyticKey {
     fn to_bytes(&self) -> Cow<[u8]> {
         serialize_bounded_analytic_key(self)
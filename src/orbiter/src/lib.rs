@@ -1,5 +1,7 @@
 mod analytics;
+mod anonymization;
 mod assert;
+mod breakdown;
 mod config;
 mod constants;
 mod controllers;
@@ -8,17 +10,21 @@ mod guards;
 mod impls;
 mod memory;
 mod msg;
+mod runtime;
+mod sampling;
 mod serializers;
 mod store;
 mod types;
 
 use crate::analytics::{
-    analytics_page_views_clients, analytics_page_views_metrics, analytics_page_views_top_10,
-    analytics_performance_metrics_web_vitals, analytics_track_events,
+    analytics_error_reports, analytics_page_views_clients, analytics_page_views_metrics,
+    analytics_page_views_top_10, analytics_performance_metrics_web_vitals, analytics_track_events,
 };
 use crate::assert::config::{
-    assert_page_views_enabled, assert_performance_metrics_enabled, assert_track_events_enabled,
+    assert_error_reports_enabled, assert_page_views_enabled, assert_performance_metrics_enabled,
+    assert_track_events_enabled,
 };
+use crate::breakdown::analytics_by_satellite;
 use crate::config::store::{
     del_satellite_config as del_satellite_config_store, get_satellite_configs,
     set_satellite_config as set_satellite_config_store,
@@ -29,19 +35,32 @@ use crate::controllers::store::{
 };
 use crate::guards::{caller_is_admin_controller, caller_is_controller};
 use crate::memory::{get_memory_upgrades, init_stable_state, STATE};
+use crate::msg::{
+    ERROR_PAGE_VIEWS_SAMPLED_OUT, ERROR_PERFORMANCE_METRICS_SAMPLED_OUT,
+    ERROR_TRACK_EVENTS_SAMPLED_OUT,
+};
+use crate::runtime::increment_and_assert_rate;
+use crate::sampling::{
+    is_page_view_sampled, is_performance_metric_sampled, is_track_event_sampled,
+};
 use crate::store::{
-    get_page_views as get_page_views_store,
+    get_error_reports as get_error_reports_store, get_page_views as get_page_views_store,
     get_performance_metrics as get_performance_metrics_store, get_satellite_config,
-    get_track_events as get_track_events_store, insert_page_view, insert_performance_metric,
-    insert_track_event,
+    get_track_events as get_track_events_store, insert_error_report, insert_page_view,
+    insert_performance_metric, insert_track_event,
 };
 use crate::types::interface::{
-    AnalyticsClientsPageViews, AnalyticsMetricsPageViews, AnalyticsTop10PageViews,
-    AnalyticsTrackEvents, AnalyticsWebVitalsPerformanceMetrics, DelSatelliteConfig, GetAnalytics,
-    SetPageView, SetPerformanceMetric, SetSatelliteConfig, SetTrackEvent,
+    AnalyticsClientsPageViews, AnalyticsClientsPageViewsBySatellite, AnalyticsErrorReports,
+    AnalyticsErrorReportsBySatellite, AnalyticsMetricsPageViews,
+    AnalyticsMetricsPageViewsBySatellite, AnalyticsTop10PageViews,
+    AnalyticsTop10PageViewsBySatellite, AnalyticsTrackEvents, AnalyticsTrackEventsBySatellite,
+    AnalyticsWebVitalsPerformanceMetrics, AnalyticsWebVitalsPerformanceMetricsBySatellite,
+    DelSatelliteConfig, GetAnalytics, GetAnalyticsBySatellite, SetErrorReport, SetPageView,
+    SetPerformanceMetric, SetSatelliteConfig, SetTrackEvent,
 };
 use crate::types::state::{
-    AnalyticKey, HeapState, PageView, PerformanceMetric, SatelliteConfigs, State, TrackEvent,
+    AnalyticKey, ErrorReport, HeapState, PageView, PerformanceMetric, RuntimeState,
+    SatelliteConfigs, State, TrackEvent,
 };
 use ciborium::{from_reader, into_writer};
 use ic_cdk::api::call::{arg_data, ArgDecoderConfig};
@@ -74,6 +93,7 @@ fn init() {
         *state.borrow_mut() = State {
             stable: init_stable_state(),
             heap,
+            runtime: RuntimeState::default(),
         };
     });
 }
@@ -103,9 +123,17 @@ fn post_upgrade() {
 
 #[update]
 fn set_page_view(key: AnalyticKey, page_view: SetPageView) -> Result<PageView, String> {
-    assert_page_views_enabled(&get_satellite_config(&page_view.satellite_id))?;
+    let config = get_satellite_config(&page_view.satellite_id);
+
+    assert_page_views_enabled(&config)?;
+
+    if !is_page_view_sampled(&config, &page_view.session_id) {
+        return Err(ERROR_PAGE_VIEWS_SAMPLED_OUT.to_string());
+    }
+
+    increment_and_assert_rate(&page_view.satellite_id, &config)?;
 
-    insert_page_view(key, page_view)
+    insert_page_view(key, page_view, &config)
 }
 
 #[update]
@@ -113,8 +141,17 @@ fn set_page_views(
     page_views: Vec<(AnalyticKey, SetPageView)>,
 ) -> Result<(), Vec<(AnalyticKey, String)>> {
     fn insert(key: AnalyticKey, page_view: SetPageView) -> Result<(), String> {
-        assert_page_views_enabled(&get_satellite_config(&page_view.satellite_id))?;
-        insert_page_view(key, page_view)?;
+        let config = get_satellite_config(&page_view.satellite_id);
+
+        assert_page_views_enabled(&config)?;
+
+        // The sessions left out by the sampling are ignored without error
+        if !is_page_view_sampled(&config, &page_view.session_id) {
+            return Ok(());
+        }
+
+        increment_and_assert_rate(&page_view.satellite_id, &config)?;
+        insert_page_view(key, page_view, &config)?;
 
         Ok(())
     }
@@ -148,25 +185,66 @@ fn get_page_views_analytics_metrics(filter: GetAnalytics) -> AnalyticsMetricsPag
     analytics_page_views_metrics(&page_views)
 }
 
+#[query(guard = "caller_is_controller")]
+fn get_page_views_analytics_metrics_by_satellite(
+    filter: GetAnalyticsBySatellite,
+) -> AnalyticsMetricsPageViewsBySatellite {
+    let (total, satellites) =
+        analytics_by_satellite(&filter, get_page_views_store, analytics_page_views_metrics)
+            .unwrap_or_else(|e| trap(&e));
+
+    AnalyticsMetricsPageViewsBySatellite { total, satellites }
+}
+
 #[query(guard = "caller_is_controller")]
 fn get_page_views_analytics_top_10(filter: GetAnalytics) -> AnalyticsTop10PageViews {
     let page_views = get_page_views_store(&filter);
     analytics_page_views_top_10(&page_views)
 }
 
+#[query(guard = "caller_is_controller")]
+fn get_page_views_analytics_top_10_by_satellite(
+    filter: GetAnalyticsBySatellite,
+) -> AnalyticsTop10PageViewsBySatellite {
+    let (total, satellites) =
+        analytics_by_satellite(&filter, get_page_views_store, analytics_page_views_top_10)
+            .unwrap_or_else(|e| trap(&e));
+
+    AnalyticsTop10PageViewsBySatellite { total, satellites }
+}
+
 #[query(guard = "caller_is_controller")]
 fn get_page_views_analytics_clients(filter: GetAnalytics) -> AnalyticsClientsPageViews {
     let page_views = get_page_views_store(&filter);
     analytics_page_views_clients(&page_views)
 }
 
+#[query(guard = "caller_is_controller")]
+fn get_page_views_analytics_clients_by_satellite(
+    filter: GetAnalyticsBySatellite,
+) -> AnalyticsClientsPageViewsBySatellite {
+    let (total, satellites) =
+        analytics_by_satellite(&filter, get_page_views_store, analytics_page_views_clients)
+            .unwrap_or_else(|e| trap(&e));
+
+    AnalyticsClientsPageViewsBySatellite { total, satellites }
+}
+
 /// Track events
 
 #[update]
 fn set_track_event(key: AnalyticKey, track_event: SetTrackEvent) -> Result<TrackEvent, String> {
-    assert_track_events_enabled(&get_satellite_config(&track_event.satellite_id))?;
+    let config = get_satellite_config(&track_event.satellite_id);
 
-    insert_track_event(key, track_event)
+    assert_track_events_enabled(&config)?;
+
+    if !is_track_event_sampled(&config, &track_event.session_id) {
+        return Err(ERROR_TRACK_EVENTS_SAMPLED_OUT.to_string());
+    }
+
+    increment_and_assert_rate(&track_event.satellite_id, &config)?;
+
+    insert_track_event(key, track_event, &config)
 }
 
 #[update]
@@ -174,8 +252,17 @@ fn set_track_events(
     track_events: Vec<(AnalyticKey, SetTrackEvent)>,
 ) -> Result<(), Vec<(AnalyticKey, String)>> {
     fn insert(key: AnalyticKey, track_event: SetTrackEvent) -> Result<(), String> {
-        assert_track_events_enabled(&get_satellite_config(&track_event.satellite_id))?;
-        insert_track_event(key, track_event)?;
+        let config = get_satellite_config(&track_event.satellite_id);
+
+        assert_track_events_enabled(&config)?;
+
+        // The sessions left out by the sampling are ignored without error
+        if !is_track_event_sampled(&config, &track_event.session_id) {
+            return Ok(());
+        }
+
+        increment_and_assert_rate(&track_event.satellite_id, &config)?;
+        insert_track_event(key, track_event, &config)?;
 
         Ok(())
     }
@@ -209,6 +296,17 @@ fn get_track_events_analytics(filter: GetAnalytics) -> AnalyticsTrackEvents {
     analytics_track_events(&track_events)
 }
 
+#[query(guard = "caller_is_controller")]
+fn get_track_events_analytics_by_satellite(
+    filter: GetAnalyticsBySatellite,
+) -> AnalyticsTrackEventsBySatellite {
+    let (total, satellites) =
+        analytics_by_satellite(&filter, get_track_events_store, analytics_track_events)
+            .unwrap_or_else(|e| trap(&e));
+
+    AnalyticsTrackEventsBySatellite { total, satellites }
+}
+
 /// Performance metrics
 
 #[update]
@@ -216,9 +314,17 @@ fn set_performance_metric(
     key: AnalyticKey,
     performance_metric: SetPerformanceMetric,
 ) -> Result<PerformanceMetric, String> {
-    assert_performance_metrics_enabled(&get_satellite_config(&performance_metric.satellite_id))?;
+    let config = get_satellite_config(&performance_metric.satellite_id);
+
+    assert_performance_metrics_enabled(&config)?;
 
-    insert_performance_metric(key, performance_metric)
+    if !is_performance_metric_sampled(&config, &performance_metric.session_id) {
+        return Err(ERROR_PERFORMANCE_METRICS_SAMPLED_OUT.to_string());
+    }
+
+    increment_and_assert_rate(&performance_metric.satellite_id, &config)?;
+
+    insert_performance_metric(key, performance_metric, &config)
 }
 
 #[update]
@@ -226,10 +332,17 @@ fn set_performance_metrics(
     performance_metrics: Vec<(AnalyticKey, SetPerformanceMetric)>,
 ) -> Result<(), Vec<(AnalyticKey, String)>> {
     fn insert(key: AnalyticKey, performance_metric: SetPerformanceMetric) -> Result<(), String> {
-        assert_performance_metrics_enabled(&get_satellite_config(
-            &performance_metric.satellite_id,
-        ))?;
-        insert_performance_metric(key, performance_metric)?;
+        let config = get_satellite_config(&performance_metric.satellite_id);
+
+        assert_performance_metrics_enabled(&config)?;
+
+        // The sessions left out by the sampling are ignored without error
+        if !is_performance_metric_sampled(&config, &performance_metric.session_id) {
+            return Ok(());
+        }
+
+        increment_and_assert_rate(&performance_metric.satellite_id, &config)?;
+        insert_performance_metric(key, performance_metric, &config)?;
 
         Ok(())
     }
@@ -265,6 +378,88 @@ fn get_performance_metrics_analytics_web_vitals(
     analytics_performance_metrics_web_vitals(&metrics)
 }
 
+#[query(guard = "caller_is_controller")]
+fn get_performance_metrics_analytics_web_vitals_by_satellite(
+    filter: GetAnalyticsBySatellite,
+) -> AnalyticsWebVitalsPerformanceMetricsBySatellite {
+    let (total, satellites) = analytics_by_satellite(
+        &filter,
+        get_performance_metrics_store,
+        analytics_performance_metrics_web_vitals,
+    )
+    .unwrap_or_else(|e| trap(&e));
+
+    AnalyticsWebVitalsPerformanceMetricsBySatellite { total, satellites }
+}
+
+/// Error reports
+
+#[update]
+fn set_error_report(key: AnalyticKey, error_report: SetErrorReport) -> Result<ErrorReport, String> {
+    let config = get_satellite_config(&error_report.satellite_id);
+
+    assert_error_reports_enabled(&config)?;
+
+    increment_and_assert_rate(&error_report.satellite_id, &config)?;
+
+    insert_error_report(key, error_report, &config)
+}
+
+#[update]
+fn set_error_reports(
+    error_reports: Vec<(AnalyticKey, SetErrorReport)>,
+) -> Result<(), Vec<(AnalyticKey, String)>> {
+    fn insert(key: AnalyticKey, error_report: SetErrorReport) -> Result<(), String> {
+        let config = get_satellite_config(&error_report.satellite_id);
+
+        assert_error_reports_enabled(&config)?;
+
+        increment_and_assert_rate(&error_report.satellite_id, &config)?;
+        insert_error_report(key, error_report, &config)?;
+
+        Ok(())
+    }
+
+    let mut errors: Vec<(AnalyticKey, String)> = Vec::new();
+
+    for (key, error_report) in error_reports {
+        let result = insert(key.clone(), error_report);
+
+        match result {
+            Ok(_) => {}
+            Err(err) => errors.push((key, err)),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(())
+}
+
+#[query(guard = "caller_is_controller")]
+fn get_error_reports(filter: GetAnalytics) -> Vec<(AnalyticKey, ErrorReport)> {
+    get_error_reports_store(&filter)
+}
+
+#[query(guard = "caller_is_controller")]
+fn get_error_reports_analytics(filter: GetAnalytics) -> AnalyticsErrorReports {
+    let error_reports = get_error_reports_store(&filter);
+    analytics_error_reports(&error_reports)
+}
+
+#[query(guard = "caller_is_controller")]
+fn get_error_reports_analytics_by_satellite(
+    filter: GetAnalyticsBySatellite,
+) -> AnalyticsErrorReportsBySatellite {
+    let (total, satellites) =
+        analytics_by_satellite(&filter, get_error_reports_store, analytics_error_reports)
+            .unwrap_or_else(|e| trap(&e));
+
+    AnalyticsErrorReportsBySatellite { total, satellites }
+}
+
 ///
 /// Controllers
 ///
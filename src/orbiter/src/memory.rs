@@ -12,6 +12,8 @@ const SATELLITES_PAGE_VIEWS: MemoryId = MemoryId::new(3);
 const SATELLITES_TRACK_EVENTS: MemoryId = MemoryId::new(4);
 const PERFORMANCE_METRICS: MemoryId = MemoryId::new(5);
 const SATELLITES_PERFORMANCE_METRICS: MemoryId = MemoryId::new(6);
+const ERROR_REPORTS: MemoryId = MemoryId::new(7);
+const SATELLITES_ERROR_REPORTS: MemoryId = MemoryId::new(8);
 
 thread_local! {
     pub static STATE: RefCell<State> = RefCell::default();
@@ -38,5 +40,7 @@ pub fn init_stable_state() -> StableState {
         satellites_performance_metrics: StableBTreeMap::init(get_memory(
             SATELLITES_PERFORMANCE_METRICS,
         )),
+        error_reports: StableBTreeMap::init(get_memory(ERROR_REPORTS)),
+        satellites_error_reports: StableBTreeMap::init(get_memory(SATELLITES_ERROR_REPORTS)),
     }
 }
@@ -2,4 +2,11 @@ pub const ERROR_PAGE_VIEWS_FEATURE_DISABLED: &str = "error_page_views_feature_di
 pub const ERROR_TRACK_EVENTS_FEATURE_DISABLED: &str = "error_track_events_feature_disabled";
 pub const ERROR_PERFORMANCE_METRICS_FEATURE_DISABLED: &str =
     "error_performance_metrics_feature_disabled";
+pub const ERROR_ERROR_REPORTS_FEATURE_DISABLED: &str = "error_error_reports_feature_disabled";
 pub const ERROR_BOT_CALL: &str = "error_bot_call";
+pub const ERROR_PAGE_VIEWS_SAMPLED_OUT: &str = "error_page_views_sampled_out";
+pub const ERROR_TRACK_EVENTS_SAMPLED_OUT: &str = "error_track_events_sampled_out";
+pub const ERROR_PERFORMANCE_METRICS_SAMPLED_OUT: &str = "error_performance_metrics_sampled_out";
+pub const ERROR_SAMPLING_EXCEEDED: &str = "Sampling rate cannot exceed 100 percent.";
+pub const ERROR_NO_SATELLITE_IDS: &str = "At least one satellite must be provided.";
+pub const ERROR_TOO_MANY_SATELLITE_IDS: &str = "Too many satellites provided.";
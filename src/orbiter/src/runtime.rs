@@ -0,0 +1,24 @@
+use crate::memory::STATE;
+use crate::types::state::SatelliteConfig;
+use junobuild_shared::rate::utils::increment_and_assert_rate_store;
+use junobuild_shared::types::state::SatelliteId;
+
+// ---------------------------------------------------------
+// Rates
+// ---------------------------------------------------------
+
+/// The ingestion is throttled per satellite - i.e. per tracked origin - regardless of the kind of analytics.
+pub fn increment_and_assert_rate(
+    satellite_id: &SatelliteId,
+    config: &Option<SatelliteConfig>,
+) -> Result<(), String> {
+    let rate_config = config.as_ref().and_then(|c| c.rate_config.clone());
+
+    STATE.with(|state| {
+        increment_and_assert_rate_store(
+            &satellite_id.to_text(),
+            &rate_config,
+            &mut state.borrow_mut().runtime.rate_tokens,
+        )
+    })
+}
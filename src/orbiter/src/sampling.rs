@@ -0,0 +1,47 @@
+use crate::types::state::{SatelliteConfig, SessionId};
+use sha2::{Digest, Sha256};
+
+pub fn is_page_view_sampled(config: &Option<SatelliteConfig>, session_id: &SessionId) -> bool {
+    is_sampled(
+        config
+            .as_ref()
+            .and_then(|c| c.sampling.as_ref())
+            .and_then(|s| s.page_views),
+        session_id,
+    )
+}
+
+pub fn is_track_event_sampled(config: &Option<SatelliteConfig>, session_id: &SessionId) -> bool {
+    is_sampled(
+        config
+            .as_ref()
+            .and_then(|c| c.sampling.as_ref())
+            .and_then(|s| s.track_events),
+        session_id,
+    )
+}
+
+pub fn is_performance_metric_sampled(
+    config: &Option<SatelliteConfig>,
+    session_id: &SessionId,
+) -> bool {
+    is_sampled(
+        config
+            .as_ref()
+            .and_then(|c| c.sampling.as_ref())
+            .and_then(|s| s.performance_metrics),
+        session_id,
+    )
+}
+
+// The decision is derived from the session identifier. That way the analytics of a session are either all recorded or all left out.
+fn is_sampled(rate: Option<u8>, session_id: &SessionId) -> bool {
+    match rate {
+        None => true,
+        Some(rate) => {
+            let hash = Sha256::digest(session_id.as_bytes());
+
+            u64::from_be_bytes(hash[..8].try_into().unwrap_or_default()) % 100 < u64::from(rate)
+        }
+    }
+}
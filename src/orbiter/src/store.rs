@@ -1,33 +1,53 @@
+use crate::anonymization::{
+    anonymize_error_report, anonymize_page_view, anonymize_performance_metric,
+    anonymize_track_event, ingestion_time, is_anonymized,
+};
 use crate::assert::constraints::{
-    assert_analytic_key_length, assert_bot, assert_page_view_length, assert_satellite_id,
-    assert_session_id, assert_track_event_length,
+    assert_analytic_key_length, assert_bot, assert_error_report_length, assert_page_view_length,
+    assert_satellite_id, assert_session_id, assert_track_event_length,
 };
 use crate::filters::{filter_analytics, filter_satellites_analytics};
 use crate::memory::STATE;
-use crate::types::interface::{GetAnalytics, SetPageView, SetPerformanceMetric, SetTrackEvent};
+use crate::types::interface::{
+    GetAnalytics, SetErrorReport, SetPageView, SetPerformanceMetric, SetTrackEvent,
+};
 use crate::types::memory::{StoredPageView, StoredTrackEvent};
 use crate::types::state::{
-    AnalyticKey, AnalyticSatelliteKey, PageView, PerformanceMetric, SatelliteConfig, StableState,
-    TrackEvent,
+    AnalyticKey, AnalyticSatelliteKey, ErrorReport, PageView, PerformanceMetric, SatelliteConfig,
+    StableState, TrackEvent,
 };
-use ic_cdk::api::time;
 use junobuild_shared::assert::{assert_timestamp, assert_version};
 use junobuild_shared::constants::INITIAL_VERSION;
 use junobuild_shared::types::state::{SatelliteId, Timestamp, Version};
 
-pub fn insert_page_view(key: AnalyticKey, page_view: SetPageView) -> Result<PageView, String> {
-    STATE.with(|state| insert_page_view_impl(key, page_view, &mut state.borrow_mut().stable))
+pub fn insert_page_view(
+    key: AnalyticKey,
+    page_view: SetPageView,
+    config: &Option<SatelliteConfig>,
+) -> Result<PageView, String> {
+    STATE
+        .with(|state| insert_page_view_impl(key, page_view, config, &mut state.borrow_mut().stable))
 }
 
 fn insert_page_view_impl(
     key: AnalyticKey,
     page_view: SetPageView,
+    config: &Option<SatelliteConfig>,
     state: &mut StableState,
 ) -> Result<PageView, String> {
     assert_bot(&page_view.user_agent)?;
     assert_analytic_key_length(&key)?;
     assert_page_view_length(&page_view)?;
 
+    let anonymized = is_anonymized(config);
+
+    // The identifying fields are anonymized once the raw data has been validated - e.g. the user agent for the bots
+    let (key, page_view) = if anonymized {
+        anonymize_page_view(key, page_view)
+    } else {
+        (key, page_view)
+    };
+
     let current_page_view = state.page_views.get(&key);
 
     // Validate overwrite
@@ -71,7 +91,7 @@ fn insert_page_view_impl(
         }
     }
 
-    let now = time();
+    let now = ingestion_time(anonymized);
 
     let created_at: Timestamp = match current_page_view.clone() {
         None => now,
@@ -120,19 +140,32 @@ fn insert_page_view_impl(
 pub fn insert_track_event(
     key: AnalyticKey,
     track_event: SetTrackEvent,
+    config: &Option<SatelliteConfig>,
 ) -> Result<TrackEvent, String> {
-    STATE.with(|state| insert_track_event_impl(key, track_event, &mut state.borrow_mut().stable))
+    STATE.with(|state| {
+        insert_track_event_impl(key, track_event, config, &mut state.borrow_mut().stable)
+    })
 }
 
 fn insert_track_event_impl(
     key: AnalyticKey,
     track_event: SetTrackEvent,
+    config: &Option<SatelliteConfig>,
     state: &mut StableState,
 ) -> Result<TrackEvent, String> {
     assert_bot(&track_event.user_agent)?;
     assert_analytic_key_length(&key)?;
     assert_track_event_length(&track_event)?;
 
+    let anonymized = is_anonymized(config);
+
+    // The identifying fields are anonymized once the raw data has been validated - e.g. the user agent for the bots
+    let (key, track_event) = if anonymized {
+        anonymize_track_event(key, track_event)
+    } else {
+        (key, track_event)
+    };
+
     let current_track_event = state.track_events.get(&key);
 
     // Validate overwrite
@@ -182,7 +215,7 @@ fn insert_track_event_impl(
         }
     }
 
-    let now = time();
+    let now = ingestion_time(anonymized);
 
     let created_at: Timestamp = match current_track_event.clone() {
         None => now,
@@ -229,20 +262,36 @@ fn insert_track_event_impl(
 pub fn insert_performance_metric(
     key: AnalyticKey,
     performance_metric: SetPerformanceMetric,
+    config: &Option<SatelliteConfig>,
 ) -> Result<PerformanceMetric, String> {
     STATE.with(|state| {
-        insert_performance_metric_impl(key, performance_metric, &mut state.borrow_mut().stable)
+        insert_performance_metric_impl(
+            key,
+            performance_metric,
+            config,
+            &mut state.borrow_mut().stable,
+        )
     })
 }
 
 fn insert_performance_metric_impl(
     key: AnalyticKey,
     performance_metric: SetPerformanceMetric,
+    config: &Option<SatelliteConfig>,
     state: &mut StableState,
 ) -> Result<PerformanceMetric, String> {
     assert_bot(&performance_metric.user_agent)?;
     assert_analytic_key_length(&key)?;
 
+    let anonymized = is_anonymized(config);
+
+    // The identifying fields are anonymized once the raw data has been validated - e.g. the user agent for the bots
+    let (key, performance_metric) = if anonymized {
+        anonymize_performance_metric(key, performance_metric)
+    } else {
+        (key, performance_metric)
+    };
+
     let current_performance_metric = state.performance_metrics.get(&key);
 
     // Validate overwrite
@@ -283,7 +332,7 @@ fn insert_performance_metric_impl(
         }
     }
 
-    let now = time();
+    let now = ingestion_time(anonymized);
 
     let created_at: Timestamp = match &current_performance_metric {
         None => now,
@@ -325,6 +374,97 @@ fn insert_performance_metric_impl(
     Ok(new_performance_metric.clone())
 }
 
+pub fn insert_error_report(
+    key: AnalyticKey,
+    error_report: SetErrorReport,
+    config: &Option<SatelliteConfig>,
+) -> Result<ErrorReport, String> {
+    STATE.with(|state| {
+        insert_error_report_impl(key, error_report, config, &mut state.borrow_mut().stable)
+    })
+}
+
+fn insert_error_report_impl(
+    key: AnalyticKey,
+    error_report: SetErrorReport,
+    config: &Option<SatelliteConfig>,
+    state: &mut StableState,
+) -> Result<ErrorReport, String> {
+    assert_bot(&error_report.user_agent)?;
+    assert_analytic_key_length(&key)?;
+    assert_error_report_length(&error_report)?;
+
+    let anonymized = is_anonymized(config);
+
+    // The identifying fields are anonymized once the raw data has been validated - e.g. the user agent for the bots
+    let (key, error_report) = if anonymized {
+        anonymize_error_report(key, error_report)
+    } else {
+        (key, error_report)
+    };
+
+    let current_error_report = state.error_reports.get(&key);
+
+    // Validate overwrite
+    match &current_error_report {
+        None => (),
+        Some(current_error_report) => {
+            assert_version(error_report.version, current_error_report.version)?;
+        }
+    }
+
+    // Validate session id
+    match &current_error_report {
+        None => (),
+        Some(current_error_report) => {
+            assert_session_id(&error_report.session_id, &current_error_report.session_id)?;
+        }
+    }
+
+    // Validate satellite id
+    match &current_error_report {
+        None => (),
+        Some(current_error_report) => {
+            assert_satellite_id(error_report.satellite_id, current_error_report.satellite_id)?;
+        }
+    }
+
+    let now = ingestion_time(anonymized);
+
+    let created_at: Timestamp = match &current_error_report {
+        None => now,
+        Some(current_error_report) => current_error_report.created_at,
+    };
+
+    let version: Version = match &current_error_report {
+        None => INITIAL_VERSION,
+        Some(current_error_report) => current_error_report.version.unwrap_or_default() + 1,
+    };
+
+    let new_error_report: ErrorReport = ErrorReport {
+        message: error_report.message,
+        stack_hash: error_report.stack_hash,
+        href: error_report.href,
+        user_agent: error_report.user_agent,
+        satellite_id: error_report.satellite_id,
+        session_id: error_report.session_id,
+        created_at,
+        updated_at: now,
+        version: Some(version),
+    };
+
+    state
+        .error_reports
+        .insert(key.clone(), new_error_report.clone());
+
+    state.satellites_error_reports.insert(
+        AnalyticSatelliteKey::from_key(&key, &new_error_report.satellite_id),
+        key.clone(),
+    );
+
+    Ok(new_error_report)
+}
+
 pub fn get_page_views(filter: &GetAnalytics) -> Vec<(AnalyticKey, PageView)> {
     STATE.with(|state| get_page_views_impl(filter, &state.borrow_mut().stable))
 }
@@ -411,6 +551,35 @@ fn get_performance_metrics_impl(
     }
 }
 
+pub fn get_error_reports(filter: &GetAnalytics) -> Vec<(AnalyticKey, ErrorReport)> {
+    STATE.with(|state| get_error_reports_impl(filter, &state.borrow().stable))
+}
+
+fn get_error_reports_impl(
+    filter: &GetAnalytics,
+    state: &StableState,
+) -> Vec<(AnalyticKey, ErrorReport)> {
+    match filter.satellite_id {
+        None => state
+            .error_reports
+            .range(filter_analytics(filter))
+            .collect(),
+        Some(satellite_id) => {
+            let satellites_keys: Vec<(AnalyticSatelliteKey, AnalyticKey)> = state
+                .satellites_error_reports
+                .range(filter_satellites_analytics(filter, satellite_id))
+                .collect();
+            satellites_keys
+                .iter()
+                .filter_map(|(_, key)| {
+                    let error_report = state.error_reports.get(key);
+                    error_report.map(|error_report| (key.clone(), error_report))
+                })
+                .collect()
+        }
+    }
+}
+
 pub fn get_satellite_config(satellite_id: &SatelliteId) -> Option<SatelliteConfig> {
     STATE.with(|state| {
         let binding = state.borrow();
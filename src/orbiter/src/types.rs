@@ -3,6 +3,7 @@ pub mod state {
     use crate::types::memory::{StoredPageView, StoredTrackEvent};
     use candid::CandidType;
     use ic_stable_structures::StableBTreeMap;
+    use junobuild_shared::rate::types::RateTokenStore;
     use junobuild_shared::types::memory::Memory;
     use junobuild_shared::types::state::{
         Controllers, Metadata, OrbiterSatelliteConfig, SatelliteId, Timestamp, Version,
@@ -18,6 +19,10 @@ pub mod state {
 
         // Indirect stable state: State that lives on the heap, but is saved into stable memory on upgrades.
         pub heap: HeapState,
+
+        // Unstable state: State that resides only on the heap, that’s lost after an upgrade.
+        #[serde(skip, default)]
+        pub runtime: RuntimeState,
     }
 
     pub type Key = String;
@@ -26,12 +31,15 @@ pub mod state {
     pub type PageViewsStable = StableBTreeMap<AnalyticKey, StoredPageView, Memory>;
     pub type TrackEventsStable = StableBTreeMap<AnalyticKey, StoredTrackEvent, Memory>;
     pub type PerformanceMetricsStable = StableBTreeMap<AnalyticKey, PerformanceMetric, Memory>;
+    pub type ErrorReportsStable = StableBTreeMap<AnalyticKey, ErrorReport, Memory>;
 
     pub type SatellitesPageViewsStable = StableBTreeMap<AnalyticSatelliteKey, AnalyticKey, Memory>;
     pub type SatellitesTrackEventsStable =
         StableBTreeMap<AnalyticSatelliteKey, AnalyticKey, Memory>;
     pub type SatellitesPerformanceMetricsStable =
         StableBTreeMap<AnalyticSatelliteKey, AnalyticKey, Memory>;
+    pub type SatellitesErrorReportsStable =
+        StableBTreeMap<AnalyticSatelliteKey, AnalyticKey, Memory>;
 
     pub struct StableState {
         pub page_views: PageViewsStable,
@@ -40,6 +48,8 @@ pub mod state {
         pub satellites_page_views: SatellitesPageViewsStable,
         pub satellites_track_events: SatellitesTrackEventsStable,
         pub satellites_performance_metrics: SatellitesPerformanceMetricsStable,
+        pub error_reports: ErrorReportsStable,
+        pub satellites_error_reports: SatellitesErrorReportsStable,
     }
 
     pub type SatelliteConfig = OrbiterSatelliteConfig;
@@ -51,6 +61,11 @@ pub mod state {
         pub config: SatelliteConfigs,
     }
 
+    #[derive(Default, Clone)]
+    pub struct RuntimeState {
+        pub rate_tokens: RateTokenStore,
+    }
+
     #[derive(CandidType, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct AnalyticKey {
         pub collected_at: Timestamp,
@@ -140,6 +155,20 @@ pub mod state {
         Prerender,
         Restore,
     }
+
+    /// A client-side error. The stack hash - computed by the client - groups the occurrences of a same error.
+    #[derive(CandidType, Serialize, Deserialize, Clone)]
+    pub struct ErrorReport {
+        pub message: String,
+        pub stack_hash: String,
+        pub href: String,
+        pub user_agent: Option<String>,
+        pub satellite_id: SatelliteId,
+        pub session_id: SessionId,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+        pub version: Option<Version>,
+    }
 }
 
 pub mod memory {
@@ -163,8 +192,10 @@ pub mod memory {
 pub mod interface {
     use crate::types::state::{PageViewDevice, PerformanceData, PerformanceMetricName, SessionId};
     use candid::CandidType;
+    use junobuild_shared::rate::types::RateConfig;
     use junobuild_shared::types::state::{
-        Metadata, OrbiterSatelliteFeatures, SatelliteId, Timestamp, Version,
+        Metadata, OrbiterSatelliteFeatures, OrbiterSatelliteSampling, SatelliteId, Timestamp,
+        Version,
     };
     use junobuild_shared::types::utils::CalendarDate;
     use serde::Deserialize;
@@ -214,6 +245,17 @@ pub mod interface {
         pub version: Option<Version>,
     }
 
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct SetErrorReport {
+        pub message: String,
+        pub stack_hash: String,
+        pub href: String,
+        pub user_agent: Option<String>,
+        pub satellite_id: SatelliteId,
+        pub session_id: SessionId,
+        pub version: Option<Version>,
+    }
+
     #[derive(CandidType, Deserialize, Clone)]
     pub struct GetAnalytics {
         pub satellite_id: Option<SatelliteId>,
@@ -221,9 +263,21 @@ pub mod interface {
         pub to: Option<Timestamp>,
     }
 
+    /// Aggregates the analytics of several satellites in a single call - e.g. for the frontends of a same project.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct GetAnalyticsBySatellite {
+        pub satellite_ids: Vec<SatelliteId>,
+        pub from: Option<Timestamp>,
+        pub to: Option<Timestamp>,
+    }
+
     #[derive(CandidType, Deserialize, Clone)]
     pub struct SetSatelliteConfig {
         pub features: Option<OrbiterSatelliteFeatures>,
+        pub sampling: Option<OrbiterSatelliteSampling>,
+        pub rate_config: Option<RateConfig>,
+        /// Truncates the timestamps and the user agents and rotates the session identifiers daily at ingestion time.
+        pub anonymize: Option<bool>,
         pub version: Option<Version>,
     }
 
@@ -275,6 +329,21 @@ pub mod interface {
         pub total: HashMap<String, u32>,
     }
 
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct AnalyticsErrorReports {
+        pub groups: Vec<AnalyticsErrorReportsGroup>,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct AnalyticsErrorReportsGroup {
+        pub stack_hash: String,
+        pub message: String,
+        pub total: u32,
+        pub daily_total: HashMap<CalendarDate, u32>,
+        pub unique_sessions: usize,
+        pub last_seen_at: Timestamp,
+    }
+
     #[derive(CandidType, Deserialize, Clone)]
     pub struct AnalyticsWebVitalsPerformanceMetrics {
         pub overall: AnalyticsWebVitalsPageMetrics,
@@ -289,4 +358,42 @@ pub mod interface {
         pub lcp: Option<f64>,
         pub ttfb: Option<f64>,
     }
+
+    /// - `total`: The analytics of all the requested satellites together.
+    /// - `satellites`: The analytics of each satellite.
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct AnalyticsMetricsPageViewsBySatellite {
+        pub total: AnalyticsMetricsPageViews,
+        pub satellites: Vec<(SatelliteId, AnalyticsMetricsPageViews)>,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct AnalyticsTop10PageViewsBySatellite {
+        pub total: AnalyticsTop10PageViews,
+        pub satellites: Vec<(SatelliteId, AnalyticsTop10PageViews)>,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct AnalyticsClientsPageViewsBySatellite {
+        pub total: AnalyticsClientsPageViews,
+        pub satellites: Vec<(SatelliteId, AnalyticsClientsPageViews)>,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct AnalyticsTrackEventsBySatellite {
+        pub total: AnalyticsTrackEvents,
+        pub satellites: Vec<(SatelliteId, AnalyticsTrackEvents)>,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct AnalyticsWebVitalsPerformanceMetricsBySatellite {
+        pub total: AnalyticsWebVitalsPerformanceMetrics,
+        pub satellites: Vec<(SatelliteId, AnalyticsWebVitalsPerformanceMetrics)>,
+    }
+
+    #[derive(CandidType, Deserialize, Clone)]
+    pub struct AnalyticsErrorReportsBySatellite {
+        pub total: AnalyticsErrorReports,
+        pub satellites: Vec<(SatelliteId, AnalyticsErrorReports)>,
+    }
 }
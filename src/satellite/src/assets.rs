@@ -19,6 +19,9 @@ pub fn init_asset() -> Result<(), String> {
         collection,
         owner: id(),
         description: None,
+        publish_at: None,
+        unpublish_at: None,
+        moderation: None,
     };
 
     let headers = vec![HeaderField(
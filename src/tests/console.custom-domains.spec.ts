@@ -97,7 +97,9 @@ describe('Console / Custom Domains', () => {
 						encoding_type: [],
 						full_path: '/.well-known/ic-domains',
 						name: 'ic-domains',
-						token: toNullable()
+						publish_at: toNullable(),
+						token: toNullable(),
+						unpublish_at: toNullable()
 					},
 					proposalId
 				)
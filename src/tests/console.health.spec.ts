@@ -0,0 +1,97 @@
+import type { _SERVICE as ConsoleActor } from '$declarations/console/console.did';
+import { idlFactory as idlFactorConsole } from '$declarations/console/console.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/console-tests.constants';
+import { deploySegments, initMissionControls } from './utils/console-tests.utils';
+import { CONSOLE_WASM_PATH, WASM_VERSIONS } from './utils/setup-tests.utils';
+
+describe('Console / Health', () => {
+	let pic: PocketIc;
+	let actor: Actor<ConsoleActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<ConsoleActor>({
+			idlFactory: idlFactorConsole,
+			wasm: CONSOLE_WASM_PATH,
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		await deploySegments(actor);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should provide the health of the platform', async () => {
+			const { get_platform_health, version } = actor;
+
+			const { console, observatory, releases } = await get_platform_health();
+
+			expect(console.version).toEqual(await version());
+			expect(console.cycles).toBeGreaterThan(0n);
+			expect(console.memory_size.heap).toBeGreaterThan(0n);
+
+			expect(fromNullable(releases.satellite)).toEqual(WASM_VERSIONS.satellite);
+			expect(fromNullable(releases.orbiter)).toEqual(WASM_VERSIONS.orbiter);
+			expect(fromNullable(releases.mission_control)).toEqual(WASM_VERSIONS.mission_control);
+
+			// The observatory is not deployed in this suite
+			expect(fromNullable(observatory.version)).toBeUndefined();
+			expect(fromNullable(observatory.error)).not.toBeUndefined();
+		});
+
+		it('should account the creations per day', async () => {
+			await initMissionControls({ actor, pic, length: 2 });
+
+			actor.setIdentity(controller);
+
+			const { list_creation_stats } = actor;
+
+			const stats = await list_creation_stats();
+
+			expect(stats).toHaveLength(1);
+
+			const [[{ day, segment }, { succeeded, failed }]] = stats;
+
+			expect(segment).toEqual({ MissionControl: null });
+			expect(day % (24n * 60n * 60n * 1_000_000_000n)).toEqual(0n);
+			expect(succeeded).toEqual(2n);
+			expect(failed).toEqual(0n);
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not get the health of the platform', async () => {
+			const { get_platform_health } = actor;
+
+			await expect(get_platform_health()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+
+		it('should not list the creation statistics', async () => {
+			const { list_creation_stats } = actor;
+
+			await expect(list_creation_stats()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
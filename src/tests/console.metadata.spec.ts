@@ -112,7 +112,9 @@ describe('Console / Metadata', () => {
 						encoding_type: [],
 						full_path: '/releases/metadata.json',
 						name: 'metadata.json',
-						token: toNullable()
+						publish_at: toNullable(),
+						token: toNullable(),
+						unpublish_at: toNullable()
 					},
 					proposalId
 				)
@@ -0,0 +1,140 @@
+import type { _SERVICE as ConsoleActor } from '$declarations/console/console.did';
+import { idlFactory as idlFactorConsole } from '$declarations/console/console.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/console-tests.constants';
+import { CONSOLE_WASM_PATH } from './utils/setup-tests.utils';
+
+describe('Console / Project templates', () => {
+	let pic: PocketIc;
+	let actor: Actor<ConsoleActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const templateId = 'blog';
+
+	const bundle = new Uint8Array([68, 73, 68, 76, 0, 0]);
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<ConsoleActor>({
+			idlFactory: idlFactorConsole,
+			wasm: CONSOLE_WASM_PATH,
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should not set a project template without bundle', async () => {
+			const { set_project_template } = actor;
+
+			await expect(
+				set_project_template(templateId, {
+					description: toNullable(),
+					bundle: []
+				})
+			).rejects.toThrow('The configuration bundle of a project template cannot be empty.');
+		});
+
+		it('should not set a project template with an invalid id', async () => {
+			const { set_project_template } = actor;
+
+			await expect(
+				set_project_template('My_Blog', {
+					description: toNullable(),
+					bundle
+				})
+			).rejects.toThrow(
+				'Template ID must contain only lowercase letters, digits and dashes.'
+			);
+		});
+
+		it('should set a project template', async () => {
+			const { set_project_template } = actor;
+
+			const template = await set_project_template(templateId, {
+				description: toNullable('A blog'),
+				bundle
+			});
+
+			expect(fromNullable(template.description)).toEqual('A blog');
+			expect(template.bundle).toEqual(bundle);
+		});
+
+		it('should keep the creation date when a project template is updated', async () => {
+			const { set_project_template, list_project_templates } = actor;
+
+			const [[_, { created_at }]] = await list_project_templates();
+
+			await pic.advanceTime(1000);
+
+			const template = await set_project_template(templateId, {
+				description: toNullable('My blog'),
+				bundle
+			});
+
+			expect(template.created_at).toEqual(created_at);
+			expect(template.updated_at).toBeGreaterThan(created_at);
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should list project templates', async () => {
+			const { list_project_templates } = actor;
+
+			const templates = await list_project_templates();
+
+			expect(templates.map(([id]) => id)).toEqual([templateId]);
+		});
+
+		it('should not set a project template', async () => {
+			const { set_project_template } = actor;
+
+			await expect(
+				set_project_template('shop', {
+					description: toNullable(),
+					bundle
+				})
+			).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+
+		it('should not delete a project template', async () => {
+			const { del_project_template } = actor;
+
+			await expect(del_project_template(templateId)).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+
+	describe('delete', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should delete a project template', async () => {
+			const { del_project_template, list_project_templates } = actor;
+
+			await del_project_template(templateId);
+
+			expect(await list_project_templates()).toHaveLength(0);
+		});
+	});
+});
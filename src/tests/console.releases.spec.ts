@@ -0,0 +1,96 @@
+import type { _SERVICE as ConsoleActor } from '$declarations/console/console.did';
+import { idlFactory as idlFactorConsole } from '$declarations/console/console.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { createHash } from 'node:crypto';
+import { readFile } from 'node:fs/promises';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { deploySegments } from './utils/console-tests.utils';
+import { CONSOLE_WASM_PATH, SATELLITE_WASM_PATH, WASM_VERSIONS } from './utils/setup-tests.utils';
+
+describe('Console / Releases', () => {
+	let pic: PocketIc;
+	let actor: Actor<ConsoleActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<ConsoleActor>({
+			idlFactory: idlFactorConsole,
+			wasm: CONSOLE_WASM_PATH,
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		await deploySegments(actor);
+
+		actor.setIdentity(new AnonymousIdentity());
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should get release wasm chunks with the published checksum', async () => {
+		const { get_release_wasm_chunk } = actor;
+
+		const first = await get_release_wasm_chunk({
+			segment_type: { Satellite: null },
+			version: WASM_VERSIONS.satellite,
+			chunk_index: 0n
+		});
+
+		const chunks = [first.content];
+
+		for (let i = 1n; i < first.chunks_length; i++) {
+			const { content, sha256 } = await get_release_wasm_chunk({
+				segment_type: { Satellite: null },
+				version: WASM_VERSIONS.satellite,
+				chunk_index: i
+			});
+
+			expect(sha256).toEqual(first.sha256);
+
+			chunks.push(content);
+		}
+
+		const wasm = Buffer.concat(chunks.map((chunk) => Buffer.from(chunk)));
+
+		expect(wasm).toEqual(await readFile(SATELLITE_WASM_PATH));
+
+		const sha256 = createHash('sha256').update(wasm).digest();
+
+		expect(Buffer.from(first.sha256)).toEqual(sha256);
+	});
+
+	it('should not get an unknown release version', async () => {
+		const { get_release_wasm_chunk } = actor;
+
+		await expect(
+			get_release_wasm_chunk({
+				segment_type: { Orbiter: null },
+				version: '100.0.0',
+				chunk_index: 0n
+			})
+		).rejects.toThrow('Release version not found.');
+	});
+
+	it('should not get an unknown chunk', async () => {
+		const { get_release_wasm_chunk } = actor;
+
+		await expect(
+			get_release_wasm_chunk({
+				segment_type: { MissionControl: null },
+				version: WASM_VERSIONS.mission_control,
+				chunk_index: 1000n
+			})
+		).rejects.toThrow(
+			`No chunk 1000 for /releases/mission_control-v${WASM_VERSIONS.mission_control}.wasm.gz.`
+		);
+	});
+});
@@ -80,9 +80,11 @@ describe('Console / Storage', () => {
 				token: toNullable(),
 				collection: '#dapp',
 				name: 'hello',
+				publish_at: toNullable(),
 				description: toNullable(),
 				encoding_type: toNullable(),
-				full_path: '/hello.html'
+				full_path: '/hello.html',
+				unpublish_at: toNullable()
 			};
 
 			await expect(init_asset_upload(key, 123n)).rejects.toThrow(CONTROLLER_ERROR_MSG);
@@ -141,6 +143,13 @@ describe('Console / Storage', () => {
 					redirects: toNullable(),
 					rewrites: [],
 					raw_access: toNullable(),
+					delegations: toNullable(),
+					max_response_body_size: toNullable(),
+					csp_nonce: toNullable(),
+					wildcard_domains: toNullable(),
+					locales: toNullable(),
+					weighted_rewrites: toNullable(),
+					list_limits: toNullable(),
 					max_memory_size: toNullable()
 				})
 			).rejects.toThrow(CONTROLLER_ERROR_MSG);
@@ -163,6 +172,13 @@ describe('Console / Storage', () => {
 				redirects: [],
 				rewrites: [],
 				raw_access: toNullable(),
+				delegations: toNullable(),
+				max_response_body_size: toNullable(),
+				csp_nonce: toNullable(),
+				wildcard_domains: toNullable(),
+				locales: toNullable(),
+				weighted_rewrites: toNullable(),
+				list_limits: toNullable(),
 				max_memory_size: toNullable()
 			};
 
@@ -240,7 +256,9 @@ describe('Console / Storage', () => {
 								encoding_type: [],
 								full_path,
 								name: 'hello.html',
-								token: toNullable()
+								publish_at: toNullable(),
+								token: toNullable(),
+								unpublish_at: toNullable()
 							},
 							unknownProposalId
 						)
@@ -258,7 +276,9 @@ describe('Console / Storage', () => {
 							encoding_type: [],
 							full_path,
 							name: 'hello.html',
-							token: toNullable()
+							publish_at: toNullable(),
+							token: toNullable(),
+							unpublish_at: toNullable()
 						},
 						proposalId
 					);
@@ -568,7 +588,9 @@ describe('Console / Storage', () => {
 								encoding_type: [],
 								full_path: `/releases/${filename}`,
 								name: filename,
-								token: toNullable()
+								publish_at: toNullable(),
+								token: toNullable(),
+								unpublish_at: toNullable()
 							},
 							proposalId
 						)
@@ -602,7 +624,9 @@ describe('Console / Storage', () => {
 						encoding_type: gzip ? ['gzip'] : [],
 						full_path: '/index.js',
 						name: 'index.gz',
-						token: toNullable()
+						publish_at: toNullable(),
+						token: toNullable(),
+						unpublish_at: toNullable()
 					},
 					proposalId
 				);
@@ -0,0 +1,215 @@
+import type { _SERVICE as ConsoleActor } from '$declarations/console/console.did';
+import { idlFactory as idlFactorConsole } from '$declarations/console/console.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/console-tests.constants';
+import { CONSOLE_WASM_PATH } from './utils/setup-tests.utils';
+
+describe('Console / Templates', () => {
+	let pic: PocketIc;
+	let actor: Actor<ConsoleActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const templateId = 'my-extension';
+	const version = '0.0.1';
+
+	const wasm = new Uint8Array([0, 97, 115, 109, 1, 0, 0, 0]);
+
+	const uploadTemplate = async () => {
+		const { init_proposal, init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+		const { submit_proposal, commit_proposal } = actor;
+
+		const [proposalId, _] = await init_proposal({
+			AssetsUpgrade: {
+				clear_existing_assets: toNullable()
+			}
+		});
+
+		const name = `${templateId}-v${version}.wasm.gz`;
+
+		const { batch_id } = await init_asset_upload(
+			{
+				collection: '#releases',
+				description: toNullable(),
+				encoding_type: ['identity'],
+				full_path: `/releases/templates/${name}`,
+				name,
+				publish_at: toNullable(),
+				token: toNullable(),
+				unpublish_at: toNullable()
+			},
+			proposalId
+		);
+
+		const { chunk_id } = await upload_asset_chunk({
+			batch_id,
+			content: wasm,
+			order_id: [0n]
+		});
+
+		await commit_asset_upload({
+			batch_id,
+			chunk_ids: [chunk_id],
+			headers: [['Content-Encoding', 'gzip']]
+		});
+
+		const [__, { sha256 }] = await submit_proposal(proposalId);
+
+		const definedSha256 = fromNullable(sha256);
+
+		assertNonNullish(definedSha256);
+
+		await commit_proposal({
+			proposal_id: proposalId,
+			sha256: definedSha256
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<ConsoleActor>({
+			idlFactory: idlFactorConsole,
+			wasm: CONSOLE_WASM_PATH,
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should not set a template version without wasm', async () => {
+			const { set_template_version } = actor;
+
+			await expect(
+				set_template_version(templateId, {
+					version,
+					description: toNullable('My extension'),
+					metadata: []
+				})
+			).rejects.toThrow(
+				`No asset found for /releases/templates/${templateId}-v${version}.wasm.gz`
+			);
+		});
+
+		it('should not set a template with an invalid id', async () => {
+			const { set_template_version } = actor;
+
+			await expect(
+				set_template_version('My_Extension', {
+					version,
+					description: toNullable(),
+					metadata: []
+				})
+			).rejects.toThrow(
+				'Template ID must contain only lowercase letters, digits and dashes.'
+			);
+		});
+
+		it('should set a template version', async () => {
+			await uploadTemplate();
+
+			const { set_template_version } = actor;
+
+			const template = await set_template_version(templateId, {
+				version,
+				description: toNullable('My extension'),
+				metadata: [['author', 'juno']]
+			});
+
+			expect(fromNullable(template.description)).toEqual('My extension');
+			expect(template.versions).toHaveLength(1);
+
+			const [[v, { full_path, size, metadata }]] = template.versions;
+
+			expect(v).toEqual(version);
+			expect(full_path).toEqual(`/releases/templates/${templateId}-v${version}.wasm.gz`);
+			expect(size).toEqual(BigInt(wasm.length));
+			expect(metadata).toEqual([['author', 'juno']]);
+		});
+
+		it('should not overwrite a published template version', async () => {
+			const { set_template_version } = actor;
+
+			await expect(
+				set_template_version(templateId, {
+					version,
+					description: toNullable(),
+					metadata: []
+				})
+			).rejects.toThrow('Template version already published.');
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should list templates', async () => {
+			const { list_templates } = actor;
+
+			const templates = await list_templates();
+
+			expect(templates).toHaveLength(1);
+
+			const [[id, { versions }]] = templates;
+
+			expect(id).toEqual(templateId);
+			expect(versions.map(([v]) => v)).toEqual([version]);
+		});
+
+		it('should get template wasm chunk', async () => {
+			const { get_template_wasm_chunk, list_templates } = actor;
+
+			const [[_, { versions }]] = await list_templates();
+			const [[__, { sha256 }]] = versions;
+
+			const chunk = await get_template_wasm_chunk(templateId, {
+				version,
+				chunk_index: 0n
+			});
+
+			expect(chunk.chunks_length).toEqual(1n);
+			expect(chunk.content).toEqual(wasm);
+			expect(chunk.sha256).toEqual(sha256);
+		});
+
+		it('should not get an unknown template version', async () => {
+			const { get_template_wasm_chunk } = actor;
+
+			await expect(
+				get_template_wasm_chunk(templateId, {
+					version: '1.0.0',
+					chunk_index: 0n
+				})
+			).rejects.toThrow('Template version not found.');
+		});
+
+		it('should not set a template version', async () => {
+			const { set_template_version } = actor;
+
+			await expect(
+				set_template_version(templateId, {
+					version: '0.0.2',
+					description: toNullable(),
+					metadata: []
+				})
+			).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
@@ -0,0 +1,72 @@
+import type { _SERVICE as MissionControlActor } from '$declarations/mission_control/mission_control.did';
+import { idlFactory as idlFactorMissionControl } from '$declarations/mission_control/mission_control.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { Principal } from '@dfinity/principal';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/mission-control-tests.constants';
+import { missionControlUserInitArgs } from './utils/mission-control-tests.utils';
+import { MISSION_CONTROL_WASM_PATH } from './utils/setup-tests.utils';
+
+describe('Mission Control - Clone', () => {
+	let pic: PocketIc;
+	let actor: Actor<MissionControlActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const sourceId = Principal.fromText('ck4tp-3iaaa-aaaal-ab7da-cai');
+
+	const options = {
+		name: toNullable('Staging'),
+		subnet_id: toNullable<Principal>(),
+		config: true,
+		db_collections: ['notes'],
+		storage_collections: []
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const userInitArgs = (): ArrayBuffer => missionControlUserInitArgs(controller.getPrincipal());
+
+		const { actor: c } = await pic.setupCanister<MissionControlActor>({
+			idlFactory: idlFactorMissionControl,
+			wasm: MISSION_CONTROL_WASM_PATH,
+			arg: userInitArgs(),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('user', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should not clone a satellite that is not attached to the mission control', async () => {
+			const { clone_satellite } = actor;
+
+			await expect(clone_satellite(sourceId, options)).rejects.toThrow(
+				'Satellite not found or not owned by this mission control.'
+			);
+		});
+	});
+
+	describe('unknown identity', () => {
+		beforeAll(() => {
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+		});
+
+		it('should not clone a satellite', async () => {
+			const { clone_satellite } = actor;
+
+			await expect(clone_satellite(sourceId, options)).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
@@ -0,0 +1,166 @@
+import type {
+	CyclesMonitoringStrategy,
+	_SERVICE as MissionControlActor
+} from '$declarations/mission_control/mission_control.did';
+import { idlFactory as idlFactorMissionControl } from '$declarations/mission_control/mission_control.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { type Actor, PocketIc } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import {
+	missionControlUserInitArgs,
+	setupMissionControlModules
+} from './utils/mission-control-tests.utils';
+import { tick } from './utils/pic-tests.utils';
+import { MISSION_CONTROL_WASM_PATH } from './utils/setup-tests.utils';
+
+describe('Mission Control - Digest', () => {
+	let pic: PocketIc;
+	let actor: Actor<MissionControlActor>;
+
+	let missionControlId: Principal;
+	let orbiterId: Principal;
+	let satelliteId: Principal;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const userInitArgs = (): ArrayBuffer => missionControlUserInitArgs(controller.getPrincipal());
+
+		const { actor: c, canisterId: mId } = await pic.setupCanister<MissionControlActor>({
+			idlFactory: idlFactorMissionControl,
+			wasm: MISSION_CONTROL_WASM_PATH,
+			arg: userInitArgs(),
+			sender: controller.getPrincipal()
+		});
+
+		missionControlId = mId;
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { orbiterId: oId, satelliteId: sId } = await setupMissionControlModules({
+			pic,
+			controller,
+			missionControlId
+		});
+
+		orbiterId = oId;
+		satelliteId = sId;
+
+		const { set_orbiter, set_satellite } = actor;
+
+		await set_orbiter(orbiterId, []);
+		await set_satellite(satelliteId, []);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('user', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should collect the usage of the modules', async () => {
+			const { get_usage_digest } = actor;
+
+			const digest = await get_usage_digest({ from: toNullable(), to: toNullable() });
+
+			expect(digest.from).toBeLessThan(digest.to);
+
+			expect(digest.satellites).toHaveLength(1);
+
+			const [[sId, satellite]] = digest.satellites;
+
+			expect(sId.toText()).toEqual(satelliteId.toText());
+
+			const memorySize = fromNullable(satellite.memory_size);
+
+			expect(memorySize?.heap).toBeGreaterThan(0n);
+
+			expect(digest.orbiters).toHaveLength(1);
+
+			const [[oId, orbiter]] = digest.orbiters;
+
+			expect(oId.toText()).toEqual(orbiterId.toText());
+			expect(fromNullable(orbiter.page_views)).toEqual(0);
+			expect(orbiter.top_pages).toHaveLength(0);
+		});
+
+		it('should not report cycles burned without monitoring history', async () => {
+			const { get_usage_digest } = actor;
+
+			const digest = await get_usage_digest({ from: toNullable(), to: toNullable() });
+
+			expect(fromNullable(digest.mission_control_cycles_burned)).toBeUndefined();
+
+			const [[_, satellite]] = digest.satellites;
+
+			expect(fromNullable(satellite.cycles_burned)).toBeUndefined();
+		});
+
+		it('should report the cycles burned according to the monitoring history', async () => {
+			const { update_and_start_monitoring, get_usage_digest } = actor;
+
+			const strategy: CyclesMonitoringStrategy = {
+				BelowThreshold: {
+					min_cycles: 500_000n,
+					fund_cycles: 100_000n
+				}
+			};
+
+			await update_and_start_monitoring({
+				cycles_config: [
+					{
+						satellites_strategy: toNullable({ ids: [satelliteId], strategy }),
+						orbiters_strategy: toNullable({ ids: [orbiterId], strategy }),
+						mission_control_strategy: toNullable(strategy)
+					}
+				]
+			});
+
+			await tick(pic);
+
+			await pic.advanceTime(30000);
+
+			await tick(pic);
+
+			const digest = await get_usage_digest({ from: toNullable(), to: toNullable() });
+
+			expect(fromNullable(digest.mission_control_cycles_burned)).not.toBeUndefined();
+
+			const [[_, satellite]] = digest.satellites;
+
+			expect(fromNullable(satellite.cycles_burned)).not.toBeUndefined();
+		});
+
+		it('should not collect the usage for an invalid period', async () => {
+			const { get_usage_digest } = actor;
+
+			await expect(
+				get_usage_digest({ from: toNullable(10n), to: toNullable(10n) })
+			).rejects.toThrow('The period of the usage digest is invalid.');
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not collect the usage digest', async () => {
+			const { get_usage_digest } = actor;
+
+			await expect(get_usage_digest({ from: toNullable(), to: toNullable() })).rejects.toThrow(
+				'Caller has no read permission for selected function of the mission control.'
+			);
+		});
+	});
+});
@@ -0,0 +1,205 @@
+import type {
+	_SERVICE as MissionControlActor,
+	ModulesControllersResults
+} from '$declarations/mission_control/mission_control.did';
+import { idlFactory as idlFactorMissionControl } from '$declarations/mission_control/mission_control.factory.did';
+import type { _SERVICE as OrbiterActor } from '$declarations/orbiter/orbiter.did';
+import { idlFactory as idlFactorOrbiter } from '$declarations/orbiter/orbiter.factory.did';
+import type { _SERVICE as SatelliteActor } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { toNullable } from '@dfinity/utils';
+import { type Actor, PocketIc } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/mission-control-tests.constants';
+import {
+	missionControlUserInitArgs,
+	setupMissionControlModules
+} from './utils/mission-control-tests.utils';
+import { MISSION_CONTROL_WASM_PATH } from './utils/setup-tests.utils';
+
+describe('Mission Control - Modules controllers', () => {
+	let pic: PocketIc;
+	let actor: Actor<MissionControlActor>;
+
+	let orbiterId: Principal;
+	let satelliteId: Principal;
+
+	let satelliteActor: Actor<SatelliteActor>;
+	let orbiterActor: Actor<OrbiterActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const ciKey = Ed25519KeyIdentity.generate();
+	const rotatedCiKey = Ed25519KeyIdentity.generate();
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const userInitArgs = (): ArrayBuffer => missionControlUserInitArgs(controller.getPrincipal());
+
+		const { actor: c, canisterId: missionControlId } = await pic.setupCanister<MissionControlActor>(
+			{
+				idlFactory: idlFactorMissionControl,
+				wasm: MISSION_CONTROL_WASM_PATH,
+				arg: userInitArgs(),
+				sender: controller.getPrincipal()
+			}
+		);
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { orbiterId: oId, satelliteId: sId } = await setupMissionControlModules({
+			pic,
+			controller,
+			missionControlId
+		});
+
+		orbiterId = oId;
+		satelliteId = sId;
+
+		const { set_orbiter, set_satellite } = actor;
+
+		await set_orbiter(orbiterId, []);
+		await set_satellite(satelliteId, []);
+
+		satelliteActor = pic.createActor<SatelliteActor>(idlFactorSatellite, satelliteId);
+		satelliteActor.setIdentity(controller);
+
+		orbiterActor = pic.createActor<OrbiterActor>(idlFactorOrbiter, orbiterId);
+		orbiterActor.setIdentity(controller);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	const testResults = ({ satellites, orbiters }: ModulesControllersResults) => {
+		expect(satellites).toHaveLength(1);
+		expect(orbiters).toHaveLength(1);
+
+		const [[sId, satelliteResult]] = satellites;
+		const [[oId, orbiterResult]] = orbiters;
+
+		expect(sId.toText()).toEqual(satelliteId.toText());
+		expect(oId.toText()).toEqual(orbiterId.toText());
+
+		expect('Ok' in satelliteResult).toBeTruthy();
+		expect('Ok' in orbiterResult).toBeTruthy();
+	};
+
+	const listControllerIds = async (): Promise<{
+		satelliteControllerIds: string[];
+		orbiterControllerIds: string[];
+	}> => {
+		const satelliteControllers = await satelliteActor.list_controllers();
+		const orbiterControllers = await orbiterActor.list_controllers();
+
+		return {
+			satelliteControllerIds: satelliteControllers.map(([id, _]) => id.toText()),
+			orbiterControllerIds: orbiterControllers.map(([id, _]) => id.toText())
+		};
+	};
+
+	it('should set a controller across modules', async () => {
+		const { update_modules_controllers } = actor;
+
+		const results = await update_modules_controllers({
+			Set: {
+				controllers: [ciKey.getPrincipal()],
+				controller: {
+					scope: { Write: null },
+					metadata: [['profile', 'ci']],
+					expires_at: toNullable()
+				}
+			}
+		});
+
+		testResults(results);
+
+		const { satelliteControllerIds, orbiterControllerIds } = await listControllerIds();
+
+		expect(satelliteControllerIds).toContain(ciKey.getPrincipal().toText());
+		expect(orbiterControllerIds).toContain(ciKey.getPrincipal().toText());
+	});
+
+	it('should rotate a controller across modules', async () => {
+		const { update_modules_controllers } = actor;
+
+		const results = await update_modules_controllers({
+			Rotate: {
+				from: ciKey.getPrincipal(),
+				to: rotatedCiKey.getPrincipal(),
+				controller: {
+					scope: { Write: null },
+					metadata: [['profile', 'ci']],
+					expires_at: toNullable()
+				}
+			}
+		});
+
+		testResults(results);
+
+		const { satelliteControllerIds, orbiterControllerIds } = await listControllerIds();
+
+		expect(satelliteControllerIds).not.toContain(ciKey.getPrincipal().toText());
+		expect(orbiterControllerIds).not.toContain(ciKey.getPrincipal().toText());
+
+		expect(satelliteControllerIds).toContain(rotatedCiKey.getPrincipal().toText());
+		expect(orbiterControllerIds).toContain(rotatedCiKey.getPrincipal().toText());
+	});
+
+	it('should delete a controller across modules', async () => {
+		const { update_modules_controllers } = actor;
+
+		const results = await update_modules_controllers({
+			Delete: {
+				controllers: [rotatedCiKey.getPrincipal()]
+			}
+		});
+
+		testResults(results);
+
+		const { satelliteControllerIds, orbiterControllerIds } = await listControllerIds();
+
+		expect(satelliteControllerIds).not.toContain(rotatedCiKey.getPrincipal().toText());
+		expect(orbiterControllerIds).not.toContain(rotatedCiKey.getPrincipal().toText());
+	});
+
+	it('should not rotate a controller to itself', async () => {
+		const { update_modules_controllers } = actor;
+
+		await expect(
+			update_modules_controllers({
+				Rotate: {
+					from: ciKey.getPrincipal(),
+					to: ciKey.getPrincipal(),
+					controller: {
+						scope: { Write: null },
+						metadata: [],
+						expires_at: toNullable()
+					}
+				}
+			})
+		).rejects.toThrow('The controller to rotate must differ from the new one.');
+	});
+
+	it('should not update modules controllers with an unknown identity', async () => {
+		actor.setIdentity(Ed25519KeyIdentity.generate());
+
+		const { update_modules_controllers } = actor;
+
+		await expect(
+			update_modules_controllers({
+				Delete: {
+					controllers: [ciKey.getPrincipal()]
+				}
+			})
+		).rejects.toThrow(CONTROLLER_ERROR_MSG);
+
+		actor.setIdentity(controller);
+	});
+});
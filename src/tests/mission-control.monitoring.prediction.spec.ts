@@ -0,0 +1,204 @@
+import type {
+	CyclesMonitoringStrategy,
+	_SERVICE as MissionControlActor,
+	MonitoringStartConfig
+} from '$declarations/mission_control/mission_control.did';
+import { idlFactory as idlFactorMissionControl } from '$declarations/mission_control/mission_control.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { type Actor, PocketIc } from '@hadronous/pic';
+import { assertNonNullish } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import {
+	missionControlUserInitArgs,
+	setupMissionControlModules
+} from './utils/mission-control-tests.utils';
+import { tick } from './utils/pic-tests.utils';
+import { MISSION_CONTROL_WASM_PATH } from './utils/setup-tests.utils';
+
+describe('Mission Control - Freeze prediction', () => {
+	let pic: PocketIc;
+	let actor: Actor<MissionControlActor>;
+
+	let missionControlId: Principal;
+	let orbiterId: Principal;
+	let satelliteId: Principal;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const horizonDays = 90n;
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const userInitArgs = (): ArrayBuffer => missionControlUserInitArgs(controller.getPrincipal());
+
+		const { actor: c, canisterId: mId } = await pic.setupCanister<MissionControlActor>({
+			idlFactory: idlFactorMissionControl,
+			wasm: MISSION_CONTROL_WASM_PATH,
+			arg: userInitArgs(),
+			sender: controller.getPrincipal()
+		});
+
+		missionControlId = mId;
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const { orbiterId: oId, satelliteId: sId } = await setupMissionControlModules({
+			pic,
+			controller,
+			missionControlId
+		});
+
+		orbiterId = oId;
+		satelliteId = sId;
+
+		const { set_orbiter, set_satellite } = actor;
+
+		await set_orbiter(orbiterId, []);
+		await set_satellite(satelliteId, []);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should have no freeze alert configured', async () => {
+		const { get_settings } = actor;
+
+		const settings = fromNullable(await get_settings());
+
+		expect(settings).toBeUndefined();
+	});
+
+	it('should set freeze alert', async () => {
+		const { set_freeze_alert, get_settings } = actor;
+
+		const result = await set_freeze_alert({ horizon_days: horizonDays });
+
+		expect(fromNullable(result.freeze_alert)?.horizon_days).toEqual(horizonDays);
+		expect(fromNullable(result.monitoring)).toBeUndefined();
+
+		const settings = fromNullable(await get_settings());
+
+		assertNonNullish(settings);
+
+		expect(fromNullable(settings.freeze_alert)?.horizon_days).toEqual(horizonDays);
+	});
+
+	describe('with monitoring', () => {
+		const strategy: CyclesMonitoringStrategy = {
+			BelowThreshold: {
+				min_cycles: 500_000n,
+				fund_cycles: 100_000n
+			}
+		};
+
+		beforeAll(async () => {
+			const { update_and_start_monitoring } = actor;
+
+			const config: MonitoringStartConfig = {
+				cycles_config: [
+					{
+						satellites_strategy: toNullable({
+							ids: [satelliteId],
+							strategy
+						}),
+						orbiters_strategy: toNullable({
+							ids: [orbiterId],
+							strategy
+						}),
+						mission_control_strategy: toNullable(strategy)
+					}
+				]
+			};
+
+			await update_and_start_monitoring(config);
+
+			for (let i = 0; i < 3; i++) {
+				await pic.advanceTime(30000);
+				await tick(pic);
+			}
+		});
+
+		it('should keep freeze alert when monitoring is configured', async () => {
+			const { get_settings } = actor;
+
+			const settings = fromNullable(await get_settings());
+
+			assertNonNullish(settings);
+
+			expect(fromNullable(settings.freeze_alert)?.horizon_days).toEqual(horizonDays);
+			expect(fromNullable(settings.monitoring)).not.toBeUndefined();
+		});
+
+		it('should expose freeze predictions in monitoring status', async () => {
+			const { get_monitoring_status } = actor;
+
+			const { cycles } = await get_monitoring_status();
+
+			const status = fromNullable(cycles);
+
+			assertNonNullish(status);
+
+			const ids = status.freeze_predictions.map(([id, _]) => id.toText());
+
+			expect(ids).toContain(missionControlId.toText());
+			expect(ids).toContain(satelliteId.toText());
+			expect(ids).toContain(orbiterId.toText());
+
+			status.freeze_predictions.forEach(([_, prediction]) => {
+				expect(prediction.cycles).toBeGreaterThan(0n);
+				expect(prediction.freezing_threshold_cycles).toBeGreaterThan(0n);
+				expect(prediction.predicted_at).toBeGreaterThan(0n);
+
+				const daysUntilFreeze = fromNullable(prediction.days_until_freeze);
+
+				expect(prediction.alert).toEqual(
+					daysUntilFreeze !== undefined && daysUntilFreeze < horizonDays
+				);
+			});
+		});
+
+		it('should list the freeze predictions', async () => {
+			const { get_freeze_predictions, get_monitoring_status } = actor;
+
+			const { cycles } = await get_monitoring_status();
+
+			const status = fromNullable(cycles);
+
+			assertNonNullish(status);
+
+			const predictions = await get_freeze_predictions();
+
+			expect(predictions).toHaveLength(status.freeze_predictions.length);
+		});
+
+		it('should keep the freeze predictions after an upgrade', async () => {
+			const { get_freeze_predictions } = actor;
+
+			const predictions = await get_freeze_predictions();
+
+			await pic.upgradeCanister({
+				canisterId: missionControlId,
+				wasm: MISSION_CONTROL_WASM_PATH,
+				sender: controller.getPrincipal()
+			});
+
+			const predictionsAfterUpgrade = await get_freeze_predictions();
+
+			expect(predictionsAfterUpgrade).toHaveLength(predictions.length);
+
+			predictions.forEach(([id, prediction]) => {
+				const predictionAfterUpgrade = predictionsAfterUpgrade.find(
+					([idAfterUpgrade, _]) => idAfterUpgrade.toText() === id.toText()
+				);
+
+				expect(predictionAfterUpgrade?.[1].predicted_at).toEqual(prediction.predicted_at);
+			});
+		});
+	});
+});
@@ -0,0 +1,86 @@
+import type { _SERVICE as MissionControlActor } from '$declarations/mission_control/mission_control.did';
+import { idlFactory as idlFactorMissionControl } from '$declarations/mission_control/mission_control.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/mission-control-tests.constants';
+import { missionControlUserInitArgs } from './utils/mission-control-tests.utils';
+import { MISSION_CONTROL_WASM_PATH } from './utils/setup-tests.utils';
+
+describe('Mission Control - Previews', () => {
+	let pic: PocketIc;
+	let actor: Actor<MissionControlActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const userInitArgs = (): ArrayBuffer => missionControlUserInitArgs(controller.getPrincipal());
+
+		const { actor: c } = await pic.setupCanister<MissionControlActor>({
+			idlFactory: idlFactorMissionControl,
+			wasm: MISSION_CONTROL_WASM_PATH,
+			arg: userInitArgs(),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('user', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should list no previews', async () => {
+			const { list_previews } = actor;
+
+			expect(await list_previews()).toEqual([]);
+		});
+
+		it.each([0n, 31n * 24n * 60n * 60n])(
+			'should not create a preview with a time to live of %s seconds',
+			async (ttl_seconds) => {
+				const { create_preview_satellite } = actor;
+
+				await expect(
+					create_preview_satellite({
+						ttl_seconds,
+						subnet_id: toNullable(),
+						name: toNullable('Preview')
+					})
+				).rejects.toThrow('The time to live of a preview must be between 1 second and 30 days.');
+			}
+		);
+	});
+
+	describe('unknown identity', () => {
+		beforeAll(() => {
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+		});
+
+		it('should not list the previews', async () => {
+			const { list_previews } = actor;
+
+			await expect(list_previews()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+
+		it('should not create a preview', async () => {
+			const { create_preview_satellite } = actor;
+
+			await expect(
+				create_preview_satellite({
+					ttl_seconds: 60n,
+					subnet_id: toNullable(),
+					name: toNullable()
+				})
+			).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
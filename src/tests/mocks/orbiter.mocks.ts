@@ -1,4 +1,5 @@
 import type {
+	SetErrorReport,
 	SetPageView,
 	SetPerformanceMetric,
 	SetTrackEvent
@@ -65,3 +66,15 @@ export const performanceMetricMock: SetPerformanceMetric = {
 		'Mozilla/5.0 (Macintosh; Intel Mac OS X 10.15; rv:125.0) Gecko/20100101 Firefox/125.0'
 	]
 };
+
+export const errorReportMock: SetErrorReport = {
+	session_id: sessionId,
+	message: "TypeError: Cannot read properties of undefined (reading 'id')",
+	stack_hash: '5f2b8c1e9a7d4b3c',
+	href: 'https://test.com',
+	satellite_id: satelliteIdMock,
+	version: [],
+	user_agent: [
+		'Mozilla/5.0 (Macintosh; Intel Mac OS X 10.15; rv:125.0) Gecko/20100101 Firefox/125.0'
+	]
+};
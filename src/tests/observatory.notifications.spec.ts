@@ -0,0 +1,281 @@
+import type { _SERVICE as ConsoleActor } from '$declarations/console/console.did';
+import { idlFactory as idlFactorConsole } from '$declarations/console/console.factory.did';
+import type {
+	_SERVICE as ObservatoryActor,
+	SetNotificationChannel
+} from '$declarations/observatory/observatory.did';
+import { idlFactory as idlFactorObservatory } from '$declarations/observatory/observatory.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { Principal } from '@dfinity/principal';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { ANONYMOUS_ERROR_MSG } from './constants/observatory-tests.constants';
+import { deploySegments } from './utils/console-tests.utils';
+import { tick } from './utils/pic-tests.utils';
+import { CONSOLE_WASM_PATH, OBSERVATORY_WASM_PATH } from './utils/setup-tests.utils';
+
+describe('Observatory / Notifications', () => {
+	let pic: PocketIc;
+	let actor: Actor<ObservatoryActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const slack: SetNotificationChannel = {
+		kind: { Slack: { url: 'https://hooks.slack.com/services/T000/B000/XXXX' } },
+		enabled: true,
+		version: toNullable()
+	};
+
+	const CONSOLE_ID = Principal.fromText('cokmz-oiaaa-aaaal-aby6q-cai');
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<ObservatoryActor>({
+			idlFactory: idlFactorObservatory,
+			wasm: OBSERVATORY_WASM_PATH,
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		// The observatory asserts the mission control of a cron tab with the console.
+		const { actor: consoleActor } = await pic.setupCanister<ConsoleActor>({
+			idlFactory: idlFactorConsole,
+			wasm: CONSOLE_WASM_PATH,
+			sender: controller.getPrincipal(),
+			targetCanisterId: CONSOLE_ID
+		});
+
+		consoleActor.setIdentity(controller);
+
+		await deploySegments(consoleActor);
+
+		consoleActor.setIdentity(user);
+
+		const { mission_control_id } = await consoleActor.init_user_mission_control_center();
+
+		await tick(pic);
+
+		const missionControlId = fromNullable(mission_control_id);
+
+		assertNonNullish(missionControlId);
+
+		actor.setIdentity(user);
+
+		await actor.set_cron_tab({
+			cron_jobs: {
+				metadata: [],
+				statuses: {
+					mission_control_cycles_threshold: [],
+					orbiters: [],
+					satellites: [],
+					enabled: false,
+					cycles_threshold: []
+				},
+				digest: []
+			},
+			mission_control_id: missionControlId,
+			version: []
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('user', () => {
+		beforeAll(() => {
+			actor.setIdentity(user);
+		});
+
+		it('should set notification channels', async () => {
+			const { set_notification_channel, list_notification_channels } = actor;
+
+			const channel = await set_notification_channel('team', slack);
+
+			expect(channel.enabled).toBeTruthy();
+			expect(fromNullable(channel.version)).toEqual(1n);
+
+			await set_notification_channel('ops', {
+				kind: { Telegram: { bot_token: '123456:ABC', chat_id: '-1001234' } },
+				enabled: false,
+				version: toNullable()
+			});
+
+			await set_notification_channel('pager', {
+				kind: {
+					Webhook: {
+						url: 'https://example.com/hooks/juno',
+						headers: [['Authorization', 'Bearer a-token']]
+					}
+				},
+				enabled: true,
+				version: toNullable()
+			});
+
+			const channels = await list_notification_channels();
+
+			expect(channels.map(([id]) => id).sort()).toEqual(['ops', 'pager', 'team']);
+		});
+
+		it('should not return the secrets of the channels', async () => {
+			const { list_notification_channels } = actor;
+
+			const channels = await list_notification_channels();
+
+			const kinds = Object.fromEntries(channels.map(([id, { kind }]) => [id, kind]));
+
+			expect(kinds.ops).toEqual({ Telegram: { chat_id: '-1001234' } });
+			expect(kinds.team).toEqual({ Slack: null });
+			expect(kinds.pager).toEqual({
+				Webhook: { url: 'https://example.com/hooks/juno', headers: ['Authorization'] }
+			});
+		});
+
+		it('should not report a failure for channels that were not notified', async () => {
+			const { list_notification_channels } = actor;
+
+			const channels = await list_notification_channels();
+
+			for (const [_, { last_error, last_failed_at }] of channels) {
+				expect(fromNullable(last_error)).toBeUndefined();
+				expect(fromNullable(last_failed_at)).toBeUndefined();
+			}
+		});
+
+		it('should disable a channel with its current version', async () => {
+			const { set_notification_channel } = actor;
+
+			await expect(
+				set_notification_channel('team', { ...slack, enabled: false })
+			).rejects.toThrow();
+
+			const channel = await set_notification_channel('team', {
+				...slack,
+				enabled: false,
+				version: toNullable(1n)
+			});
+
+			expect(channel.enabled).toBeFalsy();
+			expect(fromNullable(channel.version)).toEqual(2n);
+		});
+
+		it('should not set a channel without HTTPS', async () => {
+			const { set_notification_channel } = actor;
+
+			await expect(
+				set_notification_channel('insecure', {
+					...slack,
+					kind: { Slack: { url: 'http://hooks.slack.com/services/T000/B000/XXXX' } }
+				})
+			).rejects.toThrow('The URL of a notification channel must use HTTPS.');
+		});
+
+		it('should not set an incomplete Telegram channel', async () => {
+			const { set_notification_channel } = actor;
+
+			await expect(
+				set_notification_channel('telegram', {
+					...slack,
+					kind: { Telegram: { bot_token: '', chat_id: '-1001234' } }
+				})
+			).rejects.toThrow('The bot token and the chat ID of a Telegram channel cannot be empty.');
+		});
+
+		it('should not test an unknown channel', async () => {
+			const { test_notification_channel } = actor;
+
+			await expect(test_notification_channel('unknown')).rejects.toThrow(
+				'Notification channel not found.'
+			);
+		});
+
+		it('should not test the channels more than once per minute', async () => {
+			const { test_notification_channel } = actor;
+
+			await expect(test_notification_channel('unknown')).rejects.toThrow(
+				'A notification channel can be tested once per minute.'
+			);
+
+			await pic.advanceTime(60_000);
+			await tick(pic);
+
+			await expect(test_notification_channel('unknown')).rejects.toThrow(
+				'Notification channel not found.'
+			);
+		});
+
+		it('should delete a channel', async () => {
+			const { del_notification_channel, list_notification_channels } = actor;
+
+			await del_notification_channel('ops');
+
+			const channels = await list_notification_channels();
+
+			expect(channels.map(([id]) => id).sort()).toEqual(['pager', 'team']);
+
+			await expect(del_notification_channel('ops')).rejects.toThrow(
+				'Notification channel not found.'
+			);
+		});
+
+		it('should not list the channels of another user', async () => {
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+
+			const { list_notification_channels } = actor;
+
+			expect(await list_notification_channels()).toHaveLength(0);
+		});
+	});
+
+	describe('user without cron tab', () => {
+		beforeAll(() => {
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+		});
+
+		it('should not set a notification channel', async () => {
+			const { set_notification_channel } = actor;
+
+			await expect(set_notification_channel('team', slack)).rejects.toThrow(
+				'A cron tab must be set before the notification channels can be configured.'
+			);
+		});
+
+		it('should not test a notification channel', async () => {
+			const { test_notification_channel } = actor;
+
+			await expect(test_notification_channel('team')).rejects.toThrow(
+				'A cron tab must be set before the notification channels can be configured.'
+			);
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not set a notification channel', async () => {
+			const { set_notification_channel } = actor;
+
+			await expect(set_notification_channel('team', slack)).rejects.toThrow(ANONYMOUS_ERROR_MSG);
+		});
+
+		it('should not list the notification channels', async () => {
+			const { list_notification_channels } = actor;
+
+			await expect(list_notification_channels()).rejects.toThrow(ANONYMOUS_ERROR_MSG);
+		});
+
+		it('should not test a notification channel', async () => {
+			const { test_notification_channel } = actor;
+
+			await expect(test_notification_channel('team')).rejects.toThrow(ANONYMOUS_ERROR_MSG);
+		});
+	});
+});
@@ -48,7 +48,8 @@ describe('Observatory', () => {
 							satellites: [],
 							enabled: false,
 							cycles_threshold: []
-						}
+						},
+						digest: []
 					},
 					mission_control_id: Ed25519KeyIdentity.generate().getPrincipal(),
 					version: []
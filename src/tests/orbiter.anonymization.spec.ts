@@ -0,0 +1,166 @@
+import type {
+	_SERVICE as OrbiterActor,
+	OrbiterSatelliteFeatures,
+	PageView
+} from '$declarations/orbiter/orbiter.did';
+import { idlFactory as idlFactorOrbiter } from '$declarations/orbiter/orbiter.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { nanoid } from 'nanoid';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { errorReportMock, pageViewMock, satelliteIdMock } from './mocks/orbiter.mocks';
+import { ORBITER_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Orbiter - Anonymization', () => {
+	let pic: PocketIc;
+	let actor: Actor<OrbiterActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const HOUR_NS = 3_600_000_000_000n;
+	const DAY_NS = 24n * HOUR_NS;
+
+	const COLLECTED_AT = 1_700_000_123_456_789_000n;
+
+	const ANONYMIZED_USER_AGENT = 'Mozilla (Macintosh; Intel Mac OS X ; rv:) Gecko Firefox';
+
+	const allFeatures: OrbiterSatelliteFeatures = {
+		page_views: true,
+		performance_metrics: true,
+		track_events: true,
+		error_reports: [true]
+	};
+
+	const setConfig = async (anonymize: boolean) => {
+		actor.setIdentity(controller);
+
+		const { set_satellite_configs, list_satellite_configs } = actor;
+
+		const current = (await list_satellite_configs()).find(
+			([id, _]) => id.toText() === satelliteIdMock.toText()
+		);
+
+		await set_satellite_configs([
+			[
+				satelliteIdMock,
+				{
+					features: [allFeatures],
+					version: current?.[1].version ?? [],
+					sampling: [],
+					rate_config: [],
+					anonymize: [anonymize]
+				}
+			]
+		]);
+
+		actor.setIdentity(user);
+	};
+
+	const setPageView = async (collected_at: bigint): Promise<PageView> => {
+		const { set_page_view } = actor;
+
+		const result = await set_page_view({ key: nanoid(), collected_at }, pageViewMock);
+
+		if ('Err' in result) {
+			throw new Error(result.Err);
+		}
+
+		return result.Ok;
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<OrbiterActor>({
+			idlFactory: idlFactorOrbiter,
+			wasm: ORBITER_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('anonymized', () => {
+		beforeAll(async () => {
+			await setConfig(true);
+		});
+
+		it('should remove the versions of the user agent', async () => {
+			const { user_agent } = await setPageView(COLLECTED_AT);
+
+			expect(user_agent).toEqual([ANONYMIZED_USER_AGENT]);
+		});
+
+		it('should truncate the timestamps to the hour', async () => {
+			const { created_at, updated_at } = await setPageView(COLLECTED_AT);
+
+			expect(created_at % HOUR_NS).toEqual(0n);
+			expect(updated_at % HOUR_NS).toEqual(0n);
+
+			actor.setIdentity(controller);
+
+			const { get_page_views } = actor;
+
+			const pageViews = await get_page_views({
+				from: [],
+				to: [],
+				satellite_id: [satelliteIdMock]
+			});
+
+			expect(pageViews.length).toBeGreaterThan(0);
+
+			for (const [{ collected_at }, _] of pageViews) {
+				expect(collected_at).toEqual(COLLECTED_AT - (COLLECTED_AT % HOUR_NS));
+			}
+
+			actor.setIdentity(user);
+		});
+
+		it('should rotate the session id daily', async () => {
+			const { session_id: first } = await setPageView(COLLECTED_AT);
+			const { session_id: sameDay } = await setPageView(COLLECTED_AT + HOUR_NS);
+			const { session_id: nextDay } = await setPageView(COLLECTED_AT + DAY_NS);
+
+			expect(first).not.toEqual(pageViewMock.session_id);
+			expect(sameDay).toEqual(first);
+			expect(nextDay).not.toEqual(first);
+		});
+
+		it('should anonymize the error reports', async () => {
+			const { set_error_report } = actor;
+
+			const result = await set_error_report(
+				{ key: nanoid(), collected_at: COLLECTED_AT },
+				errorReportMock
+			);
+
+			if ('Err' in result) {
+				throw new Error(result.Err);
+			}
+
+			const { user_agent, session_id } = result.Ok;
+
+			expect(user_agent).toEqual([ANONYMIZED_USER_AGENT]);
+			expect(session_id).not.toEqual(errorReportMock.session_id);
+		});
+	});
+
+	describe('not anonymized', () => {
+		beforeAll(async () => {
+			await setConfig(false);
+		});
+
+		it('should record the analytics as collected', async () => {
+			const { user_agent, session_id } = await setPageView(COLLECTED_AT);
+
+			expect(user_agent).toEqual(pageViewMock.user_agent);
+			expect(session_id).toEqual(pageViewMock.session_id);
+		});
+	});
+});
@@ -0,0 +1,168 @@
+import type {
+	AnalyticKey,
+	GetAnalyticsBySatellite,
+	_SERVICE as OrbiterActor,
+	SetPageView,
+	SetTrackEvent
+} from '$declarations/orbiter/orbiter.did';
+import { idlFactory as idlFactorOrbiter } from '$declarations/orbiter/orbiter.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { Principal } from '@dfinity/principal';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { nanoid } from 'nanoid';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { pageViewMock, satelliteIdMock, trackEventMock } from './mocks/orbiter.mocks';
+import { ORBITER_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Orbiter - By satellite', () => {
+	let pic: PocketIc;
+	let actor: Actor<OrbiterActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const otherSatelliteId = Principal.fromText('dgk4v-yaaaa-aaaal-ab7aq-cai');
+
+	const filter: GetAnalyticsBySatellite = {
+		from: [],
+		to: [],
+		satellite_ids: [satelliteIdMock, otherSatelliteId]
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<OrbiterActor>({
+			idlFactory: idlFactorOrbiter,
+			wasm: ORBITER_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_satellite_configs, set_page_views, set_track_events } = actor;
+
+		await set_satellite_configs(
+			[satelliteIdMock, otherSatelliteId].map((satelliteId) => [
+				satelliteId,
+				{
+					version: [],
+					features: [
+						{
+							page_views: true,
+							performance_metrics: true,
+							track_events: true,
+							error_reports: []
+						}
+					],
+					sampling: [],
+					rate_config: [],
+					anonymize: []
+				}
+			])
+		);
+
+		const otherPageView: SetPageView = {
+			...pageViewMock,
+			satellite_id: otherSatelliteId,
+			session_id: nanoid()
+		};
+
+		const pageViews: [AnalyticKey, SetPageView][] = [
+			[{ key: nanoid(), collected_at: 123n }, pageViewMock],
+			[{ key: nanoid(), collected_at: 124n }, pageViewMock],
+			[{ key: nanoid(), collected_at: 125n }, otherPageView]
+		];
+
+		await set_page_views(pageViews);
+
+		const otherTrackEvent: SetTrackEvent = {
+			...trackEventMock,
+			satellite_id: otherSatelliteId
+		};
+
+		const trackEvents: [AnalyticKey, SetTrackEvent][] = [
+			[{ key: nanoid(), collected_at: 123n }, trackEventMock],
+			[{ key: nanoid(), collected_at: 124n }, otherTrackEvent],
+			[{ key: nanoid(), collected_at: 125n }, otherTrackEvent]
+		];
+
+		await set_track_events(trackEvents);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should aggregate the page views of several satellites', async () => {
+		const { get_page_views_analytics_metrics_by_satellite } = actor;
+
+		const { total, satellites } = await get_page_views_analytics_metrics_by_satellite(filter);
+
+		expect(total.total_page_views).toEqual(3);
+		expect(total.unique_sessions).toEqual(2n);
+
+		const breakdown = satellites.map(([id, { total_page_views }]) => [
+			id.toText(),
+			total_page_views
+		]);
+
+		expect(breakdown).toEqual([
+			[satelliteIdMock.toText(), 2],
+			[otherSatelliteId.toText(), 1]
+		]);
+	});
+
+	it('should aggregate the track events of several satellites', async () => {
+		const { get_track_events_analytics_by_satellite } = actor;
+
+		const { total, satellites } = await get_track_events_analytics_by_satellite(filter);
+
+		expect(total.total).toEqual([['my_event', 3]]);
+
+		expect(satellites.map(([id, { total }]) => [id.toText(), total])).toEqual([
+			[satelliteIdMock.toText(), [['my_event', 1]]],
+			[otherSatelliteId.toText(), [['my_event', 2]]]
+		]);
+	});
+
+	it('should account a satellite provided twice only once', async () => {
+		const { get_page_views_analytics_metrics_by_satellite } = actor;
+
+		const { total, satellites } = await get_page_views_analytics_metrics_by_satellite({
+			...filter,
+			satellite_ids: [satelliteIdMock, satelliteIdMock]
+		});
+
+		expect(total.total_page_views).toEqual(2);
+		expect(satellites).toHaveLength(1);
+	});
+
+	it('should not aggregate without satellites', async () => {
+		const { get_page_views_analytics_top_10_by_satellite } = actor;
+
+		await expect(
+			get_page_views_analytics_top_10_by_satellite({ ...filter, satellite_ids: [] })
+		).rejects.toThrow('At least one satellite must be provided.');
+	});
+
+	it('should not aggregate too many satellites', async () => {
+		const { get_page_views_analytics_clients_by_satellite } = actor;
+
+		await expect(
+			get_page_views_analytics_clients_by_satellite({
+				...filter,
+				satellite_ids: Array.from({ length: 11 }, () => satelliteIdMock)
+			})
+		).rejects.toThrow('Too many satellites provided. Maximum 10.');
+	});
+
+	it('should not aggregate for a caller that is not a controller', async () => {
+		actor.setIdentity(Ed25519KeyIdentity.generate());
+
+		const { get_error_reports_analytics_by_satellite } = actor;
+
+		await expect(get_error_reports_analytics_by_satellite(filter)).rejects.toThrow();
+	});
+});
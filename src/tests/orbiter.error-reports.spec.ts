@@ -0,0 +1,194 @@
+import type {
+	AnalyticKey,
+	GetAnalytics,
+	_SERVICE as OrbiterActor,
+	SetErrorReport
+} from '$declarations/orbiter/orbiter.did';
+import { idlFactory as idlFactorOrbiter } from '$declarations/orbiter/orbiter.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { nanoid } from 'nanoid';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { errorReportMock, satelliteIdMock } from './mocks/orbiter.mocks';
+import { ORBITER_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Orbiter - Error reports', () => {
+	let pic: PocketIc;
+	let actor: Actor<OrbiterActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const filter: GetAnalytics = {
+		from: [],
+		to: [],
+		satellite_id: [satelliteIdMock]
+	};
+
+	const setFeatures = async (error_reports: boolean) => {
+		actor.setIdentity(controller);
+
+		const { set_satellite_configs, list_satellite_configs } = actor;
+
+		const current = (await list_satellite_configs()).find(
+			([id, _]) => id.toText() === satelliteIdMock.toText()
+		);
+
+		await set_satellite_configs([
+			[
+				satelliteIdMock,
+				{
+					features: [
+						{
+							page_views: true,
+							performance_metrics: true,
+							track_events: true,
+							error_reports: [error_reports]
+						}
+					],
+					version: current?.[1].version ?? [],
+					sampling: [],
+					rate_config: [],
+					anonymize: []
+				}
+			]
+		]);
+
+		actor.setIdentity(user);
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<OrbiterActor>({
+			idlFactory: idlFactorOrbiter,
+			wasm: ORBITER_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('disabled', () => {
+		beforeAll(async () => {
+			await setFeatures(false);
+		});
+
+		it('should not set an error report', async () => {
+			const { set_error_report } = actor;
+
+			const result = await set_error_report({ key: nanoid(), collected_at: 123n }, errorReportMock);
+
+			expect(result).toEqual({ Err: 'error_error_reports_feature_disabled' });
+		});
+	});
+
+	describe('enabled', () => {
+		const otherError: SetErrorReport = {
+			...errorReportMock,
+			message: 'RangeError: Maximum call stack size exceeded',
+			stack_hash: '9c4e7a2b1f3d8e6a'
+		};
+
+		beforeAll(async () => {
+			await setFeatures(true);
+		});
+
+		it('should set error reports', async () => {
+			const { set_error_report, set_error_reports } = actor;
+
+			const result = await set_error_report({ key: nanoid(), collected_at: 123n }, errorReportMock);
+
+			expect('Ok' in result).toBeTruthy();
+
+			const errorReports: [AnalyticKey, SetErrorReport][] = [
+				[
+					{ key: nanoid(), collected_at: 123n },
+					{ ...errorReportMock, session_id: nanoid() }
+				],
+				[
+					{ key: nanoid(), collected_at: 456n },
+					{
+						...errorReportMock,
+						message: "TypeError: Cannot read properties of null (reading 'id')"
+					}
+				],
+				[{ key: nanoid(), collected_at: 123n }, otherError]
+			];
+
+			expect(await set_error_reports(errorReports)).toEqual({ Ok: null });
+		});
+
+		it('should not set an error report with a too long stack hash', async () => {
+			const { set_error_report } = actor;
+
+			const result = await set_error_report(
+				{ key: nanoid(), collected_at: 123n },
+				{ ...errorReportMock, stack_hash: 'a'.repeat(1024) }
+			);
+
+			expect('Err' in result).toBeTruthy();
+		});
+
+		it('should get the error reports', async () => {
+			actor.setIdentity(controller);
+
+			const { get_error_reports } = actor;
+
+			const errorReports = await get_error_reports(filter);
+
+			expect(errorReports).toHaveLength(4);
+		});
+
+		it('should group the error reports by stack hash', async () => {
+			actor.setIdentity(controller);
+
+			const { get_error_reports_analytics } = actor;
+
+			const { groups } = await get_error_reports_analytics(filter);
+
+			expect(groups).toHaveLength(2);
+
+			const [first, second] = groups;
+
+			expect(first.stack_hash).toEqual(errorReportMock.stack_hash);
+			expect(first.total).toEqual(3);
+			expect(first.unique_sessions).toEqual(2n);
+			expect(first.daily_total).toHaveLength(1);
+			expect(first.daily_total[0][1]).toEqual(3);
+			expect(first.message).toEqual("TypeError: Cannot read properties of null (reading 'id')");
+
+			expect(second.stack_hash).toEqual(otherError.stack_hash);
+			expect(second.total).toEqual(1);
+			expect(second.unique_sessions).toEqual(1n);
+			expect(second.message).toEqual(otherError.message);
+		});
+	});
+
+	describe('user', () => {
+		beforeAll(() => {
+			actor.setIdentity(user);
+		});
+
+		it('should not get the error reports', async () => {
+			const { get_error_reports } = actor;
+
+			await expect(get_error_reports(filter)).rejects.toThrow(
+				'Caller is not a controller of the orbiter.'
+			);
+		});
+
+		it('should not get the error reports analytics', async () => {
+			const { get_error_reports_analytics } = actor;
+
+			await expect(get_error_reports_analytics(filter)).rejects.toThrow(
+				'Caller is not a controller of the orbiter.'
+			);
+		});
+	});
+});
@@ -51,7 +51,8 @@ describe('Orbiter - No configuration', () => {
 				{
 					page_views: false,
 					performance_metrics: false,
-					track_events: false
+					track_events: false,
+					error_reports: []
 				}
 			]
 		}
@@ -70,7 +71,10 @@ describe('Orbiter - No configuration', () => {
 					satelliteIdMock,
 					{
 						version: config?.[1].version ?? [],
-						features: features as [] | [OrbiterSatelliteFeatures]
+						features: features as [] | [OrbiterSatelliteFeatures],
+						sampling: [],
+						rate_config: [],
+						anonymize: []
 					}
 				]
 			]);
@@ -0,0 +1,191 @@
+import type {
+	AnalyticKey,
+	_SERVICE as OrbiterActor,
+	OrbiterSatelliteFeatures,
+	SetPageView,
+	SetSatelliteConfig
+} from '$declarations/orbiter/orbiter.did';
+import { idlFactory as idlFactorOrbiter } from '$declarations/orbiter/orbiter.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { nanoid } from 'nanoid';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { pageViewMock, satelliteIdMock, trackEventMock } from './mocks/orbiter.mocks';
+import { ORBITER_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Orbiter - Sampling and throttling', () => {
+	let pic: PocketIc;
+	let actor: Actor<OrbiterActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const allFeatures: OrbiterSatelliteFeatures = {
+		page_views: true,
+		performance_metrics: true,
+		track_events: true,
+		error_reports: []
+	};
+
+	const setConfig = async (config: Omit<SetSatelliteConfig, 'features' | 'version'>) => {
+		actor.setIdentity(controller);
+
+		const { set_satellite_configs, list_satellite_configs } = actor;
+
+		const current = (await list_satellite_configs()).find(
+			([id, _]) => id.toText() === satelliteIdMock.toText()
+		);
+
+		await set_satellite_configs([
+			[
+				satelliteIdMock,
+				{
+					features: [allFeatures],
+					version: current?.[1].version ?? [],
+					...config
+				}
+			]
+		]);
+
+		actor.setIdentity(user);
+	};
+
+	const countPageViews = async (): Promise<number> => {
+		actor.setIdentity(controller);
+
+		const { get_page_views } = actor;
+
+		const pageViews = await get_page_views({
+			from: [],
+			to: [],
+			satellite_id: [satelliteIdMock]
+		});
+
+		actor.setIdentity(user);
+
+		return pageViews.length;
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<OrbiterActor>({
+			idlFactory: idlFactorOrbiter,
+			wasm: ORBITER_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('sampling', () => {
+		it('should not set a sampling rate above 100 percent', async () => {
+			await expect(
+				setConfig({
+					sampling: [
+						{
+							page_views: [150],
+							track_events: [],
+							performance_metrics: []
+						}
+					],
+					rate_config: [],
+					anonymize: []
+				})
+			).rejects.toThrow('Sampling rate cannot exceed 100 percent.');
+		});
+
+		it('should leave out the page views of the sessions that are not sampled', async () => {
+			await setConfig({
+				sampling: [
+					{
+						page_views: [0],
+						track_events: [],
+						performance_metrics: []
+					}
+				],
+				rate_config: [],
+				anonymize: []
+			});
+
+			const { set_page_view, set_page_views } = actor;
+
+			const result = await set_page_view({ key: nanoid(), collected_at: 123n }, pageViewMock);
+
+			expect(result).toEqual({ Err: 'error_page_views_sampled_out' });
+
+			const pagesViews: [AnalyticKey, SetPageView][] = [
+				[{ key: nanoid(), collected_at: 123n }, pageViewMock],
+				[{ key: nanoid(), collected_at: 123n }, pageViewMock]
+			];
+
+			expect(await set_page_views(pagesViews)).toEqual({ Ok: null });
+
+			expect(await countPageViews()).toEqual(0);
+		});
+
+		it('should still record the analytics that are not sampled', async () => {
+			const { set_track_event } = actor;
+
+			const result = await set_track_event({ key: nanoid(), collected_at: 123n }, trackEventMock);
+
+			expect('Ok' in result).toBeTruthy();
+		});
+
+		it('should record every session with a full sampling rate', async () => {
+			await setConfig({
+				sampling: [
+					{
+						page_views: [100],
+						track_events: [],
+						performance_metrics: []
+					}
+				],
+				rate_config: [],
+				anonymize: []
+			});
+
+			const { set_page_view } = actor;
+
+			const result = await set_page_view({ key: nanoid(), collected_at: 123n }, pageViewMock);
+
+			expect('Ok' in result).toBeTruthy();
+
+			expect(await countPageViews()).toEqual(1);
+		});
+	});
+
+	describe('throttling', () => {
+		it('should throttle the ingestion of a satellite', async () => {
+			await setConfig({
+				sampling: [],
+				rate_config: [
+					{
+						max_tokens: 2n,
+						time_per_token_ns: 3_600_000_000_000n
+					}
+				],
+				anonymize: []
+			});
+
+			const { set_page_view } = actor;
+
+			const results = [];
+
+			for (let i = 0; i < 4; i++) {
+				results.push(await set_page_view({ key: nanoid(), collected_at: 123n }, pageViewMock));
+			}
+
+			expect(results.filter((result) => 'Ok' in result)).toHaveLength(3);
+
+			expect(results[3]).toEqual({
+				Err: expect.stringContaining('Rate limit reached, try again later.')
+			});
+		});
+	});
+});
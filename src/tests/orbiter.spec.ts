@@ -52,7 +52,8 @@ describe('Orbiter', () => {
 			const allFeatures: OrbiterSatelliteFeatures = {
 				page_views: true,
 				performance_metrics: true,
-				track_events: true
+				track_events: true,
+				error_reports: []
 			};
 
 			beforeAll(() => {
@@ -68,7 +69,10 @@ describe('Orbiter', () => {
 							satelliteIdMock,
 							{
 								version: [],
-								features: [allFeatures]
+								features: [allFeatures],
+								sampling: [],
+								rate_config: [],
+								anonymize: []
 							}
 						]
 					])
@@ -84,7 +88,10 @@ describe('Orbiter', () => {
 							satelliteIdMock,
 							{
 								version: [],
-								features: [allFeatures]
+								features: [allFeatures],
+								sampling: [],
+								rate_config: [],
+								anonymize: []
 							}
 						]
 					])
@@ -100,7 +107,10 @@ describe('Orbiter', () => {
 							satelliteIdMock,
 							{
 								version: [123n],
-								features: [allFeatures]
+								features: [allFeatures],
+								sampling: [],
+								rate_config: [],
+								anonymize: []
 							}
 						]
 					])
@@ -134,7 +134,8 @@ describe('Orbiter upgrade- Configuration', () => {
 			expect(fromNullable(configs[0][1].features)).toEqual({
 				page_views: true,
 				performance_metrics: true,
-				track_events: true
+				track_events: true,
+				error_reports: []
 			});
 		});
 	});
@@ -172,7 +173,8 @@ describe('Orbiter upgrade- Configuration', () => {
 			expect(fromNullable(configs[0][1].features)).toEqual({
 				page_views: true,
 				performance_metrics: true,
-				track_events: true
+				track_events: true,
+				error_reports: []
 			});
 		});
 	});
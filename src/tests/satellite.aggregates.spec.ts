@@ -0,0 +1,265 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { fromArray, toArray } from '@junobuild/utils';
+import { nanoid } from 'nanoid';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite aggregates', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const COMMENTS_COLLECTION = 'comments';
+	const STATS_COLLECTION = 'stats';
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const setRule: SetRule = {
+			memory: toNullable(),
+			max_size: toNullable(),
+			max_capacity: toNullable(),
+			read: { Public: null },
+			mutable_permissions: toNullable(),
+			write: { Managed: null },
+			version: toNullable(),
+			rate_config: toNullable(),
+			user_quota: toNullable(),
+			collation: toNullable(),
+			retention: toNullable()
+		};
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, COMMENTS_COLLECTION, setRule);
+		await set_rule({ Db: null }, STATS_COLLECTION, {
+			...setRule,
+			write: { Controllers: null }
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	const postId = nanoid();
+	const otherPostId = nanoid();
+
+	const getStats = async (
+		key: string
+	): Promise<{ count: number; sums: Record<string, number> } | undefined> => {
+		const { get_doc } = actor;
+
+		const doc = fromNullable(await get_doc(STATS_COLLECTION, key));
+
+		return doc === undefined ? undefined : await fromArray(doc.data);
+	};
+
+	const setComment = async ({
+		key,
+		post_id,
+		likes,
+		version
+	}: {
+		key: string;
+		post_id: string;
+		likes: number;
+		version?: bigint;
+	}) => {
+		const { set_doc } = actor;
+
+		return set_doc(COMMENTS_COLLECTION, key, {
+			attachments: toNullable(),
+			data: await toArray({ post_id, likes }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable(version)
+		});
+	};
+
+	describe('config', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should throw if parent field is empty', async () => {
+			const { set_db_config } = actor;
+
+			await expect(
+				set_db_config({
+					proof_of_work: toNullable(),
+					list_limits: toNullable(),
+					aggregates: [
+						[
+							{
+								collection: COMMENTS_COLLECTION,
+								parent_field: '',
+								target_collection: STATS_COLLECTION,
+								sum_fields: toNullable()
+							}
+						]
+					],
+					max_memory_size: toNullable(),
+					history_collections: toNullable(),
+					views: toNullable(),
+					attachments: toNullable(),
+					http_collections: toNullable()
+				})
+			).rejects.toThrow('Aggregate parent field cannot be empty.');
+		});
+
+		it('should throw if target is the aggregated collection', async () => {
+			const { set_db_config } = actor;
+
+			await expect(
+				set_db_config({
+					proof_of_work: toNullable(),
+					list_limits: toNullable(),
+					aggregates: [
+						[
+							{
+								collection: COMMENTS_COLLECTION,
+								parent_field: 'post_id',
+								target_collection: COMMENTS_COLLECTION,
+								sum_fields: toNullable()
+							}
+						]
+					],
+					max_memory_size: toNullable(),
+					history_collections: toNullable(),
+					views: toNullable(),
+					attachments: toNullable(),
+					http_collections: toNullable()
+				})
+			).rejects.toThrow('Aggregate target collection must differ from the aggregated collection.');
+		});
+
+		it('should throw if target collection does not exist', async () => {
+			const { set_db_config } = actor;
+
+			await expect(
+				set_db_config({
+					proof_of_work: toNullable(),
+					list_limits: toNullable(),
+					aggregates: [
+						[
+							{
+								collection: COMMENTS_COLLECTION,
+								parent_field: 'post_id',
+								target_collection: 'unknown',
+								sum_fields: toNullable()
+							}
+						]
+					],
+					max_memory_size: toNullable(),
+					history_collections: toNullable(),
+					views: toNullable(),
+					attachments: toNullable(),
+					http_collections: toNullable()
+				})
+			).rejects.toThrow('Collection "unknown" not found in Datastore.');
+		});
+
+		it('should set aggregates', async () => {
+			const { set_db_config } = actor;
+
+			await set_db_config({
+				proof_of_work: toNullable(),
+				list_limits: toNullable(),
+				aggregates: [
+					[
+						{
+							collection: COMMENTS_COLLECTION,
+							parent_field: 'post_id',
+							target_collection: STATS_COLLECTION,
+							sum_fields: [['likes']]
+						}
+					]
+				],
+				max_memory_size: toNullable(),
+				history_collections: toNullable(),
+				views: toNullable(),
+				attachments: toNullable(),
+				http_collections: toNullable()
+			});
+		});
+	});
+
+	describe('user', () => {
+		const key = nanoid();
+
+		beforeAll(() => {
+			actor.setIdentity(user);
+		});
+
+		it('should count new comments', async () => {
+			await setComment({ key, post_id: postId, likes: 2 });
+			await setComment({ key: nanoid(), post_id: postId, likes: 3 });
+
+			expect(await getStats(postId)).toEqual({ count: 2, sums: { likes: 5 } });
+		});
+
+		it('should update sums when a comment is updated', async () => {
+			await setComment({ key, post_id: postId, likes: 10, version: 1n });
+
+			expect(await getStats(postId)).toEqual({ count: 2, sums: { likes: 13 } });
+		});
+
+		it('should move a comment to another parent', async () => {
+			await setComment({ key, post_id: otherPostId, likes: 10, version: 2n });
+
+			expect(await getStats(postId)).toEqual({ count: 1, sums: { likes: 3 } });
+			expect(await getStats(otherPostId)).toEqual({ count: 1, sums: { likes: 10 } });
+		});
+
+		it('should decrement and remove aggregate on delete', async () => {
+			const { del_doc } = actor;
+
+			await del_doc(COMMENTS_COLLECTION, key, { version: [3n] });
+
+			expect(await getStats(otherPostId)).toBeUndefined();
+			expect(await getStats(postId)).toEqual({ count: 1, sums: { likes: 3 } });
+		});
+
+		it('should sum decimals without rounding errors', async () => {
+			const decimalPostId = nanoid();
+
+			await setComment({ key: nanoid(), post_id: decimalPostId, likes: 0.1 });
+			await setComment({ key: nanoid(), post_id: decimalPostId, likes: 0.2 });
+
+			expect(await getStats(decimalPostId)).toEqual({ count: 2, sums: { likes: 0.3 } });
+		});
+
+		it('should not allow user to write aggregates', async () => {
+			const { set_doc } = actor;
+
+			await expect(
+				set_doc(STATS_COLLECTION, postId, {
+					attachments: toNullable(),
+					data: await toArray({ count: 100, sums: {} }),
+					description: toNullable(),
+					publish_at: toNullable(),
+					unpublish_at: toNullable(),
+					version: toNullable()
+				})
+			).rejects.toThrow('Cannot write.');
+		});
+	});
+});
@@ -0,0 +1,321 @@
+import type { _SERVICE as OrbiterActor } from '$declarations/orbiter/orbiter.did';
+import { idlFactory as idlFactorOrbiter } from '$declarations/orbiter/orbiter.factory.did';
+import type { _SERVICE as SatelliteActor } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { nanoid } from 'nanoid';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_ADMIN_ERROR_MSG } from './constants/satellite-tests.constants';
+import {
+	ORBITER_WASM_PATH,
+	SATELLITE_WASM_PATH,
+	controllersInitArgs
+} from './utils/setup-tests.utils';
+
+describe('Satellite analytics', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+	let orbiterActor: Actor<OrbiterActor>;
+
+	let satelliteId: Principal;
+	let orbiterId: Principal;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c, canisterId } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		satelliteId = canisterId;
+
+		const { actor: o, canisterId: oId } = await pic.setupCanister<OrbiterActor>({
+			idlFactory: idlFactorOrbiter,
+			wasm: ORBITER_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		orbiterActor = o;
+		orbiterActor.setIdentity(controller);
+
+		orbiterId = oId;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	const sessionId = nanoid();
+
+	const pageViewBody = () =>
+		new TextEncoder().encode(
+			JSON.stringify({
+				key: {
+					key: nanoid(),
+					collected_at: 1230
+				},
+				page_view: {
+					title: 'Test',
+					href: 'https://test.com',
+					referrer: null,
+					device: {
+						inner_width: 600,
+						inner_height: 300
+					},
+					time_zone: 'Europe/Zurich',
+					user_agent: null,
+					session_id: sessionId,
+					version: null
+				}
+			})
+		);
+
+	const userAgent =
+		'Mozilla/5.0 (Macintosh; Intel Mac OS X 10.15; rv:125.0) Gecko/20100101 Firefox/125.0';
+
+	describe('admin', () => {
+		it('should not upgrade analytics requests if no orbiter is configured', async () => {
+			const { http_request } = actor;
+
+			const { status_code, upgrade } = await http_request({
+				body: pageViewBody(),
+				certificate_version: toNullable(),
+				headers: [],
+				method: 'POST',
+				url: '/_juno/analytics/page-view'
+			});
+
+			expect(status_code).toEqual(404);
+			expect(fromNullable(upgrade)).toBeUndefined();
+		});
+
+		it('should not forward if no orbiter is configured', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code } = await http_request_update({
+				body: pageViewBody(),
+				certificate_version: toNullable(),
+				headers: [],
+				method: 'POST',
+				url: '/_juno/analytics/page-view'
+			});
+
+			expect(status_code).toEqual(404);
+		});
+
+		it('should set analytics config', async () => {
+			const { set_analytics_config, get_analytics_config } = actor;
+
+			await set_analytics_config({
+				orbiter_id: [orbiterId],
+				rate_config: []
+			});
+
+			const config = await get_analytics_config();
+
+			expect(fromNullable(config)?.orbiter_id).toEqual([orbiterId]);
+		});
+
+		it('should upgrade analytics requests', async () => {
+			const { http_request } = actor;
+
+			const { status_code, upgrade } = await http_request({
+				body: pageViewBody(),
+				certificate_version: toNullable(),
+				headers: [],
+				method: 'POST',
+				url: '/_juno/analytics/page-view'
+			});
+
+			expect(status_code).toEqual(200);
+			expect(fromNullable(upgrade)).toBeTruthy();
+		});
+
+		it('should reject a payload too large without upgrading', async () => {
+			const { http_request, http_request_update } = actor;
+
+			const body = new Uint8Array(16 * 1024 + 1);
+
+			const { status_code, upgrade } = await http_request({
+				body,
+				certificate_version: toNullable(),
+				headers: [],
+				method: 'POST',
+				url: '/_juno/analytics/page-view'
+			});
+
+			expect(status_code).toEqual(413);
+			expect(fromNullable(upgrade)).toBeUndefined();
+
+			const { status_code: statusCodeUpdate } = await http_request_update({
+				body,
+				certificate_version: toNullable(),
+				headers: [],
+				method: 'POST',
+				url: '/_juno/analytics/page-view'
+			});
+
+			expect(statusCodeUpdate).toEqual(413);
+		});
+
+		it('should reject an invalid rate config', async () => {
+			const { set_analytics_config } = actor;
+
+			await expect(
+				set_analytics_config({
+					orbiter_id: [orbiterId],
+					rate_config: [{ max_tokens: 0n, time_per_token_ns: 1n }]
+				})
+			).rejects.toThrow('The maximal number of tokens of a rate limit must be greater than zero.');
+		});
+
+		it('should reject unknown endpoints', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code } = await http_request_update({
+				body: pageViewBody(),
+				certificate_version: toNullable(),
+				headers: [],
+				method: 'POST',
+				url: '/_juno/analytics/unknown'
+			});
+
+			expect(status_code).toEqual(404);
+		});
+
+		it('should reject invalid payload', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code } = await http_request_update({
+				body: new TextEncoder().encode('{ "hello": "world" }'),
+				certificate_version: toNullable(),
+				headers: [],
+				method: 'POST',
+				url: '/_juno/analytics/page-view'
+			});
+
+			expect(status_code).toEqual(400);
+		});
+
+		it('should report orbiter errors', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code } = await http_request_update({
+				body: pageViewBody(),
+				certificate_version: toNullable(),
+				headers: [],
+				method: 'POST',
+				url: '/_juno/analytics/page-view'
+			});
+
+			expect(status_code).toEqual(502);
+		});
+
+		it('should forward page view to the orbiter', async () => {
+			const { set_satellite_configs, get_page_views } = orbiterActor;
+
+			await set_satellite_configs([
+				[
+					satelliteId,
+					{
+						version: [],
+						features: [
+							{
+								page_views: true,
+								performance_metrics: false,
+								track_events: false,
+								error_reports: []
+							}
+						],
+						sampling: [],
+						rate_config: [],
+						anonymize: []
+					}
+				]
+			]);
+
+			const { http_request_update } = actor;
+
+			const { status_code } = await http_request_update({
+				body: pageViewBody(),
+				certificate_version: toNullable(),
+				headers: [['User-Agent', userAgent]],
+				method: 'POST',
+				url: '/_juno/analytics/page-view'
+			});
+
+			expect(status_code).toEqual(202);
+
+			const pageViews = await get_page_views({
+				satellite_id: [satelliteId],
+				from: [],
+				to: []
+			});
+
+			expect(pageViews).toHaveLength(1);
+
+			const [[_, pageView]] = pageViews;
+
+			expect(pageView.satellite_id.toText()).toEqual(satelliteId.toText());
+			expect(pageView.session_id).toEqual(sessionId);
+			expect(fromNullable(pageView.user_agent)).toEqual(userAgent);
+		});
+
+		it('should limit the rate of the pings', async () => {
+			const { set_analytics_config, http_request_update } = actor;
+
+			await set_analytics_config({
+				orbiter_id: [orbiterId],
+				rate_config: [{ max_tokens: 1n, time_per_token_ns: 3_600_000_000_000n }]
+			});
+
+			const ping = () =>
+				http_request_update({
+					body: pageViewBody(),
+					certificate_version: toNullable(),
+					headers: [],
+					method: 'POST',
+					url: '/_juno/analytics/page-view'
+				});
+
+			await ping();
+			await ping();
+
+			const { status_code } = await ping();
+
+			expect(status_code).toEqual(429);
+		});
+	});
+
+	describe('admin guard', () => {
+		beforeAll(() => {
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+		});
+
+		it('should throw errors on setting analytics config', async () => {
+			const { set_analytics_config } = actor;
+
+			await expect(set_analytics_config({ orbiter_id: [], rate_config: [] })).rejects.toThrow(
+				SATELLITE_ADMIN_ERROR_MSG
+			);
+		});
+
+		it('should throw errors on getting analytics config', async () => {
+			const { get_analytics_config } = actor;
+
+			await expect(get_analytics_config()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+	});
+});
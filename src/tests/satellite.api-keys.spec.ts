@@ -0,0 +1,252 @@
+import type {
+	HttpRequest,
+	_SERVICE as SatelliteActor,
+	SetApiKey,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_ADMIN_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - API keys', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const setApiKey: SetApiKey = {
+		collections: ['orders'],
+		description: toNullable('Billing backend'),
+		expires_at: toNullable()
+	};
+
+	const request = ({ url, secret }: { url: string; secret?: string }): HttpRequest => ({
+		body: [],
+		certificate_version: toNullable(2),
+		headers: secret !== undefined ? [['Authorization', `Bearer ${secret}`]] : [],
+		method: 'GET',
+		url
+	});
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		// Initializes the random number generator used to create the secrets
+		await pic.tick();
+
+		const { set_rule, set_doc } = actor;
+
+		await set_rule({ Db: null }, 'orders', setRule);
+		await set_rule({ Db: null }, 'invoices', setRule);
+		await set_rule({ Db: null }, 'secrets', { ...setRule, read: { Private: null } });
+
+		for (const collection of ['orders', 'invoices', 'secrets']) {
+			await set_doc(collection, 'first', {
+				attachments: toNullable(),
+				data: await toArray({ amount: 42 }),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+		}
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		let secret: string;
+		let id: string;
+
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should not create an API key without collection', async () => {
+			const { create_api_key } = actor;
+
+			await expect(create_api_key({ ...setApiKey, collections: [] })).rejects.toThrow(
+				'API keys require at least one collection.'
+			);
+		});
+
+		it('should not create an API key for an unknown collection', async () => {
+			const { create_api_key } = actor;
+
+			await expect(create_api_key({ ...setApiKey, collections: ['unknown'] })).rejects.toThrow(
+				'API keys require existing datastore collections.'
+			);
+		});
+
+		it('should create an API key and keep only its hash', async () => {
+			const { create_api_key, list_api_keys } = actor;
+
+			const result = await create_api_key(setApiKey);
+
+			secret = result.secret;
+			id = result.id;
+
+			expect(secret).toHaveLength(64);
+			expect(id).not.toEqual(secret);
+
+			const keys = await list_api_keys();
+
+			expect(keys).toHaveLength(1);
+
+			const [[keyId, apiKey]] = keys;
+
+			expect(keyId).toEqual(id);
+			expect(apiKey.collections).toEqual(['orders']);
+			expect(apiKey).not.toHaveProperty('secret');
+		});
+
+		it('should read a protected document with the API key', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code, body } = await http_request_update(
+				request({ url: '/_juno/db/orders/first', secret })
+			);
+
+			expect(status_code).toEqual(200);
+
+			const doc = JSON.parse(new TextDecoder().decode(body as Uint8Array));
+
+			expect(doc.data).toEqual({ amount: 42 });
+		});
+
+		it('should not read a protected document without API key', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code } = await http_request_update(
+				request({ url: '/_juno/db/orders/first' })
+			);
+
+			expect(status_code).toEqual(404);
+		});
+
+		it('should not read a collection outside of the scope of the API key', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code } = await http_request_update(
+				request({ url: '/_juno/db/invoices/first', secret })
+			);
+
+			expect(status_code).toEqual(403);
+		});
+
+		it('should not read a private document with an API key', async () => {
+			const { create_api_key, http_request_update } = actor;
+
+			const { secret: privateSecret } = await create_api_key({
+				...setApiKey,
+				collections: ['secrets']
+			});
+
+			const { status_code } = await http_request_update(
+				request({ url: '/_juno/db/secrets/first', secret: privateSecret })
+			);
+
+			expect(status_code).toEqual(404);
+		});
+
+		it('should not read with an unknown API key', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code } = await http_request_update(
+				request({ url: '/_juno/db/orders/first', secret: 'unknown' })
+			);
+
+			expect(status_code).toEqual(401);
+		});
+
+		it('should not read with an expired API key', async () => {
+			const { create_api_key, http_request_update } = actor;
+
+			const now = BigInt((await pic.getTime()) * 1_000_000);
+
+			const { secret: expiring } = await create_api_key({
+				...setApiKey,
+				expires_at: toNullable(now + 60_000_000_000n)
+			});
+
+			await pic.advanceTime(120_000);
+			await pic.tick();
+
+			const { status_code } = await http_request_update(
+				request({ url: '/_juno/db/orders/first', secret: expiring })
+			);
+
+			expect(status_code).toEqual(401);
+		});
+
+		it('should revoke an API key', async () => {
+			const { del_api_key, http_request_update } = actor;
+
+			await del_api_key(id);
+
+			const { status_code } = await http_request_update(
+				request({ url: '/_juno/db/orders/first', secret })
+			);
+
+			expect(status_code).toEqual(401);
+
+			await expect(del_api_key(id)).rejects.toThrow('API key not found.');
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not create an API key', async () => {
+			const { create_api_key } = actor;
+
+			await expect(create_api_key(setApiKey)).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should not list the API keys', async () => {
+			const { list_api_keys } = actor;
+
+			await expect(list_api_keys()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should not delete an API key', async () => {
+			const { del_api_key } = actor;
+
+			await expect(del_api_key('id')).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+	});
+});
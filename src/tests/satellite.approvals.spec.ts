@@ -0,0 +1,304 @@
+import type {
+	ApprovalsConfig,
+	Rule,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_ADMIN_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Approvals', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const proposer = Ed25519KeyIdentity.generate();
+	const approver = Ed25519KeyIdentity.generate();
+
+	const NOTES_COLLECTION = 'notes';
+	const DRAFTS_COLLECTION = 'drafts';
+
+	const config: ApprovalsConfig = {
+		enabled: true,
+		confirmation_window_ns: toNullable(60n * 1_000_000_000n)
+	};
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const getRule = async (collection: string): Promise<Rule | undefined> => {
+		const { list_rules } = actor;
+
+		const rules = await list_rules({ Db: null });
+
+		return rules.find(([key]) => key === collection)?.[1];
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs([proposer.getPrincipal(), approver.getPrincipal()]),
+			sender: proposer.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(proposer);
+
+		const { set_rule, set_approvals_config } = actor;
+
+		await set_rule({ Db: null }, NOTES_COLLECTION, setRule);
+		await set_rule({ Db: null }, DRAFTS_COLLECTION, setRule);
+
+		await set_approvals_config(config);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should not delete a collection without approval', async () => {
+		actor.setIdentity(proposer);
+
+		const { del_rule, del_docs } = actor;
+
+		const rule = await getRule(NOTES_COLLECTION);
+
+		await expect(
+			del_rule({ Db: null }, NOTES_COLLECTION, { version: rule?.version ?? [] })
+		).rejects.toThrow('The operation must be proposed by a controller and confirmed by another.');
+
+		await expect(del_docs(NOTES_COLLECTION)).rejects.toThrow(
+			'The operation must be proposed by a controller and confirmed by another.'
+		);
+	});
+
+	it('should not change the controllers, the permissions or the config without approval', async () => {
+		actor.setIdentity(proposer);
+
+		const {
+			set_controllers,
+			del_controllers,
+			del_filtered_docs,
+			set_rule,
+			get_config_bundle,
+			apply_config_bundle
+		} = actor;
+
+		const error = 'The operation must be proposed by a controller and confirmed by another.';
+
+		await expect(
+			set_controllers({
+				controllers: [Ed25519KeyIdentity.generate().getPrincipal()],
+				controller: {
+					metadata: [],
+					expires_at: toNullable(),
+					scope: { Admin: null }
+				}
+			})
+		).rejects.toThrow(error);
+
+		await expect(del_controllers({ controllers: [approver.getPrincipal()] })).rejects.toThrow(
+			error
+		);
+
+		await expect(
+			del_filtered_docs(DRAFTS_COLLECTION, {
+				matcher: toNullable(),
+				order: toNullable(),
+				owner: toNullable(),
+				paginate: toNullable()
+			})
+		).rejects.toThrow(error);
+
+		const rule = await getRule(DRAFTS_COLLECTION);
+
+		await expect(
+			set_rule({ Db: null }, DRAFTS_COLLECTION, {
+				...setRule,
+				write: { Public: null },
+				version: rule?.version ?? []
+			})
+		).rejects.toThrow(error);
+
+		await expect(apply_config_bundle(await get_config_bundle())).rejects.toThrow(error);
+	});
+
+	it('should update a collection without changing its permissions', async () => {
+		actor.setIdentity(proposer);
+
+		const { set_rule } = actor;
+
+		const rule = await getRule(DRAFTS_COLLECTION);
+
+		await set_rule({ Db: null }, DRAFTS_COLLECTION, {
+			...setRule,
+			max_capacity: toNullable(100),
+			version: rule?.version ?? []
+		});
+
+		expect(fromNullable((await getRule(DRAFTS_COLLECTION))?.max_capacity ?? [])).toEqual(100);
+	});
+
+	it('should set a controller once confirmed by another controller', async () => {
+		actor.setIdentity(proposer);
+
+		const { propose_action } = actor;
+
+		const controller = Ed25519KeyIdentity.generate().getPrincipal();
+
+		const id = await propose_action({
+			SetControllers: {
+				controllers: [controller],
+				controller: {
+					metadata: [],
+					expires_at: toNullable(),
+					scope: { Write: null }
+				}
+			}
+		});
+
+		actor.setIdentity(approver);
+
+		const { confirm_action, list_controllers } = actor;
+
+		await confirm_action(id);
+
+		const controllers = await list_controllers();
+
+		expect(controllers.find(([id]) => id.toText() === controller.toText())).not.toBeUndefined();
+	});
+
+	it('should not confirm an action proposed by the same controller', async () => {
+		actor.setIdentity(proposer);
+
+		const { propose_action, confirm_action } = actor;
+
+		const id = await propose_action({ DelDocs: { collection: NOTES_COLLECTION } });
+
+		await expect(confirm_action(id)).rejects.toThrow(
+			'A pending action cannot be confirmed by the controller that proposed it.'
+		);
+	});
+
+	it('should delete a collection once confirmed by another controller', async () => {
+		actor.setIdentity(proposer);
+
+		const { propose_action, list_pending_actions } = actor;
+
+		const id = await propose_action({
+			DelRule: {
+				collection: NOTES_COLLECTION,
+				rule: { version: (await getRule(NOTES_COLLECTION))?.version ?? [] },
+				rules_type: { Db: null }
+			}
+		});
+
+		const pending = await list_pending_actions();
+
+		const action = pending.find(([actionId]) => actionId === id);
+
+		expect(action?.[1].proposed_by.toText()).toEqual(proposer.getPrincipal().toText());
+
+		actor.setIdentity(approver);
+
+		const { confirm_action } = actor;
+
+		await confirm_action(id);
+
+		expect(await getRule(NOTES_COLLECTION)).toBeUndefined();
+
+		await expect(confirm_action(id)).rejects.toThrow('Pending action not found.');
+	});
+
+	it('should not confirm an expired action', async () => {
+		actor.setIdentity(proposer);
+
+		const { propose_action, list_pending_actions } = actor;
+
+		const id = await propose_action({
+			DelRule: {
+				collection: DRAFTS_COLLECTION,
+				rule: { version: (await getRule(DRAFTS_COLLECTION))?.version ?? [] },
+				rules_type: { Db: null }
+			}
+		});
+
+		await pic.advanceTime(2 * 60 * 1000);
+		await pic.tick();
+
+		expect((await list_pending_actions()).find(([actionId]) => actionId === id)).toBeUndefined();
+
+		actor.setIdentity(approver);
+
+		const { confirm_action } = actor;
+
+		await expect(confirm_action(id)).rejects.toThrow('The pending action has expired.');
+
+		expect(await getRule(DRAFTS_COLLECTION)).not.toBeUndefined();
+	});
+
+	it('should disable the approvals only with a confirmed action', async () => {
+		actor.setIdentity(proposer);
+
+		const { set_approvals_config, propose_action, get_approvals_config } = actor;
+
+		const disabled: ApprovalsConfig = { ...config, enabled: false };
+
+		await expect(set_approvals_config(disabled)).rejects.toThrow(
+			'The operation must be proposed by a controller and confirmed by another.'
+		);
+
+		const id = await propose_action({ SetApprovalsConfig: disabled });
+
+		actor.setIdentity(approver);
+
+		const { confirm_action } = actor;
+
+		await confirm_action(id);
+
+		expect(fromNullable(await get_approvals_config())?.enabled).toBeFalsy();
+
+		const { del_docs } = actor;
+
+		await del_docs(DRAFTS_COLLECTION);
+	});
+
+	it('should not propose an action once disabled', async () => {
+		actor.setIdentity(proposer);
+
+		const { propose_action } = actor;
+
+		await expect(propose_action({ DelDocs: { collection: DRAFTS_COLLECTION } })).rejects.toThrow(
+			'The approval of the controller operations is not enabled.'
+		);
+	});
+
+	it('should not propose an action as anonymous', async () => {
+		actor.setIdentity(new AnonymousIdentity());
+
+		const { propose_action } = actor;
+
+		await expect(propose_action({ DelDocs: { collection: DRAFTS_COLLECTION } })).rejects.toThrow(
+			SATELLITE_ADMIN_ERROR_MSG
+		);
+	});
+});
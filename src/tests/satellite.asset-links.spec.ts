@@ -0,0 +1,138 @@
+import type { _SERVICE as SatelliteActor } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import {
+	CONTROLLER_ERROR_MSG,
+	SATELLITE_ADMIN_ERROR_MSG
+} from './constants/satellite-tests.constants';
+import { tick } from './utils/pic-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Asset links', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const upload = async ({
+		full_path,
+		content,
+		type
+	}: {
+		full_path: string;
+		content: string;
+		type: string;
+	}) => {
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+		const file = await init_asset_upload({
+			collection: '#dapp',
+			description: toNullable(),
+			encoding_type: [],
+			full_path,
+			name: full_path.split('/').pop() ?? full_path,
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const chunk = await upload_asset_chunk({
+			batch_id: file.batch_id,
+			content: new TextEncoder().encode(content),
+			order_id: [0n]
+		});
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: [chunk.chunk_id],
+			headers: [['Content-Type', type]]
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		await upload({
+			full_path: '/index.html',
+			content:
+				'<html><head><link rel="stylesheet" href="/style.css"></head><body><a href="#top">Top</a><a href="https://juno.build">Juno</a><img src="./missing.png"></body></html>',
+			type: 'text/html'
+		});
+		await upload({
+			full_path: '/style.css',
+			content: 'body { background: url("/logo.png") }',
+			type: 'text/css'
+		});
+		await upload({ full_path: '/logo.png', content: 'logo', type: 'image/png' });
+		await upload({ full_path: '/unused.html', content: '<html>Unused</html>', type: 'text/html' });
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('controller', () => {
+		it('should not provide a report before an analysis', async () => {
+			const { get_asset_links_report } = actor;
+
+			const report = await get_asset_links_report();
+
+			expect(fromNullable(report)).toBeUndefined();
+		});
+
+		it('should report the orphans and the broken links', async () => {
+			const { analyze_asset_links, get_asset_links_report } = actor;
+
+			await analyze_asset_links();
+
+			await tick(pic);
+
+			const report = fromNullable(await get_asset_links_report());
+
+			expect(report).not.toBeUndefined();
+			expect(fromNullable(report?.completed_at ?? [])).not.toBeUndefined();
+
+			expect(report?.parsed_assets).toEqual(3n);
+
+			expect(report?.orphans).toContain('/unused.html');
+			expect(report?.orphans).not.toContain('/index.html');
+			expect(report?.orphans).not.toContain('/style.css');
+			expect(report?.orphans).not.toContain('/logo.png');
+
+			expect(report?.broken_links).toEqual([{ source: '/index.html', target: '/missing.png' }]);
+			expect(report?.broken_links_count).toEqual(1n);
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not analyze the links', async () => {
+			const { analyze_asset_links } = actor;
+
+			await expect(analyze_asset_links()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should not get the report', async () => {
+			const { get_asset_links_report } = actor;
+
+			await expect(get_asset_links_report()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
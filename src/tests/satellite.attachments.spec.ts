@@ -0,0 +1,213 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { tick } from './utils/pic-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Attachments', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const user1 = Ed25519KeyIdentity.generate();
+	const user2 = Ed25519KeyIdentity.generate();
+
+	const POSTS_COLLECTION = 'posts';
+	const NOTES_COLLECTION = 'notes';
+	const IMAGES_COLLECTION = 'images';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const uploadAsset = async ({
+		name,
+		identity
+	}: {
+		name: string;
+		identity: Ed25519KeyIdentity;
+	}) => {
+		actor.setIdentity(identity);
+
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+		const full_path = `/${IMAGES_COLLECTION}/${name}`;
+
+		const file = await init_asset_upload({
+			collection: IMAGES_COLLECTION,
+			description: toNullable(),
+			encoding_type: [],
+			full_path,
+			name,
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const chunk = await upload_asset_chunk({
+			batch_id: file.batch_id,
+			content: new Uint8Array([1, 2, 3]),
+			order_id: [0n]
+		});
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: [chunk.chunk_id],
+			headers: []
+		});
+
+		return full_path;
+	};
+
+	const setDoc = async ({
+		collection,
+		key,
+		identity,
+		attachments
+	}: {
+		collection: string;
+		key: string;
+		identity: Ed25519KeyIdentity;
+		attachments: string[];
+	}) => {
+		actor.setIdentity(identity);
+
+		const { set_doc } = actor;
+
+		return await set_doc(collection, key, {
+			attachments: toNullable(attachments),
+			data: await toArray({ hello: key }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule, set_db_config } = actor;
+
+		await set_rule({ Db: null }, POSTS_COLLECTION, setRule);
+		await set_rule({ Db: null }, NOTES_COLLECTION, setRule);
+		await set_rule({ Storage: null }, IMAGES_COLLECTION, setRule);
+
+		await set_db_config({
+			proof_of_work: toNullable(),
+			aggregates: toNullable(),
+			list_limits: toNullable(),
+			max_memory_size: toNullable(),
+			history_collections: toNullable(),
+			views: toNullable(),
+			attachments: toNullable([
+				{
+					collection: POSTS_COLLECTION,
+					storage_collection: IMAGES_COLLECTION,
+					cascade_delete: toNullable(true)
+				}
+			]),
+			http_collections: toNullable()
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should attach an asset to a document', async () => {
+		const fullPath = await uploadAsset({ name: 'photo.png', identity: user1 });
+
+		const doc = await setDoc({
+			collection: POSTS_COLLECTION,
+			key: 'post-1',
+			identity: user1,
+			attachments: [fullPath]
+		});
+
+		expect(fromNullable(doc.attachments)).toEqual([fullPath]);
+	});
+
+	it('should not attach an asset that does not exist', async () => {
+		await expect(
+			setDoc({
+				collection: POSTS_COLLECTION,
+				key: 'post-2',
+				identity: user1,
+				attachments: [`/${IMAGES_COLLECTION}/missing.png`]
+			})
+		).rejects.toThrow(`Attached asset not found: /${IMAGES_COLLECTION}/missing.png`);
+	});
+
+	it('should not attach the asset of another user', async () => {
+		const fullPath = await uploadAsset({ name: 'other.png', identity: user1 });
+
+		await expect(
+			setDoc({
+				collection: POSTS_COLLECTION,
+				key: 'post-3',
+				identity: user2,
+				attachments: [fullPath]
+			})
+		).rejects.toThrow(`Attached asset does not belong to the caller: ${fullPath}`);
+	});
+
+	it('should not accept attachments in a collection without configuration', async () => {
+		const fullPath = await uploadAsset({ name: 'note.png', identity: user1 });
+
+		await expect(
+			setDoc({
+				collection: NOTES_COLLECTION,
+				key: 'note-1',
+				identity: user1,
+				attachments: [fullPath]
+			})
+		).rejects.toThrow('Attachments are not enabled for this collection.');
+	});
+
+	it('should delete the attachments with the document', async () => {
+		const fullPath = await uploadAsset({ name: 'cascade.png', identity: user1 });
+
+		const doc = await setDoc({
+			collection: POSTS_COLLECTION,
+			key: 'post-4',
+			identity: user1,
+			attachments: [fullPath]
+		});
+
+		const { del_doc, get_asset } = actor;
+
+		expect(fromNullable(await get_asset(IMAGES_COLLECTION, fullPath))).not.toBeUndefined();
+
+		await del_doc(POSTS_COLLECTION, 'post-4', { version: doc.version });
+
+		await tick(pic);
+
+		expect(fromNullable(await get_asset(IMAGES_COLLECTION, fullPath))).toBeUndefined();
+	});
+});
@@ -0,0 +1,135 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Audit', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const setRule: SetRule = {
+		memory: toNullable(),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('controller', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should start with an empty log', async () => {
+			const { list_audit_blocks } = actor;
+
+			const { log_length, blocks } = await list_audit_blocks({ start: 0n, length: 10n });
+
+			expect(log_length).toEqual(0n);
+			expect(blocks).toEqual([]);
+		});
+
+		it('should record the administrative operations', async () => {
+			const { set_rule, set_controllers, set_doc, list_audit_blocks } = actor;
+
+			await set_rule({ Db: null }, 'notes', setRule);
+
+			await set_controllers({
+				controllers: [Ed25519KeyIdentity.generate().getPrincipal()],
+				controller: {
+					metadata: [],
+					expires_at: toNullable(),
+					scope: { Write: null }
+				}
+			});
+
+			await set_doc('notes', 'first', {
+				attachments: toNullable(),
+				data: new Uint8Array(),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+
+			const { log_length, blocks } = await list_audit_blocks({ start: 0n, length: 10n });
+
+			expect(log_length).toEqual(2n);
+
+			const [[firstIndex, first], [secondIndex, second]] = blocks;
+
+			expect(firstIndex).toEqual(0n);
+			expect(first.operation).toEqual({
+				SetRule: { collection: 'notes', rules_type: { Db: null } }
+			});
+			expect(first.caller.toText()).toEqual(controller.getPrincipal().toText());
+
+			expect(secondIndex).toEqual(1n);
+			expect('SetControllers' in second.operation).toBeTruthy();
+		});
+
+		it('should chain the blocks with their hash', async () => {
+			const { list_audit_blocks } = actor;
+
+			const { blocks } = await list_audit_blocks({ start: 0n, length: 10n });
+
+			const [[_, first], [__, second]] = blocks;
+
+			expect(fromNullable(first.parent_hash)).toBeUndefined();
+			expect(fromNullable(second.parent_hash)).toEqual(first.hash);
+		});
+
+		it('should paginate the blocks', async () => {
+			const { list_audit_blocks } = actor;
+
+			const { log_length, blocks } = await list_audit_blocks({ start: 1n, length: 1n });
+
+			expect(log_length).toEqual(2n);
+			expect(blocks.map(([index]) => index)).toEqual([1n]);
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not list the audit blocks', async () => {
+			const { list_audit_blocks } = actor;
+
+			await expect(list_audit_blocks({ start: 0n, length: 10n })).rejects.toThrow(
+				CONTROLLER_ERROR_MSG
+			);
+		});
+	});
+});
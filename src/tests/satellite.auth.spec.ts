@@ -261,10 +261,13 @@ describe('Satellite authentication', () => {
 				const { set_doc, list_docs } = actor;
 
 				await set_doc('#user', user.getPrincipal().toText(), {
+					attachments: toNullable(),
 					data: await toArray({
 						provider: 'internet_identity'
 					}),
 					description: toNullable(),
+					publish_at: toNullable(),
+					unpublish_at: toNullable(),
 					version: toNullable()
 				});
 
@@ -292,10 +295,13 @@ describe('Satellite authentication', () => {
 
 				await expect(
 					set_doc('#user', user.getPrincipal().toText(), {
+						attachments: toNullable(),
 						data: await toArray({
 							provider: 'internet_identity'
 						}),
 						description: toNullable(),
+						publish_at: toNullable(),
+						unpublish_at: toNullable(),
 						version: toNullable()
 					})
 				).rejects.toThrow('Caller and key must match to create a user.');
@@ -306,10 +312,13 @@ describe('Satellite authentication', () => {
 
 				await expect(
 					set_doc('#user', 'test', {
+						attachments: toNullable(),
 						data: await toArray({
 							provider: 'internet_identity'
 						}),
 						description: toNullable(),
+						publish_at: toNullable(),
+						unpublish_at: toNullable(),
 						version: toNullable()
 					})
 				).rejects.toThrow('User key must be a textual representation of a principal.');
@@ -345,10 +354,13 @@ describe('Satellite authentication', () => {
 
 			await expect(
 				set_doc('#user', user.getPrincipal().toText(), {
+					attachments: toNullable(),
 					data: await toArray({
 						provider: 'internet_identity'
 					}),
 					description: toNullable(),
+					publish_at: toNullable(),
+					unpublish_at: toNullable(),
 					version: toNullable()
 				})
 			).rejects.toThrow('Cannot write.');
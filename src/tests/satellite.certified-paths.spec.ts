@@ -0,0 +1,116 @@
+import type { _SERVICE as SatelliteActor } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/satellite-tests.constants';
+import { deleteDefaultIndexHTML } from './utils/satellite-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Certified paths', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		await deleteDefaultIndexHTML({ actor, controller });
+
+		actor.setIdentity(controller);
+
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+		const file = await init_asset_upload({
+			collection: '#dapp',
+			description: toNullable(),
+			encoding_type: [],
+			full_path: '/hello.html',
+			name: 'hello.html',
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const chunk = await upload_asset_chunk({
+			batch_id: file.batch_id,
+			content: new TextEncoder().encode('<html><body>Hello</body></html>'),
+			order_id: [0n]
+		});
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: [chunk.chunk_id],
+			headers: []
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('controller', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should list the certified paths of an asset with its hash', async () => {
+			const { list_certified_paths, get_asset } = actor;
+
+			const asset = fromNullable(await get_asset('#dapp', '/hello.html'));
+
+			assertNonNullish(asset);
+
+			const [[_, { sha256 }]] = asset.encodings;
+
+			const paths = await list_certified_paths();
+
+			const urls = paths.map(({ url }) => url);
+
+			expect(urls).toContain('/hello.html');
+			expect(urls).toContain('/hello');
+
+			const certified = paths.find(({ url }) => url === '/hello.html');
+
+			assertNonNullish(certified);
+
+			expect(certified.sha256).toEqual(sha256);
+		});
+
+		it('should no longer list the paths of a deleted asset', async () => {
+			const { list_certified_paths, del_asset } = actor;
+
+			await del_asset('#dapp', '/hello.html');
+
+			const urls = (await list_certified_paths()).map(({ url }) => url);
+
+			expect(urls).not.toContain('/hello.html');
+			expect(urls).not.toContain('/hello');
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not list the certified paths', async () => {
+			const { list_certified_paths } = actor;
+
+			await expect(list_certified_paths()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
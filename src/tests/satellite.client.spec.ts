@@ -0,0 +1,109 @@
+import type { _SERVICE as SatelliteActor } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Client metadata', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should expose the interface of the satellite', async () => {
+			const { get_client_metadata, version } = actor;
+
+			const metadata = await get_client_metadata();
+
+			expect(metadata.version).toEqual(await version());
+			expect(metadata.candid).toContain('service : () -> {');
+			expect(metadata.candid).toContain('get_client_metadata : () -> (ClientMetadata) query;');
+		});
+
+		it('should expose the custom endpoints of the serverless functions', async () => {
+			const { get_client_metadata } = actor;
+
+			const { extension_candid, custom_endpoints } = await get_client_metadata();
+
+			expect(extension_candid).toContain('build_version : () -> (text) query');
+			expect(custom_endpoints).toEqual(['build_version']);
+		});
+
+		it('should not report features or extensions by default', async () => {
+			const { get_client_metadata } = actor;
+
+			const { features, extensions } = await get_client_metadata();
+
+			expect(features).toEqual({
+				authentication: false,
+				traces: false,
+				analytics: false,
+				moderation: false,
+				feature_flags: false
+			});
+			expect(extensions).toHaveLength(0);
+		});
+	});
+
+	describe('with extensions', () => {
+		beforeAll(async () => {
+			actor.setIdentity(controller);
+
+			const { set_extension_config } = actor;
+
+			await set_extension_config(
+				{ Traces: null },
+				{ enabled: false, settings: [], version: toNullable() }
+			);
+
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not list a disabled extension', async () => {
+			const { get_client_metadata } = actor;
+
+			const { extensions } = await get_client_metadata();
+
+			expect(extensions).toEqual([]);
+		});
+
+		it('should list an enabled extension', async () => {
+			actor.setIdentity(controller);
+
+			const { set_traces_config, get_client_metadata } = actor;
+
+			await set_traces_config({ enabled: true });
+
+			actor.setIdentity(new AnonymousIdentity());
+
+			const { extensions } = await get_client_metadata();
+
+			expect(extensions).toEqual([{ Traces: null }]);
+		});
+	});
+});
@@ -0,0 +1,164 @@
+import type {
+	KeyCollation,
+	ListOrder,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Collation', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const setRule: SetRule = {
+		memory: toNullable(),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const createCollection = async ({
+		collection,
+		collation,
+		keys
+	}: {
+		collection: string;
+		collation?: KeyCollation;
+		keys: string[];
+	}) => {
+		const { set_rule, set_doc } = actor;
+
+		await set_rule({ Db: null }, collection, {
+			...setRule,
+			collation: toNullable(collation)
+		});
+
+		for (const key of keys) {
+			await set_doc(collection, key, {
+				attachments: toNullable(),
+				data: await toArray({ hello: key }),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+		}
+	};
+
+	const listKeys = async ({
+		collection,
+		order
+	}: {
+		collection: string;
+		order?: ListOrder;
+	}): Promise<string[]> => {
+		const { list_docs } = actor;
+
+		const { items } = await list_docs(collection, {
+			matcher: toNullable(),
+			order: toNullable(order),
+			owner: toNullable(),
+			paginate: toNullable()
+		});
+
+		return items.map(([key]) => key);
+	};
+
+	const keys = ['item10', 'item2', 'Item3', 'item1', 'item02'];
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		await createCollection({ collection: 'binary', keys });
+		await createCollection({
+			collection: 'case_insensitive',
+			collation: { CaseInsensitive: null },
+			keys
+		});
+		await createCollection({ collection: 'numeric', collation: { Numeric: null }, keys });
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should save the collation of the rule', async () => {
+		const { get_rule } = actor;
+
+		const rule = fromNullable(await get_rule({ Db: null }, 'numeric'));
+
+		assertNonNullish(rule);
+
+		expect(fromNullable(rule.collation)).toEqual({ Numeric: null });
+	});
+
+	it('should list the keys byte by byte by default', async () => {
+		expect(
+			await listKeys({ collection: 'binary', order: { desc: false, field: { Keys: null } } })
+		).toEqual(['Item3', 'item02', 'item1', 'item10', 'item2']);
+	});
+
+	it('should list the keys ignoring the case', async () => {
+		expect(await listKeys({ collection: 'case_insensitive' })).toEqual([
+			'item02',
+			'item1',
+			'item10',
+			'item2',
+			'Item3'
+		]);
+	});
+
+	it('should list the keys in natural order', async () => {
+		expect(await listKeys({ collection: 'numeric' })).toEqual([
+			'Item3',
+			'item1',
+			'item02',
+			'item2',
+			'item10'
+		]);
+	});
+
+	it('should list the keys in natural descending order', async () => {
+		expect(
+			await listKeys({ collection: 'numeric', order: { desc: true, field: { Keys: null } } })
+		).toEqual(['item10', 'item2', 'item02', 'item1', 'Item3']);
+	});
+
+	it('should paginate according to the collation', async () => {
+		const { list_docs } = actor;
+
+		const { items } = await list_docs('numeric', {
+			matcher: toNullable(),
+			order: toNullable(),
+			owner: toNullable(),
+			paginate: toNullable({ start_after: toNullable('item02'), limit: toNullable(2n) })
+		});
+
+		expect(items.map(([key]) => key)).toEqual(['item2', 'item10']);
+	});
+});
@@ -0,0 +1,121 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Collection version', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_collection_version';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Public: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const setDoc = async (key: string) => {
+		const { set_doc } = actor;
+
+		await set_doc(TEST_COLLECTION, key, {
+			attachments: toNullable(),
+			data: await toArray({ key }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	};
+
+	const getChanges = async (): Promise<bigint | undefined> => {
+		const { get_collection_version } = actor;
+
+		return fromNullable(await get_collection_version({ Db: null }, TEST_COLLECTION))?.changes;
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should have no version for a collection that was never written', async () => {
+		expect(await getChanges()).toBeUndefined();
+	});
+
+	it('should increment the version of the collection on each change', async () => {
+		await setDoc('first');
+		await setDoc('second');
+
+		expect(await getChanges()).toEqual(2n);
+
+		const { get_doc, del_doc } = actor;
+
+		const doc = fromNullable(await get_doc(TEST_COLLECTION, 'first'));
+
+		assertNonNullish(doc);
+
+		await del_doc(TEST_COLLECTION, 'first', { version: doc.version });
+
+		expect(await getChanges()).toEqual(3n);
+	});
+
+	it('should return the version with the listed documents', async () => {
+		const { list_docs, get_collection_version } = actor;
+
+		const { version } = await list_docs(TEST_COLLECTION, {
+			matcher: toNullable(),
+			order: toNullable(),
+			owner: toNullable(),
+			paginate: toNullable()
+		});
+
+		expect(version).toEqual(await get_collection_version({ Db: null }, TEST_COLLECTION));
+	});
+
+	it('should not decrease the version when the collection is emptied', async () => {
+		const { del_docs } = actor;
+
+		await del_docs(TEST_COLLECTION);
+
+		expect(await getChanges()).toEqual(4n);
+	});
+
+	it('should provide the version to anonymous callers', async () => {
+		actor.setIdentity(new AnonymousIdentity());
+
+		expect(await getChanges()).toEqual(4n);
+	});
+});
@@ -0,0 +1,247 @@
+import type {
+	HttpRequest,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { fromArray, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Compression', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const PLAIN_COLLECTION = 'plain';
+	const COMPRESSED_COLLECTION = 'compressed';
+	const FILES_COLLECTION = 'files';
+
+	const text = 'Lorem ipsum dolor sit amet, consectetur adipiscing elit. '.repeat(1000);
+
+	const setRule: SetRule = {
+		memory: toNullable({ Stable: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Public: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const dbUsed = async (): Promise<bigint> => {
+		const { get_memory_stats } = actor;
+
+		const stats = await get_memory_stats();
+
+		return stats.find(({ region }) => 'Db' in region)?.used ?? 0n;
+	};
+
+	const setDoc = async ({
+		collection,
+		key = 'article',
+		data
+	}: {
+		collection: string;
+		key?: string;
+		data: unknown;
+	}) => {
+		const { set_doc } = actor;
+
+		return set_doc(collection, key, {
+			attachments: toNullable(),
+			data: await toArray(data),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule, set_rule_compression } = actor;
+
+		await set_rule({ Db: null }, PLAIN_COLLECTION, setRule);
+		await set_rule({ Db: null }, COMPRESSED_COLLECTION, setRule);
+		await set_rule({ Storage: null }, FILES_COLLECTION, setRule);
+
+		await set_rule_compression({ Db: null }, COMPRESSED_COLLECTION, toNullable({ Deflate: null }));
+		await set_rule_compression({ Storage: null }, FILES_COLLECTION, toNullable({ Deflate: null }));
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should save the compression of the rule', async () => {
+		const { get_rule } = actor;
+
+		const rule = fromNullable(await get_rule({ Db: null }, COMPRESSED_COLLECTION));
+
+		expect(fromNullable(rule?.compression ?? [])).toEqual({ Deflate: null });
+	});
+
+	it('should keep the compression when the rule is updated', async () => {
+		const { get_rule, set_rule } = actor;
+
+		const current = fromNullable(await get_rule({ Db: null }, COMPRESSED_COLLECTION));
+
+		const rule = await set_rule({ Db: null }, COMPRESSED_COLLECTION, {
+			...setRule,
+			version: current?.version ?? []
+		});
+
+		expect(fromNullable(rule.compression)).toEqual({ Deflate: null });
+	});
+
+	it('should not compress a collection of the heap', async () => {
+		const { set_rule, set_rule_compression } = actor;
+
+		await set_rule({ Db: null }, 'heap', { ...setRule, memory: toNullable({ Heap: null }) });
+
+		await expect(
+			set_rule_compression({ Db: null }, 'heap', toNullable({ Deflate: null }))
+		).rejects.toThrow('The compression is only supported in stable memory.');
+	});
+
+	it('should not compress an unknown collection', async () => {
+		const { set_rule_compression } = actor;
+
+		await expect(
+			set_rule_compression({ Db: null }, 'unknown', toNullable({ Deflate: null }))
+		).rejects.toThrow('Collection "unknown" not found in Datastore.');
+	});
+
+	it('should write less to stable memory', async () => {
+		const initial = await dbUsed();
+
+		await setDoc({ collection: PLAIN_COLLECTION, data: { text } });
+
+		const plain = (await dbUsed()) - initial;
+
+		const before = await dbUsed();
+
+		await setDoc({ collection: COMPRESSED_COLLECTION, data: { text } });
+
+		const compressed = (await dbUsed()) - before;
+
+		expect(compressed * 10n).toBeLessThan(plain);
+	});
+
+	it('should decompress the documents transparently', async () => {
+		const { get_doc, list_docs } = actor;
+
+		const doc = fromNullable(await get_doc(COMPRESSED_COLLECTION, 'article'));
+
+		expect(await fromArray(doc?.data ?? [])).toEqual({ text });
+
+		const { items } = await list_docs(COMPRESSED_COLLECTION, {
+			matcher: toNullable(),
+			order: toNullable(),
+			owner: toNullable(),
+			paginate: toNullable()
+		});
+
+		expect(items).toHaveLength(1);
+
+		const [[_, listed]] = items;
+
+		expect(await fromArray(listed.data)).toEqual({ text });
+	});
+
+	it('should keep the small documents as they are', async () => {
+		const { get_doc } = actor;
+
+		await setDoc({ collection: COMPRESSED_COLLECTION, key: 'note', data: { text: 'Hello' } });
+
+		const doc = fromNullable(await get_doc(COMPRESSED_COLLECTION, 'note'));
+
+		expect(await fromArray(doc?.data ?? [])).toEqual({ text: 'Hello' });
+	});
+
+	it('should read the documents written before the compression', async () => {
+		const { get_doc, set_rule_compression } = actor;
+
+		await set_rule_compression({ Db: null }, PLAIN_COLLECTION, toNullable({ Deflate: null }));
+
+		const doc = fromNullable(await get_doc(PLAIN_COLLECTION, 'article'));
+
+		expect(await fromArray(doc?.data ?? [])).toEqual({ text });
+
+		await set_rule_compression({ Db: null }, PLAIN_COLLECTION, toNullable());
+	});
+
+	it('should read the compressed documents once the compression is disabled', async () => {
+		const { get_doc, set_rule_compression } = actor;
+
+		await set_rule_compression({ Db: null }, COMPRESSED_COLLECTION, toNullable());
+
+		const doc = fromNullable(await get_doc(COMPRESSED_COLLECTION, 'article'));
+
+		expect(await fromArray(doc?.data ?? [])).toEqual({ text });
+
+		await set_rule_compression({ Db: null }, COMPRESSED_COLLECTION, toNullable({ Deflate: null }));
+	});
+
+	it('should serve the compressed content of an asset', async () => {
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload, http_request } = actor;
+
+		const fullPath = `/${FILES_COLLECTION}/article.txt`;
+
+		const file = await init_asset_upload({
+			collection: FILES_COLLECTION,
+			description: toNullable(),
+			encoding_type: [],
+			full_path: fullPath,
+			name: 'article.txt',
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const { chunk_id } = await upload_asset_chunk({
+			batch_id: file.batch_id,
+			content: new TextEncoder().encode(text),
+			order_id: [0n]
+		});
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: [chunk_id],
+			headers: [['Content-Type', 'text/plain']]
+		});
+
+		const request: HttpRequest = {
+			body: [],
+			certificate_version: toNullable(2),
+			headers: [],
+			method: 'GET',
+			url: fullPath
+		};
+
+		const { status_code, body } = await http_request(request);
+
+		expect(status_code).toEqual(200);
+		expect(new TextDecoder().decode(body as Uint8Array)).toEqual(text);
+	});
+});
@@ -0,0 +1,172 @@
+import type {
+	ConfigBundle,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_ADMIN_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Config bundle', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+	let targetActor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const setRule: SetRule = {
+		memory: toNullable({ Stable: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(100),
+		read: { Public: null },
+		mutable_permissions: toNullable(true),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: [],
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	let bundle: ConfigBundle;
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: a } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = a;
+		actor.setIdentity(controller);
+
+		const { actor: t } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		targetActor = t;
+		targetActor.setIdentity(controller);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		beforeAll(async () => {
+			const { set_rule, set_custom_domain, set_auth_config } = actor;
+
+			await set_rule({ Db: null }, 'notes', setRule);
+			await set_rule({ Db: null }, 'articles', setRule);
+			await set_rule({ Storage: null }, 'images', setRule);
+
+			await set_custom_domain('hello.com', toNullable('123456'));
+
+			await set_auth_config({
+				internet_identity: [
+					{
+						derivation_origin: ['hello.com']
+					}
+				]
+			});
+		});
+
+		it('should export the config bundle', async () => {
+			const { get_config_bundle } = actor;
+
+			bundle = await get_config_bundle();
+
+			expect(bundle.version).toEqual(1n);
+
+			expect(bundle.rules.db.map(([collection]) => collection)).toEqual(['articles', 'notes']);
+			expect(bundle.rules.storage.map(([collection]) => collection)).toEqual(['images']);
+
+			bundle.rules.db.forEach(([_, rule]) => {
+				expect(rule.version).toEqual(toNullable());
+				expect(rule.read).toEqual({ Public: null });
+				expect(fromNullable(rule.max_capacity)).toEqual(100);
+			});
+
+			expect(bundle.custom_domains).toEqual([['hello.com', ['123456']]]);
+
+			expect(fromNullable(bundle.authentication)).toEqual({
+				internet_identity: [
+					{
+						derivation_origin: ['hello.com']
+					}
+				]
+			});
+		});
+
+		it('should export the same bundle twice', async () => {
+			const { get_config_bundle } = actor;
+
+			expect(await get_config_bundle()).toEqual(bundle);
+		});
+
+		it('should apply the config bundle to another satellite', async () => {
+			const { apply_config_bundle, get_config_bundle, list_rules } = targetActor;
+
+			await apply_config_bundle(bundle);
+
+			expect(await get_config_bundle()).toEqual(bundle);
+
+			const rules = await list_rules({ Db: null });
+
+			expect(rules).toHaveLength(2);
+		});
+
+		it('should apply the config bundle again regardless of the rules versions', async () => {
+			const { apply_config_bundle, get_rule } = targetActor;
+
+			await apply_config_bundle(bundle);
+
+			const rule = fromNullable(await get_rule({ Db: null }, 'notes'));
+
+			assertNonNullish(rule);
+
+			expect(rule.version).toEqual(toNullable(2n));
+		});
+
+		it('should not apply an unsupported bundle version', async () => {
+			const { apply_config_bundle } = targetActor;
+
+			await expect(
+				apply_config_bundle({
+					...bundle,
+					version: 2n
+				})
+			).rejects.toThrow('Unsupported configuration bundle version.');
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not export the config bundle', async () => {
+			const { get_config_bundle } = actor;
+
+			await expect(get_config_bundle()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should not apply a config bundle', async () => {
+			const { apply_config_bundle } = actor;
+
+			await expect(apply_config_bundle(bundle)).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+	});
+});
@@ -0,0 +1,137 @@
+import type {
+	Icrc21ConsentMessageSpec,
+	_SERVICE as SatelliteActor
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { IDL } from '@dfinity/candid';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Consent messages', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const genericDisplay: Icrc21ConsentMessageSpec = {
+		metadata: {
+			language: 'en',
+			utc_offset_minutes: toNullable()
+		},
+		device_spec: toNullable({ GenericDisplay: null })
+	};
+
+	const delDocsArg = new Uint8Array(IDL.encode([IDL.Text], ['notes']));
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		// Wallets request the consent message before the user is signed in the dapp.
+		actor.setIdentity(new AnonymousIdentity());
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should list the supported standards', async () => {
+		const { icrc10_supported_standards } = actor;
+
+		const standards = await icrc10_supported_standards();
+
+		expect(standards.map(({ name }) => name)).toEqual(['ICRC-10', 'ICRC-21']);
+	});
+
+	it('should describe the deletion of the documents of a collection', async () => {
+		const { icrc21_canister_call_consent_message } = actor;
+
+		const result = await icrc21_canister_call_consent_message({
+			method: 'del_docs',
+			arg: delDocsArg,
+			user_preferences: genericDisplay
+		});
+
+		if ('Err' in result) {
+			throw new Error('Unexpected result. Consent message should have been provided.');
+		}
+
+		const { consent_message, metadata } = result.Ok;
+
+		expect(metadata.language).toEqual('en');
+
+		if (!('GenericDisplayMessage' in consent_message)) {
+			throw new Error('Unexpected result. Consent message should be a generic display.');
+		}
+
+		expect(consent_message.GenericDisplayMessage).toContain('# Delete all documents');
+		expect(consent_message.GenericDisplayMessage).toContain('Datastore collection: notes');
+	});
+
+	it('should wrap the message for a line display', async () => {
+		const { icrc21_canister_call_consent_message } = actor;
+
+		const result = await icrc21_canister_call_consent_message({
+			method: 'del_docs',
+			arg: delDocsArg,
+			user_preferences: {
+				...genericDisplay,
+				device_spec: toNullable({
+					LineDisplay: { characters_per_line: 20, lines_per_page: 3 }
+				})
+			}
+		});
+
+		if ('Err' in result || !('LineDisplayMessage' in result.Ok.consent_message)) {
+			throw new Error('Unexpected result. Consent message should be a line display.');
+		}
+
+		const { pages } = result.Ok.consent_message.LineDisplayMessage;
+
+		expect(pages.length).toBeGreaterThan(1);
+
+		for (const { lines } of pages) {
+			expect(lines.length).toBeLessThanOrEqual(3);
+
+			for (const line of lines) {
+				expect(line.length).toBeLessThanOrEqual(20);
+			}
+		}
+	});
+
+	it('should not describe a method without consent message', async () => {
+		const { icrc21_canister_call_consent_message } = actor;
+
+		const result = await icrc21_canister_call_consent_message({
+			method: 'list_docs',
+			arg: delDocsArg,
+			user_preferences: genericDisplay
+		});
+
+		expect('Err' in result && 'UnsupportedCanisterCall' in result.Err).toBeTruthy();
+	});
+
+	it('should not describe a call with invalid arguments', async () => {
+		const { icrc21_canister_call_consent_message } = actor;
+
+		const result = await icrc21_canister_call_consent_message({
+			method: 'deposit_cycles',
+			arg: delDocsArg,
+			user_preferences: genericDisplay
+		});
+
+		expect('Err' in result && 'UnsupportedCanisterCall' in result.Err).toBeTruthy();
+	});
+});
@@ -0,0 +1,112 @@
+import type { _SERVICE as SatelliteActor } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/satellite-tests.constants';
+import { tick } from './utils/pic-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Controllers expiration', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+	let canisterId: Principal;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const contractor = Ed25519KeyIdentity.generate();
+
+	const now = async (): Promise<bigint> => BigInt(await pic.getTime()) * 1_000_000n;
+
+	const grant = async (expires_at: bigint) => {
+		actor.setIdentity(controller);
+
+		const { set_controllers } = actor;
+
+		return set_controllers({
+			controllers: [contractor.getPrincipal()],
+			controller: {
+				expires_at: toNullable(expires_at),
+				metadata: [],
+				scope: { Write: null }
+			}
+		});
+	};
+
+	const hasContractor = async (): Promise<boolean> => {
+		actor.setIdentity(controller);
+
+		const { list_controllers } = actor;
+
+		const controllers = await list_controllers();
+
+		return controllers.some(([id, _]) => id.toText() === contractor.getPrincipal().toText());
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c, canisterId: cId } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		canisterId = cId;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should not grant an access that is already expired', async () => {
+		await expect(grant((await now()) - 1n)).rejects.toThrow(
+			'The expiration of the controller must be in the future.'
+		);
+	});
+
+	it('should grant a temporary access', async () => {
+		await grant((await now()) + 60_000_000_000n);
+
+		expect(await hasContractor()).toBeTruthy();
+
+		actor.setIdentity(contractor);
+
+		const { list_traces } = actor;
+
+		await expect(list_traces()).resolves.not.toThrow();
+	});
+
+	it('should remove the controller once expired', async () => {
+		await pic.advanceTime(61_000);
+		await tick(pic);
+
+		expect(await hasContractor()).toBeFalsy();
+
+		actor.setIdentity(contractor);
+
+		const { list_traces } = actor;
+
+		await expect(list_traces()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+	});
+
+	it('should schedule the removal again after an upgrade', async () => {
+		await grant((await now()) + 60_000_000_000n);
+
+		await pic.upgradeCanister({
+			canisterId,
+			wasm: SATELLITE_WASM_PATH,
+			sender: controller.getPrincipal()
+		});
+
+		expect(await hasContractor()).toBeTruthy();
+
+		await pic.advanceTime(61_000);
+		await tick(pic);
+
+		expect(await hasContractor()).toBeFalsy();
+	});
+});
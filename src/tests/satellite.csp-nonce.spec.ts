@@ -0,0 +1,132 @@
+import type {
+	HttpRequest,
+	_SERVICE as SatelliteActor,
+	StorageConfig
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { tick } from './utils/pic-tests.utils';
+import { deleteDefaultIndexHTML } from './utils/satellite-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - CSP nonce', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+	let canisterId: Principal;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const html = '<html><head><script>console.log("Hello")</script></head><body></body></html>';
+
+	const storage: StorageConfig = {
+		headers: [],
+		iframe: toNullable(),
+		redirects: toNullable(),
+		rewrites: [],
+		raw_access: toNullable({ Allow: null }),
+		delegations: toNullable(),
+		max_response_body_size: toNullable(),
+		csp_nonce: toNullable(true),
+		wildcard_domains: toNullable(),
+		locales: toNullable(),
+		weighted_rewrites: toNullable(),
+		list_limits: toNullable(),
+		max_memory_size: toNullable()
+	};
+
+	const request = (host: string): HttpRequest => ({
+		body: [],
+		certificate_version: toNullable(2),
+		headers: [['Host', host]],
+		method: 'GET',
+		url: '/hello.html'
+	});
+
+	const decoder = new TextDecoder();
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c, canisterId: cId } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		canisterId = cId;
+
+		// Seeds the random number generator
+		await tick(pic);
+
+		await deleteDefaultIndexHTML({ actor, controller });
+
+		actor.setIdentity(controller);
+
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload, set_storage_config } =
+			actor;
+
+		await set_storage_config(storage);
+
+		const file = await init_asset_upload({
+			collection: '#dapp',
+			description: toNullable(),
+			encoding_type: [],
+			full_path: '/hello.html',
+			name: 'hello.html',
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const chunk = await upload_asset_chunk({
+			batch_id: file.batch_id,
+			content: new TextEncoder().encode(html),
+			order_id: [0n]
+		});
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: [chunk.chunk_id],
+			headers: [['Content-Type', 'text/html']]
+		});
+
+		actor.setIdentity(new AnonymousIdentity());
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should inject a nonce in the scripts and the policy of a raw response', async () => {
+		const { http_request } = actor;
+
+		const { body, headers } = await http_request(request(`${canisterId.toText()}.raw.icp0.io`));
+
+		const content = decoder.decode(body as Uint8Array);
+
+		const [_, nonce] = /<script nonce="([^"]+)">/.exec(content) ?? [];
+
+		expect(nonce).not.toBeUndefined();
+
+		const csp = headers.find(([key]) => key === 'Content-Security-Policy');
+
+		expect(csp?.[1]).toContain(`script-src 'nonce-${nonce}' 'strict-dynamic'`);
+	});
+
+	it('should serve a certified response as uploaded', async () => {
+		const { http_request } = actor;
+
+		const { body, headers } = await http_request(request(`${canisterId.toText()}.icp0.io`));
+
+		expect(decoder.decode(body as Uint8Array)).toEqual(html);
+
+		expect(headers.find(([key]) => key === 'Content-Security-Policy')).toBeUndefined();
+	});
+});
@@ -77,7 +77,9 @@ describe('Satellite custom domains', () => {
 					encoding_type: [],
 					full_path: '/.well-known/ic-domains',
 					name: 'ic-domains',
-					token: toNullable()
+					publish_at: toNullable(),
+					token: toNullable(),
+					unpublish_at: toNullable()
 				})
 			).rejects.toThrow('/.well-known/ic-domains is a reserved asset.');
 		});
@@ -49,7 +49,10 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 				mutable_permissions: toNullable(),
 				write: { Managed: null },
 				version: toNullable(),
-				rate_config: toNullable()
+				rate_config: toNullable(),
+				user_quota: toNullable(),
+				collation: toNullable(),
+				retention: toNullable()
 			};
 
 			const { set_rule } = actor;
@@ -70,8 +73,11 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 			const { set_doc } = actor;
 
 			await set_doc(TEST_COLLECTION, key, {
+				attachments: toNullable(),
 				data,
 				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
 				version: toNullable()
 			});
 
@@ -179,6 +185,64 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 					})
 				).rejects.toThrowError(new RegExp(INVALID_VERSION_ERROR_MSG, 'i'));
 			});
+
+			it('should provide a code and params with a validation error', async () => {
+				const key = await createDoc();
+
+				const { get_doc, set_doc } = actor;
+
+				const doc = fromNullable(await get_doc(TEST_COLLECTION, key));
+
+				try {
+					await set_doc(TEST_COLLECTION, key, {
+						...doc!,
+						version: [123n]
+					});
+
+					expect(true).toBeFalsy();
+				} catch (error: unknown) {
+					const [json] = (error as Error).message.match(/{.*}/) ?? [];
+
+					expect(JSON.parse(json)).toEqual({
+						version: 1,
+						code: INVALID_VERSION_ERROR_MSG,
+						message: `Version is outdated or in the future (${doc!.version[0]} - 123).`,
+						params: {
+							current_version: `${doc!.version[0]}`,
+							user_version: '123',
+							request_id: expect.any(String)
+						}
+					});
+				}
+			});
+
+			it('should provide an unknown code with an error without code', async () => {
+				const { set_doc } = actor;
+
+				try {
+					await set_doc('unknown', nanoid(), {
+						attachments: toNullable(),
+						data: await toArray({ hello: 'world' }),
+						description: toNullable(),
+						publish_at: toNullable(),
+						unpublish_at: toNullable(),
+						version: toNullable()
+					});
+
+					expect(true).toBeFalsy();
+				} catch (error: unknown) {
+					const [json] = (error as Error).message.match(/{.*}/) ?? [];
+
+					expect(JSON.parse(json)).toEqual({
+						version: 1,
+						code: 'error_unknown',
+						message: 'Collection "unknown" not found in Datastore.',
+						params: {
+							request_id: expect.any(String)
+						}
+					});
+				}
+			});
 		});
 
 		describe('controller', () => {
@@ -207,12 +271,19 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 				const { set_db_config, get_db_config } = actor;
 
 				const config: DbConfig = {
+					proof_of_work: toNullable(),
+					aggregates: toNullable(),
+					list_limits: toNullable(),
 					max_memory_size: [
 						{
 							heap: [1234n],
 							stable: [789n]
 						}
-					]
+					],
+					history_collections: toNullable(),
+					views: toNullable(),
+					attachments: toNullable(),
+					http_collections: toNullable()
 				};
 
 				await set_db_config(config);
@@ -222,7 +293,14 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 
 				// Redo for next test
 				await set_db_config({
-					max_memory_size: []
+					proof_of_work: toNullable(),
+					aggregates: toNullable(),
+					list_limits: toNullable(),
+					max_memory_size: [],
+					history_collections: toNullable(),
+					views: toNullable(),
+					attachments: toNullable(),
+					http_collections: toNullable()
 				});
 			});
 		});
@@ -265,7 +343,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						created_at: toNullable({
 							GreaterThan: items[4][1].created_at
 						}),
-						updated_at: toNullable()
+						updated_at: toNullable(),
+						filter: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -288,7 +367,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						created_at: toNullable({
 							LessThan: items[4][1].created_at
 						}),
-						updated_at: toNullable()
+						updated_at: toNullable(),
+						filter: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -308,7 +388,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						created_at: toNullable({
 							Between: [items[4][1].created_at, items[8][1].created_at]
 						}),
-						updated_at: toNullable()
+						updated_at: toNullable(),
+						filter: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -351,7 +432,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						updated_at: toNullable({
 							GreaterThan: items[4][1].created_at
 						}),
-						created_at: toNullable()
+						created_at: toNullable(),
+						filter: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -374,7 +456,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						updated_at: toNullable({
 							LessThan: items[4][1].created_at
 						}),
-						created_at: toNullable()
+						created_at: toNullable(),
+						filter: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -394,7 +477,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						updated_at: toNullable({
 							Between: [items[4][1].created_at, items[8][1].created_at]
 						}),
-						created_at: toNullable()
+						created_at: toNullable(),
+						filter: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -442,7 +526,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						created_at: toNullable({
 							GreaterThan: 0n
 						}),
-						updated_at: toNullable()
+						updated_at: toNullable(),
+						filter: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -467,8 +552,11 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 				for (let i = 0; i < 5; i++) {
 					const key = nanoid();
 					await set_doc(TEST_COLLECTION, key, {
+						attachments: toNullable(),
 						data,
 						description: toNullable(),
+						publish_at: toNullable(),
+						unpublish_at: toNullable(),
 						version: toNullable()
 					});
 					await pic.advanceTime(50);
@@ -481,7 +569,8 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						created_at: toNullable({
 							GreaterThan: 100n
 						}),
-						updated_at: toNullable()
+						updated_at: toNullable(),
+						filter: toNullable()
 					}),
 					order: toNullable(),
 					owner: toNullable(),
@@ -591,7 +680,10 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 				mutable_permissions: toNullable(),
 				write: { Managed: null },
 				version: toNullable(),
-				rate_config: toNullable()
+				rate_config: toNullable(),
+				user_quota: toNullable(),
+				collation: toNullable(),
+				retention: toNullable()
 			};
 
 			beforeAll(() => {
@@ -614,8 +706,11 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						collection,
 						nanoid(),
 						{
+							attachments: toNullable(),
 							data,
 							description: toNullable(),
+							publish_at: toNullable(),
+							unpublish_at: toNullable(),
 							version: toNullable()
 						}
 					])
@@ -683,8 +778,11 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 						COLLECTION,
 						`${i}`,
 						{
+							attachments: toNullable(),
 							data,
 							description: toNullable(),
+							publish_at: toNullable(),
+							unpublish_at: toNullable(),
 							version: toNullable()
 						}
 					])
@@ -730,8 +828,11 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 
 				try {
 					await set_doc(collectionUnknown, nanoid(), {
+						attachments: toNullable(),
 						data,
 						description: toNullable(),
+						publish_at: toNullable(),
+						unpublish_at: toNullable(),
 						version: toNullable()
 					});
 
@@ -753,7 +854,10 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 				write: { Managed: null },
 				version: toNullable(),
 				max_capacity: toNullable(),
-				rate_config: toNullable()
+				rate_config: toNullable(),
+				user_quota: toNullable(),
+				collation: toNullable(),
+				retention: toNullable()
 			};
 
 			beforeAll(() => {
@@ -782,10 +886,17 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 					await set_rule({ Db: null }, collection, setRule);
 
 					await set_db_config({
+						proof_of_work: toNullable(),
+						aggregates: toNullable(),
+						list_limits: toNullable(),
 						max_memory_size: toNullable({
 							heap: 'Heap' in memory ? [20_000n] : [],
 							stable: 'Stable' in memory ? [20_000n] : []
-						})
+						}),
+						history_collections: toNullable(),
+						views: toNullable(),
+						attachments: toNullable(),
+						http_collections: toNullable()
 					});
 				});
 
@@ -802,8 +913,11 @@ describe.each([{ memory: { Heap: null } }, { memory: { Stable: null } }])(
 								collection,
 								`${i}`,
 								{
+									attachments: toNullable(),
 									data,
 									description: toNullable(),
+									publish_at: toNullable(),
+									unpublish_at: toNullable(),
 									version: toNullable()
 								}
 							])
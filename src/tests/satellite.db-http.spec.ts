@@ -0,0 +1,258 @@
+import type {
+	DbConfig,
+	HttpRequest,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { decodeExprPath } from './utils/certification-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Db over HTTP', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Public: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const config: DbConfig = {
+		proof_of_work: toNullable(),
+		aggregates: toNullable(),
+		list_limits: toNullable(),
+		max_memory_size: toNullable(),
+		history_collections: toNullable(),
+		views: toNullable(),
+		attachments: toNullable(),
+		http_collections: toNullable(['posts', 'drafts'])
+	};
+
+	const request = ({
+		url,
+		headers = []
+	}: {
+		url: string;
+		headers?: [string, string][];
+	}): HttpRequest => ({
+		body: [],
+		certificate_version: toNullable(2),
+		headers,
+		method: 'GET',
+		url
+	});
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule, set_doc, set_db_config } = actor;
+
+		await set_rule({ Db: null }, 'posts', setRule);
+		await set_rule({ Db: null }, 'drafts', { ...setRule, read: { Managed: null } });
+		await set_rule({ Db: null }, 'notes', setRule);
+
+		await set_db_config(config);
+
+		for (const collection of ['posts', 'drafts', 'notes']) {
+			await set_doc(collection, 'hello world', {
+				attachments: toNullable(),
+				data: await toArray({ title: 'Hello' }),
+				description: toNullable('greetings'),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+		}
+
+		actor.setIdentity(new AnonymousIdentity());
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should serve a certified public document with a query', async () => {
+		const { http_request } = actor;
+
+		const { status_code, headers, body, upgrade } = await http_request(
+			request({ url: '/_juno/db/posts/hello%20world' })
+		);
+
+		expect(status_code).toEqual(200);
+		expect(fromNullable(upgrade)).toBeUndefined();
+		expect(headers).toContainEqual(['Content-Type', 'application/json; charset=utf-8']);
+		expect(decodeExprPath(headers)).toEqual([
+			'http_expr',
+			'_juno',
+			'db',
+			'posts',
+			'hello world',
+			'<$>'
+		]);
+
+		const doc = JSON.parse(new TextDecoder().decode(body as Uint8Array));
+
+		expect(doc.key).toEqual('hello world');
+	});
+
+	it('should serve a certified public document as Candid with a query', async () => {
+		const { http_request } = actor;
+
+		const { status_code, headers, upgrade } = await http_request(
+			request({
+				url: '/_juno/db/posts/hello%20world',
+				headers: [['Accept', 'application/candid']]
+			})
+		);
+
+		expect(status_code).toEqual(200);
+		expect(fromNullable(upgrade)).toBeUndefined();
+		expect(headers).toContainEqual(['Content-Type', 'application/candid']);
+	});
+
+	it('should certify the document again when it is updated', async () => {
+		actor.setIdentity(controller);
+
+		const { set_doc, get_doc } = actor;
+
+		const current = fromNullable(await get_doc('posts', 'hello world'));
+
+		await set_doc('posts', 'hello world', {
+			attachments: toNullable(),
+			data: await toArray({ title: 'Hello again' }),
+			description: toNullable('greetings'),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable(current?.version[0])
+		});
+
+		actor.setIdentity(new AnonymousIdentity());
+
+		const { http_request } = actor;
+
+		const { status_code, body, upgrade } = await http_request(
+			request({ url: '/_juno/db/posts/hello%20world' })
+		);
+
+		expect(status_code).toEqual(200);
+		expect(fromNullable(upgrade)).toBeUndefined();
+
+		const doc = JSON.parse(new TextDecoder().decode(body as Uint8Array));
+
+		expect(doc.data).toEqual({ title: 'Hello again' });
+		expect(doc.version).toEqual('2');
+	});
+
+	it('should upgrade the db http request with an api key', async () => {
+		const { http_request } = actor;
+
+		const { upgrade } = await http_request(
+			request({
+				url: '/_juno/db/drafts/hello%20world',
+				headers: [['Authorization', 'Bearer secret']]
+			})
+		);
+
+		expect(fromNullable(upgrade)).toBeTruthy();
+	});
+
+	it('should not serve a collection that is not exposed with a query', async () => {
+		const { http_request } = actor;
+
+		const { status_code, upgrade } = await http_request(
+			request({ url: '/_juno/db/notes/hello%20world' })
+		);
+
+		expect(status_code).toEqual(404);
+		expect(fromNullable(upgrade)).toBeUndefined();
+	});
+
+	it('should serve a public document as JSON', async () => {
+		const { http_request_update } = actor;
+
+		const { status_code, headers, body } = await http_request_update(
+			request({ url: '/_juno/db/posts/hello%20world' })
+		);
+
+		expect(status_code).toEqual(200);
+		expect(headers).toContainEqual(['Content-Type', 'application/json; charset=utf-8']);
+
+		const doc = JSON.parse(new TextDecoder().decode(body as Uint8Array));
+
+		expect(doc.collection).toEqual('posts');
+		expect(doc.key).toEqual('hello world');
+		expect(doc.owner).toEqual(controller.getPrincipal().toText());
+		expect(doc.data).toEqual({ title: 'Hello again' });
+		expect(doc.description).toEqual('greetings');
+		expect(doc.version).toEqual('2');
+	});
+
+	it('should serve a public document as Candid', async () => {
+		const { http_request_update } = actor;
+
+		const { status_code, headers } = await http_request_update(
+			request({
+				url: '/_juno/db/posts/hello%20world',
+				headers: [['Accept', 'application/candid']]
+			})
+		);
+
+		expect(status_code).toEqual(200);
+		expect(headers).toContainEqual(['Content-Type', 'application/candid']);
+	});
+
+	it('should not serve a collection that is not readable publicly', async () => {
+		const { http_request_update } = actor;
+
+		const { status_code } = await http_request_update(
+			request({ url: '/_juno/db/drafts/hello%20world' })
+		);
+
+		expect(status_code).toEqual(404);
+	});
+
+	it('should not serve a collection that is not exposed', async () => {
+		const { http_request_update } = actor;
+
+		const { status_code } = await http_request_update(
+			request({ url: '/_juno/db/notes/hello%20world' })
+		);
+
+		expect(status_code).toEqual(404);
+	});
+
+	it('should not serve an unknown document', async () => {
+		const { http_request_update } = actor;
+
+		const { status_code } = await http_request_update(request({ url: '/_juno/db/posts/unknown' }));
+
+		expect(status_code).toEqual(404);
+	});
+});
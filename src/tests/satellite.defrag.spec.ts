@@ -0,0 +1,225 @@
+import type {
+	MemoryRegionStats,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import {
+	CONTROLLER_ERROR_MSG,
+	SATELLITE_ADMIN_ERROR_MSG
+} from './constants/satellite-tests.constants';
+import { tick } from './utils/pic-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Defrag', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const FILES_COLLECTION = 'files';
+	const FULL_PATH = `/${FILES_COLLECTION}/report.txt`;
+
+	const upload = async (chunks: string[]) => {
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+		const file = await init_asset_upload({
+			collection: FILES_COLLECTION,
+			description: toNullable(),
+			encoding_type: [],
+			full_path: FULL_PATH,
+			name: 'report.txt',
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const chunkIds = [];
+
+		for (const [i, content] of chunks.entries()) {
+			const { chunk_id } = await upload_asset_chunk({
+				batch_id: file.batch_id,
+				content: new TextEncoder().encode(content),
+				order_id: [BigInt(i)]
+			});
+
+			chunkIds.push(chunk_id);
+		}
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: chunkIds,
+			headers: [['Content-Type', 'text/plain']]
+		});
+	};
+
+	const contentChunksStats = async (): Promise<MemoryRegionStats | undefined> => {
+		const { get_memory_stats } = actor;
+
+		const stats = await get_memory_stats();
+
+		return stats.find(({ region }) => 'ContentChunks' in region);
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const setRule: SetRule = {
+			memory: toNullable({ Stable: null }),
+			max_size: toNullable(),
+			max_capacity: toNullable(),
+			read: { Managed: null },
+			mutable_permissions: toNullable(),
+			write: { Managed: null },
+			version: toNullable(),
+			rate_config: toNullable(),
+			user_quota: toNullable(),
+			collation: toNullable(),
+			retention: toNullable()
+		};
+
+		const { set_rule } = actor;
+
+		await set_rule({ Storage: null }, FILES_COLLECTION, setRule);
+
+		await upload(['first', 'second', 'third']);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('controller', () => {
+		it('should provide the statistics of each region', async () => {
+			const { get_memory_stats } = actor;
+
+			const stats = await get_memory_stats();
+
+			expect(stats).toHaveLength(6);
+
+			for (const { allocated, used, fragmented } of stats) {
+				expect(fragmented).toEqual(allocated - used);
+			}
+
+			const contentChunks = await contentChunksStats();
+
+			expect(contentChunks?.entries).toEqual(3n);
+			expect(contentChunks?.used).toEqual(16n);
+		});
+
+		it('should count the entries written in a region', async () => {
+			const { set_rule, set_doc, del_doc, get_memory_stats } = actor;
+
+			await set_rule({ Db: null }, 'notes', {
+				memory: toNullable({ Stable: null }),
+				max_size: toNullable(),
+				max_capacity: toNullable(),
+				read: { Managed: null },
+				mutable_permissions: toNullable(),
+				write: { Managed: null },
+				version: toNullable(),
+				rate_config: toNullable(),
+				user_quota: toNullable(),
+				collation: toNullable(),
+				retention: toNullable()
+			});
+
+			const dbStats = async (): Promise<MemoryRegionStats | undefined> =>
+				(await get_memory_stats()).find(({ region }) => 'Db' in region);
+
+			const doc = await set_doc('notes', 'first', {
+				attachments: toNullable(),
+				data: new TextEncoder().encode('hello'),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+
+			const stats = await dbStats();
+
+			expect(stats?.entries).toEqual(1n);
+			expect(stats?.used).toBeGreaterThan(0n);
+
+			await del_doc('notes', 'first', { version: doc.version });
+
+			const deleted = await dbStats();
+
+			expect(deleted?.entries).toEqual(0n);
+			expect(deleted?.used).toEqual(0n);
+		});
+
+		it('should account the chunks of a replaced asset as fragmented', async () => {
+			await upload(['final']);
+
+			const contentChunks = await contentChunksStats();
+
+			expect(contentChunks?.entries).toEqual(3n);
+			expect(contentChunks?.used).toEqual(5n);
+		});
+
+		it('should compact the chunks that are not referenced anymore', async () => {
+			const { compact_content_chunks, get_compaction_report, get_asset } = actor;
+
+			await compact_content_chunks();
+
+			await tick(pic);
+
+			const report = fromNullable(await get_compaction_report());
+
+			expect(report).not.toBeUndefined();
+			expect(fromNullable(report?.completed_at ?? [])).not.toBeUndefined();
+
+			expect(report?.scanned_chunks).toEqual(3n);
+			expect(report?.removed_chunks).toEqual(2n);
+			expect(report?.reclaimed_bytes).toEqual(11n);
+
+			const contentChunks = await contentChunksStats();
+
+			expect(contentChunks?.entries).toEqual(1n);
+
+			const asset = fromNullable(await get_asset(FILES_COLLECTION, FULL_PATH));
+
+			expect(asset).not.toBeUndefined();
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not get the statistics', async () => {
+			const { get_memory_stats } = actor;
+
+			await expect(get_memory_stats()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+
+		it('should not compact the content chunks', async () => {
+			const { compact_content_chunks } = actor;
+
+			await expect(compact_content_chunks()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should not get the report', async () => {
+			const { get_compaction_report } = actor;
+
+			await expect(get_compaction_report()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
@@ -0,0 +1,172 @@
+import type {
+	HttpRequest,
+	_SERVICE as SatelliteActor,
+	StorageConfig
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { Principal } from '@dfinity/principal';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/satellite-tests.constants';
+import { decodeExprPath } from './utils/certification-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Delegation', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const MEDIA_CANISTER_ID = 'ck4tp-3iaaa-aaaal-ab7da-cai';
+	const mediaCanisterId = Principal.fromText(MEDIA_CANISTER_ID);
+
+	const storage: StorageConfig = {
+		headers: [],
+		iframe: toNullable(),
+		redirects: toNullable(),
+		rewrites: [],
+		raw_access: toNullable(),
+		delegations: toNullable(),
+		max_response_body_size: toNullable(),
+		csp_nonce: toNullable(),
+		wildcard_domains: toNullable(),
+		locales: toNullable(),
+		weighted_rewrites: toNullable(),
+		list_limits: toNullable(),
+		max_memory_size: toNullable()
+	};
+
+	const request = (url: string): HttpRequest => ({
+		body: [],
+		certificate_version: toNullable(2),
+		headers: [],
+		method: 'GET',
+		url
+	});
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('user', () => {
+		beforeAll(() => {
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+		});
+
+		it('should not set delegations', async () => {
+			const { set_storage_config } = actor;
+
+			await expect(set_storage_config(storage)).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+
+	describe('controller', () => {
+		beforeAll(async () => {
+			actor.setIdentity(controller);
+
+			const { set_storage_config } = actor;
+
+			await set_storage_config({
+				...storage,
+				delegations: toNullable([
+					['/media/**', { canister_id: mediaCanisterId, raw: toNullable(), domain: toNullable() }],
+					['/videos/**', { canister_id: mediaCanisterId, raw: [true], domain: toNullable() }],
+					[
+						'/logo.png',
+						{ canister_id: mediaCanisterId, raw: toNullable(), domain: ['media.example.com'] }
+					]
+				])
+			});
+
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should upgrade the request of a delegated path', async () => {
+			const { http_request } = actor;
+
+			const { status_code, upgrade } = await http_request(request('/media/cat.jpg'));
+
+			expect(status_code).toEqual(200);
+			expect(fromNullable(upgrade)).toBeTruthy();
+		});
+
+		it('should redirect a delegated path to the target canister', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code, headers } = await http_request_update(
+				request('/media/cat.jpg?token=123')
+			);
+
+			expect(status_code).toEqual(308);
+			expect(headers).toContainEqual([
+				'Location',
+				`https://${MEDIA_CANISTER_ID}.icp0.io/media/cat.jpg?token=123`
+			]);
+		});
+
+		it('should redirect a raw delegated path to the raw domain', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code, headers } = await http_request_update(request('/videos/intro.mp4'));
+
+			expect(status_code).toEqual(308);
+			expect(headers).toContainEqual([
+				'Location',
+				`https://${MEDIA_CANISTER_ID}.raw.icp0.io/videos/intro.mp4`
+			]);
+		});
+
+		it('should answer a certified redirect for a delegated exact path', async () => {
+			const { http_request } = actor;
+
+			const { status_code, headers, upgrade } = await http_request(request('/logo.png'));
+
+			expect(status_code).toEqual(308);
+			expect(fromNullable(upgrade)).toBeUndefined();
+			expect(headers).toContainEqual(['Location', 'https://media.example.com/logo.png']);
+			expect(decodeExprPath(headers)).toEqual(['http_expr', 'logo.png', '<$>']);
+		});
+
+		it('should upgrade a delegated exact path requested with query parameters', async () => {
+			const { http_request, http_request_update } = actor;
+
+			const { upgrade } = await http_request(request('/logo.png?v=2'));
+
+			expect(fromNullable(upgrade)).toBeTruthy();
+
+			const { status_code, headers } = await http_request_update(request('/logo.png?v=2'));
+
+			expect(status_code).toEqual(308);
+			expect(headers).toContainEqual(['Location', 'https://media.example.com/logo.png?v=2']);
+		});
+
+		it('should not upgrade a path that is not delegated', async () => {
+			const { http_request, http_request_update } = actor;
+
+			const { status_code, upgrade } = await http_request(request('/hello.html'));
+
+			expect(status_code).toEqual(404);
+			expect(fromNullable(upgrade)).toBeUndefined();
+
+			const { status_code: status_code_update } = await http_request_update(request('/hello.html'));
+
+			expect(status_code_update).toEqual(405);
+		});
+	});
+});
@@ -0,0 +1,122 @@
+import type { _SERVICE as SatelliteActor } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { createHash } from 'node:crypto';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Diff assets', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const FILES: Record<string, string> = {
+		'/hello.html': '<html>Hello</html>',
+		'/style.css': 'body { color: red }'
+	};
+
+	const sha256 = (content: string): Uint8Array =>
+		createHash('sha256').update(new TextEncoder().encode(content)).digest();
+
+	const upload = async ({ full_path, content }: { full_path: string; content: string }) => {
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+		const file = await init_asset_upload({
+			collection: '#dapp',
+			description: toNullable(),
+			encoding_type: [],
+			full_path,
+			name: full_path.split('/').pop() ?? full_path,
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const chunk = await upload_asset_chunk({
+			batch_id: file.batch_id,
+			content: new TextEncoder().encode(content),
+			order_id: [0n]
+		});
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: [chunk.chunk_id],
+			headers: []
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		for (const [full_path, content] of Object.entries(FILES)) {
+			await upload({ full_path, content });
+		}
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('controller', () => {
+		it('should not report any change for a no-op deployment', async () => {
+			const { diff_assets } = actor;
+
+			const changed = await diff_assets({
+				collection: '#dapp',
+				assets: Object.entries(FILES).map(([full_path, content]) => [full_path, sha256(content)])
+			});
+
+			expect(changed).toEqual([]);
+		});
+
+		it('should report the modified and the new assets', async () => {
+			const { diff_assets } = actor;
+
+			const changed = await diff_assets({
+				collection: '#dapp',
+				assets: [
+					['/hello.html', sha256(FILES['/hello.html'])],
+					['/style.css', sha256('body { color: blue }')],
+					['/new.js', sha256('console.log("new")')]
+				]
+			});
+
+			expect(changed).toEqual(['/style.css', '/new.js']);
+		});
+
+		it('should throw for an unknown collection', async () => {
+			const { diff_assets } = actor;
+
+			await expect(diff_assets({ collection: 'unknown', assets: [] })).rejects.toThrow();
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not diff the assets', async () => {
+			const { diff_assets } = actor;
+
+			await expect(diff_assets({ collection: '#dapp', assets: [] })).rejects.toThrow(
+				CONTROLLER_ERROR_MSG
+			);
+		});
+	});
+});
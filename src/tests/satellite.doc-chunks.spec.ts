@@ -0,0 +1,146 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Doc chunks', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'large';
+	const CHUNK_SIZE = 1_900_000;
+
+	const setRule: SetRule = {
+		memory: toNullable(),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const data = Uint8Array.from({ length: 2_000_000 }, (_, i) => i % 256);
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule, set_doc } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+
+		await set_doc(TEST_COLLECTION, 'large', {
+			attachments: toNullable(),
+			data,
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+
+		await set_doc(TEST_COLLECTION, 'small', {
+			attachments: toNullable(),
+			data: await toArray({ hello: 'World' }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('owner', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should read a large document in chunks', async () => {
+			const { get_doc_chunk } = actor;
+
+			const first = fromNullable(await get_doc_chunk(TEST_COLLECTION, 'large', 0n));
+
+			assertNonNullish(first);
+
+			expect(first.chunks_length).toEqual(2n);
+			expect(first.data_length).toEqual(BigInt(data.length));
+			expect(fromNullable(first.version)).toEqual(1n);
+			expect(first.content).toHaveLength(CHUNK_SIZE);
+
+			const second = fromNullable(await get_doc_chunk(TEST_COLLECTION, 'large', 1n));
+
+			assertNonNullish(second);
+
+			expect(second.content).toHaveLength(data.length - CHUNK_SIZE);
+
+			const content = new Uint8Array([...first.content, ...second.content]);
+
+			expect(content).toEqual(data);
+		});
+
+		it('should read a small document in a single chunk', async () => {
+			const { get_doc_chunk, get_doc } = actor;
+
+			const chunk = fromNullable(await get_doc_chunk(TEST_COLLECTION, 'small', 0n));
+
+			assertNonNullish(chunk);
+
+			expect(chunk.chunks_length).toEqual(1n);
+
+			const doc = fromNullable(await get_doc(TEST_COLLECTION, 'small'));
+
+			assertNonNullish(doc);
+
+			expect(chunk.content).toEqual(doc.data);
+		});
+
+		it('should not read a chunk out of range', async () => {
+			const { get_doc_chunk } = actor;
+
+			await expect(get_doc_chunk(TEST_COLLECTION, 'large', 2n)).rejects.toThrow(
+				'Document chunk index out of range.'
+			);
+		});
+
+		it('should return nothing for an unknown document', async () => {
+			const { get_doc_chunk } = actor;
+
+			expect(fromNullable(await get_doc_chunk(TEST_COLLECTION, 'unknown', 0n))).toBeUndefined();
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not read the chunks of a managed document', async () => {
+			const { get_doc_chunk } = actor;
+
+			expect(fromNullable(await get_doc_chunk(TEST_COLLECTION, 'large', 0n))).toBeUndefined();
+		});
+	});
+});
@@ -0,0 +1,209 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_ADMIN_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Dry run', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_dry_run';
+	const TEST_STORAGE_COLLECTION = 'test_dry_run_storage';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Stable: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: [],
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const keys = ['key-1', 'key-2', 'other-key'];
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule, set_doc } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+		await set_rule({ Storage: null }, TEST_STORAGE_COLLECTION, setRule);
+
+		const data = await toArray({
+			hello: 'World'
+		});
+
+		for (const key of keys) {
+			await set_doc(TEST_COLLECTION, key, {
+				attachments: toNullable(),
+				data,
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+		}
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		it('should report the documents of a collection without deleting those', async () => {
+			const { dry_run, count_collection_docs } = actor;
+
+			const report = await dry_run({ DelDocs: { collection: TEST_COLLECTION } });
+
+			expect(report.keys).toEqual(keys);
+			expect(fromNullable(report.error)).toBeUndefined();
+
+			expect(await count_collection_docs(TEST_COLLECTION)).toEqual(3n);
+		});
+
+		it('should report the filtered documents', async () => {
+			const { dry_run, count_collection_docs } = actor;
+
+			const report = await dry_run({
+				DelFilteredDocs: {
+					collection: TEST_COLLECTION,
+					filter: {
+						matcher: toNullable({
+							key: toNullable('^key-'),
+							description: toNullable(),
+							created_at: toNullable(),
+							updated_at: toNullable(),
+							filter: toNullable()
+						}),
+						order: toNullable(),
+						owner: toNullable(),
+						paginate: toNullable()
+					}
+				}
+			});
+
+			expect(report.keys).toEqual(['key-1', 'key-2']);
+
+			expect(await count_collection_docs(TEST_COLLECTION)).toEqual(3n);
+		});
+
+		it('should report that a collection with documents cannot be deleted', async () => {
+			const { dry_run, list_rules } = actor;
+
+			const report = await dry_run({
+				DelRule: {
+					rules_type: { Db: null },
+					collection: TEST_COLLECTION,
+					rule: { version: toNullable(1n) }
+				}
+			});
+
+			expect(fromNullable(report.error)).toContain(TEST_COLLECTION);
+
+			const rules = await list_rules({ Db: null });
+
+			expect(rules.map(([collection]) => collection)).toContain(TEST_COLLECTION);
+		});
+
+		it('should report that an empty collection can be deleted', async () => {
+			const { dry_run, list_rules } = actor;
+
+			const report = await dry_run({
+				DelRule: {
+					rules_type: { Storage: null },
+					collection: TEST_STORAGE_COLLECTION,
+					rule: { version: toNullable(1n) }
+				}
+			});
+
+			expect(fromNullable(report.error)).toBeUndefined();
+			expect(report.keys).toHaveLength(0);
+
+			const rules = await list_rules({ Storage: null });
+
+			expect(rules.map(([collection]) => collection)).toContain(TEST_STORAGE_COLLECTION);
+		});
+
+		it('should report the rule that would be saved', async () => {
+			const { dry_run, get_rule } = actor;
+
+			const report = await dry_run({
+				SetRule: {
+					rules_type: { Db: null },
+					collection: TEST_COLLECTION,
+					rule: {
+						...setRule,
+						read: { Public: null },
+						version: toNullable(1n)
+					}
+				}
+			});
+
+			const rule = fromNullable(report.rule);
+
+			assertNonNullish(rule);
+
+			expect(rule.read).toEqual({ Public: null });
+			expect(rule.version).toEqual(toNullable(2n));
+
+			const current = fromNullable(await get_rule({ Db: null }, TEST_COLLECTION));
+
+			assertNonNullish(current);
+
+			expect(current.read).toEqual({ Managed: null });
+			expect(current.version).toEqual(toNullable(1n));
+		});
+
+		it('should report the error of a rule change', async () => {
+			const { dry_run } = actor;
+
+			const report = await dry_run({
+				SetRule: {
+					rules_type: { Db: null },
+					collection: TEST_COLLECTION,
+					rule: setRule
+				}
+			});
+
+			expect(fromNullable(report.rule)).toBeUndefined();
+			expect(fromNullable(report.error)).not.toBeUndefined();
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not dry run an operation', async () => {
+			const { dry_run } = actor;
+
+			await expect(dry_run({ DelDocs: { collection: TEST_COLLECTION } })).rejects.toThrow(
+				SATELLITE_ADMIN_ERROR_MSG
+			);
+		});
+	});
+});
@@ -0,0 +1,168 @@
+import type {
+	_SERVICE as SatelliteActor,
+	SetExtensionConfig
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import {
+	INVALID_VERSION_ERROR_MSG,
+	NO_VERSION_ERROR_MSG,
+	SATELLITE_ADMIN_ERROR_MSG
+} from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Extensions', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const traces: SetExtensionConfig = {
+		enabled: true,
+		settings: [['endpoints', 'set_doc,del_doc']],
+		version: toNullable()
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should have no extension configured', async () => {
+			const { list_extensions } = actor;
+
+			expect(await list_extensions()).toHaveLength(0);
+		});
+
+		it('should enable an extension', async () => {
+			const { set_extension_config, list_extensions } = actor;
+
+			const extension = await set_extension_config({ Traces: null }, traces);
+
+			expect(extension.enabled).toBeTruthy();
+			expect(extension.settings).toEqual([['endpoints', 'set_doc,del_doc']]);
+			expect(fromNullable(extension.version)).toEqual(1n);
+
+			const extensions = await list_extensions();
+
+			expect(extensions).toHaveLength(1);
+
+			const [[key]] = extensions;
+
+			expect(key).toEqual({ Traces: null });
+		});
+
+		it('should not update an extension without its current version', async () => {
+			const { set_extension_config } = actor;
+
+			await expect(set_extension_config({ Traces: null }, traces)).rejects.toThrow(
+				NO_VERSION_ERROR_MSG
+			);
+
+			await expect(
+				set_extension_config({ Traces: null }, { ...traces, version: toNullable(5n) })
+			).rejects.toThrow(INVALID_VERSION_ERROR_MSG);
+		});
+
+		it('should disable an extension and keep its settings', async () => {
+			const { set_extension_config } = actor;
+
+			const extension = await set_extension_config(
+				{ Traces: null },
+				{ ...traces, enabled: false, version: toNullable(1n) }
+			);
+
+			expect(extension.enabled).toBeFalsy();
+			expect(extension.settings).toEqual([['endpoints', 'set_doc,del_doc']]);
+			expect(fromNullable(extension.version)).toEqual(2n);
+		});
+
+		it('should toggle the module of an extension', async () => {
+			const { set_traces_config, get_traces_config, list_extensions } = actor;
+
+			expect(fromNullable(await get_traces_config())?.enabled).toBeFalsy();
+
+			await set_traces_config({ enabled: true });
+
+			const extensions = await list_extensions();
+
+			const extension = extensions.find(([key]) => 'Traces' in key);
+
+			expect(extension?.[1].enabled).toBeTruthy();
+			expect(extension?.[1].settings).toEqual([['endpoints', 'set_doc,del_doc']]);
+			expect(fromNullable(extension?.[1].version ?? [])).toEqual(3n);
+
+			expect(fromNullable(await get_traces_config())?.enabled).toBeTruthy();
+		});
+
+		it('should not set invalid settings', async () => {
+			const { set_extension_config } = actor;
+
+			await expect(
+				set_extension_config(
+					{ Traces: null },
+					{ enabled: true, settings: [[' ', 'value']], version: toNullable() }
+				)
+			).rejects.toThrow('The key of a setting of an extension cannot be empty.');
+
+			await expect(
+				set_extension_config(
+					{ Traces: null },
+					{ enabled: true, settings: [['title', 'a'.repeat(1025)]], version: toNullable() }
+				)
+			).rejects.toThrow('The value of a setting of an extension is too long.');
+
+			await expect(
+				set_extension_config(
+					{ Traces: null },
+					{
+						enabled: true,
+						settings: Array.from({ length: 33 }, (_, i): [string, string] => [`key${i}`, 'value']),
+						version: toNullable()
+					}
+				)
+			).rejects.toThrow('An extension cannot have more than 32 settings.');
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not set an extension config', async () => {
+			const { set_extension_config } = actor;
+
+			await expect(set_extension_config({ Traces: null }, traces)).rejects.toThrow(
+				SATELLITE_ADMIN_ERROR_MSG
+			);
+		});
+
+		it('should not list the extensions', async () => {
+			const { list_extensions } = actor;
+
+			await expect(list_extensions()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+	});
+});
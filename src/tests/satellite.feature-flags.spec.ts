@@ -0,0 +1,219 @@
+import type {
+	_SERVICE as SatelliteActor,
+	SetFeatureFlag
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import {
+	INVALID_VERSION_ERROR_MSG,
+	NO_VERSION_ERROR_MSG,
+	SATELLITE_ADMIN_ERROR_MSG
+} from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite feature flags', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	const setFlag: SetFeatureFlag = {
+		rule: { Boolean: true },
+		description: toNullable('A new feature'),
+		version: toNullable()
+	};
+
+	const getFlags = async (): Promise<Record<string, boolean>> => {
+		const { get_feature_flags } = actor;
+
+		return Object.fromEntries(await get_feature_flags());
+	};
+
+	describe('admin', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should set feature flags', async () => {
+			const { set_feature_flag } = actor;
+
+			const flag = await set_feature_flag('boolean', setFlag);
+
+			expect(flag.rule).toEqual({ Boolean: true });
+			expect(flag.description).toEqual(['A new feature']);
+			expect(flag.version).toEqual([1n]);
+			expect(flag.created_at).toBeGreaterThan(0n);
+			expect(flag.updated_at).toBeGreaterThan(0n);
+
+			await set_feature_flag('admin', {
+				...setFlag,
+				rule: { Targeted: [{ Admin: null }] }
+			});
+
+			await set_feature_flag('anonymous', {
+				...setFlag,
+				rule: { Targeted: [{ Anonymous: null }] }
+			});
+
+			await set_feature_flag('full-rollout', {
+				...setFlag,
+				rule: { Percentage: 100 }
+			});
+
+			await set_feature_flag('no-rollout', {
+				...setFlag,
+				rule: { Percentage: 0 }
+			});
+		});
+
+		it('should list feature flags', async () => {
+			const { list_feature_flags } = actor;
+
+			const flags = await list_feature_flags();
+
+			expect(flags.map(([key, _]) => key).sort()).toEqual([
+				'admin',
+				'anonymous',
+				'boolean',
+				'full-rollout',
+				'no-rollout'
+			]);
+		});
+
+		it('should evaluate feature flags for the controller', async () => {
+			expect(await getFlags()).toEqual({
+				admin: true,
+				anonymous: false,
+				boolean: true,
+				'full-rollout': true,
+				'no-rollout': false
+			});
+		});
+
+		it('should update feature flag', async () => {
+			const { set_feature_flag } = actor;
+
+			const flag = await set_feature_flag('boolean', {
+				...setFlag,
+				rule: { Boolean: false },
+				version: [1n]
+			});
+
+			expect(flag.rule).toEqual({ Boolean: false });
+			expect(flag.version).toEqual([2n]);
+
+			const { boolean } = await getFlags();
+
+			expect(boolean).toBeFalsy();
+		});
+
+		it('should throw if update without version', async () => {
+			const { set_feature_flag } = actor;
+
+			await expect(set_feature_flag('boolean', setFlag)).rejects.toThrow(NO_VERSION_ERROR_MSG);
+		});
+
+		it('should throw if update with invalid version', async () => {
+			const { set_feature_flag } = actor;
+
+			await expect(
+				set_feature_flag('boolean', {
+					...setFlag,
+					version: [1n]
+				})
+			).rejects.toThrow(INVALID_VERSION_ERROR_MSG);
+		});
+
+		it('should throw if percentage is invalid', async () => {
+			const { set_feature_flag } = actor;
+
+			await expect(
+				set_feature_flag('invalid', {
+					...setFlag,
+					rule: { Percentage: 101 }
+				})
+			).rejects.toThrow('Feature flag percentage must be between 0 and 100.');
+		});
+
+		it('should delete feature flag', async () => {
+			const { del_feature_flag, list_feature_flags } = actor;
+
+			await del_feature_flag('boolean', { version: [2n] });
+
+			const flags = await list_feature_flags();
+
+			expect(flags.find(([key, _]) => key === 'boolean')).toBeUndefined();
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should evaluate feature flags for anonymous', async () => {
+			expect(await getFlags()).toEqual({
+				admin: false,
+				anonymous: true,
+				'full-rollout': true,
+				'no-rollout': false
+			});
+		});
+	});
+
+	describe('admin guard', () => {
+		beforeAll(() => {
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+		});
+
+		it('should evaluate feature flags for a user', async () => {
+			expect(await getFlags()).toEqual({
+				admin: false,
+				anonymous: false,
+				'full-rollout': true,
+				'no-rollout': false
+			});
+		});
+
+		it('should throw errors on setting feature flag', async () => {
+			const { set_feature_flag } = actor;
+
+			await expect(set_feature_flag('test', setFlag)).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should throw errors on listing feature flags', async () => {
+			const { list_feature_flags } = actor;
+
+			await expect(list_feature_flags()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should throw errors on deleting feature flag', async () => {
+			const { del_feature_flag } = actor;
+
+			await expect(del_feature_flag('admin', { version: [1n] })).rejects.toThrow(
+				SATELLITE_ADMIN_ERROR_MSG
+			);
+		});
+	});
+});
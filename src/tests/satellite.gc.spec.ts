@@ -0,0 +1,254 @@
+import type {
+	MemoryRegionStats,
+	_SERVICE as SatelliteActor,
+	SetDoc,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { tick } from './utils/pic-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Garbage collection', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+	let canisterId: Principal;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const FILES_COLLECTION = 'files';
+	const NOTES_COLLECTION = 'notes';
+
+	const upload = async ({ fullPath, chunks }: { fullPath: string; chunks: string[] }) => {
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+		const file = await init_asset_upload({
+			collection: FILES_COLLECTION,
+			description: toNullable(),
+			encoding_type: [],
+			full_path: fullPath,
+			name: fullPath.split('/').pop() ?? '',
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const chunkIds = [];
+
+		for (const [i, content] of chunks.entries()) {
+			const { chunk_id } = await upload_asset_chunk({
+				batch_id: file.batch_id,
+				content: new TextEncoder().encode(content),
+				order_id: [BigInt(i)]
+			});
+
+			chunkIds.push(chunk_id);
+		}
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: chunkIds,
+			headers: [['Content-Type', 'text/plain']]
+		});
+	};
+
+	const contentChunksStats = async (): Promise<MemoryRegionStats | undefined> => {
+		const { get_memory_stats } = actor;
+
+		const stats = await get_memory_stats();
+
+		return stats.find(({ region }) => 'ContentChunks' in region);
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c, canisterId: cId } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		canisterId = cId;
+
+		actor.setIdentity(controller);
+
+		const setRule: SetRule = {
+			memory: toNullable({ Stable: null }),
+			max_size: toNullable(),
+			max_capacity: toNullable(),
+			read: { Public: null },
+			mutable_permissions: toNullable(),
+			write: { Managed: null },
+			version: toNullable(),
+			rate_config: toNullable(),
+			user_quota: toNullable(),
+			collation: toNullable(),
+			retention: toNullable()
+		};
+
+		const { set_rule } = actor;
+
+		await set_rule({ Storage: null }, FILES_COLLECTION, setRule);
+		await set_rule({ Db: null }, NOTES_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should delete an asset right away and collect its chunks in the background', async () => {
+		const fullPath = `/${FILES_COLLECTION}/large.txt`;
+
+		await upload({
+			fullPath,
+			chunks: Array.from({ length: 50 }, (_, i) => `chunk-${i}`)
+		});
+
+		expect((await contentChunksStats())?.entries).toEqual(50n);
+
+		const { del_asset, get_asset, health } = actor;
+
+		await del_asset(FILES_COLLECTION, fullPath);
+
+		expect(fromNullable(await get_asset(FILES_COLLECTION, fullPath))).toBeUndefined();
+
+		await tick(pic);
+
+		expect((await contentChunksStats())?.entries).toEqual(0n);
+
+		const { pending_deleted_chunks } = await health();
+
+		expect(pending_deleted_chunks).toEqual(0n);
+	});
+
+	it('should keep the chunks of an asset uploaded again before the collection', async () => {
+		const fullPath = `/${FILES_COLLECTION}/report.txt`;
+
+		await upload({ fullPath, chunks: ['first', 'second', 'third'] });
+
+		const { del_asset, get_asset, http_request } = actor;
+
+		await del_asset(FILES_COLLECTION, fullPath);
+
+		await upload({ fullPath, chunks: ['final'] });
+
+		await tick(pic);
+
+		expect((await contentChunksStats())?.entries).toEqual(1n);
+
+		expect(fromNullable(await get_asset(FILES_COLLECTION, fullPath))).not.toBeUndefined();
+
+		const { status_code, body } = await http_request({
+			body: [],
+			certificate_version: toNullable(2),
+			headers: [],
+			method: 'GET',
+			url: fullPath
+		});
+
+		expect(status_code).toEqual(200);
+		expect(new TextDecoder().decode(body as Uint8Array)).toEqual('final');
+	});
+
+	it('should resume the collection after an upgrade', async () => {
+		const fullPath = `/${FILES_COLLECTION}/archive.txt`;
+
+		await upload({ fullPath, chunks: ['a', 'b', 'c'] });
+
+		const { del_assets } = actor;
+
+		await del_assets(FILES_COLLECTION);
+
+		await pic.upgradeCanister({
+			canisterId,
+			wasm: SATELLITE_WASM_PATH,
+			sender: controller.getPrincipal()
+		});
+
+		await tick(pic);
+
+		expect((await contentChunksStats())?.entries).toEqual(0n);
+	});
+
+	describe('documents', () => {
+		const setDoc = async (key: string): Promise<SetDoc> => ({
+			attachments: toNullable(),
+			data: await toArray({ key }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+
+		const setDocs = async (keys: string[]) => {
+			const { set_many_docs } = actor;
+
+			for (let i = 0; i < keys.length; i += 50) {
+				await set_many_docs(
+					await Promise.all(
+						keys
+							.slice(i, i + 50)
+							.map(
+								async (key): Promise<[string, string, SetDoc]> => [
+									NOTES_COLLECTION,
+									key,
+									await setDoc(key)
+								]
+							)
+					)
+				);
+			}
+		};
+
+		it('should delete the first documents right away and the others in the background', async () => {
+			await setDocs(Array.from({ length: 150 }, (_, i) => `note-${i}`));
+
+			const { del_docs, count_collection_docs, health } = actor;
+
+			await del_docs(NOTES_COLLECTION);
+
+			expect(await count_collection_docs(NOTES_COLLECTION)).toEqual(50n);
+
+			const { pending_deleted_docs } = await health();
+
+			expect(pending_deleted_docs).toEqual(50n);
+
+			await tick(pic);
+
+			expect(await count_collection_docs(NOTES_COLLECTION)).toEqual(0n);
+
+			const { pending_deleted_docs: remaining } = await health();
+
+			expect(remaining).toEqual(0n);
+		});
+
+		it('should keep a document written again before its deletion', async () => {
+			await setDocs(Array.from({ length: 101 }, (_, i) => `note-${i}`));
+
+			const { del_docs, get_doc, set_doc, count_collection_docs } = actor;
+
+			await del_docs(NOTES_COLLECTION);
+
+			const doc = fromNullable(await get_doc(NOTES_COLLECTION, 'note-99'));
+
+			expect(doc).not.toBeUndefined();
+
+			await set_doc(NOTES_COLLECTION, 'note-99', {
+				...(await setDoc('updated')),
+				version: doc?.version ?? []
+			});
+
+			await tick(pic);
+
+			expect(await count_collection_docs(NOTES_COLLECTION)).toEqual(1n);
+		});
+	});
+});
@@ -0,0 +1,106 @@
+import type { _SERVICE as SatelliteActor } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/satellite-tests.constants';
+import { decodeExprPath } from './utils/certification-tests.utils';
+import { tick } from './utils/pic-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite health', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+	let canisterId: Principal;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c, canisterId: cId } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		canisterId = cId;
+
+		actor.setIdentity(controller);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should not report health to anonymous', async () => {
+		actor.setIdentity(new AnonymousIdentity());
+
+		const { health } = actor;
+
+		await expect(health()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+
+		actor.setIdentity(controller);
+	});
+
+	it('should report health', async () => {
+		const { health, version } = actor;
+
+		const result = await health();
+
+		expect(result.version).toEqual(await version());
+		expect(result.cycles).toBeGreaterThan(0n);
+		expect(result.memory_size.heap).toBeGreaterThan(0n);
+		expect(result.pending_upload_batches).toEqual(0n);
+		expect(result.certification_pending).toBeFalsy();
+		expect(fromNullable(result.upgraded_at)).toBeUndefined();
+	});
+
+	it('should report last upgrade time', async () => {
+		await pic.upgradeCanister({
+			canisterId,
+			wasm: SATELLITE_WASM_PATH,
+			sender: controller.getPrincipal()
+		});
+
+		await tick(pic);
+
+		const { health } = actor;
+
+		const result = await health();
+
+		expect(fromNullable(result.upgraded_at)).toBeGreaterThan(0n);
+		expect(result.certification_pending).toBeFalsy();
+	});
+
+	it('should serve a certified health over http', async () => {
+		actor.setIdentity(new AnonymousIdentity());
+
+		const { http_request } = actor;
+
+		const { status_code, headers, body, upgrade } = await http_request({
+			body: [],
+			certificate_version: toNullable(2),
+			headers: [],
+			method: 'GET',
+			url: '/_juno/health'
+		});
+
+		expect(status_code).toEqual(200);
+		expect(fromNullable(upgrade)).toBeUndefined();
+		expect(headers).toContainEqual(['Content-Type', 'application/json; charset=utf-8']);
+		expect(decodeExprPath(headers)).toEqual(['http_expr', '_juno', 'health', '<$>']);
+
+		const json = JSON.parse(new TextDecoder().decode(body as Uint8Array));
+
+		expect(json).toEqual({ status: 'ok', version: await actor.version() });
+		expect(json.cycles).toBeUndefined();
+
+		actor.setIdentity(controller);
+	});
+});
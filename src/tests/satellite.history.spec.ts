@@ -0,0 +1,193 @@
+import type {
+	DbConfig,
+	Doc,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { fromArray, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - History', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+	let canisterId: Principal;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const HISTORY_COLLECTION = 'test_history';
+	const OTHER_COLLECTION = 'test_no_history';
+
+	const KEY = 'report';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const dbConfig: DbConfig = {
+		proof_of_work: toNullable(),
+		aggregates: toNullable(),
+		list_limits: toNullable(),
+		max_memory_size: toNullable(),
+		history_collections: toNullable([HISTORY_COLLECTION]),
+		views: toNullable(),
+		attachments: toNullable(),
+		http_collections: toNullable()
+	};
+
+	let first: Doc;
+	let second: Doc;
+	let deletedAt: bigint;
+
+	const setDoc = async ({ status, version }: { status: string; version?: bigint }) => {
+		const { set_doc } = actor;
+
+		return await set_doc(HISTORY_COLLECTION, KEY, {
+			attachments: toNullable(),
+			data: await toArray({ status }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable(version)
+		});
+	};
+
+	const statusAt = async (timestamp: bigint): Promise<string | undefined> => {
+		const { get_doc_at } = actor;
+
+		const doc = fromNullable(await get_doc_at(HISTORY_COLLECTION, KEY, timestamp));
+
+		if (doc === undefined) {
+			return undefined;
+		}
+
+		const { status } = await fromArray<{ status: string }>(doc.data);
+
+		return status;
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c, canisterId: cId } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		canisterId = cId;
+		actor.setIdentity(controller);
+
+		const { set_rule, set_db_config, del_doc } = actor;
+
+		await set_rule({ Db: null }, HISTORY_COLLECTION, setRule);
+		await set_rule({ Db: null }, OTHER_COLLECTION, setRule);
+
+		await set_db_config(dbConfig);
+
+		first = await setDoc({ status: 'open' });
+
+		await pic.advanceTime(1000);
+
+		second = await setDoc({ status: 'closed', version: fromNullable(first.version) });
+
+		await pic.advanceTime(1000);
+
+		await del_doc(HISTORY_COLLECTION, KEY, { version: second.version });
+
+		deletedAt = BigInt(await pic.getTime()) * 1_000_000n;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('controller', () => {
+		it('should read nothing before the creation', async () => {
+			expect(await statusAt(first.created_at - 1n)).toBeUndefined();
+		});
+
+		it('should read the states of the document in the past', async () => {
+			expect(await statusAt(first.updated_at)).toEqual('open');
+			expect(await statusAt(second.updated_at - 1n)).toEqual('open');
+			expect(await statusAt(second.updated_at)).toEqual('closed');
+		});
+
+		it('should read nothing after the deletion', async () => {
+			expect(await statusAt(deletedAt)).toBeUndefined();
+		});
+
+		it('should not read the history of a collection without history', async () => {
+			const { get_doc_at } = actor;
+
+			await expect(get_doc_at(OTHER_COLLECTION, KEY, deletedAt)).rejects.toThrow(
+				'The history is not enabled for this collection.'
+			);
+		});
+
+		it('should keep the revisions in their own region of the stable memory', async () => {
+			const { get_memory_stats } = actor;
+
+			const stats = await get_memory_stats();
+
+			const revisions = stats.find(({ region }) => 'Revisions' in region);
+
+			// The creation, the update and the deletion
+			expect(revisions?.entries).toEqual(3n);
+		});
+
+		it('should read the states of the document in the past after an upgrade', async () => {
+			await pic.upgradeCanister({
+				canisterId,
+				wasm: SATELLITE_WASM_PATH,
+				sender: controller.getPrincipal()
+			});
+
+			expect(await statusAt(first.created_at - 1n)).toBeUndefined();
+			expect(await statusAt(second.updated_at - 1n)).toEqual('open');
+			expect(await statusAt(second.updated_at)).toEqual('closed');
+			expect(await statusAt(deletedAt)).toBeUndefined();
+		});
+
+		it('should not read outside of the retention window', async () => {
+			const { get_doc_at } = actor;
+
+			await expect(get_doc_at(HISTORY_COLLECTION, KEY, 0n)).rejects.toThrow(
+				'The requested time is outside the retention window of the history.'
+			);
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not read the history', async () => {
+			const { get_doc_at } = actor;
+
+			await expect(get_doc_at(HISTORY_COLLECTION, KEY, deletedAt)).rejects.toThrow(
+				CONTROLLER_ERROR_MSG
+			);
+		});
+	});
+});
@@ -0,0 +1,189 @@
+import type { ImportUser, _SERVICE as SatelliteActor } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, fromArray, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_ADMIN_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Import users', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const first = Ed25519KeyIdentity.generate();
+	const second = Ed25519KeyIdentity.generate();
+
+	const createdAt = 1_700_000_000_000_000_000n;
+
+	const user = async ({
+		identity,
+		provider
+	}: {
+		identity: Ed25519KeyIdentity;
+		provider: string;
+	}): Promise<ImportUser> => ({
+		user_id: identity.getPrincipal(),
+		data: await toArray({ provider }),
+		created_at: toNullable(createdAt)
+	});
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		it('should import users with their original creation', async () => {
+			const { import_users, get_doc } = actor;
+
+			const { imported, skipped } = await import_users({
+				users: [
+					await user({ identity: first, provider: 'internet_identity' }),
+					await user({ identity: second, provider: 'nfid' })
+				],
+				conflict: toNullable()
+			});
+
+			expect(imported).toHaveLength(2);
+			expect(skipped).toHaveLength(0);
+
+			const doc = fromNullable(await get_doc('#user', first.getPrincipal().toText()));
+
+			assertNonNullish(doc);
+
+			expect(doc.owner.toText()).toEqual(first.getPrincipal().toText());
+			expect(doc.created_at).toEqual(createdAt);
+			expect(await fromArray(doc.data)).toEqual({ provider: 'internet_identity' });
+		});
+
+		it('should skip the users that already exist', async () => {
+			const { import_users } = actor;
+
+			const { imported, skipped } = await import_users({
+				users: [await user({ identity: first, provider: 'other' })],
+				conflict: toNullable({ Skip: null })
+			});
+
+			expect(imported).toHaveLength(0);
+			expect(skipped.map((p) => p.toText())).toEqual([first.getPrincipal().toText()]);
+		});
+
+		it('should overwrite the users that already exist', async () => {
+			const { import_users, get_doc } = actor;
+
+			const { imported } = await import_users({
+				users: [await user({ identity: first, provider: 'other' })],
+				conflict: toNullable({ Overwrite: null })
+			});
+
+			expect(imported).toHaveLength(1);
+
+			const doc = fromNullable(await get_doc('#user', first.getPrincipal().toText()));
+
+			assertNonNullish(doc);
+
+			expect(await fromArray(doc.data)).toEqual({ provider: 'other' });
+			expect(fromNullable(doc.version)).toEqual(2n);
+		});
+
+		it('should reject the whole batch if a user exists', async () => {
+			const { import_users, get_doc } = actor;
+
+			const third = Ed25519KeyIdentity.generate();
+
+			await expect(
+				import_users({
+					users: [
+						await user({ identity: third, provider: 'nfid' }),
+						await user({ identity: second, provider: 'nfid' })
+					],
+					conflict: toNullable({ Reject: null })
+				})
+			).rejects.toThrow('User at index 1 already exists.');
+
+			expect(fromNullable(await get_doc('#user', third.getPrincipal().toText()))).toBeUndefined();
+		});
+
+		it('should not import a user twice in a batch', async () => {
+			const { import_users } = actor;
+
+			const third = Ed25519KeyIdentity.generate();
+
+			await expect(
+				import_users({
+					users: [
+						await user({ identity: third, provider: 'nfid' }),
+						await user({ identity: third, provider: 'nfid' })
+					],
+					conflict: toNullable()
+				})
+			).rejects.toThrow('User at index 1 is provided more than once.');
+		});
+
+		it('should not import an anonymous user', async () => {
+			const { import_users } = actor;
+
+			await expect(
+				import_users({
+					users: [
+						{
+							user_id: new AnonymousIdentity().getPrincipal(),
+							data: await toArray({ provider: 'nfid' }),
+							created_at: toNullable()
+						}
+					],
+					conflict: toNullable()
+				})
+			).rejects.toThrow('User at index 0 cannot be anonymous.');
+		});
+
+		it('should not import a user with data that is not a JSON object', async () => {
+			const { import_users } = actor;
+
+			await expect(
+				import_users({
+					users: [
+						{
+							user_id: Ed25519KeyIdentity.generate().getPrincipal(),
+							data: [1, 2, 3],
+							created_at: toNullable()
+						}
+					],
+					conflict: toNullable()
+				})
+			).rejects.toThrow('Data of the user at index 0 is not a JSON object.');
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not import users', async () => {
+			const { import_users } = actor;
+
+			await expect(import_users({ users: [], conflict: toNullable() })).rejects.toThrow(
+				SATELLITE_ADMIN_ERROR_MSG
+			);
+		});
+	});
+});
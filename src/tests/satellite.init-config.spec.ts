@@ -0,0 +1,162 @@
+import type {
+	ConfigBundle,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { IDL } from '@dfinity/candid';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Init config', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const writer = Ed25519KeyIdentity.generate();
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const SetController = IDL.Record({
+		metadata: IDL.Vec(IDL.Tuple(IDL.Text, IDL.Text)),
+		scope: IDL.Variant({ Write: IDL.Null, Admin: IDL.Null }),
+		expires_at: IDL.Opt(IDL.Nat64)
+	});
+
+	const SatelliteArgs = IDL.Record({
+		controllers: IDL.Vec(IDL.Principal),
+		init_config: IDL.Opt(
+			IDL.Record({
+				bundle: IDL.Opt(IDL.Vec(IDL.Nat8)),
+				controllers: IDL.Vec(IDL.Tuple(IDL.Principal, SetController))
+			})
+		)
+	});
+
+	const encodeBundle = (bundle: ConfigBundle): Uint8Array => {
+		const service = idlFactorSatellite({ IDL });
+
+		const [_, func] = service._fields.find(
+			([name]: [string, IDL.FuncClass]) => name === 'get_config_bundle'
+		);
+
+		return new Uint8Array(IDL.encode(func.retTypes, [bundle]));
+	};
+
+	const initArgs = ({
+		bundle,
+		writers
+	}: {
+		bundle: Uint8Array;
+		writers: Principal[];
+	}): ArrayBuffer =>
+		IDL.encode(
+			[SatelliteArgs],
+			[
+				{
+					controllers: [controller.getPrincipal()],
+					init_config: [
+						{
+							bundle: [bundle],
+							controllers: writers.map((id) => [
+								id,
+								{ metadata: [], scope: { Write: null }, expires_at: [] }
+							])
+						}
+					]
+				}
+			]
+		);
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: source } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		source.setIdentity(controller);
+
+		const { set_rule, get_config_bundle } = source;
+
+		await set_rule({ Db: null }, 'notes', setRule);
+		await set_rule({ Storage: null }, 'images', setRule);
+
+		const bundle = await get_config_bundle();
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: initArgs({
+				bundle: encodeBundle(bundle),
+				writers: [writer.getPrincipal()]
+			}),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should create the collections of the bundle', async () => {
+		const { list_rules } = actor;
+
+		const db = await list_rules({ Db: null });
+		expect(db.map(([collection]) => collection)).toContain('notes');
+
+		const storage = await list_rules({ Storage: null });
+		expect(storage.map(([collection]) => collection)).toContain('images');
+	});
+
+	it('should set the controllers with their scopes', async () => {
+		const { list_controllers } = actor;
+
+		const controllers = await list_controllers();
+
+		const writerController = controllers.find(
+			([id]) => id.toText() === writer.getPrincipal().toText()
+		);
+
+		expect(writerController?.[1].scope).toEqual({ Write: null });
+
+		const adminController = controllers.find(
+			([id]) => id.toText() === controller.getPrincipal().toText()
+		);
+
+		expect(adminController?.[1].scope).toEqual({ Admin: null });
+	});
+
+	it('should not install a satellite with an invalid bundle', async () => {
+		await expect(
+			pic.setupCanister<SatelliteActor>({
+				idlFactory: idlFactorSatellite,
+				wasm: SATELLITE_WASM_PATH,
+				arg: initArgs({ bundle: new Uint8Array([1, 2, 3]), writers: [] }),
+				sender: controller.getPrincipal()
+			})
+		).rejects.toThrow('The configuration bundle of the init arguments cannot be decoded:');
+	});
+});
@@ -0,0 +1,113 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/satellite-tests.constants';
+import { tick } from './utils/pic-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Integrity', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+	let canisterId: Principal;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const setRule: SetRule = {
+		memory: toNullable({ Stable: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c, canisterId: cId } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		canisterId = cId;
+
+		actor.setIdentity(controller);
+
+		const { set_rule, set_doc } = actor;
+
+		await set_rule({ Db: null }, 'notes', setRule);
+		await set_rule({ Db: null }, 'drafts', { ...setRule, memory: toNullable({ Heap: null }) });
+		await set_rule({ Storage: null }, 'files', setRule);
+
+		for (const collection of ['notes', 'drafts']) {
+			await set_doc(collection, 'first', {
+				attachments: toNullable(),
+				data: await toArray({ hello: 'world' }),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+		}
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('controller', () => {
+		it('should not provide a report before an upgrade', async () => {
+			const { get_integrity_report } = actor;
+
+			expect(fromNullable(await get_integrity_report())).toBeUndefined();
+		});
+
+		it('should check the state after an upgrade', async () => {
+			await pic.upgradeCanister({
+				canisterId,
+				wasm: SATELLITE_WASM_PATH,
+				sender: controller.getPrincipal()
+			});
+
+			await tick(pic);
+
+			const { get_integrity_report, version } = actor;
+
+			const report = fromNullable(await get_integrity_report());
+
+			assertNonNullish(report);
+
+			expect(report.version).toEqual(await version());
+			expect(fromNullable(report.completed_at)).toBeGreaterThanOrEqual(report.started_at);
+			expect(report.checked_collections).toBeGreaterThanOrEqual(3n);
+			expect(report.issues_count).toEqual(0n);
+			expect(report.issues).toHaveLength(0);
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not get the report', async () => {
+			const { get_integrity_report } = actor;
+
+			await expect(get_integrity_report()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
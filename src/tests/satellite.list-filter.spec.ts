@@ -0,0 +1,183 @@
+import type {
+	ListFilter,
+	ListParams,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - List filter', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_list_filter';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Public: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	interface Matchers {
+		key?: string;
+		description?: string;
+		owner?: Principal;
+	}
+
+	const params = (
+		filter: ListFilter,
+		{ key, description, owner }: Matchers = {}
+	): ListParams => ({
+		matcher: toNullable({
+			key: toNullable(key),
+			description: toNullable(description),
+			created_at: toNullable(),
+			updated_at: toNullable(),
+			filter: toNullable(filter)
+		}),
+		order: toNullable(),
+		owner: toNullable(owner),
+		paginate: toNullable()
+	});
+
+	const listKeys = async (filter: ListFilter, matchers?: Matchers): Promise<string[]> => {
+		const { list_docs } = actor;
+
+		const { items } = await list_docs(TEST_COLLECTION, params(filter, matchers));
+
+		return items.map(([key]) => key).sort();
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+
+		const docs: { key: string; description?: string; identity: Ed25519KeyIdentity }[] = [
+			{ key: 'apple', description: 'fruit', identity: controller },
+			{ key: 'apricot', description: 'fruit', identity: user },
+			{ key: 'banana', description: 'fruit', identity: controller },
+			{ key: 'broccoli', description: 'vegetable', identity: user },
+			{ key: 'cherry', identity: controller }
+		];
+
+		for (const { key, description, identity } of docs) {
+			actor.setIdentity(identity);
+
+			const { set_doc } = actor;
+
+			await set_doc(TEST_COLLECTION, key, {
+				attachments: toNullable(),
+				data: await toArray({ key }),
+				description: toNullable(description),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+		}
+
+		actor.setIdentity(controller);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should filter the keys with a prefix', async () => {
+		expect(await listKeys({ Key: { Prefix: 'ap' } })).toEqual(['apple', 'apricot']);
+	});
+
+	it('should filter the keys in a set', async () => {
+		expect(await listKeys({ Key: { In: ['banana', 'cherry', 'unknown'] } })).toEqual([
+			'banana',
+			'cherry'
+		]);
+	});
+
+	it('should compare the keys', async () => {
+		expect(await listKeys({ Key: { GreaterThan: 'banana' } })).toEqual(['broccoli', 'cherry']);
+		expect(await listKeys({ Key: { LessThan: 'apricot' } })).toEqual(['apple']);
+	});
+
+	it('should filter the owners', async () => {
+		expect(await listKeys({ Owner: { Equal: user.getPrincipal() } })).toEqual([
+			'apricot',
+			'broccoli'
+		]);
+	});
+
+	it('should not match a description that is not set', async () => {
+		expect(await listKeys({ Not: { Description: { Equal: 'fruit' } } })).toEqual([
+			'broccoli',
+			'cherry'
+		]);
+	});
+
+	it('should combine the filters', async () => {
+		expect(
+			await listKeys({
+				And: [
+					{ Description: { Equal: 'fruit' } },
+					{
+						Or: [{ Key: { Prefix: 'b' } }, { Owner: { Equal: user.getPrincipal() } }]
+					}
+				]
+			})
+		).toEqual(['apricot', 'banana']);
+	});
+
+	it('should match all documents with an empty And and none with an empty Or', async () => {
+		expect(await listKeys({ And: [] })).toHaveLength(5);
+		expect(await listKeys({ Or: [] })).toHaveLength(0);
+	});
+
+	it('should filter the timestamps', async () => {
+		const keys = await listKeys({ CreatedAt: { GreaterThan: 0n } });
+
+		expect(keys).toHaveLength(5);
+	});
+
+	it('should combine the filter with the other matchers and the owner', async () => {
+		const filter: ListFilter = { Description: { Equal: 'fruit' } };
+
+		expect(await listKeys(filter, { key: '^a' })).toEqual(['apple', 'apricot']);
+		expect(await listKeys(filter, { description: 'vegetable' })).toEqual([]);
+		expect(await listKeys(filter, { owner: user.getPrincipal() })).toEqual(['apricot']);
+	});
+
+	it('should apply the filter to the count', async () => {
+		const { count_docs } = actor;
+
+		expect(await count_docs(TEST_COLLECTION, params({ Key: { Prefix: 'b' } }))).toEqual(2n);
+	});
+});
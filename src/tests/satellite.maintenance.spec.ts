@@ -0,0 +1,151 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import {
+	CONTROLLER_ERROR_MSG,
+	SATELLITE_ADMIN_ERROR_MSG
+} from './constants/satellite-tests.constants';
+import { tick } from './utils/pic-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Maintenance', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const setDoc = async ({ collection, key }: { collection: string; key: string }) => {
+		const { set_doc } = actor;
+
+		await set_doc(collection, key, {
+			attachments: toNullable(),
+			data: await toArray({ hello: key }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, 'notes', setRule);
+		await set_rule({ Db: null }, 'archive', { ...setRule, memory: toNullable({ Stable: null }) });
+
+		for (const key of ['first', 'second', 'third']) {
+			await setDoc({ collection: 'notes', key });
+			await setDoc({ collection: 'archive', key });
+		}
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		it('should rebuild the indexes of a collection', async () => {
+			const { rebuild_indexes, get_collection_job_report, get_collection_stats } = actor;
+
+			await rebuild_indexes('notes');
+
+			await tick(pic);
+
+			const report = fromNullable(await get_collection_job_report());
+
+			expect(report).not.toBeUndefined();
+			expect(report?.kind).toEqual({ RebuildIndexes: null });
+			expect(report?.collection).toEqual('notes');
+			expect(fromNullable(report?.completed_at ?? [])).not.toBeUndefined();
+			expect(fromNullable(report?.error ?? [])).toBeUndefined();
+			expect(report?.total_docs).toEqual(3n);
+			expect(report?.processed_docs).toEqual(3n);
+
+			const { docs } = await get_collection_stats('notes');
+
+			expect(fromNullable(docs)?.items_count).toEqual(3n);
+		});
+
+		it('should not compact a collection in heap memory', async () => {
+			const { compact_collection } = actor;
+
+			await expect(compact_collection('notes')).rejects.toThrow(
+				'Only the collections in stable memory can be compacted.'
+			);
+		});
+
+		it('should compact a collection in stable memory', async () => {
+			const { compact_collection, get_collection_job_report, get_doc } = actor;
+
+			await compact_collection('archive');
+
+			await tick(pic);
+
+			const report = fromNullable(await get_collection_job_report());
+
+			expect(report?.kind).toEqual({ CompactCollection: null });
+			expect(report?.collection).toEqual('archive');
+			expect(fromNullable(report?.completed_at ?? [])).not.toBeUndefined();
+			expect(report?.processed_docs).toEqual(3n);
+
+			const doc = fromNullable(await get_doc('archive', 'first'));
+
+			expect(doc).not.toBeUndefined();
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not rebuild the indexes', async () => {
+			const { rebuild_indexes } = actor;
+
+			await expect(rebuild_indexes('notes')).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should not compact a collection', async () => {
+			const { compact_collection } = actor;
+
+			await expect(compact_collection('archive')).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should not get the report', async () => {
+			const { get_collection_job_report } = actor;
+
+			await expect(get_collection_job_report()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
@@ -0,0 +1,151 @@
+import type {
+	HttpRequest,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { Principal } from '@dfinity/principal';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { decodeExprPath } from './utils/certification-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Manifest', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const request: HttpRequest = {
+		body: [],
+		certificate_version: toNullable(2),
+		headers: [],
+		method: 'GET',
+		url: '/_juno/manifest.json'
+	};
+
+	const setRule: SetRule = {
+		memory: toNullable(),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Public: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const getManifest = async () => {
+		actor.setIdentity(new AnonymousIdentity());
+
+		const { http_request } = actor;
+
+		const { status_code, headers, body, upgrade } = await http_request(request);
+
+		expect(status_code).toEqual(200);
+		expect(fromNullable(upgrade)).toBeUndefined();
+		expect(headers).toContainEqual(['Content-Type', 'application/json; charset=utf-8']);
+		expect(decodeExprPath(headers)).toEqual(['http_expr', '_juno', 'manifest.json', '<$>']);
+
+		return JSON.parse(new TextDecoder().decode(body as Uint8Array));
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should serve the same manifest with an update call', async () => {
+		actor.setIdentity(new AnonymousIdentity());
+
+		const { http_request_update } = actor;
+
+		const { status_code, body } = await http_request_update(request);
+
+		expect(status_code).toEqual(200);
+
+		const manifest = JSON.parse(new TextDecoder().decode(body as Uint8Array));
+
+		expect(manifest).toEqual(await getManifest());
+	});
+
+	it('should describe a new satellite', async () => {
+		const manifest = await getManifest();
+
+		expect(manifest.versions.satellite).toEqual(await actor.version());
+
+		expect(manifest.features).toEqual({
+			authentication: false,
+			analytics: false,
+			feature_flags: false,
+			moderation: false,
+			traces: false
+		});
+
+		expect(manifest.collections).toEqual({ db: [], storage: [] });
+
+		expect(manifest.endpoints).toEqual(['/_juno/health', '/_juno/manifest.json']);
+	});
+
+	it('should list the public collections only', async () => {
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, 'posts', setRule);
+		await set_rule({ Db: null }, 'drafts', { ...setRule, read: { Managed: null } });
+		await set_rule({ Storage: null }, 'images', setRule);
+
+		const { collections } = await getManifest();
+
+		expect(collections).toEqual({ db: ['posts'], storage: ['images'] });
+	});
+
+	it('should describe the enabled features and their endpoints', async () => {
+		actor.setIdentity(controller);
+
+		const { set_moderation_config, set_traces_config, set_analytics_config } = actor;
+
+		await set_moderation_config({ db: ['posts'], storage: [] });
+		await set_traces_config({ enabled: true });
+		await set_analytics_config({
+			orbiter_id: [Principal.fromText('ck4tp-3iaaa-aaaal-ab7da-cai')],
+			rate_config: []
+		});
+
+		const { features, endpoints } = await getManifest();
+
+		expect(features).toEqual({
+			authentication: false,
+			analytics: true,
+			feature_flags: false,
+			moderation: true,
+			traces: true
+		});
+
+		expect(endpoints).toEqual([
+			'/_juno/health',
+			'/_juno/manifest.json',
+			'/_juno/analytics/page-view',
+			'/_juno/analytics/track-event'
+		]);
+	});
+});
@@ -0,0 +1,319 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, toArray } from '@junobuild/utils';
+import { nanoid } from 'nanoid';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import {
+	CONTROLLER_ERROR_MSG,
+	SATELLITE_ADMIN_ERROR_MSG
+} from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Moderation', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'reviews';
+	const TEST_STORAGE_COLLECTION = 'uploads';
+
+	const setRule: SetRule = {
+		memory: toNullable(),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Public: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule, set_moderation_config } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+		await set_rule({ Storage: null }, TEST_STORAGE_COLLECTION, setRule);
+
+		await set_moderation_config({
+			db: [TEST_COLLECTION],
+			storage: [TEST_STORAGE_COLLECTION]
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	const listKeys = async (identity: Ed25519KeyIdentity | AnonymousIdentity): Promise<string[]> => {
+		actor.setIdentity(identity);
+
+		const { list_docs } = actor;
+
+		const { items } = await list_docs(TEST_COLLECTION, {
+			matcher: toNullable(),
+			order: toNullable(),
+			owner: toNullable(),
+			paginate: toNullable()
+		});
+
+		return items.map(([key]) => key);
+	};
+
+	describe('documents', () => {
+		const key = nanoid();
+
+		it('should save the document of a user as pending', async () => {
+			actor.setIdentity(user);
+
+			const { set_doc } = actor;
+
+			const doc = await set_doc(TEST_COLLECTION, key, {
+				attachments: toNullable(),
+				data: await toArray({ review: 'Great' }),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+
+			expect(fromNullable(doc.moderation)).toEqual({ Pending: null });
+		});
+
+		it('should list a pending document only for its owner', async () => {
+			expect(await listKeys(user)).toEqual([key]);
+			expect(await listKeys(new AnonymousIdentity())).toEqual([]);
+		});
+
+		it('should approve the document', async () => {
+			actor.setIdentity(controller);
+
+			const { get_doc, set_doc_moderation } = actor;
+
+			const current = fromNullable(await get_doc(TEST_COLLECTION, key));
+
+			assertNonNullish(current);
+
+			const doc = await set_doc_moderation(TEST_COLLECTION, key, {
+				status: { Approved: null },
+				version: current.version
+			});
+
+			expect(fromNullable(doc.moderation)).toEqual({ Approved: null });
+
+			expect(await listKeys(new AnonymousIdentity())).toEqual([key]);
+		});
+
+		it('should review the document again when it is updated by its owner', async () => {
+			actor.setIdentity(user);
+
+			const { get_doc, set_doc } = actor;
+
+			const current = fromNullable(await get_doc(TEST_COLLECTION, key));
+
+			assertNonNullish(current);
+
+			const doc = await set_doc(TEST_COLLECTION, key, {
+				...current,
+				data: await toArray({ review: 'Great!!!' })
+			});
+
+			expect(fromNullable(doc.moderation)).toEqual({ Pending: null });
+
+			expect(await listKeys(new AnonymousIdentity())).toEqual([]);
+		});
+
+		it('should reject the document', async () => {
+			actor.setIdentity(controller);
+
+			const { get_doc, set_doc_moderation } = actor;
+
+			const current = fromNullable(await get_doc(TEST_COLLECTION, key));
+
+			assertNonNullish(current);
+
+			await set_doc_moderation(TEST_COLLECTION, key, {
+				status: { Rejected: null },
+				version: current.version
+			});
+
+			expect(await listKeys(new AnonymousIdentity())).toEqual([]);
+			expect(await listKeys(user)).toEqual([key]);
+		});
+
+		it('should approve the documents of the controllers right away', async () => {
+			actor.setIdentity(controller);
+
+			const { set_doc } = actor;
+
+			const doc = await set_doc(TEST_COLLECTION, nanoid(), {
+				attachments: toNullable(),
+				data: await toArray({ review: 'Official' }),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+
+			expect(fromNullable(doc.moderation)).toEqual({ Approved: null });
+		});
+
+		it('should not moderate a document of a collection that does not require it', async () => {
+			actor.setIdentity(controller);
+
+			const { set_rule, set_doc, set_doc_moderation } = actor;
+
+			await set_rule({ Db: null }, 'other', setRule);
+
+			const doc = await set_doc('other', key, {
+				attachments: toNullable(),
+				data: await toArray({ hello: 'World' }),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+
+			expect(fromNullable(doc.moderation)).toBeUndefined();
+
+			await expect(
+				set_doc_moderation('other', key, {
+					status: { Approved: null },
+					version: doc.version
+				})
+			).rejects.toThrow('Collection does not require moderation.');
+		});
+	});
+
+	describe('assets', () => {
+		const full_path = `/${TEST_STORAGE_COLLECTION}/hello.html`;
+
+		const requestStatusCode = async (): Promise<number> => {
+			const { http_request } = actor;
+
+			const { status_code } = await http_request({
+				body: [],
+				certificate_version: toNullable(2),
+				headers: [],
+				method: 'GET',
+				url: full_path
+			});
+
+			return status_code;
+		};
+
+		it('should not serve the asset of a user until approved', async () => {
+			actor.setIdentity(user);
+
+			const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+			const file = await init_asset_upload({
+				collection: TEST_STORAGE_COLLECTION,
+				description: toNullable(),
+				encoding_type: [],
+				full_path,
+				name: 'hello.html',
+				publish_at: toNullable(),
+				token: toNullable(),
+				unpublish_at: toNullable()
+			});
+
+			const chunk = await upload_asset_chunk({
+				batch_id: file.batch_id,
+				content: new TextEncoder().encode('<html><body>Hello</body></html>'),
+				order_id: [0n]
+			});
+
+			await commit_asset_upload({
+				batch_id: file.batch_id,
+				chunk_ids: [chunk.chunk_id],
+				headers: []
+			});
+
+			expect(await requestStatusCode()).toEqual(404);
+		});
+
+		it('should serve the asset once approved', async () => {
+			actor.setIdentity(controller);
+
+			const { get_asset, set_asset_moderation } = actor;
+
+			const current = fromNullable(await get_asset(TEST_STORAGE_COLLECTION, full_path));
+
+			assertNonNullish(current);
+
+			expect(fromNullable(current.key.moderation)).toEqual({ Pending: null });
+
+			const asset = await set_asset_moderation(TEST_STORAGE_COLLECTION, full_path, {
+				status: { Approved: null },
+				version: current.version
+			});
+
+			expect(fromNullable(asset.key.moderation)).toEqual({ Approved: null });
+
+			expect(await requestStatusCode()).toEqual(200);
+		});
+
+		it('should not serve the asset anymore once rejected', async () => {
+			actor.setIdentity(controller);
+
+			const { get_asset, set_asset_moderation } = actor;
+
+			const current = fromNullable(await get_asset(TEST_STORAGE_COLLECTION, full_path));
+
+			assertNonNullish(current);
+
+			await set_asset_moderation(TEST_STORAGE_COLLECTION, full_path, {
+				status: { Rejected: null },
+				version: current.version
+			});
+
+			expect(await requestStatusCode()).toEqual(404);
+		});
+	});
+
+	describe('user', () => {
+		beforeAll(() => {
+			actor.setIdentity(user);
+		});
+
+		it('should not set the moderation config', async () => {
+			const { set_moderation_config } = actor;
+
+			await expect(set_moderation_config({ db: [], storage: [] })).rejects.toThrow(
+				SATELLITE_ADMIN_ERROR_MSG
+			);
+		});
+
+		it('should not moderate a document', async () => {
+			const { set_doc_moderation } = actor;
+
+			await expect(
+				set_doc_moderation(TEST_COLLECTION, 'key', {
+					status: { Approved: null },
+					version: toNullable()
+				})
+			).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
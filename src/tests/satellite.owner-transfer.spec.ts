@@ -0,0 +1,236 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Owner transfer', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const user1 = Ed25519KeyIdentity.generate();
+	const user2 = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_owner_transfer';
+	const TEST_KEY = 'doc-1';
+
+	const TEST_STORAGE_COLLECTION = 'test_owner_transfer_storage';
+	const TEST_FULL_PATH = `/${TEST_STORAGE_COLLECTION}/hello.html`;
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: [],
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+		await set_rule({ Storage: null }, TEST_STORAGE_COLLECTION, setRule);
+
+		actor.setIdentity(user1);
+
+		const { set_doc, init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+		await set_doc(TEST_COLLECTION, TEST_KEY, {
+			attachments: toNullable(),
+			data: await toArray({ hello: 'World' }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+
+		const file = await init_asset_upload({
+			collection: TEST_STORAGE_COLLECTION,
+			description: toNullable(),
+			encoding_type: [],
+			full_path: TEST_FULL_PATH,
+			name: 'hello.html',
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const chunk = await upload_asset_chunk({
+			batch_id: file.batch_id,
+			content: new TextEncoder().encode('<html><body>Hello</body></html>'),
+			order_id: [0n]
+		});
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: [chunk.chunk_id],
+			headers: []
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('controller', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should transfer the owner of a document', async () => {
+			const { get_doc, set_doc_owner } = actor;
+
+			const current = fromNullable(await get_doc(TEST_COLLECTION, TEST_KEY));
+
+			assertNonNullish(current);
+
+			const doc = await set_doc_owner(TEST_COLLECTION, TEST_KEY, {
+				owner: user2.getPrincipal(),
+				version: current.version
+			});
+
+			expect(doc.owner.toText()).toEqual(user2.getPrincipal().toText());
+			expect(doc.data).toEqual(current.data);
+			expect(fromNullable(doc.version)).toEqual((fromNullable(current.version) ?? 0n) + 1n);
+		});
+
+		it('should let the new owner read the document', async () => {
+			actor.setIdentity(user2);
+
+			const { get_doc } = actor;
+
+			const doc = fromNullable(await get_doc(TEST_COLLECTION, TEST_KEY));
+
+			expect(doc?.owner.toText()).toEqual(user2.getPrincipal().toText());
+
+			actor.setIdentity(controller);
+		});
+
+		it('should not let the previous owner read the document anymore', async () => {
+			actor.setIdentity(user1);
+
+			const { get_doc } = actor;
+
+			expect(fromNullable(await get_doc(TEST_COLLECTION, TEST_KEY))).toBeUndefined();
+
+			actor.setIdentity(controller);
+		});
+
+		it('should not transfer a document with an outdated version', async () => {
+			const { set_doc_owner } = actor;
+
+			await expect(
+				set_doc_owner(TEST_COLLECTION, TEST_KEY, {
+					owner: user1.getPrincipal(),
+					version: toNullable(1n)
+				})
+			).rejects.toThrow('error_version_outdated_or_future');
+		});
+
+		it('should not transfer an unknown document', async () => {
+			const { set_doc_owner } = actor;
+
+			await expect(
+				set_doc_owner(TEST_COLLECTION, 'unknown', {
+					owner: user1.getPrincipal(),
+					version: toNullable()
+				})
+			).rejects.toThrow('Document not found.');
+		});
+
+		it('should not transfer a document to anonymous', async () => {
+			const { get_doc, set_doc_owner } = actor;
+
+			const current = fromNullable(await get_doc(TEST_COLLECTION, TEST_KEY));
+
+			assertNonNullish(current);
+
+			await expect(
+				set_doc_owner(TEST_COLLECTION, TEST_KEY, {
+					owner: new AnonymousIdentity().getPrincipal(),
+					version: current.version
+				})
+			).rejects.toThrow('The owner cannot be anonymous.');
+		});
+
+		it('should transfer the owner of an asset', async () => {
+			const { get_asset, set_asset_owner } = actor;
+
+			const current = fromNullable(await get_asset(TEST_STORAGE_COLLECTION, TEST_FULL_PATH));
+
+			assertNonNullish(current);
+
+			const asset = await set_asset_owner(TEST_STORAGE_COLLECTION, TEST_FULL_PATH, {
+				owner: user2.getPrincipal(),
+				version: current.version
+			});
+
+			expect(asset.key.owner.toText()).toEqual(user2.getPrincipal().toText());
+			expect(asset.encodings).toEqual(current.encodings);
+		});
+
+		it('should not transfer an unknown asset', async () => {
+			const { set_asset_owner } = actor;
+
+			await expect(
+				set_asset_owner(TEST_STORAGE_COLLECTION, '/unknown.html', {
+					owner: user1.getPrincipal(),
+					version: toNullable()
+				})
+			).rejects.toThrow('No asset.');
+		});
+	});
+
+	describe('user', () => {
+		beforeAll(() => {
+			actor.setIdentity(user2);
+		});
+
+		it('should not transfer the owner of a document', async () => {
+			const { set_doc_owner } = actor;
+
+			await expect(
+				set_doc_owner(TEST_COLLECTION, TEST_KEY, {
+					owner: user1.getPrincipal(),
+					version: toNullable()
+				})
+			).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+
+		it('should not transfer the owner of an asset', async () => {
+			const { set_asset_owner } = actor;
+
+			await expect(
+				set_asset_owner(TEST_STORAGE_COLLECTION, TEST_FULL_PATH, {
+					owner: user1.getPrincipal(),
+					version: toNullable()
+				})
+			).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
@@ -0,0 +1,189 @@
+import type {
+	ListParams,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_ADMIN_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Pagination', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_pagination';
+
+	const KEYS = ['a', 'b', 'c', 'd', 'e'];
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const params = (start_after?: string): ListParams => ({
+		matcher: toNullable(),
+		order: toNullable(),
+		owner: toNullable(),
+		paginate: toNullable({
+			start_after: toNullable(start_after),
+			limit: toNullable(2n)
+		})
+	});
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule, set_doc } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+
+		for (const key of KEYS) {
+			await set_doc(TEST_COLLECTION, key, {
+				attachments: toNullable(),
+				data: await toArray({ key }),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+		}
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('controller', () => {
+		it('should list the docs page by page with a cursor', async () => {
+			const { list_docs_page } = actor;
+
+			const keys: string[] = [];
+
+			let cursor: string | undefined = undefined;
+			let pages = 0;
+
+			do {
+				const { items, next_cursor, total_estimate } = await list_docs_page(
+					TEST_COLLECTION,
+					params(cursor)
+				);
+
+				expect(total_estimate).toEqual(BigInt(KEYS.length));
+
+				keys.push(...items.map(([key, _]) => key));
+
+				cursor = fromNullable(next_cursor);
+				pages++;
+			} while (cursor !== undefined);
+
+			expect(keys).toEqual(KEYS);
+			expect(pages).toEqual(3);
+		});
+
+		it('should not provide a cursor when the last page is full', async () => {
+			const { list_docs_page } = actor;
+
+			const { items, next_cursor } = await list_docs_page(TEST_COLLECTION, params('c'));
+
+			expect(items.map(([key, _]) => key)).toEqual(['d', 'e']);
+			expect(fromNullable(next_cursor)).toBeUndefined();
+		});
+
+		it('should not provide a cursor without pagination', async () => {
+			const { list_docs_page } = actor;
+
+			const { items, next_cursor } = await list_docs_page(TEST_COLLECTION, {
+				...params(),
+				paginate: toNullable()
+			});
+
+			expect(items).toHaveLength(KEYS.length);
+			expect(fromNullable(next_cursor)).toBeUndefined();
+		});
+
+		it('should list the assets as a page', async () => {
+			const { list_assets_page } = actor;
+
+			const { items, next_cursor, total_estimate } = await list_assets_page('#dapp', params());
+
+			expect(items.length).toEqual(Number(total_estimate));
+			expect(fromNullable(next_cursor)).toBeUndefined();
+		});
+
+		it('should list the controllers page by page', async () => {
+			const { set_controllers, list_controllers_page } = actor;
+
+			const others = [Ed25519KeyIdentity.generate(), Ed25519KeyIdentity.generate()];
+
+			await set_controllers({
+				controllers: others.map((identity) => identity.getPrincipal()),
+				controller: {
+					expires_at: toNullable(),
+					metadata: [],
+					scope: { Write: null }
+				}
+			});
+
+			const first = await list_controllers_page({
+				start_after: toNullable(),
+				limit: toNullable(2n)
+			});
+
+			expect(first.items).toHaveLength(2);
+			expect(first.total_estimate).toEqual(3n);
+
+			const cursor = fromNullable(first.next_cursor);
+
+			expect(cursor).toEqual(first.items[1][0].toText());
+
+			const second = await list_controllers_page({
+				start_after: toNullable(cursor),
+				limit: toNullable(2n)
+			});
+
+			expect(second.items).toHaveLength(1);
+			expect(fromNullable(second.next_cursor)).toBeUndefined();
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not list the controllers', async () => {
+			const { list_controllers_page } = actor;
+
+			await expect(
+				list_controllers_page({ start_after: toNullable(), limit: toNullable() })
+			).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+	});
+});
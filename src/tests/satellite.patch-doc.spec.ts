@@ -0,0 +1,207 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { fromArray, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Patch doc', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'profiles';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const setDoc = async ({ key, data }: { key: string; data: Uint8Array | number[] }) => {
+		const { set_doc } = actor;
+
+		return set_doc(TEST_COLLECTION, key, {
+			attachments: toNullable(),
+			data,
+			description: toNullable('A profile'),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+
+		actor.setIdentity(user);
+
+		await setDoc({
+			key: 'alice',
+			data: await toArray({ name: 'Alice', city: 'Zurich', tags: ['a'] })
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should apply a merge patch', async () => {
+		const { patch_doc } = actor;
+
+		const doc = await patch_doc(TEST_COLLECTION, 'alice', {
+			patch: { MergePatch: JSON.stringify({ city: 'Geneva', age: 30 }) },
+			version: toNullable()
+		});
+
+		expect(await fromArray(doc.data)).toEqual({
+			name: 'Alice',
+			city: 'Geneva',
+			age: 30,
+			tags: ['a']
+		});
+		expect(fromNullable(doc.version)).toEqual(2n);
+		expect(fromNullable(doc.description)).toEqual('A profile');
+	});
+
+	it('should apply a JSON patch', async () => {
+		const { patch_doc } = actor;
+
+		const doc = await patch_doc(TEST_COLLECTION, 'alice', {
+			patch: {
+				JsonPatch: JSON.stringify([
+					{ op: 'test', path: '/name', value: 'Alice' },
+					{ op: 'add', path: '/tags/-', value: 'b' },
+					{ op: 'replace', path: '/age', value: 31 },
+					{ op: 'move', from: '/city', path: '/location' }
+				])
+			},
+			version: toNullable()
+		});
+
+		expect(await fromArray(doc.data)).toEqual({
+			name: 'Alice',
+			location: 'Geneva',
+			age: 31,
+			tags: ['a', 'b']
+		});
+	});
+
+	it('should not clobber the partial updates of distinct fields', async () => {
+		const { patch_doc, get_doc } = actor;
+
+		await Promise.all([
+			patch_doc(TEST_COLLECTION, 'alice', {
+				patch: { MergePatch: JSON.stringify({ name: 'Alice B.' }) },
+				version: toNullable()
+			}),
+			patch_doc(TEST_COLLECTION, 'alice', {
+				patch: { MergePatch: JSON.stringify({ age: 32 }) },
+				version: toNullable()
+			})
+		]);
+
+		const doc = fromNullable(await get_doc(TEST_COLLECTION, 'alice'));
+
+		expect(await fromArray(doc?.data ?? [])).toEqual(
+			expect.objectContaining({ name: 'Alice B.', age: 32 })
+		);
+	});
+
+	it('should not patch a document with an outdated version', async () => {
+		const { patch_doc } = actor;
+
+		await expect(
+			patch_doc(TEST_COLLECTION, 'alice', {
+				patch: { MergePatch: JSON.stringify({ age: 40 }) },
+				version: toNullable(1n)
+			})
+		).rejects.toThrow();
+	});
+
+	it('should apply the operations of a JSON patch all or nothing', async () => {
+		const { patch_doc, get_doc } = actor;
+
+		const before = fromNullable(await get_doc(TEST_COLLECTION, 'alice'));
+
+		await expect(
+			patch_doc(TEST_COLLECTION, 'alice', {
+				patch: {
+					JsonPatch: JSON.stringify([
+						{ op: 'remove', path: '/age' },
+						{ op: 'test', path: '/name', value: 'Bob' }
+					])
+				},
+				version: toNullable()
+			})
+		).rejects.toThrow('Test operation failed: /name');
+
+		const after = fromNullable(await get_doc(TEST_COLLECTION, 'alice'));
+
+		expect(after?.version).toEqual(before?.version);
+		expect(after?.data).toEqual(before?.data);
+	});
+
+	it('should not patch a document that does not exist', async () => {
+		const { patch_doc } = actor;
+
+		await expect(
+			patch_doc(TEST_COLLECTION, 'unknown', {
+				patch: { MergePatch: JSON.stringify({ age: 40 }) },
+				version: toNullable()
+			})
+		).rejects.toThrow('The document to patch does not exist.');
+	});
+
+	it('should not patch a document that is not JSON', async () => {
+		await setDoc({ key: 'binary', data: [0, 159, 146, 150] });
+
+		const { patch_doc } = actor;
+
+		await expect(
+			patch_doc(TEST_COLLECTION, 'binary', {
+				patch: { MergePatch: JSON.stringify({ age: 40 }) },
+				version: toNullable()
+			})
+		).rejects.toThrow('The data of the document is not JSON.');
+	});
+
+	it('should not patch the document of another user', async () => {
+		actor.setIdentity(Ed25519KeyIdentity.generate());
+
+		const { patch_doc } = actor;
+
+		await expect(
+			patch_doc(TEST_COLLECTION, 'alice', {
+				patch: { MergePatch: JSON.stringify({ age: 40 }) },
+				version: toNullable()
+			})
+		).rejects.toThrow('The document to patch does not exist.');
+	});
+});
@@ -41,10 +41,13 @@ describe.skip('Satellite playground (kind of)', () => {
 			const user = Ed25519KeyIdentity.generate();
 
 			await set_doc('#user', user.getPrincipal().toText(), {
+				attachments: toNullable(),
 				data: await toArray({
 					provider: 'internet_identity'
 				}),
 				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
 				version: toNullable()
 			});
 
@@ -0,0 +1,193 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { createHash } from 'node:crypto';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Proof of work', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'feedback';
+	const DIFFICULTY = 8;
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Controllers: null },
+		mutable_permissions: toNullable(),
+		write: { Public: null },
+		version: toNullable(),
+		rate_config: [],
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const leadingZeroBits = (hash: Buffer): number => {
+		let bits = 0;
+
+		for (const byte of hash) {
+			if (byte === 0) {
+				bits += 8;
+				continue;
+			}
+
+			return bits + Math.clz32(byte) - 24;
+		}
+
+		return bits;
+	};
+
+	const findKey = ({ data, valid }: { data: Uint8Array; valid: boolean }): string => {
+		for (let i = 0; ; i++) {
+			const key = `entry-${i}`;
+
+			const hash = createHash('sha256')
+				.update(`${TEST_COLLECTION}:${key}:`)
+				.update(data)
+				.digest();
+
+			if (leadingZeroBits(hash) >= DIFFICULTY === valid) {
+				return key;
+			}
+		}
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should not set a difficulty above 32 bits', async () => {
+			const { set_db_config } = actor;
+
+			await expect(
+				set_db_config({
+					proof_of_work: [[{ collection: TEST_COLLECTION, difficulty: 33 }]],
+					aggregates: toNullable(),
+					list_limits: toNullable(),
+					max_memory_size: toNullable(),
+					history_collections: toNullable(),
+					views: toNullable(),
+					attachments: toNullable(),
+					http_collections: toNullable()
+				})
+			).rejects.toThrow('Proof of work difficulty cannot exceed 32 bits.');
+		});
+
+		it('should require a proof of work', async () => {
+			const { set_db_config } = actor;
+
+			await set_db_config({
+				proof_of_work: [[{ collection: TEST_COLLECTION, difficulty: DIFFICULTY }]],
+				aggregates: toNullable(),
+				list_limits: toNullable(),
+				max_memory_size: toNullable(),
+				history_collections: toNullable(),
+				views: toNullable(),
+				attachments: toNullable(),
+				http_collections: toNullable()
+			});
+		});
+	});
+
+	describe('anonymous', () => {
+		let data: Uint8Array | number[];
+
+		beforeAll(async () => {
+			actor.setIdentity(new AnonymousIdentity());
+
+			data = await toArray({ feedback: 'Hello World' });
+		});
+
+		it('should not write without a valid proof of work', async () => {
+			const { set_doc } = actor;
+
+			const key = findKey({ data: Uint8Array.from(data), valid: false });
+
+			await expect(
+				set_doc(TEST_COLLECTION, key, {
+					attachments: toNullable(),
+					data,
+					description: toNullable(),
+					publish_at: toNullable(),
+					unpublish_at: toNullable(),
+					version: toNullable()
+				})
+			).rejects.toThrow('Invalid proof of work.');
+		});
+
+		it('should write with a valid proof of work', async () => {
+			const { set_doc } = actor;
+
+			const key = findKey({ data: Uint8Array.from(data), valid: true });
+
+			const doc = await set_doc(TEST_COLLECTION, key, {
+				attachments: toNullable(),
+				data,
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+
+			expect(doc.owner.toText()).toEqual(new AnonymousIdentity().getPrincipal().toText());
+		});
+	});
+
+	describe('user', () => {
+		beforeAll(() => {
+			actor.setIdentity(Ed25519KeyIdentity.generate());
+		});
+
+		it('should write without a proof of work', async () => {
+			const { set_doc } = actor;
+
+			const data = await toArray({ feedback: 'Hello World' });
+
+			const key = findKey({ data: Uint8Array.from(data), valid: false });
+
+			const doc = await set_doc(TEST_COLLECTION, key, {
+				attachments: toNullable(),
+				data,
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+
+			expect(doc.data).toEqual(data);
+		});
+	});
+});
@@ -0,0 +1,262 @@
+import type {
+	ListParams,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Query limits', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_query_limits';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Stable: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Public: null },
+		mutable_permissions: toNullable(),
+		write: { Public: null },
+		version: toNullable(),
+		rate_config: [],
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const filter: ListParams = {
+		matcher: toNullable(),
+		order: toNullable(),
+		owner: toNullable(),
+		paginate: toNullable()
+	};
+
+	const setListLimits = async ({
+		max_scanned_items,
+		max_returned_bytes
+	}: {
+		max_scanned_items?: bigint;
+		max_returned_bytes?: bigint;
+	}) => {
+		actor.setIdentity(controller);
+
+		const { set_db_config } = actor;
+
+		await set_db_config({
+			proof_of_work: toNullable(),
+			aggregates: toNullable(),
+			list_limits: toNullable({
+				max_scanned_items: toNullable(max_scanned_items),
+				max_returned_bytes: toNullable(max_returned_bytes)
+			}),
+			max_memory_size: toNullable(),
+			history_collections: toNullable(),
+			views: toNullable(),
+			attachments: toNullable(),
+			http_collections: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule, set_doc } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+
+		for (const key of ['key-1', 'key-2', 'key-3', 'key-4', 'key-5']) {
+			await set_doc(TEST_COLLECTION, key, {
+				attachments: toNullable(),
+				data: await toArray({ hello: key }),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+		}
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should list all documents without limits', async () => {
+		const { list_docs } = actor;
+
+		const { items, truncated } = await list_docs(TEST_COLLECTION, filter);
+
+		expect(items).toHaveLength(5);
+		expect(fromNullable(truncated)).toBeUndefined();
+	});
+
+	it('should stop scanning at the maximal number of items', async () => {
+		await setListLimits({ max_scanned_items: 3n });
+
+		actor.setIdentity(Ed25519KeyIdentity.generate());
+
+		const { list_docs } = actor;
+
+		const { items, items_length, truncated } = await list_docs(TEST_COLLECTION, filter);
+
+		expect(items.map(([key]) => key)).toEqual(['key-1', 'key-2', 'key-3']);
+		expect(items_length).toEqual(3n);
+		expect(fromNullable(truncated)).toEqual({ MaxScannedItems: null });
+	});
+
+	it('should resume scanning after the last scanned item', async () => {
+		const { list_docs } = actor;
+
+		const { resume_after } = await list_docs(TEST_COLLECTION, filter);
+
+		expect(fromNullable(resume_after)).toEqual('key-3');
+
+		const { items, truncated } = await list_docs(TEST_COLLECTION, {
+			...filter,
+			paginate: toNullable({
+				start_after: resume_after,
+				limit: toNullable()
+			})
+		});
+
+		expect(items.map(([key]) => key)).toEqual(['key-4', 'key-5']);
+		expect(fromNullable(truncated)).toBeUndefined();
+	});
+
+	it('should resume scanning after entries that do not match', async () => {
+		const { list_docs } = actor;
+
+		const matcher = toNullable({
+			key: toNullable('^key-5$'),
+			description: toNullable(),
+			created_at: toNullable(),
+			updated_at: toNullable(),
+			filter: toNullable()
+		});
+
+		const { items, resume_after } = await list_docs(TEST_COLLECTION, { ...filter, matcher });
+
+		expect(items).toHaveLength(0);
+		expect(fromNullable(resume_after)).toEqual('key-3');
+
+		const { items: nextItems } = await list_docs(TEST_COLLECTION, {
+			...filter,
+			matcher,
+			paginate: toNullable({
+				start_after: resume_after,
+				limit: toNullable()
+			})
+		});
+
+		expect(nextItems.map(([key]) => key)).toEqual(['key-5']);
+	});
+
+	it('should resume after the last returned item when the page is full', async () => {
+		const { list_docs } = actor;
+
+		const { items, resume_after } = await list_docs(TEST_COLLECTION, {
+			...filter,
+			paginate: toNullable({
+				start_after: toNullable(),
+				limit: toNullable(2n)
+			})
+		});
+
+		expect(items.map(([key]) => key)).toEqual(['key-1', 'key-2']);
+		expect(fromNullable(resume_after)).toEqual('key-2');
+	});
+
+	it('should not order a listing exceeding the maximal number of items to scan', async () => {
+		const { list_docs } = actor;
+
+		await expect(
+			list_docs(TEST_COLLECTION, {
+				...filter,
+				order: toNullable({
+					desc: true,
+					field: { UpdatedAt: null }
+				})
+			})
+		).rejects.toThrow('error_list_max_scanned_items_exceeded');
+	});
+
+	it('should not flag a listing that fits the limits as truncated', async () => {
+		await setListLimits({ max_scanned_items: 5n });
+
+		const { list_docs } = actor;
+
+		const { items, truncated } = await list_docs(TEST_COLLECTION, filter);
+
+		expect(items).toHaveLength(5);
+		expect(fromNullable(truncated)).toBeUndefined();
+	});
+
+	it('should return at least one item when the maximal size is exceeded', async () => {
+		await setListLimits({ max_returned_bytes: 1n });
+
+		const { list_docs } = actor;
+
+		const { items, matches_length, truncated, resume_after, items_page } = await list_docs(
+			TEST_COLLECTION,
+			filter
+		);
+
+		expect(items.map(([key]) => key)).toEqual(['key-1']);
+		expect(matches_length).toEqual(5n);
+		expect(fromNullable(truncated)).toEqual({ MaxReturnedBytes: null });
+		expect(fromNullable(resume_after)).toEqual('key-1');
+		expect(fromNullable(items_page)).toBeUndefined();
+	});
+
+	it('should estimate the cost of a query', async () => {
+		await setListLimits({ max_scanned_items: 4n });
+
+		const { estimate_query } = actor;
+
+		const estimate = await estimate_query({ Db: null }, TEST_COLLECTION, {
+			...filter,
+			matcher: toNullable({
+				key: toNullable('^key-[12]$'),
+				description: toNullable(),
+				created_at: toNullable(),
+				updated_at: toNullable(),
+				filter: toNullable()
+			})
+		});
+
+		expect(estimate.scanned_items).toEqual(4n);
+		expect(estimate.matches_length).toEqual(2n);
+		expect(estimate.items_length).toEqual(2n);
+		expect(estimate.returned_bytes).toBeGreaterThan(0n);
+		expect(fromNullable(estimate.truncated)).toEqual({ MaxScannedItems: null });
+	});
+
+	it('should not estimate a query as a user', async () => {
+		actor.setIdentity(Ed25519KeyIdentity.generate());
+
+		const { estimate_query } = actor;
+
+		await expect(estimate_query({ Db: null }, TEST_COLLECTION, filter)).rejects.toThrow(
+			CONTROLLER_ERROR_MSG
+		);
+	});
+});
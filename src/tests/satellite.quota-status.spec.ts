@@ -0,0 +1,190 @@
+import type {
+	QuotaThresholds,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import {
+	CONTROLLER_ERROR_MSG,
+	SATELLITE_ADMIN_ERROR_MSG
+} from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Quota status', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'quotas';
+
+	const setRule: SetRule = {
+		memory: toNullable(),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const setDoc = async (key: string) => {
+		const { set_doc } = actor;
+
+		await set_doc(TEST_COLLECTION, key, {
+			attachments: toNullable(),
+			data: await toArray({ hello: key }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('controller', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should not report a status without thresholds', async () => {
+			const { get_quota_status, get_quota_thresholds } = actor;
+
+			expect(fromNullable(await get_quota_thresholds())).toBeUndefined();
+
+			await setDoc('first');
+
+			expect(fromNullable(await get_quota_status())).toBeUndefined();
+		});
+
+		it('should set the thresholds', async () => {
+			const { set_quota_thresholds, get_quota_thresholds, get_quota_status } = actor;
+
+			const thresholds: QuotaThresholds = {
+				memory_size: toNullable(),
+				docs_count: toNullable(3n)
+			};
+
+			await set_quota_thresholds(thresholds);
+
+			expect(fromNullable(await get_quota_thresholds())).toEqual(thresholds);
+
+			expect(fromNullable(await get_quota_status())).toBeUndefined();
+		});
+
+		it('should report a warning once the number of documents crosses the threshold', async () => {
+			const { get_quota_status } = actor;
+
+			await setDoc('second');
+
+			expect(fromNullable(await get_quota_status())).toBeUndefined();
+
+			await setDoc('third');
+
+			const status = fromNullable(await get_quota_status());
+
+			assertNonNullish(status);
+
+			expect(status.warnings).toEqual([{ DocsCount: null }]);
+			expect(status.docs_count).toEqual(3n);
+			expect(status.memory_size).toBeGreaterThan(0n);
+		});
+
+		it('should not record a new status while the warnings are unchanged', async () => {
+			const { get_quota_status } = actor;
+
+			const status = fromNullable(await get_quota_status());
+
+			assertNonNullish(status);
+
+			await pic.advanceTime(1000);
+
+			await setDoc('fourth');
+
+			expect(fromNullable(await get_quota_status())?.updated_at).toEqual(status.updated_at);
+		});
+
+		it('should clear the warnings once back below the threshold', async () => {
+			const { del_doc, get_quota_status, get_doc } = actor;
+
+			for (const key of ['third', 'fourth']) {
+				const doc = fromNullable(await get_doc(TEST_COLLECTION, key));
+
+				await del_doc(TEST_COLLECTION, key, { version: doc?.version ?? [] });
+			}
+
+			const status = fromNullable(await get_quota_status());
+
+			assertNonNullish(status);
+
+			expect(status.warnings).toEqual([]);
+			expect(status.docs_count).toEqual(2n);
+		});
+
+		it('should report a warning for the memory when the thresholds are set', async () => {
+			const { set_quota_thresholds, get_quota_status } = actor;
+
+			await set_quota_thresholds({
+				memory_size: toNullable(1n),
+				docs_count: toNullable(3n)
+			});
+
+			const status = fromNullable(await get_quota_status());
+
+			assertNonNullish(status);
+
+			expect(status.warnings).toEqual([{ MemorySize: null }]);
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not set the thresholds', async () => {
+			const { set_quota_thresholds } = actor;
+
+			await expect(
+				set_quota_thresholds({ memory_size: toNullable(), docs_count: toNullable() })
+			).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should not get the status', async () => {
+			const { get_quota_status } = actor;
+
+			await expect(get_quota_status()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
@@ -0,0 +1,221 @@
+import type {
+	HttpRequest,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_ADMIN_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Rate limit', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_rate_limit';
+
+	const MAX_TOKENS = 2n;
+	const TIME_PER_TOKEN_NS = 60_000_000_000n;
+
+	const rateConfig = { max_tokens: MAX_TOKENS, time_per_token_ns: TIME_PER_TOKEN_NS };
+
+	const rateLimitConfig = {
+		caller: rateConfig,
+		anonymous: toNullable<typeof rateConfig>(),
+		http: toNullable(rateConfig)
+	};
+
+	const httpRequest = (origin: string): HttpRequest => ({
+		body: [],
+		certificate_version: toNullable(2),
+		headers: [['Origin', origin]],
+		method: 'GET',
+		url: '/hello.html'
+	});
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const setDocs = async (length: number) => {
+		const { set_doc } = actor;
+
+		for (let i = 0; i < length; i++) {
+			await set_doc(TEST_COLLECTION, `${i}-${Date.now()}`, {
+				attachments: toNullable(),
+				data: await toArray({ i }),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+		}
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		it('should not set a rate limit without tokens', async () => {
+			const { set_rate_limit_config } = actor;
+
+			await expect(
+				set_rate_limit_config(
+					toNullable({
+						caller: { max_tokens: 0n, time_per_token_ns: TIME_PER_TOKEN_NS },
+						anonymous: toNullable(),
+						http: toNullable()
+					})
+				)
+			).rejects.toThrow('The maximal number of tokens of a rate limit must be greater than zero.');
+		});
+
+		it('should not set a rate limit without time per token', async () => {
+			const { set_rate_limit_config } = actor;
+
+			await expect(
+				set_rate_limit_config(
+					toNullable({
+						caller: rateConfig,
+						anonymous: toNullable(),
+						http: toNullable({ max_tokens: MAX_TOKENS, time_per_token_ns: 0n })
+					})
+				)
+			).rejects.toThrow('The time per token of a rate limit must be greater than zero.');
+		});
+
+		it('should set a rate limit', async () => {
+			const { set_rate_limit_config, get_rate_limit_config } = actor;
+
+			await set_rate_limit_config(toNullable(rateLimitConfig));
+
+			expect(fromNullable(await get_rate_limit_config())).toEqual(rateLimitConfig);
+		});
+
+		it('should not limit the controllers', async () => {
+			await expect(setDocs(10)).resolves.not.toThrow();
+		});
+	});
+
+	describe('user', () => {
+		beforeAll(() => {
+			actor.setIdentity(user);
+		});
+
+		it('should reject the calls once the burst is consumed', async () => {
+			await expect(setDocs(10)).rejects.toThrow('Rate limit reached, try again later.');
+		});
+
+		it('should refill the tokens over time', async () => {
+			await pic.advanceTime(Number(TIME_PER_TOKEN_NS / 1_000_000n));
+			await pic.tick();
+
+			await expect(setDocs(1)).resolves.not.toThrow();
+		});
+
+		it('should limit the http requests answered with an update call per origin', async () => {
+			const { http_request_update } = actor;
+
+			const statusCodes: number[] = [];
+
+			for (let i = 0; i < 5; i++) {
+				const { status_code } = await http_request_update(httpRequest('https://a.com'));
+
+				statusCodes.push(status_code);
+			}
+
+			expect(statusCodes).toContain(429);
+
+			const { status_code: other_origin_status_code } = await http_request_update(
+				httpRequest('https://b.com')
+			);
+
+			expect(other_origin_status_code).not.toEqual(429);
+		});
+
+		it('should limit the consent messages', async () => {
+			const { icrc21_canister_call_consent_message } = actor;
+
+			const request = {
+				arg: new Uint8Array(),
+				method: 'set_doc',
+				user_preferences: {
+					metadata: { utc_offset_minutes: toNullable<number>(), language: 'en' },
+					device_spec: toNullable()
+				}
+			};
+
+			// The tokens of the user were consumed by the previous calls
+			await expect(icrc21_canister_call_consent_message(request)).rejects.toThrow(
+				'Rate limit reached, try again later.'
+			);
+		});
+
+		it('should not limit the calls once the rate limit is removed', async () => {
+			actor.setIdentity(controller);
+
+			const { set_rate_limit_config } = actor;
+
+			await set_rate_limit_config(toNullable());
+
+			actor.setIdentity(user);
+
+			await expect(setDocs(10)).resolves.not.toThrow();
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not set a rate limit', async () => {
+			const { set_rate_limit_config } = actor;
+
+			await expect(set_rate_limit_config(toNullable())).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should not get the rate limit', async () => {
+			const { get_rate_limit_config } = actor;
+
+			await expect(get_rate_limit_config()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+	});
+});
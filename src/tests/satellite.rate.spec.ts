@@ -42,8 +42,11 @@ describe('Satellite rate', () => {
 		actor.setIdentity(user);
 
 		const doc = await set_doc(collection, user.getPrincipal().toText(), {
+			attachments: toNullable(),
 			data: await toArray({}),
 			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
 			version: toNullable()
 		});
 
@@ -174,6 +177,9 @@ describe('Satellite rate', () => {
 			mutable_permissions: toNullable(true),
 			write: { Public: null },
 			version: nonNullish(rule) ? rule.version : toNullable(),
+			user_quota: toNullable(),
+			collation: toNullable(),
+			retention: toNullable(),
 			rate_config: isNullish(max_tokens)
 				? []
 				: [
@@ -298,7 +304,9 @@ describe('Satellite rate', () => {
 				encoding_type: [],
 				full_path: `/${collection}/hello${i}.html`,
 				name: `hello${i}.html`,
-				token: toNullable()
+				publish_at: toNullable(),
+				token: toNullable(),
+				unpublish_at: toNullable()
 			});
 
 			return { batch, user };
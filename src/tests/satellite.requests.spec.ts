@@ -0,0 +1,181 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Requests', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+	let satelliteId: Principal;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const setDoc = async ({ collection, key }: { collection: string; key: string }) => {
+		const { set_doc } = actor;
+
+		await set_doc(collection, key, {
+			attachments: toNullable(),
+			data: await toArray({ hello: key }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c, canisterId } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		satelliteId = canisterId;
+
+		const { set_rule, set_webhook } = actor;
+
+		await set_rule({ Db: null }, 'notes', setRule);
+
+		await set_webhook('sync', {
+			url: 'https://example.com/hooks/juno',
+			secret: toNullable('a-secret'),
+			events: [{ SetDoc: null }],
+			collections: toNullable(),
+			enabled: true
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should deliver the id of the request to the webhooks', async () => {
+		await setDoc({ collection: 'notes', key: 'first' });
+		await setDoc({ collection: 'notes', key: 'second' });
+
+		const { list_webhook_deliveries } = actor;
+
+		const deliveries = await list_webhook_deliveries();
+
+		expect(deliveries).toHaveLength(2);
+
+		const requestIds = deliveries.map(([_, { request_id, payload }]) => {
+			const requestId = fromNullable(request_id);
+
+			expect(requestId).toMatch(/^\d+-\d+$/);
+			expect(JSON.parse(payload).request_id).toEqual(requestId);
+
+			return requestId;
+		});
+
+		expect(new Set(requestIds).size).toEqual(2);
+	});
+
+	it('should include the id of the request in the errors', async () => {
+		await expect(setDoc({ collection: 'unknown', key: 'first' })).rejects.toThrow(
+			/Request ID: \d+-\d+/
+		);
+	});
+
+	it('should include the id of the request in the parameters of the structured errors', async () => {
+		await expect(setDoc({ collection: 'notes', key: 'first' })).rejects.toThrow(
+			/"params":\{"request_id":"\d+-\d+"\}/
+		);
+	});
+
+	it('should return the id of the request with the HTTP responses', async () => {
+		const { http_request_update } = actor;
+
+		const { headers } = await http_request_update({
+			body: [],
+			certificate_version: toNullable(),
+			headers: [],
+			method: 'GET',
+			url: '/_juno/health'
+		});
+
+		const requestId = headers.find(([name]) => name === 'X-Request-Id');
+
+		expect(requestId?.[1]).toMatch(/^\d+-\d+$/);
+	});
+
+	it('should return the id of each request with the HTTP responses of interleaved calls', async () => {
+		const { set_analytics_config, http_request_update } = actor;
+
+		// The pings are forwarded to the satellite itself, which rejects the call the update awaits
+		await set_analytics_config({
+			orbiter_id: [satelliteId],
+			rate_config: []
+		});
+
+		const responses = await Promise.all([
+			http_request_update({
+				body: new TextEncoder().encode(
+					JSON.stringify({
+						key: {
+							key: 'interleaved',
+							collected_at: 1230
+						},
+						page_view: {
+							title: 'Test',
+							href: 'https://test.com',
+							referrer: null,
+							device: {
+								inner_width: 600,
+								inner_height: 300
+							},
+							time_zone: 'Europe/Zurich',
+							user_agent: null,
+							session_id: 'session',
+							version: null
+						}
+					})
+				),
+				certificate_version: toNullable(),
+				headers: [],
+				method: 'POST',
+				url: '/_juno/analytics/page-view'
+			}),
+			http_request_update({
+				body: [],
+				certificate_version: toNullable(),
+				headers: [],
+				method: 'GET',
+				url: '/_juno/health'
+			})
+		]);
+
+		const requestIds = responses.map(({ headers }) => new Map(headers).get('X-Request-Id'));
+
+		for (const requestId of requestIds) {
+			expect(requestId).toMatch(/^\d+-\d+$/);
+		}
+
+		expect(new Set(requestIds).size).toEqual(2);
+	});
+});
@@ -0,0 +1,147 @@
+import type {
+	_SERVICE as SatelliteActor,
+	StorageConfig,
+	StreamingCallbackToken
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { deleteDefaultIndexHTML } from './utils/satellite-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Max response body size', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const storage: StorageConfig = {
+		headers: [],
+		iframe: toNullable(),
+		redirects: toNullable(),
+		rewrites: [],
+		raw_access: toNullable(),
+		delegations: toNullable(),
+		max_response_body_size: toNullable(),
+		csp_nonce: toNullable(),
+		wildcard_domains: toNullable(),
+		locales: toNullable(),
+		weighted_rewrites: toNullable(),
+		list_limits: toNullable(),
+		max_memory_size: toNullable()
+	};
+
+	const chunks = ['Hello World!', 'Juno'];
+
+	const decoder = new TextDecoder();
+
+	const readAsset = async (): Promise<string[]> => {
+		const { http_request, http_request_streaming_callback } = actor;
+
+		const { body, streaming_strategy } = await http_request({
+			body: [],
+			certificate_version: toNullable(2),
+			headers: [],
+			method: 'GET',
+			url: '/hello.html'
+		});
+
+		const bodies = [decoder.decode(body as Uint8Array)];
+
+		let token: StreamingCallbackToken | undefined =
+			fromNullable(streaming_strategy)?.Callback.token;
+
+		while (token !== undefined) {
+			const { body, token: next } = await http_request_streaming_callback(token);
+
+			bodies.push(decoder.decode(body as Uint8Array));
+
+			token = fromNullable(next);
+		}
+
+		return bodies;
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		await deleteDefaultIndexHTML({ actor, controller });
+
+		actor.setIdentity(controller);
+
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+		const file = await init_asset_upload({
+			collection: '#dapp',
+			description: toNullable(),
+			encoding_type: [],
+			full_path: '/hello.html',
+			name: 'hello.html',
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const chunkIds = [];
+
+		for (const [i, chunk] of chunks.entries()) {
+			const { chunk_id } = await upload_asset_chunk({
+				batch_id: file.batch_id,
+				content: new TextEncoder().encode(chunk),
+				order_id: [BigInt(i)]
+			});
+
+			chunkIds.push(chunk_id);
+		}
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: chunkIds,
+			headers: [['Content-Type', 'text/html']]
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should stream the chunks as uploaded by default', async () => {
+		actor.setIdentity(new AnonymousIdentity());
+
+		expect(await readAsset()).toEqual(chunks);
+	});
+
+	it('should split the chunks exceeding the max response body size', async () => {
+		actor.setIdentity(controller);
+
+		const { set_storage_config, get_config } = actor;
+
+		await set_storage_config({
+			...storage,
+			max_response_body_size: toNullable(5n)
+		});
+
+		const { storage: config } = await get_config();
+
+		expect(fromNullable(config.max_response_body_size)).toEqual(5n);
+
+		actor.setIdentity(new AnonymousIdentity());
+
+		const bodies = await readAsset();
+
+		expect(bodies).toEqual(['Hello', ' Worl', 'd!', 'Juno']);
+		expect(bodies.join('')).toEqual(chunks.join(''));
+	});
+});
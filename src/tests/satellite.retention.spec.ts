@@ -0,0 +1,163 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Retention', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_retention';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable({
+			max_age: toNullable(),
+			max_items: toNullable(2n)
+		})
+	};
+
+	const listKeys = async (): Promise<string[]> => {
+		const { list_docs } = actor;
+
+		const { items } = await list_docs(TEST_COLLECTION, {
+			matcher: toNullable(),
+			order: toNullable(),
+			owner: toNullable(),
+			paginate: toNullable()
+		});
+
+		return items.map(([key]) => key).sort();
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule, set_doc } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+
+		for (const key of ['first', 'second', 'third', 'fourth']) {
+			await set_doc(TEST_COLLECTION, key, {
+				attachments: toNullable(),
+				data: await toArray({ key }),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+
+			await pic.advanceTime(100);
+		}
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should save the retention of the rule', async () => {
+		const { get_rule } = actor;
+
+		const rule = fromNullable(await get_rule({ Db: null }, TEST_COLLECTION));
+
+		assertNonNullish(rule);
+
+		expect(fromNullable(rule.retention)?.max_items).toEqual([2n]);
+	});
+
+	it('should not save a retention without limits', async () => {
+		const { set_rule } = actor;
+
+		await expect(
+			set_rule({ Db: null }, 'invalid', {
+				...setRule,
+				retention: toNullable({ max_age: toNullable(), max_items: toNullable() })
+			})
+		).rejects.toThrow('A retention policy must define a maximal age or number of items.');
+	});
+
+	it('should report the oldest documents in a dry run', async () => {
+		const { dry_run } = actor;
+
+		const { keys, error } = await dry_run({
+			PurgeRetention: { rules_type: { Db: null }, collection: TEST_COLLECTION }
+		});
+
+		expect(fromNullable(error)).toBeUndefined();
+		expect(keys).toEqual(['first', 'second']);
+	});
+
+	it('should purge the documents that exceed the retention', async () => {
+		expect(await listKeys()).toHaveLength(4);
+
+		await pic.advanceTime(60 * 60 * 1000);
+		await pic.tick(2);
+
+		expect(await listKeys()).toEqual(['fourth', 'third']);
+	});
+
+	it('should index the existing documents when a retention is set', async () => {
+		const collection = 'test_later_retention';
+
+		const { set_rule, set_doc, get_rule, dry_run } = actor;
+
+		await set_rule({ Db: null }, collection, { ...setRule, retention: toNullable() });
+
+		for (const key of ['first', 'second', 'third']) {
+			await set_doc(collection, key, {
+				attachments: toNullable(),
+				data: await toArray({ key }),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+
+			await pic.advanceTime(100);
+		}
+
+		const rule = fromNullable(await get_rule({ Db: null }, collection));
+
+		assertNonNullish(rule);
+
+		await set_rule({ Db: null }, collection, {
+			...setRule,
+			retention: toNullable({
+				max_age: toNullable(),
+				max_items: toNullable(1n)
+			}),
+			version: rule.version
+		});
+
+		const { keys } = await dry_run({
+			PurgeRetention: { rules_type: { Db: null }, collection }
+		});
+
+		expect(keys).toEqual(['first', 'second']);
+	});
+});
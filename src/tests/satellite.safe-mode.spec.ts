@@ -0,0 +1,208 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { Principal } from '@dfinity/principal';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import {
+	CONTROLLER_ERROR_MSG,
+	SATELLITE_ADMIN_ERROR_MSG
+} from './constants/satellite-tests.constants';
+import { tick } from './utils/pic-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Safe mode', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+	let canisterId: Principal;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const writer = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'notes';
+
+	const SAFE_MODE_ERROR_MSG =
+		'The satellite is in safe mode. Writes are blocked until a controller acknowledges the issues found at startup.';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const setDoc = async (key: string) => {
+		const { set_doc } = actor;
+
+		return set_doc(TEST_COLLECTION, key, {
+			attachments: toNullable(),
+			data: await toArray({ hello: key }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c, canisterId: cId } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		canisterId = cId;
+
+		actor.setIdentity(controller);
+
+		const { set_rule, set_controllers } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+
+		await set_controllers({
+			controllers: [writer.getPrincipal()],
+			controller: {
+				expires_at: toNullable(),
+				metadata: [],
+				scope: { Write: null }
+			}
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should start without issues', async () => {
+		const { get_self_test_report, health } = actor;
+
+		const report = fromNullable(await get_self_test_report());
+
+		expect(report?.safe_mode).toBeFalsy();
+		expect(report?.issues).toHaveLength(0);
+
+		const { safe_mode } = await health();
+
+		expect(safe_mode).toBeFalsy();
+	});
+
+	describe('safe mode', () => {
+		beforeAll(async () => {
+			actor.setIdentity(writer);
+
+			await setDoc('before');
+
+			actor.setIdentity(controller);
+
+			const { del_controllers } = actor;
+
+			await del_controllers({ controllers: [controller.getPrincipal()] });
+
+			await pic.upgradeCanister({
+				canisterId,
+				wasm: SATELLITE_WASM_PATH,
+				sender: controller.getPrincipal()
+			});
+
+			await tick(pic);
+
+			actor.setIdentity(writer);
+		});
+
+		it('should report the issues found at startup', async () => {
+			const { get_self_test_report } = actor;
+
+			const report = fromNullable(await get_self_test_report());
+
+			expect(report?.safe_mode).toBeTruthy();
+			expect(report?.issues).toEqual([{ NoAdminController: null }]);
+		});
+
+		it('should serve the reads', async () => {
+			const { get_doc } = actor;
+
+			const doc = fromNullable(await get_doc(TEST_COLLECTION, 'before'));
+
+			expect(doc).not.toBeUndefined();
+		});
+
+		it('should block the writes', async () => {
+			await expect(setDoc('during')).rejects.toThrow(SAFE_MODE_ERROR_MSG);
+
+			const { del_doc } = actor;
+
+			await expect(
+				del_doc(TEST_COLLECTION, 'before', { version: toNullable(1n) })
+			).rejects.toThrow(SAFE_MODE_ERROR_MSG);
+		});
+
+		it('should block the writes of the controllers', async () => {
+			const { del_docs } = actor;
+
+			await expect(del_docs(TEST_COLLECTION)).rejects.toThrow(SAFE_MODE_ERROR_MSG);
+		});
+
+		it('should not acknowledge the safe mode for anonymous', async () => {
+			actor.setIdentity(new AnonymousIdentity());
+
+			const { acknowledge_safe_mode, get_self_test_report } = actor;
+
+			await expect(acknowledge_safe_mode()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+			await expect(get_self_test_report()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+
+			actor.setIdentity(writer);
+		});
+
+		it('should not acknowledge the safe mode for a write controller', async () => {
+			const { acknowledge_safe_mode } = actor;
+
+			await expect(acknowledge_safe_mode()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should unblock the writes once acknowledged by a controller of the canister', async () => {
+			actor.setIdentity(controller);
+
+			const { acknowledge_safe_mode, get_self_test_report } = actor;
+
+			await acknowledge_safe_mode();
+
+			actor.setIdentity(writer);
+
+			const report = fromNullable(await get_self_test_report());
+
+			expect(report?.safe_mode).toBeFalsy();
+			expect(report?.issues).toHaveLength(1);
+			expect(fromNullable(report?.acknowledged_by ?? [])?.toText()).toEqual(
+				controller.getPrincipal().toText()
+			);
+
+			const doc = await setDoc('after');
+
+			expect(fromNullable(doc.version)).toEqual(1n);
+		});
+
+		it('should not acknowledge once the safe mode is left', async () => {
+			actor.setIdentity(controller);
+
+			const { acknowledge_safe_mode } = actor;
+
+			await expect(acknowledge_safe_mode()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+
+			actor.setIdentity(writer);
+		});
+	});
+});
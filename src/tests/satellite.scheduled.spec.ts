@@ -0,0 +1,221 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { arrayBufferToUint8Array, fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { nanoid } from 'nanoid';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite scheduled publishing', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test';
+
+	const ONE_HOUR_NANOS = 3_600_000_000_000n;
+	const ONE_HOUR_MS = 3_600_000;
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		actor.setIdentity(controller);
+
+		const setRule: SetRule = {
+			memory: toNullable(),
+			max_size: toNullable(),
+			max_capacity: toNullable(),
+			read: { Public: null },
+			mutable_permissions: toNullable(),
+			write: { Managed: null },
+			version: toNullable(),
+			rate_config: toNullable(),
+			user_quota: toNullable(),
+			collation: toNullable(),
+			retention: toNullable()
+		};
+
+		const { set_rule } = actor;
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	const now = async (): Promise<bigint> => BigInt(await pic.getTime()) * 1_000_000n;
+
+	describe('documents', () => {
+		const key = nanoid();
+
+		it('should reject an invalid schedule', async () => {
+			actor.setIdentity(user);
+
+			const { set_doc } = actor;
+
+			const publishAt = await now();
+
+			await expect(
+				set_doc(TEST_COLLECTION, nanoid(), {
+					attachments: toNullable(),
+					data: await toArray({ hello: 'World' }),
+					description: toNullable(),
+					publish_at: toNullable(publishAt + ONE_HOUR_NANOS),
+					unpublish_at: toNullable(publishAt),
+					version: toNullable()
+				})
+			).rejects.toThrow('error_unpublish_at_before_publish_at');
+		});
+
+		it('should hide a doc scheduled for later', async () => {
+			actor.setIdentity(user);
+
+			const { set_doc, get_doc } = actor;
+
+			const publishAt = (await now()) + ONE_HOUR_NANOS;
+
+			const doc = await set_doc(TEST_COLLECTION, key, {
+				attachments: toNullable(),
+				data: await toArray({ hello: 'World' }),
+				description: toNullable(),
+				publish_at: toNullable(publishAt),
+				unpublish_at: toNullable(publishAt + ONE_HOUR_NANOS),
+				version: toNullable()
+			});
+
+			expect(doc.publish_at).toEqual([publishAt]);
+
+			// The owner can read its scheduled doc
+			expect(fromNullable(await get_doc(TEST_COLLECTION, key))).not.toBeUndefined();
+
+			actor.setIdentity(new AnonymousIdentity());
+
+			expect(fromNullable(await actor.get_doc(TEST_COLLECTION, key))).toBeUndefined();
+
+			const { items_length } = await actor.list_docs(TEST_COLLECTION, {
+				matcher: toNullable(),
+				owner: toNullable(),
+				order: toNullable(),
+				paginate: toNullable()
+			});
+
+			expect(items_length).toEqual(0n);
+		});
+
+		it('should read a doc scheduled for later as a controller', async () => {
+			actor.setIdentity(controller);
+
+			expect(fromNullable(await actor.get_doc(TEST_COLLECTION, key))).not.toBeUndefined();
+		});
+
+		it('should publish the doc', async () => {
+			await pic.advanceTime(ONE_HOUR_MS);
+
+			actor.setIdentity(new AnonymousIdentity());
+
+			expect(fromNullable(await actor.get_doc(TEST_COLLECTION, key))).not.toBeUndefined();
+
+			const { items_length } = await actor.list_docs(TEST_COLLECTION, {
+				matcher: toNullable(),
+				owner: toNullable(),
+				order: toNullable(),
+				paginate: toNullable()
+			});
+
+			expect(items_length).toEqual(1n);
+		});
+
+		it('should unpublish the doc', async () => {
+			await pic.advanceTime(ONE_HOUR_MS);
+
+			actor.setIdentity(new AnonymousIdentity());
+
+			expect(fromNullable(await actor.get_doc(TEST_COLLECTION, key))).toBeUndefined();
+		});
+	});
+
+	describe('assets', () => {
+		const HTML = '<html><body>Scheduled</body></html>';
+
+		const full_path = '/scheduled.html';
+
+		const request = async (): Promise<number> => {
+			const { http_request } = actor;
+
+			const { status_code } = await http_request({
+				body: [],
+				certificate_version: toNullable(2),
+				headers: [],
+				method: 'GET',
+				url: full_path
+			});
+
+			return status_code;
+		};
+
+		it('should not serve an asset scheduled for later', async () => {
+			actor.setIdentity(controller);
+
+			const { commit_asset_upload, upload_asset_chunk, init_asset_upload } = actor;
+
+			const publishAt = (await now()) + ONE_HOUR_NANOS;
+
+			const file = await init_asset_upload({
+				collection: '#dapp',
+				description: toNullable(),
+				encoding_type: [],
+				full_path,
+				name: 'scheduled.html',
+				publish_at: toNullable(publishAt),
+				token: toNullable(),
+				unpublish_at: toNullable(publishAt + ONE_HOUR_NANOS)
+			});
+
+			const blob = new Blob([HTML], {
+				type: 'text/plain; charset=utf-8'
+			});
+
+			const chunk = await upload_asset_chunk({
+				batch_id: file.batch_id,
+				content: arrayBufferToUint8Array(await blob.arrayBuffer()),
+				order_id: [0n]
+			});
+
+			await commit_asset_upload({
+				batch_id: file.batch_id,
+				chunk_ids: [chunk.chunk_id],
+				headers: []
+			});
+
+			expect(await request()).not.toEqual(200);
+		});
+
+		it('should serve the asset once published', async () => {
+			await pic.advanceTime(ONE_HOUR_MS);
+			await pic.tick();
+
+			expect(await request()).toEqual(200);
+		});
+
+		it('should no longer serve the asset once unpublished', async () => {
+			await pic.advanceTime(ONE_HOUR_MS);
+			await pic.tick();
+
+			expect(await request()).not.toEqual(200);
+		});
+	});
+});
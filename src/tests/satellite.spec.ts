@@ -47,7 +47,10 @@ describe('Satellite', () => {
 		mutable_permissions: toNullable(),
 		write: { Managed: null },
 		version: toNullable(),
-		rate_config: []
+		rate_config: [],
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
 	};
 
 	const setRuleWithValues: SetRule = {
@@ -63,7 +66,10 @@ describe('Satellite', () => {
 				max_tokens: 999n,
 				time_per_token_ns: 888n
 			}
-		]
+		],
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
 	};
 
 	let testRuleVersion: [] | [bigint];
@@ -722,7 +728,9 @@ describe('Satellite', () => {
 					encoding_type: [],
 					full_path: '/hello.html',
 					name: 'hello.html',
-					token: toNullable()
+					publish_at: toNullable(),
+					token: toNullable(),
+					unpublish_at: toNullable()
 				})
 			).rejects.toThrow('Caller not allowed to upload data.');
 		});
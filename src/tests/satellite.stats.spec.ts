@@ -0,0 +1,255 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Collection stats', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const user1 = Ed25519KeyIdentity.generate();
+	const user2 = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_stats';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Stable: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Public: null },
+		mutable_permissions: toNullable(),
+		write: { Public: null },
+		version: toNullable(),
+		rate_config: [],
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const setDoc = async ({ key, identity }: { key: string; identity: Ed25519KeyIdentity }) => {
+		actor.setIdentity(identity);
+
+		const { set_doc } = actor;
+
+		return await set_doc(TEST_COLLECTION, key, {
+			attachments: toNullable(),
+			data: await toArray({ hello: key }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('controller', () => {
+		let docsBytes = 0n;
+
+		beforeAll(async () => {
+			for (const [key, identity] of [
+				['doc-1', user1],
+				['doc-2', user1],
+				['doc-3', user2]
+			] as [string, Ed25519KeyIdentity][]) {
+				const doc = await setDoc({ key, identity });
+				docsBytes += BigInt(doc.data.length);
+			}
+
+			actor.setIdentity(controller);
+		});
+
+		it('should have no stats for an unknown collection', async () => {
+			const { get_collection_stats } = actor;
+
+			const { docs, assets } = await get_collection_stats('unknown');
+
+			expect(fromNullable(docs)).toBeUndefined();
+			expect(fromNullable(assets)).toBeUndefined();
+		});
+
+		it('should count the documents of a collection', async () => {
+			const { get_collection_stats } = actor;
+
+			const { docs, assets } = await get_collection_stats(TEST_COLLECTION);
+
+			const stats = fromNullable(docs);
+
+			assertNonNullish(stats);
+
+			expect(stats.items_count).toEqual(3n);
+			expect(stats.items_bytes).toEqual(docsBytes);
+			expect(fromNullable(stats.min_created_at)).not.toBeUndefined();
+			expect(fromNullable(stats.max_updated_at)).not.toBeUndefined();
+
+			expect(fromNullable(assets)).toBeUndefined();
+		});
+
+		it('should list the top contributors', async () => {
+			const { get_collection_stats } = actor;
+
+			const { docs } = await get_collection_stats(TEST_COLLECTION);
+
+			const stats = fromNullable(docs);
+
+			assertNonNullish(stats);
+
+			expect(stats.top_owners).toHaveLength(2);
+
+			const [[firstOwner, firstCounters], [secondOwner, secondCounters]] = stats.top_owners;
+
+			expect(firstOwner.toText()).toEqual(user1.getPrincipal().toText());
+			expect(firstCounters.items_count).toEqual(2n);
+
+			expect(secondOwner.toText()).toEqual(user2.getPrincipal().toText());
+			expect(secondCounters.items_count).toEqual(1n);
+		});
+
+		it('should keep the count when a document is updated', async () => {
+			const { get_doc, set_doc, get_collection_stats } = actor;
+
+			const doc = fromNullable(await get_doc(TEST_COLLECTION, 'doc-3'));
+
+			assertNonNullish(doc);
+
+			actor.setIdentity(user2);
+
+			await set_doc(TEST_COLLECTION, 'doc-3', {
+				...doc,
+				data: await toArray({ hello: 'updated' })
+			});
+
+			actor.setIdentity(controller);
+
+			const { docs } = await get_collection_stats(TEST_COLLECTION);
+
+			expect(fromNullable(docs)?.items_count).toEqual(3n);
+		});
+
+		it('should narrow the timestamps when the latest document is deleted', async () => {
+			const { get_doc, del_doc, get_collection_stats } = actor;
+
+			const [doc1, doc2, doc3] = await Promise.all(
+				['doc-1', 'doc-2', 'doc-3'].map(async (key) =>
+					fromNullable(await get_doc(TEST_COLLECTION, key))
+				)
+			);
+
+			assertNonNullish(doc1);
+			assertNonNullish(doc2);
+			assertNonNullish(doc3);
+
+			await del_doc(TEST_COLLECTION, 'doc-3', { version: doc3.version });
+
+			const { docs } = await get_collection_stats(TEST_COLLECTION);
+
+			const stats = fromNullable(docs);
+
+			assertNonNullish(stats);
+
+			expect(stats.items_count).toEqual(2n);
+			expect(fromNullable(stats.min_created_at)).toEqual(
+				doc1.created_at < doc2.created_at ? doc1.created_at : doc2.created_at
+			);
+			expect(fromNullable(stats.max_updated_at)).toEqual(
+				doc1.updated_at > doc2.updated_at ? doc1.updated_at : doc2.updated_at
+			);
+		});
+
+		it('should update the stats when documents are deleted', async () => {
+			const { del_docs, get_collection_stats } = actor;
+
+			await del_docs(TEST_COLLECTION);
+
+			const { docs } = await get_collection_stats(TEST_COLLECTION);
+
+			expect(fromNullable(docs)).toBeUndefined();
+		});
+
+		it('should count the assets of a collection', async () => {
+			const {
+				del_assets,
+				init_asset_upload,
+				upload_asset_chunk,
+				commit_asset_upload,
+				get_collection_stats
+			} = actor;
+
+			await del_assets('#dapp');
+
+			const html = new TextEncoder().encode('<html><body>Hello</body></html>');
+
+			const file = await init_asset_upload({
+				collection: '#dapp',
+				description: toNullable(),
+				encoding_type: [],
+				full_path: '/hello.html',
+				name: 'hello.html',
+				publish_at: toNullable(),
+				token: toNullable(),
+				unpublish_at: toNullable()
+			});
+
+			const chunk = await upload_asset_chunk({
+				batch_id: file.batch_id,
+				content: html,
+				order_id: [0n]
+			});
+
+			await commit_asset_upload({
+				batch_id: file.batch_id,
+				chunk_ids: [chunk.chunk_id],
+				headers: []
+			});
+
+			const { assets } = await get_collection_stats('#dapp');
+
+			const stats = fromNullable(assets);
+
+			assertNonNullish(stats);
+
+			expect(stats.items_count).toEqual(1n);
+			expect(stats.items_bytes).toEqual(BigInt(html.length));
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not get the stats of a collection', async () => {
+			const { get_collection_stats } = actor;
+
+			await expect(get_collection_stats(TEST_COLLECTION)).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
@@ -0,0 +1,124 @@
+import type { _SERVICE as SatelliteActor } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Storage chunks', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const encoder = new TextEncoder();
+	const decoder = new TextDecoder();
+
+	const initUpload = async (full_path: string): Promise<bigint> => {
+		const { init_asset_upload } = actor;
+
+		const { batch_id } = await init_asset_upload({
+			collection: '#dapp',
+			description: toNullable(),
+			encoding_type: [],
+			full_path,
+			name: full_path.split('/').pop() ?? full_path,
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		return batch_id;
+	};
+
+	const uploadChunk = async ({
+		batch_id,
+		content,
+		order_id
+	}: {
+		batch_id: bigint;
+		content: string;
+		order_id: bigint;
+	}): Promise<bigint> => {
+		const { upload_asset_chunk } = actor;
+
+		const { chunk_id } = await upload_asset_chunk({
+			batch_id,
+			content: encoder.encode(content),
+			order_id: [order_id]
+		});
+
+		return chunk_id;
+	};
+
+	const readBody = async (url: string): Promise<string> => {
+		const { http_request } = actor;
+
+		const { body } = await http_request({
+			body: [],
+			certificate_version: toNullable(),
+			headers: [],
+			method: 'GET',
+			url
+		});
+
+		return decoder.decode(body as Uint8Array);
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should replace a chunk uploaded again at the same position', async () => {
+		const batch_id = await initUpload('/retry.txt');
+
+		const first = await uploadChunk({ batch_id, content: 'Hel', order_id: 0n });
+		const retry = await uploadChunk({ batch_id, content: 'Hello', order_id: 0n });
+		const second = await uploadChunk({ batch_id, content: ' World', order_id: 1n });
+
+		expect(retry).toEqual(first);
+
+		const { commit_asset_upload } = actor;
+
+		await commit_asset_upload({
+			batch_id,
+			chunk_ids: [first, retry, second],
+			headers: []
+		});
+
+		expect(await readBody('/retry.txt')).toEqual('Hello World');
+	});
+
+	it('should assemble the chunks by order regardless of the upload order', async () => {
+		const batch_id = await initUpload('/parallel.txt');
+
+		const last = await uploadChunk({ batch_id, content: 'C', order_id: 2n });
+		const first = await uploadChunk({ batch_id, content: 'A', order_id: 0n });
+		const middle = await uploadChunk({ batch_id, content: 'B', order_id: 1n });
+
+		const { commit_asset_upload } = actor;
+
+		await commit_asset_upload({
+			batch_id,
+			chunk_ids: [middle, last, first],
+			headers: []
+		});
+
+		expect(await readBody('/parallel.txt')).toEqual('ABC');
+	});
+});
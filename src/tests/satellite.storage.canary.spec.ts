@@ -0,0 +1,200 @@
+import type { _SERVICE as SatelliteActor } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { arrayBufferToUint8Array, fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG } from './constants/satellite-tests.constants';
+import { deleteDefaultIndexHTML } from './utils/satellite-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite storage - Canary deployment', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const PREVIOUS_HTML = '<html><body>Previous</body></html>';
+	const NEXT_HTML = '<html><body>Next</body></html>';
+
+	const uploadHtml = async (html: string) => {
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+		const file = await init_asset_upload({
+			collection: '#dapp',
+			description: toNullable(),
+			encoding_type: [],
+			full_path: '/hello.html',
+			name: 'hello.html',
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const blob = new Blob([html], {
+			type: 'text/html; charset=utf-8'
+		});
+
+		const chunk = await upload_asset_chunk({
+			batch_id: file.batch_id,
+			content: arrayBufferToUint8Array(await blob.arrayBuffer()),
+			order_id: [0n]
+		});
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: [chunk.chunk_id],
+			headers: []
+		});
+	};
+
+	const requestHtml = async (headers: [string, string][] = []): Promise<string> => {
+		const { http_request } = actor;
+
+		const { body } = await http_request({
+			body: [],
+			certificate_version: toNullable(2),
+			headers,
+			method: 'GET',
+			url: '/hello.html'
+		});
+
+		return new TextDecoder().decode(body as Uint8Array<ArrayBufferLike>);
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: a } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = a;
+
+		await deleteDefaultIndexHTML({ actor, controller });
+
+		await uploadHtml(PREVIOUS_HTML);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not set a canary deployment', async () => {
+			const { set_canary_deployment } = actor;
+
+			await expect(
+				set_canary_deployment({ percentage: 50, session: toNullable() })
+			).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+
+		it('should not finalize a deployment', async () => {
+			const { finalize_deployment } = actor;
+
+			await expect(finalize_deployment()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+
+	describe('controller', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should not set an invalid percentage', async () => {
+			const { set_canary_deployment } = actor;
+
+			await expect(
+				set_canary_deployment({ percentage: 101, session: toNullable() })
+			).rejects.toThrow('Canary deployment percentage must be between 0 and 100.');
+		});
+
+		it('should not finalize without deployment', async () => {
+			const { finalize_deployment } = actor;
+
+			await expect(finalize_deployment()).rejects.toThrow('No canary deployment in progress.');
+		});
+
+		it('should serve new assets to the canary only', async () => {
+			const { set_canary_deployment, get_canary_deployment } = actor;
+
+			await set_canary_deployment({
+				percentage: 0,
+				session: toNullable({ Cookie: 'session_id' })
+			});
+
+			await uploadHtml(NEXT_HTML);
+
+			const deployment = fromNullable(await get_canary_deployment());
+
+			assertNonNullish(deployment);
+
+			expect(deployment.paths).toEqual(['/hello.html']);
+			expect(deployment.percentage).toEqual(0);
+
+			expect(await requestHtml()).toEqual(PREVIOUS_HTML);
+			expect(await requestHtml([['Cookie', 'session_id=visitor']])).toEqual(PREVIOUS_HTML);
+
+			expect(await requestHtml([['X-Juno-Canary', '1']])).toEqual(NEXT_HTML);
+			expect(await requestHtml([['Cookie', 'juno_canary=1']])).toEqual(NEXT_HTML);
+		});
+
+		it('should serve new assets to the visitors of the percentage', async () => {
+			const { set_canary_deployment } = actor;
+
+			const { paths } = await set_canary_deployment({
+				percentage: 100,
+				session: toNullable({ Cookie: 'session_id' })
+			});
+
+			expect(paths).toEqual(['/hello.html']);
+
+			expect(await requestHtml([['Cookie', 'session_id=visitor']])).toEqual(NEXT_HTML);
+
+			// Requests without session identifier are served the previous assets
+			expect(await requestHtml()).toEqual(PREVIOUS_HTML);
+		});
+
+		it('should roll out to everyone', async () => {
+			const { finalize_deployment, get_canary_deployment } = actor;
+
+			await finalize_deployment();
+
+			expect(fromNullable(await get_canary_deployment())).toBeUndefined();
+
+			expect(await requestHtml()).toEqual(NEXT_HTML);
+		});
+
+		it('should serve a deleted asset to the visitors of the previous deployment', async () => {
+			const { set_canary_deployment, del_asset, get_canary_deployment, finalize_deployment } =
+				actor;
+
+			await set_canary_deployment({
+				percentage: 0,
+				session: toNullable({ Cookie: 'session_id' })
+			});
+
+			await del_asset('#dapp', '/hello.html');
+
+			const deployment = fromNullable(await get_canary_deployment());
+
+			expect(deployment?.paths).toEqual(['/hello.html']);
+
+			expect(await requestHtml()).toEqual(NEXT_HTML);
+			expect(await requestHtml([['X-Juno-Canary', '1']])).not.toEqual(NEXT_HTML);
+
+			await finalize_deployment();
+
+			expect(await requestHtml()).not.toEqual(NEXT_HTML);
+		});
+	});
+});
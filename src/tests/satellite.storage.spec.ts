@@ -13,11 +13,13 @@ import { PocketIc, type Actor } from '@hadronous/pic';
 import { toArray } from '@junobuild/utils';
 import { readFileSync } from 'node:fs';
 import { join } from 'node:path';
+import { brotliCompressSync, gzipSync } from 'node:zlib';
 import { afterAll, beforeAll, beforeEach, describe, expect, inject } from 'vitest';
 import {
 	CONTROLLER_ERROR_MSG,
 	SATELLITE_ADMIN_ERROR_MSG
 } from './constants/satellite-tests.constants';
+import { decodeExprPath } from './utils/certification-tests.utils';
 import { deleteDefaultIndexHTML } from './utils/satellite-tests.utils';
 import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
 
@@ -69,6 +71,13 @@ describe('Satellite storage', () => {
 					redirects: toNullable(),
 					rewrites: [],
 					raw_access: toNullable(),
+					delegations: toNullable(),
+					max_response_body_size: toNullable(),
+					csp_nonce: toNullable(),
+					wildcard_domains: toNullable(),
+					locales: toNullable(),
+					weighted_rewrites: toNullable(),
+					list_limits: toNullable(),
 					max_memory_size: toNullable()
 				})
 			).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
@@ -97,7 +106,9 @@ describe('Satellite storage', () => {
 				encoding_type: [],
 				full_path: '/hello.html',
 				name: 'hello.html',
-				token: toNullable()
+				publish_at: toNullable(),
+				token: toNullable(),
+				unpublish_at: toNullable()
 			});
 
 			actor.setIdentity(hacker);
@@ -120,7 +131,9 @@ describe('Satellite storage', () => {
 				encoding_type: [],
 				full_path: '/hello.html',
 				name: 'hello.html',
-				token: toNullable()
+				publish_at: toNullable(),
+				token: toNullable(),
+				unpublish_at: toNullable()
 			});
 
 			const chunk = await upload_asset_chunk({
@@ -164,7 +177,9 @@ describe('Satellite storage', () => {
 			encoding_type: [],
 			full_path,
 			name,
-			token: toNullable()
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
 		});
 
 		const chunk = await upload_asset_chunk({
@@ -194,6 +209,13 @@ describe('Satellite storage', () => {
 				redirects: [],
 				rewrites: [],
 				raw_access: toNullable(),
+				delegations: toNullable(),
+				max_response_body_size: toNullable(),
+				csp_nonce: toNullable(),
+				wildcard_domains: toNullable(),
+				locales: toNullable(),
+				weighted_rewrites: toNullable(),
+				list_limits: toNullable(),
 				max_memory_size: toNullable()
 			};
 
@@ -217,7 +239,9 @@ describe('Satellite storage', () => {
 				encoding_type: [],
 				full_path: '/hello.html',
 				name: 'hello.html',
-				token: toNullable()
+				publish_at: toNullable(),
+				token: toNullable(),
+				unpublish_at: toNullable()
 			});
 
 			const HTML = '<html><body>Hello</body></html>';
@@ -271,6 +295,77 @@ describe('Satellite storage', () => {
 			expect(decoder.decode(body as Uint8Array<ArrayBufferLike>)).toEqual(HTML);
 		});
 
+		describe('precompressed encoding', () => {
+			const uploadEncoding = async ({
+				encoding_type,
+				content
+			}: {
+				encoding_type: [] | [string];
+				content: Uint8Array;
+			}) => {
+				const { commit_asset_upload, upload_asset_chunk, init_asset_upload } = actor;
+
+				const file = await init_asset_upload({
+					collection: '#dapp',
+					description: toNullable(),
+					encoding_type,
+					full_path: '/compressed.js',
+					name: 'compressed.js',
+					publish_at: toNullable(),
+					token: toNullable(),
+					unpublish_at: toNullable()
+				});
+
+				const chunk = await upload_asset_chunk({
+					batch_id: file.batch_id,
+					content,
+					order_id: [0n]
+				});
+
+				await commit_asset_upload({
+					batch_id: file.batch_id,
+					chunk_ids: [chunk.chunk_id],
+					headers: []
+				});
+			};
+
+			const SCRIPT = 'console.log("Hello World");';
+
+			beforeAll(async () => {
+				await uploadEncoding({
+					encoding_type: [],
+					content: new TextEncoder().encode(SCRIPT)
+				});
+			});
+
+			it('should commit a gzip encoding matching the identity encoding', async () => {
+				await expect(
+					uploadEncoding({
+						encoding_type: ['gzip'],
+						content: gzipSync(SCRIPT)
+					})
+				).resolves.not.toThrow();
+			});
+
+			it('should throw error if the gzip encoding does not match the identity encoding', async () => {
+				await expect(
+					uploadEncoding({
+						encoding_type: ['gzip'],
+						content: gzipSync('console.log("Something else");')
+					})
+				).rejects.toThrow('The decompressed gzip encoding does not match the identity encoding.');
+			});
+
+			it('should throw error if the br encoding does not match the identity encoding', async () => {
+				await expect(
+					uploadEncoding({
+						encoding_type: ['br'],
+						content: brotliCompressSync('console.log("Something else");')
+					})
+				).rejects.toThrow('The decompressed br encoding does not match the identity encoding.');
+			});
+		});
+
 		describe.each(['/.well-known/ic-domains', '/.well-known/ii-alternative-origins'])(
 			'Assertion',
 			(full_path) => {
@@ -284,7 +379,9 @@ describe('Satellite storage', () => {
 							encoding_type: [],
 							full_path,
 							name: 'ic-domains',
-							token: toNullable()
+							publish_at: toNullable(),
+							token: toNullable(),
+							unpublish_at: toNullable()
 						})
 					).rejects.toThrow(`${full_path} is a reserved asset.`);
 				});
@@ -307,7 +404,10 @@ describe('Satellite storage', () => {
 						mutable_permissions: toNullable(),
 						write: { Managed: null },
 						version: toNullable(),
-						rate_config: toNullable()
+						rate_config: toNullable(),
+						user_quota: toNullable(),
+						collation: toNullable(),
+						retention: toNullable()
 					};
 
 					await set_rule({ Storage: null }, collection, setRule);
@@ -341,7 +441,9 @@ describe('Satellite storage', () => {
 							encoding_type: [],
 							full_path: '/hello.html',
 							name: 'hello.html',
-							token: toNullable()
+							publish_at: toNullable(),
+							token: toNullable(),
+							unpublish_at: toNullable()
 						})
 					).rejects.toThrow('Asset path must be prefixed with collection key.');
 				});
@@ -443,7 +545,9 @@ describe('Satellite storage', () => {
 					encoding_type: [],
 					full_path: '/index.html',
 					name: 'index.html',
-					token: toNullable()
+					publish_at: toNullable(),
+					token: toNullable(),
+					unpublish_at: toNullable()
 				});
 
 				const HTML = '<html><body>Index</body></html>';
@@ -497,6 +601,13 @@ describe('Satellite storage', () => {
 					],
 					rewrites: [['/hello.html', '/hello.html']],
 					raw_access: toNullable(),
+					delegations: toNullable(),
+					max_response_body_size: toNullable(),
+					csp_nonce: toNullable(),
+					wildcard_domains: toNullable(),
+					locales: toNullable(),
+					weighted_rewrites: toNullable(),
+					list_limits: toNullable(),
 					max_memory_size: toNullable()
 				};
 
@@ -534,7 +645,9 @@ describe('Satellite storage', () => {
 				encoding_type: [],
 				full_path,
 				name: full_path,
-				token: toNullable()
+				publish_at: toNullable(),
+				token: toNullable(),
+				unpublish_at: toNullable()
 			});
 
 			const chunk = await upload_asset_chunk({
@@ -579,6 +692,13 @@ describe('Satellite storage', () => {
 					redirects: [],
 					rewrites: [],
 					raw_access: toNullable({ Allow: null }),
+					delegations: toNullable(),
+					max_response_body_size: toNullable(),
+					csp_nonce: toNullable(),
+					wildcard_domains: toNullable(),
+					locales: toNullable(),
+					weighted_rewrites: toNullable(),
+					list_limits: toNullable(),
 					max_memory_size: toNullable()
 				};
 
@@ -607,6 +727,13 @@ describe('Satellite storage', () => {
 					redirects: [],
 					rewrites: [],
 					raw_access: toNullable({ Deny: null }),
+					delegations: toNullable(),
+					max_response_body_size: toNullable(),
+					csp_nonce: toNullable(),
+					wildcard_domains: toNullable(),
+					locales: toNullable(),
+					weighted_rewrites: toNullable(),
+					list_limits: toNullable(),
 					max_memory_size: toNullable()
 				};
 
@@ -632,6 +759,13 @@ describe('Satellite storage', () => {
 					redirects: [],
 					rewrites: [],
 					raw_access: toNullable({ Allow: null }),
+					delegations: toNullable(),
+					max_response_body_size: toNullable(),
+					csp_nonce: toNullable(),
+					wildcard_domains: toNullable(),
+					locales: toNullable(),
+					weighted_rewrites: toNullable(),
+					list_limits: toNullable(),
 					max_memory_size: toNullable()
 				};
 
@@ -651,6 +785,242 @@ describe('Satellite storage', () => {
 				expect(decoder.decode(body as Uint8Array<ArrayBufferLike>)).toEqual(HTML);
 			});
 		});
+
+		describe('weighted rewrite', () => {
+			const HTML_B = '<html><body>Hello B</body></html>';
+
+			beforeAll(async () => {
+				const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+				const blobB = new Blob([HTML_B], {
+					type: 'text/plain; charset=utf-8'
+				});
+
+				const file = await init_asset_upload({
+					collection,
+					description: toNullable(),
+					encoding_type: [],
+					full_path: '/index-b.html',
+					name: 'index-b.html',
+					publish_at: toNullable(),
+					token: toNullable(),
+					unpublish_at: toNullable()
+				});
+
+				const chunk = await upload_asset_chunk({
+					batch_id: file.batch_id,
+					content: arrayBufferToUint8Array(await blobB.arrayBuffer()),
+					order_id: [0n]
+				});
+
+				await commit_asset_upload({
+					batch_id: file.batch_id,
+					chunk_ids: [chunk.chunk_id],
+					headers: []
+				});
+
+				const { set_storage_config } = actor;
+
+				await set_storage_config({
+					headers: [],
+					iframe: toNullable(),
+					redirects: [],
+					rewrites: [['/campaign/**', '/index.html']],
+					raw_access: toNullable({ Allow: null }),
+					delegations: toNullable(),
+					max_response_body_size: toNullable(),
+					csp_nonce: toNullable(),
+					wildcard_domains: toNullable(),
+					locales: toNullable(),
+					weighted_rewrites: toNullable([
+						[
+							'/campaign/**',
+							{
+								session: { Cookie: 'session_id' },
+								destinations: [
+									{ destination: '/index.html', weight: 0 },
+									{ destination: '/index-b.html', weight: 100 }
+								]
+							}
+						]
+					]),
+					list_limits: toNullable(),
+					max_memory_size: toNullable()
+				});
+			});
+
+			it('should rewrite to the weighted destination', async () => {
+				const { http_request } = actor;
+
+				const { status_code, body } = await http_request({
+					body: [],
+					certificate_version: toNullable(2),
+					headers: [['Cookie', 'session_id=123']],
+					method: 'GET',
+					url: '/campaign/hello'
+				});
+
+				expect(status_code).toEqual(200);
+
+				const decoder = new TextDecoder();
+				expect(decoder.decode(body as Uint8Array<ArrayBufferLike>)).toEqual(HTML_B);
+			});
+
+			it('should fallback to the plain rewrite if no destination has weight', async () => {
+				const { http_request, set_storage_config } = actor;
+
+				await set_storage_config({
+					headers: [],
+					iframe: toNullable(),
+					redirects: [],
+					rewrites: [['/campaign/**', '/index.html']],
+					raw_access: toNullable({ Allow: null }),
+					delegations: toNullable(),
+					max_response_body_size: toNullable(),
+					csp_nonce: toNullable(),
+					wildcard_domains: toNullable(),
+					locales: toNullable(),
+					weighted_rewrites: toNullable([
+						[
+							'/campaign/**',
+							{
+								session: { Header: 'X-Session-Id' },
+								destinations: [{ destination: '/index-b.html', weight: 0 }]
+							}
+						]
+					]),
+					list_limits: toNullable(),
+					max_memory_size: toNullable()
+				});
+
+				const { status_code, body } = await http_request({
+					body: [],
+					certificate_version: toNullable(2),
+					headers: [['X-Session-Id', '123']],
+					method: 'GET',
+					url: '/campaign/hello'
+				});
+
+				expect(status_code).toEqual(200);
+
+				const decoder = new TextDecoder();
+				expect(decoder.decode(body as Uint8Array<ArrayBufferLike>)).toEqual(HTML);
+			});
+		});
+
+		describe('locales', () => {
+			const HTML_EN = '<html><body>Hello</body></html>';
+			const HTML_FR = '<html><body>Bonjour</body></html>';
+
+			beforeAll(async () => {
+				const { init_asset_upload, upload_asset_chunk, commit_asset_upload, set_storage_config } =
+					actor;
+
+				for (const [full_path, html] of [
+					['/en/welcome.html', HTML_EN],
+					['/fr/welcome.html', HTML_FR]
+				]) {
+					const blob = new Blob([html], {
+						type: 'text/plain; charset=utf-8'
+					});
+
+					const file = await init_asset_upload({
+						collection,
+						description: toNullable(),
+						encoding_type: [],
+						full_path,
+						name: 'welcome.html',
+						publish_at: toNullable(),
+						token: toNullable(),
+						unpublish_at: toNullable()
+					});
+
+					const chunk = await upload_asset_chunk({
+						batch_id: file.batch_id,
+						content: arrayBufferToUint8Array(await blob.arrayBuffer()),
+						order_id: [0n]
+					});
+
+					await commit_asset_upload({
+						batch_id: file.batch_id,
+						chunk_ids: [chunk.chunk_id],
+						headers: []
+					});
+				}
+
+				await set_storage_config({
+					headers: [],
+					iframe: toNullable(),
+					redirects: [],
+					rewrites: [],
+					raw_access: toNullable({ Allow: null }),
+					delegations: toNullable(),
+					max_response_body_size: toNullable(),
+					csp_nonce: toNullable(),
+					wildcard_domains: toNullable(),
+					locales: toNullable({
+						default_locale: 'en',
+						supported_locales: ['fr'],
+						cookie: toNullable('lang')
+					}),
+					weighted_rewrites: toNullable(),
+					list_limits: toNullable(),
+					max_memory_size: toNullable()
+				});
+			});
+
+			const request = async (headers: [string, string][]): Promise<string> => {
+				const { http_request } = actor;
+
+				const { status_code, body } = await http_request({
+					body: [],
+					certificate_version: toNullable(2),
+					headers,
+					method: 'GET',
+					url: '/welcome'
+				});
+
+				expect(status_code).toEqual(200);
+
+				const decoder = new TextDecoder();
+				return decoder.decode(body as Uint8Array<ArrayBufferLike>);
+			};
+
+			it('should route to the locale of the Accept-Language header', async () => {
+				await expect(request([['Accept-Language', 'fr-CH, fr;q=0.9, en;q=0.8']])).resolves.toEqual(
+					HTML_FR
+				);
+			});
+
+			it('should route to the default locale if the language is not supported', async () => {
+				await expect(request([['Accept-Language', 'de-CH, de;q=0.9']])).resolves.toEqual(HTML_EN);
+			});
+
+			it('should route to the locale of the override cookie', async () => {
+				await expect(
+					request([
+						['Accept-Language', 'fr-CH, fr;q=0.9'],
+						['Cookie', 'lang=en']
+					])
+				).resolves.toEqual(HTML_EN);
+			});
+
+			it('should certify the localized responses for the exact path', async () => {
+				const { http_request } = actor;
+
+				for (const language of ['fr', 'en']) {
+					const { headers } = await http_request({
+						body: [],
+						certificate_version: toNullable(2),
+						headers: [['Accept-Language', language]],
+						method: 'GET',
+						url: '/welcome'
+					});
+
+					expect(decodeExprPath(headers)).toEqual(['http_expr', 'welcome', '<$>']);
+				}
+			});
+		});
 	});
 
 	const user = Ed25519KeyIdentity.generate();
@@ -663,20 +1033,26 @@ describe('Satellite storage', () => {
 			actor.setIdentity(user);
 
 			await set_doc('#user', user.getPrincipal().toText(), {
+				attachments: toNullable(),
 				data: await toArray({
 					provider: 'internet_identity'
 				}),
 				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
 				version: toNullable()
 			});
 
 			actor.setIdentity(user2);
 
 			await set_doc('#user', user2.getPrincipal().toText(), {
+				attachments: toNullable(),
 				data: await toArray({
 					provider: 'internet_identity'
 				}),
 				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
 				version: toNullable()
 			});
 		});
@@ -699,7 +1075,10 @@ describe('Satellite storage', () => {
 						mutable_permissions: toNullable(),
 						write: { Managed: null },
 						version: toNullable(),
-						rate_config: toNullable()
+						rate_config: toNullable(),
+						user_quota: toNullable(),
+						collation: toNullable(),
+						retention: toNullable()
 					};
 
 					await set_rule({ Storage: null }, collection, setRule);
@@ -719,7 +1098,9 @@ describe('Satellite storage', () => {
 						encoding_type: [],
 						full_path: `/${collection}/${name}`,
 						name,
-						token: toNullable()
+						publish_at: toNullable(),
+						token: toNullable(),
+						unpublish_at: toNullable()
 					});
 
 					const blob = new Blob([SVG], {
@@ -819,7 +1200,8 @@ describe('Satellite storage', () => {
 								created_at: toNullable({
 									GreaterThan: items[4][1].created_at
 								}),
-								updated_at: toNullable()
+								updated_at: toNullable(),
+								filter: toNullable()
 							}),
 							order: toNullable(),
 							owner: toNullable(),
@@ -842,7 +1224,8 @@ describe('Satellite storage', () => {
 								created_at: toNullable({
 									LessThan: items[4][1].created_at
 								}),
-								updated_at: toNullable()
+								updated_at: toNullable(),
+								filter: toNullable()
 							}),
 							order: toNullable(),
 							owner: toNullable(),
@@ -862,7 +1245,8 @@ describe('Satellite storage', () => {
 								created_at: toNullable({
 									Between: [items[4][1].created_at, items[8][1].created_at]
 								}),
-								updated_at: toNullable()
+								updated_at: toNullable(),
+								filter: toNullable()
 							}),
 							order: toNullable(),
 							owner: toNullable(),
@@ -905,7 +1289,8 @@ describe('Satellite storage', () => {
 								updated_at: toNullable({
 									GreaterThan: items[4][1].created_at
 								}),
-								created_at: toNullable()
+								created_at: toNullable(),
+								filter: toNullable()
 							}),
 							order: toNullable(),
 							owner: toNullable(),
@@ -928,7 +1313,8 @@ describe('Satellite storage', () => {
 								updated_at: toNullable({
 									LessThan: items[4][1].created_at
 								}),
-								created_at: toNullable()
+								created_at: toNullable(),
+								filter: toNullable()
 							}),
 							order: toNullable(),
 							owner: toNullable(),
@@ -948,7 +1334,8 @@ describe('Satellite storage', () => {
 								updated_at: toNullable({
 									Between: [items[4][1].created_at, items[8][1].created_at]
 								}),
-								created_at: toNullable()
+								created_at: toNullable(),
+								filter: toNullable()
 							}),
 							order: toNullable(),
 							owner: toNullable(),
@@ -979,7 +1366,8 @@ describe('Satellite storage', () => {
 								key: toNullable('/asset2\\.svg$'),
 								description: toNullable(),
 								created_at: toNullable(),
-								updated_at: toNullable()
+								updated_at: toNullable(),
+								filter: toNullable()
 							}),
 							order: toNullable({
 								desc: true,
@@ -994,7 +1382,8 @@ describe('Satellite storage', () => {
 								key: toNullable('/asset\\d+\\.svg$'),
 								description: toNullable(),
 								created_at: toNullable(),
-								updated_at: toNullable()
+								updated_at: toNullable(),
+								filter: toNullable()
 							}),
 							order: toNullable({
 								desc: true,
@@ -1028,7 +1417,8 @@ describe('Satellite storage', () => {
 								key: toNullable('/asset1\\.svg$'),
 								description: toNullable(),
 								created_at: toNullable(),
-								updated_at: toNullable()
+								updated_at: toNullable(),
+								filter: toNullable()
 							}),
 							order: toNullable({
 								desc: true,
@@ -1045,7 +1435,8 @@ describe('Satellite storage', () => {
 								key: toNullable('/asset\\d+\\.svg$'),
 								description: toNullable(),
 								created_at: toNullable(),
-								updated_at: toNullable()
+								updated_at: toNullable(),
+								filter: toNullable()
 							}),
 							order: toNullable({
 								desc: true,
@@ -1065,7 +1456,8 @@ describe('Satellite storage', () => {
 								key: toNullable('/user2_asset\\.svg$'),
 								description: toNullable(),
 								created_at: toNullable(),
-								updated_at: toNullable()
+								updated_at: toNullable(),
+								filter: toNullable()
 							}),
 							order: toNullable({
 								desc: true,
@@ -1095,7 +1487,8 @@ describe('Satellite storage', () => {
 								key: toNullable('/user2_asset\\.svg$'),
 								description: toNullable(),
 								created_at: toNullable(),
-								updated_at: toNullable()
+								updated_at: toNullable(),
+								filter: toNullable()
 							}),
 							order: toNullable(),
 							owner: toNullable(),
@@ -1208,7 +1601,9 @@ describe('Satellite storage', () => {
 					encoding_type: [],
 					full_path,
 					name,
-					token: toNullable()
+					publish_at: toNullable(),
+					token: toNullable(),
+					unpublish_at: toNullable()
 				});
 
 				const chunk = await upload_asset_chunk({
@@ -1247,6 +1642,13 @@ describe('Satellite storage', () => {
 					redirects: [],
 					rewrites: [],
 					raw_access: toNullable(),
+					delegations: toNullable(),
+					max_response_body_size: toNullable(),
+					csp_nonce: toNullable(),
+					wildcard_domains: toNullable(),
+					locales: toNullable(),
+					weighted_rewrites: toNullable(),
+					list_limits: toNullable(),
 					max_memory_size: toNullable({
 						heap: max === 'heap' ? [maxHeapMemorySize] : [],
 						stable: max === 'stable' ? [maxStableMemorySize] : []
@@ -1272,7 +1674,9 @@ describe('Satellite storage', () => {
 							encoding_type: [],
 							full_path,
 							name,
-							token: toNullable()
+							publish_at: toNullable(),
+							token: toNullable(),
+							unpublish_at: toNullable()
 						})
 					).rejects.toThrow(errorMsg);
 				});
@@ -1288,7 +1692,9 @@ describe('Satellite storage', () => {
 						encoding_type: [],
 						full_path,
 						name,
-						token: toNullable()
+						publish_at: toNullable(),
+						token: toNullable(),
+						unpublish_at: toNullable()
 					});
 
 					await configMaxMemory('Heap' in memory ? 'heap' : 'stable');
@@ -1313,7 +1719,9 @@ describe('Satellite storage', () => {
 						encoding_type: [],
 						full_path,
 						name,
-						token: toNullable()
+						publish_at: toNullable(),
+						token: toNullable(),
+						unpublish_at: toNullable()
 					});
 
 					const { chunk_id } = await upload_asset_chunk({
@@ -0,0 +1,254 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import type { _SERVICE as LedgerActor } from '@dfinity/ledger-icp/dist/candid/ledger';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, SubnetStateType, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { LEDGER_ID } from './constants/ledger-tests.contants';
+import { setupLedger } from './utils/ledger-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Token gates', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+	let ledgerActor: Actor<LedgerActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const holder = Ed25519KeyIdentity.generate();
+	const otherHolder = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const PREMIUM_COLLECTION = 'premium';
+	const FORUM_COLLECTION = 'forum';
+	const MEMBERS_COLLECTION = 'members';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: {
+			TokenGated: {
+				ledger_id: LEDGER_ID,
+				min_balance: 100_000n,
+				standard: { Icrc1: null }
+			}
+		},
+		mutable_permissions: toNullable(),
+		write: { Controllers: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'), {
+			nns: {
+				enableBenchmarkingInstructionLimits: false,
+				enableDeterministicTimeSlicing: false,
+				state: { type: SubnetStateType.New }
+			}
+		});
+
+		const { actor: l } = await setupLedger({ pic, controller });
+		ledgerActor = l;
+
+		const { icrc1_transfer } = ledgerActor;
+
+		for (const identity of [holder, otherHolder]) {
+			await icrc1_transfer({
+				amount: 500_000n,
+				to: { owner: identity.getPrincipal(), subaccount: [] },
+				fee: [],
+				memo: [],
+				from_subaccount: [],
+				created_at_time: []
+			});
+		}
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule, set_doc } = actor;
+
+		await set_rule({ Db: null }, PREMIUM_COLLECTION, setRule);
+		await set_rule({ Db: null }, FORUM_COLLECTION, {
+			...setRule,
+			read: { Public: null },
+			write: setRule.read
+		});
+
+		await set_doc(PREMIUM_COLLECTION, 'article', {
+			attachments: toNullable(),
+			data: await toArray({ title: 'Premium' }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should not set a token gated storage collection', async () => {
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await expect(set_rule({ Storage: null }, 'premium_images', setRule)).rejects.toThrow(
+			'Token gated permissions are not supported by the storage.'
+		);
+	});
+
+	it('should not read the documents before the holdings are verified', async () => {
+		actor.setIdentity(holder);
+
+		const { get_doc } = actor;
+
+		expect(fromNullable(await get_doc(PREMIUM_COLLECTION, 'article'))).toBeUndefined();
+	});
+
+	it('should read the documents once the holdings are verified', async () => {
+		actor.setIdentity(holder);
+
+		const { verify_token_gate, get_doc, list_docs } = actor;
+
+		expect(await verify_token_gate(PREMIUM_COLLECTION)).toBeTruthy();
+
+		expect(fromNullable(await get_doc(PREMIUM_COLLECTION, 'article'))).not.toBeUndefined();
+
+		const { items_length } = await list_docs(PREMIUM_COLLECTION, {
+			matcher: toNullable(),
+			order: toNullable(),
+			owner: toNullable(),
+			paginate: toNullable()
+		});
+
+		expect(items_length).toEqual(1n);
+	});
+
+	it('should only let the holders modify the documents they own', async () => {
+		const doc = {
+			attachments: toNullable<string[]>(),
+			data: await toArray({ title: 'Hello' }),
+			description: toNullable<string>(),
+			publish_at: toNullable<bigint>(),
+			unpublish_at: toNullable<bigint>(),
+			version: toNullable<bigint>()
+		};
+
+		actor.setIdentity(holder);
+
+		const { verify_token_gate, set_doc } = actor;
+
+		expect(await verify_token_gate(FORUM_COLLECTION)).toBeTruthy();
+
+		const { version } = await set_doc(FORUM_COLLECTION, 'post', doc);
+
+		actor.setIdentity(otherHolder);
+
+		expect(await verify_token_gate(FORUM_COLLECTION)).toBeTruthy();
+
+		await expect(set_doc(FORUM_COLLECTION, 'post', { ...doc, version })).rejects.toThrow(
+			'Cannot write.'
+		);
+
+		await expect(set_doc(FORUM_COLLECTION, 'reply', doc)).resolves.not.toThrow();
+	});
+
+	it('should not grant access to a caller without enough tokens', async () => {
+		actor.setIdentity(user);
+
+		const { verify_token_gate, get_doc } = actor;
+
+		expect(await verify_token_gate(PREMIUM_COLLECTION)).toBeFalsy();
+
+		expect(fromNullable(await get_doc(PREMIUM_COLLECTION, 'article'))).toBeUndefined();
+	});
+
+	it('should verify every gate of a collection', async () => {
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, MEMBERS_COLLECTION, {
+			...setRule,
+			write: {
+				TokenGated: {
+					ledger_id: LEDGER_ID,
+					min_balance: 1_000_000n,
+					standard: { Icrc1: null }
+				}
+			}
+		});
+
+		actor.setIdentity(holder);
+
+		const { verify_token_gate, set_doc } = actor;
+
+		expect(await verify_token_gate(MEMBERS_COLLECTION)).toBeFalsy();
+
+		await expect(
+			set_doc(MEMBERS_COLLECTION, 'profile', {
+				attachments: toNullable(),
+				data: await toArray({ name: 'Holder' }),
+				description: toNullable(),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			})
+		).rejects.toThrow('Cannot write.');
+	});
+
+	it('should restrict the access again once the verification expired', async () => {
+		actor.setIdentity(holder);
+
+		await pic.advanceTime(11 * 60 * 1000);
+		await pic.tick();
+
+		const { get_doc } = actor;
+
+		expect(fromNullable(await get_doc(PREMIUM_COLLECTION, 'article'))).toBeUndefined();
+	});
+
+	it('should not verify a collection that is not token gated', async () => {
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, 'notes', {
+			...setRule,
+			read: { Managed: null },
+			write: { Managed: null }
+		});
+
+		actor.setIdentity(holder);
+
+		const { verify_token_gate } = actor;
+
+		await expect(verify_token_gate('notes')).rejects.toThrow('The collection is not token gated.');
+	});
+
+	it('should not verify the holdings of an anonymous caller', async () => {
+		actor.setIdentity(new AnonymousIdentity());
+
+		const { verify_token_gate } = actor;
+
+		await expect(verify_token_gate(PREMIUM_COLLECTION)).rejects.toThrow(
+			'The holdings of an anonymous caller cannot be verified.'
+		);
+	});
+});
@@ -0,0 +1,183 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { nanoid } from 'nanoid';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import {
+	CONTROLLER_ERROR_MSG,
+	SATELLITE_ADMIN_ERROR_MSG
+} from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Traces', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_traces';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const setDoc = async () => {
+		actor.setIdentity(user);
+
+		const { set_doc } = actor;
+
+		await set_doc(TEST_COLLECTION, nanoid(), {
+			attachments: toNullable(),
+			data: await toArray({ hello: 'World' }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	};
+
+	const countSetDocCalls = async (): Promise<bigint> => {
+		actor.setIdentity(controller);
+
+		const { list_traces } = actor;
+
+		const traces = await list_traces();
+
+		return traces
+			.filter(({ endpoint }) => endpoint === 'set_doc')
+			.reduce((acc, { trace: { calls } }) => acc + calls, 0n);
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should not trace by default', async () => {
+			const { get_traces_config } = actor;
+
+			expect(fromNullable(await get_traces_config())).toBeUndefined();
+
+			await setDoc();
+
+			expect(await countSetDocCalls()).toEqual(0n);
+		});
+
+		it('should trace the calls once enabled', async () => {
+			const { set_traces_config, get_traces_config } = actor;
+
+			await set_traces_config({ enabled: true });
+
+			expect(fromNullable(await get_traces_config())).toEqual({ enabled: true });
+
+			await setDoc();
+			await setDoc();
+
+			actor.setIdentity(controller);
+
+			const { list_traces } = actor;
+
+			const traces = await list_traces();
+
+			const trace = traces.find(({ endpoint }) => endpoint === 'set_doc');
+
+			expect(trace).not.toBeUndefined();
+			expect(trace?.trace.calls).toEqual(2n);
+			expect(trace?.trace.instructions).toBeGreaterThan(0n);
+			expect(trace?.trace.max_instructions).toBeGreaterThan(0n);
+			expect(trace?.trace.instructions).toBeGreaterThan(trace?.trace.max_instructions ?? 0n);
+
+			const now = BigInt(await pic.getTime()) * 1_000_000n;
+			const day = 86_400_000_000_000n;
+
+			expect(trace?.day).toEqual((now / day) * day);
+		});
+
+		it('should not trace failed calls', async () => {
+			actor.setIdentity(user);
+
+			const { set_doc } = actor;
+
+			await expect(
+				set_doc('unknown', nanoid(), {
+					attachments: toNullable(),
+					data: await toArray({ hello: 'World' }),
+					description: toNullable(),
+					publish_at: toNullable(),
+					unpublish_at: toNullable(),
+					version: toNullable()
+				})
+			).rejects.toThrow();
+
+			expect(await countSetDocCalls()).toEqual(2n);
+		});
+
+		it('should stop tracing once disabled', async () => {
+			actor.setIdentity(controller);
+
+			const { set_traces_config } = actor;
+
+			await set_traces_config({ enabled: false });
+
+			await setDoc();
+
+			expect(await countSetDocCalls()).toEqual(2n);
+		});
+	});
+
+	describe('user', () => {
+		beforeAll(() => {
+			actor.setIdentity(user);
+		});
+
+		it('should not set the traces config', async () => {
+			const { set_traces_config } = actor;
+
+			await expect(set_traces_config({ enabled: true })).rejects.toThrow(
+				SATELLITE_ADMIN_ERROR_MSG
+			);
+		});
+
+		it('should not list the traces', async () => {
+			const { list_traces } = actor;
+
+			await expect(list_traces()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
@@ -0,0 +1,258 @@
+import type {
+	_SERVICE as SatelliteActor,
+	SetRule,
+	StorageConfig,
+	TransferSection
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_ADMIN_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Transfer', () => {
+	let pic: PocketIc;
+	let sourceActor: Actor<SatelliteActor>;
+	let targetActor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const importer = Ed25519KeyIdentity.generate();
+
+	const setRule: SetRule = {
+		memory: toNullable({ Stable: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const storage: StorageConfig = {
+		headers: [],
+		iframe: toNullable({ Deny: null }),
+		redirects: toNullable(),
+		rewrites: [],
+		raw_access: toNullable(),
+		delegations: toNullable(),
+		max_response_body_size: toNullable(),
+		csp_nonce: toNullable(),
+		wildcard_domains: toNullable(),
+		locales: toNullable(),
+		weighted_rewrites: toNullable(),
+		list_limits: toNullable(),
+		max_memory_size: toNullable()
+	};
+
+	const transfer = async (section: TransferSection): Promise<number> => {
+		const { export_chunk } = sourceActor;
+		const { import_chunk } = targetActor;
+
+		let cursor: [] | [Uint8Array | number[]] = [];
+		let chunks = 0;
+
+		do {
+			const { data, cursor: next } = await export_chunk({ section, cursor });
+
+			await import_chunk({ section, data });
+
+			cursor = next;
+			chunks++;
+		} while (cursor.length > 0);
+
+		return chunks;
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: s } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		sourceActor = s;
+		sourceActor.setIdentity(controller);
+
+		const { actor: t } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(importer),
+			sender: importer.getPrincipal()
+		});
+
+		targetActor = t;
+		targetActor.setIdentity(importer);
+
+		const { set_rule, set_doc, set_storage_config } = sourceActor;
+
+		await set_storage_config(storage);
+
+		await set_rule({ Db: null }, 'notes', setRule);
+		await set_rule({ Storage: null }, 'images', setRule);
+
+		for (const key of ['first', 'second', 'third']) {
+			await set_doc('notes', key, {
+				attachments: toNullable(),
+				data: await toArray({ hello: key }),
+				description: toNullable(`Note ${key}`),
+				publish_at: toNullable(),
+				unpublish_at: toNullable(),
+				version: toNullable()
+			});
+		}
+
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = sourceActor;
+
+		const file = await init_asset_upload({
+			collection: 'images',
+			description: toNullable(),
+			encoding_type: [],
+			full_path: '/images/hello.txt',
+			name: 'hello.txt',
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const chunk = await upload_asset_chunk({
+			batch_id: file.batch_id,
+			content: new Uint8Array(1_500_000).fill(97),
+			order_id: [0n]
+		});
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: [chunk.chunk_id],
+			headers: [['Content-Type', 'text/plain']]
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		it('should transfer the config', async () => {
+			expect(await transfer({ Config: null })).toEqual(1);
+
+			const { get_config } = targetActor;
+
+			const { storage: config } = await get_config();
+
+			expect(fromNullable(config.iframe)).toEqual({ Deny: null });
+		});
+
+		it('should transfer the documents with their owner and timestamps', async () => {
+			expect(await transfer({ Db: 'notes' })).toEqual(2);
+
+			const { get_rule: getTargetRule } = targetActor;
+
+			const rule = fromNullable(await getTargetRule({ Db: null }, 'notes'));
+
+			assertNonNullish(rule);
+
+			expect(fromNullable(rule.memory)).toEqual({ Stable: null });
+
+			const params = {
+				matcher: toNullable(),
+				order: toNullable(),
+				owner: toNullable(),
+				paginate: toNullable()
+			};
+
+			const { items: sourceItems } = await sourceActor.list_docs('notes', params);
+			const { items: targetItems } = await targetActor.list_docs('notes', params);
+
+			expect(targetItems).toHaveLength(3);
+			expect(targetItems).toEqual(sourceItems);
+
+			const [[_, doc]] = targetItems;
+
+			expect(doc.owner.toText()).toEqual(controller.getPrincipal().toText());
+		});
+
+		it('should emit the changes of the imported documents', async () => {
+			const { set_webhook, list_webhook_deliveries } = targetActor;
+
+			await set_webhook('transfer', {
+				url: 'https://example.com/hooks/juno',
+				secret: toNullable('a-secret'),
+				events: [{ SetDoc: null }],
+				collections: toNullable(['notes']),
+				enabled: true
+			});
+
+			await transfer({ Db: 'notes' });
+
+			const deliveries = await list_webhook_deliveries();
+
+			expect(deliveries).toHaveLength(1);
+
+			const [[_, { payload }]] = deliveries;
+
+			const { caller, changes } = JSON.parse(payload);
+
+			expect(caller).toEqual(importer.getPrincipal().toText());
+			expect(changes).toHaveLength(3);
+		});
+
+		it('should transfer an asset in several chunks and restore its owner', async () => {
+			expect(await transfer({ Storage: 'images' })).toBeGreaterThan(2);
+
+			const sourceAsset = fromNullable(await sourceActor.get_asset('images', '/images/hello.txt'));
+			const targetAsset = fromNullable(await targetActor.get_asset('images', '/images/hello.txt'));
+
+			assertNonNullish(sourceAsset);
+			assertNonNullish(targetAsset);
+
+			expect(targetAsset.key.owner.toText()).toEqual(controller.getPrincipal().toText());
+			expect(targetAsset.headers).toEqual(sourceAsset.headers);
+
+			const [[_, { sha256, total_length }]] = targetAsset.encodings;
+			const [[__, source]] = sourceAsset.encodings;
+
+			expect(total_length).toEqual(1_500_000n);
+			expect(sha256).toEqual(source.sha256);
+		});
+
+		it('should not export a collection that does not exist', async () => {
+			const { export_chunk } = sourceActor;
+
+			await expect(export_chunk({ section: { Db: 'unknown' }, cursor: [] })).rejects.toThrow();
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			sourceActor.setIdentity(new AnonymousIdentity());
+			targetActor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not export a chunk', async () => {
+			const { export_chunk } = sourceActor;
+
+			await expect(export_chunk({ section: { Config: null }, cursor: [] })).rejects.toThrow(
+				SATELLITE_ADMIN_ERROR_MSG
+			);
+		});
+
+		it('should not import a chunk', async () => {
+			const { import_chunk } = targetActor;
+
+			await expect(import_chunk({ section: { Config: null }, data: [] })).rejects.toThrow(
+				SATELLITE_ADMIN_ERROR_MSG
+			);
+		});
+	});
+});
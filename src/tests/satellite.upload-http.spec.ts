@@ -0,0 +1,276 @@
+import type {
+	HttpRequest,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_ADMIN_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Upload over HTTP', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'forms';
+	const BOUNDARY = '----JunoFormBoundary';
+
+	const setRule: SetRule = {
+		memory: toNullable(),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Public: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const form = (files: { filename: string; content: string }[]): Uint8Array =>
+		new TextEncoder().encode(
+			[
+				`--${BOUNDARY}`,
+				'Content-Disposition: form-data; name="title"',
+				'',
+				'Hello',
+				...files.flatMap(({ filename, content }) => [
+					`--${BOUNDARY}`,
+					`Content-Disposition: form-data; name="file"; filename="${filename}"`,
+					'Content-Type: text/plain',
+					'',
+					content
+				]),
+				`--${BOUNDARY}--`,
+				''
+			].join('\r\n')
+		);
+
+	const request = ({
+		body,
+		secret,
+		contentType = `multipart/form-data; boundary=${BOUNDARY}`
+	}: {
+		body: Uint8Array;
+		secret?: string;
+		contentType?: string;
+	}): HttpRequest => ({
+		body,
+		certificate_version: toNullable(2),
+		headers: [
+			['Content-Type', contentType],
+			...(secret !== undefined
+				? ([['Authorization', `Bearer ${secret}`]] as [string, string][])
+				: [])
+		],
+		method: 'POST',
+		url: '/_juno/storage/upload'
+	});
+
+	let secret: string;
+	let tokenId: string;
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+
+		// The random number generator is initialized with a timer
+		await pic.tick();
+
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Storage: null }, TEST_COLLECTION, setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should not create a token for an unknown collection', async () => {
+			const { create_upload_token } = actor;
+
+			await expect(
+				create_upload_token({
+					collection: 'unknown',
+					owner: user.getPrincipal(),
+					expires_at: toNullable()
+				})
+			).rejects.toThrow('Upload tokens require an existing storage collection.');
+		});
+
+		it('should create an upload token', async () => {
+			const { create_upload_token, list_upload_tokens } = actor;
+
+			const result = await create_upload_token({
+				collection: TEST_COLLECTION,
+				owner: user.getPrincipal(),
+				expires_at: toNullable()
+			});
+
+			secret = result.secret;
+			tokenId = result.id;
+
+			expect(secret).not.toEqual(tokenId);
+
+			const tokens = await list_upload_tokens();
+
+			expect(tokens).toHaveLength(1);
+
+			const [[id, token]] = tokens;
+
+			expect(id).toEqual(tokenId);
+			expect(token.collection).toEqual(TEST_COLLECTION);
+			expect(token.owner.toText()).toEqual(user.getPrincipal().toText());
+		});
+	});
+
+	describe('http', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should upgrade the upload http request', async () => {
+			const { http_request } = actor;
+
+			const { upgrade } = await http_request(request({ body: new Uint8Array(), secret }));
+
+			expect(fromNullable(upgrade)).toBeTruthy();
+		});
+
+		it('should reject an upload without a valid token', async () => {
+			const { http_request_update } = actor;
+
+			const body = form([{ filename: 'hello.txt', content: 'Hello' }]);
+
+			const { status_code } = await http_request_update(request({ body }));
+
+			expect(status_code).toEqual(401);
+
+			const { status_code: status_code_unknown } = await http_request_update(
+				request({ body, secret: 'unknown' })
+			);
+
+			expect(status_code_unknown).toEqual(401);
+		});
+
+		it('should reject a body that is not a multipart form', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code } = await http_request_update(
+				request({
+					body: new TextEncoder().encode('{}'),
+					secret,
+					contentType: 'application/json'
+				})
+			);
+
+			expect(status_code).toEqual(415);
+		});
+
+		it('should reject a form without file', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code } = await http_request_update(request({ body: form([]), secret }));
+
+			expect(status_code).toEqual(400);
+		});
+
+		it('should upload the files of a form', async () => {
+			const { http_request_update } = actor;
+
+			const { status_code, body } = await http_request_update(
+				request({
+					body: form([
+						{ filename: 'hello.txt', content: 'Hello' },
+						{ filename: 'C:\\fakepath\\world.txt', content: 'World' }
+					]),
+					secret
+				})
+			);
+
+			expect(status_code).toEqual(201);
+
+			expect(JSON.parse(new TextDecoder().decode(body as Uint8Array))).toEqual({
+				full_paths: [`/${TEST_COLLECTION}/hello.txt`, `/${TEST_COLLECTION}/world.txt`]
+			});
+		});
+
+		it('should have uploaded the assets on behalf of the owner of the token', async () => {
+			actor.setIdentity(controller);
+
+			const { get_asset } = actor;
+
+			const asset = fromNullable(await get_asset(TEST_COLLECTION, `/${TEST_COLLECTION}/hello.txt`));
+
+			assertNonNullish(asset);
+
+			expect(asset.key.owner.toText()).toEqual(user.getPrincipal().toText());
+			expect(asset.headers).toContainEqual(['Content-Type', 'text/plain']);
+		});
+
+		it('should not upload anymore once the token is deleted', async () => {
+			actor.setIdentity(controller);
+
+			const { del_upload_token, list_upload_tokens, http_request_update } = actor;
+
+			await del_upload_token(tokenId);
+
+			expect(await list_upload_tokens()).toHaveLength(0);
+
+			const { status_code } = await http_request_update(
+				request({ body: form([{ filename: 'hello.txt', content: 'Hello' }]), secret })
+			);
+
+			expect(status_code).toEqual(401);
+		});
+	});
+
+	describe('user', () => {
+		beforeAll(() => {
+			actor.setIdentity(user);
+		});
+
+		it('should not create an upload token', async () => {
+			const { create_upload_token } = actor;
+
+			await expect(
+				create_upload_token({
+					collection: TEST_COLLECTION,
+					owner: user.getPrincipal(),
+					expires_at: toNullable()
+				})
+			).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should not list the upload tokens', async () => {
+			const { list_upload_tokens } = actor;
+
+			await expect(list_upload_tokens()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+	});
+});
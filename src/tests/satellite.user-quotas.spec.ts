@@ -0,0 +1,186 @@
+import type { _SERVICE as SatelliteActor, SetRule } from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { assertNonNullish, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - User quotas', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const user1 = Ed25519KeyIdentity.generate();
+	const user2 = Ed25519KeyIdentity.generate();
+
+	const TEST_COLLECTION = 'test_quotas';
+	const TEST_STORAGE_COLLECTION = 'test_quotas_storage';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: [],
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, TEST_COLLECTION, {
+			...setRule,
+			user_quota: toNullable({
+				max_items: toNullable(2n),
+				max_bytes: toNullable()
+			})
+		});
+
+		await set_rule({ Storage: null }, TEST_STORAGE_COLLECTION, {
+			...setRule,
+			user_quota: toNullable({
+				max_items: toNullable(),
+				max_bytes: toNullable(64n)
+			})
+		});
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	const setDoc = async ({ key, identity }: { key: string; identity: Ed25519KeyIdentity }) => {
+		actor.setIdentity(identity);
+
+		const { set_doc, get_doc } = actor;
+
+		const current = fromNullable(await get_doc(TEST_COLLECTION, key));
+
+		return await set_doc(TEST_COLLECTION, key, {
+			attachments: toNullable(),
+			data: await toArray({ hello: key }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: current?.version ?? toNullable()
+		});
+	};
+
+	const uploadAsset = async ({
+		name,
+		identity,
+		size
+	}: {
+		name: string;
+		identity: Ed25519KeyIdentity;
+		size: number;
+	}) => {
+		actor.setIdentity(identity);
+
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+		const file = await init_asset_upload({
+			collection: TEST_STORAGE_COLLECTION,
+			description: toNullable(),
+			encoding_type: [],
+			full_path: `/${TEST_STORAGE_COLLECTION}/${name}`,
+			name,
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const chunk = await upload_asset_chunk({
+			batch_id: file.batch_id,
+			content: new Uint8Array(size),
+			order_id: [0n]
+		});
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: [chunk.chunk_id],
+			headers: []
+		});
+	};
+
+	describe('datastore', () => {
+		it('should write documents up to the quota', async () => {
+			await setDoc({ key: 'doc-1', identity: user1 });
+			await setDoc({ key: 'doc-2', identity: user1 });
+
+			await expect(setDoc({ key: 'doc-3', identity: user1 })).rejects.toThrow(
+				'error_user_quota_max_items'
+			);
+		});
+
+		it('should still update the documents once the quota is reached', async () => {
+			const doc = await setDoc({ key: 'doc-1', identity: user1 });
+
+			expect(fromNullable(doc.version)).toEqual(2n);
+		});
+
+		it('should count the quota per user', async () => {
+			const doc = await setDoc({ key: 'doc-3', identity: user2 });
+
+			expect(doc.owner.toText()).toEqual(user2.getPrincipal().toText());
+		});
+
+		it('should free the quota when a document is deleted', async () => {
+			actor.setIdentity(user1);
+
+			const { get_doc, del_doc } = actor;
+
+			const doc = fromNullable(await get_doc(TEST_COLLECTION, 'doc-2'));
+
+			assertNonNullish(doc);
+
+			await del_doc(TEST_COLLECTION, 'doc-2', { version: doc.version });
+
+			await setDoc({ key: 'doc-4', identity: user1 });
+		});
+
+		it('should not apply the quota to the controllers', async () => {
+			for (const key of ['doc-5', 'doc-6', 'doc-7']) {
+				await setDoc({ key, identity: controller });
+			}
+		});
+	});
+
+	describe('storage', () => {
+		it('should upload assets up to the quota', async () => {
+			await uploadAsset({ name: 'hello.txt', identity: user1, size: 50 });
+
+			await expect(uploadAsset({ name: 'world.txt', identity: user1, size: 50 })).rejects.toThrow(
+				'error_user_quota_max_bytes'
+			);
+		});
+
+		it('should replace the size of an updated asset', async () => {
+			await uploadAsset({ name: 'hello.txt', identity: user1, size: 60 });
+		});
+
+		it('should count the quota per user', async () => {
+			await uploadAsset({ name: 'world.txt', identity: user2, size: 50 });
+		});
+	});
+});
@@ -0,0 +1,241 @@
+import type {
+	DbConfig,
+	_SERVICE as SatelliteActor,
+	SetRule
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { fromNullable, toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { fromArray, toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Views', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+	const user = Ed25519KeyIdentity.generate();
+
+	const POSTS_COLLECTION = 'posts';
+	const STATS_COLLECTION = 'post_stats';
+	const VIEW_COLLECTION = 'public_posts';
+
+	const KEY = 'post-1';
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const dbConfig: DbConfig = {
+		proof_of_work: toNullable(),
+		aggregates: toNullable(),
+		list_limits: toNullable(),
+		max_memory_size: toNullable(),
+		history_collections: toNullable(),
+		views: toNullable([
+			{
+				collection: VIEW_COLLECTION,
+				sources: [POSTS_COLLECTION, STATS_COLLECTION],
+				fields: ['title', 'views']
+			}
+		]),
+		attachments: toNullable(),
+		http_collections: toNullable()
+	};
+
+	const setDoc = async ({
+		collection,
+		data
+	}: {
+		collection: string;
+		data: Record<string, unknown>;
+	}) => {
+		actor.setIdentity(user);
+
+		const { set_doc } = actor;
+
+		return await set_doc(collection, KEY, {
+			attachments: toNullable(),
+			data: await toArray(data),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	};
+
+	const getViewData = async (): Promise<Record<string, unknown> | undefined> => {
+		actor.setIdentity(new AnonymousIdentity());
+
+		const { get_doc } = actor;
+
+		const doc = fromNullable(await get_doc(VIEW_COLLECTION, KEY));
+
+		return doc === undefined ? undefined : await fromArray(doc.data);
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule, set_db_config } = actor;
+
+		await set_rule({ Db: null }, POSTS_COLLECTION, setRule);
+		await set_rule({ Db: null }, STATS_COLLECTION, setRule);
+		await set_rule({ Db: null }, VIEW_COLLECTION, { ...setRule, read: { Public: null } });
+
+		await set_db_config(dbConfig);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should not accept a view that is one of its sources', async () => {
+		actor.setIdentity(controller);
+
+		const { set_db_config } = actor;
+
+		await expect(
+			set_db_config({
+				...dbConfig,
+				views: toNullable([
+					{ collection: POSTS_COLLECTION, sources: [POSTS_COLLECTION], fields: ['title'] }
+				])
+			})
+		).rejects.toThrow('A view cannot be one of its sources.');
+	});
+
+	it('should not accept a view of a collection that does not exist', async () => {
+		actor.setIdentity(controller);
+
+		const { set_db_config } = actor;
+
+		await expect(
+			set_db_config({
+				...dbConfig,
+				views: toNullable([
+					{ collection: 'unknown', sources: [POSTS_COLLECTION], fields: ['title'] }
+				])
+			})
+		).rejects.toThrow('Collection "unknown" not found in Datastore.');
+
+		await expect(
+			set_db_config({
+				...dbConfig,
+				views: toNullable([
+					{ collection: VIEW_COLLECTION, sources: ['unknown'], fields: ['title'] }
+				])
+			})
+		).rejects.toThrow('Collection "unknown" not found in Datastore.');
+	});
+
+	it('should project the fields of a private document', async () => {
+		await setDoc({
+			collection: POSTS_COLLECTION,
+			data: { title: 'Hello', body: 'World', draft_notes: 'Secret' }
+		});
+
+		expect(await getViewData()).toEqual({ title: 'Hello' });
+	});
+
+	it('should join the documents of the sources by key', async () => {
+		await setDoc({ collection: STATS_COLLECTION, data: { views: 3, ip_addresses: ['1.2.3.4'] } });
+
+		expect(await getViewData()).toEqual({ title: 'Hello', views: 3 });
+
+		const { list_docs } = actor;
+
+		const { items_length } = await list_docs(VIEW_COLLECTION, {
+			matcher: toNullable(),
+			order: toNullable(),
+			owner: toNullable(),
+			paginate: toNullable()
+		});
+
+		expect(items_length).toEqual(1n);
+	});
+
+	it('should emit the changes of the documents of the view', async () => {
+		actor.setIdentity(controller);
+
+		const { set_webhook, list_webhook_deliveries } = actor;
+
+		await set_webhook('views', {
+			url: 'https://example.com/hooks/juno',
+			secret: toNullable('a-secret'),
+			events: [{ SetDoc: null }],
+			collections: toNullable([VIEW_COLLECTION]),
+			enabled: true
+		});
+
+		actor.setIdentity(user);
+
+		const { get_doc, set_doc } = actor;
+
+		const doc = fromNullable(await get_doc(STATS_COLLECTION, KEY));
+
+		await set_doc(STATS_COLLECTION, KEY, {
+			...doc!,
+			data: await toArray({ views: 4 })
+		});
+
+		actor.setIdentity(controller);
+
+		const deliveries = await list_webhook_deliveries();
+
+		expect(deliveries).toHaveLength(1);
+
+		const [[_, { payload }]] = deliveries;
+
+		const { changes } = JSON.parse(payload);
+
+		expect(changes).toHaveLength(1);
+		expect(changes[0].collection).toEqual(VIEW_COLLECTION);
+		expect(changes[0].key).toEqual(KEY);
+
+		expect(await getViewData()).toEqual({ title: 'Hello', views: 4 });
+	});
+
+	it('should not write the documents of a view', async () => {
+		await expect(
+			setDoc({ collection: VIEW_COLLECTION, data: { title: 'Forged' } })
+		).rejects.toThrow('The documents of a view cannot be written.');
+	});
+
+	it('should delete the document of the view with those of its sources', async () => {
+		actor.setIdentity(user);
+
+		const { del_doc, get_doc } = actor;
+
+		for (const collection of [POSTS_COLLECTION, STATS_COLLECTION]) {
+			const doc = fromNullable(await get_doc(collection, KEY));
+
+			await del_doc(collection, KEY, { version: doc?.version ?? [] });
+		}
+
+		expect(await getViewData()).toBeUndefined();
+	});
+});
@@ -0,0 +1,225 @@
+import type {
+	_SERVICE as SatelliteActor,
+	SetRule,
+	SetWebhook
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { toArray } from '@junobuild/utils';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { CONTROLLER_ERROR_MSG, SATELLITE_ADMIN_ERROR_MSG } from './constants/satellite-tests.constants';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Webhooks', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const setRule: SetRule = {
+		memory: toNullable({ Heap: null }),
+		max_size: toNullable(),
+		max_capacity: toNullable(),
+		read: { Managed: null },
+		mutable_permissions: toNullable(),
+		write: { Managed: null },
+		version: toNullable(),
+		rate_config: toNullable(),
+		user_quota: toNullable(),
+		collation: toNullable(),
+		retention: toNullable()
+	};
+
+	const webhook: SetWebhook = {
+		url: 'https://example.com/hooks/juno',
+		secret: toNullable('a-secret'),
+		events: [{ SetDoc: null }, { DeleteDoc: null }],
+		collections: toNullable(['notes']),
+		enabled: true
+	};
+
+	const setDoc = async ({ collection, key }: { collection: string; key: string }) => {
+		const { set_doc } = actor;
+
+		await set_doc(collection, key, {
+			attachments: toNullable(),
+			data: await toArray({ hello: key }),
+			description: toNullable(),
+			publish_at: toNullable(),
+			unpublish_at: toNullable(),
+			version: toNullable()
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_rule } = actor;
+
+		await set_rule({ Db: null }, 'notes', setRule);
+		await set_rule({ Db: null }, 'others', setRule);
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	describe('admin', () => {
+		it('should not set a webhook with an url that is not https', async () => {
+			const { set_webhook } = actor;
+
+			await expect(
+				set_webhook('sync', { ...webhook, url: 'http://example.com/hooks/juno' })
+			).rejects.toThrow('Webhook URL must be a valid https URL.');
+		});
+
+		it('should not set a webhook without events', async () => {
+			const { set_webhook } = actor;
+
+			await expect(set_webhook('sync', { ...webhook, events: [] })).rejects.toThrow(
+				'Webhooks must subscribe to at least one event.'
+			);
+		});
+
+		it('should not create a webhook without secret', async () => {
+			const { set_webhook } = actor;
+
+			await expect(set_webhook('sync', { ...webhook, secret: toNullable() })).rejects.toThrow(
+				'Webhook secret missing or empty.'
+			);
+		});
+
+		it('should set a webhook and list it without its secret', async () => {
+			const { set_webhook, list_webhooks } = actor;
+
+			await set_webhook('sync', webhook);
+
+			const webhooks = await list_webhooks();
+
+			expect(webhooks).toHaveLength(1);
+
+			const [[id, result]] = webhooks;
+
+			expect(id).toEqual('sync');
+			expect(result.url).toEqual(webhook.url);
+			expect(result.enabled).toBeTruthy();
+			expect(Object.keys(result)).not.toContain('secret');
+		});
+
+		it('should keep the secret when updating a webhook', async () => {
+			const { set_webhook, list_webhooks } = actor;
+
+			await set_webhook('sync', { ...webhook, secret: toNullable() });
+
+			expect(await list_webhooks()).toHaveLength(1);
+		});
+
+		it('should record a pending delivery for a matching change', async () => {
+			await setDoc({ collection: 'notes', key: 'first' });
+
+			const { list_webhook_deliveries } = actor;
+
+			const deliveries = await list_webhook_deliveries();
+
+			expect(deliveries).toHaveLength(1);
+
+			const [[_, delivery]] = deliveries;
+
+			expect(delivery.webhook_id).toEqual('sync');
+			expect(delivery.event).toEqual({ SetDoc: null });
+
+			const payload = JSON.parse(delivery.payload);
+
+			expect(payload.event).toEqual('SetDoc');
+			expect(payload.caller).toEqual(controller.getPrincipal().toText());
+			expect(payload.changes).toHaveLength(1);
+			expect(payload.changes[0].collection).toEqual('notes');
+			expect(payload.changes[0].key).toEqual('first');
+		});
+
+		it('should not deliver the changes of the collections that are not observed', async () => {
+			await setDoc({ collection: 'others', key: 'second' });
+
+			const { list_webhook_deliveries } = actor;
+
+			expect(await list_webhook_deliveries()).toHaveLength(1);
+		});
+
+		it('should not deliver the changes to a disabled webhook', async () => {
+			const { set_webhook, list_webhook_deliveries } = actor;
+
+			await set_webhook('sync', { ...webhook, enabled: false });
+
+			await setDoc({ collection: 'notes', key: 'third' });
+
+			expect(await list_webhook_deliveries()).toHaveLength(1);
+		});
+
+		it('should not drop the pending deliveries when the log is full', async () => {
+			const { set_webhook, list_webhook_deliveries } = actor;
+
+			await set_webhook('sync', webhook);
+
+			for (let i = 0; i < 105; i++) {
+				await setDoc({ collection: 'notes', key: `full-${i}` });
+			}
+
+			const deliveries = await list_webhook_deliveries();
+
+			expect(deliveries).toHaveLength(100);
+			expect(deliveries[0][0]).toEqual(0n);
+			expect(deliveries.every(([_, { status }]) => 'Pending' in status)).toBeTruthy();
+		});
+
+		it('should delete a webhook', async () => {
+			const { del_webhook, list_webhooks } = actor;
+
+			await del_webhook('sync');
+
+			expect(await list_webhooks()).toHaveLength(0);
+		});
+
+		it('should not delete an unknown webhook', async () => {
+			const { del_webhook } = actor;
+
+			await expect(del_webhook('unknown')).rejects.toThrow('Webhook not found.');
+		});
+	});
+
+	describe('anonymous', () => {
+		beforeAll(() => {
+			actor.setIdentity(new AnonymousIdentity());
+		});
+
+		it('should not set a webhook', async () => {
+			const { set_webhook } = actor;
+
+			await expect(set_webhook('sync', webhook)).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should not list the webhooks', async () => {
+			const { list_webhooks } = actor;
+
+			await expect(list_webhooks()).rejects.toThrow(SATELLITE_ADMIN_ERROR_MSG);
+		});
+
+		it('should not list the deliveries', async () => {
+			const { list_webhook_deliveries } = actor;
+
+			await expect(list_webhook_deliveries()).rejects.toThrow(CONTROLLER_ERROR_MSG);
+		});
+	});
+});
@@ -0,0 +1,218 @@
+import type {
+	HttpRequest,
+	_SERVICE as SatelliteActor,
+	StorageConfig
+} from '$declarations/satellite/satellite.did';
+import { idlFactory as idlFactorSatellite } from '$declarations/satellite/satellite.factory.did';
+import { AnonymousIdentity } from '@dfinity/agent';
+import { Ed25519KeyIdentity } from '@dfinity/identity';
+import { toNullable } from '@dfinity/utils';
+import { PocketIc, type Actor } from '@hadronous/pic';
+import { afterAll, beforeAll, describe, expect, inject } from 'vitest';
+import { decodeExprPath } from './utils/certification-tests.utils';
+import { SATELLITE_WASM_PATH, controllersInitArgs } from './utils/setup-tests.utils';
+
+describe('Satellite - Wildcard domains', () => {
+	let pic: PocketIc;
+	let actor: Actor<SatelliteActor>;
+
+	const controller = Ed25519KeyIdentity.generate();
+
+	const storage: StorageConfig = {
+		headers: [],
+		iframe: toNullable(),
+		redirects: toNullable(),
+		rewrites: [],
+		raw_access: toNullable(),
+		delegations: toNullable(),
+		max_response_body_size: toNullable(),
+		csp_nonce: toNullable(),
+		wildcard_domains: toNullable([['*.customers.example.com', '/tenants']]),
+		locales: toNullable(),
+		weighted_rewrites: toNullable(),
+		list_limits: toNullable(),
+		max_memory_size: toNullable()
+	};
+
+	const request = ({ host, url }: { host: string; url: string }): HttpRequest => ({
+		body: [],
+		certificate_version: toNullable(2),
+		headers: [['Host', host]],
+		method: 'GET',
+		url
+	});
+
+	const decoder = new TextDecoder();
+
+	const upload = async ({ full_path, content }: { full_path: string; content: string }) => {
+		const { init_asset_upload, upload_asset_chunk, commit_asset_upload } = actor;
+
+		const file = await init_asset_upload({
+			collection: '#dapp',
+			description: toNullable(),
+			encoding_type: [],
+			full_path,
+			name: full_path.split('/').pop() ?? full_path,
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
+		});
+
+		const chunk = await upload_asset_chunk({
+			batch_id: file.batch_id,
+			content: new TextEncoder().encode(content),
+			order_id: [0n]
+		});
+
+		await commit_asset_upload({
+			batch_id: file.batch_id,
+			chunk_ids: [chunk.chunk_id],
+			headers: [['Content-Type', 'text/html']]
+		});
+	};
+
+	beforeAll(async () => {
+		pic = await PocketIc.create(inject('PIC_URL'));
+
+		const { actor: c } = await pic.setupCanister<SatelliteActor>({
+			idlFactory: idlFactorSatellite,
+			wasm: SATELLITE_WASM_PATH,
+			arg: controllersInitArgs(controller),
+			sender: controller.getPrincipal()
+		});
+
+		actor = c;
+		actor.setIdentity(controller);
+
+		const { set_storage_config } = actor;
+
+		await set_storage_config(storage);
+
+		await upload({ full_path: '/tenants/acme/index.html', content: '<html>Acme</html>' });
+		await upload({ full_path: '/tenants/acme/about.html', content: '<html>About Acme</html>' });
+		await upload({ full_path: '/tenants/globex/index.html', content: '<html>Globex</html>' });
+
+		actor.setIdentity(new AnonymousIdentity());
+	});
+
+	afterAll(async () => {
+		await pic?.tearDown();
+	});
+
+	it('should serve the root of each tenant', async () => {
+		const { http_request } = actor;
+
+		const acme = await http_request(request({ host: 'acme.customers.example.com', url: '/' }));
+
+		expect(acme.status_code).toEqual(200);
+		expect(decoder.decode(acme.body as Uint8Array)).toEqual('<html>Acme</html>');
+
+		const globex = await http_request(
+			request({ host: 'globex.customers.example.com:443', url: '/' })
+		);
+
+		expect(globex.status_code).toEqual(200);
+		expect(decoder.decode(globex.body as Uint8Array)).toEqual('<html>Globex</html>');
+	});
+
+	it('should serve a page of a tenant with its alternative path', async () => {
+		const { http_request } = actor;
+
+		const { status_code, body } = await http_request(
+			request({ host: 'acme.customers.example.com', url: '/about' })
+		);
+
+		expect(status_code).toEqual(200);
+		expect(decoder.decode(body as Uint8Array)).toEqual('<html>About Acme</html>');
+	});
+
+	it('should fallback to the assets of the satellite for an unknown tenant', async () => {
+		const { http_request } = actor;
+
+		const { body } = await http_request(
+			request({ host: 'unknown.customers.example.com', url: '/about' })
+		);
+
+		expect(decoder.decode(body as Uint8Array)).not.toContain('Acme');
+	});
+
+	it('should not match a nested subdomain', async () => {
+		const { http_request } = actor;
+
+		const { body } = await http_request(
+			request({ host: 'www.acme.customers.example.com', url: '/' })
+		);
+
+		expect(decoder.decode(body as Uint8Array)).not.toContain('Acme');
+	});
+
+	describe('certification', () => {
+		it('should certify the response of a tenant for its exact path', async () => {
+			const { http_request } = actor;
+
+			const acme = await http_request(
+				request({ host: 'acme.customers.example.com', url: '/about' })
+			);
+
+			expect(decodeExprPath(acme.headers)).toEqual(['http_expr', 'about', '<$>']);
+
+			const globex = await http_request(
+				request({ host: 'globex.customers.example.com', url: '/' })
+			);
+
+			expect(decodeExprPath(globex.headers)).toEqual(['http_expr', '', '<$>']);
+		});
+
+		it('should still serve the tenants once an asset of another tenant is deleted', async () => {
+			const { del_asset, http_request } = actor;
+
+			actor.setIdentity(controller);
+
+			await del_asset('#dapp', '/tenants/acme/index.html');
+
+			actor.setIdentity(new AnonymousIdentity());
+
+			const globex = await http_request(
+				request({ host: 'globex.customers.example.com', url: '/' })
+			);
+
+			expect(globex.status_code).toEqual(200);
+			expect(decoder.decode(globex.body as Uint8Array)).toEqual('<html>Globex</html>');
+			expect(decodeExprPath(globex.headers)).toEqual(['http_expr', '', '<$>']);
+
+			const acme = await http_request(request({ host: 'acme.customers.example.com', url: '/' }));
+
+			expect(decoder.decode(acme.body as Uint8Array)).not.toContain('Acme');
+		});
+	});
+
+	describe('configuration', () => {
+		beforeAll(() => {
+			actor.setIdentity(controller);
+		});
+
+		it('should reject an empty prefix', async () => {
+			const { set_storage_config } = actor;
+
+			await expect(
+				set_storage_config({
+					...storage,
+					wildcard_domains: toNullable([['*.customers.example.com', '/']])
+				})
+			).rejects.toThrow(
+				'Path prefix of the tenants of a wildcard domain must be a non-empty absolute path.'
+			);
+		});
+
+		it('should reject a domain that is not a wildcard', async () => {
+			const { set_storage_config } = actor;
+
+			await expect(
+				set_storage_config({
+					...storage,
+					wildcard_domains: toNullable([['customers.example.com', '/tenants']])
+				})
+			).rejects.toThrow('Wildcard domain must start with *.');
+		});
+	});
+});
@@ -0,0 +1,10 @@
+import { Cbor } from '@dfinity/agent';
+import { base64ToUint8Array } from '@dfinity/utils';
+
+export const decodeExprPath = (headers: [string, string][]): string[] => {
+	const [_, certificate] = headers.find(([header, _]) => header === 'IC-Certificate') ?? [];
+
+	const [, exprPath] = /expr_path=:([^:]*):/.exec(certificate ?? '') ?? [];
+
+	return Cbor.decode(base64ToUint8Array(exprPath));
+};
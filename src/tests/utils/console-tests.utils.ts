@@ -93,7 +93,9 @@ const uploadSegment = async ({
 			encoding_type: ['identity'],
 			full_path: fullPath,
 			name,
-			token: toNullable()
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
 		},
 		proposalId
 	);
@@ -316,7 +318,9 @@ export const uploadFile = async ({
 			encoding_type: [],
 			full_path: '/hello3.html',
 			name: 'hello3.html',
-			token: toNullable()
+			publish_at: toNullable(),
+			token: toNullable(),
+			unpublish_at: toNullable()
 		},
 		proposalId
 	);